@@ -38,17 +38,29 @@
 //! For the complete protocol specification and documentation, visit
 //! <https://agentclientprotocol.com>.
 
+#[cfg(feature = "unstable_diff_hunks")]
+mod line_diff;
+#[cfg(feature = "unstable_raw_output_patch")]
+mod merge_patch;
+mod method_schema;
+mod resource_uri;
 pub mod rpc;
 mod serde_util;
+mod tokens;
 pub mod v1;
 #[cfg(feature = "unstable_protocol_v2")]
 pub mod v2;
 mod version;
 
+pub use method_schema::MethodSchema;
+pub use resource_uri::{ResourceUri, ResourceUriKind};
 #[cfg(feature = "unstable_auth_methods")]
 pub(crate) use serde_util::DefaultTrueOnError;
 pub(crate) use serde_util::SkipListener;
 pub use serde_util::{IntoMaybeUndefined, IntoOption, MaybeUndefined};
+#[cfg(feature = "tokenizer")]
+pub use tokens::Tokenizer;
+pub use tokens::{AUDIO_TOKEN_ESTIMATE, IMAGE_TOKEN_ESTIMATE};
 pub use version::*;
 
 #[cfg(test)]
@@ -0,0 +1,138 @@
+//! Line-based diffing used to split whole-file edits into reviewable hunks.
+//!
+//! Lines keep their trailing newline, so a change to a file's final newline
+//! shows up as a changed last line. Hunks carry no context lines.
+
+/// A changed region between two texts, as 0-based line offsets and counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LineHunk {
+    pub(crate) old_start: usize,
+    pub(crate) old_count: usize,
+    pub(crate) new_start: usize,
+    pub(crate) new_count: usize,
+}
+
+/// Above this many longest-common-subsequence table cells, the changed middle
+/// of the texts is reported as a single hunk instead of being diffed further.
+const MAX_LCS_CELLS: usize = 1 << 22;
+
+/// Splits the change from `old` to `new` into hunks of consecutive changed lines.
+pub(crate) fn line_hunks(old: &str, new: &str) -> Vec<LineHunk> {
+    let old: Vec<&str> = old.split_inclusive('\n').collect();
+    let new: Vec<&str> = new.split_inclusive('\n').collect();
+
+    let prefix = old
+        .iter()
+        .zip(&new)
+        .take_while(|(old_line, new_line)| old_line == new_line)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old_line, new_line)| old_line == new_line)
+        .count();
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    if old_mid.is_empty() && new_mid.is_empty() {
+        return Vec::new();
+    }
+    if old_mid.is_empty()
+        || new_mid.is_empty()
+        || old_mid.len().saturating_mul(new_mid.len()) > MAX_LCS_CELLS
+    {
+        return vec![LineHunk {
+            old_start: prefix,
+            old_count: old_mid.len(),
+            new_start: prefix,
+            new_count: new_mid.len(),
+        }];
+    }
+
+    // lcs[i * width + j] is the length of the longest common subsequence of
+    // old_mid[i..] and new_mid[j..].
+    let width = new_mid.len() + 1;
+    let mut lcs = vec![0u32; (old_mid.len() + 1) * width];
+    for i in (0..old_mid.len()).rev() {
+        for j in (0..new_mid.len()).rev() {
+            lcs[i * width + j] = if old_mid[i] == new_mid[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut hunks = Vec::new();
+    let mut open: Option<(usize, usize)> = None;
+    let (mut i, mut j) = (0, 0);
+    let mut close = |open: &mut Option<(usize, usize)>, i: usize, j: usize| {
+        if let Some((old_start, new_start)) = open.take() {
+            hunks.push(LineHunk {
+                old_start: prefix + old_start,
+                old_count: i - old_start,
+                new_start: prefix + new_start,
+                new_count: j - new_start,
+            });
+        }
+    };
+    while i < old_mid.len() || j < new_mid.len() {
+        if i < old_mid.len() && j < new_mid.len() && old_mid[i] == new_mid[j] {
+            close(&mut open, i, j);
+            i += 1;
+            j += 1;
+            continue;
+        }
+        open.get_or_insert((i, j));
+        if j == new_mid.len()
+            || (i < old_mid.len() && lcs[(i + 1) * width + j] >= lcs[i * width + j + 1])
+        {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    close(&mut open, i, j);
+    hunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hunk(old_start: usize, old_count: usize, new_start: usize, new_count: usize) -> LineHunk {
+        LineHunk {
+            old_start,
+            old_count,
+            new_start,
+            new_count,
+        }
+    }
+
+    #[test]
+    fn identical_texts_have_no_hunks() {
+        assert_eq!(line_hunks("a\nb\n", "a\nb\n"), []);
+        assert_eq!(line_hunks("", ""), []);
+    }
+
+    #[test]
+    fn separate_changes_become_separate_hunks() {
+        assert_eq!(
+            line_hunks("a\nb\nc\nd\ne\n", "a\nB\nc\nd\nE\nf\n"),
+            [hunk(1, 1, 1, 1), hunk(4, 1, 4, 2)]
+        );
+    }
+
+    #[test]
+    fn pure_insertions_and_deletions_have_empty_ranges() {
+        assert_eq!(line_hunks("a\nc\n", "a\nb\nc\n"), [hunk(1, 0, 1, 1)]);
+        assert_eq!(line_hunks("a\nb\nc\n", "a\nc\n"), [hunk(1, 1, 1, 0)]);
+        assert_eq!(line_hunks("", "a\n"), [hunk(0, 0, 0, 1)]);
+    }
+
+    #[test]
+    fn final_newline_change_is_a_changed_line() {
+        assert_eq!(line_hunks("a\nb", "a\nb\n"), [hunk(1, 1, 1, 1)]);
+    }
+}
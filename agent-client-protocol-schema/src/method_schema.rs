@@ -0,0 +1,173 @@
+//! JSON Schemas for a single method, for tooling such as editor completion and
+//! validation that does not want the combined protocol schema.
+//!
+//! Params and result types carry the method they belong to in their
+//! `x-method` schema extension, the same registry the published schema is
+//! grouped by. The routing enums list every such type, so a method's schemas
+//! are found by generating those enums and matching on `x-method`.
+
+use schemars::{Schema, SchemaGenerator, generate::SchemaSettings};
+use serde_json::{Map, Value};
+
+const DEFINITIONS_PREFIX: &str = "#/$defs/";
+
+/// The JSON Schemas for one method's params and result.
+///
+/// Each schema is self-contained: it carries the definitions it refers to
+/// under `$defs`.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct MethodSchema {
+    /// The schema for the method's `params`.
+    pub params: Schema,
+    /// The schema for the method's `result`, or `None` for a notification.
+    pub result: Option<Schema>,
+}
+
+/// Generates the schema of each routing enum.
+pub(crate) type RoutingSchema = fn(&mut SchemaGenerator) -> Schema;
+
+/// Finds the schemas for `method` among the types listed by the routing enums.
+///
+/// `params` generates the request and notification enums, and `results` the
+/// response enums.
+pub(crate) fn method_schema(
+    method: &str,
+    params: &[RoutingSchema],
+    results: &[RoutingSchema],
+) -> Option<MethodSchema> {
+    let mut generator = SchemaSettings::draft2020_12().into_generator();
+    let params = find_definition(&mut generator, method, params)?;
+    let result = find_definition(&mut generator, method, results);
+    Some(MethodSchema {
+        params: standalone_schema(&generator, &params),
+        result: result.map(|result| standalone_schema(&generator, &result)),
+    })
+}
+
+/// Returns the name of the first definition for `method` listed by `routing`.
+fn find_definition(
+    generator: &mut SchemaGenerator,
+    method: &str,
+    routing: &[RoutingSchema],
+) -> Option<String> {
+    routing.iter().find_map(|routing_schema| {
+        let schema = routing_schema(generator);
+        let mut names = Vec::new();
+        collect_refs(schema.as_value(), &mut names);
+        names.into_iter().find(|name| {
+            generator
+                .definitions()
+                .get(name)
+                .and_then(|definition| definition.get("x-method"))
+                .and_then(Value::as_str)
+                == Some(method)
+        })
+    })
+}
+
+/// Builds a root schema for the definition `name`, with every definition it
+/// reaches under `$defs`.
+fn standalone_schema(generator: &SchemaGenerator, name: &str) -> Schema {
+    let definitions = generator.definitions();
+    let mut root = match definitions.get(name) {
+        Some(Value::Object(definition)) => definition.clone(),
+        _ => Map::new(),
+    };
+
+    let mut reachable = Map::new();
+    let mut pending = Vec::new();
+    collect_refs(&Value::Object(root.clone()), &mut pending);
+    while let Some(next) = pending.pop() {
+        if reachable.contains_key(&next) {
+            continue;
+        }
+        if let Some(definition) = definitions.get(&next) {
+            collect_refs(definition, &mut pending);
+            reachable.insert(next, definition.clone());
+        }
+    }
+
+    if let Some(meta_schema) = generator.settings().meta_schema.as_deref() {
+        root.insert("$schema".into(), meta_schema.into());
+    }
+    root.entry("title").or_insert_with(|| name.into());
+    if !reachable.is_empty() {
+        root.insert("$defs".into(), Value::Object(reachable));
+    }
+    root.into()
+}
+
+/// Collects the definition names referenced anywhere in `value`.
+fn collect_refs(value: &Value, names: &mut Vec<String>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                if key == "$ref"
+                    && let Some(name) = value
+                        .as_str()
+                        .and_then(|reference| reference.strip_prefix(DEFINITIONS_PREFIX))
+                {
+                    names.push(name.to_string());
+                } else {
+                    collect_refs(value, names);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_refs(item, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v1::{AGENT_METHOD_NAMES, CLIENT_METHOD_NAMES, method_schema};
+
+    fn property_names(schema: &schemars::Schema) -> Vec<&str> {
+        schema.as_value()["properties"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect()
+    }
+
+    #[test]
+    fn test_request_permission_schema() {
+        let schema = method_schema(CLIENT_METHOD_NAMES.session_request_permission).unwrap();
+
+        assert_eq!(
+            schema.params.as_value()["title"],
+            "RequestPermissionRequest"
+        );
+        assert_eq!(
+            property_names(&schema.params),
+            ["sessionId", "toolCall", "options", "_meta"]
+        );
+        let definitions = schema.params.as_value()["$defs"].as_object().unwrap();
+        assert!(definitions.contains_key("ToolCallUpdate"));
+        assert!(definitions.contains_key("PermissionOption"));
+        assert!(!definitions.contains_key("InitializeRequest"));
+
+        let result = schema.result.unwrap();
+        assert_eq!(result.as_value()["title"], "RequestPermissionResponse");
+        assert_eq!(property_names(&result), ["outcome", "_meta"]);
+    }
+
+    #[test]
+    fn test_notification_schema_has_no_result() {
+        let schema = method_schema(AGENT_METHOD_NAMES.session_cancel).unwrap();
+        assert_eq!(schema.params.as_value()["title"], "CancelNotification");
+        assert_eq!(schema.result, None);
+    }
+
+    #[test]
+    fn test_unknown_method_has_no_schema() {
+        assert_eq!(method_schema("session/unknown"), None);
+        assert_eq!(method_schema("_zed/custom"), None);
+    }
+}
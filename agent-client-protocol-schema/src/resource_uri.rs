@@ -0,0 +1,263 @@
+//! Classification of the free-form `uri` strings on resources.
+//!
+//! Resource URIs in the wild mix `file://` URIs, bare paths, web URLs, and
+//! editor-specific schemes such as `zed://`. [`ResourceUri`] keeps the raw
+//! string for the wire and sorts it into one of those shapes on demand.
+
+use std::path::PathBuf;
+
+use derive_more::{Display, From};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A resource URI, serialized as the raw string it was built from.
+///
+/// ```
+/// use agent_client_protocol_schema::{ResourceUri, ResourceUriKind};
+///
+/// let uri = ResourceUri::new("file:///home/me/My%20Notes.md");
+/// assert_eq!(uri.scheme(), Some("file"));
+/// assert_eq!(
+///     uri.as_local_path(),
+///     Some(std::path::PathBuf::from("/home/me/My Notes.md"))
+/// );
+///
+/// let uri = ResourceUri::new("zed://settings");
+/// assert_eq!(
+///     uri.kind(),
+///     ResourceUriKind::Custom {
+///         scheme: "zed",
+///         rest: "//settings"
+///     }
+/// );
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
+#[serde(transparent)]
+#[from(String, &str)]
+#[non_exhaustive]
+pub struct ResourceUri(pub String);
+
+impl ResourceUri {
+    /// Wraps a raw URI string.
+    #[must_use]
+    pub fn new(uri: impl Into<String>) -> Self {
+        Self(uri.into())
+    }
+
+    /// The raw URI string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The URI scheme as written, or `None` for a bare path.
+    ///
+    /// A single letter followed by `:` is read as a Windows drive letter, not
+    /// a scheme.
+    #[must_use]
+    pub fn scheme(&self) -> Option<&str> {
+        let (scheme, _) = self.0.split_once(':')?;
+        let mut chars = scheme.chars();
+        let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+            && scheme.len() > 1;
+        valid.then_some(scheme)
+    }
+
+    /// Sorts the URI into a local file, a web URL, or another scheme.
+    ///
+    /// Bare paths are local files. `file:` URIs are local files when they have
+    /// no host or the host is `localhost`; their path is percent-decoded and
+    /// any query or fragment is dropped. A `file:` URI that names another host
+    /// or does not decode to UTF-8 is reported as [`ResourceUriKind::Custom`].
+    #[must_use]
+    pub fn kind(&self) -> ResourceUriKind<'_> {
+        let Some(scheme) = self.scheme() else {
+            return ResourceUriKind::File(PathBuf::from(&self.0));
+        };
+        let rest = &self.0[scheme.len() + 1..];
+        if scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https") {
+            return ResourceUriKind::Http(&self.0);
+        }
+        if scheme.eq_ignore_ascii_case("file")
+            && let Some(path) = file_uri_path(rest)
+        {
+            return ResourceUriKind::File(path);
+        }
+        ResourceUriKind::Custom { scheme, rest }
+    }
+
+    /// The local file system path this URI points to, if any.
+    #[must_use]
+    pub fn as_local_path(&self) -> Option<PathBuf> {
+        match self.kind() {
+            ResourceUriKind::File(path) => Some(path),
+            ResourceUriKind::Http(_) | ResourceUriKind::Custom { .. } => None,
+        }
+    }
+}
+
+/// What a [`ResourceUri`] points to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ResourceUriKind<'a> {
+    /// A file on the local machine, from a `file:` URI or a bare path.
+    File(PathBuf),
+    /// An `http:` or `https:` URL, as written.
+    Http(&'a str),
+    /// Any other scheme, with the text after the `:`.
+    Custom {
+        /// The scheme as written.
+        scheme: &'a str,
+        /// Everything after the `:`.
+        rest: &'a str,
+    },
+}
+
+/// Extracts the local path from the part of a `file:` URI after the scheme.
+fn file_uri_path(rest: &str) -> Option<PathBuf> {
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    let path = match rest.strip_prefix("//") {
+        Some(authority_and_path) => {
+            let (host, path) = authority_and_path
+                .find('/')
+                .map_or((authority_and_path, ""), |index| {
+                    authority_and_path.split_at(index)
+                });
+            if !host.is_empty() && !host.eq_ignore_ascii_case("localhost") {
+                return None;
+            }
+            path
+        }
+        None => rest,
+    };
+    let path = percent_decode(path)?;
+    // `file:///C:/dir` names `C:/dir` on Windows.
+    #[cfg(windows)]
+    let path = match path.strip_prefix('/') {
+        Some(stripped) if stripped.as_bytes().get(1) == Some(&b':') => stripped.to_string(),
+        _ => path,
+    };
+    Some(PathBuf::from(path))
+}
+
+/// Decodes `%XX` escapes, failing on malformed escapes or invalid UTF-8.
+fn percent_decode(text: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = tail.get(..2)?;
+            let hex = std::str::from_utf8(hex).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_uris() {
+        let uri = ResourceUri::new("file:///project/src/main.rs");
+        assert_eq!(uri.scheme(), Some("file"));
+        assert_eq!(
+            uri.kind(),
+            ResourceUriKind::File(PathBuf::from("/project/src/main.rs"))
+        );
+
+        assert_eq!(
+            ResourceUri::new("file://localhost/project/My%20Notes%C3%A9.md").as_local_path(),
+            Some(PathBuf::from("/project/My Notesé.md"))
+        );
+        assert_eq!(
+            ResourceUri::new("file:///project/src/cli.rs#L12").as_local_path(),
+            Some(PathBuf::from("/project/src/cli.rs"))
+        );
+        assert_eq!(
+            ResourceUri::new("file:/project/a%2Bb.rs").as_local_path(),
+            Some(PathBuf::from("/project/a+b.rs"))
+        );
+    }
+
+    #[test]
+    fn test_file_uris_that_are_not_local() {
+        for raw in [
+            "file://build-server/share/log.txt",
+            "file:///bad%zzescape",
+            "file:///truncated%2",
+            "file:///invalid%FFutf8",
+        ] {
+            let uri = ResourceUri::new(raw);
+            assert_eq!(uri.as_local_path(), None, "{raw}");
+            assert!(
+                matches!(uri.kind(), ResourceUriKind::Custom { scheme: "file", .. }),
+                "{raw}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_bare_paths() {
+        for raw in [
+            "/project/src/main.rs",
+            "src/main.rs",
+            "C:\\project\\main.rs",
+        ] {
+            let uri = ResourceUri::new(raw);
+            assert_eq!(uri.scheme(), None, "{raw}");
+            assert_eq!(uri.as_local_path(), Some(PathBuf::from(raw)));
+        }
+    }
+
+    #[test]
+    fn test_http_uris() {
+        let uri = ResourceUri::new("https://agentclientprotocol.com/protocol?tab=1#top");
+        assert_eq!(uri.scheme(), Some("https"));
+        assert_eq!(
+            uri.kind(),
+            ResourceUriKind::Http("https://agentclientprotocol.com/protocol?tab=1#top")
+        );
+        assert_eq!(uri.as_local_path(), None);
+        assert!(matches!(
+            ResourceUri::new("HTTP://example.com").kind(),
+            ResourceUriKind::Http(_)
+        ));
+    }
+
+    #[test]
+    fn test_custom_schemes() {
+        let uri = ResourceUri::new("zed://settings/keymap.json");
+        assert_eq!(uri.scheme(), Some("zed"));
+        assert_eq!(
+            uri.kind(),
+            ResourceUriKind::Custom {
+                scheme: "zed",
+                rest: "//settings/keymap.json"
+            }
+        );
+        assert_eq!(uri.as_local_path(), None);
+
+        assert_eq!(
+            ResourceUri::new("attachment:report.md").kind(),
+            ResourceUriKind::Custom {
+                scheme: "attachment",
+                rest: "report.md"
+            }
+        );
+    }
+
+    #[test]
+    fn test_serializes_as_raw_string() {
+        let uri = ResourceUri::new("file:///project/My%20Notes.md");
+        let json = serde_json::to_value(&uri).unwrap();
+        assert_eq!(json, "file:///project/My%20Notes.md");
+        assert_eq!(serde_json::from_value::<ResourceUri>(json).unwrap(), uri);
+    }
+}
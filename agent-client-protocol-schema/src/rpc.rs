@@ -123,6 +123,28 @@ pub struct Notification<Params> {
     pub params: Option<Params>,
 }
 
+/// Any JSON-RPC message: a request, a response, or a notification.
+///
+/// The payload types are usually one side's routing enums, as in
+/// [`v1::AgentOutgoingMessage`](crate::v1::AgentOutgoingMessage). Tooling that
+/// records, replays, or proxies frames can use [`v1::RawMessage`](crate::v1::RawMessage)
+/// instead, which keeps payloads as JSON so they can be decoded by `method`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[allow(
+    clippy::exhaustive_enums,
+    reason = "This comes from the JSON-RPC specification itself"
+)]
+#[serde(untagged)]
+#[schemars(inline)]
+pub enum Message<Req, Resp, Notif, Err> {
+    /// A request, which expects a response with the same id.
+    Request(Request<Req>),
+    /// A response to an earlier request.
+    Response(Response<Resp, Err>),
+    /// A notification, which expects no response.
+    Notification(Notification<Notif>),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[schemars(inline)]
 enum JsonRpcVersion {
@@ -239,8 +261,10 @@ mod tests {
     use super::*;
 
     use crate::v1::{
-        AgentNotification, CancelNotification, ClientNotification, ContentBlock, ContentChunk,
-        SessionId, SessionNotification, SessionUpdate, TextContent,
+        AgentNotification, AgentOutgoingMessage, AgentResponse, CancelNotification,
+        ClientNotification, ClientOutgoingMessage, ClientRequest, ContentBlock, ContentChunk,
+        ErrorCode, NewSessionRequest, RawMessage, SessionId, SessionNotification, SessionUpdate,
+        TextContent,
     };
     use serde_json::{Number, Value, json};
 
@@ -304,10 +328,9 @@ mod tests {
     fn batch_serialization_round_trips_non_empty_messages() {
         let notification = JsonRpcMessage::wrap(Notification {
             method: "cancel".into(),
-            params: Some(ClientNotification::CancelNotification(CancelNotification {
-                session_id: SessionId("test-123".into()),
-                meta: None,
-            })),
+            params: Some(ClientNotification::CancelNotification(
+                CancelNotification::new(SessionId("test-123".into())),
+            )),
         });
 
         let batch = JsonRpcBatch::new(vec![notification]).unwrap();
@@ -335,10 +358,9 @@ mod tests {
         // Test client -> agent notification wire format
         let outgoing_msg = JsonRpcMessage::wrap(Notification {
             method: "cancel".into(),
-            params: Some(ClientNotification::CancelNotification(CancelNotification {
-                session_id: SessionId("test-123".into()),
-                meta: None,
-            })),
+            params: Some(ClientNotification::CancelNotification(
+                CancelNotification::new(SessionId("test-123".into())),
+            )),
         });
 
         let serialized: Value = serde_json::to_value(&outgoing_msg).unwrap();
@@ -366,8 +388,14 @@ mod tests {
                             meta: None,
                         }),
                         message_id: None,
+                        #[cfg(feature = "unstable_user_message_echo")]
+                        echo: false,
                         meta: None,
                     }),
+                    #[cfg(feature = "unstable_session_update_sequence")]
+                    sequence: None,
+                    #[cfg(feature = "unstable_turn_id")]
+                    turn_id: None,
                     meta: None,
                 },
             )),
@@ -392,4 +420,105 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn raw_request_frame_parses() {
+        let message = serde_json::from_value::<JsonRpcMessage<ClientOutgoingMessage>>(json!({
+            "jsonrpc": "2.0",
+            "id": 0,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": 1,
+                "clientCapabilities": {}
+            }
+        }))
+        .unwrap()
+        .into_inner();
+        let ClientOutgoingMessage::Request(request) = message else {
+            panic!("expected request, got {message:?}");
+        };
+        assert_eq!(request.id, RequestId::Number(0));
+        assert_eq!(request.method.as_ref(), "initialize");
+        assert!(matches!(
+            request.params,
+            Some(ClientRequest::InitializeRequest(_))
+        ));
+    }
+
+    #[test]
+    fn raw_response_frames_parse() {
+        let message = serde_json::from_value::<JsonRpcMessage<AgentOutgoingMessage>>(json!({
+            "jsonrpc": "2.0",
+            "id": 0,
+            "result": {
+                "protocolVersion": 1,
+                "agentCapabilities": {}
+            }
+        }))
+        .unwrap()
+        .into_inner();
+        let AgentOutgoingMessage::Response(Response::Result { id, result }) = message else {
+            panic!("expected successful response, got {message:?}");
+        };
+        assert_eq!(id, RequestId::Number(0));
+        assert!(matches!(result, AgentResponse::InitializeResponse(_)));
+
+        let message = serde_json::from_value::<JsonRpcMessage<AgentOutgoingMessage>>(json!({
+            "jsonrpc": "2.0",
+            "id": "req-1",
+            "error": {
+                "code": -32601,
+                "message": "Method not found"
+            }
+        }))
+        .unwrap()
+        .into_inner();
+        let AgentOutgoingMessage::Response(Response::Error { id, error }) = message else {
+            panic!("expected error response, got {message:?}");
+        };
+        assert_eq!(id, RequestId::Str("req-1".into()));
+        assert_eq!(error.code, ErrorCode::MethodNotFound);
+    }
+
+    #[test]
+    fn raw_batch_frame_parses() {
+        let frame = json!([
+            {
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "session/new",
+                "params": {
+                    "cwd": "/home/user/project",
+                    "mcpServers": []
+                }
+            },
+            {
+                "jsonrpc": "2.0",
+                "method": "session/cancel",
+                "params": {
+                    "sessionId": "sess-1"
+                }
+            }
+        ]);
+
+        let batch = serde_json::from_value::<JsonRpcBatch<RawMessage>>(frame.clone()).unwrap();
+        let [request, notification] = batch.as_slice() else {
+            panic!("expected two messages");
+        };
+        let Message::Request(request) = request.inner() else {
+            panic!("expected request, got {request:?}");
+        };
+        assert_eq!(request.id, RequestId::Number(1));
+        assert_eq!(request.method.as_ref(), "session/new");
+        let params: NewSessionRequest =
+            serde_json::from_value(request.params.clone().unwrap()).unwrap();
+        assert_eq!(params.cwd.to_str(), Some("/home/user/project"));
+
+        let Message::Notification(notification) = notification.inner() else {
+            panic!("expected notification, got {notification:?}");
+        };
+        assert_eq!(notification.method.as_ref(), "session/cancel");
+
+        assert_eq!(serde_json::to_value(&batch).unwrap(), frame);
+    }
 }
@@ -0,0 +1,38 @@
+//! Rough token counts for prompt content, so clients can warn before a prompt
+//! outgrows the model's context window.
+//!
+//! Media is charged a fixed amount because its real cost depends on how each
+//! model encodes it.
+
+/// Tokens charged for an image, whatever its size.
+pub const IMAGE_TOKEN_ESTIMATE: usize = 1_000;
+
+/// Tokens charged for an audio clip, whatever its length.
+pub const AUDIO_TOKEN_ESTIMATE: usize = 1_000;
+
+/// Counts tokens the way a specific model does.
+///
+/// Pass one to `ContentBlock::count_tokens` for an exact count of the text in
+/// a prompt. Images and audio are still charged [`IMAGE_TOKEN_ESTIMATE`] and
+/// [`AUDIO_TOKEN_ESTIMATE`].
+#[cfg(feature = "tokenizer")]
+pub trait Tokenizer {
+    /// Returns how many tokens `text` encodes to.
+    fn count_tokens(&self, text: &str) -> usize;
+}
+
+/// One token per four characters, rounded up.
+pub(crate) fn estimate_text_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// The fixed charge for an embedded binary resource with the given MIME type.
+///
+/// Anything that is not audio is charged as an image.
+pub(crate) fn media_tokens(mime_type: Option<&str>) -> usize {
+    if mime_type.is_some_and(|mime_type| mime_type.starts_with("audio/")) {
+        AUDIO_TOKEN_ESTIMATE
+    } else {
+        IMAGE_TOKEN_ESTIMATE
+    }
+}
@@ -21,6 +21,24 @@ use super::{
     ClientCapabilities, ContentBlock, ExtNotification, ExtRequest, ExtResponse, Meta, SessionId,
 };
 
+#[cfg(feature = "unstable_cancel_reason")]
+use super::CancelReason;
+
+#[cfg(feature = "unstable_edit_conflict")]
+use super::ConflictReport;
+
+#[cfg(any(
+    feature = "unstable_edit_conflict",
+    feature = "unstable_cancel_tool_call"
+))]
+use super::ToolCallId;
+
+#[cfg(feature = "unstable_cancel_tool_call")]
+use super::ToolCallStatus;
+
+#[cfg(feature = "unstable_fs_watch")]
+use super::{FS_FILE_CHANGED_NOTIFICATION, FileChangedNotification};
+
 #[cfg(feature = "unstable_mcp_over_acp")]
 use super::mcp::{
     MCP_MESSAGE_METHOD_NAME, MessageMcpNotification, MessageMcpRequest, MessageMcpResponse,
@@ -70,6 +88,20 @@ pub struct InitializeRequest {
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
     pub client_info: Option<Implementation>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The user's preferred locale as a BCP 47 language tag, such as `en-US` or
+    /// `pt-BR`.
+    ///
+    /// Advisory only: agents MAY use it to localize notices, errors and
+    /// suggestions, and fall back to English for locales they don't support.
+    #[cfg(feature = "unstable_locale")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub locale: Option<String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -90,6 +122,8 @@ impl InitializeRequest {
             protocol_version,
             client_capabilities: ClientCapabilities::default(),
             client_info: None,
+            #[cfg(feature = "unstable_locale")]
+            locale: None,
             meta: None,
         }
     }
@@ -108,6 +142,14 @@ impl InitializeRequest {
         self
     }
 
+    /// The user's preferred locale as a BCP 47 language tag, such as `en-US`.
+    #[cfg(feature = "unstable_locale")]
+    #[must_use]
+    pub fn locale(mut self, locale: impl IntoOption<String>) -> Self {
+        self.locale = locale.into_option();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -154,6 +196,20 @@ pub struct InitializeResponse {
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
     pub agent_info: Option<Implementation>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent can accept prompts yet.
+    ///
+    /// Omitted or `true` means the agent is ready. `false` means it is still
+    /// warming up, for example loading a model or indexing the workspace, and
+    /// will send an `agent/ready` notification once it can take prompts.
+    #[cfg(feature = "unstable_agent_ready")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub ready: Option<bool>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -175,6 +231,8 @@ impl InitializeResponse {
             agent_capabilities: AgentCapabilities::default(),
             auth_methods: vec![],
             agent_info: None,
+            #[cfg(feature = "unstable_agent_ready")]
+            ready: None,
             meta: None,
         }
     }
@@ -200,6 +258,33 @@ impl InitializeResponse {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent can accept prompts yet.
+    ///
+    /// Set `false` while warming up and send `agent/ready` once done.
+    #[cfg(feature = "unstable_agent_ready")]
+    #[must_use]
+    pub fn ready(mut self, ready: impl IntoOption<bool>) -> Self {
+        self.ready = ready.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent reported itself ready to take prompts.
+    ///
+    /// Only an explicit `ready: false` counts as not ready.
+    #[cfg(feature = "unstable_agent_ready")]
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.ready != Some(false)
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -850,9 +935,13 @@ fn is_true(v: &bool) -> bool {
     *v
 }
 
-#[cfg(feature = "unstable_auth_methods")]
+#[cfg(any(
+    feature = "unstable_auth_methods",
+    feature = "unstable_user_message_echo",
+    feature = "unstable_write_dry_run"
+))]
 #[expect(clippy::trivially_copy_pass_by_ref)]
-fn is_false(v: &bool) -> bool {
+pub(crate) fn is_false(v: &bool) -> bool {
     !*v
 }
 
@@ -1024,6 +1113,17 @@ pub struct NewSessionRequest {
     #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
     #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
     pub mcp_servers: Vec<McpServer>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The client's environment, shared up front so the agent does not have to
+    /// probe for it.
+    #[cfg(feature = "unstable_session_context")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub context: Option<SessionContext>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1044,6 +1144,8 @@ impl NewSessionRequest {
             cwd: cwd.into(),
             additional_directories: vec![],
             mcp_servers: vec![],
+            #[cfg(feature = "unstable_session_context")]
+            context: None,
             meta: None,
         }
     }
@@ -1062,6 +1164,127 @@ impl NewSessionRequest {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The client's environment, shared up front so the agent does not have to
+    /// probe for it.
+    #[cfg(feature = "unstable_session_context")]
+    #[must_use]
+    pub fn context(mut self, context: impl IntoOption<SessionContext>) -> Self {
+        self.context = context.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// The client's environment at session start.
+///
+/// The working directory is not repeated here; it is the `cwd` of the request
+/// that carries this context.
+#[cfg(feature = "unstable_session_context")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SessionContext {
+    /// The operating system the client runs on, such as `linux`, `macos` or
+    /// `windows`.
+    pub os: String,
+    /// The user's default shell, such as `/bin/zsh` or `pwsh`.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub shell: Option<String>,
+    /// Names of environment variables the agent may read and pass on to the
+    /// tools it runs.
+    ///
+    /// The client only lists variables the user is willing to share. Agents
+    /// SHOULD NOT read or forward environment variables missing from this list.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env_allowlist: Vec<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The user's preferred locale for this session as a BCP 47 language tag,
+    /// such as `en-US`. Overrides the `locale` sent in `initialize`.
+    ///
+    /// Advisory only: agents fall back to English for locales they don't
+    /// support.
+    #[cfg(feature = "unstable_locale")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_session_context")]
+impl SessionContext {
+    /// Builds [`SessionContext`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(os: impl Into<String>) -> Self {
+        Self {
+            os: os.into(),
+            shell: None,
+            env_allowlist: vec![],
+            #[cfg(feature = "unstable_locale")]
+            locale: None,
+            meta: None,
+        }
+    }
+
+    /// The user's default shell, such as `/bin/zsh` or `pwsh`.
+    #[must_use]
+    pub fn shell(mut self, shell: impl IntoOption<String>) -> Self {
+        self.shell = shell.into_option();
+        self
+    }
+
+    /// Names of environment variables the agent may read and pass on to the
+    /// tools it runs.
+    #[must_use]
+    pub fn env_allowlist(mut self, env_allowlist: Vec<String>) -> Self {
+        self.env_allowlist = env_allowlist;
+        self
+    }
+
+    /// The user's preferred locale for this session as a BCP 47 language tag.
+    #[cfg(feature = "unstable_locale")]
+    #[must_use]
+    pub fn locale(mut self, locale: impl IntoOption<String>) -> Self {
+        self.locale = locale.into_option();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1187,6 +1410,33 @@ pub struct LoadSessionRequest {
     pub additional_directories: Vec<PathBuf>,
     /// The ID of the session to load.
     pub session_id: SessionId,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Opaque cursor token from a previous response's nextCursor field to continue replaying
+    /// the session history where the previous page stopped.
+    ///
+    /// Clients MUST only send a cursor returned by the Agent for this session. If the cursor
+    /// is invalid, expired or was issued for another session, the Agent MUST respond with an
+    /// `invalid_params` error.
+    ///
+    /// Only honored when the Agent advertises the `loadPagination` session capability.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[serde(default)]
+    pub cursor: Option<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Maximum number of history updates the Agent should replay before responding.
+    ///
+    /// Agents MAY replay fewer updates. When omitted, the Agent chooses the page size.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub limit: Option<u32>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1208,6 +1458,10 @@ impl LoadSessionRequest {
             cwd: cwd.into(),
             additional_directories: vec![],
             session_id: session_id.into(),
+            #[cfg(feature = "unstable_session_load_pagination")]
+            cursor: None,
+            #[cfg(feature = "unstable_session_load_pagination")]
+            limit: None,
             meta: None,
         }
     }
@@ -1226,6 +1480,82 @@ impl LoadSessionRequest {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Opaque cursor token from a previous response's nextCursor field to continue replaying
+    /// the session history where the previous page stopped.
+    ///
+    /// Clients MUST only send a cursor returned by the Agent for this session. If the cursor
+    /// is invalid, expired or was issued for another session, the Agent MUST respond with an
+    /// `invalid_params` error.
+    ///
+    /// Only honored when the Agent advertises the `loadPagination` session capability.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[must_use]
+    pub fn cursor(mut self, cursor: impl IntoOption<String>) -> Self {
+        self.cursor = cursor.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Maximum number of history updates the Agent should replay before responding.
+    ///
+    /// Agents MAY replay fewer updates. When omitted, the Agent chooses the page size.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[must_use]
+    pub fn limit(mut self, limit: impl IntoOption<u32>) -> Self {
+        self.limit = limit.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Resolves the page of a `history_len`-update history that this request
+    /// should replay, along with the `nextCursor` to return.
+    ///
+    /// Cursors produced here encode the session ID and the offset of the next
+    /// update, so consecutive pages never overlap or skip updates. Without a
+    /// `limit` the rest of the history is replayed; a `limit` of `0` still
+    /// replays one update so the replay always makes progress.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::invalid_params`](super::Error::invalid_params) when the
+    /// cursor is malformed, was issued for another session, or points past the
+    /// end of the history.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    pub fn replay_page(
+        &self,
+        history_len: usize,
+    ) -> Result<(std::ops::Range<usize>, Option<String>), super::Error> {
+        let start = match &self.cursor {
+            None => 0,
+            Some(cursor) => cursor
+                .rsplit_once(':')
+                .filter(|(session_id, _)| *session_id == &*self.session_id.0)
+                .and_then(|(_, offset)| offset.parse::<usize>().ok())
+                .filter(|offset| *offset <= history_len)
+                .ok_or_else(|| {
+                    super::Error::invalid_params().data(serde_json::json!({ "cursor": cursor }))
+                })?,
+        };
+        let end = match self.limit {
+            Some(limit) => start
+                .saturating_add(usize::try_from(limit.max(1)).unwrap_or(usize::MAX))
+                .min(history_len),
+            None => history_len,
+        };
+        let next_cursor = (end < history_len).then(|| format!("{}:{end}", self.session_id.0));
+        Ok((start..end, next_cursor))
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1258,6 +1588,20 @@ pub struct LoadSessionResponse {
     #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
     #[serde(default)]
     pub config_options: Option<Vec<SessionConfigOption>>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Opaque cursor token. If present, more history remains; pass this in the next
+    /// request's cursor parameter to replay the next page. If absent, the replay is complete.
+    ///
+    /// Updates are replayed in their original order, and each page resumes exactly after
+    /// the last update of the previous page.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub next_cursor: Option<String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1296,6 +1640,22 @@ impl LoadSessionResponse {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Opaque cursor token. If present, more history remains; pass this in the next
+    /// request's cursor parameter to replay the next page. If absent, the replay is complete.
+    ///
+    /// Updates are replayed in their original order, and each page resumes exactly after
+    /// the last update of the previous page.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[must_use]
+    pub fn next_cursor(mut self, next_cursor: impl IntoOption<String>) -> Self {
+        self.next_cursor = next_cursor.into_option();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1727,28 +2087,37 @@ impl CloseSessionResponse {
     }
 }
 
-// List sessions
+// Compact session
 
-/// Request parameters for listing existing sessions.
+/// **UNSTABLE**
 ///
-/// Only available if the Agent supports the `sessionCapabilities.list` capability.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request parameters for compacting a session's history.
+///
+/// The agent compacts its own context for the session, typically by
+/// summarizing or dropping earlier messages, so that long sessions stay within
+/// the model's context window. Subsequent `session/prompt` requests use the
+/// compacted context.
+///
+/// Only available if the agent advertises the `sessionCapabilities.compact` capability.
+#[cfg(feature = "unstable_session_compact")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_LIST_METHOD_NAME))]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_COMPACT_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct ListSessionsRequest {
-    /// Filter sessions by working directory. Must be an absolute path.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub cwd: Option<PathBuf>,
-    /// Opaque cursor token from a previous response's nextCursor field for cursor-based pagination
+pub struct CompactSessionRequest {
+    /// The ID of the session to compact.
+    pub session_id: SessionId,
+    /// The number of tokens the compacted context should fit in.
+    ///
+    /// A hint only. When omitted, the agent picks its own target.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub cursor: Option<String>,
+    pub target_tokens: Option<u64>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1761,24 +2130,22 @@ pub struct ListSessionsRequest {
     pub meta: Option<Meta>,
 }
 
-impl ListSessionsRequest {
-    /// Builds [`ListSessionsRequest`] with the required request fields set; optional fields start unset or empty.
-    #[must_use]
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    /// Filter sessions by working directory. Must be an absolute path.
+#[cfg(feature = "unstable_session_compact")]
+impl CompactSessionRequest {
+    /// Builds [`CompactSessionRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn cwd(mut self, cwd: impl IntoOption<PathBuf>) -> Self {
-        self.cwd = cwd.into_option();
-        self
+    pub fn new(session_id: impl Into<SessionId>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            target_tokens: None,
+            meta: None,
+        }
     }
 
-    /// Opaque cursor token from a previous response's nextCursor field for cursor-based pagination
+    /// The number of tokens the compacted context should fit in.
     #[must_use]
-    pub fn cursor(mut self, cursor: impl IntoOption<String>) -> Self {
-        self.cursor = cursor.into_option();
+    pub fn target_tokens(mut self, target_tokens: impl IntoOption<u64>) -> Self {
+        self.target_tokens = target_tokens.into_option();
         self
     }
 
@@ -1794,24 +2161,26 @@ impl ListSessionsRequest {
     }
 }
 
-/// Response from listing sessions.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response from compacting a session.
+#[cfg(feature = "unstable_session_compact")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_LIST_METHOD_NAME))]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_COMPACT_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct ListSessionsResponse {
-    /// Array of session information objects
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub sessions: Vec<SessionInfo>,
-    /// Opaque cursor token. If present, pass this in the next request's cursor parameter
-    /// to fetch the next page. If absent, there are no more results.
+pub struct CompactSessionResponse {
+    /// How many messages were removed from, or folded out of, the agent's context.
+    pub removed_messages: u32,
+    /// A summary of the compacted history, for the client to display.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub next_cursor: Option<String>,
+    pub summary: Option<String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1824,21 +2193,22 @@ pub struct ListSessionsResponse {
     pub meta: Option<Meta>,
 }
 
-impl ListSessionsResponse {
-    /// Builds [`ListSessionsResponse`] with the required response fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_session_compact")]
+impl CompactSessionResponse {
+    /// Builds [`CompactSessionResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(sessions: Vec<SessionInfo>) -> Self {
+    pub fn new(removed_messages: u32) -> Self {
         Self {
-            sessions,
-            next_cursor: None,
+            removed_messages,
+            summary: None,
             meta: None,
         }
     }
 
-    /// Sets or clears the optional `nextCursor` field.
+    /// A summary of the compacted history, for the client to display.
     #[must_use]
-    pub fn next_cursor(mut self, next_cursor: impl IntoOption<String>) -> Self {
-        self.next_cursor = next_cursor.into_option();
+    pub fn summary(mut self, summary: impl IntoOption<String>) -> Self {
+        self.summary = summary.into_option();
         self
     }
 
@@ -1854,20 +2224,31 @@ impl ListSessionsResponse {
     }
 }
 
-// Delete session
+// Set session title
 
-/// Request parameters for deleting an existing session from `session/list`.
+/// **UNSTABLE**
 ///
-/// Only available if the Agent supports the `sessionCapabilities.delete` capability.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request parameters for renaming a session.
+///
+/// The agent persists the new title, so it is reflected in `session/list`
+/// and survives loading or resuming the session. The agent MAY also send a
+/// `session_info_update` with the new title to keep every client in sync.
+///
+/// Only available if the agent advertises the `sessionCapabilities.setTitle` capability.
+#[cfg(feature = "unstable_session_set_title")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_DELETE_METHOD_NAME))]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_TITLE_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct DeleteSessionRequest {
-    /// The ID of the session to delete.
+pub struct SetSessionTitleRequest {
+    /// The ID of the session to rename.
     pub session_id: SessionId,
+    /// The new human-readable title for the session.
+    pub title: String,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1880,12 +2261,14 @@ pub struct DeleteSessionRequest {
     pub meta: Option<Meta>,
 }
 
-impl DeleteSessionRequest {
-    /// Builds [`DeleteSessionRequest`] with the required request fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_session_set_title")]
+impl SetSessionTitleRequest {
+    /// Builds [`SetSessionTitleRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(session_id: impl Into<SessionId>) -> Self {
+    pub fn new(session_id: impl Into<SessionId>, title: impl Into<String>) -> Self {
         Self {
             session_id: session_id.into(),
+            title: title.into(),
             meta: None,
         }
     }
@@ -1902,14 +2285,19 @@ impl DeleteSessionRequest {
     }
 }
 
-/// Response from deleting a session.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response from renaming a session.
+#[cfg(feature = "unstable_session_set_title")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_DELETE_METHOD_NAME))]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_TITLE_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct DeleteSessionResponse {
+pub struct SetSessionTitleResponse {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1922,8 +2310,9 @@ pub struct DeleteSessionResponse {
     pub meta: Option<Meta>,
 }
 
-impl DeleteSessionResponse {
-    /// Builds [`DeleteSessionResponse`] with the required response fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_session_set_title")]
+impl SetSessionTitleResponse {
+    /// Builds [`SetSessionTitleResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -1941,37 +2330,35 @@ impl DeleteSessionResponse {
     }
 }
 
-/// Information about a session returned by session/list
+// Cancel tool call
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request parameters for cancelling a single tool call.
+///
+/// Unlike `session/cancel`, which stops the whole prompt turn, this stops only
+/// the given tool call. The agent aborts it, reports it with a `cancelled`
+/// status, and carries on with the rest of the turn.
+///
+/// If the tool call already finished, the agent leaves its status unchanged and
+/// still responds with success. If the session has no tool call with this ID,
+/// the agent responds with an `invalid_params` error.
+///
+/// Only available if the agent advertises the `sessionCapabilities.cancelToolCall` capability.
+#[cfg(feature = "unstable_cancel_tool_call")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_CANCEL_TOOL_CALL_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionInfo {
-    /// Unique identifier for the session
+pub struct CancelToolCallRequest {
+    /// The session the tool call belongs to.
     pub session_id: SessionId,
-    /// The working directory for this session. Must be an absolute path.
-    pub cwd: PathBuf,
-    /// Additional workspace roots reported for this session. Each path must be absolute.
-    ///
-    /// When present, this is the complete ordered additional-root list reported
-    /// by the Agent. Omitted and empty values are equivalent: the response
-    /// reports no additional roots.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub additional_directories: Vec<PathBuf>,
-
-    /// Human-readable title for the session
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub title: Option<String>,
-    /// ISO 8601 timestamp of last activity
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub updated_at: Option<String>,
+    /// The tool call to cancel.
+    pub tool_call_id: ToolCallId,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1984,39 +2371,34 @@ pub struct SessionInfo {
     pub meta: Option<Meta>,
 }
 
-impl SessionInfo {
-    /// Builds [`SessionInfo`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_cancel_tool_call")]
+impl CancelToolCallRequest {
+    /// Builds [`CancelToolCallRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(session_id: impl Into<SessionId>, cwd: impl Into<PathBuf>) -> Self {
+    pub fn new(session_id: impl Into<SessionId>, tool_call_id: impl Into<ToolCallId>) -> Self {
         Self {
             session_id: session_id.into(),
-            cwd: cwd.into(),
-            additional_directories: vec![],
-            title: None,
-            updated_at: None,
+            tool_call_id: tool_call_id.into(),
             meta: None,
         }
     }
 
-    /// Additional workspace roots reported for this session. Each path must be absolute.
-    #[must_use]
-    pub fn additional_directories(mut self, additional_directories: Vec<PathBuf>) -> Self {
-        self.additional_directories = additional_directories;
-        self
-    }
-
-    /// Human-readable title for the session
-    #[must_use]
-    pub fn title(mut self, title: impl IntoOption<String>) -> Self {
-        self.title = title.into_option();
-        self
-    }
-
-    /// ISO 8601 timestamp of last activity
-    #[must_use]
-    pub fn updated_at(mut self, updated_at: impl IntoOption<String>) -> Self {
-        self.updated_at = updated_at.into_option();
-        self
+    /// Decides how to answer this request given the current status of the
+    /// targeted tool call, or `None` if the session has no tool call with its ID.
+    ///
+    /// Returns `Ok(true)` when the agent must abort the tool call, and `Ok(false)`
+    /// when the tool call already finished and the request is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `invalid_params` error naming the `toolCallId` when the tool
+    /// call is unknown.
+    pub fn check(&self, status: Option<&ToolCallStatus>) -> Result<bool, super::Error> {
+        let Some(status) = status else {
+            return Err(super::Error::invalid_params()
+                .data(serde_json::json!({ "toolCallId": self.tool_call_id })));
+        };
+        Ok(!status.is_terminal())
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -2031,21 +2413,19 @@ impl SessionInfo {
     }
 }
 
-// Session modes
-
-/// The set of modes and the one currently active.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response from cancelling a tool call.
+#[cfg(feature = "unstable_cancel_tool_call")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_CANCEL_TOOL_CALL_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionModeState {
-    /// The current mode the Agent is in.
-    pub current_mode_id: SessionModeId,
-    /// The set of modes that the Agent can operate in
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub available_modes: Vec<SessionMode>,
+pub struct CancelToolCallResponse {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2058,18 +2438,12 @@ pub struct SessionModeState {
     pub meta: Option<Meta>,
 }
 
-impl SessionModeState {
-    /// Builds [`SessionModeState`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_cancel_tool_call")]
+impl CancelToolCallResponse {
+    /// Builds [`CancelToolCallResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(
-        current_mode_id: impl Into<SessionModeId>,
-        available_modes: Vec<SessionMode>,
-    ) -> Self {
-        Self {
-            current_mode_id: current_mode_id.into(),
-            available_modes,
-            meta: None,
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -2084,24 +2458,38 @@ impl SessionModeState {
     }
 }
 
-/// A mode the agent can operate in.
+// Invoke command
+
+/// **UNSTABLE**
 ///
-/// See protocol docs: [Session Modes](https://agentclientprotocol.com/protocol/session-modes)
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request parameters for invoking one of the agent's available commands.
+///
+/// Lets a client run a command advertised through an `available_commands_update`
+/// session notification directly, instead of sending it as prompt text.
+///
+/// Agents respond with a `command_not_found` error when the command name is not
+/// one they currently advertise for the session.
+#[cfg(feature = "unstable_command_invocation")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = COMMAND_INVOKE_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionMode {
-    /// Stable identifier used to refer to this protocol object in later messages.
-    pub id: SessionModeId,
-    /// Human-readable name shown for this protocol object.
-    pub name: String,
-    /// Optional human-readable details shown with this protocol object.
+pub struct InvokeCommandRequest {
+    /// The ID of the session to run the command in.
+    pub session_id: SessionId,
+    /// The name of the command to invoke, as advertised in `AvailableCommand.name`.
+    pub command: String,
+    /// Arguments for the command, if any.
+    ///
+    /// The shape of this value is defined by the command itself.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub description: Option<String>,
+    pub arguments: Option<serde_json::Value>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2114,22 +2502,23 @@ pub struct SessionMode {
     pub meta: Option<Meta>,
 }
 
-impl SessionMode {
-    /// Builds [`SessionMode`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_command_invocation")]
+impl InvokeCommandRequest {
+    /// Builds [`InvokeCommandRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(id: impl Into<SessionModeId>, name: impl Into<String>) -> Self {
+    pub fn new(session_id: impl Into<SessionId>, command: impl Into<String>) -> Self {
         Self {
-            id: id.into(),
-            name: name.into(),
-            description: None,
+            session_id: session_id.into(),
+            command: command.into(),
+            arguments: None,
             meta: None,
         }
     }
 
-    /// Sets or clears the optional `description` field.
+    /// Arguments for the command, if any.
     #[must_use]
-    pub fn description(mut self, description: impl IntoOption<String>) -> Self {
-        self.description = description.into_option();
+    pub fn arguments(mut self, arguments: impl IntoOption<serde_json::Value>) -> Self {
+        self.arguments = arguments.into_option();
         self
     }
 
@@ -2145,33 +2534,77 @@ impl SessionMode {
     }
 }
 
-/// Unique identifier for a Session Mode.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, From, Display)]
-#[serde(transparent)]
-#[from(Arc<str>, String, &'static str)]
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response from invoking a command.
+///
+/// Any output the command produces is streamed through `session/update`
+/// notifications before this response is sent.
+#[cfg(feature = "unstable_command_invocation")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = COMMAND_INVOKE_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionModeId(pub Arc<str>);
+pub struct InvokeCommandResponse {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
 
-impl SessionModeId {
-    /// Wraps a protocol string as a typed [`SessionModeId`].
+#[cfg(feature = "unstable_command_invocation")]
+impl InvokeCommandResponse {
+    /// Builds [`InvokeCommandResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(id: impl Into<Arc<str>>) -> Self {
-        Self(id.into())
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
     }
 }
 
-/// Request parameters for setting a session mode.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request parameters for answering an `input_request` session update.
+///
+/// The agent resumes the prompt turn that asked the question once it receives
+/// the answer. Agents respond with an `invalid_params` error when `input_id`
+/// does not match a question that is still waiting for an answer.
+#[cfg(feature = "unstable_input_request")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_MODE_METHOD_NAME))]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_PROVIDE_INPUT_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SetSessionModeRequest {
-    /// The ID of the session to set the mode for.
+pub struct ProvideInputRequest {
+    /// The ID of the session the question was asked in.
     pub session_id: SessionId,
-    /// The ID of the mode to set.
-    pub mode_id: SessionModeId,
+    /// The `inputId` of the `input_request` being answered.
+    pub input_id: String,
+    /// The user's answer.
+    pub text: String,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2184,18 +2617,28 @@ pub struct SetSessionModeRequest {
     pub meta: Option<Meta>,
 }
 
-impl SetSessionModeRequest {
-    /// Builds [`SetSessionModeRequest`] with the required request fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_input_request")]
+impl ProvideInputRequest {
+    /// Builds [`ProvideInputRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(session_id: impl Into<SessionId>, mode_id: impl Into<SessionModeId>) -> Self {
+    pub fn new(
+        session_id: impl Into<SessionId>,
+        input_id: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
         Self {
             session_id: session_id.into(),
-            mode_id: mode_id.into(),
+            input_id: input_id.into(),
+            text: text.into(),
             meta: None,
         }
     }
 
-    /// Sets or clears ACP `_meta` extension metadata.
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
     pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
         self.meta = meta.into_option();
@@ -2203,14 +2646,19 @@ impl SetSessionModeRequest {
     }
 }
 
-/// Response to `session/set_mode` method.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response to a `session/provide_input` request.
+#[cfg(feature = "unstable_input_request")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_MODE_METHOD_NAME))]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_PROVIDE_INPUT_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SetSessionModeResponse {
+pub struct ProvideInputResponse {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2223,8 +2671,9 @@ pub struct SetSessionModeResponse {
     pub meta: Option<Meta>,
 }
 
-impl SetSessionModeResponse {
-    /// Builds [`SetSessionModeResponse`] with the required response fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_input_request")]
+impl ProvideInputResponse {
+    /// Builds [`ProvideInputResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -2242,69 +2691,91 @@ impl SetSessionModeResponse {
     }
 }
 
-// Session config options
+// List sessions
 
-/// Unique identifier for a session configuration option.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, From, Display)]
-#[serde(transparent)]
-#[from(Arc<str>, String, &'static str)]
+/// Request parameters for listing existing sessions.
+///
+/// Only available if the Agent supports the `sessionCapabilities.list` capability.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_LIST_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionConfigId(pub Arc<str>);
+pub struct ListSessionsRequest {
+    /// Filter sessions by working directory. Must be an absolute path.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub cwd: Option<PathBuf>,
+    /// Opaque cursor token from a previous response's nextCursor field for cursor-based pagination
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub cursor: Option<String>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
 
-impl SessionConfigId {
-    /// Wraps a protocol string as a typed [`SessionConfigId`].
+impl ListSessionsRequest {
+    /// Builds [`ListSessionsRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(id: impl Into<Arc<str>>) -> Self {
-        Self(id.into())
+    pub fn new() -> Self {
+        Self::default()
     }
-}
-
-/// Unique identifier for a session configuration option value.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, From, Display)]
-#[serde(transparent)]
-#[from(Arc<str>, String, &'static str)]
-#[non_exhaustive]
-pub struct SessionConfigValueId(pub Arc<str>);
 
-impl SessionConfigValueId {
-    /// Wraps a protocol string as a typed [`SessionConfigValueId`].
+    /// Filter sessions by working directory. Must be an absolute path.
     #[must_use]
-    pub fn new(id: impl Into<Arc<str>>) -> Self {
-        Self(id.into())
+    pub fn cwd(mut self, cwd: impl IntoOption<PathBuf>) -> Self {
+        self.cwd = cwd.into_option();
+        self
     }
-}
 
-/// Unique identifier for a session configuration option value group.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, From, Display)]
-#[serde(transparent)]
-#[from(Arc<str>, String, &'static str)]
-#[non_exhaustive]
-pub struct SessionConfigGroupId(pub Arc<str>);
+    /// Opaque cursor token from a previous response's nextCursor field for cursor-based pagination
+    #[must_use]
+    pub fn cursor(mut self, cursor: impl IntoOption<String>) -> Self {
+        self.cursor = cursor.into_option();
+        self
+    }
 
-impl SessionConfigGroupId {
-    /// Wraps a protocol string as a typed [`SessionConfigGroupId`].
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn new(id: impl Into<Arc<str>>) -> Self {
-        Self(id.into())
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
     }
 }
 
-/// A possible value for a session configuration option.
+/// Response from listing sessions.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_LIST_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionConfigSelectOption {
-    /// Unique identifier for this option value.
-    pub value: SessionConfigValueId,
-    /// Human-readable label for this option value.
-    pub name: String,
-    /// Optional description for this option value.
+pub struct ListSessionsResponse {
+    /// Array of session information objects
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub sessions: Vec<SessionInfo>,
+    /// Opaque cursor token. If present, pass this in the next request's cursor parameter
+    /// to fetch the next page. If absent, there are no more results.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub description: Option<String>,
+    pub next_cursor: Option<String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2317,22 +2788,21 @@ pub struct SessionConfigSelectOption {
     pub meta: Option<Meta>,
 }
 
-impl SessionConfigSelectOption {
-    /// Builds [`SessionConfigSelectOption`] with the required fields set; optional fields start unset or empty.
+impl ListSessionsResponse {
+    /// Builds [`ListSessionsResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(value: impl Into<SessionConfigValueId>, name: impl Into<String>) -> Self {
+    pub fn new(sessions: Vec<SessionInfo>) -> Self {
         Self {
-            value: value.into(),
-            name: name.into(),
-            description: None,
+            sessions,
+            next_cursor: None,
             meta: None,
         }
     }
 
-    /// Sets or clears the optional `description` field.
+    /// Sets or clears the optional `nextCursor` field.
     #[must_use]
-    pub fn description(mut self, description: impl IntoOption<String>) -> Self {
-        self.description = description.into_option();
+    pub fn next_cursor(mut self, next_cursor: impl IntoOption<String>) -> Self {
+        self.next_cursor = next_cursor.into_option();
         self
     }
 
@@ -2348,21 +2818,20 @@ impl SessionConfigSelectOption {
     }
 }
 
-/// A group of possible values for a session configuration option.
+// Delete session
+
+/// Request parameters for deleting an existing session from `session/list`.
+///
+/// Only available if the Agent supports the `sessionCapabilities.delete` capability.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_DELETE_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionConfigSelectGroup {
-    /// Unique identifier for this group.
-    pub group: SessionConfigGroupId,
-    /// Human-readable label for this group.
-    pub name: String,
-    /// The set of option values in this group.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub options: Vec<SessionConfigSelectOption>,
+pub struct DeleteSessionRequest {
+    /// The ID of the session to delete.
+    pub session_id: SessionId,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2375,18 +2844,12 @@ pub struct SessionConfigSelectGroup {
     pub meta: Option<Meta>,
 }
 
-impl SessionConfigSelectGroup {
-    /// Builds [`SessionConfigSelectGroup`] with the required fields set; optional fields start unset or empty.
+impl DeleteSessionRequest {
+    /// Builds [`DeleteSessionRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(
-        group: impl Into<SessionConfigGroupId>,
-        name: impl Into<String>,
-        options: Vec<SessionConfigSelectOption>,
-    ) -> Self {
+    pub fn new(session_id: impl Into<SessionId>) -> Self {
         Self {
-            group: group.into(),
-            name: name.into(),
-            options,
+            session_id: session_id.into(),
             meta: None,
         }
     }
@@ -2403,146 +2866,206 @@ impl SessionConfigSelectGroup {
     }
 }
 
-/// Possible values for a session configuration option.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(untagged)]
+/// Response from deleting a session.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_DELETE_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub enum SessionConfigSelectOptions {
-    /// A flat list of options with no grouping.
-    Ungrouped(Vec<SessionConfigSelectOption>),
-    /// A list of options grouped under headers.
-    Grouped(Vec<SessionConfigSelectGroup>),
+pub struct DeleteSessionResponse {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-impl From<Vec<SessionConfigSelectOption>> for SessionConfigSelectOptions {
-    fn from(options: Vec<SessionConfigSelectOption>) -> Self {
-        SessionConfigSelectOptions::Ungrouped(options)
+impl DeleteSessionResponse {
+    /// Builds [`DeleteSessionResponse`] with the required response fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
     }
-}
 
-impl From<Vec<SessionConfigSelectGroup>> for SessionConfigSelectOptions {
-    fn from(groups: Vec<SessionConfigSelectGroup>) -> Self {
-        SessionConfigSelectOptions::Grouped(groups)
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
     }
 }
 
-/// A single-value selector (dropdown) session configuration option payload.
+/// Information about a session returned by session/list
+#[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionConfigSelect {
-    /// The currently selected value.
-    pub current_value: SessionConfigValueId,
-    /// The set of selectable options.
-    pub options: SessionConfigSelectOptions,
+pub struct SessionInfo {
+    /// Unique identifier for the session
+    pub session_id: SessionId,
+    /// The working directory for this session. Must be an absolute path.
+    pub cwd: PathBuf,
+    /// Additional workspace roots reported for this session. Each path must be absolute.
+    ///
+    /// When present, this is the complete ordered additional-root list reported
+    /// by the Agent. Omitted and empty values are equivalent: the response
+    /// reports no additional roots.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_directories: Vec<PathBuf>,
+
+    /// Human-readable title for the session
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub title: Option<String>,
+    /// ISO 8601 timestamp of last activity
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-impl SessionConfigSelect {
-    /// Builds [`SessionConfigSelect`] with the required fields set; optional fields start unset or empty.
+impl SessionInfo {
+    /// Builds [`SessionInfo`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(
-        current_value: impl Into<SessionConfigValueId>,
-        options: impl Into<SessionConfigSelectOptions>,
-    ) -> Self {
+    pub fn new(session_id: impl Into<SessionId>, cwd: impl Into<PathBuf>) -> Self {
         Self {
-            current_value: current_value.into(),
-            options: options.into(),
+            session_id: session_id.into(),
+            cwd: cwd.into(),
+            additional_directories: vec![],
+            title: None,
+            updated_at: None,
+            meta: None,
         }
     }
-}
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// A boolean on/off toggle session configuration option payload.
-#[cfg(feature = "unstable_boolean_config")]
-#[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
-#[non_exhaustive]
-pub struct SessionConfigBoolean {
-    /// The current value of the boolean option.
-    pub current_value: bool,
-}
+    /// Additional workspace roots reported for this session. Each path must be absolute.
+    #[must_use]
+    pub fn additional_directories(mut self, additional_directories: Vec<PathBuf>) -> Self {
+        self.additional_directories = additional_directories;
+        self
+    }
 
-#[cfg(feature = "unstable_boolean_config")]
-impl SessionConfigBoolean {
-    /// Builds [`SessionConfigBoolean`] with the required fields set; optional fields start unset or empty.
+    /// Human-readable title for the session
     #[must_use]
-    pub fn new(current_value: bool) -> Self {
-        Self { current_value }
+    pub fn title(mut self, title: impl IntoOption<String>) -> Self {
+        self.title = title.into_option();
+        self
     }
-}
 
-/// Semantic category for a session configuration option.
-///
-/// This is intended to help Clients distinguish broadly common selectors (e.g. model selector vs
-/// session mode selector vs thought/reasoning level) for UX purposes (keyboard shortcuts, icons,
-/// placement). It MUST NOT be required for correctness. Clients MUST handle missing or unknown
-/// categories gracefully.
-///
-/// Category names beginning with `_` are free for custom use, like other ACP extension methods.
-/// Category names that do not begin with `_` are reserved for the ACP spec.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "snake_case")]
-#[non_exhaustive]
-pub enum SessionConfigOptionCategory {
-    /// Session mode selector.
-    Mode,
-    /// Model selector.
-    Model,
-    /// Model-related configuration parameter.
-    ModelConfig,
-    /// Thought/reasoning level selector.
-    ThoughtLevel,
-    /// Unknown / uncategorized selector.
-    #[serde(untagged)]
-    Other(String),
+    /// ISO 8601 timestamp of last activity
+    #[must_use]
+    pub fn updated_at(mut self, updated_at: impl IntoOption<String>) -> Self {
+        self.updated_at = updated_at.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
 }
 
-/// Type-specific session configuration option payload.
+// Session modes
+
+/// The set of modes and the one currently active.
+#[serde_as]
+#[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(tag = "type", rename_all = "snake_case")]
-#[schemars(extend("discriminator" = {"propertyName": "type"}))]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub enum SessionConfigKind {
-    /// Single-value selector (dropdown).
-    Select(SessionConfigSelect),
-    /// **UNSTABLE**
+pub struct SessionModeState {
+    /// The current mode the Agent is in.
+    pub current_mode_id: SessionModeId,
+    /// The set of modes that the Agent can operate in
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub available_modes: Vec<SessionMode>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SessionModeState {
+    /// Builds [`SessionModeState`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(
+        current_mode_id: impl Into<SessionModeId>,
+        available_modes: Vec<SessionMode>,
+    ) -> Self {
+        Self {
+            current_mode_id: current_mode_id.into(),
+            available_modes,
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// Boolean on/off toggle.
-    #[cfg(feature = "unstable_boolean_config")]
-    Boolean(SessionConfigBoolean),
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
 }
 
-/// A session configuration option selector and its current state.
+/// A mode the agent can operate in.
+///
+/// See protocol docs: [Session Modes](https://agentclientprotocol.com/protocol/session-modes)
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionConfigOption {
-    /// Unique identifier for the configuration option.
-    pub id: SessionConfigId,
-    /// Human-readable label for the option.
+pub struct SessionMode {
+    /// Stable identifier used to refer to this protocol object in later messages.
+    pub id: SessionModeId,
+    /// Human-readable name shown for this protocol object.
     pub name: String,
-    /// Optional description for the Client to display to the user.
+    /// Optional human-readable details shown with this protocol object.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
     pub description: Option<String>,
-    /// Optional semantic category for this option (UX only).
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub category: Option<SessionConfigOptionCategory>,
-    /// Type-specific fields for this configuration option.
-    #[serde(flatten)]
-    pub kind: SessionConfigKind,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2555,56 +3078,18 @@ pub struct SessionConfigOption {
     pub meta: Option<Meta>,
 }
 
-impl SessionConfigOption {
-    /// Builds [`SessionConfigOption`] with the required fields set; optional fields start unset or empty.
+impl SessionMode {
+    /// Builds [`SessionMode`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(
-        id: impl Into<SessionConfigId>,
-        name: impl Into<String>,
-        kind: SessionConfigKind,
-    ) -> Self {
+    pub fn new(id: impl Into<SessionModeId>, name: impl Into<String>) -> Self {
         Self {
             id: id.into(),
             name: name.into(),
             description: None,
-            category: None,
-            kind,
             meta: None,
         }
     }
 
-    /// Builds a select-style session configuration option with its current value and choices.
-    #[must_use]
-    pub fn select(
-        id: impl Into<SessionConfigId>,
-        name: impl Into<String>,
-        current_value: impl Into<SessionConfigValueId>,
-        options: impl Into<SessionConfigSelectOptions>,
-    ) -> Self {
-        Self::new(
-            id,
-            name,
-            SessionConfigKind::Select(SessionConfigSelect::new(current_value, options)),
-        )
-    }
-
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
-    #[cfg(feature = "unstable_boolean_config")]
-    #[must_use]
-    pub fn boolean(
-        id: impl Into<SessionConfigId>,
-        name: impl Into<String>,
-        current_value: bool,
-    ) -> Self {
-        Self::new(
-            id,
-            name,
-            SessionConfigKind::Boolean(SessionConfigBoolean::new(current_value)),
-        )
-    }
-
     /// Sets or clears the optional `description` field.
     #[must_use]
     pub fn description(mut self, description: impl IntoOption<String>) -> Self {
@@ -2612,13 +3097,6 @@ impl SessionConfigOption {
         self
     }
 
-    /// Sets or clears the optional `category` field.
-    #[must_use]
-    pub fn category(mut self, category: impl IntoOption<SessionConfigOptionCategory>) -> Self {
-        self.category = category.into_option();
-        self
-    }
-
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2631,121 +3109,72 @@ impl SessionConfigOption {
     }
 }
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// The value to set for a session configuration option.
-///
-/// The `type` field acts as the discriminator in the serialized JSON form.
-/// When no `type` is present, the value is treated as a [`SessionConfigValueId`]
-/// via the [`ValueId`](Self::ValueId) fallback variant.
-///
-/// The `type` discriminator describes the *shape* of the value, not the option
-/// kind. For example every option kind that picks from a list of ids
-/// (`select`, `radio`, …) would use [`ValueId`](Self::ValueId), while a
-/// future freeform text option would get its own variant.
-#[cfg(feature = "unstable_boolean_config")]
+/// Unique identifier for a Session Mode.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, From, Display)]
+#[serde(transparent)]
+#[from(Arc<str>, String, &'static str)]
+#[non_exhaustive]
+pub struct SessionModeId(pub Arc<str>);
+
+impl SessionModeId {
+    /// Wraps a protocol string as a typed [`SessionModeId`].
+    #[must_use]
+    pub fn new(id: impl Into<Arc<str>>) -> Self {
+        Self(id.into())
+    }
+}
+
+/// Request parameters for setting a session mode.
+#[serde_as]
+#[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_MODE_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub enum SessionConfigOptionValue {
-    /// A boolean value (`type: "boolean"`).
-    Boolean {
-        /// The boolean value.
-        value: bool,
-    },
-    /// A [`SessionConfigValueId`] string value.
+pub struct SetSessionModeRequest {
+    /// The ID of the session to set the mode for.
+    pub session_id: SessionId,
+    /// The ID of the mode to set.
+    pub mode_id: SessionModeId,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// This is the default when `type` is absent on the wire. Unknown `type`
-    /// values with string payloads also gracefully deserialize into this
-    /// variant.
-    #[serde(untagged)]
-    ValueId {
-        /// The value ID.
-        value: SessionConfigValueId,
-    },
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_boolean_config")]
-impl SessionConfigOptionValue {
-    /// Create a value-id option value (used by `select` and other id-based option types).
+impl SetSessionModeRequest {
+    /// Builds [`SetSessionModeRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn value_id(id: impl Into<SessionConfigValueId>) -> Self {
-        Self::ValueId { value: id.into() }
+    pub fn new(session_id: impl Into<SessionId>, mode_id: impl Into<SessionModeId>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            mode_id: mode_id.into(),
+            meta: None,
+        }
     }
 
-    /// Create a boolean option value.
+    /// Sets or clears ACP `_meta` extension metadata.
     #[must_use]
-    pub fn boolean(val: bool) -> Self {
-        Self::Boolean { value: val }
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
     }
+}
 
-    /// Return the inner [`SessionConfigValueId`] if this is a
-    /// [`ValueId`](Self::ValueId) value.
-    #[must_use]
-    pub fn as_value_id(&self) -> Option<&SessionConfigValueId> {
-        match self {
-            Self::ValueId { value } => Some(value),
-            _ => None,
-        }
-    }
-
-    /// Return the inner [`bool`] if this is a [`Boolean`](Self::Boolean) value.
-    #[must_use]
-    pub fn as_bool(&self) -> Option<bool> {
-        match self {
-            Self::Boolean { value } => Some(*value),
-            _ => None,
-        }
-    }
-}
-
-#[cfg(feature = "unstable_boolean_config")]
-impl From<SessionConfigValueId> for SessionConfigOptionValue {
-    fn from(value: SessionConfigValueId) -> Self {
-        Self::ValueId { value }
-    }
-}
-
-#[cfg(feature = "unstable_boolean_config")]
-impl From<bool> for SessionConfigOptionValue {
-    fn from(value: bool) -> Self {
-        Self::Boolean { value }
-    }
-}
-
-#[cfg(feature = "unstable_boolean_config")]
-impl From<&str> for SessionConfigOptionValue {
-    fn from(value: &str) -> Self {
-        Self::ValueId {
-            value: SessionConfigValueId::new(value),
-        }
-    }
-}
-
-/// Request parameters for setting a session configuration option.
+/// Response to `session/set_mode` method.
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_CONFIG_OPTION_METHOD_NAME))]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_MODE_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SetSessionConfigOptionRequest {
-    /// The ID of the session to set the configuration option for.
-    pub session_id: SessionId,
-    /// The ID of the configuration option to set.
-    pub config_id: SessionConfigId,
-    /// The value to set, including a `type` discriminator and the raw `value`.
-    ///
-    /// When `type` is absent on the wire, defaults to treating the value as a
-    /// [`SessionConfigValueId`] for `select` options.
-    #[cfg(feature = "unstable_boolean_config")]
-    #[serde(flatten)]
-    pub value: SessionConfigOptionValue,
-    /// The ID of the configuration option value to set.
-    #[cfg(not(feature = "unstable_boolean_config"))]
-    pub value: SessionConfigValueId,
+pub struct SetSessionModeResponse {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2758,38 +3187,11 @@ pub struct SetSessionConfigOptionRequest {
     pub meta: Option<Meta>,
 }
 
-impl SetSessionConfigOptionRequest {
-    /// Builds [`SetSessionConfigOptionRequest`] with the required request fields set; optional fields start unset or empty.
-    #[cfg(feature = "unstable_boolean_config")]
-    #[must_use]
-    pub fn new(
-        session_id: impl Into<SessionId>,
-        config_id: impl Into<SessionConfigId>,
-        value: impl Into<SessionConfigOptionValue>,
-    ) -> Self {
-        Self {
-            session_id: session_id.into(),
-            config_id: config_id.into(),
-            value: value.into(),
-            meta: None,
-        }
-    }
-
-    /// Builds a select-value `session/set_config_option` request for crates built
-    /// without boolean session configuration support.
-    #[cfg(not(feature = "unstable_boolean_config"))]
+impl SetSessionModeResponse {
+    /// Builds [`SetSessionModeResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(
-        session_id: impl Into<SessionId>,
-        config_id: impl Into<SessionConfigId>,
-        value: impl Into<SessionConfigValueId>,
-    ) -> Self {
-        Self {
-            session_id: session_id.into(),
-            config_id: config_id.into(),
-            value: value.into(),
-            meta: None,
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -2804,18 +3206,69 @@ impl SetSessionConfigOptionRequest {
     }
 }
 
-/// Response to `session/set_config_option` method.
+// Session config options
+
+/// Unique identifier for a session configuration option.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, From, Display)]
+#[serde(transparent)]
+#[from(Arc<str>, String, &'static str)]
+#[non_exhaustive]
+pub struct SessionConfigId(pub Arc<str>);
+
+impl SessionConfigId {
+    /// Wraps a protocol string as a typed [`SessionConfigId`].
+    #[must_use]
+    pub fn new(id: impl Into<Arc<str>>) -> Self {
+        Self(id.into())
+    }
+}
+
+/// Unique identifier for a session configuration option value.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, From, Display)]
+#[serde(transparent)]
+#[from(Arc<str>, String, &'static str)]
+#[non_exhaustive]
+pub struct SessionConfigValueId(pub Arc<str>);
+
+impl SessionConfigValueId {
+    /// Wraps a protocol string as a typed [`SessionConfigValueId`].
+    #[must_use]
+    pub fn new(id: impl Into<Arc<str>>) -> Self {
+        Self(id.into())
+    }
+}
+
+/// Unique identifier for a session configuration option value group.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, From, Display)]
+#[serde(transparent)]
+#[from(Arc<str>, String, &'static str)]
+#[non_exhaustive]
+pub struct SessionConfigGroupId(pub Arc<str>);
+
+impl SessionConfigGroupId {
+    /// Wraps a protocol string as a typed [`SessionConfigGroupId`].
+    #[must_use]
+    pub fn new(id: impl Into<Arc<str>>) -> Self {
+        Self(id.into())
+    }
+}
+
+/// A possible value for a session configuration option.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_CONFIG_OPTION_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SetSessionConfigOptionResponse {
-    /// The full set of configuration options and their current values.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub config_options: Vec<SessionConfigOption>,
+pub struct SessionConfigSelectOption {
+    /// Unique identifier for this option value.
+    pub value: SessionConfigValueId,
+    /// Human-readable label for this option value.
+    pub name: String,
+    /// Optional description for this option value.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub description: Option<String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2828,16 +3281,25 @@ pub struct SetSessionConfigOptionResponse {
     pub meta: Option<Meta>,
 }
 
-impl SetSessionConfigOptionResponse {
-    /// Builds [`SetSessionConfigOptionResponse`] with the required response fields set; optional fields start unset or empty.
+impl SessionConfigSelectOption {
+    /// Builds [`SessionConfigSelectOption`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(config_options: Vec<SessionConfigOption>) -> Self {
+    pub fn new(value: impl Into<SessionConfigValueId>, name: impl Into<String>) -> Self {
         Self {
-            config_options,
+            value: value.into(),
+            name: name.into(),
+            description: None,
             meta: None,
         }
     }
 
+    /// Sets or clears the optional `description` field.
+    #[must_use]
+    pub fn description(mut self, description: impl IntoOption<String>) -> Self {
+        self.description = description.into_option();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2850,58 +3312,21 @@ impl SetSessionConfigOptionResponse {
     }
 }
 
-// MCP
-
-/// Configuration for connecting to an MCP (Model Context Protocol) server.
-///
-/// MCP servers provide tools and context that the agent can use when
-/// processing prompts.
-///
-/// See protocol docs: [MCP Servers](https://agentclientprotocol.com/protocol/session-setup#mcp-servers)
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(tag = "type", rename_all = "snake_case")]
-#[non_exhaustive]
-pub enum McpServer {
-    /// HTTP transport configuration
-    ///
-    /// Only available when the Agent capabilities indicate `mcp_capabilities.http` is `true`.
-    Http(McpServerHttp),
-    /// SSE transport configuration
-    ///
-    /// Only available when the Agent capabilities indicate `mcp_capabilities.sse` is `true`.
-    Sse(McpServerSse),
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
-    ///
-    /// ACP transport configuration
-    ///
-    /// Only available when the Agent capabilities indicate `mcp_capabilities.acp` is `true`.
-    /// The MCP server is provided by an ACP component and communicates over the ACP channel.
-    #[cfg(feature = "unstable_mcp_over_acp")]
-    Acp(McpServerAcp),
-    /// Stdio transport configuration
-    ///
-    /// All Agents MUST support this transport.
-    #[serde(untagged)]
-    Stdio(McpServerStdio),
-}
-
-/// HTTP transport configuration for MCP.
+/// A group of possible values for a session configuration option.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct McpServerHttp {
-    /// Human-readable name identifying this MCP server.
+pub struct SessionConfigSelectGroup {
+    /// Unique identifier for this group.
+    pub group: SessionConfigGroupId,
+    /// Human-readable label for this group.
     pub name: String,
-    /// URL to the MCP server.
-    pub url: String,
-    /// HTTP headers to set when making requests to the MCP server.
+    /// The set of option values in this group.
     #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
     #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub headers: Vec<HttpHeader>,
+    pub options: Vec<SessionConfigSelectOption>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2914,25 +3339,22 @@ pub struct McpServerHttp {
     pub meta: Option<Meta>,
 }
 
-impl McpServerHttp {
-    /// Builds [`McpServerHttp`] with the required fields set; optional fields start unset or empty.
+impl SessionConfigSelectGroup {
+    /// Builds [`SessionConfigSelectGroup`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(name: impl Into<String>, url: impl Into<String>) -> Self {
+    pub fn new(
+        group: impl Into<SessionConfigGroupId>,
+        name: impl Into<String>,
+        options: Vec<SessionConfigSelectOption>,
+    ) -> Self {
         Self {
+            group: group.into(),
             name: name.into(),
-            url: url.into(),
-            headers: Vec::new(),
+            options,
             meta: None,
         }
     }
 
-    /// HTTP headers to set when making requests to the MCP server.
-    #[must_use]
-    pub fn headers(mut self, headers: Vec<HttpHeader>) -> Self {
-        self.headers = headers;
-        self
-    }
-
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2945,61 +3367,52 @@ impl McpServerHttp {
     }
 }
 
-/// SSE transport configuration for MCP.
-#[serde_as]
-#[skip_serializing_none]
+/// Possible values for a session configuration option.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
+#[serde(untagged)]
 #[non_exhaustive]
-pub struct McpServerSse {
-    /// Human-readable name identifying this MCP server.
-    pub name: String,
-    /// URL to the MCP server.
-    pub url: String,
-    /// HTTP headers to set when making requests to the MCP server.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub headers: Vec<HttpHeader>,
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
-    ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
+pub enum SessionConfigSelectOptions {
+    /// A flat list of options with no grouping.
+    Ungrouped(Vec<SessionConfigSelectOption>),
+    /// A list of options grouped under headers.
+    Grouped(Vec<SessionConfigSelectGroup>),
 }
 
-impl McpServerSse {
-    /// Builds [`McpServerSse`] with the required fields set; optional fields start unset or empty.
-    #[must_use]
-    pub fn new(name: impl Into<String>, url: impl Into<String>) -> Self {
-        Self {
-            name: name.into(),
-            url: url.into(),
-            headers: Vec::new(),
-            meta: None,
-        }
+impl From<Vec<SessionConfigSelectOption>> for SessionConfigSelectOptions {
+    fn from(options: Vec<SessionConfigSelectOption>) -> Self {
+        SessionConfigSelectOptions::Ungrouped(options)
     }
+}
 
-    /// HTTP headers to set when making requests to the MCP server.
-    #[must_use]
-    pub fn headers(mut self, headers: Vec<HttpHeader>) -> Self {
-        self.headers = headers;
-        self
+impl From<Vec<SessionConfigSelectGroup>> for SessionConfigSelectOptions {
+    fn from(groups: Vec<SessionConfigSelectGroup>) -> Self {
+        SessionConfigSelectOptions::Grouped(groups)
     }
+}
 
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
-    ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+/// A single-value selector (dropdown) session configuration option payload.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SessionConfigSelect {
+    /// The currently selected value.
+    pub current_value: SessionConfigValueId,
+    /// The set of selectable options.
+    pub options: SessionConfigSelectOptions,
+}
+
+impl SessionConfigSelect {
+    /// Builds [`SessionConfigSelect`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
-        self
+    pub fn new(
+        current_value: impl Into<SessionConfigValueId>,
+        options: impl Into<SessionConfigSelectOptions>,
+    ) -> Self {
+        Self {
+            current_value: current_value.into(),
+            options: options.into(),
+        }
     }
 }
 
@@ -3007,104 +3420,97 @@ impl McpServerSse {
 ///
 /// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// Unique identifier for an MCP server using the ACP transport.
-///
-/// The value is opaque and generated by the ACP component providing the MCP server. It is
-/// used by `mcp/connect` to route connection requests back to the component that declared the
-/// server.
-#[cfg(feature = "unstable_mcp_over_acp")]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
-#[serde(transparent)]
-#[from(Arc<str>, String, &'static str)]
+/// A boolean on/off toggle session configuration option payload.
+#[cfg(feature = "unstable_boolean_config")]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct McpServerAcpId(pub Arc<str>);
+pub struct SessionConfigBoolean {
+    /// The current value of the boolean option.
+    pub current_value: bool,
+}
 
-#[cfg(feature = "unstable_mcp_over_acp")]
-impl McpServerAcpId {
-    /// Wraps a protocol string as a typed [`McpServerAcpId`].
+#[cfg(feature = "unstable_boolean_config")]
+impl SessionConfigBoolean {
+    /// Builds [`SessionConfigBoolean`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(id: impl Into<Arc<str>>) -> Self {
-        Self(id.into())
+    pub fn new(current_value: bool) -> Self {
+        Self { current_value }
     }
 }
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
+/// Semantic category for a session configuration option.
 ///
-/// ACP transport configuration for MCP.
+/// This is intended to help Clients distinguish broadly common selectors (e.g. model selector vs
+/// session mode selector vs thought/reasoning level) for UX purposes (keyboard shortcuts, icons,
+/// placement). It MUST NOT be required for correctness. Clients MUST handle missing or unknown
+/// categories gracefully.
 ///
-/// The MCP server is provided by an ACP component and communicates over the ACP channel
-/// using `mcp/connect`, `mcp/message`, and `mcp/disconnect`.
-#[serde_as]
-#[skip_serializing_none]
-#[cfg(feature = "unstable_mcp_over_acp")]
+/// Category names beginning with `_` are free for custom use, like other ACP extension methods.
+/// Category names that do not begin with `_` are reserved for the ACP spec.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "snake_case")]
 #[non_exhaustive]
-pub struct McpServerAcp {
-    /// Human-readable name identifying this MCP server.
-    pub name: String,
-    /// Unique identifier for this MCP server, generated by the component providing it.
-    ///
-    /// Providers MUST NOT reuse an ID for multiple ACP-transport MCP servers that are visible
-    /// on the same ACP connection.
-    pub server_id: McpServerAcpId,
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+pub enum SessionConfigOptionCategory {
+    /// Session mode selector.
+    Mode,
+    /// Model selector.
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
+    /// Agents expose the models a user can pick from as a `select` option in this
+    /// category. Clients switch models with `session/set_config_option`, and
+    /// agent-driven changes arrive as `config_option_update` session updates.
+    Model,
+    /// Model-related configuration parameter.
+    ModelConfig,
+    /// Thought/reasoning level selector.
+    ThoughtLevel,
+    /// Unknown / uncategorized selector.
+    #[serde(untagged)]
+    Other(String),
 }
 
-#[cfg(feature = "unstable_mcp_over_acp")]
-impl McpServerAcp {
-    /// Builds [`McpServerAcp`] with the required fields set; optional fields start unset or empty.
-    #[must_use]
-    pub fn new(name: impl Into<String>, id: impl Into<McpServerAcpId>) -> Self {
-        Self {
-            name: name.into(),
-            server_id: id.into(),
-            meta: None,
-        }
-    }
-
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+/// Type-specific session configuration option payload.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[schemars(extend("discriminator" = {"propertyName": "type"}))]
+#[non_exhaustive]
+pub enum SessionConfigKind {
+    /// Single-value selector (dropdown).
+    Select(SessionConfigSelect),
+    /// **UNSTABLE**
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
-        self
-    }
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Boolean on/off toggle.
+    #[cfg(feature = "unstable_boolean_config")]
+    Boolean(SessionConfigBoolean),
 }
 
-/// Stdio transport configuration for MCP.
+/// A session configuration option selector and its current state.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct McpServerStdio {
-    /// Human-readable name identifying this MCP server.
+pub struct SessionConfigOption {
+    /// Unique identifier for the configuration option.
+    pub id: SessionConfigId,
+    /// Human-readable label for the option.
     pub name: String,
-    /// Absolute path to the MCP server executable.
-    pub command: PathBuf,
-    /// Command-line arguments to pass to the MCP server.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub args: Vec<String>,
-    /// Environment variables to set when launching the MCP server.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub env: Vec<EnvVariable>,
+    /// Optional description for the Client to display to the user.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Optional semantic category for this option (UX only).
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub category: Option<SessionConfigOptionCategory>,
+    /// Type-specific fields for this configuration option.
+    #[serde(flatten)]
+    pub kind: SessionConfigKind,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3117,30 +3523,67 @@ pub struct McpServerStdio {
     pub meta: Option<Meta>,
 }
 
-impl McpServerStdio {
-    /// Builds [`McpServerStdio`] with the required fields set; optional fields start unset or empty.
+impl SessionConfigOption {
+    /// Builds [`SessionConfigOption`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(name: impl Into<String>, command: impl Into<PathBuf>) -> Self {
+    pub fn new(
+        id: impl Into<SessionConfigId>,
+        name: impl Into<String>,
+        kind: SessionConfigKind,
+    ) -> Self {
         Self {
+            id: id.into(),
             name: name.into(),
-            command: command.into(),
-            args: Vec::new(),
-            env: Vec::new(),
+            description: None,
+            category: None,
+            kind,
             meta: None,
         }
     }
 
-    /// Command-line arguments to pass to the MCP server.
+    /// Builds a select-style session configuration option with its current value and choices.
     #[must_use]
-    pub fn args(mut self, args: Vec<String>) -> Self {
-        self.args = args;
-        self
+    pub fn select(
+        id: impl Into<SessionConfigId>,
+        name: impl Into<String>,
+        current_value: impl Into<SessionConfigValueId>,
+        options: impl Into<SessionConfigSelectOptions>,
+    ) -> Self {
+        Self::new(
+            id,
+            name,
+            SessionConfigKind::Select(SessionConfigSelect::new(current_value, options)),
+        )
     }
 
-    /// Environment variables to set when launching the MCP server.
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    #[cfg(feature = "unstable_boolean_config")]
     #[must_use]
-    pub fn env(mut self, env: Vec<EnvVariable>) -> Self {
-        self.env = env;
+    pub fn boolean(
+        id: impl Into<SessionConfigId>,
+        name: impl Into<String>,
+        current_value: bool,
+    ) -> Self {
+        Self::new(
+            id,
+            name,
+            SessionConfigKind::Boolean(SessionConfigBoolean::new(current_value)),
+        )
+    }
+
+    /// Sets or clears the optional `description` field.
+    #[must_use]
+    pub fn description(mut self, description: impl IntoOption<String>) -> Self {
+        self.description = description.into_option();
+        self
+    }
+
+    /// Sets or clears the optional `category` field.
+    #[must_use]
+    pub fn category(mut self, category: impl IntoOption<SessionConfigOptionCategory>) -> Self {
+        self.category = category.into_option();
         self
     }
 
@@ -3156,63 +3599,121 @@ impl McpServerStdio {
     }
 }
 
-/// An environment variable to set when launching an MCP server.
-#[serde_as]
-#[skip_serializing_none]
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// The value to set for a session configuration option.
+///
+/// The `type` field acts as the discriminator in the serialized JSON form.
+/// When no `type` is present, the value is treated as a [`SessionConfigValueId`]
+/// via the [`ValueId`](Self::ValueId) fallback variant.
+///
+/// The `type` discriminator describes the *shape* of the value, not the option
+/// kind. For example every option kind that picks from a list of ids
+/// (`select`, `radio`, …) would use [`ValueId`](Self::ValueId), while a
+/// future freeform text option would get its own variant.
+#[cfg(feature = "unstable_boolean_config")]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
+#[serde(tag = "type", rename_all = "snake_case")]
 #[non_exhaustive]
-pub struct EnvVariable {
-    /// The name of the environment variable.
-    pub name: String,
-    /// The value to set for the environment variable.
-    pub value: String,
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+pub enum SessionConfigOptionValue {
+    /// A boolean value (`type: "boolean"`).
+    Boolean {
+        /// The boolean value.
+        value: bool,
+    },
+    /// A [`SessionConfigValueId`] string value.
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
+    /// This is the default when `type` is absent on the wire. Unknown `type`
+    /// values with string payloads also gracefully deserialize into this
+    /// variant.
+    #[serde(untagged)]
+    ValueId {
+        /// The value ID.
+        value: SessionConfigValueId,
+    },
 }
 
-impl EnvVariable {
-    /// Builds [`EnvVariable`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_boolean_config")]
+impl SessionConfigOptionValue {
+    /// Create a value-id option value (used by `select` and other id-based option types).
     #[must_use]
-    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
-        Self {
-            name: name.into(),
-            value: value.into(),
-            meta: None,
+    pub fn value_id(id: impl Into<SessionConfigValueId>) -> Self {
+        Self::ValueId { value: id.into() }
+    }
+
+    /// Create a boolean option value.
+    #[must_use]
+    pub fn boolean(val: bool) -> Self {
+        Self::Boolean { value: val }
+    }
+
+    /// Return the inner [`SessionConfigValueId`] if this is a
+    /// [`ValueId`](Self::ValueId) value.
+    #[must_use]
+    pub fn as_value_id(&self) -> Option<&SessionConfigValueId> {
+        match self {
+            Self::ValueId { value } => Some(value),
+            _ => None,
         }
     }
 
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
-    ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    /// Return the inner [`bool`] if this is a [`Boolean`](Self::Boolean) value.
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
-        self
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean { value } => Some(*value),
+            _ => None,
+        }
     }
 }
 
-/// An HTTP header to set when making requests to the MCP server.
+#[cfg(feature = "unstable_boolean_config")]
+impl From<SessionConfigValueId> for SessionConfigOptionValue {
+    fn from(value: SessionConfigValueId) -> Self {
+        Self::ValueId { value }
+    }
+}
+
+#[cfg(feature = "unstable_boolean_config")]
+impl From<bool> for SessionConfigOptionValue {
+    fn from(value: bool) -> Self {
+        Self::Boolean { value }
+    }
+}
+
+#[cfg(feature = "unstable_boolean_config")]
+impl From<&str> for SessionConfigOptionValue {
+    fn from(value: &str) -> Self {
+        Self::ValueId {
+            value: SessionConfigValueId::new(value),
+        }
+    }
+}
+
+/// Request parameters for setting a session configuration option.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_CONFIG_OPTION_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct HttpHeader {
-    /// The name of the HTTP header.
-    pub name: String,
-    /// The value to set for the HTTP header.
-    pub value: String,
+pub struct SetSessionConfigOptionRequest {
+    /// The ID of the session to set the configuration option for.
+    pub session_id: SessionId,
+    /// The ID of the configuration option to set.
+    pub config_id: SessionConfigId,
+    /// The value to set, including a `type` discriminator and the raw `value`.
+    ///
+    /// When `type` is absent on the wire, defaults to treating the value as a
+    /// [`SessionConfigValueId`] for `select` options.
+    #[cfg(feature = "unstable_boolean_config")]
+    #[serde(flatten)]
+    pub value: SessionConfigOptionValue,
+    /// The ID of the configuration option value to set.
+    #[cfg(not(feature = "unstable_boolean_config"))]
+    pub value: SessionConfigValueId,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3225,12 +3726,35 @@ pub struct HttpHeader {
     pub meta: Option<Meta>,
 }
 
-impl HttpHeader {
-    /// Builds [`HttpHeader`] with the required fields set; optional fields start unset or empty.
+impl SetSessionConfigOptionRequest {
+    /// Builds [`SetSessionConfigOptionRequest`] with the required request fields set; optional fields start unset or empty.
+    #[cfg(feature = "unstable_boolean_config")]
     #[must_use]
-    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+    pub fn new(
+        session_id: impl Into<SessionId>,
+        config_id: impl Into<SessionConfigId>,
+        value: impl Into<SessionConfigOptionValue>,
+    ) -> Self {
         Self {
-            name: name.into(),
+            session_id: session_id.into(),
+            config_id: config_id.into(),
+            value: value.into(),
+            meta: None,
+        }
+    }
+
+    /// Builds a select-value `session/set_config_option` request for crates built
+    /// without boolean session configuration support.
+    #[cfg(not(feature = "unstable_boolean_config"))]
+    #[must_use]
+    pub fn new(
+        session_id: impl Into<SessionId>,
+        config_id: impl Into<SessionConfigId>,
+        value: impl Into<SessionConfigValueId>,
+    ) -> Self {
+        Self {
+            session_id: session_id.into(),
+            config_id: config_id.into(),
             value: value.into(),
             meta: None,
         }
@@ -3248,38 +3772,18 @@ impl HttpHeader {
     }
 }
 
-// Prompt
-
-/// Request parameters for sending a user prompt to the agent.
-///
-/// Contains the user's message and any additional context.
-///
-/// See protocol docs: [User Message](https://agentclientprotocol.com/protocol/prompt-turn#1-user-message)
+/// Response to `session/set_config_option` method.
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_PROMPT_METHOD_NAME))]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_CONFIG_OPTION_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct PromptRequest {
-    /// The ID of the session to send this user message to
-    pub session_id: SessionId,
-    /// The blocks of content that compose the user's message.
-    ///
-    /// As a baseline, the Agent MUST support [`ContentBlock::Text`] and [`ContentBlock::ResourceLink`],
-    /// while other variants are optionally enabled via [`PromptCapabilities`].
-    ///
-    /// The Client MUST adapt its interface according to [`PromptCapabilities`].
-    ///
-    /// The client MAY include referenced pieces of context as either
-    /// [`ContentBlock::Resource`] or [`ContentBlock::ResourceLink`].
-    ///
-    /// When available, [`ContentBlock::Resource`] is preferred
-    /// as it avoids extra round-trips and allows the message to include
-    /// pieces of context from sources the agent may not have access to.
+pub struct SetSessionConfigOptionResponse {
+    /// The full set of configuration options and their current values.
     #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
     #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub prompt: Vec<ContentBlock>,
+    pub config_options: Vec<SessionConfigOption>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3292,13 +3796,12 @@ pub struct PromptRequest {
     pub meta: Option<Meta>,
 }
 
-impl PromptRequest {
-    /// Builds [`PromptRequest`] with the required request fields set; optional fields start unset or empty.
+impl SetSessionConfigOptionResponse {
+    /// Builds [`SetSessionConfigOptionResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(session_id: impl Into<SessionId>, prompt: Vec<ContentBlock>) -> Self {
+    pub fn new(config_options: Vec<SessionConfigOption>) -> Self {
         Self {
-            session_id: session_id.into(),
-            prompt,
+            config_options,
             meta: None,
         }
     }
@@ -3315,28 +3818,58 @@ impl PromptRequest {
     }
 }
 
-/// Response from processing a user prompt.
+// MCP
+
+/// Configuration for connecting to an MCP (Model Context Protocol) server.
 ///
-/// See protocol docs: [Check for Completion](https://agentclientprotocol.com/protocol/prompt-turn#4-check-for-completion)
-#[serde_as]
-#[skip_serializing_none]
+/// MCP servers provide tools and context that the agent can use when
+/// processing prompts.
+///
+/// See protocol docs: [MCP Servers](https://agentclientprotocol.com/protocol/session-setup#mcp-servers)
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_PROMPT_METHOD_NAME))]
-#[serde(rename_all = "camelCase")]
+#[serde(tag = "type", rename_all = "snake_case")]
 #[non_exhaustive]
-pub struct PromptResponse {
-    /// Indicates why the agent stopped processing the turn.
-    pub stop_reason: StopReason,
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+pub enum McpServer {
+    /// HTTP transport configuration
     ///
-    /// Token usage for this turn (optional).
-    #[cfg(feature = "unstable_end_turn_token_usage")]
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub usage: Option<Usage>,
+    /// Only available when the Agent capabilities indicate `mcp_capabilities.http` is `true`.
+    Http(McpServerHttp),
+    /// SSE transport configuration
+    ///
+    /// Only available when the Agent capabilities indicate `mcp_capabilities.sse` is `true`.
+    Sse(McpServerSse),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// ACP transport configuration
+    ///
+    /// Only available when the Agent capabilities indicate `mcp_capabilities.acp` is `true`.
+    /// The MCP server is provided by an ACP component and communicates over the ACP channel.
+    #[cfg(feature = "unstable_mcp_over_acp")]
+    Acp(McpServerAcp),
+    /// Stdio transport configuration
+    ///
+    /// All Agents MUST support this transport.
+    #[serde(untagged)]
+    Stdio(McpServerStdio),
+}
+
+/// HTTP transport configuration for MCP.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct McpServerHttp {
+    /// Human-readable name identifying this MCP server.
+    pub name: String,
+    /// URL to the MCP server.
+    pub url: String,
+    /// HTTP headers to set when making requests to the MCP server.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub headers: Vec<HttpHeader>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3349,27 +3882,22 @@ pub struct PromptResponse {
     pub meta: Option<Meta>,
 }
 
-impl PromptResponse {
-    /// Builds [`PromptResponse`] with the required response fields set; optional fields start unset or empty.
+impl McpServerHttp {
+    /// Builds [`McpServerHttp`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(stop_reason: StopReason) -> Self {
+    pub fn new(name: impl Into<String>, url: impl Into<String>) -> Self {
         Self {
-            stop_reason,
-            #[cfg(feature = "unstable_end_turn_token_usage")]
-            usage: None,
+            name: name.into(),
+            url: url.into(),
+            headers: Vec::new(),
             meta: None,
         }
     }
 
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
-    ///
-    /// Token usage for this turn.
-    #[cfg(feature = "unstable_end_turn_token_usage")]
+    /// HTTP headers to set when making requests to the MCP server.
     #[must_use]
-    pub fn usage(mut self, usage: impl IntoOption<Usage>) -> Self {
-        self.usage = usage.into_option();
+    pub fn headers(mut self, headers: Vec<HttpHeader>) -> Self {
+        self.headers = headers;
         self
     }
 
@@ -3385,66 +3913,21 @@ impl PromptResponse {
     }
 }
 
-/// Reasons why an agent stops processing a prompt turn.
-///
-/// See protocol docs: [Stop Reasons](https://agentclientprotocol.com/protocol/prompt-turn#stop-reasons)
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
-#[serde(rename_all = "snake_case")]
-#[non_exhaustive]
-pub enum StopReason {
-    /// The turn ended successfully.
-    EndTurn,
-    /// The turn ended because the agent reached the maximum number of tokens.
-    MaxTokens,
-    /// The turn ended because the agent reached the maximum number of allowed
-    /// agent requests between user turns.
-    MaxTurnRequests,
-    /// The turn ended because the agent refused to continue. The user prompt
-    /// and everything that comes after it won't be included in the next
-    /// prompt, so this should be reflected in the UI.
-    Refusal,
-    /// The turn was cancelled by the client via `session/cancel`.
-    ///
-    /// This stop reason MUST be returned when the client sends a `session/cancel`
-    /// notification, even if the cancellation causes exceptions in underlying operations.
-    /// Agents should catch these exceptions and return this semantically meaningful
-    /// response to confirm successful cancellation.
-    Cancelled,
-}
-
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// Token usage information for a prompt turn.
-#[cfg(feature = "unstable_end_turn_token_usage")]
+/// SSE transport configuration for MCP.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct Usage {
-    /// Sum of all token types across session.
-    pub total_tokens: u64,
-    /// Total input tokens across all turns.
-    pub input_tokens: u64,
-    /// Total output tokens across all turns.
-    pub output_tokens: u64,
-    /// Total thought/reasoning tokens
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub thought_tokens: Option<u64>,
-    /// Total cache read tokens.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub cached_read_tokens: Option<u64>,
-    /// Total cache write tokens.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub cached_write_tokens: Option<u64>,
+pub struct McpServerSse {
+    /// Human-readable name identifying this MCP server.
+    pub name: String,
+    /// URL to the MCP server.
+    pub url: String,
+    /// HTTP headers to set when making requests to the MCP server.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub headers: Vec<HttpHeader>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3457,40 +3940,22 @@ pub struct Usage {
     pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_end_turn_token_usage")]
-impl Usage {
-    /// Builds [`Usage`] with the required fields set; optional fields start unset or empty.
+impl McpServerSse {
+    /// Builds [`McpServerSse`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(total_tokens: u64, input_tokens: u64, output_tokens: u64) -> Self {
+    pub fn new(name: impl Into<String>, url: impl Into<String>) -> Self {
         Self {
-            total_tokens,
-            input_tokens,
-            output_tokens,
-            thought_tokens: None,
-            cached_read_tokens: None,
-            cached_write_tokens: None,
+            name: name.into(),
+            url: url.into(),
+            headers: Vec::new(),
             meta: None,
         }
     }
 
-    /// Total thought/reasoning tokens
-    #[must_use]
-    pub fn thought_tokens(mut self, thought_tokens: impl IntoOption<u64>) -> Self {
-        self.thought_tokens = thought_tokens.into_option();
-        self
-    }
-
-    /// Total cache read tokens.
-    #[must_use]
-    pub fn cached_read_tokens(mut self, cached_read_tokens: impl IntoOption<u64>) -> Self {
-        self.cached_read_tokens = cached_read_tokens.into_option();
-        self
-    }
-
-    /// Total cache write tokens.
+    /// HTTP headers to set when making requests to the MCP server.
     #[must_use]
-    pub fn cached_write_tokens(mut self, cached_write_tokens: impl IntoOption<u64>) -> Self {
-        self.cached_write_tokens = cached_write_tokens.into_option();
+    pub fn headers(mut self, headers: Vec<HttpHeader>) -> Self {
+        self.headers = headers;
         self
     }
 
@@ -3506,56 +3971,53 @@ impl Usage {
     }
 }
 
-// Providers
-
 /// **UNSTABLE**
 ///
 /// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// Well-known API protocol identifiers for LLM providers.
-///
-/// Agents and clients MUST handle unknown protocol identifiers gracefully.
+/// Unique identifier for an MCP server using the ACP transport.
 ///
-/// Protocol names beginning with `_` are free for custom use, like other ACP extension methods.
-/// Protocol names that do not begin with `_` are reserved for the ACP spec.
-#[cfg(feature = "unstable_llm_providers")]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "snake_case")]
+/// The value is opaque and generated by the ACP component providing the MCP server. It is
+/// used by `mcp/connect` to route connection requests back to the component that declared the
+/// server.
+#[cfg(feature = "unstable_mcp_over_acp")]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
+#[serde(transparent)]
+#[from(Arc<str>, String, &'static str)]
 #[non_exhaustive]
-#[expect(clippy::doc_markdown)]
-pub enum LlmProtocol {
-    /// Anthropic API protocol.
-    Anthropic,
-    /// OpenAI API protocol.
-    #[serde(rename = "openai")]
-    OpenAi,
-    /// Azure OpenAI API protocol.
-    Azure,
-    /// Google Vertex AI API protocol.
-    Vertex,
-    /// AWS Bedrock API protocol.
-    Bedrock,
-    /// Unknown or custom protocol.
-    #[serde(untagged)]
-    Other(String),
+pub struct McpServerAcpId(pub Arc<str>);
+
+#[cfg(feature = "unstable_mcp_over_acp")]
+impl McpServerAcpId {
+    /// Wraps a protocol string as a typed [`McpServerAcpId`].
+    #[must_use]
+    pub fn new(id: impl Into<Arc<str>>) -> Self {
+        Self(id.into())
+    }
 }
 
 /// **UNSTABLE**
 ///
 /// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// Current effective non-secret routing configuration for a provider.
-#[cfg(feature = "unstable_llm_providers")]
+/// ACP transport configuration for MCP.
+///
+/// The MCP server is provided by an ACP component and communicates over the ACP channel
+/// using `mcp/connect`, `mcp/message`, and `mcp/disconnect`.
 #[serde_as]
 #[skip_serializing_none]
+#[cfg(feature = "unstable_mcp_over_acp")]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct ProviderCurrentConfig {
-    /// Protocol currently used by this provider.
-    pub api_type: LlmProtocol,
-    /// Base URL currently used by this provider.
-    pub base_url: String,
+pub struct McpServerAcp {
+    /// Human-readable name identifying this MCP server.
+    pub name: String,
+    /// Unique identifier for this MCP server, generated by the component providing it.
+    ///
+    /// Providers MUST NOT reuse an ID for multiple ACP-transport MCP servers that are visible
+    /// on the same ACP connection.
+    pub server_id: McpServerAcpId,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3568,14 +4030,14 @@ pub struct ProviderCurrentConfig {
     pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl ProviderCurrentConfig {
-    /// Builds [`ProviderCurrentConfig`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_mcp_over_acp")]
+impl McpServerAcp {
+    /// Builds [`McpServerAcp`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(api_type: LlmProtocol, base_url: impl Into<String>) -> Self {
+    pub fn new(name: impl Into<String>, id: impl Into<McpServerAcpId>) -> Self {
         Self {
-            api_type,
-            base_url: base_url.into(),
+            name: name.into(),
+            server_id: id.into(),
             meta: None,
         }
     }
@@ -3592,54 +4054,25 @@ impl ProviderCurrentConfig {
     }
 }
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// Unique identifier for a configurable LLM provider.
-#[cfg(feature = "unstable_llm_providers")]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
-#[serde(transparent)]
-#[from(Arc<str>, String, &'static str)]
+/// Stdio transport configuration for MCP.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct ProviderId(pub Arc<str>);
-
-#[cfg(feature = "unstable_llm_providers")]
-impl ProviderId {
-    /// Wraps a protocol string as a typed [`ProviderId`].
-    #[must_use]
-    pub fn new(id: impl Into<Arc<str>>) -> Self {
-        Self(id.into())
-    }
-}
-
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// Information about a configurable LLM provider.
-#[cfg(feature = "unstable_llm_providers")]
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
-#[non_exhaustive]
-pub struct ProviderInfo {
-    /// Provider identifier, for example "main" or "openai".
-    pub provider_id: ProviderId,
-    /// Supported protocol types for this provider.
+pub struct McpServerStdio {
+    /// Human-readable name identifying this MCP server.
+    pub name: String,
+    /// Absolute path to the MCP server executable.
+    pub command: PathBuf,
+    /// Command-line arguments to pass to the MCP server.
     #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
     #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub supported: Vec<LlmProtocol>,
-    /// Whether this provider is mandatory and cannot be disabled via `providers/disable`.
-    /// If true, clients must not call `providers/disable` for this provider ID.
-    pub required: bool,
-    /// Current effective non-secret routing config.
-    /// Null or omitted means provider is disabled.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub current: Option<ProviderCurrentConfig>,
+    pub args: Vec<String>,
+    /// Environment variables to set when launching the MCP server.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub env: Vec<EnvVariable>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3652,25 +4085,33 @@ pub struct ProviderInfo {
     pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl ProviderInfo {
-    /// Builds [`ProviderInfo`] with the required fields set; optional fields start unset or empty.
+impl McpServerStdio {
+    /// Builds [`McpServerStdio`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(
-        provider_id: impl Into<ProviderId>,
-        supported: Vec<LlmProtocol>,
-        required: bool,
-        current: impl IntoOption<ProviderCurrentConfig>,
-    ) -> Self {
+    pub fn new(name: impl Into<String>, command: impl Into<PathBuf>) -> Self {
         Self {
-            provider_id: provider_id.into(),
-            supported,
-            required,
-            current: current.into_option(),
+            name: name.into(),
+            command: command.into(),
+            args: Vec::new(),
+            env: Vec::new(),
             meta: None,
         }
     }
 
+    /// Command-line arguments to pass to the MCP server.
+    #[must_use]
+    pub fn args(mut self, args: Vec<String>) -> Self {
+        self.args = args;
+        self
+    }
+
+    /// Environment variables to set when launching the MCP server.
+    #[must_use]
+    pub fn env(mut self, env: Vec<EnvVariable>) -> Self {
+        self.env = env;
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3683,19 +4124,17 @@ impl ProviderInfo {
     }
 }
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// Request parameters for `providers/list`.
-#[cfg(feature = "unstable_llm_providers")]
+/// An environment variable to set when launching an MCP server.
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_LIST_METHOD_NAME))]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct ListProvidersRequest {
+pub struct EnvVariable {
+    /// The name of the environment variable.
+    pub name: String,
+    /// The value to set for the environment variable.
+    pub value: String,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3708,12 +4147,15 @@ pub struct ListProvidersRequest {
     pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl ListProvidersRequest {
-    /// Builds [`ListProvidersRequest`] with the required request fields set; optional fields start unset or empty.
+impl EnvVariable {
+    /// Builds [`EnvVariable`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new() -> Self {
-        Self::default()
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            meta: None,
+        }
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -3728,23 +4170,17 @@ impl ListProvidersRequest {
     }
 }
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// Response to `providers/list`.
-#[cfg(feature = "unstable_llm_providers")]
+/// An HTTP header to set when making requests to the MCP server.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_LIST_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct ListProvidersResponse {
-    /// Configurable providers with current routing info suitable for UI display.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub providers: Vec<ProviderInfo>,
+pub struct HttpHeader {
+    /// The name of the HTTP header.
+    pub name: String,
+    /// The value to set for the HTTP header.
+    pub value: String,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3757,13 +4193,13 @@ pub struct ListProvidersResponse {
     pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl ListProvidersResponse {
-    /// Builds [`ListProvidersResponse`] with the required response fields set; optional fields start unset or empty.
+impl HttpHeader {
+    /// Builds [`HttpHeader`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(providers: Vec<ProviderInfo>) -> Self {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
         Self {
-            providers,
+            name: name.into(),
+            value: value.into(),
             meta: None,
         }
     }
@@ -3780,33 +4216,96 @@ impl ListProvidersResponse {
     }
 }
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
+// Prompt
+
+/// Request parameters for sending a user prompt to the agent.
 ///
-/// Request parameters for `providers/set`.
+/// Contains the user's message and any additional context.
 ///
-/// Replaces the full configuration for one provider ID.
-#[cfg(feature = "unstable_llm_providers")]
+/// See protocol docs: [User Message](https://agentclientprotocol.com/protocol/prompt-turn#1-user-message)
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_SET_METHOD_NAME))]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_PROMPT_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SetProviderRequest {
-    /// Provider ID to configure.
-    pub provider_id: ProviderId,
-    /// Protocol type for this provider.
-    pub api_type: LlmProtocol,
-    /// Base URL for requests sent through this provider.
-    pub base_url: String,
-    /// Full headers map for this provider.
-    /// May include authorization, routing, or other integration-specific headers.
+pub struct PromptRequest {
+    /// The ID of the session to send this user message to
+    pub session_id: SessionId,
+    /// The blocks of content that compose the user's message.
+    ///
+    /// As a baseline, the Agent MUST support [`ContentBlock::Text`] and [`ContentBlock::ResourceLink`],
+    /// while other variants are optionally enabled via [`PromptCapabilities`].
+    ///
+    /// The Client MUST adapt its interface according to [`PromptCapabilities`].
+    ///
+    /// The client MAY include referenced pieces of context as either
+    /// [`ContentBlock::Resource`] or [`ContentBlock::ResourceLink`].
+    ///
+    /// When available, [`ContentBlock::Resource`] is preferred
+    /// as it avoids extra round-trips and allows the message to include
+    /// pieces of context from sources the agent may not have access to.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub prompt: Vec<ContentBlock>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// How much reasoning the agent should spend on this prompt.
+    ///
+    /// A hint for agents backed by reasoning models. Agents that do not
+    /// advertise the `reasoningEffort` prompt capability, or do not recognize
+    /// the value, ignore it.
+    #[cfg(feature = "unstable_reasoning_effort")]
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub headers: HashMap<String, String>,
+    #[serde(default)]
+    pub reasoning_effort: Option<ReasoningEffort>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Sampling parameters that override the session defaults for this prompt
+    /// only.
+    ///
+    /// Agents that do not advertise the `samplingOverrides` prompt capability
+    /// ignore them. Out-of-range values deserialize as unset instead of failing
+    /// the request.
+    #[cfg(feature = "unstable_sampling_overrides")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub overrides: Option<SamplingOverrides>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// How long the client is willing to wait for this prompt, in milliseconds
+    /// from when the agent receives it.
+    ///
+    /// Advisory. Agents that honor it SHOULD wind down before the deadline,
+    /// keep the partial results they have, and respond with [`StopReason::Deadline`].
+    /// Agents MAY ignore it.
+    #[cfg(feature = "unstable_prompt_deadline")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub deadline_ms: Option<u64>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Identifies the turn this prompt starts.
+    ///
+    /// Chosen by the Client and unique among the session's turns. The Agent
+    /// MUST echo it as the `turnId` of every `session/update` notification the
+    /// turn produces and of the `session/prompt` response, so Clients can
+    /// attribute updates to this prompt while they stream. Clients that send
+    /// concurrent prompts MUST set it.
+    #[cfg(feature = "unstable_turn_id")]
+    #[serde(default)]
+    pub turn_id: Option<TurnId>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3819,125 +4318,110 @@ pub struct SetProviderRequest {
     pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl SetProviderRequest {
-    /// Builds [`SetProviderRequest`] with the required request fields set; optional fields start unset or empty.
+impl PromptRequest {
+    /// Builds [`PromptRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(
-        provider_id: impl Into<ProviderId>,
-        api_type: LlmProtocol,
-        base_url: impl Into<String>,
-    ) -> Self {
+    pub fn new(session_id: impl Into<SessionId>, prompt: Vec<ContentBlock>) -> Self {
         Self {
-            provider_id: provider_id.into(),
-            api_type,
-            base_url: base_url.into(),
-            headers: HashMap::new(),
+            session_id: session_id.into(),
+            prompt,
+            #[cfg(feature = "unstable_reasoning_effort")]
+            reasoning_effort: None,
+            #[cfg(feature = "unstable_sampling_overrides")]
+            overrides: None,
+            #[cfg(feature = "unstable_prompt_deadline")]
+            deadline_ms: None,
+            #[cfg(feature = "unstable_turn_id")]
+            turn_id: None,
             meta: None,
         }
     }
 
-    /// Full headers map for this provider.
-    /// May include authorization, routing, or other integration-specific headers.
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// How much reasoning the agent should spend on this prompt.
+    #[cfg(feature = "unstable_reasoning_effort")]
     #[must_use]
-    pub fn headers(mut self, headers: HashMap<String, String>) -> Self {
-        self.headers = headers;
+    pub fn reasoning_effort(mut self, reasoning_effort: impl IntoOption<ReasoningEffort>) -> Self {
+        self.reasoning_effort = reasoning_effort.into_option();
         self
     }
 
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+    /// **UNSTABLE**
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The reasoning effort the agent should apply under `capabilities`.
+    ///
+    /// Returns `None` when no hint was sent or when the agent does not advertise
+    /// the `reasoningEffort` prompt capability.
+    #[cfg(feature = "unstable_reasoning_effort")]
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
+    pub fn supported_reasoning_effort(
+        &self,
+        capabilities: &PromptCapabilities,
+    ) -> Option<&ReasoningEffort> {
+        self.reasoning_effort
+            .as_ref()
+            .filter(|_| capabilities.reasoning_effort)
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Sampling parameters that override the session defaults for this prompt
+    /// only.
+    #[cfg(feature = "unstable_sampling_overrides")]
+    #[must_use]
+    pub fn overrides(mut self, overrides: impl IntoOption<SamplingOverrides>) -> Self {
+        self.overrides = overrides.into_option();
         self
     }
-}
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// Response to `providers/set`.
-#[cfg(feature = "unstable_llm_providers")]
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_SET_METHOD_NAME))]
-#[serde(rename_all = "camelCase")]
-#[non_exhaustive]
-pub struct SetProviderResponse {
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+    /// **UNSTABLE**
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
-}
-
-#[cfg(feature = "unstable_llm_providers")]
-impl SetProviderResponse {
-    /// Builds [`SetProviderResponse`] with the required response fields set; optional fields start unset or empty.
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The sampling overrides the agent should apply under `capabilities`.
+    ///
+    /// Returns `None` when no overrides were sent or when the agent does not
+    /// advertise the `samplingOverrides` prompt capability.
+    #[cfg(feature = "unstable_sampling_overrides")]
     #[must_use]
-    pub fn new() -> Self {
-        Self::default()
+    pub fn supported_overrides(
+        &self,
+        capabilities: &PromptCapabilities,
+    ) -> Option<&SamplingOverrides> {
+        self.overrides
+            .as_ref()
+            .filter(|_| capabilities.sampling_overrides)
     }
 
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+    /// **UNSTABLE**
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// How long the client is willing to wait for this prompt, in milliseconds.
+    #[cfg(feature = "unstable_prompt_deadline")]
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
+    pub fn deadline_ms(mut self, deadline_ms: impl IntoOption<u64>) -> Self {
+        self.deadline_ms = deadline_ms.into_option();
         self
     }
-}
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// Request parameters for `providers/disable`.
-#[cfg(feature = "unstable_llm_providers")]
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_DISABLE_METHOD_NAME))]
-#[serde(rename_all = "camelCase")]
-#[non_exhaustive]
-pub struct DisableProviderRequest {
-    /// Provider ID to disable.
-    pub provider_id: ProviderId,
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+    /// **UNSTABLE**
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
-}
-
-#[cfg(feature = "unstable_llm_providers")]
-impl DisableProviderRequest {
-    /// Builds [`DisableProviderRequest`] with the required request fields set; optional fields start unset or empty.
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Identifies the turn this prompt starts.
+    #[cfg(feature = "unstable_turn_id")]
     #[must_use]
-    pub fn new(provider_id: impl Into<ProviderId>) -> Self {
-        Self {
-            provider_id: provider_id.into(),
-            meta: None,
-        }
+    pub fn turn_id(mut self, turn_id: impl IntoOption<TurnId>) -> Self {
+        self.turn_id = turn_id.into_option();
+        self
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -3956,15 +4440,49 @@ impl DisableProviderRequest {
 ///
 /// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// Response to `providers/disable`.
-#[cfg(feature = "unstable_llm_providers")]
+/// How much reasoning an agent backed by a reasoning model should spend on a
+/// prompt.
+///
+/// Agents map each level onto their model's own setting, such as a thinking
+/// token budget.
+#[cfg(feature = "unstable_reasoning_effort")]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ReasoningEffort {
+    /// Answer quickly with minimal reasoning.
+    Low,
+    /// The agent's balanced default.
+    Medium,
+    /// Reason thoroughly, at the cost of latency and tokens.
+    High,
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Sampling parameters that override the session defaults for a single
+/// prompt.
+///
+/// Unset fields keep the session default. Deserialization rejects values
+/// outside the documented ranges.
+#[cfg(feature = "unstable_sampling_overrides")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_DISABLE_METHOD_NAME))]
+#[derive(Default, Debug, Clone, Serialize, JsonSchema, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct DisableProviderResponse {
+pub struct SamplingOverrides {
+    /// Sampling temperature, from `0.0` to `2.0`.
+    #[schemars(range(min = 0.0, max = 2.0))]
+    pub temperature: Option<f32>,
+    /// Nucleus sampling probability mass, from `0.0` to `1.0`.
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub top_p: Option<f32>,
+    /// Maximum number of tokens to generate, at least `1`.
+    #[schemars(range(min = 1))]
+    pub max_tokens: Option<u32>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3977,14 +4495,35 @@ pub struct DisableProviderResponse {
     pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl DisableProviderResponse {
-    /// Builds [`DisableProviderResponse`] with the required response fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_sampling_overrides")]
+impl SamplingOverrides {
+    /// Builds an empty [`SamplingOverrides`]; every parameter keeps the session default.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Sampling temperature, from `0.0` to `2.0`.
+    #[must_use]
+    pub fn temperature(mut self, temperature: impl IntoOption<f32>) -> Self {
+        self.temperature = temperature.into_option();
+        self
+    }
+
+    /// Nucleus sampling probability mass, from `0.0` to `1.0`.
+    #[must_use]
+    pub fn top_p(mut self, top_p: impl IntoOption<f32>) -> Self {
+        self.top_p = top_p.into_option();
+        self
+    }
+
+    /// Maximum number of tokens to generate, at least `1`.
+    #[must_use]
+    pub fn max_tokens(mut self, max_tokens: impl IntoOption<u32>) -> Self {
+        self.max_tokens = max_tokens.into_option();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3995,83 +4534,91 @@ impl DisableProviderResponse {
         self.meta = meta.into_option();
         self
     }
+
+    fn out_of_range(&self) -> Option<&'static str> {
+        if self
+            .temperature
+            .is_some_and(|temperature| !(0.0..=2.0).contains(&temperature))
+        {
+            Some("`temperature` must be between 0.0 and 2.0")
+        } else if self
+            .top_p
+            .is_some_and(|top_p| !(0.0..=1.0).contains(&top_p))
+        {
+            Some("`topP` must be between 0.0 and 1.0")
+        } else if self.max_tokens == Some(0) {
+            Some("`maxTokens` must be at least 1")
+        } else {
+            None
+        }
+    }
 }
 
-// Capabilities
+#[cfg(feature = "unstable_sampling_overrides")]
+impl<'de> Deserialize<'de> for SamplingOverrides {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Wire {
+            temperature: Option<f32>,
+            top_p: Option<f32>,
+            max_tokens: Option<u32>,
+            #[serde(default, rename = "_meta", with = "serde_with::As::<DefaultOnError>")]
+            meta: Option<Meta>,
+        }
 
-/// Capabilities supported by the agent.
-///
-/// Advertised during initialization to inform the client about
-/// available features and content types.
+        let wire = Wire::deserialize(deserializer)?;
+        let overrides = Self {
+            temperature: wire.temperature,
+            top_p: wire.top_p,
+            max_tokens: wire.max_tokens,
+            meta: wire.meta,
+        };
+        if let Some(message) = overrides.out_of_range() {
+            return Err(serde::de::Error::custom(message));
+        }
+        Ok(overrides)
+    }
+}
+
+/// Response from processing a user prompt.
 ///
-/// See protocol docs: [Agent Capabilities](https://agentclientprotocol.com/protocol/initialization#agent-capabilities)
+/// See protocol docs: [Check for Completion](https://agentclientprotocol.com/protocol/prompt-turn#4-check-for-completion)
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_PROMPT_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct AgentCapabilities {
-    /// Whether the agent supports `session/load`.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub load_session: bool,
-    /// Prompt capabilities supported by the agent.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub prompt_capabilities: PromptCapabilities,
-    /// MCP capabilities supported by the agent.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub mcp_capabilities: McpCapabilities,
-    /// Session lifecycle and prompt capabilities advertised by the agent.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub session_capabilities: SessionCapabilities,
-    /// Authentication-related capabilities supported by the agent.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub auth: AgentAuthCapabilities,
+pub struct PromptResponse {
+    /// Indicates why the agent stopped processing the turn.
+    pub stop_reason: StopReason,
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Provider configuration capabilities supported by the agent.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports provider configuration methods.
-    #[cfg(feature = "unstable_llm_providers")]
+    /// Token usage for this turn (optional).
+    #[cfg(feature = "unstable_end_turn_token_usage")]
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub providers: Option<ProvidersCapabilities>,
+    pub usage: Option<Usage>,
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// NES (Next Edit Suggestions) capabilities supported by the agent.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support
-    /// for NES methods.
-    #[cfg(feature = "unstable_nes")]
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub nes: Option<NesCapabilities>,
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    /// The turn this response completes.
     ///
-    /// The position encoding selected by the agent from the client's supported encodings.
-    #[cfg(feature = "unstable_nes")]
+    /// Agents MUST echo the `turnId` of the `session/prompt` request here when
+    /// the request carried one.
+    #[cfg(feature = "unstable_turn_id")]
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub position_encoding: Option<PositionEncodingKind>,
+    pub turn_id: Option<TurnId>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4084,57 +4631,29 @@ pub struct AgentCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl AgentCapabilities {
-    /// Builds an empty [`AgentCapabilities`]; use builder methods to advertise supported sub-capabilities.
-    #[must_use]
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    /// Whether the agent supports `session/load`.
-    #[must_use]
-    pub fn load_session(mut self, load_session: bool) -> Self {
-        self.load_session = load_session;
-        self
-    }
-
-    /// Prompt capabilities supported by the agent.
-    #[must_use]
-    pub fn prompt_capabilities(mut self, prompt_capabilities: PromptCapabilities) -> Self {
-        self.prompt_capabilities = prompt_capabilities;
-        self
-    }
-
-    /// MCP capabilities supported by the agent.
-    #[must_use]
-    pub fn mcp_capabilities(mut self, mcp_capabilities: McpCapabilities) -> Self {
-        self.mcp_capabilities = mcp_capabilities;
-        self
-    }
-
-    /// Session capabilities supported by the agent.
-    #[must_use]
-    pub fn session_capabilities(mut self, session_capabilities: SessionCapabilities) -> Self {
-        self.session_capabilities = session_capabilities;
-        self
-    }
-
-    /// Authentication-related capabilities supported by the agent.
+impl PromptResponse {
+    /// Builds [`PromptResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn auth(mut self, auth: AgentAuthCapabilities) -> Self {
-        self.auth = auth;
-        self
+    pub fn new(stop_reason: StopReason) -> Self {
+        Self {
+            stop_reason,
+            #[cfg(feature = "unstable_end_turn_token_usage")]
+            usage: None,
+            #[cfg(feature = "unstable_turn_id")]
+            turn_id: None,
+            meta: None,
+        }
     }
 
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Provider configuration capabilities supported by the agent.
-    #[cfg(feature = "unstable_llm_providers")]
+    /// Token usage for this turn.
+    #[cfg(feature = "unstable_end_turn_token_usage")]
     #[must_use]
-    pub fn providers(mut self, providers: impl IntoOption<ProvidersCapabilities>) -> Self {
-        self.providers = providers.into_option();
+    pub fn usage(mut self, usage: impl IntoOption<Usage>) -> Self {
+        self.usage = usage.into_option();
         self
     }
 
@@ -4142,24 +4661,11 @@ impl AgentCapabilities {
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// NES (Next Edit Suggestions) capabilities supported by the agent.
-    #[cfg(feature = "unstable_nes")]
-    #[must_use]
-    pub fn nes(mut self, nes: impl IntoOption<NesCapabilities>) -> Self {
-        self.nes = nes.into_option();
-        self
-    }
-
-    /// **UNSTABLE**
-    ///
-    /// The position encoding selected by the agent from the client's supported encodings.
-    #[cfg(feature = "unstable_nes")]
+    /// The turn this response completes.
+    #[cfg(feature = "unstable_turn_id")]
     #[must_use]
-    pub fn position_encoding(
-        mut self,
-        position_encoding: impl IntoOption<PositionEncodingKind>,
-    ) -> Self {
-        self.position_encoding = position_encoding.into_option();
+    pub fn turn_id(mut self, turn_id: impl IntoOption<TurnId>) -> Self {
+        self.turn_id = turn_id.into_option();
         self
     }
 
@@ -4179,120 +4685,103 @@ impl AgentCapabilities {
 ///
 /// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// Provider configuration capabilities supported by the agent.
+/// Identifies one prompt turn within a session.
 ///
-/// Supplying `{}` means the agent supports provider configuration methods.
-#[cfg(feature = "unstable_llm_providers")]
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+/// Clients choose turn IDs when they send `session/prompt`, and Agents echo
+/// them on the turn's `session/update` notifications and on the response, so
+/// updates can be attributed to their prompt while they stream.
+#[cfg(feature = "unstable_turn_id")]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
+#[serde(transparent)]
+#[from(Arc<str>, String, &'static str)]
 #[non_exhaustive]
-pub struct ProvidersCapabilities {
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
-    ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
-}
+pub struct TurnId(pub Arc<str>);
 
-#[cfg(feature = "unstable_llm_providers")]
-impl ProvidersCapabilities {
-    /// Builds an empty [`ProvidersCapabilities`]; use builder methods to advertise supported sub-capabilities.
+#[cfg(feature = "unstable_turn_id")]
+impl TurnId {
+    /// Wraps a protocol string as a typed [`TurnId`].
     #[must_use]
-    pub fn new() -> Self {
-        Self::default()
+    pub fn new(id: impl Into<Arc<str>>) -> Self {
+        Self(id.into())
     }
+}
 
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
-    ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
-        self
+#[cfg(feature = "unstable_turn_id")]
+impl IntoOption<TurnId> for &str {
+    fn into_option(self) -> Option<TurnId> {
+        Some(TurnId::new(self))
     }
 }
 
-/// Session capabilities supported by the agent.
-///
-/// As a baseline, all Agents **MUST** support `session/new`, `session/prompt`, `session/cancel`, and `session/update`.
-///
-/// Optionally, they **MAY** support other session methods and notifications by specifying additional capabilities.
-///
-/// Note: `session/load` is still handled by the top-level `load_session` capability. This will be unified in future versions of the protocol.
+/// Reasons why an agent stops processing a prompt turn.
 ///
-/// See protocol docs: [Session Capabilities](https://agentclientprotocol.com/protocol/initialization#session-capabilities)
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
+/// See protocol docs: [Stop Reasons](https://agentclientprotocol.com/protocol/prompt-turn#stop-reasons)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
 #[non_exhaustive]
-pub struct SessionCapabilities {
-    /// Whether the agent supports `session/list`.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports listing sessions.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub list: Option<SessionListCapabilities>,
-    /// Whether the agent supports `session/delete`.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports deleting sessions from `session/list`.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub delete: Option<SessionDeleteCapabilities>,
-    /// Whether the agent supports `additionalDirectories` on supported session lifecycle requests.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports `additionalDirectories` on
-    /// supported session lifecycle requests.
+pub enum StopReason {
+    /// The turn ended successfully.
+    EndTurn,
+    /// The turn ended because the agent reached the maximum number of tokens.
+    MaxTokens,
+    /// The turn ended because the agent reached the maximum number of allowed
+    /// agent requests between user turns.
+    MaxTurnRequests,
+    /// The turn ended because the agent refused to continue. The user prompt
+    /// and everything that comes after it won't be included in the next
+    /// prompt, so this should be reflected in the UI.
+    Refusal,
+    /// The turn was cancelled by the client via `session/cancel`.
     ///
-    /// Agents that also support `session/list` may return
-    /// `SessionInfo.additionalDirectories` to report the complete ordered
-    /// additional-root list associated with a listed session.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub additional_directories: Option<SessionAdditionalDirectoriesCapabilities>,
+    /// This stop reason MUST be returned when the client sends a `session/cancel`
+    /// notification, even if the cancellation causes exceptions in underlying operations.
+    /// Agents should catch these exceptions and return this semantically meaningful
+    /// response to confirm successful cancellation.
+    Cancelled,
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Whether the agent supports `session/fork`.
+    /// The turn ended early because the prompt's `deadlineMs` ran out.
     ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports forking sessions.
-    #[cfg(feature = "unstable_session_fork")]
+    /// Agents SHOULD include the partial results produced so far.
+    #[cfg(feature = "unstable_prompt_deadline")]
+    Deadline,
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Token usage information for a prompt turn.
+#[cfg(feature = "unstable_end_turn_token_usage")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Usage {
+    /// Sum of all token types across session.
+    pub total_tokens: u64,
+    /// Total input tokens across all turns.
+    pub input_tokens: u64,
+    /// Total output tokens across all turns.
+    pub output_tokens: u64,
+    /// Total thought/reasoning tokens
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub fork: Option<SessionForkCapabilities>,
-    /// Whether the agent supports `session/resume`.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports resuming sessions.
+    pub thought_tokens: Option<u64>,
+    /// Total cache read tokens.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub resume: Option<SessionResumeCapabilities>,
-    /// Whether the agent supports `session/close`.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports closing sessions.
+    pub cached_read_tokens: Option<u64>,
+    /// Total cache write tokens.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub close: Option<SessionCloseCapabilities>,
+    pub cached_write_tokens: Option<u64>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4305,79 +4794,40 @@ pub struct SessionCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl SessionCapabilities {
-    /// Builds an empty [`SessionCapabilities`]; use builder methods to advertise supported sub-capabilities.
-    #[must_use]
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    /// Whether the agent supports `session/list`.
-    ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports listing sessions.
-    #[must_use]
-    pub fn list(mut self, list: impl IntoOption<SessionListCapabilities>) -> Self {
-        self.list = list.into_option();
-        self
-    }
-
-    /// Whether the agent supports `session/delete`.
-    ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports deleting sessions from `session/list`.
-    #[must_use]
-    pub fn delete(mut self, delete: impl IntoOption<SessionDeleteCapabilities>) -> Self {
-        self.delete = delete.into_option();
-        self
-    }
-
-    /// Whether the agent supports `additionalDirectories` on supported session lifecycle requests.
-    ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports `additionalDirectories` on
-    /// supported session lifecycle requests.
-    ///
-    /// Agents that also support `session/list` may return
-    /// `SessionInfo.additionalDirectories` to report the complete ordered
-    /// additional-root list associated with a listed session.
+#[cfg(feature = "unstable_end_turn_token_usage")]
+impl Usage {
+    /// Builds [`Usage`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn additional_directories(
-        mut self,
-        additional_directories: impl IntoOption<SessionAdditionalDirectoriesCapabilities>,
-    ) -> Self {
-        self.additional_directories = additional_directories.into_option();
-        self
+    pub fn new(total_tokens: u64, input_tokens: u64, output_tokens: u64) -> Self {
+        Self {
+            total_tokens,
+            input_tokens,
+            output_tokens,
+            thought_tokens: None,
+            cached_read_tokens: None,
+            cached_write_tokens: None,
+            meta: None,
+        }
     }
 
-    #[cfg(feature = "unstable_session_fork")]
-    /// Whether the agent supports `session/fork`.
-    ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports forking sessions.
+    /// Total thought/reasoning tokens
     #[must_use]
-    pub fn fork(mut self, fork: impl IntoOption<SessionForkCapabilities>) -> Self {
-        self.fork = fork.into_option();
+    pub fn thought_tokens(mut self, thought_tokens: impl IntoOption<u64>) -> Self {
+        self.thought_tokens = thought_tokens.into_option();
         self
     }
 
-    /// Whether the agent supports `session/resume`.
-    ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports resuming sessions.
+    /// Total cache read tokens.
     #[must_use]
-    pub fn resume(mut self, resume: impl IntoOption<SessionResumeCapabilities>) -> Self {
-        self.resume = resume.into_option();
+    pub fn cached_read_tokens(mut self, cached_read_tokens: impl IntoOption<u64>) -> Self {
+        self.cached_read_tokens = cached_read_tokens.into_option();
         self
     }
 
-    /// Whether the agent supports `session/close`.
-    ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports closing sessions.
+    /// Total cache write tokens.
     #[must_use]
-    pub fn close(mut self, close: impl IntoOption<SessionCloseCapabilities>) -> Self {
-        self.close = close.into_option();
+    pub fn cached_write_tokens(mut self, cached_write_tokens: impl IntoOption<u64>) -> Self {
+        self.cached_write_tokens = cached_write_tokens.into_option();
         self
     }
 
@@ -4393,31 +4843,78 @@ impl SessionCapabilities {
     }
 }
 
-/// Capabilities for the `session/list` method.
+// Providers
+
+/// **UNSTABLE**
 ///
-/// Supplying `{}` means the agent supports listing sessions.
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Well-known API protocol identifiers for LLM providers.
+///
+/// Agents and clients MUST handle unknown protocol identifiers gracefully.
+///
+/// Protocol names beginning with `_` are free for custom use, like other ACP extension methods.
+/// Protocol names that do not begin with `_` are reserved for the ACP spec.
+#[cfg(feature = "unstable_llm_providers")]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
 #[non_exhaustive]
-pub struct SessionListCapabilities {
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
-    ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
-}
-
-impl SessionListCapabilities {
-    /// Builds an empty [`SessionListCapabilities`]; use builder methods to advertise supported sub-capabilities.
+#[expect(clippy::doc_markdown)]
+pub enum LlmProtocol {
+    /// Anthropic API protocol.
+    Anthropic,
+    /// OpenAI API protocol.
+    #[serde(rename = "openai")]
+    OpenAi,
+    /// Azure OpenAI API protocol.
+    Azure,
+    /// Google Vertex AI API protocol.
+    Vertex,
+    /// AWS Bedrock API protocol.
+    Bedrock,
+    /// Unknown or custom protocol.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Current effective non-secret routing configuration for a provider.
+#[cfg(feature = "unstable_llm_providers")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ProviderCurrentConfig {
+    /// Protocol currently used by this provider.
+    pub api_type: LlmProtocol,
+    /// Base URL currently used by this provider.
+    pub base_url: String,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_llm_providers")]
+impl ProviderCurrentConfig {
+    /// Builds [`ProviderCurrentConfig`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new() -> Self {
-        Self::default()
+    pub fn new(api_type: LlmProtocol, base_url: impl Into<String>) -> Self {
+        Self {
+            api_type,
+            base_url: base_url.into(),
+            meta: None,
+        }
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -4432,14 +4929,54 @@ impl SessionListCapabilities {
     }
 }
 
-/// Capabilities for the `session/delete` method.
+/// **UNSTABLE**
 ///
-/// Supplying `{}` means the agent supports deleting sessions from `session/list`.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Unique identifier for a configurable LLM provider.
+#[cfg(feature = "unstable_llm_providers")]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
+#[serde(transparent)]
+#[from(Arc<str>, String, &'static str)]
+#[non_exhaustive]
+pub struct ProviderId(pub Arc<str>);
+
+#[cfg(feature = "unstable_llm_providers")]
+impl ProviderId {
+    /// Wraps a protocol string as a typed [`ProviderId`].
+    #[must_use]
+    pub fn new(id: impl Into<Arc<str>>) -> Self {
+        Self(id.into())
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Information about a configurable LLM provider.
+#[cfg(feature = "unstable_llm_providers")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionDeleteCapabilities {
+pub struct ProviderInfo {
+    /// Provider identifier, for example "main" or "openai".
+    pub provider_id: ProviderId,
+    /// Supported protocol types for this provider.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub supported: Vec<LlmProtocol>,
+    /// Whether this provider is mandatory and cannot be disabled via `providers/disable`.
+    /// If true, clients must not call `providers/disable` for this provider ID.
+    pub required: bool,
+    /// Current effective non-secret routing config.
+    /// Null or omitted means provider is disabled.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub current: Option<ProviderCurrentConfig>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4452,11 +4989,23 @@ pub struct SessionDeleteCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl SessionDeleteCapabilities {
-    /// Builds an empty [`SessionDeleteCapabilities`]; use builder methods to advertise supported sub-capabilities.
+#[cfg(feature = "unstable_llm_providers")]
+impl ProviderInfo {
+    /// Builds [`ProviderInfo`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new() -> Self {
-        Self::default()
+    pub fn new(
+        provider_id: impl Into<ProviderId>,
+        supported: Vec<LlmProtocol>,
+        required: bool,
+        current: impl IntoOption<ProviderCurrentConfig>,
+    ) -> Self {
+        Self {
+            provider_id: provider_id.into(),
+            supported,
+            required,
+            current: current.into_option(),
+            meta: None,
+        }
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -4471,17 +5020,19 @@ impl SessionDeleteCapabilities {
     }
 }
 
-/// Capabilities for additional session directories support.
+/// **UNSTABLE**
 ///
-/// Supplying `{}` means the agent supports the `additionalDirectories` field on
-/// supported session lifecycle requests. Agents that also support
-/// `session/list` may return `SessionInfo.additionalDirectories` to report the
-/// complete ordered additional-root list associated with a listed session.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request parameters for `providers/list`.
+#[cfg(feature = "unstable_llm_providers")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_LIST_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionAdditionalDirectoriesCapabilities {
+pub struct ListProvidersRequest {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4494,8 +5045,9 @@ pub struct SessionAdditionalDirectoriesCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl SessionAdditionalDirectoriesCapabilities {
-    /// Builds an empty [`SessionAdditionalDirectoriesCapabilities`]; use builder methods to advertise supported sub-capabilities.
+#[cfg(feature = "unstable_llm_providers")]
+impl ListProvidersRequest {
+    /// Builds [`ListProvidersRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -4517,15 +5069,19 @@ impl SessionAdditionalDirectoriesCapabilities {
 ///
 /// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// Capabilities for the `session/fork` method.
-///
-/// Supplying `{}` means the agent supports forking sessions.
-#[cfg(feature = "unstable_session_fork")]
+/// Response to `providers/list`.
+#[cfg(feature = "unstable_llm_providers")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_LIST_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionForkCapabilities {
+pub struct ListProvidersResponse {
+    /// Configurable providers with current routing info suitable for UI display.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub providers: Vec<ProviderInfo>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4538,12 +5094,15 @@ pub struct SessionForkCapabilities {
     pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_session_fork")]
-impl SessionForkCapabilities {
-    /// Builds an empty [`SessionForkCapabilities`]; use builder methods to advertise supported sub-capabilities.
+#[cfg(feature = "unstable_llm_providers")]
+impl ListProvidersResponse {
+    /// Builds [`ListProvidersResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new() -> Self {
-        Self::default()
+    pub fn new(providers: Vec<ProviderInfo>) -> Self {
+        Self {
+            providers,
+            meta: None,
+        }
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -4558,14 +5117,33 @@ impl SessionForkCapabilities {
     }
 }
 
-/// Capabilities for the `session/resume` method.
+/// **UNSTABLE**
 ///
-/// Supplying `{}` means the agent supports resuming sessions.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request parameters for `providers/set`.
+///
+/// Replaces the full configuration for one provider ID.
+#[cfg(feature = "unstable_llm_providers")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_SET_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionResumeCapabilities {
+pub struct SetProviderRequest {
+    /// Provider ID to configure.
+    pub provider_id: ProviderId,
+    /// Protocol type for this provider.
+    pub api_type: LlmProtocol,
+    /// Base URL for requests sent through this provider.
+    pub base_url: String,
+    /// Full headers map for this provider.
+    /// May include authorization, routing, or other integration-specific headers.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4578,11 +5156,30 @@ pub struct SessionResumeCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl SessionResumeCapabilities {
-    /// Builds an empty [`SessionResumeCapabilities`]; use builder methods to advertise supported sub-capabilities.
+#[cfg(feature = "unstable_llm_providers")]
+impl SetProviderRequest {
+    /// Builds [`SetProviderRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new() -> Self {
-        Self::default()
+    pub fn new(
+        provider_id: impl Into<ProviderId>,
+        api_type: LlmProtocol,
+        base_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            provider_id: provider_id.into(),
+            api_type,
+            base_url: base_url.into(),
+            headers: HashMap::new(),
+            meta: None,
+        }
+    }
+
+    /// Full headers map for this provider.
+    /// May include authorization, routing, or other integration-specific headers.
+    #[must_use]
+    pub fn headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.headers = headers;
+        self
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -4597,14 +5194,19 @@ impl SessionResumeCapabilities {
     }
 }
 
-/// Capabilities for the `session/close` method.
+/// **UNSTABLE**
 ///
-/// Supplying `{}` means the agent supports closing sessions.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response to `providers/set`.
+#[cfg(feature = "unstable_llm_providers")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_SET_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionCloseCapabilities {
+pub struct SetProviderResponse {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4617,8 +5219,9 @@ pub struct SessionCloseCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl SessionCloseCapabilities {
-    /// Builds an empty [`SessionCloseCapabilities`]; use builder methods to advertise supported sub-capabilities.
+#[cfg(feature = "unstable_llm_providers")]
+impl SetProviderResponse {
+    /// Builds [`SetProviderResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -4636,42 +5239,21 @@ impl SessionCloseCapabilities {
     }
 }
 
-/// Prompt capabilities supported by the agent in `session/prompt` requests.
-///
-/// Baseline agent functionality requires support for [`ContentBlock::Text`]
-/// and [`ContentBlock::ResourceLink`] in prompt requests.
-///
-/// Other variants must be explicitly opted in to.
-/// Capabilities for different types of content in prompt requests.
+/// **UNSTABLE**
 ///
-/// Indicates which content types beyond the baseline (text and resource links)
-/// the agent can process.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// See protocol docs: [Prompt Capabilities](https://agentclientprotocol.com/protocol/initialization#prompt-capabilities)
+/// Request parameters for `providers/disable`.
+#[cfg(feature = "unstable_llm_providers")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_DISABLE_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct PromptCapabilities {
-    /// Agent supports [`ContentBlock::Image`].
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub image: bool,
-    /// Agent supports [`ContentBlock::Audio`].
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub audio: bool,
-    /// Agent supports embedded context in `session/prompt` requests.
-    ///
-    /// When enabled, the Client is allowed to include [`ContentBlock::Resource`]
-    /// in prompt requests for pieces of context that are referenced in the message.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub embedded_context: bool,
+pub struct DisableProviderRequest {
+    /// Provider ID to disable.
+    pub provider_id: ProviderId,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4684,35 +5266,60 @@ pub struct PromptCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl PromptCapabilities {
-    /// Builds an empty [`PromptCapabilities`]; use builder methods to advertise supported sub-capabilities.
-    #[must_use]
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    /// Agent supports [`ContentBlock::Image`].
+#[cfg(feature = "unstable_llm_providers")]
+impl DisableProviderRequest {
+    /// Builds [`DisableProviderRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn image(mut self, image: bool) -> Self {
-        self.image = image;
-        self
+    pub fn new(provider_id: impl Into<ProviderId>) -> Self {
+        Self {
+            provider_id: provider_id.into(),
+            meta: None,
+        }
     }
 
-    /// Agent supports [`ContentBlock::Audio`].
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn audio(mut self, audio: bool) -> Self {
-        self.audio = audio;
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
         self
     }
+}
 
-    /// Agent supports embedded context in `session/prompt` requests.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response to `providers/disable`.
+#[cfg(feature = "unstable_llm_providers")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_DISABLE_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DisableProviderResponse {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// When enabled, the Client is allowed to include [`ContentBlock::Resource`]
-    /// in prompt requests for pieces of context that are referenced in the message.
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_llm_providers")]
+impl DisableProviderResponse {
+    /// Builds [`DisableProviderResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn embedded_context(mut self, embedded_context: bool) -> Self {
-        self.embedded_context = embedded_context;
-        self
+    pub fn new() -> Self {
+        Self::default()
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -4727,33 +5334,111 @@ impl PromptCapabilities {
     }
 }
 
-/// MCP capabilities supported by the agent
+// Capabilities
+
+/// Capabilities supported by the agent.
+///
+/// Advertised during initialization to inform the client about
+/// available features and content types.
+///
+/// See protocol docs: [Agent Capabilities](https://agentclientprotocol.com/protocol/initialization#agent-capabilities)
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct McpCapabilities {
-    /// Agent supports [`McpServer::Http`].
+pub struct AgentCapabilities {
+    /// Whether the agent supports `session/load`.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub http: bool,
-    /// Agent supports [`McpServer::Sse`].
+    pub load_session: bool,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent processes several `session/prompt` requests for the
+    /// same session at once.
+    ///
+    /// When `false`, the agent rejects a prompt that arrives while another one
+    /// is in flight with a `SessionBusy` error.
+    #[cfg(feature = "unstable_concurrent_prompts")]
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub sse: bool,
+    pub concurrent_prompts: bool,
+    /// Prompt capabilities supported by the agent.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub prompt_capabilities: PromptCapabilities,
+    /// MCP capabilities supported by the agent.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub mcp_capabilities: McpCapabilities,
+    /// Session lifecycle and prompt capabilities advertised by the agent.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub session_capabilities: SessionCapabilities,
+    /// Authentication-related capabilities supported by the agent.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub auth: AgentAuthCapabilities,
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Agent supports [`McpServer::Acp`].
-    #[cfg(feature = "unstable_mcp_over_acp")]
+    /// Provider configuration capabilities supported by the agent.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports provider configuration methods.
+    #[cfg(feature = "unstable_llm_providers")]
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub acp: bool,
+    pub providers: Option<ProvidersCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// NES (Next Edit Suggestions) capabilities supported by the agent.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support
+    /// for NES methods.
+    #[cfg(feature = "unstable_nes")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub nes: Option<NesCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The position encoding selected by the agent from the client's supported encodings.
+    #[cfg(feature = "unstable_nes")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub position_encoding: Option<PositionEncodingKind>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// MIME types the agent accepts in prompt content, such as `image/png` or
+    /// `audio/wav`.
+    ///
+    /// An entry may use `*` for the subtype, such as `image/*`, to accept every
+    /// subtype. Clients SHOULD NOT send images, audio or embedded resources of
+    /// other types. An empty list means the agent does not advertise any, and
+    /// clients fall back to `promptCapabilities`.
+    #[cfg(feature = "unstable_accepted_mime_types")]
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accepted_mime_types: Vec<String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4766,24 +5451,58 @@ pub struct McpCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl McpCapabilities {
-    /// Builds an empty [`McpCapabilities`]; use builder methods to advertise supported sub-capabilities.
+impl AgentCapabilities {
+    /// Builds an empty [`AgentCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
     }
 
-    /// Agent supports [`McpServer::Http`].
+    /// Whether the agent supports `session/load`.
     #[must_use]
-    pub fn http(mut self, http: bool) -> Self {
-        self.http = http;
+    pub fn load_session(mut self, load_session: bool) -> Self {
+        self.load_session = load_session;
         self
     }
 
-    /// Agent supports [`McpServer::Sse`].
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent processes several `session/prompt` requests for the
+    /// same session at once.
+    #[cfg(feature = "unstable_concurrent_prompts")]
     #[must_use]
-    pub fn sse(mut self, sse: bool) -> Self {
-        self.sse = sse;
+    pub fn concurrent_prompts(mut self, concurrent_prompts: bool) -> Self {
+        self.concurrent_prompts = concurrent_prompts;
+        self
+    }
+
+    /// Prompt capabilities supported by the agent.
+    #[must_use]
+    pub fn prompt_capabilities(mut self, prompt_capabilities: PromptCapabilities) -> Self {
+        self.prompt_capabilities = prompt_capabilities;
+        self
+    }
+
+    /// MCP capabilities supported by the agent.
+    #[must_use]
+    pub fn mcp_capabilities(mut self, mcp_capabilities: McpCapabilities) -> Self {
+        self.mcp_capabilities = mcp_capabilities;
+        self
+    }
+
+    /// Session capabilities supported by the agent.
+    #[must_use]
+    pub fn session_capabilities(mut self, session_capabilities: SessionCapabilities) -> Self {
+        self.session_capabilities = session_capabilities;
+        self
+    }
+
+    /// Authentication-related capabilities supported by the agent.
+    #[must_use]
+    pub fn auth(mut self, auth: AgentAuthCapabilities) -> Self {
+        self.auth = auth;
         self
     }
 
@@ -4791,597 +5510,465 @@ impl McpCapabilities {
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Agent supports [`McpServer::Acp`].
-    #[cfg(feature = "unstable_mcp_over_acp")]
+    /// Provider configuration capabilities supported by the agent.
+    #[cfg(feature = "unstable_llm_providers")]
     #[must_use]
-    pub fn acp(mut self, acp: bool) -> Self {
-        self.acp = acp;
+    pub fn providers(mut self, providers: impl IntoOption<ProvidersCapabilities>) -> Self {
+        self.providers = providers.into_option();
         self
     }
 
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+    /// **UNSTABLE**
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// NES (Next Edit Suggestions) capabilities supported by the agent.
+    #[cfg(feature = "unstable_nes")]
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
+    pub fn nes(mut self, nes: impl IntoOption<NesCapabilities>) -> Self {
+        self.nes = nes.into_option();
         self
     }
-}
 
-// Method schema
+    /// **UNSTABLE**
+    ///
+    /// The position encoding selected by the agent from the client's supported encodings.
+    #[cfg(feature = "unstable_nes")]
+    #[must_use]
+    pub fn position_encoding(
+        mut self,
+        position_encoding: impl IntoOption<PositionEncodingKind>,
+    ) -> Self {
+        self.position_encoding = position_encoding.into_option();
+        self
+    }
 
-/// Names of all methods that agents handle.
-///
-/// Provides a centralized definition of method names used in the protocol.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[non_exhaustive]
-pub struct AgentMethodNames {
-    /// Method for initializing the connection.
-    pub initialize: &'static str,
-    /// Method for authenticating with the agent.
-    pub authenticate: &'static str,
-    /// Method for listing configurable providers.
-    #[cfg(feature = "unstable_llm_providers")]
-    pub providers_list: &'static str,
-    /// Method for setting provider configuration.
-    #[cfg(feature = "unstable_llm_providers")]
-    pub providers_set: &'static str,
-    /// Method for disabling a provider.
-    #[cfg(feature = "unstable_llm_providers")]
-    pub providers_disable: &'static str,
-    /// Method for creating a new session.
-    pub session_new: &'static str,
-    /// Method for loading an existing session.
-    pub session_load: &'static str,
-    /// Method for setting the mode for a session.
-    pub session_set_mode: &'static str,
-    /// Method for setting a configuration option for a session.
-    pub session_set_config_option: &'static str,
-    /// Method for sending a prompt to the agent.
-    pub session_prompt: &'static str,
-    /// Notification for cancelling operations.
-    pub session_cancel: &'static str,
-    /// Method for exchanging MCP-over-ACP messages.
-    #[cfg(feature = "unstable_mcp_over_acp")]
-    pub mcp_message: &'static str,
-    /// Method for listing existing sessions.
-    pub session_list: &'static str,
-    /// Method for deleting an existing session.
-    pub session_delete: &'static str,
-    /// Method for forking an existing session.
-    #[cfg(feature = "unstable_session_fork")]
-    pub session_fork: &'static str,
-    /// Method for resuming an existing session.
-    pub session_resume: &'static str,
-    /// Method for closing an active session.
-    pub session_close: &'static str,
-    /// Method for logging out of an authenticated session.
-    pub logout: &'static str,
-    /// Method for starting an NES session.
-    #[cfg(feature = "unstable_nes")]
-    pub nes_start: &'static str,
-    /// Method for requesting a suggestion.
-    #[cfg(feature = "unstable_nes")]
-    pub nes_suggest: &'static str,
-    /// Notification for accepting a suggestion.
-    #[cfg(feature = "unstable_nes")]
-    pub nes_accept: &'static str,
-    /// Notification for rejecting a suggestion.
-    #[cfg(feature = "unstable_nes")]
-    pub nes_reject: &'static str,
-    /// Method for closing an NES session.
-    #[cfg(feature = "unstable_nes")]
-    pub nes_close: &'static str,
-    /// Notification for document open events.
-    #[cfg(feature = "unstable_nes")]
-    pub document_did_open: &'static str,
-    /// Notification for document change events.
-    #[cfg(feature = "unstable_nes")]
-    pub document_did_change: &'static str,
-    /// Notification for document close events.
-    #[cfg(feature = "unstable_nes")]
-    pub document_did_close: &'static str,
-    /// Notification for document save events.
-    #[cfg(feature = "unstable_nes")]
-    pub document_did_save: &'static str,
-    /// Notification for document focus events.
-    #[cfg(feature = "unstable_nes")]
-    pub document_did_focus: &'static str,
-}
-
-/// Constant containing all agent method names.
-pub const AGENT_METHOD_NAMES: AgentMethodNames = AgentMethodNames {
-    initialize: INITIALIZE_METHOD_NAME,
-    authenticate: AUTHENTICATE_METHOD_NAME,
-    #[cfg(feature = "unstable_llm_providers")]
-    providers_list: PROVIDERS_LIST_METHOD_NAME,
-    #[cfg(feature = "unstable_llm_providers")]
-    providers_set: PROVIDERS_SET_METHOD_NAME,
-    #[cfg(feature = "unstable_llm_providers")]
-    providers_disable: PROVIDERS_DISABLE_METHOD_NAME,
-    session_new: SESSION_NEW_METHOD_NAME,
-    session_load: SESSION_LOAD_METHOD_NAME,
-    session_set_mode: SESSION_SET_MODE_METHOD_NAME,
-    session_set_config_option: SESSION_SET_CONFIG_OPTION_METHOD_NAME,
-    session_prompt: SESSION_PROMPT_METHOD_NAME,
-    session_cancel: SESSION_CANCEL_METHOD_NAME,
-    #[cfg(feature = "unstable_mcp_over_acp")]
-    mcp_message: MCP_MESSAGE_METHOD_NAME,
-    session_list: SESSION_LIST_METHOD_NAME,
-    session_delete: SESSION_DELETE_METHOD_NAME,
-    #[cfg(feature = "unstable_session_fork")]
-    session_fork: SESSION_FORK_METHOD_NAME,
-    session_resume: SESSION_RESUME_METHOD_NAME,
-    session_close: SESSION_CLOSE_METHOD_NAME,
-    logout: LOGOUT_METHOD_NAME,
-    #[cfg(feature = "unstable_nes")]
-    nes_start: NES_START_METHOD_NAME,
-    #[cfg(feature = "unstable_nes")]
-    nes_suggest: NES_SUGGEST_METHOD_NAME,
-    #[cfg(feature = "unstable_nes")]
-    nes_accept: NES_ACCEPT_METHOD_NAME,
-    #[cfg(feature = "unstable_nes")]
-    nes_reject: NES_REJECT_METHOD_NAME,
-    #[cfg(feature = "unstable_nes")]
-    nes_close: NES_CLOSE_METHOD_NAME,
-    #[cfg(feature = "unstable_nes")]
-    document_did_open: DOCUMENT_DID_OPEN_METHOD_NAME,
-    #[cfg(feature = "unstable_nes")]
-    document_did_change: DOCUMENT_DID_CHANGE_METHOD_NAME,
-    #[cfg(feature = "unstable_nes")]
-    document_did_close: DOCUMENT_DID_CLOSE_METHOD_NAME,
-    #[cfg(feature = "unstable_nes")]
-    document_did_save: DOCUMENT_DID_SAVE_METHOD_NAME,
-    #[cfg(feature = "unstable_nes")]
-    document_did_focus: DOCUMENT_DID_FOCUS_METHOD_NAME,
-};
-
-/// Method name for the initialize request.
-pub(crate) const INITIALIZE_METHOD_NAME: &str = "initialize";
-/// Method name for the authenticate request.
-pub(crate) const AUTHENTICATE_METHOD_NAME: &str = "authenticate";
-/// Method name for listing configurable providers.
-#[cfg(feature = "unstable_llm_providers")]
-pub(crate) const PROVIDERS_LIST_METHOD_NAME: &str = "providers/list";
-/// Method name for setting provider configuration.
-#[cfg(feature = "unstable_llm_providers")]
-pub(crate) const PROVIDERS_SET_METHOD_NAME: &str = "providers/set";
-/// Method name for disabling a provider.
-#[cfg(feature = "unstable_llm_providers")]
-pub(crate) const PROVIDERS_DISABLE_METHOD_NAME: &str = "providers/disable";
-/// Method name for creating a new session.
-pub(crate) const SESSION_NEW_METHOD_NAME: &str = "session/new";
-/// Method name for loading an existing session.
-pub(crate) const SESSION_LOAD_METHOD_NAME: &str = "session/load";
-/// Method name for setting the mode for a session.
-pub(crate) const SESSION_SET_MODE_METHOD_NAME: &str = "session/set_mode";
-/// Method name for setting a configuration option for a session.
-pub(crate) const SESSION_SET_CONFIG_OPTION_METHOD_NAME: &str = "session/set_config_option";
-/// Method name for sending a prompt.
-pub(crate) const SESSION_PROMPT_METHOD_NAME: &str = "session/prompt";
-/// Method name for the cancel notification.
-pub(crate) const SESSION_CANCEL_METHOD_NAME: &str = "session/cancel";
-/// Method name for listing existing sessions.
-pub(crate) const SESSION_LIST_METHOD_NAME: &str = "session/list";
-/// Method name for deleting an existing session.
-pub(crate) const SESSION_DELETE_METHOD_NAME: &str = "session/delete";
-/// Method name for forking an existing session.
-#[cfg(feature = "unstable_session_fork")]
-pub(crate) const SESSION_FORK_METHOD_NAME: &str = "session/fork";
-/// Method name for resuming an existing session.
-pub(crate) const SESSION_RESUME_METHOD_NAME: &str = "session/resume";
-/// Method name for closing an active session.
-pub(crate) const SESSION_CLOSE_METHOD_NAME: &str = "session/close";
-/// Method name for logging out of an authenticated session.
-pub(crate) const LOGOUT_METHOD_NAME: &str = "logout";
-
-/// All possible requests that a client can send to an agent.
-///
-/// This enum is used internally for routing RPC requests. You typically won't need
-/// to use this directly.
-///
-/// This enum encompasses all method calls from client to agent.
-#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
-#[serde(untagged)]
-#[schemars(inline)]
-#[non_exhaustive]
-#[allow(clippy::large_enum_variant)]
-pub enum ClientRequest {
-    /// Establishes the connection with a client and negotiates protocol capabilities.
-    ///
-    /// This method is called once at the beginning of the connection to:
-    /// - Negotiate the protocol version to use
-    /// - Exchange capability information between client and agent
-    /// - Determine available authentication methods
-    ///
-    /// The agent should respond with its supported protocol version and capabilities.
-    ///
-    /// See protocol docs: [Initialization](https://agentclientprotocol.com/protocol/initialization)
-    InitializeRequest(InitializeRequest),
-    /// Authenticates the client using the specified authentication method.
-    ///
-    /// Called when the agent requires authentication before allowing session creation.
-    /// The client provides the authentication method ID that was advertised during initialization.
-    ///
-    /// After successful authentication, the client can proceed to create sessions with
-    /// `new_session` without receiving an `auth_required` error.
-    ///
-    /// See protocol docs: [Initialization](https://agentclientprotocol.com/protocol/initialization)
-    AuthenticateRequest(AuthenticateRequest),
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Lists providers that can be configured by the client.
-    #[cfg(feature = "unstable_llm_providers")]
-    ListProvidersRequest(ListProvidersRequest),
+    /// MIME types the agent accepts in prompt content, such as `image/png` or
+    /// `image/*`.
+    #[cfg(feature = "unstable_accepted_mime_types")]
+    #[must_use]
+    pub fn accepted_mime_types(mut self, accepted_mime_types: Vec<String>) -> Self {
+        self.accepted_mime_types = accepted_mime_types;
+        self
+    }
+
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Replaces the configuration for a provider.
-    #[cfg(feature = "unstable_llm_providers")]
-    SetProviderRequest(SetProviderRequest),
+    /// Checks whether a new `session/prompt` for `session_id` may start while
+    /// `in_flight` prompts are still being processed in that session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::session_busy`](super::Error::session_busy) when a prompt
+    /// is in flight and the agent does not advertise `concurrentPrompts`.
+    #[cfg(feature = "unstable_concurrent_prompts")]
+    pub fn check_prompt_admission(
+        &self,
+        session_id: &SessionId,
+        in_flight: usize,
+    ) -> Result<(), super::Error> {
+        if in_flight > 0 && !self.concurrent_prompts {
+            return Err(super::Error::session_busy(session_id.clone()));
+        }
+        Ok(())
+    }
+
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Disables a provider.
-    #[cfg(feature = "unstable_llm_providers")]
-    DisableProviderRequest(DisableProviderRequest),
-    /// Logs out of the current authenticated state.
-    ///
-    /// After a successful logout, all new sessions will require authentication.
-    /// There is no guarantee about the behavior of already running sessions.
-    LogoutRequest(LogoutRequest),
-    /// Creates a new conversation session with the agent.
-    ///
-    /// Sessions represent independent conversation contexts with their own history and state.
-    ///
-    /// The agent should:
-    /// - Create a new session context
-    /// - Connect to any specified MCP servers
-    /// - Return a unique session ID for future requests
-    ///
-    /// May return an `auth_required` error if the agent requires authentication.
-    ///
-    /// See protocol docs: [Session Setup](https://agentclientprotocol.com/protocol/session-setup)
-    NewSessionRequest(NewSessionRequest),
-    /// Loads an existing session to resume a previous conversation.
-    ///
-    /// This method is only available if the agent advertises the `loadSession` capability.
-    ///
-    /// The agent should:
-    /// - Restore the session context and conversation history
-    /// - Connect to the specified MCP servers
-    /// - Stream the entire conversation history back to the client via notifications
+    /// Whether `mime` matches one of [`Self::accepted_mime_types`].
     ///
-    /// See protocol docs: [Loading Sessions](https://agentclientprotocol.com/protocol/session-setup#loading-sessions)
-    LoadSessionRequest(LoadSessionRequest),
-    /// Lists existing sessions known to the agent.
+    /// Matching ignores case and any parameters such as `; charset=utf-8`. An
+    /// entry whose subtype is `*`, such as `image/*`, matches every subtype of
+    /// that type, and `*/*` matches everything.
     ///
-    /// This method is only available if the agent advertises the `sessionCapabilities.list` capability.
+    /// Returns `None` when the agent does not advertise any MIME types, in
+    /// which case callers fall back to `promptCapabilities`.
+    #[cfg(feature = "unstable_accepted_mime_types")]
+    #[must_use]
+    pub fn accepts(&self, mime: &str) -> Option<bool> {
+        fn split(mime: &str) -> Option<(&str, &str)> {
+            let essence = mime.split(';').next().unwrap_or_default().trim();
+            essence.split_once('/')
+        }
+
+        if self.accepted_mime_types.is_empty() {
+            return None;
+        }
+        let Some((kind, subtype)) = split(mime) else {
+            return Some(false);
+        };
+        Some(self.accepted_mime_types.iter().any(|accepted| {
+            split(accepted).is_some_and(|(accepted_kind, accepted_subtype)| {
+                (accepted_kind == "*" || accepted_kind.eq_ignore_ascii_case(kind))
+                    && (accepted_subtype == "*" || accepted_subtype.eq_ignore_ascii_case(subtype))
+            })
+        }))
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// The agent should return metadata about sessions with optional filtering and pagination support.
-    ListSessionsRequest(ListSessionsRequest),
-    /// Deletes an existing session from `session/list`.
-    ///
-    /// This method is only available if the agent advertises the `sessionCapabilities.delete` capability.
-    DeleteSessionRequest(DeleteSessionRequest),
-    #[cfg(feature = "unstable_session_fork")]
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
-    ///
-    /// Forks an existing session to create a new independent session.
-    ///
-    /// This method is only available if the agent advertises the `session.fork` capability.
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Provider configuration capabilities supported by the agent.
+///
+/// Supplying `{}` means the agent supports provider configuration methods.
+#[cfg(feature = "unstable_llm_providers")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ProvidersCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// The agent should create a new session with the same conversation context as the
-    /// original, allowing operations like generating summaries without affecting the
-    /// original session's history.
-    ForkSessionRequest(ForkSessionRequest),
-    /// Resumes an existing session without returning previous messages.
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_llm_providers")]
+impl ProvidersCapabilities {
+    /// Builds an empty [`ProvidersCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// This method is only available if the agent advertises the `sessionCapabilities.resume` capability.
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Session capabilities supported by the agent.
+///
+/// As a baseline, all Agents **MUST** support `session/new`, `session/prompt`, `session/cancel`, and `session/update`.
+///
+/// Optionally, they **MAY** support other session methods and notifications by specifying additional capabilities.
+///
+/// Note: `session/load` is still handled by the top-level `load_session` capability. This will be unified in future versions of the protocol.
+///
+/// See protocol docs: [Session Capabilities](https://agentclientprotocol.com/protocol/initialization#session-capabilities)
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SessionCapabilities {
+    /// Whether the agent supports `session/list`.
     ///
-    /// The agent should resume the session context, allowing the conversation to continue
-    /// without replaying the message history (unlike `session/load`).
-    ResumeSessionRequest(ResumeSessionRequest),
-    /// Closes an active session and frees up any resources associated with it.
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports listing sessions.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub list: Option<SessionListCapabilities>,
+    /// Whether the agent supports `session/delete`.
     ///
-    /// This method is only available if the agent advertises the `sessionCapabilities.close` capability.
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports deleting sessions from `session/list`.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub delete: Option<SessionDeleteCapabilities>,
+    /// Whether the agent supports `additionalDirectories` on supported session lifecycle requests.
     ///
-    /// The agent must cancel any ongoing work (as if `session/cancel` was called)
-    /// and then free up any resources associated with the session.
-    CloseSessionRequest(CloseSessionRequest),
-    /// Sets the current mode for a session.
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports `additionalDirectories` on
+    /// supported session lifecycle requests.
     ///
-    /// Allows switching between different agent modes (e.g., "ask", "architect", "code")
-    /// that affect system prompts, tool availability, and permission behaviors.
+    /// Agents that also support `session/list` may return
+    /// `SessionInfo.additionalDirectories` to report the complete ordered
+    /// additional-root list associated with a listed session.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub additional_directories: Option<SessionAdditionalDirectoriesCapabilities>,
+    /// **UNSTABLE**
     ///
-    /// The mode must be one of the modes advertised in `availableModes` during session
-    /// creation or loading. Agents may also change modes autonomously and notify the
-    /// client via `current_mode_update` notifications.
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// This method can be called at any time during a session, whether the Agent is
-    /// idle or actively generating a response.
+    /// Whether the agent supports `session/fork`.
     ///
-    /// See protocol docs: [Session Modes](https://agentclientprotocol.com/protocol/session-modes)
-    SetSessionModeRequest(SetSessionModeRequest),
-    /// Sets the current value for a session configuration option.
-    SetSessionConfigOptionRequest(SetSessionConfigOptionRequest),
-    /// Processes a user prompt within a session.
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports forking sessions.
+    #[cfg(feature = "unstable_session_fork")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub fork: Option<SessionForkCapabilities>,
+    /// Whether the agent supports `session/resume`.
     ///
-    /// This method handles the whole lifecycle of a prompt:
-    /// - Receives user messages with optional context (files, images, etc.)
-    /// - Processes the prompt using language models
-    /// - Reports language model content and tool calls to the Clients
-    /// - Requests permission to run tools
-    /// - Executes any requested tool calls
-    /// - Returns when the turn is complete with a stop reason
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports resuming sessions.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub resume: Option<SessionResumeCapabilities>,
+    /// Whether the agent supports `session/close`.
     ///
-    /// See protocol docs: [Prompt Turn](https://agentclientprotocol.com/protocol/prompt-turn)
-    PromptRequest(PromptRequest),
-    #[cfg(feature = "unstable_nes")]
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports closing sessions.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub close: Option<SessionCloseCapabilities>,
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Starts an NES session.
-    StartNesRequest(StartNesRequest),
-    #[cfg(feature = "unstable_nes")]
+    /// Whether the agent supports `session/compact`.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports compacting sessions.
+    #[cfg(feature = "unstable_session_compact")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub compact: Option<SessionCompactCapabilities>,
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Requests a code suggestion.
-    SuggestNesRequest(SuggestNesRequest),
-    #[cfg(feature = "unstable_nes")]
+    /// Whether the agent supports `session/set_title`.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports renaming sessions.
+    #[cfg(feature = "unstable_session_set_title")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub set_title: Option<SessionSetTitleCapabilities>,
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Closes an active NES session and frees up any resources associated with it.
+    /// Whether the agent supports `session/cancel_tool_call`.
     ///
-    /// The agent must cancel any ongoing work and then free up any resources
-    /// associated with the NES session.
-    CloseNesRequest(CloseNesRequest),
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports cancelling individual tool calls.
+    #[cfg(feature = "unstable_cancel_tool_call")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub cancel_tool_call: Option<SessionCancelToolCallCapabilities>,
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Exchanges an MCP-over-ACP message.
-    #[cfg(feature = "unstable_mcp_over_acp")]
-    MessageMcpRequest(MessageMcpRequest),
-    /// Handles extension method requests from the client.
+    /// Whether the agent pages the history it replays on `session/load`.
     ///
-    /// Extension methods provide a way to add custom functionality while maintaining
-    /// protocol compatibility.
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent honors `cursor` and `limit` on `session/load`.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub load_pagination: Option<SessionLoadPaginationCapabilities>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
     /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    ExtMethodRequest(ExtRequest),
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-impl ClientRequest {
-    /// Returns the corresponding method name of the request.
+impl SessionCapabilities {
+    /// Builds an empty [`SessionCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
-    pub fn method(&self) -> &str {
-        match self {
-            Self::InitializeRequest(_) => AGENT_METHOD_NAMES.initialize,
-            Self::AuthenticateRequest(_) => AGENT_METHOD_NAMES.authenticate,
-            #[cfg(feature = "unstable_llm_providers")]
-            Self::ListProvidersRequest(_) => AGENT_METHOD_NAMES.providers_list,
-            #[cfg(feature = "unstable_llm_providers")]
-            Self::SetProviderRequest(_) => AGENT_METHOD_NAMES.providers_set,
-            #[cfg(feature = "unstable_llm_providers")]
-            Self::DisableProviderRequest(_) => AGENT_METHOD_NAMES.providers_disable,
-            Self::LogoutRequest(_) => AGENT_METHOD_NAMES.logout,
-            Self::NewSessionRequest(_) => AGENT_METHOD_NAMES.session_new,
-            Self::LoadSessionRequest(_) => AGENT_METHOD_NAMES.session_load,
-            Self::ListSessionsRequest(_) => AGENT_METHOD_NAMES.session_list,
-            Self::DeleteSessionRequest(_) => AGENT_METHOD_NAMES.session_delete,
-            #[cfg(feature = "unstable_session_fork")]
-            Self::ForkSessionRequest(_) => AGENT_METHOD_NAMES.session_fork,
-            Self::ResumeSessionRequest(_) => AGENT_METHOD_NAMES.session_resume,
-            Self::CloseSessionRequest(_) => AGENT_METHOD_NAMES.session_close,
-            Self::SetSessionModeRequest(_) => AGENT_METHOD_NAMES.session_set_mode,
-            Self::SetSessionConfigOptionRequest(_) => AGENT_METHOD_NAMES.session_set_config_option,
-            Self::PromptRequest(_) => AGENT_METHOD_NAMES.session_prompt,
-            #[cfg(feature = "unstable_nes")]
-            Self::StartNesRequest(_) => AGENT_METHOD_NAMES.nes_start,
-            #[cfg(feature = "unstable_nes")]
-            Self::SuggestNesRequest(_) => AGENT_METHOD_NAMES.nes_suggest,
-            #[cfg(feature = "unstable_nes")]
-            Self::CloseNesRequest(_) => AGENT_METHOD_NAMES.nes_close,
-            #[cfg(feature = "unstable_mcp_over_acp")]
-            Self::MessageMcpRequest(_) => AGENT_METHOD_NAMES.mcp_message,
-            Self::ExtMethodRequest(ext_request) => &ext_request.method,
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
-}
 
-/// All possible responses that an agent can send to a client.
-///
-/// This enum is used internally for routing RPC responses. You typically won't need
-/// to use this directly - the responses are handled automatically by the connection.
-///
-/// These are responses to the corresponding `ClientRequest` variants.
-#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
-#[serde(untagged)]
-#[schemars(inline)]
-#[non_exhaustive]
-#[allow(clippy::large_enum_variant)]
-pub enum AgentResponse {
-    /// Successful result returned for a `initialize` request.
-    InitializeResponse(InitializeResponse),
-    /// Successful result returned for a `authenticate` request.
-    AuthenticateResponse(#[serde(default)] AuthenticateResponse),
-    /// Successful result returned for a `providers/list` request.
-    #[cfg(feature = "unstable_llm_providers")]
-    ListProvidersResponse(ListProvidersResponse),
-    /// Successful result returned for a `providers/set` request.
-    #[cfg(feature = "unstable_llm_providers")]
-    SetProviderResponse(#[serde(default)] SetProviderResponse),
-    /// Successful result returned for a `providers/disable` request.
-    #[cfg(feature = "unstable_llm_providers")]
-    DisableProviderResponse(#[serde(default)] DisableProviderResponse),
-    /// Successful result returned for a `logout` request.
-    LogoutResponse(#[serde(default)] LogoutResponse),
-    /// Successful result returned for a `session/new` request.
-    NewSessionResponse(NewSessionResponse),
-    /// Successful result returned for a `session/load` request.
-    LoadSessionResponse(#[serde(default)] LoadSessionResponse),
-    /// Successful result returned for a `session/list` request.
-    ListSessionsResponse(ListSessionsResponse),
-    /// Successful result returned for a `session/delete` request.
-    DeleteSessionResponse(#[serde(default)] DeleteSessionResponse),
-    /// Successful result returned for a `session/fork` request.
-    #[cfg(feature = "unstable_session_fork")]
-    ForkSessionResponse(ForkSessionResponse),
-    /// Successful result returned for a `session/resume` request.
-    ResumeSessionResponse(#[serde(default)] ResumeSessionResponse),
-    /// Successful result returned for a `session/close` request.
-    CloseSessionResponse(#[serde(default)] CloseSessionResponse),
-    /// Successful result returned for a `session/set_mode` request.
-    SetSessionModeResponse(#[serde(default)] SetSessionModeResponse),
-    /// Successful result returned for a `session/set_config_option` request.
-    SetSessionConfigOptionResponse(SetSessionConfigOptionResponse),
-    /// Successful result returned for a `session/prompt` request.
-    PromptResponse(PromptResponse),
-    /// Successful result returned for a `nes/start` request.
-    #[cfg(feature = "unstable_nes")]
-    StartNesResponse(StartNesResponse),
-    /// Successful result returned for a `nes/suggest` request.
-    #[cfg(feature = "unstable_nes")]
-    SuggestNesResponse(SuggestNesResponse),
-    /// Successful result returned for a `nes/close` request.
-    #[cfg(feature = "unstable_nes")]
-    CloseNesResponse(#[serde(default)] CloseNesResponse),
-    /// Successful result returned by an extension method outside the core ACP method set.
-    ExtMethodResponse(ExtResponse),
-    /// Successful result returned by an MCP-over-ACP `mcp/message` request.
-    #[cfg(feature = "unstable_mcp_over_acp")]
-    MessageMcpResponse(MessageMcpResponse),
-}
+    /// Whether the agent supports `session/list`.
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports listing sessions.
+    #[must_use]
+    pub fn list(mut self, list: impl IntoOption<SessionListCapabilities>) -> Self {
+        self.list = list.into_option();
+        self
+    }
 
-/// All possible notifications that a client can send to an agent.
-///
-/// This enum is used internally for routing RPC notifications. You typically won't need
-/// to use this directly.
-///
-/// Notifications do not expect a response.
-#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
-#[serde(untagged)]
-#[schemars(inline)]
-#[non_exhaustive]
-#[allow(clippy::large_enum_variant)]
-pub enum ClientNotification {
-    /// Cancels ongoing operations for a session.
+    /// Whether the agent supports `session/delete`.
     ///
-    /// This is a notification sent by the client to cancel an ongoing prompt turn.
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports deleting sessions from `session/list`.
+    #[must_use]
+    pub fn delete(mut self, delete: impl IntoOption<SessionDeleteCapabilities>) -> Self {
+        self.delete = delete.into_option();
+        self
+    }
+
+    /// Whether the agent supports `additionalDirectories` on supported session lifecycle requests.
     ///
-    /// Upon receiving this notification, the Agent SHOULD:
-    /// - Stop all language model requests as soon as possible
-    /// - Abort all tool call invocations in progress
-    /// - Send any pending `session/update` notifications
-    /// - Respond to the original `session/prompt` request with `StopReason::Cancelled`
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports `additionalDirectories` on
+    /// supported session lifecycle requests.
     ///
-    /// See protocol docs: [Cancellation](https://agentclientprotocol.com/protocol/prompt-turn#cancellation)
-    CancelNotification(CancelNotification),
-    #[cfg(feature = "unstable_nes")]
-    /// **UNSTABLE**
+    /// Agents that also support `session/list` may return
+    /// `SessionInfo.additionalDirectories` to report the complete ordered
+    /// additional-root list associated with a listed session.
+    #[must_use]
+    pub fn additional_directories(
+        mut self,
+        additional_directories: impl IntoOption<SessionAdditionalDirectoriesCapabilities>,
+    ) -> Self {
+        self.additional_directories = additional_directories.into_option();
+        self
+    }
+
+    #[cfg(feature = "unstable_session_fork")]
+    /// Whether the agent supports `session/fork`.
     ///
-    /// Notification sent when a file is opened in the editor.
-    DidOpenDocumentNotification(DidOpenDocumentNotification),
-    #[cfg(feature = "unstable_nes")]
-    /// **UNSTABLE**
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports forking sessions.
+    #[must_use]
+    pub fn fork(mut self, fork: impl IntoOption<SessionForkCapabilities>) -> Self {
+        self.fork = fork.into_option();
+        self
+    }
+
+    /// Whether the agent supports `session/resume`.
     ///
-    /// Notification sent when a file is edited.
-    DidChangeDocumentNotification(DidChangeDocumentNotification),
-    #[cfg(feature = "unstable_nes")]
-    /// **UNSTABLE**
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports resuming sessions.
+    #[must_use]
+    pub fn resume(mut self, resume: impl IntoOption<SessionResumeCapabilities>) -> Self {
+        self.resume = resume.into_option();
+        self
+    }
+
+    /// Whether the agent supports `session/close`.
     ///
-    /// Notification sent when a file is closed.
-    DidCloseDocumentNotification(DidCloseDocumentNotification),
-    #[cfg(feature = "unstable_nes")]
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports closing sessions.
+    #[must_use]
+    pub fn close(mut self, close: impl IntoOption<SessionCloseCapabilities>) -> Self {
+        self.close = close.into_option();
+        self
+    }
+
     /// **UNSTABLE**
     ///
-    /// Notification sent when a file is saved.
-    DidSaveDocumentNotification(DidSaveDocumentNotification),
-    #[cfg(feature = "unstable_nes")]
-    /// **UNSTABLE**
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Notification sent when a file becomes the active editor tab.
-    DidFocusDocumentNotification(DidFocusDocumentNotification),
-    #[cfg(feature = "unstable_nes")]
+    /// Whether the agent supports `session/compact`.
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports compacting sessions.
+    #[cfg(feature = "unstable_session_compact")]
+    #[must_use]
+    pub fn compact(mut self, compact: impl IntoOption<SessionCompactCapabilities>) -> Self {
+        self.compact = compact.into_option();
+        self
+    }
+
     /// **UNSTABLE**
     ///
-    /// Notification sent when a suggestion is accepted.
-    AcceptNesNotification(AcceptNesNotification),
-    #[cfg(feature = "unstable_nes")]
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent supports `session/set_title`.
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports renaming sessions.
+    #[cfg(feature = "unstable_session_set_title")]
+    #[must_use]
+    pub fn set_title(mut self, set_title: impl IntoOption<SessionSetTitleCapabilities>) -> Self {
+        self.set_title = set_title.into_option();
+        self
+    }
+
     /// **UNSTABLE**
     ///
-    /// Notification sent when a suggestion is rejected.
-    RejectNesNotification(RejectNesNotification),
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent supports `session/cancel_tool_call`.
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports cancelling individual tool calls.
+    #[cfg(feature = "unstable_cancel_tool_call")]
+    #[must_use]
+    pub fn cancel_tool_call(
+        mut self,
+        cancel_tool_call: impl IntoOption<SessionCancelToolCallCapabilities>,
+    ) -> Self {
+        self.cancel_tool_call = cancel_tool_call.into_option();
+        self
+    }
+
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Sends an MCP-over-ACP notification.
-    #[cfg(feature = "unstable_mcp_over_acp")]
-    MessageMcpNotification(MessageMcpNotification),
-    /// Handles extension notifications from the client.
+    /// Whether the agent pages the history it replays on `session/load`.
     ///
-    /// Extension notifications provide a way to send one-way messages for custom functionality
-    /// while maintaining protocol compatibility.
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent honors `cursor` and `limit` on `session/load`.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[must_use]
+    pub fn load_pagination(
+        mut self,
+        load_pagination: impl IntoOption<SessionLoadPaginationCapabilities>,
+    ) -> Self {
+        self.load_pagination = load_pagination.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
     /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    ExtNotification(ExtNotification),
-}
-
-impl ClientNotification {
-    /// Returns the corresponding method name of the notification.
     #[must_use]
-    pub fn method(&self) -> &str {
-        match self {
-            Self::CancelNotification(_) => AGENT_METHOD_NAMES.session_cancel,
-            #[cfg(feature = "unstable_nes")]
-            Self::DidOpenDocumentNotification(_) => AGENT_METHOD_NAMES.document_did_open,
-            #[cfg(feature = "unstable_nes")]
-            Self::DidChangeDocumentNotification(_) => AGENT_METHOD_NAMES.document_did_change,
-            #[cfg(feature = "unstable_nes")]
-            Self::DidCloseDocumentNotification(_) => AGENT_METHOD_NAMES.document_did_close,
-            #[cfg(feature = "unstable_nes")]
-            Self::DidSaveDocumentNotification(_) => AGENT_METHOD_NAMES.document_did_save,
-            #[cfg(feature = "unstable_nes")]
-            Self::DidFocusDocumentNotification(_) => AGENT_METHOD_NAMES.document_did_focus,
-            #[cfg(feature = "unstable_nes")]
-            Self::AcceptNesNotification(_) => AGENT_METHOD_NAMES.nes_accept,
-            #[cfg(feature = "unstable_nes")]
-            Self::RejectNesNotification(_) => AGENT_METHOD_NAMES.nes_reject,
-            #[cfg(feature = "unstable_mcp_over_acp")]
-            Self::MessageMcpNotification(_) => AGENT_METHOD_NAMES.mcp_message,
-            Self::ExtNotification(ext_notification) => &ext_notification.method,
-        }
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
     }
 }
 
-/// Notification to cancel ongoing operations for a session.
+/// Capabilities for the `session/list` method.
 ///
-/// See protocol docs: [Cancellation](https://agentclientprotocol.com/protocol/prompt-turn#cancellation)
+/// Supplying `{}` means the agent supports listing sessions.
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_CANCEL_METHOD_NAME))]
-#[serde(rename_all = "camelCase")]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct CancelNotification {
-    /// The ID of the session to cancel operations for.
-    pub session_id: SessionId,
+pub struct SessionListCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -5394,14 +5981,11 @@ pub struct CancelNotification {
     pub meta: Option<Meta>,
 }
 
-impl CancelNotification {
-    /// Builds [`CancelNotification`] with the required notification fields set; optional fields start unset or empty.
+impl SessionListCapabilities {
+    /// Builds an empty [`SessionListCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
-    pub fn new(session_id: impl Into<SessionId>) -> Self {
-        Self {
-            session_id: session_id.into(),
-            meta: None,
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -5414,1251 +5998,3825 @@ impl CancelNotification {
         self.meta = meta.into_option();
         self
     }
-}
-
-#[cfg(test)]
-mod test_serialization {
-    use super::*;
-    use serde_json::json;
+}
+
+/// Capabilities for the `session/delete` method.
+///
+/// Supplying `{}` means the agent supports deleting sessions from `session/list`.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SessionDeleteCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SessionDeleteCapabilities {
+    /// Builds an empty [`SessionDeleteCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Capabilities for additional session directories support.
+///
+/// Supplying `{}` means the agent supports the `additionalDirectories` field on
+/// supported session lifecycle requests. Agents that also support
+/// `session/list` may return `SessionInfo.additionalDirectories` to report the
+/// complete ordered additional-root list associated with a listed session.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SessionAdditionalDirectoriesCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SessionAdditionalDirectoriesCapabilities {
+    /// Builds an empty [`SessionAdditionalDirectoriesCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for the `session/fork` method.
+///
+/// Supplying `{}` means the agent supports forking sessions.
+#[cfg(feature = "unstable_session_fork")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SessionForkCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_session_fork")]
+impl SessionForkCapabilities {
+    /// Builds an empty [`SessionForkCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Capabilities for the `session/resume` method.
+///
+/// Supplying `{}` means the agent supports resuming sessions.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SessionResumeCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SessionResumeCapabilities {
+    /// Builds an empty [`SessionResumeCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Capabilities for the `session/close` method.
+///
+/// Supplying `{}` means the agent supports closing sessions.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SessionCloseCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SessionCloseCapabilities {
+    /// Builds an empty [`SessionCloseCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for the `session/compact` method.
+///
+/// Supplying `{}` means the agent supports compacting sessions.
+#[cfg(feature = "unstable_session_compact")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SessionCompactCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_session_compact")]
+impl SessionCompactCapabilities {
+    /// Builds an empty [`SessionCompactCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for the `session/set_title` method.
+///
+/// Supplying `{}` means the agent supports renaming sessions.
+#[cfg(feature = "unstable_session_set_title")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SessionSetTitleCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_session_set_title")]
+impl SessionSetTitleCapabilities {
+    /// Builds an empty [`SessionSetTitleCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for the `session/cancel_tool_call` method.
+///
+/// Supplying `{}` means the agent supports cancelling individual tool calls.
+#[cfg(feature = "unstable_cancel_tool_call")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SessionCancelToolCallCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_cancel_tool_call")]
+impl SessionCancelToolCallCapabilities {
+    /// Builds an empty [`SessionCancelToolCallCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for paging the history replayed by `session/load`.
+///
+/// Supplying `{}` means the agent honors `cursor` and `limit` on `session/load`.
+#[cfg(feature = "unstable_session_load_pagination")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SessionLoadPaginationCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_session_load_pagination")]
+impl SessionLoadPaginationCapabilities {
+    /// Builds an empty [`SessionLoadPaginationCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Prompt capabilities supported by the agent in `session/prompt` requests.
+///
+/// Baseline agent functionality requires support for [`ContentBlock::Text`]
+/// and [`ContentBlock::ResourceLink`] in prompt requests.
+///
+/// Other variants must be explicitly opted in to.
+/// Capabilities for different types of content in prompt requests.
+///
+/// Indicates which content types beyond the baseline (text and resource links)
+/// the agent can process.
+///
+/// See protocol docs: [Prompt Capabilities](https://agentclientprotocol.com/protocol/initialization#prompt-capabilities)
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PromptCapabilities {
+    /// Agent supports [`ContentBlock::Image`].
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub image: bool,
+    /// Agent supports [`ContentBlock::Audio`].
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub audio: bool,
+    /// Agent supports embedded context in `session/prompt` requests.
+    ///
+    /// When enabled, the Client is allowed to include [`ContentBlock::Resource`]
+    /// in prompt requests for pieces of context that are referenced in the message.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub embedded_context: bool,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent supports [`ContentBlock::ToolResultRef`].
+    #[cfg(feature = "unstable_tool_result_ref")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub tool_result_ref: bool,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent supports [`ContentBlock::Mention`].
+    #[cfg(feature = "unstable_mention")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub mention: bool,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent honors [`PromptRequest::reasoning_effort`].
+    #[cfg(feature = "unstable_reasoning_effort")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub reasoning_effort: bool,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent honors [`PromptRequest::overrides`].
+    #[cfg(feature = "unstable_sampling_overrides")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub sampling_overrides: bool,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl PromptCapabilities {
+    /// Builds an empty [`PromptCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Agent supports [`ContentBlock::Image`].
+    #[must_use]
+    pub fn image(mut self, image: bool) -> Self {
+        self.image = image;
+        self
+    }
+
+    /// Agent supports [`ContentBlock::Audio`].
+    #[must_use]
+    pub fn audio(mut self, audio: bool) -> Self {
+        self.audio = audio;
+        self
+    }
+
+    /// Agent supports embedded context in `session/prompt` requests.
+    ///
+    /// When enabled, the Client is allowed to include [`ContentBlock::Resource`]
+    /// in prompt requests for pieces of context that are referenced in the message.
+    #[must_use]
+    pub fn embedded_context(mut self, embedded_context: bool) -> Self {
+        self.embedded_context = embedded_context;
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent supports [`ContentBlock::ToolResultRef`].
+    #[cfg(feature = "unstable_tool_result_ref")]
+    #[must_use]
+    pub fn tool_result_ref(mut self, tool_result_ref: bool) -> Self {
+        self.tool_result_ref = tool_result_ref;
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent supports [`ContentBlock::Mention`].
+    #[cfg(feature = "unstable_mention")]
+    #[must_use]
+    pub fn mention(mut self, mention: bool) -> Self {
+        self.mention = mention;
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent honors [`PromptRequest::reasoning_effort`].
+    #[cfg(feature = "unstable_reasoning_effort")]
+    #[must_use]
+    pub fn reasoning_effort(mut self, reasoning_effort: bool) -> Self {
+        self.reasoning_effort = reasoning_effort;
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent honors [`PromptRequest::overrides`].
+    #[cfg(feature = "unstable_sampling_overrides")]
+    #[must_use]
+    pub fn sampling_overrides(mut self, sampling_overrides: bool) -> Self {
+        self.sampling_overrides = sampling_overrides;
+        self
+    }
+
+    /// Whether a prompt may include `block` under these capabilities.
+    ///
+    /// Text and resource links are always allowed. Tables and JSON values are
+    /// agent output and are never allowed. Every other block type requires its matching capability.
+    #[must_use]
+    pub fn supports(&self, block: &ContentBlock) -> bool {
+        match block {
+            ContentBlock::Text(_) | ContentBlock::ResourceLink(_) => true,
+            #[cfg(feature = "unstable_mention")]
+            ContentBlock::Mention(_) => self.mention,
+            #[cfg(feature = "unstable_table")]
+            ContentBlock::Table(_) => false,
+            #[cfg(feature = "unstable_json_content")]
+            ContentBlock::Json(_) => false,
+            ContentBlock::Image(_) => self.image,
+            ContentBlock::Audio(_) => self.audio,
+            ContentBlock::Resource(_) => self.embedded_context,
+            #[cfg(feature = "unstable_tool_result_ref")]
+            ContentBlock::ToolResultRef(_) => self.tool_result_ref,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// MCP capabilities supported by the agent
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct McpCapabilities {
+    /// Agent supports [`McpServer::Http`].
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub http: bool,
+    /// Agent supports [`McpServer::Sse`].
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub sse: bool,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent supports [`McpServer::Acp`].
+    #[cfg(feature = "unstable_mcp_over_acp")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub acp: bool,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl McpCapabilities {
+    /// Builds an empty [`McpCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Agent supports [`McpServer::Http`].
+    #[must_use]
+    pub fn http(mut self, http: bool) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Agent supports [`McpServer::Sse`].
+    #[must_use]
+    pub fn sse(mut self, sse: bool) -> Self {
+        self.sse = sse;
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent supports [`McpServer::Acp`].
+    #[cfg(feature = "unstable_mcp_over_acp")]
+    #[must_use]
+    pub fn acp(mut self, acp: bool) -> Self {
+        self.acp = acp;
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+// Method schema
+
+/// Names of all methods that agents handle.
+///
+/// Provides a centralized definition of method names used in the protocol.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AgentMethodNames {
+    /// Method for initializing the connection.
+    pub initialize: &'static str,
+    /// Method for authenticating with the agent.
+    pub authenticate: &'static str,
+    /// Method for listing configurable providers.
+    #[cfg(feature = "unstable_llm_providers")]
+    pub providers_list: &'static str,
+    /// Method for setting provider configuration.
+    #[cfg(feature = "unstable_llm_providers")]
+    pub providers_set: &'static str,
+    /// Method for disabling a provider.
+    #[cfg(feature = "unstable_llm_providers")]
+    pub providers_disable: &'static str,
+    /// Method for creating a new session.
+    pub session_new: &'static str,
+    /// Method for loading an existing session.
+    pub session_load: &'static str,
+    /// Method for setting the mode for a session.
+    pub session_set_mode: &'static str,
+    /// Method for setting a configuration option for a session.
+    pub session_set_config_option: &'static str,
+    /// Method for sending a prompt to the agent.
+    pub session_prompt: &'static str,
+    /// Notification for cancelling operations.
+    pub session_cancel: &'static str,
+    /// Notification for reporting a diff that could not be applied.
+    #[cfg(feature = "unstable_edit_conflict")]
+    pub session_report_edit_conflict: &'static str,
+    /// Method for exchanging MCP-over-ACP messages.
+    #[cfg(feature = "unstable_mcp_over_acp")]
+    pub mcp_message: &'static str,
+    /// Method for listing existing sessions.
+    pub session_list: &'static str,
+    /// Method for deleting an existing session.
+    pub session_delete: &'static str,
+    /// Method for forking an existing session.
+    #[cfg(feature = "unstable_session_fork")]
+    pub session_fork: &'static str,
+    /// Method for resuming an existing session.
+    pub session_resume: &'static str,
+    /// Method for closing an active session.
+    pub session_close: &'static str,
+    /// Method for compacting a session's history.
+    #[cfg(feature = "unstable_session_compact")]
+    pub session_compact: &'static str,
+    /// Method for renaming a session.
+    #[cfg(feature = "unstable_session_set_title")]
+    pub session_set_title: &'static str,
+    /// Method for cancelling a single tool call.
+    #[cfg(feature = "unstable_cancel_tool_call")]
+    pub session_cancel_tool_call: &'static str,
+    /// Method for invoking an available command.
+    #[cfg(feature = "unstable_command_invocation")]
+    pub command_invoke: &'static str,
+    /// Method for answering an input request.
+    #[cfg(feature = "unstable_input_request")]
+    pub session_provide_input: &'static str,
+    /// Method for logging out of an authenticated session.
+    pub logout: &'static str,
+    /// Method for starting an NES session.
+    #[cfg(feature = "unstable_nes")]
+    pub nes_start: &'static str,
+    /// Method for requesting a suggestion.
+    #[cfg(feature = "unstable_nes")]
+    pub nes_suggest: &'static str,
+    /// Notification for accepting a suggestion.
+    #[cfg(feature = "unstable_nes")]
+    pub nes_accept: &'static str,
+    /// Notification for rejecting a suggestion.
+    #[cfg(feature = "unstable_nes")]
+    pub nes_reject: &'static str,
+    /// Method for closing an NES session.
+    #[cfg(feature = "unstable_nes")]
+    pub nes_close: &'static str,
+    /// Notification for document open events.
+    #[cfg(feature = "unstable_nes")]
+    pub document_did_open: &'static str,
+    /// Notification for document change events.
+    #[cfg(feature = "unstable_nes")]
+    pub document_did_change: &'static str,
+    /// Notification for document close events.
+    #[cfg(feature = "unstable_nes")]
+    pub document_did_close: &'static str,
+    /// Notification for document save events.
+    #[cfg(feature = "unstable_nes")]
+    pub document_did_save: &'static str,
+    /// Notification for document focus events.
+    #[cfg(feature = "unstable_nes")]
+    pub document_did_focus: &'static str,
+    /// Notification for changes under watched paths.
+    #[cfg(feature = "unstable_fs_watch")]
+    pub fs_file_changed: &'static str,
+}
+
+/// Constant containing all agent method names.
+pub const AGENT_METHOD_NAMES: AgentMethodNames = AgentMethodNames {
+    initialize: INITIALIZE_METHOD_NAME,
+    authenticate: AUTHENTICATE_METHOD_NAME,
+    #[cfg(feature = "unstable_llm_providers")]
+    providers_list: PROVIDERS_LIST_METHOD_NAME,
+    #[cfg(feature = "unstable_llm_providers")]
+    providers_set: PROVIDERS_SET_METHOD_NAME,
+    #[cfg(feature = "unstable_llm_providers")]
+    providers_disable: PROVIDERS_DISABLE_METHOD_NAME,
+    session_new: SESSION_NEW_METHOD_NAME,
+    session_load: SESSION_LOAD_METHOD_NAME,
+    session_set_mode: SESSION_SET_MODE_METHOD_NAME,
+    session_set_config_option: SESSION_SET_CONFIG_OPTION_METHOD_NAME,
+    session_prompt: SESSION_PROMPT_METHOD_NAME,
+    session_cancel: SESSION_CANCEL_METHOD_NAME,
+    #[cfg(feature = "unstable_edit_conflict")]
+    session_report_edit_conflict: SESSION_REPORT_EDIT_CONFLICT_METHOD_NAME,
+    #[cfg(feature = "unstable_mcp_over_acp")]
+    mcp_message: MCP_MESSAGE_METHOD_NAME,
+    session_list: SESSION_LIST_METHOD_NAME,
+    session_delete: SESSION_DELETE_METHOD_NAME,
+    #[cfg(feature = "unstable_session_fork")]
+    session_fork: SESSION_FORK_METHOD_NAME,
+    session_resume: SESSION_RESUME_METHOD_NAME,
+    session_close: SESSION_CLOSE_METHOD_NAME,
+    #[cfg(feature = "unstable_session_compact")]
+    session_compact: SESSION_COMPACT_METHOD_NAME,
+    #[cfg(feature = "unstable_session_set_title")]
+    session_set_title: SESSION_SET_TITLE_METHOD_NAME,
+    #[cfg(feature = "unstable_cancel_tool_call")]
+    session_cancel_tool_call: SESSION_CANCEL_TOOL_CALL_METHOD_NAME,
+    #[cfg(feature = "unstable_command_invocation")]
+    command_invoke: COMMAND_INVOKE_METHOD_NAME,
+    #[cfg(feature = "unstable_input_request")]
+    session_provide_input: SESSION_PROVIDE_INPUT_METHOD_NAME,
+    logout: LOGOUT_METHOD_NAME,
+    #[cfg(feature = "unstable_nes")]
+    nes_start: NES_START_METHOD_NAME,
+    #[cfg(feature = "unstable_nes")]
+    nes_suggest: NES_SUGGEST_METHOD_NAME,
+    #[cfg(feature = "unstable_nes")]
+    nes_accept: NES_ACCEPT_METHOD_NAME,
+    #[cfg(feature = "unstable_nes")]
+    nes_reject: NES_REJECT_METHOD_NAME,
+    #[cfg(feature = "unstable_nes")]
+    nes_close: NES_CLOSE_METHOD_NAME,
+    #[cfg(feature = "unstable_nes")]
+    document_did_open: DOCUMENT_DID_OPEN_METHOD_NAME,
+    #[cfg(feature = "unstable_nes")]
+    document_did_change: DOCUMENT_DID_CHANGE_METHOD_NAME,
+    #[cfg(feature = "unstable_nes")]
+    document_did_close: DOCUMENT_DID_CLOSE_METHOD_NAME,
+    #[cfg(feature = "unstable_nes")]
+    document_did_save: DOCUMENT_DID_SAVE_METHOD_NAME,
+    #[cfg(feature = "unstable_nes")]
+    document_did_focus: DOCUMENT_DID_FOCUS_METHOD_NAME,
+    #[cfg(feature = "unstable_fs_watch")]
+    fs_file_changed: FS_FILE_CHANGED_NOTIFICATION,
+};
+
+/// Method name for the initialize request.
+pub(crate) const INITIALIZE_METHOD_NAME: &str = "initialize";
+/// Method name for the authenticate request.
+pub(crate) const AUTHENTICATE_METHOD_NAME: &str = "authenticate";
+/// Method name for listing configurable providers.
+#[cfg(feature = "unstable_llm_providers")]
+pub(crate) const PROVIDERS_LIST_METHOD_NAME: &str = "providers/list";
+/// Method name for setting provider configuration.
+#[cfg(feature = "unstable_llm_providers")]
+pub(crate) const PROVIDERS_SET_METHOD_NAME: &str = "providers/set";
+/// Method name for disabling a provider.
+#[cfg(feature = "unstable_llm_providers")]
+pub(crate) const PROVIDERS_DISABLE_METHOD_NAME: &str = "providers/disable";
+/// Method name for creating a new session.
+pub(crate) const SESSION_NEW_METHOD_NAME: &str = "session/new";
+/// Method name for loading an existing session.
+pub(crate) const SESSION_LOAD_METHOD_NAME: &str = "session/load";
+/// Method name for setting the mode for a session.
+pub(crate) const SESSION_SET_MODE_METHOD_NAME: &str = "session/set_mode";
+/// Method name for setting a configuration option for a session.
+pub(crate) const SESSION_SET_CONFIG_OPTION_METHOD_NAME: &str = "session/set_config_option";
+/// Method name for sending a prompt.
+pub(crate) const SESSION_PROMPT_METHOD_NAME: &str = "session/prompt";
+/// Method name for the cancel notification.
+pub(crate) const SESSION_CANCEL_METHOD_NAME: &str = "session/cancel";
+/// Method name for reporting a diff that could not be applied.
+#[cfg(feature = "unstable_edit_conflict")]
+pub(crate) const SESSION_REPORT_EDIT_CONFLICT_METHOD_NAME: &str = "session/report_edit_conflict";
+/// Method name for listing existing sessions.
+pub(crate) const SESSION_LIST_METHOD_NAME: &str = "session/list";
+/// Method name for deleting an existing session.
+pub(crate) const SESSION_DELETE_METHOD_NAME: &str = "session/delete";
+/// Method name for forking an existing session.
+#[cfg(feature = "unstable_session_fork")]
+pub(crate) const SESSION_FORK_METHOD_NAME: &str = "session/fork";
+/// Method name for resuming an existing session.
+pub(crate) const SESSION_RESUME_METHOD_NAME: &str = "session/resume";
+/// Method name for closing an active session.
+pub(crate) const SESSION_CLOSE_METHOD_NAME: &str = "session/close";
+/// Method name for compacting a session's history.
+#[cfg(feature = "unstable_session_compact")]
+pub(crate) const SESSION_COMPACT_METHOD_NAME: &str = "session/compact";
+/// Method name for renaming a session.
+#[cfg(feature = "unstable_session_set_title")]
+pub(crate) const SESSION_SET_TITLE_METHOD_NAME: &str = "session/set_title";
+/// Method name for cancelling a single tool call.
+#[cfg(feature = "unstable_cancel_tool_call")]
+pub(crate) const SESSION_CANCEL_TOOL_CALL_METHOD_NAME: &str = "session/cancel_tool_call";
+/// Method name for invoking an available command.
+#[cfg(feature = "unstable_command_invocation")]
+pub(crate) const COMMAND_INVOKE_METHOD_NAME: &str = "command/invoke";
+/// Method name for answering an input request.
+#[cfg(feature = "unstable_input_request")]
+pub(crate) const SESSION_PROVIDE_INPUT_METHOD_NAME: &str = "session/provide_input";
+/// Method name for logging out of an authenticated session.
+pub(crate) const LOGOUT_METHOD_NAME: &str = "logout";
+
+/// All possible requests that a client can send to an agent.
+///
+/// This enum is used internally for routing RPC requests. You typically won't need
+/// to use this directly.
+///
+/// This enum encompasses all method calls from client to agent.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+#[schemars(inline)]
+#[non_exhaustive]
+#[allow(clippy::large_enum_variant)]
+pub enum ClientRequest {
+    /// Establishes the connection with a client and negotiates protocol capabilities.
+    ///
+    /// This method is called once at the beginning of the connection to:
+    /// - Negotiate the protocol version to use
+    /// - Exchange capability information between client and agent
+    /// - Determine available authentication methods
+    ///
+    /// The agent should respond with its supported protocol version and capabilities.
+    ///
+    /// See protocol docs: [Initialization](https://agentclientprotocol.com/protocol/initialization)
+    InitializeRequest(InitializeRequest),
+    /// Authenticates the client using the specified authentication method.
+    ///
+    /// Called when the agent requires authentication before allowing session creation.
+    /// The client provides the authentication method ID that was advertised during initialization.
+    ///
+    /// After successful authentication, the client can proceed to create sessions with
+    /// `new_session` without receiving an `auth_required` error.
+    ///
+    /// See protocol docs: [Initialization](https://agentclientprotocol.com/protocol/initialization)
+    AuthenticateRequest(AuthenticateRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Lists providers that can be configured by the client.
+    #[cfg(feature = "unstable_llm_providers")]
+    ListProvidersRequest(ListProvidersRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Replaces the configuration for a provider.
+    #[cfg(feature = "unstable_llm_providers")]
+    SetProviderRequest(SetProviderRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Disables a provider.
+    #[cfg(feature = "unstable_llm_providers")]
+    DisableProviderRequest(DisableProviderRequest),
+    /// Logs out of the current authenticated state.
+    ///
+    /// After a successful logout, all new sessions will require authentication.
+    /// There is no guarantee about the behavior of already running sessions.
+    LogoutRequest(LogoutRequest),
+    /// Creates a new conversation session with the agent.
+    ///
+    /// Sessions represent independent conversation contexts with their own history and state.
+    ///
+    /// The agent should:
+    /// - Create a new session context
+    /// - Connect to any specified MCP servers
+    /// - Return a unique session ID for future requests
+    ///
+    /// May return an `auth_required` error if the agent requires authentication.
+    ///
+    /// See protocol docs: [Session Setup](https://agentclientprotocol.com/protocol/session-setup)
+    NewSessionRequest(NewSessionRequest),
+    /// Loads an existing session to resume a previous conversation.
+    ///
+    /// This method is only available if the agent advertises the `loadSession` capability.
+    ///
+    /// The agent should:
+    /// - Restore the session context and conversation history
+    /// - Connect to the specified MCP servers
+    /// - Stream the entire conversation history back to the client via notifications
+    ///
+    /// See protocol docs: [Loading Sessions](https://agentclientprotocol.com/protocol/session-setup#loading-sessions)
+    LoadSessionRequest(LoadSessionRequest),
+    /// Lists existing sessions known to the agent.
+    ///
+    /// This method is only available if the agent advertises the `sessionCapabilities.list` capability.
+    ///
+    /// The agent should return metadata about sessions with optional filtering and pagination support.
+    ListSessionsRequest(ListSessionsRequest),
+    /// Deletes an existing session from `session/list`.
+    ///
+    /// This method is only available if the agent advertises the `sessionCapabilities.delete` capability.
+    DeleteSessionRequest(DeleteSessionRequest),
+    #[cfg(feature = "unstable_session_fork")]
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Forks an existing session to create a new independent session.
+    ///
+    /// This method is only available if the agent advertises the `session.fork` capability.
+    ///
+    /// The agent should create a new session with the same conversation context as the
+    /// original, allowing operations like generating summaries without affecting the
+    /// original session's history.
+    ForkSessionRequest(ForkSessionRequest),
+    /// Resumes an existing session without returning previous messages.
+    ///
+    /// This method is only available if the agent advertises the `sessionCapabilities.resume` capability.
+    ///
+    /// The agent should resume the session context, allowing the conversation to continue
+    /// without replaying the message history (unlike `session/load`).
+    ResumeSessionRequest(ResumeSessionRequest),
+    /// Closes an active session and frees up any resources associated with it.
+    ///
+    /// This method is only available if the agent advertises the `sessionCapabilities.close` capability.
+    ///
+    /// The agent must cancel any ongoing work (as if `session/cancel` was called)
+    /// and then free up any resources associated with the session.
+    CloseSessionRequest(CloseSessionRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Compacts the agent's context for a session.
+    ///
+    /// This method is only available if the agent advertises the `sessionCapabilities.compact` capability.
+    ///
+    /// Subsequent prompts in the session use the compacted context.
+    #[cfg(feature = "unstable_session_compact")]
+    CompactSessionRequest(CompactSessionRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Renames a session.
+    ///
+    /// This method is only available if the agent advertises the `sessionCapabilities.setTitle` capability.
+    #[cfg(feature = "unstable_session_set_title")]
+    SetSessionTitleRequest(SetSessionTitleRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Cancels a single tool call without cancelling the prompt turn.
+    ///
+    /// This method is only available if the agent advertises the `sessionCapabilities.cancelToolCall` capability.
+    #[cfg(feature = "unstable_cancel_tool_call")]
+    CancelToolCallRequest(CancelToolCallRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Invokes one of the commands the agent advertised via `available_commands_update`.
+    ///
+    /// Returns a `command_not_found` error if the command is not currently available.
+    #[cfg(feature = "unstable_command_invocation")]
+    InvokeCommandRequest(InvokeCommandRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Answers a question the agent asked with an `input_request` session update.
+    ///
+    /// The prompt turn that asked the question resumes once the agent receives
+    /// the answer.
+    #[cfg(feature = "unstable_input_request")]
+    ProvideInputRequest(ProvideInputRequest),
+    /// Sets the current mode for a session.
+    ///
+    /// Allows switching between different agent modes (e.g., "ask", "architect", "code")
+    /// that affect system prompts, tool availability, and permission behaviors.
+    ///
+    /// The mode must be one of the modes advertised in `availableModes` during session
+    /// creation or loading. Agents may also change modes autonomously and notify the
+    /// client via `current_mode_update` notifications.
+    ///
+    /// This method can be called at any time during a session, whether the Agent is
+    /// idle or actively generating a response.
+    ///
+    /// See protocol docs: [Session Modes](https://agentclientprotocol.com/protocol/session-modes)
+    SetSessionModeRequest(SetSessionModeRequest),
+    /// Sets the current value for a session configuration option.
+    SetSessionConfigOptionRequest(SetSessionConfigOptionRequest),
+    /// Processes a user prompt within a session.
+    ///
+    /// This method handles the whole lifecycle of a prompt:
+    /// - Receives user messages with optional context (files, images, etc.)
+    /// - Processes the prompt using language models
+    /// - Reports language model content and tool calls to the Clients
+    /// - Requests permission to run tools
+    /// - Executes any requested tool calls
+    /// - Returns when the turn is complete with a stop reason
+    ///
+    /// See protocol docs: [Prompt Turn](https://agentclientprotocol.com/protocol/prompt-turn)
+    PromptRequest(PromptRequest),
+    #[cfg(feature = "unstable_nes")]
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Starts an NES session.
+    StartNesRequest(StartNesRequest),
+    #[cfg(feature = "unstable_nes")]
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Requests a code suggestion.
+    SuggestNesRequest(SuggestNesRequest),
+    #[cfg(feature = "unstable_nes")]
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Closes an active NES session and frees up any resources associated with it.
+    ///
+    /// The agent must cancel any ongoing work and then free up any resources
+    /// associated with the NES session.
+    CloseNesRequest(CloseNesRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Exchanges an MCP-over-ACP message.
+    #[cfg(feature = "unstable_mcp_over_acp")]
+    MessageMcpRequest(MessageMcpRequest),
+    /// Handles extension method requests from the client.
+    ///
+    /// Extension methods provide a way to add custom functionality while maintaining
+    /// protocol compatibility.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    ExtMethodRequest(ExtRequest),
+}
+
+impl ClientRequest {
+    /// Returns the corresponding method name of the request.
+    #[must_use]
+    pub fn method(&self) -> &str {
+        match self {
+            Self::InitializeRequest(_) => AGENT_METHOD_NAMES.initialize,
+            Self::AuthenticateRequest(_) => AGENT_METHOD_NAMES.authenticate,
+            #[cfg(feature = "unstable_llm_providers")]
+            Self::ListProvidersRequest(_) => AGENT_METHOD_NAMES.providers_list,
+            #[cfg(feature = "unstable_llm_providers")]
+            Self::SetProviderRequest(_) => AGENT_METHOD_NAMES.providers_set,
+            #[cfg(feature = "unstable_llm_providers")]
+            Self::DisableProviderRequest(_) => AGENT_METHOD_NAMES.providers_disable,
+            Self::LogoutRequest(_) => AGENT_METHOD_NAMES.logout,
+            Self::NewSessionRequest(_) => AGENT_METHOD_NAMES.session_new,
+            Self::LoadSessionRequest(_) => AGENT_METHOD_NAMES.session_load,
+            Self::ListSessionsRequest(_) => AGENT_METHOD_NAMES.session_list,
+            Self::DeleteSessionRequest(_) => AGENT_METHOD_NAMES.session_delete,
+            #[cfg(feature = "unstable_session_fork")]
+            Self::ForkSessionRequest(_) => AGENT_METHOD_NAMES.session_fork,
+            Self::ResumeSessionRequest(_) => AGENT_METHOD_NAMES.session_resume,
+            Self::CloseSessionRequest(_) => AGENT_METHOD_NAMES.session_close,
+            #[cfg(feature = "unstable_session_compact")]
+            Self::CompactSessionRequest(_) => AGENT_METHOD_NAMES.session_compact,
+            #[cfg(feature = "unstable_session_set_title")]
+            Self::SetSessionTitleRequest(_) => AGENT_METHOD_NAMES.session_set_title,
+            #[cfg(feature = "unstable_cancel_tool_call")]
+            Self::CancelToolCallRequest(_) => AGENT_METHOD_NAMES.session_cancel_tool_call,
+            #[cfg(feature = "unstable_command_invocation")]
+            Self::InvokeCommandRequest(_) => AGENT_METHOD_NAMES.command_invoke,
+            #[cfg(feature = "unstable_input_request")]
+            Self::ProvideInputRequest(_) => AGENT_METHOD_NAMES.session_provide_input,
+            Self::SetSessionModeRequest(_) => AGENT_METHOD_NAMES.session_set_mode,
+            Self::SetSessionConfigOptionRequest(_) => AGENT_METHOD_NAMES.session_set_config_option,
+            Self::PromptRequest(_) => AGENT_METHOD_NAMES.session_prompt,
+            #[cfg(feature = "unstable_nes")]
+            Self::StartNesRequest(_) => AGENT_METHOD_NAMES.nes_start,
+            #[cfg(feature = "unstable_nes")]
+            Self::SuggestNesRequest(_) => AGENT_METHOD_NAMES.nes_suggest,
+            #[cfg(feature = "unstable_nes")]
+            Self::CloseNesRequest(_) => AGENT_METHOD_NAMES.nes_close,
+            #[cfg(feature = "unstable_mcp_over_acp")]
+            Self::MessageMcpRequest(_) => AGENT_METHOD_NAMES.mcp_message,
+            Self::ExtMethodRequest(ext_request) => &ext_request.method,
+        }
+    }
+}
+
+/// All possible responses that an agent can send to a client.
+///
+/// This enum is used internally for routing RPC responses. You typically won't need
+/// to use this directly - the responses are handled automatically by the connection.
+///
+/// These are responses to the corresponding `ClientRequest` variants.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+#[schemars(inline)]
+#[non_exhaustive]
+#[allow(clippy::large_enum_variant)]
+pub enum AgentResponse {
+    /// Successful result returned for a `initialize` request.
+    InitializeResponse(InitializeResponse),
+    /// Successful result returned for a `authenticate` request.
+    AuthenticateResponse(#[serde(default)] AuthenticateResponse),
+    /// Successful result returned for a `providers/list` request.
+    #[cfg(feature = "unstable_llm_providers")]
+    ListProvidersResponse(ListProvidersResponse),
+    /// Successful result returned for a `providers/set` request.
+    #[cfg(feature = "unstable_llm_providers")]
+    SetProviderResponse(#[serde(default)] SetProviderResponse),
+    /// Successful result returned for a `providers/disable` request.
+    #[cfg(feature = "unstable_llm_providers")]
+    DisableProviderResponse(#[serde(default)] DisableProviderResponse),
+    /// Successful result returned for a `logout` request.
+    LogoutResponse(#[serde(default)] LogoutResponse),
+    /// Successful result returned for a `session/new` request.
+    NewSessionResponse(NewSessionResponse),
+    /// Successful result returned for a `session/load` request.
+    LoadSessionResponse(#[serde(default)] LoadSessionResponse),
+    /// Successful result returned for a `session/list` request.
+    ListSessionsResponse(ListSessionsResponse),
+    /// Successful result returned for a `session/delete` request.
+    DeleteSessionResponse(#[serde(default)] DeleteSessionResponse),
+    /// Successful result returned for a `session/fork` request.
+    #[cfg(feature = "unstable_session_fork")]
+    ForkSessionResponse(ForkSessionResponse),
+    /// Successful result returned for a `session/resume` request.
+    ResumeSessionResponse(#[serde(default)] ResumeSessionResponse),
+    /// Successful result returned for a `session/close` request.
+    CloseSessionResponse(#[serde(default)] CloseSessionResponse),
+    /// Successful result returned for a `session/compact` request.
+    #[cfg(feature = "unstable_session_compact")]
+    CompactSessionResponse(CompactSessionResponse),
+    /// Successful result returned for a `session/set_title` request.
+    #[cfg(feature = "unstable_session_set_title")]
+    SetSessionTitleResponse(#[serde(default)] SetSessionTitleResponse),
+    /// Successful result returned for a `session/cancel_tool_call` request.
+    #[cfg(feature = "unstable_cancel_tool_call")]
+    CancelToolCallResponse(#[serde(default)] CancelToolCallResponse),
+    /// Successful result returned for a `command/invoke` request.
+    #[cfg(feature = "unstable_command_invocation")]
+    InvokeCommandResponse(#[serde(default)] InvokeCommandResponse),
+    /// Successful result returned for a `session/provide_input` request.
+    #[cfg(feature = "unstable_input_request")]
+    ProvideInputResponse(#[serde(default)] ProvideInputResponse),
+    /// Successful result returned for a `session/set_mode` request.
+    SetSessionModeResponse(#[serde(default)] SetSessionModeResponse),
+    /// Successful result returned for a `session/set_config_option` request.
+    SetSessionConfigOptionResponse(SetSessionConfigOptionResponse),
+    /// Successful result returned for a `session/prompt` request.
+    PromptResponse(PromptResponse),
+    /// Successful result returned for a `nes/start` request.
+    #[cfg(feature = "unstable_nes")]
+    StartNesResponse(StartNesResponse),
+    /// Successful result returned for a `nes/suggest` request.
+    #[cfg(feature = "unstable_nes")]
+    SuggestNesResponse(SuggestNesResponse),
+    /// Successful result returned for a `nes/close` request.
+    #[cfg(feature = "unstable_nes")]
+    CloseNesResponse(#[serde(default)] CloseNesResponse),
+    /// Successful result returned by an extension method outside the core ACP method set.
+    ExtMethodResponse(ExtResponse),
+    /// Successful result returned by an MCP-over-ACP `mcp/message` request.
+    #[cfg(feature = "unstable_mcp_over_acp")]
+    MessageMcpResponse(MessageMcpResponse),
+}
+
+/// All possible notifications that a client can send to an agent.
+///
+/// This enum is used internally for routing RPC notifications. You typically won't need
+/// to use this directly.
+///
+/// Notifications do not expect a response.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+#[schemars(inline)]
+#[non_exhaustive]
+#[allow(clippy::large_enum_variant)]
+pub enum ClientNotification {
+    /// Cancels ongoing operations for a session.
+    ///
+    /// This is a notification sent by the client to cancel an ongoing prompt turn.
+    ///
+    /// Upon receiving this notification, the Agent SHOULD:
+    /// - Stop all language model requests as soon as possible
+    /// - Abort all tool call invocations in progress
+    /// - Send any pending `session/update` notifications
+    /// - Respond to the original `session/prompt` request with `StopReason::Cancelled`
+    ///
+    /// See protocol docs: [Cancellation](https://agentclientprotocol.com/protocol/prompt-turn#cancellation)
+    CancelNotification(CancelNotification),
+    #[cfg(feature = "unstable_nes")]
+    /// **UNSTABLE**
+    ///
+    /// Notification sent when a file is opened in the editor.
+    DidOpenDocumentNotification(DidOpenDocumentNotification),
+    #[cfg(feature = "unstable_nes")]
+    /// **UNSTABLE**
+    ///
+    /// Notification sent when a file is edited.
+    DidChangeDocumentNotification(DidChangeDocumentNotification),
+    #[cfg(feature = "unstable_nes")]
+    /// **UNSTABLE**
+    ///
+    /// Notification sent when a file is closed.
+    DidCloseDocumentNotification(DidCloseDocumentNotification),
+    #[cfg(feature = "unstable_nes")]
+    /// **UNSTABLE**
+    ///
+    /// Notification sent when a file is saved.
+    DidSaveDocumentNotification(DidSaveDocumentNotification),
+    #[cfg(feature = "unstable_nes")]
+    /// **UNSTABLE**
+    ///
+    /// Notification sent when a file becomes the active editor tab.
+    DidFocusDocumentNotification(DidFocusDocumentNotification),
+    #[cfg(feature = "unstable_nes")]
+    /// **UNSTABLE**
+    ///
+    /// Notification sent when a suggestion is accepted.
+    AcceptNesNotification(AcceptNesNotification),
+    #[cfg(feature = "unstable_nes")]
+    /// **UNSTABLE**
+    ///
+    /// Notification sent when a suggestion is rejected.
+    RejectNesNotification(RejectNesNotification),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Sends an MCP-over-ACP notification.
+    #[cfg(feature = "unstable_mcp_over_acp")]
+    MessageMcpNotification(MessageMcpNotification),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Reports a change to a file under a path the agent asked to watch with `fs/watch`.
+    #[cfg(feature = "unstable_fs_watch")]
+    FileChangedNotification(FileChangedNotification),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Reports that a tool call's diff could not be applied because the file changed.
+    #[cfg(feature = "unstable_edit_conflict")]
+    ReportEditConflictNotification(ReportEditConflictNotification),
+    /// Handles extension notifications from the client.
+    ///
+    /// Extension notifications provide a way to send one-way messages for custom functionality
+    /// while maintaining protocol compatibility.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    ExtNotification(ExtNotification),
+}
+
+impl ClientNotification {
+    /// Returns the corresponding method name of the notification.
+    #[must_use]
+    pub fn method(&self) -> &str {
+        match self {
+            Self::CancelNotification(_) => AGENT_METHOD_NAMES.session_cancel,
+            #[cfg(feature = "unstable_nes")]
+            Self::DidOpenDocumentNotification(_) => AGENT_METHOD_NAMES.document_did_open,
+            #[cfg(feature = "unstable_nes")]
+            Self::DidChangeDocumentNotification(_) => AGENT_METHOD_NAMES.document_did_change,
+            #[cfg(feature = "unstable_nes")]
+            Self::DidCloseDocumentNotification(_) => AGENT_METHOD_NAMES.document_did_close,
+            #[cfg(feature = "unstable_nes")]
+            Self::DidSaveDocumentNotification(_) => AGENT_METHOD_NAMES.document_did_save,
+            #[cfg(feature = "unstable_nes")]
+            Self::DidFocusDocumentNotification(_) => AGENT_METHOD_NAMES.document_did_focus,
+            #[cfg(feature = "unstable_nes")]
+            Self::AcceptNesNotification(_) => AGENT_METHOD_NAMES.nes_accept,
+            #[cfg(feature = "unstable_nes")]
+            Self::RejectNesNotification(_) => AGENT_METHOD_NAMES.nes_reject,
+            #[cfg(feature = "unstable_mcp_over_acp")]
+            Self::MessageMcpNotification(_) => AGENT_METHOD_NAMES.mcp_message,
+            #[cfg(feature = "unstable_fs_watch")]
+            Self::FileChangedNotification(_) => AGENT_METHOD_NAMES.fs_file_changed,
+            #[cfg(feature = "unstable_edit_conflict")]
+            Self::ReportEditConflictNotification(_) => {
+                AGENT_METHOD_NAMES.session_report_edit_conflict
+            }
+            Self::ExtNotification(ext_notification) => &ext_notification.method,
+        }
+    }
+}
+
+/// Notification to cancel ongoing operations for a session.
+///
+/// See protocol docs: [Cancellation](https://agentclientprotocol.com/protocol/prompt-turn#cancellation)
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_CANCEL_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CancelNotification {
+    /// The ID of the session to cancel operations for.
+    pub session_id: SessionId,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Why the sender is cancelling.
+    ///
+    /// Optional. Receivers MUST NOT require it and SHOULD treat an omitted or
+    /// unrecognized reason the same as a plain cancellation.
+    #[cfg(feature = "unstable_cancel_reason")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub reason: Option<CancelReason>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl CancelNotification {
+    /// Builds [`CancelNotification`] with the required notification fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(session_id: impl Into<SessionId>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            #[cfg(feature = "unstable_cancel_reason")]
+            reason: None,
+            meta: None,
+        }
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Why the sender is cancelling.
+    #[cfg(feature = "unstable_cancel_reason")]
+    #[must_use]
+    pub fn reason(mut self, reason: impl IntoOption<CancelReason>) -> Self {
+        self.reason = reason.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Notification that a tool call's diff could not be applied because the file
+/// changed since the agent produced it.
+///
+/// Clients send it instead of applying the stale diff, so the agent can re-read
+/// the file and re-plan the edit.
+///
+/// See protocol docs: [Edit Conflicts](https://agentclientprotocol.com/protocol/tool-calls#edit-conflicts)
+#[cfg(feature = "unstable_edit_conflict")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_REPORT_EDIT_CONFLICT_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ReportEditConflictNotification {
+    /// The session the tool call belongs to.
+    pub session_id: SessionId,
+    /// The tool call whose diff could not be applied.
+    pub tool_call_id: ToolCallId,
+    /// What the diff expected and what the client found.
+    pub report: ConflictReport,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_edit_conflict")]
+impl ReportEditConflictNotification {
+    /// Builds [`ReportEditConflictNotification`] with the required notification fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(
+        session_id: impl Into<SessionId>,
+        tool_call_id: impl Into<ToolCallId>,
+        report: ConflictReport,
+    ) -> Self {
+        Self {
+            session_id: session_id.into(),
+            tool_call_id: tool_call_id.into(),
+            report,
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+#[cfg(test)]
+mod test_serialization {
+    use super::*;
+    use serde_json::json;
+
+    #[cfg(feature = "unstable_boolean_config")]
+    fn test_meta() -> Meta {
+        json!({ "source": "test" }).as_object().unwrap().clone()
+    }
+
+    #[cfg(feature = "unstable_boolean_config")]
+    fn serialized_meta_key_count(value: &impl serde::Serialize) -> usize {
+        serde_json::to_string(value)
+            .unwrap()
+            .matches("\"_meta\"")
+            .count()
+    }
+
+    #[test]
+    fn test_initialize_capabilities_default_on_malformed_values() {
+        let request: InitializeRequest = serde_json::from_value(json!({
+            "protocolVersion": 1,
+            "clientCapabilities": false
+        }))
+        .unwrap();
+        assert_eq!(request.client_capabilities, ClientCapabilities::default());
+
+        let response: InitializeResponse = serde_json::from_value(json!({
+            "protocolVersion": 1,
+            "agentCapabilities": false
+        }))
+        .unwrap();
+        assert_eq!(response.agent_capabilities, AgentCapabilities::default());
+    }
+
+    #[test]
+    fn test_agent_capabilities_default_on_malformed_values() {
+        let capabilities: AgentCapabilities = serde_json::from_value(json!({
+            "loadSession": "yes",
+            "promptCapabilities": {
+                "image": "yes",
+                "audio": true,
+                "embeddedContext": {}
+            },
+            "mcpCapabilities": {
+                "http": "yes",
+                "sse": true
+            },
+            "sessionCapabilities": false,
+            "auth": false
+        }))
+        .unwrap();
+
+        assert!(!capabilities.load_session);
+        assert!(!capabilities.prompt_capabilities.image);
+        assert!(capabilities.prompt_capabilities.audio);
+        assert!(!capabilities.prompt_capabilities.embedded_context);
+        assert!(!capabilities.mcp_capabilities.http);
+        assert!(capabilities.mcp_capabilities.sse);
+        assert_eq!(
+            capabilities.session_capabilities,
+            SessionCapabilities::default()
+        );
+        assert_eq!(capabilities.auth, AgentAuthCapabilities::default());
+    }
+
+    #[test]
+    fn test_mcp_server_stdio_serialization() {
+        let server = McpServer::Stdio(
+            McpServerStdio::new("test-server", "/usr/bin/server")
+                .args(vec!["--port".to_string(), "3000".to_string()])
+                .env(vec![EnvVariable::new("API_KEY", "secret123")]),
+        );
+
+        let json = serde_json::to_value(&server).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "name": "test-server",
+                "command": "/usr/bin/server",
+                "args": ["--port", "3000"],
+                "env": [
+                    {
+                        "name": "API_KEY",
+                        "value": "secret123"
+                    }
+                ]
+            })
+        );
+
+        let deserialized: McpServer = serde_json::from_value(json).unwrap();
+        match deserialized {
+            McpServer::Stdio(McpServerStdio {
+                name,
+                command,
+                args,
+                env,
+                meta: _,
+            }) => {
+                assert_eq!(name, "test-server");
+                assert_eq!(command, PathBuf::from("/usr/bin/server"));
+                assert_eq!(args, vec!["--port", "3000"]);
+                assert_eq!(env.len(), 1);
+                assert_eq!(env[0].name, "API_KEY");
+                assert_eq!(env[0].value, "secret123");
+            }
+            _ => panic!("Expected Stdio variant"),
+        }
+    }
+
+    #[test]
+    fn test_mcp_server_http_serialization() {
+        let server = McpServer::Http(
+            McpServerHttp::new("http-server", "https://api.example.com").headers(vec![
+                HttpHeader::new("Authorization", "Bearer token123"),
+                HttpHeader::new("Content-Type", "application/json"),
+            ]),
+        );
+
+        let json = serde_json::to_value(&server).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "type": "http",
+                "name": "http-server",
+                "url": "https://api.example.com",
+                "headers": [
+                    {
+                        "name": "Authorization",
+                        "value": "Bearer token123"
+                    },
+                    {
+                        "name": "Content-Type",
+                        "value": "application/json"
+                    }
+                ]
+            })
+        );
+
+        let deserialized: McpServer = serde_json::from_value(json).unwrap();
+        match deserialized {
+            McpServer::Http(McpServerHttp {
+                name,
+                url,
+                headers,
+                meta: _,
+            }) => {
+                assert_eq!(name, "http-server");
+                assert_eq!(url, "https://api.example.com");
+                assert_eq!(headers.len(), 2);
+                assert_eq!(headers[0].name, "Authorization");
+                assert_eq!(headers[0].value, "Bearer token123");
+                assert_eq!(headers[1].name, "Content-Type");
+                assert_eq!(headers[1].value, "application/json");
+            }
+            _ => panic!("Expected Http variant"),
+        }
+    }
+
+    #[cfg(feature = "unstable_mcp_over_acp")]
+    #[test]
+    fn test_mcp_server_acp_serialization() {
+        let server = McpServer::Acp(McpServerAcp::new("project-tools", "project-tools-id"));
+
+        let json = serde_json::to_value(&server).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "type": "acp",
+                "name": "project-tools",
+                "serverId": "project-tools-id"
+            })
+        );
+
+        let deserialized: McpServer = serde_json::from_value(json).unwrap();
+        match deserialized {
+            McpServer::Acp(McpServerAcp {
+                name,
+                server_id: id,
+                meta: _,
+            }) => {
+                assert_eq!(name, "project-tools");
+                assert_eq!(id, McpServerAcpId::new("project-tools-id"));
+            }
+            _ => panic!("Expected Acp variant"),
+        }
+    }
+
+    #[cfg(feature = "unstable_mcp_over_acp")]
+    #[test]
+    fn test_client_mcp_message_method_names() {
+        assert_eq!(AGENT_METHOD_NAMES.mcp_message, "mcp/message");
+
+        assert_eq!(
+            ClientRequest::MessageMcpRequest(MessageMcpRequest::new("conn-1", "tools/list"))
+                .method(),
+            "mcp/message"
+        );
+        assert_eq!(
+            ClientNotification::MessageMcpNotification(MessageMcpNotification::new(
+                "conn-1",
+                "notifications/progress"
+            ))
+            .method(),
+            "mcp/message"
+        );
+    }
+
+    #[cfg(feature = "unstable_mcp_over_acp")]
+    #[test]
+    fn test_mcp_server_acp_schema() {
+        let mcp_server_schema = serde_json::to_value(schemars::schema_for!(McpServer)).unwrap();
+        assert!(json_contains_entry(
+            &mcp_server_schema,
+            "const",
+            &json!("acp")
+        ));
+        assert!(json_contains_entry(
+            &mcp_server_schema,
+            "$ref",
+            &json!("#/$defs/McpServerAcp")
+        ));
+
+        let capabilities_schema =
+            serde_json::to_value(schemars::schema_for!(McpCapabilities)).unwrap();
+        assert!(json_contains_key(&capabilities_schema, "acp"));
+    }
+
+    #[cfg(feature = "unstable_mcp_over_acp")]
+    fn json_contains_entry(
+        value: &serde_json::Value,
+        key: &str,
+        expected: &serde_json::Value,
+    ) -> bool {
+        match value {
+            serde_json::Value::Object(map) => {
+                map.get(key) == Some(expected)
+                    || map
+                        .values()
+                        .any(|value| json_contains_entry(value, key, expected))
+            }
+            serde_json::Value::Array(values) => values
+                .iter()
+                .any(|value| json_contains_entry(value, key, expected)),
+            _ => false,
+        }
+    }
+
+    #[cfg(feature = "unstable_mcp_over_acp")]
+    fn json_contains_key(value: &serde_json::Value, key: &str) -> bool {
+        match value {
+            serde_json::Value::Object(map) => {
+                map.contains_key(key) || map.values().any(|value| json_contains_key(value, key))
+            }
+            serde_json::Value::Array(values) => {
+                values.iter().any(|value| json_contains_key(value, key))
+            }
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_mcp_server_sse_serialization() {
+        let server = McpServer::Sse(
+            McpServerSse::new("sse-server", "https://sse.example.com/events")
+                .headers(vec![HttpHeader::new("X-API-Key", "apikey456")]),
+        );
+
+        let json = serde_json::to_value(&server).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "type": "sse",
+                "name": "sse-server",
+                "url": "https://sse.example.com/events",
+                "headers": [
+                    {
+                        "name": "X-API-Key",
+                        "value": "apikey456"
+                    }
+                ]
+            })
+        );
+
+        let deserialized: McpServer = serde_json::from_value(json).unwrap();
+        match deserialized {
+            McpServer::Sse(McpServerSse {
+                name,
+                url,
+                headers,
+                meta: _,
+            }) => {
+                assert_eq!(name, "sse-server");
+                assert_eq!(url, "https://sse.example.com/events");
+                assert_eq!(headers.len(), 1);
+                assert_eq!(headers[0].name, "X-API-Key");
+                assert_eq!(headers[0].value, "apikey456");
+            }
+            _ => panic!("Expected Sse variant"),
+        }
+    }
+
+    #[test]
+    fn test_session_config_option_category_known_variants() {
+        // Test serialization of known variants
+        assert_eq!(
+            serde_json::to_value(&SessionConfigOptionCategory::Mode).unwrap(),
+            json!("mode")
+        );
+        assert_eq!(
+            serde_json::to_value(&SessionConfigOptionCategory::Model).unwrap(),
+            json!("model")
+        );
+        assert_eq!(
+            serde_json::to_value(&SessionConfigOptionCategory::ModelConfig).unwrap(),
+            json!("model_config")
+        );
+        assert_eq!(
+            serde_json::to_value(&SessionConfigOptionCategory::ThoughtLevel).unwrap(),
+            json!("thought_level")
+        );
+
+        // Test deserialization of known variants
+        assert_eq!(
+            serde_json::from_str::<SessionConfigOptionCategory>("\"mode\"").unwrap(),
+            SessionConfigOptionCategory::Mode
+        );
+        assert_eq!(
+            serde_json::from_str::<SessionConfigOptionCategory>("\"model\"").unwrap(),
+            SessionConfigOptionCategory::Model
+        );
+        assert_eq!(
+            serde_json::from_str::<SessionConfigOptionCategory>("\"model_config\"").unwrap(),
+            SessionConfigOptionCategory::ModelConfig
+        );
+        assert_eq!(
+            serde_json::from_str::<SessionConfigOptionCategory>("\"thought_level\"").unwrap(),
+            SessionConfigOptionCategory::ThoughtLevel
+        );
+    }
+
+    #[test]
+    fn test_session_config_option_category_unknown_variants() {
+        // Test that unknown strings are captured in Other variant
+        let unknown: SessionConfigOptionCategory =
+            serde_json::from_str("\"some_future_category\"").unwrap();
+        assert_eq!(
+            unknown,
+            SessionConfigOptionCategory::Other("some_future_category".to_string())
+        );
+
+        // Test round-trip of unknown category
+        let json = serde_json::to_value(&unknown).unwrap();
+        assert_eq!(json, json!("some_future_category"));
+    }
+
+    #[test]
+    fn test_session_config_option_category_custom_categories() {
+        // Category names beginning with `_` are free for custom use
+        let custom: SessionConfigOptionCategory =
+            serde_json::from_str("\"_my_custom_category\"").unwrap();
+        assert_eq!(
+            custom,
+            SessionConfigOptionCategory::Other("_my_custom_category".to_string())
+        );
+
+        // Test round-trip preserves the custom category name
+        let json = serde_json::to_value(&custom).unwrap();
+        assert_eq!(json, json!("_my_custom_category"));
+
+        // Deserialize back and verify
+        let deserialized: SessionConfigOptionCategory = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            deserialized,
+            SessionConfigOptionCategory::Other("_my_custom_category".to_string()),
+        );
+    }
+
+    #[test]
+    fn test_auth_method_agent_serialization() {
+        let method = AuthMethod::Agent(AuthMethodAgent::new("default-auth", "Default Auth"));
+
+        let json = serde_json::to_value(&method).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "id": "default-auth",
+                "name": "Default Auth"
+            })
+        );
+        // description should be omitted when None
+        assert!(!json.as_object().unwrap().contains_key("description"));
+        // Agent variant should not emit a `type` field (backward compat)
+        assert!(!json.as_object().unwrap().contains_key("type"));
+
+        let deserialized: AuthMethod = serde_json::from_value(json).unwrap();
+        match deserialized {
+            AuthMethod::Agent(AuthMethodAgent { id, name, .. }) => {
+                assert_eq!(id.0.as_ref(), "default-auth");
+                assert_eq!(name, "Default Auth");
+            }
+            #[cfg(feature = "unstable_auth_methods")]
+            _ => panic!("Expected Agent variant"),
+        }
+    }
+
+    #[test]
+    fn test_auth_method_explicit_agent_deserialization() {
+        // An explicit `"type": "agent"` should also deserialize to Agent
+        let json = json!({
+            "id": "agent-auth",
+            "name": "Agent Auth",
+            "type": "agent"
+        });
+
+        let deserialized: AuthMethod = serde_json::from_value(json).unwrap();
+        assert!(matches!(deserialized, AuthMethod::Agent(_)));
+    }
+
+    #[test]
+    fn test_session_delete_serialization() {
+        assert_eq!(AGENT_METHOD_NAMES.session_delete, "session/delete");
+        assert_eq!(
+            ClientRequest::DeleteSessionRequest(DeleteSessionRequest::new("sess_abc123")).method(),
+            "session/delete"
+        );
+        assert_eq!(
+            serde_json::to_value(DeleteSessionRequest::new("sess_abc123")).unwrap(),
+            json!({
+                "sessionId": "sess_abc123"
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(DeleteSessionResponse::new()).unwrap(),
+            json!({})
+        );
+        assert_eq!(
+            serde_json::to_value(
+                SessionCapabilities::new().delete(SessionDeleteCapabilities::new())
+            )
+            .unwrap(),
+            json!({
+                "delete": {}
+            })
+        );
+    }
+
+    #[test]
+    fn test_session_additional_directories_serialization() {
+        assert_eq!(
+            serde_json::to_value(NewSessionRequest::new("/home/user/project")).unwrap(),
+            json!({
+                "cwd": "/home/user/project",
+                "mcpServers": []
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(
+                NewSessionRequest::new("/home/user/project").additional_directories(vec![
+                    PathBuf::from("/home/user/shared-lib"),
+                    PathBuf::from("/home/user/product-docs"),
+                ])
+            )
+            .unwrap(),
+            json!({
+                "cwd": "/home/user/project",
+                "additionalDirectories": [
+                    "/home/user/shared-lib",
+                    "/home/user/product-docs"
+                ],
+                "mcpServers": []
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(SessionInfo::new("sess_abc123", "/home/user/project")).unwrap(),
+            json!({
+                "sessionId": "sess_abc123",
+                "cwd": "/home/user/project"
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(
+                SessionInfo::new("sess_abc123", "/home/user/project").additional_directories(vec![
+                    PathBuf::from("/home/user/shared-lib"),
+                    PathBuf::from("/home/user/product-docs"),
+                ])
+            )
+            .unwrap(),
+            json!({
+                "sessionId": "sess_abc123",
+                "cwd": "/home/user/project",
+                "additionalDirectories": [
+                    "/home/user/shared-lib",
+                    "/home/user/product-docs"
+                ]
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SessionInfo>(json!({
+                "sessionId": "sess_abc123",
+                "cwd": "/home/user/project"
+            }))
+            .unwrap()
+            .additional_directories,
+            Vec::<PathBuf>::new()
+        );
+    }
 
-    #[cfg(feature = "unstable_boolean_config")]
-    fn test_meta() -> Meta {
-        json!({ "source": "test" }).as_object().unwrap().clone()
+    #[test]
+    fn test_list_sessions_response_round_trip() {
+        let response = ListSessionsResponse::new(vec![
+            SessionInfo::new("sess_abc123", "/home/user/project")
+                .title("Fix flaky test")
+                .updated_at("2025-10-29T14:22:15Z"),
+            SessionInfo::new("sess_def456", "/home/user/other"),
+        ]);
+        let json =
+            serde_json::to_value(AgentResponse::ListSessionsResponse(response.clone())).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessions": [
+                    {
+                        "sessionId": "sess_abc123",
+                        "cwd": "/home/user/project",
+                        "title": "Fix flaky test",
+                        "updatedAt": "2025-10-29T14:22:15Z"
+                    },
+                    {
+                        "sessionId": "sess_def456",
+                        "cwd": "/home/user/other"
+                    }
+                ]
+            })
+        );
+        let decoded: ListSessionsResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, response);
+        assert_eq!(decoded.sessions[1].title, None);
+        assert_eq!(
+            ClientRequest::ListSessionsRequest(ListSessionsRequest::new()).method(),
+            AGENT_METHOD_NAMES.session_list
+        );
+        assert_eq!(AGENT_METHOD_NAMES.session_list, "session/list");
     }
 
-    #[cfg(feature = "unstable_boolean_config")]
-    fn serialized_meta_key_count(value: &impl serde::Serialize) -> usize {
-        serde_json::to_string(value)
-            .unwrap()
-            .matches("\"_meta\"")
-            .count()
+    #[test]
+    fn test_session_additional_directories_capabilities_serialization() {
+        assert_eq!(
+            serde_json::to_value(
+                SessionCapabilities::new()
+                    .additional_directories(SessionAdditionalDirectoriesCapabilities::new())
+            )
+            .unwrap(),
+            json!({
+                "additionalDirectories": {}
+            })
+        );
     }
 
+    #[cfg(feature = "unstable_auth_methods")]
     #[test]
-    fn test_initialize_capabilities_default_on_malformed_values() {
-        let request: InitializeRequest = serde_json::from_value(json!({
-            "protocolVersion": 1,
-            "clientCapabilities": false
-        }))
-        .unwrap();
-        assert_eq!(request.client_capabilities, ClientCapabilities::default());
+    fn test_auth_method_env_var_serialization() {
+        let method = AuthMethod::EnvVar(AuthMethodEnvVar::new(
+            "api-key",
+            "API Key",
+            vec![AuthEnvVar::new("API_KEY")],
+        ));
 
-        let response: InitializeResponse = serde_json::from_value(json!({
-            "protocolVersion": 1,
-            "agentCapabilities": false
-        }))
-        .unwrap();
-        assert_eq!(response.agent_capabilities, AgentCapabilities::default());
+        let json = serde_json::to_value(&method).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "id": "api-key",
+                "name": "API Key",
+                "type": "env_var",
+                "vars": [{"name": "API_KEY"}]
+            })
+        );
+        // secret defaults to true and should be omitted; optional defaults to false and should be omitted
+        assert!(!json["vars"][0].as_object().unwrap().contains_key("secret"));
+        assert!(
+            !json["vars"][0]
+                .as_object()
+                .unwrap()
+                .contains_key("optional")
+        );
+
+        let deserialized: AuthMethod = serde_json::from_value(json).unwrap();
+        match deserialized {
+            AuthMethod::EnvVar(AuthMethodEnvVar {
+                id,
+                name: method_name,
+                vars,
+                link,
+                ..
+            }) => {
+                assert_eq!(id.0.as_ref(), "api-key");
+                assert_eq!(method_name, "API Key");
+                assert_eq!(vars.len(), 1);
+                assert_eq!(vars[0].name, "API_KEY");
+                assert!(vars[0].secret);
+                assert!(!vars[0].optional);
+                assert!(link.is_none());
+            }
+            _ => panic!("Expected EnvVar variant"),
+        }
     }
 
+    #[cfg(feature = "unstable_auth_methods")]
     #[test]
-    fn test_agent_capabilities_default_on_malformed_values() {
-        let capabilities: AgentCapabilities = serde_json::from_value(json!({
-            "loadSession": "yes",
-            "promptCapabilities": {
-                "image": "yes",
-                "audio": true,
-                "embeddedContext": {}
-            },
-            "mcpCapabilities": {
-                "http": "yes",
-                "sse": true
-            },
-            "sessionCapabilities": false,
-            "auth": false
-        }))
-        .unwrap();
+    fn test_auth_method_env_var_with_link_serialization() {
+        let method = AuthMethod::EnvVar(
+            AuthMethodEnvVar::new("api-key", "API Key", vec![AuthEnvVar::new("API_KEY")])
+                .link("https://example.com/keys"),
+        );
 
-        assert!(!capabilities.load_session);
-        assert!(!capabilities.prompt_capabilities.image);
-        assert!(capabilities.prompt_capabilities.audio);
-        assert!(!capabilities.prompt_capabilities.embedded_context);
-        assert!(!capabilities.mcp_capabilities.http);
-        assert!(capabilities.mcp_capabilities.sse);
+        let json = serde_json::to_value(&method).unwrap();
         assert_eq!(
-            capabilities.session_capabilities,
-            SessionCapabilities::default()
+            json,
+            json!({
+                "id": "api-key",
+                "name": "API Key",
+                "type": "env_var",
+                "vars": [{"name": "API_KEY"}],
+                "link": "https://example.com/keys"
+            })
         );
-        assert_eq!(capabilities.auth, AgentAuthCapabilities::default());
+
+        let deserialized: AuthMethod = serde_json::from_value(json).unwrap();
+        match deserialized {
+            AuthMethod::EnvVar(AuthMethodEnvVar { link, .. }) => {
+                assert_eq!(link.as_deref(), Some("https://example.com/keys"));
+            }
+            _ => panic!("Expected EnvVar variant"),
+        }
     }
 
+    #[cfg(feature = "unstable_auth_methods")]
     #[test]
-    fn test_mcp_server_stdio_serialization() {
-        let server = McpServer::Stdio(
-            McpServerStdio::new("test-server", "/usr/bin/server")
-                .args(vec!["--port".to_string(), "3000".to_string()])
-                .env(vec![EnvVariable::new("API_KEY", "secret123")]),
-        );
+    fn test_auth_method_env_var_multiple_vars() {
+        let method = AuthMethod::EnvVar(AuthMethodEnvVar::new(
+            "azure-openai",
+            "Azure OpenAI",
+            vec![
+                AuthEnvVar::new("AZURE_OPENAI_API_KEY").label("API Key"),
+                AuthEnvVar::new("AZURE_OPENAI_ENDPOINT")
+                    .label("Endpoint URL")
+                    .secret(false),
+                AuthEnvVar::new("AZURE_OPENAI_API_VERSION")
+                    .label("API Version")
+                    .secret(false)
+                    .optional(true),
+            ],
+        ));
 
-        let json = serde_json::to_value(&server).unwrap();
+        let json = serde_json::to_value(&method).unwrap();
         assert_eq!(
             json,
             json!({
-                "name": "test-server",
-                "command": "/usr/bin/server",
-                "args": ["--port", "3000"],
-                "env": [
-                    {
-                        "name": "API_KEY",
-                        "value": "secret123"
-                    }
+                "id": "azure-openai",
+                "name": "Azure OpenAI",
+                "type": "env_var",
+                "vars": [
+                    {"name": "AZURE_OPENAI_API_KEY", "label": "API Key"},
+                    {"name": "AZURE_OPENAI_ENDPOINT", "label": "Endpoint URL", "secret": false},
+                    {"name": "AZURE_OPENAI_API_VERSION", "label": "API Version", "secret": false, "optional": true}
                 ]
             })
         );
 
-        let deserialized: McpServer = serde_json::from_value(json).unwrap();
+        let deserialized: AuthMethod = serde_json::from_value(json).unwrap();
         match deserialized {
-            McpServer::Stdio(McpServerStdio {
-                name,
-                command,
-                args,
-                env,
-                meta: _,
-            }) => {
-                assert_eq!(name, "test-server");
-                assert_eq!(command, PathBuf::from("/usr/bin/server"));
-                assert_eq!(args, vec!["--port", "3000"]);
-                assert_eq!(env.len(), 1);
-                assert_eq!(env[0].name, "API_KEY");
-                assert_eq!(env[0].value, "secret123");
+            AuthMethod::EnvVar(AuthMethodEnvVar { vars, .. }) => {
+                assert_eq!(vars.len(), 3);
+                // First var: secret (default true), not optional (default false)
+                assert_eq!(vars[0].name, "AZURE_OPENAI_API_KEY");
+                assert_eq!(vars[0].label.as_deref(), Some("API Key"));
+                assert!(vars[0].secret);
+                assert!(!vars[0].optional);
+                // Second var: not a secret, not optional
+                assert_eq!(vars[1].name, "AZURE_OPENAI_ENDPOINT");
+                assert!(!vars[1].secret);
+                assert!(!vars[1].optional);
+                // Third var: not a secret, optional
+                assert_eq!(vars[2].name, "AZURE_OPENAI_API_VERSION");
+                assert!(!vars[2].secret);
+                assert!(vars[2].optional);
             }
-            _ => panic!("Expected Stdio variant"),
+            _ => panic!("Expected EnvVar variant"),
         }
     }
 
+    #[cfg(feature = "unstable_auth_methods")]
     #[test]
-    fn test_mcp_server_http_serialization() {
-        let server = McpServer::Http(
-            McpServerHttp::new("http-server", "https://api.example.com").headers(vec![
-                HttpHeader::new("Authorization", "Bearer token123"),
-                HttpHeader::new("Content-Type", "application/json"),
-            ]),
-        );
+    fn test_auth_method_terminal_serialization() {
+        let method = AuthMethod::Terminal(AuthMethodTerminal::new("tui-auth", "Terminal Auth"));
 
-        let json = serde_json::to_value(&server).unwrap();
+        let json = serde_json::to_value(&method).unwrap();
         assert_eq!(
             json,
             json!({
-                "type": "http",
-                "name": "http-server",
-                "url": "https://api.example.com",
-                "headers": [
-                    {
-                        "name": "Authorization",
-                        "value": "Bearer token123"
-                    },
-                    {
-                        "name": "Content-Type",
-                        "value": "application/json"
-                    }
-                ]
+                "id": "tui-auth",
+                "name": "Terminal Auth",
+                "type": "terminal"
             })
         );
+        // args and env should be omitted when empty
+        assert!(!json.as_object().unwrap().contains_key("args"));
+        assert!(!json.as_object().unwrap().contains_key("env"));
 
-        let deserialized: McpServer = serde_json::from_value(json).unwrap();
+        let deserialized: AuthMethod = serde_json::from_value(json).unwrap();
         match deserialized {
-            McpServer::Http(McpServerHttp {
-                name,
-                url,
-                headers,
-                meta: _,
-            }) => {
-                assert_eq!(name, "http-server");
-                assert_eq!(url, "https://api.example.com");
-                assert_eq!(headers.len(), 2);
-                assert_eq!(headers[0].name, "Authorization");
-                assert_eq!(headers[0].value, "Bearer token123");
-                assert_eq!(headers[1].name, "Content-Type");
-                assert_eq!(headers[1].value, "application/json");
+            AuthMethod::Terminal(AuthMethodTerminal { args, env, .. }) => {
+                assert!(args.is_empty());
+                assert!(env.is_empty());
             }
-            _ => panic!("Expected Http variant"),
+            _ => panic!("Expected Terminal variant"),
         }
     }
 
-    #[cfg(feature = "unstable_mcp_over_acp")]
+    #[cfg(feature = "unstable_auth_methods")]
     #[test]
-    fn test_mcp_server_acp_serialization() {
-        let server = McpServer::Acp(McpServerAcp::new("project-tools", "project-tools-id"));
+    fn test_auth_method_terminal_with_args_and_env_serialization() {
+        use std::collections::HashMap;
 
-        let json = serde_json::to_value(&server).unwrap();
+        let mut env = HashMap::new();
+        env.insert("TERM".to_string(), "xterm-256color".to_string());
+
+        let method = AuthMethod::Terminal(
+            AuthMethodTerminal::new("tui-auth", "Terminal Auth")
+                .args(vec!["--interactive".to_string(), "--color".to_string()])
+                .env(env),
+        );
+
+        let json = serde_json::to_value(&method).unwrap();
         assert_eq!(
             json,
             json!({
-                "type": "acp",
-                "name": "project-tools",
-                "serverId": "project-tools-id"
+                "id": "tui-auth",
+                "name": "Terminal Auth",
+                "type": "terminal",
+                "args": ["--interactive", "--color"],
+                "env": {
+                    "TERM": "xterm-256color"
+                }
             })
         );
 
-        let deserialized: McpServer = serde_json::from_value(json).unwrap();
+        let deserialized: AuthMethod = serde_json::from_value(json).unwrap();
         match deserialized {
-            McpServer::Acp(McpServerAcp {
-                name,
-                server_id: id,
-                meta: _,
-            }) => {
-                assert_eq!(name, "project-tools");
-                assert_eq!(id, McpServerAcpId::new("project-tools-id"));
+            AuthMethod::Terminal(AuthMethodTerminal { args, env, .. }) => {
+                assert_eq!(args, vec!["--interactive", "--color"]);
+                assert_eq!(env.len(), 1);
+                assert_eq!(env.get("TERM").unwrap(), "xterm-256color");
             }
-            _ => panic!("Expected Acp variant"),
+            _ => panic!("Expected Terminal variant"),
         }
     }
 
-    #[cfg(feature = "unstable_mcp_over_acp")]
+    #[cfg(feature = "unstable_boolean_config")]
     #[test]
-    fn test_client_mcp_message_method_names() {
-        assert_eq!(AGENT_METHOD_NAMES.mcp_message, "mcp/message");
-
-        assert_eq!(
-            ClientRequest::MessageMcpRequest(MessageMcpRequest::new("conn-1", "tools/list"))
-                .method(),
-            "mcp/message"
-        );
-        assert_eq!(
-            ClientNotification::MessageMcpNotification(MessageMcpNotification::new(
-                "conn-1",
-                "notifications/progress"
-            ))
-            .method(),
-            "mcp/message"
-        );
+    fn test_session_config_option_value_id_serialize() {
+        let val = SessionConfigOptionValue::value_id("model-1");
+        let json = serde_json::to_value(&val).unwrap();
+        // ValueId omits the "type" field (it's the default)
+        assert_eq!(json, json!({ "value": "model-1" }));
+        assert!(!json.as_object().unwrap().contains_key("type"));
     }
 
-    #[cfg(feature = "unstable_mcp_over_acp")]
+    #[cfg(feature = "unstable_boolean_config")]
     #[test]
-    fn test_mcp_server_acp_schema() {
-        let mcp_server_schema = serde_json::to_value(schemars::schema_for!(McpServer)).unwrap();
-        assert!(json_contains_entry(
-            &mcp_server_schema,
-            "const",
-            &json!("acp")
-        ));
-        assert!(json_contains_entry(
-            &mcp_server_schema,
-            "$ref",
-            &json!("#/$defs/McpServerAcp")
-        ));
-
-        let capabilities_schema =
-            serde_json::to_value(schemars::schema_for!(McpCapabilities)).unwrap();
-        assert!(json_contains_key(&capabilities_schema, "acp"));
+    fn test_session_config_option_value_boolean_serialize() {
+        let val = SessionConfigOptionValue::boolean(true);
+        let json = serde_json::to_value(&val).unwrap();
+        assert_eq!(json, json!({ "type": "boolean", "value": true }));
     }
 
-    #[cfg(feature = "unstable_mcp_over_acp")]
-    fn json_contains_entry(
-        value: &serde_json::Value,
-        key: &str,
-        expected: &serde_json::Value,
-    ) -> bool {
-        match value {
-            serde_json::Value::Object(map) => {
-                map.get(key) == Some(expected)
-                    || map
-                        .values()
-                        .any(|value| json_contains_entry(value, key, expected))
-            }
-            serde_json::Value::Array(values) => values
-                .iter()
-                .any(|value| json_contains_entry(value, key, expected)),
-            _ => false,
-        }
+    #[cfg(feature = "unstable_boolean_config")]
+    #[test]
+    fn test_session_config_option_value_deserialize_no_type() {
+        // Missing "type" should default to ValueId
+        let json = json!({ "value": "model-1" });
+        let val: SessionConfigOptionValue = serde_json::from_value(json).unwrap();
+        assert_eq!(val, SessionConfigOptionValue::value_id("model-1"));
+        assert_eq!(val.as_value_id().unwrap().to_string(), "model-1");
     }
 
-    #[cfg(feature = "unstable_mcp_over_acp")]
-    fn json_contains_key(value: &serde_json::Value, key: &str) -> bool {
-        match value {
-            serde_json::Value::Object(map) => {
-                map.contains_key(key) || map.values().any(|value| json_contains_key(value, key))
-            }
-            serde_json::Value::Array(values) => {
-                values.iter().any(|value| json_contains_key(value, key))
-            }
-            _ => false,
-        }
+    #[cfg(feature = "unstable_boolean_config")]
+    #[test]
+    fn test_session_config_option_value_deserialize_boolean() {
+        let json = json!({ "type": "boolean", "value": true });
+        let val: SessionConfigOptionValue = serde_json::from_value(json).unwrap();
+        assert_eq!(val, SessionConfigOptionValue::boolean(true));
+        assert_eq!(val.as_bool(), Some(true));
     }
 
+    #[cfg(feature = "unstable_boolean_config")]
     #[test]
-    fn test_mcp_server_sse_serialization() {
-        let server = McpServer::Sse(
-            McpServerSse::new("sse-server", "https://sse.example.com/events")
-                .headers(vec![HttpHeader::new("X-API-Key", "apikey456")]),
-        );
-
-        let json = serde_json::to_value(&server).unwrap();
-        assert_eq!(
-            json,
-            json!({
-                "type": "sse",
-                "name": "sse-server",
-                "url": "https://sse.example.com/events",
-                "headers": [
-                    {
-                        "name": "X-API-Key",
-                        "value": "apikey456"
-                    }
-                ]
-            })
-        );
-
-        let deserialized: McpServer = serde_json::from_value(json).unwrap();
-        match deserialized {
-            McpServer::Sse(McpServerSse {
-                name,
-                url,
-                headers,
-                meta: _,
-            }) => {
-                assert_eq!(name, "sse-server");
-                assert_eq!(url, "https://sse.example.com/events");
-                assert_eq!(headers.len(), 1);
-                assert_eq!(headers[0].name, "X-API-Key");
-                assert_eq!(headers[0].value, "apikey456");
-            }
-            _ => panic!("Expected Sse variant"),
-        }
+    fn test_session_config_option_value_deserialize_boolean_false() {
+        let json = json!({ "type": "boolean", "value": false });
+        let val: SessionConfigOptionValue = serde_json::from_value(json).unwrap();
+        assert_eq!(val, SessionConfigOptionValue::boolean(false));
+        assert_eq!(val.as_bool(), Some(false));
     }
 
+    #[cfg(feature = "unstable_boolean_config")]
     #[test]
-    fn test_session_config_option_category_known_variants() {
-        // Test serialization of known variants
-        assert_eq!(
-            serde_json::to_value(&SessionConfigOptionCategory::Mode).unwrap(),
-            json!("mode")
-        );
-        assert_eq!(
-            serde_json::to_value(&SessionConfigOptionCategory::Model).unwrap(),
-            json!("model")
-        );
-        assert_eq!(
-            serde_json::to_value(&SessionConfigOptionCategory::ModelConfig).unwrap(),
-            json!("model_config")
-        );
-        assert_eq!(
-            serde_json::to_value(&SessionConfigOptionCategory::ThoughtLevel).unwrap(),
-            json!("thought_level")
-        );
-
-        // Test deserialization of known variants
-        assert_eq!(
-            serde_json::from_str::<SessionConfigOptionCategory>("\"mode\"").unwrap(),
-            SessionConfigOptionCategory::Mode
-        );
-        assert_eq!(
-            serde_json::from_str::<SessionConfigOptionCategory>("\"model\"").unwrap(),
-            SessionConfigOptionCategory::Model
-        );
-        assert_eq!(
-            serde_json::from_str::<SessionConfigOptionCategory>("\"model_config\"").unwrap(),
-            SessionConfigOptionCategory::ModelConfig
-        );
-        assert_eq!(
-            serde_json::from_str::<SessionConfigOptionCategory>("\"thought_level\"").unwrap(),
-            SessionConfigOptionCategory::ThoughtLevel
-        );
+    fn test_session_config_option_value_deserialize_unknown_type_with_string_value() {
+        // Unknown type with a string value gracefully falls back to ValueId
+        let json = json!({ "type": "text", "value": "freeform input" });
+        let val: SessionConfigOptionValue = serde_json::from_value(json).unwrap();
+        assert_eq!(val.as_value_id().unwrap().to_string(), "freeform input");
     }
 
+    #[cfg(feature = "unstable_boolean_config")]
     #[test]
-    fn test_session_config_option_category_unknown_variants() {
-        // Test that unknown strings are captured in Other variant
-        let unknown: SessionConfigOptionCategory =
-            serde_json::from_str("\"some_future_category\"").unwrap();
-        assert_eq!(
-            unknown,
-            SessionConfigOptionCategory::Other("some_future_category".to_string())
-        );
+    fn test_session_config_option_value_roundtrip_value_id() {
+        let original = SessionConfigOptionValue::value_id("option-a");
+        let json = serde_json::to_value(&original).unwrap();
+        let roundtripped: SessionConfigOptionValue = serde_json::from_value(json).unwrap();
+        assert_eq!(original, roundtripped);
+    }
 
-        // Test round-trip of unknown category
-        let json = serde_json::to_value(&unknown).unwrap();
-        assert_eq!(json, json!("some_future_category"));
+    #[cfg(feature = "unstable_boolean_config")]
+    #[test]
+    fn test_session_config_option_value_roundtrip_boolean() {
+        let original = SessionConfigOptionValue::boolean(false);
+        let json = serde_json::to_value(&original).unwrap();
+        let roundtripped: SessionConfigOptionValue = serde_json::from_value(json).unwrap();
+        assert_eq!(original, roundtripped);
     }
 
+    #[cfg(feature = "unstable_boolean_config")]
     #[test]
-    fn test_session_config_option_category_custom_categories() {
-        // Category names beginning with `_` are free for custom use
-        let custom: SessionConfigOptionCategory =
-            serde_json::from_str("\"_my_custom_category\"").unwrap();
+    fn test_session_config_option_value_type_mismatch_boolean_with_string() {
+        // type says "boolean" but value is a string — falls to untagged ValueId
+        let json = json!({ "type": "boolean", "value": "not a bool" });
+        let result = serde_json::from_value::<SessionConfigOptionValue>(json);
+        // serde tries Boolean first (fails), then falls to untagged ValueId (succeeds)
+        assert!(result.is_ok());
         assert_eq!(
-            custom,
-            SessionConfigOptionCategory::Other("_my_custom_category".to_string())
+            result.unwrap().as_value_id().unwrap().to_string(),
+            "not a bool"
         );
+    }
 
-        // Test round-trip preserves the custom category name
-        let json = serde_json::to_value(&custom).unwrap();
-        assert_eq!(json, json!("_my_custom_category"));
+    #[cfg(feature = "unstable_boolean_config")]
+    #[test]
+    fn test_session_config_option_value_from_impls() {
+        let from_str: SessionConfigOptionValue = "model-1".into();
+        assert_eq!(from_str.as_value_id().unwrap().to_string(), "model-1");
 
-        // Deserialize back and verify
-        let deserialized: SessionConfigOptionCategory = serde_json::from_value(json).unwrap();
-        assert_eq!(
-            deserialized,
-            SessionConfigOptionCategory::Other("_my_custom_category".to_string()),
-        );
+        let from_id: SessionConfigOptionValue = SessionConfigValueId::new("model-2").into();
+        assert_eq!(from_id.as_value_id().unwrap().to_string(), "model-2");
+
+        let from_bool: SessionConfigOptionValue = true.into();
+        assert_eq!(from_bool.as_bool(), Some(true));
     }
 
+    #[cfg(feature = "unstable_boolean_config")]
     #[test]
-    fn test_auth_method_agent_serialization() {
-        let method = AuthMethod::Agent(AuthMethodAgent::new("default-auth", "Default Auth"));
-
-        let json = serde_json::to_value(&method).unwrap();
+    fn test_set_session_config_option_request_value_id() {
+        let req = SetSessionConfigOptionRequest::new("sess_1", "model", "model-1");
+        let json = serde_json::to_value(&req).unwrap();
         assert_eq!(
             json,
             json!({
-                "id": "default-auth",
-                "name": "Default Auth"
+                "sessionId": "sess_1",
+                "configId": "model",
+                "value": "model-1"
             })
         );
-        // description should be omitted when None
-        assert!(!json.as_object().unwrap().contains_key("description"));
-        // Agent variant should not emit a `type` field (backward compat)
+        // No "type" field for value_id
         assert!(!json.as_object().unwrap().contains_key("type"));
+    }
 
-        let deserialized: AuthMethod = serde_json::from_value(json).unwrap();
-        match deserialized {
-            AuthMethod::Agent(AuthMethodAgent { id, name, .. }) => {
-                assert_eq!(id.0.as_ref(), "default-auth");
-                assert_eq!(name, "Default Auth");
-            }
-            #[cfg(feature = "unstable_auth_methods")]
-            _ => panic!("Expected Agent variant"),
-        }
+    #[cfg(feature = "unstable_boolean_config")]
+    #[test]
+    fn test_set_session_config_option_request_boolean() {
+        let req = SetSessionConfigOptionRequest::new("sess_1", "brave_mode", true);
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionId": "sess_1",
+                "configId": "brave_mode",
+                "type": "boolean",
+                "value": true
+            })
+        );
     }
 
+    #[cfg(feature = "unstable_boolean_config")]
     #[test]
-    fn test_auth_method_explicit_agent_deserialization() {
-        // An explicit `"type": "agent"` should also deserialize to Agent
+    fn test_set_session_config_option_request_deserialize_no_type() {
+        // Backwards-compatible: no "type" field → value_id
         let json = json!({
-            "id": "agent-auth",
-            "name": "Agent Auth",
-            "type": "agent"
+            "sessionId": "sess_1",
+            "configId": "model",
+            "value": "model-1"
         });
-
-        let deserialized: AuthMethod = serde_json::from_value(json).unwrap();
-        assert!(matches!(deserialized, AuthMethod::Agent(_)));
+        let req: SetSessionConfigOptionRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(req.session_id.to_string(), "sess_1");
+        assert_eq!(req.config_id.to_string(), "model");
+        assert_eq!(req.value.as_value_id().unwrap().to_string(), "model-1");
     }
 
+    #[cfg(feature = "unstable_boolean_config")]
     #[test]
-    fn test_session_delete_serialization() {
-        assert_eq!(AGENT_METHOD_NAMES.session_delete, "session/delete");
-        assert_eq!(
-            ClientRequest::DeleteSessionRequest(DeleteSessionRequest::new("sess_abc123")).method(),
-            "session/delete"
-        );
-        assert_eq!(
-            serde_json::to_value(DeleteSessionRequest::new("sess_abc123")).unwrap(),
-            json!({
-                "sessionId": "sess_abc123"
-            })
-        );
-        assert_eq!(
-            serde_json::to_value(DeleteSessionResponse::new()).unwrap(),
-            json!({})
-        );
-        assert_eq!(
-            serde_json::to_value(
-                SessionCapabilities::new().delete(SessionDeleteCapabilities::new())
-            )
-            .unwrap(),
-            json!({
-                "delete": {}
-            })
-        );
+    fn test_set_session_config_option_request_deserialize_boolean() {
+        let json = json!({
+            "sessionId": "sess_1",
+            "configId": "brave_mode",
+            "type": "boolean",
+            "value": true
+        });
+        let req: SetSessionConfigOptionRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(req.value.as_bool(), Some(true));
     }
+
+    #[cfg(feature = "unstable_boolean_config")]
     #[test]
-    fn test_session_additional_directories_serialization() {
-        assert_eq!(
-            serde_json::to_value(NewSessionRequest::new("/home/user/project")).unwrap(),
-            json!({
-                "cwd": "/home/user/project",
-                "mcpServers": []
-            })
-        );
-        assert_eq!(
-            serde_json::to_value(
-                NewSessionRequest::new("/home/user/project").additional_directories(vec![
-                    PathBuf::from("/home/user/shared-lib"),
-                    PathBuf::from("/home/user/product-docs"),
-                ])
-            )
-            .unwrap(),
-            json!({
-                "cwd": "/home/user/project",
-                "additionalDirectories": [
-                    "/home/user/shared-lib",
-                    "/home/user/product-docs"
-                ],
-                "mcpServers": []
-            })
-        );
-        assert_eq!(
-            serde_json::to_value(SessionInfo::new("sess_abc123", "/home/user/project")).unwrap(),
-            json!({
-                "sessionId": "sess_abc123",
-                "cwd": "/home/user/project"
-            })
-        );
-        assert_eq!(
-            serde_json::to_value(
-                SessionInfo::new("sess_abc123", "/home/user/project").additional_directories(vec![
-                    PathBuf::from("/home/user/shared-lib"),
-                    PathBuf::from("/home/user/product-docs"),
-                ])
-            )
-            .unwrap(),
-            json!({
-                "sessionId": "sess_abc123",
-                "cwd": "/home/user/project",
-                "additionalDirectories": [
-                    "/home/user/shared-lib",
-                    "/home/user/product-docs"
-                ]
-            })
-        );
-        assert_eq!(
-            serde_json::from_value::<SessionInfo>(json!({
-                "sessionId": "sess_abc123",
-                "cwd": "/home/user/project"
-            }))
-            .unwrap()
-            .additional_directories,
-            Vec::<PathBuf>::new()
-        );
+    fn test_set_session_config_option_request_roundtrip_value_id() {
+        let original = SetSessionConfigOptionRequest::new("s", "c", "v");
+        let json = serde_json::to_value(&original).unwrap();
+        let roundtripped: SetSessionConfigOptionRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(original, roundtripped);
     }
+
+    #[cfg(feature = "unstable_boolean_config")]
     #[test]
-    fn test_session_additional_directories_capabilities_serialization() {
-        assert_eq!(
-            serde_json::to_value(
-                SessionCapabilities::new()
-                    .additional_directories(SessionAdditionalDirectoriesCapabilities::new())
-            )
-            .unwrap(),
-            json!({
-                "additionalDirectories": {}
-            })
-        );
+    fn test_set_session_config_option_request_roundtrip_boolean() {
+        let original = SetSessionConfigOptionRequest::new("s", "c", false);
+        let json = serde_json::to_value(&original).unwrap();
+        let roundtripped: SetSessionConfigOptionRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(original, roundtripped);
     }
 
-    #[cfg(feature = "unstable_auth_methods")]
+    #[cfg(feature = "unstable_boolean_config")]
     #[test]
-    fn test_auth_method_env_var_serialization() {
-        let method = AuthMethod::EnvVar(AuthMethodEnvVar::new(
-            "api-key",
-            "API Key",
-            vec![AuthEnvVar::new("API_KEY")],
-        ));
+    fn test_session_config_boolean_serialization() {
+        let cfg = SessionConfigBoolean::new(true);
+        let json = serde_json::to_value(&cfg).unwrap();
+        assert_eq!(json, json!({ "currentValue": true }));
+
+        let deserialized: SessionConfigBoolean = serde_json::from_value(json).unwrap();
+        assert!(deserialized.current_value);
+    }
+
+    #[cfg(feature = "unstable_boolean_config")]
+    #[test]
+    fn test_session_config_option_boolean_variant() {
+        let opt = SessionConfigOption::boolean("brave_mode", "Brave Mode", false)
+            .description("Skip confirmation prompts")
+            .meta(test_meta());
+        assert_eq!(serialized_meta_key_count(&opt), 1);
 
-        let json = serde_json::to_value(&method).unwrap();
+        let json = serde_json::to_value(&opt).unwrap();
         assert_eq!(
             json,
             json!({
-                "id": "api-key",
-                "name": "API Key",
-                "type": "env_var",
-                "vars": [{"name": "API_KEY"}]
+                "id": "brave_mode",
+                "name": "Brave Mode",
+                "description": "Skip confirmation prompts",
+                "type": "boolean",
+                "currentValue": false,
+                "_meta": {
+                    "source": "test"
+                }
             })
         );
-        // secret defaults to true and should be omitted; optional defaults to false and should be omitted
-        assert!(!json["vars"][0].as_object().unwrap().contains_key("secret"));
-        assert!(
-            !json["vars"][0]
-                .as_object()
-                .unwrap()
-                .contains_key("optional")
-        );
 
-        let deserialized: AuthMethod = serde_json::from_value(json).unwrap();
-        match deserialized {
-            AuthMethod::EnvVar(AuthMethodEnvVar {
-                id,
-                name: method_name,
-                vars,
-                link,
-                ..
-            }) => {
-                assert_eq!(id.0.as_ref(), "api-key");
-                assert_eq!(method_name, "API Key");
-                assert_eq!(vars.len(), 1);
-                assert_eq!(vars[0].name, "API_KEY");
-                assert!(vars[0].secret);
-                assert!(!vars[0].optional);
-                assert!(link.is_none());
-            }
-            _ => panic!("Expected EnvVar variant"),
+        let deserialized: SessionConfigOption = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.id.to_string(), "brave_mode");
+        assert_eq!(deserialized.name, "Brave Mode");
+        match deserialized.kind {
+            SessionConfigKind::Boolean(ref b) => assert!(!b.current_value),
+            _ => panic!("Expected Boolean kind"),
         }
     }
 
-    #[cfg(feature = "unstable_auth_methods")]
+    #[cfg(feature = "unstable_boolean_config")]
     #[test]
-    fn test_auth_method_env_var_with_link_serialization() {
-        let method = AuthMethod::EnvVar(
-            AuthMethodEnvVar::new("api-key", "API Key", vec![AuthEnvVar::new("API_KEY")])
-                .link("https://example.com/keys"),
-        );
+    fn test_session_config_option_select_still_works() {
+        // Make sure existing select options are unaffected
+        let opt = SessionConfigOption::select(
+            "model",
+            "Model",
+            "model-1",
+            vec![
+                SessionConfigSelectOption::new("model-1", "Model 1"),
+                SessionConfigSelectOption::new("model-2", "Model 2"),
+            ],
+        )
+        .meta(test_meta());
+        assert_eq!(serialized_meta_key_count(&opt), 1);
 
-        let json = serde_json::to_value(&method).unwrap();
-        assert_eq!(
-            json,
-            json!({
-                "id": "api-key",
-                "name": "API Key",
-                "type": "env_var",
-                "vars": [{"name": "API_KEY"}],
-                "link": "https://example.com/keys"
-            })
-        );
+        let json = serde_json::to_value(&opt).unwrap();
+        assert_eq!(json["type"], "select");
+        assert_eq!(json["currentValue"], "model-1");
+        assert_eq!(json["options"].as_array().unwrap().len(), 2);
+        assert_eq!(json["_meta"]["source"], "test");
 
-        let deserialized: AuthMethod = serde_json::from_value(json).unwrap();
-        match deserialized {
-            AuthMethod::EnvVar(AuthMethodEnvVar { link, .. }) => {
-                assert_eq!(link.as_deref(), Some("https://example.com/keys"));
+        let deserialized: SessionConfigOption = serde_json::from_value(json).unwrap();
+        match deserialized.kind {
+            SessionConfigKind::Select(ref s) => {
+                assert_eq!(s.current_value.to_string(), "model-1");
             }
-            _ => panic!("Expected EnvVar variant"),
+            _ => panic!("Expected Select kind"),
         }
     }
 
-    #[cfg(feature = "unstable_auth_methods")]
+    #[cfg(feature = "unstable_llm_providers")]
     #[test]
-    fn test_auth_method_env_var_multiple_vars() {
-        let method = AuthMethod::EnvVar(AuthMethodEnvVar::new(
-            "azure-openai",
-            "Azure OpenAI",
-            vec![
-                AuthEnvVar::new("AZURE_OPENAI_API_KEY").label("API Key"),
-                AuthEnvVar::new("AZURE_OPENAI_ENDPOINT")
-                    .label("Endpoint URL")
-                    .secret(false),
-                AuthEnvVar::new("AZURE_OPENAI_API_VERSION")
-                    .label("API Version")
-                    .secret(false)
-                    .optional(true),
-            ],
-        ));
+    fn test_llm_protocol_known_variants() {
+        assert_eq!(
+            serde_json::to_value(&LlmProtocol::Anthropic).unwrap(),
+            json!("anthropic")
+        );
+        assert_eq!(
+            serde_json::to_value(&LlmProtocol::OpenAi).unwrap(),
+            json!("openai")
+        );
+        assert_eq!(
+            serde_json::to_value(&LlmProtocol::Azure).unwrap(),
+            json!("azure")
+        );
+        assert_eq!(
+            serde_json::to_value(&LlmProtocol::Vertex).unwrap(),
+            json!("vertex")
+        );
+        assert_eq!(
+            serde_json::to_value(&LlmProtocol::Bedrock).unwrap(),
+            json!("bedrock")
+        );
 
-        let json = serde_json::to_value(&method).unwrap();
         assert_eq!(
-            json,
-            json!({
-                "id": "azure-openai",
-                "name": "Azure OpenAI",
-                "type": "env_var",
-                "vars": [
-                    {"name": "AZURE_OPENAI_API_KEY", "label": "API Key"},
-                    {"name": "AZURE_OPENAI_ENDPOINT", "label": "Endpoint URL", "secret": false},
-                    {"name": "AZURE_OPENAI_API_VERSION", "label": "API Version", "secret": false, "optional": true}
-                ]
-            })
+            serde_json::from_str::<LlmProtocol>("\"anthropic\"").unwrap(),
+            LlmProtocol::Anthropic
+        );
+        assert_eq!(
+            serde_json::from_str::<LlmProtocol>("\"openai\"").unwrap(),
+            LlmProtocol::OpenAi
+        );
+        assert_eq!(
+            serde_json::from_str::<LlmProtocol>("\"azure\"").unwrap(),
+            LlmProtocol::Azure
+        );
+        assert_eq!(
+            serde_json::from_str::<LlmProtocol>("\"vertex\"").unwrap(),
+            LlmProtocol::Vertex
+        );
+        assert_eq!(
+            serde_json::from_str::<LlmProtocol>("\"bedrock\"").unwrap(),
+            LlmProtocol::Bedrock
         );
+    }
 
-        let deserialized: AuthMethod = serde_json::from_value(json).unwrap();
-        match deserialized {
-            AuthMethod::EnvVar(AuthMethodEnvVar { vars, .. }) => {
-                assert_eq!(vars.len(), 3);
-                // First var: secret (default true), not optional (default false)
-                assert_eq!(vars[0].name, "AZURE_OPENAI_API_KEY");
-                assert_eq!(vars[0].label.as_deref(), Some("API Key"));
-                assert!(vars[0].secret);
-                assert!(!vars[0].optional);
-                // Second var: not a secret, not optional
-                assert_eq!(vars[1].name, "AZURE_OPENAI_ENDPOINT");
-                assert!(!vars[1].secret);
-                assert!(!vars[1].optional);
-                // Third var: not a secret, optional
-                assert_eq!(vars[2].name, "AZURE_OPENAI_API_VERSION");
-                assert!(!vars[2].secret);
-                assert!(vars[2].optional);
-            }
-            _ => panic!("Expected EnvVar variant"),
-        }
+    #[cfg(feature = "unstable_llm_providers")]
+    #[test]
+    fn test_llm_protocol_unknown_variant() {
+        let unknown: LlmProtocol = serde_json::from_str("\"cohere\"").unwrap();
+        assert_eq!(unknown, LlmProtocol::Other("cohere".to_string()));
+
+        let json = serde_json::to_value(&unknown).unwrap();
+        assert_eq!(json, json!("cohere"));
     }
 
-    #[cfg(feature = "unstable_auth_methods")]
+    #[cfg(feature = "unstable_llm_providers")]
     #[test]
-    fn test_auth_method_terminal_serialization() {
-        let method = AuthMethod::Terminal(AuthMethodTerminal::new("tui-auth", "Terminal Auth"));
+    fn test_provider_current_config_serialization() {
+        let config =
+            ProviderCurrentConfig::new(LlmProtocol::Anthropic, "https://api.anthropic.com");
 
-        let json = serde_json::to_value(&method).unwrap();
+        let json = serde_json::to_value(&config).unwrap();
         assert_eq!(
             json,
             json!({
-                "id": "tui-auth",
-                "name": "Terminal Auth",
-                "type": "terminal"
+                "apiType": "anthropic",
+                "baseUrl": "https://api.anthropic.com"
             })
         );
-        // args and env should be omitted when empty
-        assert!(!json.as_object().unwrap().contains_key("args"));
-        assert!(!json.as_object().unwrap().contains_key("env"));
 
-        let deserialized: AuthMethod = serde_json::from_value(json).unwrap();
-        match deserialized {
-            AuthMethod::Terminal(AuthMethodTerminal { args, env, .. }) => {
-                assert!(args.is_empty());
-                assert!(env.is_empty());
-            }
-            _ => panic!("Expected Terminal variant"),
-        }
+        let deserialized: ProviderCurrentConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.api_type, LlmProtocol::Anthropic);
+        assert_eq!(deserialized.base_url, "https://api.anthropic.com");
     }
 
-    #[cfg(feature = "unstable_auth_methods")]
+    #[cfg(feature = "unstable_llm_providers")]
     #[test]
-    fn test_auth_method_terminal_with_args_and_env_serialization() {
-        use std::collections::HashMap;
-
-        let mut env = HashMap::new();
-        env.insert("TERM".to_string(), "xterm-256color".to_string());
-
-        let method = AuthMethod::Terminal(
-            AuthMethodTerminal::new("tui-auth", "Terminal Auth")
-                .args(vec!["--interactive".to_string(), "--color".to_string()])
-                .env(env),
+    fn test_provider_info_with_current_config() {
+        let info = ProviderInfo::new(
+            "main",
+            vec![LlmProtocol::Anthropic, LlmProtocol::OpenAi],
+            true,
+            Some(ProviderCurrentConfig::new(
+                LlmProtocol::Anthropic,
+                "https://api.anthropic.com",
+            )),
         );
 
-        let json = serde_json::to_value(&method).unwrap();
+        let json = serde_json::to_value(&info).unwrap();
         assert_eq!(
             json,
             json!({
-                "id": "tui-auth",
-                "name": "Terminal Auth",
-                "type": "terminal",
-                "args": ["--interactive", "--color"],
-                "env": {
-                    "TERM": "xterm-256color"
+                "providerId": "main",
+                "supported": ["anthropic", "openai"],
+                "required": true,
+                "current": {
+                    "apiType": "anthropic",
+                    "baseUrl": "https://api.anthropic.com"
                 }
             })
         );
 
-        let deserialized: AuthMethod = serde_json::from_value(json).unwrap();
-        match deserialized {
-            AuthMethod::Terminal(AuthMethodTerminal { args, env, .. }) => {
-                assert_eq!(args, vec!["--interactive", "--color"]);
-                assert_eq!(env.len(), 1);
-                assert_eq!(env.get("TERM").unwrap(), "xterm-256color");
-            }
-            _ => panic!("Expected Terminal variant"),
-        }
-    }
-
-    #[cfg(feature = "unstable_boolean_config")]
-    #[test]
-    fn test_session_config_option_value_id_serialize() {
-        let val = SessionConfigOptionValue::value_id("model-1");
-        let json = serde_json::to_value(&val).unwrap();
-        // ValueId omits the "type" field (it's the default)
-        assert_eq!(json, json!({ "value": "model-1" }));
-        assert!(!json.as_object().unwrap().contains_key("type"));
+        let deserialized: ProviderInfo = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.provider_id.to_string(), "main");
+        assert_eq!(deserialized.supported.len(), 2);
+        assert!(deserialized.required);
+        assert!(deserialized.current.is_some());
+        assert_eq!(
+            deserialized.current.as_ref().unwrap().api_type,
+            LlmProtocol::Anthropic
+        );
     }
 
-    #[cfg(feature = "unstable_boolean_config")]
+    #[cfg(feature = "unstable_llm_providers")]
     #[test]
-    fn test_session_config_option_value_boolean_serialize() {
-        let val = SessionConfigOptionValue::boolean(true);
-        let json = serde_json::to_value(&val).unwrap();
-        assert_eq!(json, json!({ "type": "boolean", "value": true }));
-    }
+    fn test_provider_info_disabled() {
+        let info = ProviderInfo::new(
+            "secondary",
+            vec![LlmProtocol::OpenAi],
+            false,
+            None::<ProviderCurrentConfig>,
+        );
 
-    #[cfg(feature = "unstable_boolean_config")]
-    #[test]
-    fn test_session_config_option_value_deserialize_no_type() {
-        // Missing "type" should default to ValueId
-        let json = json!({ "value": "model-1" });
-        let val: SessionConfigOptionValue = serde_json::from_value(json).unwrap();
-        assert_eq!(val, SessionConfigOptionValue::value_id("model-1"));
-        assert_eq!(val.as_value_id().unwrap().to_string(), "model-1");
-    }
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "providerId": "secondary",
+                "supported": ["openai"],
+                "required": false
+            })
+        );
 
-    #[cfg(feature = "unstable_boolean_config")]
-    #[test]
-    fn test_session_config_option_value_deserialize_boolean() {
-        let json = json!({ "type": "boolean", "value": true });
-        let val: SessionConfigOptionValue = serde_json::from_value(json).unwrap();
-        assert_eq!(val, SessionConfigOptionValue::boolean(true));
-        assert_eq!(val.as_bool(), Some(true));
+        let deserialized: ProviderInfo = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.provider_id.to_string(), "secondary");
+        assert!(!deserialized.required);
+        assert!(deserialized.current.is_none());
     }
 
-    #[cfg(feature = "unstable_boolean_config")]
+    #[cfg(feature = "unstable_llm_providers")]
     #[test]
-    fn test_session_config_option_value_deserialize_boolean_false() {
-        let json = json!({ "type": "boolean", "value": false });
-        let val: SessionConfigOptionValue = serde_json::from_value(json).unwrap();
-        assert_eq!(val, SessionConfigOptionValue::boolean(false));
-        assert_eq!(val.as_bool(), Some(false));
+    fn test_provider_info_missing_current_defaults_to_none() {
+        // current is optional; omitting it should decode as None
+        let json = json!({
+            "providerId": "main",
+            "supported": ["anthropic"],
+            "required": true
+        });
+        let deserialized: ProviderInfo = serde_json::from_value(json).unwrap();
+        assert!(deserialized.current.is_none());
     }
 
-    #[cfg(feature = "unstable_boolean_config")]
+    #[cfg(feature = "unstable_llm_providers")]
     #[test]
-    fn test_session_config_option_value_deserialize_unknown_type_with_string_value() {
-        // Unknown type with a string value gracefully falls back to ValueId
-        let json = json!({ "type": "text", "value": "freeform input" });
-        let val: SessionConfigOptionValue = serde_json::from_value(json).unwrap();
-        assert_eq!(val.as_value_id().unwrap().to_string(), "freeform input");
+    fn test_provider_info_explicit_null_current_decodes_to_none() {
+        // current: null and an omitted current are equivalent on the wire;
+        // both must deserialize into None so the disabled state is preserved
+        // regardless of which form the peer chose to send.
+        let json = json!({
+            "providerId": "main",
+            "supported": ["anthropic"],
+            "required": true,
+            "current": null
+        });
+        let deserialized: ProviderInfo = serde_json::from_value(json).unwrap();
+        assert!(deserialized.current.is_none());
     }
 
-    #[cfg(feature = "unstable_boolean_config")]
+    #[cfg(feature = "unstable_llm_providers")]
     #[test]
-    fn test_session_config_option_value_roundtrip_value_id() {
-        let original = SessionConfigOptionValue::value_id("option-a");
-        let json = serde_json::to_value(&original).unwrap();
-        let roundtripped: SessionConfigOptionValue = serde_json::from_value(json).unwrap();
-        assert_eq!(original, roundtripped);
-    }
+    fn test_list_providers_response_serialization() {
+        let response = ListProvidersResponse::new(vec![ProviderInfo::new(
+            "main",
+            vec![LlmProtocol::Anthropic],
+            true,
+            Some(ProviderCurrentConfig::new(
+                LlmProtocol::Anthropic,
+                "https://api.anthropic.com",
+            )),
+        )]);
 
-    #[cfg(feature = "unstable_boolean_config")]
-    #[test]
-    fn test_session_config_option_value_roundtrip_boolean() {
-        let original = SessionConfigOptionValue::boolean(false);
-        let json = serde_json::to_value(&original).unwrap();
-        let roundtripped: SessionConfigOptionValue = serde_json::from_value(json).unwrap();
-        assert_eq!(original, roundtripped);
-    }
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["providers"].as_array().unwrap().len(), 1);
+        assert_eq!(json["providers"][0]["providerId"], "main");
 
-    #[cfg(feature = "unstable_boolean_config")]
-    #[test]
-    fn test_session_config_option_value_type_mismatch_boolean_with_string() {
-        // type says "boolean" but value is a string — falls to untagged ValueId
-        let json = json!({ "type": "boolean", "value": "not a bool" });
-        let result = serde_json::from_value::<SessionConfigOptionValue>(json);
-        // serde tries Boolean first (fails), then falls to untagged ValueId (succeeds)
-        assert!(result.is_ok());
-        assert_eq!(
-            result.unwrap().as_value_id().unwrap().to_string(),
-            "not a bool"
-        );
+        let deserialized: ListProvidersResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.providers.len(), 1);
     }
 
-    #[cfg(feature = "unstable_boolean_config")]
+    #[cfg(feature = "unstable_llm_providers")]
     #[test]
-    fn test_session_config_option_value_from_impls() {
-        let from_str: SessionConfigOptionValue = "model-1".into();
-        assert_eq!(from_str.as_value_id().unwrap().to_string(), "model-1");
+    fn test_set_provider_request_serialization() {
+        use std::collections::HashMap;
 
-        let from_id: SessionConfigOptionValue = SessionConfigValueId::new("model-2").into();
-        assert_eq!(from_id.as_value_id().unwrap().to_string(), "model-2");
+        let mut headers = HashMap::new();
+        headers.insert("Authorization".to_string(), "Bearer sk-test".to_string());
 
-        let from_bool: SessionConfigOptionValue = true.into();
-        assert_eq!(from_bool.as_bool(), Some(true));
-    }
+        let request =
+            SetProviderRequest::new("main", LlmProtocol::OpenAi, "https://api.openai.com/v1")
+                .headers(headers);
 
-    #[cfg(feature = "unstable_boolean_config")]
-    #[test]
-    fn test_set_session_config_option_request_value_id() {
-        let req = SetSessionConfigOptionRequest::new("sess_1", "model", "model-1");
-        let json = serde_json::to_value(&req).unwrap();
+        let json = serde_json::to_value(&request).unwrap();
         assert_eq!(
             json,
             json!({
-                "sessionId": "sess_1",
-                "configId": "model",
-                "value": "model-1"
+                "providerId": "main",
+                "apiType": "openai",
+                "baseUrl": "https://api.openai.com/v1",
+                "headers": {
+                    "Authorization": "Bearer sk-test"
+                }
             })
         );
-        // No "type" field for value_id
-        assert!(!json.as_object().unwrap().contains_key("type"));
-    }
 
-    #[cfg(feature = "unstable_boolean_config")]
-    #[test]
-    fn test_set_session_config_option_request_boolean() {
-        let req = SetSessionConfigOptionRequest::new("sess_1", "brave_mode", true);
-        let json = serde_json::to_value(&req).unwrap();
+        let deserialized: SetProviderRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.provider_id.to_string(), "main");
+        assert_eq!(deserialized.api_type, LlmProtocol::OpenAi);
+        assert_eq!(deserialized.base_url, "https://api.openai.com/v1");
+        assert_eq!(deserialized.headers.len(), 1);
         assert_eq!(
-            json,
-            json!({
-                "sessionId": "sess_1",
-                "configId": "brave_mode",
-                "type": "boolean",
-                "value": true
-            })
+            deserialized.headers.get("Authorization").unwrap(),
+            "Bearer sk-test"
         );
     }
 
-    #[cfg(feature = "unstable_boolean_config")]
+    #[cfg(feature = "unstable_llm_providers")]
     #[test]
-    fn test_set_session_config_option_request_deserialize_no_type() {
-        // Backwards-compatible: no "type" field → value_id
-        let json = json!({
-            "sessionId": "sess_1",
-            "configId": "model",
-            "value": "model-1"
-        });
-        let req: SetSessionConfigOptionRequest = serde_json::from_value(json).unwrap();
-        assert_eq!(req.session_id.to_string(), "sess_1");
-        assert_eq!(req.config_id.to_string(), "model");
-        assert_eq!(req.value.as_value_id().unwrap().to_string(), "model-1");
+    fn test_set_provider_request_omits_empty_headers() {
+        let request =
+            SetProviderRequest::new("main", LlmProtocol::Anthropic, "https://api.anthropic.com");
+
+        let json = serde_json::to_value(&request).unwrap();
+        // headers should be omitted when empty
+        assert!(!json.as_object().unwrap().contains_key("headers"));
     }
 
-    #[cfg(feature = "unstable_boolean_config")]
+    #[cfg(feature = "unstable_llm_providers")]
     #[test]
-    fn test_set_session_config_option_request_deserialize_boolean() {
-        let json = json!({
-            "sessionId": "sess_1",
-            "configId": "brave_mode",
-            "type": "boolean",
-            "value": true
-        });
-        let req: SetSessionConfigOptionRequest = serde_json::from_value(json).unwrap();
-        assert_eq!(req.value.as_bool(), Some(true));
+    fn test_disable_provider_request_serialization() {
+        let request = DisableProviderRequest::new("secondary");
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json, json!({ "providerId": "secondary" }));
+
+        let deserialized: DisableProviderRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.provider_id.to_string(), "secondary");
+    }
+
+    #[cfg(feature = "unstable_llm_providers")]
+    #[test]
+    fn test_providers_capabilities_serialization() {
+        let caps = ProvidersCapabilities::new();
+
+        let json = serde_json::to_value(&caps).unwrap();
+        assert_eq!(json, json!({}));
+
+        let deserialized: ProvidersCapabilities = serde_json::from_value(json).unwrap();
+        assert!(deserialized.meta.is_none());
     }
 
-    #[cfg(feature = "unstable_boolean_config")]
+    #[cfg(feature = "unstable_llm_providers")]
     #[test]
-    fn test_set_session_config_option_request_roundtrip_value_id() {
-        let original = SetSessionConfigOptionRequest::new("s", "c", "v");
-        let json = serde_json::to_value(&original).unwrap();
-        let roundtripped: SetSessionConfigOptionRequest = serde_json::from_value(json).unwrap();
-        assert_eq!(original, roundtripped);
+    fn test_agent_capabilities_with_providers() {
+        let caps = AgentCapabilities::new().providers(ProvidersCapabilities::new());
+
+        let json = serde_json::to_value(&caps).unwrap();
+        assert_eq!(json["providers"], json!({}));
+
+        let deserialized: AgentCapabilities = serde_json::from_value(json).unwrap();
+        assert!(deserialized.providers.is_some());
     }
 
-    #[cfg(feature = "unstable_boolean_config")]
+    #[cfg(feature = "unstable_command_invocation")]
     #[test]
-    fn test_set_session_config_option_request_roundtrip_boolean() {
-        let original = SetSessionConfigOptionRequest::new("s", "c", false);
-        let json = serde_json::to_value(&original).unwrap();
-        let roundtripped: SetSessionConfigOptionRequest = serde_json::from_value(json).unwrap();
-        assert_eq!(original, roundtripped);
+    fn test_invoke_command_request_roundtrip() {
+        let request = ClientRequest::InvokeCommandRequest(
+            InvokeCommandRequest::new("sess_1", "compact").arguments(json!({ "keepLast": 4 })),
+        );
+        assert_eq!(request.method(), "command/invoke");
+        assert_eq!(AGENT_METHOD_NAMES.command_invoke, "command/invoke");
+
+        let ClientRequest::InvokeCommandRequest(params) = request else {
+            unreachable!();
+        };
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionId": "sess_1",
+                "command": "compact",
+                "arguments": { "keepLast": 4 }
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<InvokeCommandRequest>(json).unwrap(),
+            params
+        );
+
+        let bare: InvokeCommandRequest = serde_json::from_value(json!({
+            "sessionId": "sess_1",
+            "command": "test"
+        }))
+        .unwrap();
+        assert_eq!(bare.arguments, None);
+
+        assert_eq!(
+            serde_json::to_value(InvokeCommandResponse::new()).unwrap(),
+            json!({})
+        );
     }
 
-    #[cfg(feature = "unstable_boolean_config")]
+    #[cfg(feature = "unstable_command_invocation")]
     #[test]
-    fn test_session_config_boolean_serialization() {
-        let cfg = SessionConfigBoolean::new(true);
-        let json = serde_json::to_value(&cfg).unwrap();
-        assert_eq!(json, json!({ "currentValue": true }));
+    fn test_invoke_unknown_command_error() {
+        let error = crate::v1::Error::command_not_found("does-not-exist");
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "code": -32003,
+                "message": "Command not found",
+                "data": { "command": "does-not-exist" }
+            })
+        );
 
-        let deserialized: SessionConfigBoolean = serde_json::from_value(json).unwrap();
-        assert!(deserialized.current_value);
+        let error: crate::v1::Error = serde_json::from_value(json).unwrap();
+        assert_eq!(error.code, crate::v1::ErrorCode::CommandNotFound);
     }
 
-    #[cfg(feature = "unstable_boolean_config")]
+    #[cfg(feature = "unstable_input_request")]
     #[test]
-    fn test_session_config_option_boolean_variant() {
-        let opt = SessionConfigOption::boolean("brave_mode", "Brave Mode", false)
-            .description("Skip confirmation prompts")
-            .meta(test_meta());
-        assert_eq!(serialized_meta_key_count(&opt), 1);
+    fn test_input_request_answer_roundtrip() {
+        use crate::v1::{InputRequest, SessionUpdate};
 
-        let json = serde_json::to_value(&opt).unwrap();
+        let question = SessionUpdate::InputRequest(InputRequest::new(
+            "input_1",
+            "Which branch should I deploy?",
+        ));
+        let json = serde_json::to_value(&question).unwrap();
         assert_eq!(
             json,
             json!({
-                "id": "brave_mode",
-                "name": "Brave Mode",
-                "description": "Skip confirmation prompts",
-                "type": "boolean",
-                "currentValue": false,
-                "_meta": {
-                    "source": "test"
-                }
+                "sessionUpdate": "input_request",
+                "inputId": "input_1",
+                "prompt": "Which branch should I deploy?"
             })
         );
+        assert_eq!(
+            serde_json::from_value::<SessionUpdate>(json).unwrap(),
+            question
+        );
 
-        let deserialized: SessionConfigOption = serde_json::from_value(json).unwrap();
-        assert_eq!(deserialized.id.to_string(), "brave_mode");
-        assert_eq!(deserialized.name, "Brave Mode");
-        match deserialized.kind {
-            SessionConfigKind::Boolean(ref b) => assert!(!b.current_value),
-            _ => panic!("Expected Boolean kind"),
+        let answer = ClientRequest::ProvideInputRequest(ProvideInputRequest::new(
+            "sess_1", "input_1", "main",
+        ));
+        assert_eq!(answer.method(), "session/provide_input");
+        assert_eq!(
+            AGENT_METHOD_NAMES.session_provide_input,
+            "session/provide_input"
+        );
+
+        let ClientRequest::ProvideInputRequest(params) = answer else {
+            unreachable!();
+        };
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionId": "sess_1",
+                "inputId": "input_1",
+                "text": "main"
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<ProvideInputRequest>(json).unwrap(),
+            params
+        );
+        assert_eq!(
+            serde_json::to_value(ProvideInputResponse::new()).unwrap(),
+            json!({})
+        );
+    }
+
+    #[cfg(feature = "unstable_cancel_reason")]
+    #[test]
+    fn test_cancel_reason_roundtrip() {
+        use crate::v1::CancelRequestNotification;
+
+        for (reason, wire) in [
+            (CancelReason::UserRequested, "user_requested"),
+            (CancelReason::Superseded, "superseded"),
+            (CancelReason::Shutdown, "shutdown"),
+            (CancelReason::Timeout, "timeout"),
+        ] {
+            let notification = CancelNotification::new("sess_1").reason(reason);
+            let json = serde_json::to_value(&notification).unwrap();
+            assert_eq!(json, json!({"sessionId": "sess_1", "reason": wire}));
+            assert_eq!(
+                serde_json::from_value::<CancelNotification>(json).unwrap(),
+                notification
+            );
+
+            let notification = CancelRequestNotification::new(1_i64).reason(reason);
+            let json = serde_json::to_value(&notification).unwrap();
+            assert_eq!(json, json!({"requestId": 1, "reason": wire}));
+            assert_eq!(
+                serde_json::from_value::<CancelRequestNotification>(json).unwrap(),
+                notification
+            );
         }
+
+        let notification: CancelNotification =
+            serde_json::from_value(json!({"sessionId": "sess_1"})).unwrap();
+        assert_eq!(notification.reason, None);
+        assert_eq!(
+            serde_json::to_value(&notification).unwrap(),
+            json!({"sessionId": "sess_1"})
+        );
+
+        let notification: CancelNotification =
+            serde_json::from_value(json!({"sessionId": "sess_1", "reason": "bored"})).unwrap();
+        assert_eq!(notification.reason, None);
     }
 
-    #[cfg(feature = "unstable_boolean_config")]
     #[test]
-    fn test_session_config_option_select_still_works() {
-        // Make sure existing select options are unaffected
-        let opt = SessionConfigOption::select(
+    fn test_model_selector_config_option() {
+        let option = SessionConfigOption::select(
             "model",
             "Model",
-            "model-1",
+            "fast",
             vec![
-                SessionConfigSelectOption::new("model-1", "Model 1"),
-                SessionConfigSelectOption::new("model-2", "Model 2"),
+                SessionConfigSelectOption::new("fast", "Fast"),
+                SessionConfigSelectOption::new("smart", "Smart")
+                    .description("Slower, more capable"),
             ],
         )
-        .meta(test_meta());
-        assert_eq!(serialized_meta_key_count(&opt), 1);
+        .category(SessionConfigOptionCategory::Model);
 
-        let json = serde_json::to_value(&opt).unwrap();
-        assert_eq!(json["type"], "select");
-        assert_eq!(json["currentValue"], "model-1");
-        assert_eq!(json["options"].as_array().unwrap().len(), 2);
-        assert_eq!(json["_meta"]["source"], "test");
+        let json = serde_json::to_value(&option).unwrap();
+        assert_eq!(json["category"], "model");
+        assert_eq!(json["currentValue"], "fast");
+        assert_eq!(json["options"][1]["value"], "smart");
+        assert_eq!(
+            serde_json::from_value::<SessionConfigOption>(json).unwrap(),
+            option
+        );
 
-        let deserialized: SessionConfigOption = serde_json::from_value(json).unwrap();
-        match deserialized.kind {
-            SessionConfigKind::Select(ref s) => {
-                assert_eq!(s.current_value.to_string(), "model-1");
-            }
-            _ => panic!("Expected Select kind"),
-        }
+        let request: SetSessionConfigOptionRequest = serde_json::from_value(json!({
+            "sessionId": "sess_1",
+            "configId": "model",
+            "value": "smart"
+        }))
+        .unwrap();
+        assert_eq!(request.config_id, SessionConfigId::new("model"));
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_session_load_pagination")]
     #[test]
-    fn test_llm_protocol_known_variants() {
+    fn test_load_session_pagination() {
+        let request = LoadSessionRequest::new("sess_1", "/workspace")
+            .cursor("page_2")
+            .limit(3);
+        let json = serde_json::to_value(&request).unwrap();
         assert_eq!(
-            serde_json::to_value(&LlmProtocol::Anthropic).unwrap(),
-            json!("anthropic")
+            json,
+            json!({
+                "sessionId": "sess_1",
+                "cwd": "/workspace",
+                "mcpServers": [],
+                "cursor": "page_2",
+                "limit": 3
+            })
         );
         assert_eq!(
-            serde_json::to_value(&LlmProtocol::OpenAi).unwrap(),
-            json!("openai")
+            serde_json::from_value::<LoadSessionRequest>(json).unwrap(),
+            request
         );
+
+        let response = LoadSessionResponse::new().next_cursor("page_3");
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json, json!({"nextCursor": "page_3"}));
         assert_eq!(
-            serde_json::to_value(&LlmProtocol::Azure).unwrap(),
-            json!("azure")
+            serde_json::from_value::<LoadSessionResponse>(json).unwrap(),
+            response
         );
+
+        // Omitted fields stay omitted.
         assert_eq!(
-            serde_json::to_value(&LlmProtocol::Vertex).unwrap(),
-            json!("vertex")
+            serde_json::to_value(LoadSessionRequest::new("sess_1", "/workspace")).unwrap(),
+            json!({"sessionId": "sess_1", "cwd": "/workspace", "mcpServers": []})
         );
         assert_eq!(
-            serde_json::to_value(&LlmProtocol::Bedrock).unwrap(),
-            json!("bedrock")
+            serde_json::to_value(LoadSessionResponse::new()).unwrap(),
+            json!({})
+        );
+
+        // A malformed limit is dropped instead of failing the message.
+        let request: LoadSessionRequest = serde_json::from_value(json!({
+            "sessionId": "sess_1",
+            "cwd": "/workspace",
+            "mcpServers": [],
+            "limit": -1
+        }))
+        .unwrap();
+        assert_eq!(request.limit, None);
+        // A malformed cursor is rejected, since dropping it would replay from the start.
+        assert!(
+            serde_json::from_value::<LoadSessionRequest>(json!({
+                "sessionId": "sess_1",
+                "cwd": "/workspace",
+                "mcpServers": [],
+                "cursor": 42
+            }))
+            .is_err()
         );
+        let response: LoadSessionResponse =
+            serde_json::from_value(json!({"nextCursor": ["page_3"]})).unwrap();
+        assert_eq!(response.next_cursor, None);
+    }
+
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[test]
+    fn test_load_session_replay_pages() {
+        let history: Vec<usize> = (0..5).collect();
 
+        let first = LoadSessionRequest::new("sess_1", "/workspace").limit(3);
+        let (range, next_cursor) = first.replay_page(history.len()).unwrap();
+        let mut replayed = history[range].to_vec();
+        let next_cursor = next_cursor.expect("more history remains after the first page");
+
+        let second = LoadSessionRequest::new("sess_1", "/workspace")
+            .cursor(next_cursor)
+            .limit(3);
+        let (range, next_cursor) = second.replay_page(history.len()).unwrap();
+        replayed.extend_from_slice(&history[range]);
+
+        // Two pages replay every update exactly once, in order.
+        assert_eq!(next_cursor, None);
+        assert_eq!(replayed, history);
+
+        // Without a limit the whole history fits in one page.
         assert_eq!(
-            serde_json::from_str::<LlmProtocol>("\"anthropic\"").unwrap(),
-            LlmProtocol::Anthropic
+            LoadSessionRequest::new("sess_1", "/workspace")
+                .replay_page(history.len())
+                .unwrap(),
+            (0..5, None)
         );
         assert_eq!(
-            serde_json::from_str::<LlmProtocol>("\"openai\"").unwrap(),
-            LlmProtocol::OpenAi
+            SessionCapabilities::new().load_pagination(SessionLoadPaginationCapabilities::new()),
+            serde_json::from_value(json!({"loadPagination": {}})).unwrap()
         );
+    }
+
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[test]
+    fn test_load_session_invalid_cursor() {
+        let (_, next_cursor) = LoadSessionRequest::new("sess_1", "/workspace")
+            .limit(1)
+            .replay_page(3)
+            .unwrap();
+        let foreign_cursor = next_cursor.unwrap();
+
+        for cursor in [foreign_cursor.as_str(), "garbage", "sess_2:9"] {
+            let error = LoadSessionRequest::new("sess_2", "/workspace")
+                .cursor(cursor)
+                .replay_page(3)
+                .unwrap_err();
+            assert_eq!(
+                serde_json::to_value(&error).unwrap(),
+                json!({
+                    "code": -32602,
+                    "message": "Invalid params",
+                    "data": {"cursor": cursor}
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_prompt_capabilities_gate_content_blocks() {
+        use crate::v1::ImageContent;
+
+        let image = ContentBlock::Image(ImageContent::new("aW1n", "image/png"));
+        let text = ContentBlock::from("hello");
+
+        let caps = PromptCapabilities::new();
+        assert!(caps.supports(&text));
+        assert!(!caps.supports(&image));
+        assert!(PromptCapabilities::new().image(true).supports(&image));
+    }
+
+    #[cfg(feature = "unstable_tool_result_ref")]
+    #[test]
+    fn test_prompt_capabilities_gate_tool_result_ref() {
+        use crate::v1::ToolResultRef;
+
+        let block = ContentBlock::ToolResultRef(ToolResultRef::new("call_001"));
+
+        let caps: PromptCapabilities = serde_json::from_value(json!({})).unwrap();
+        assert!(!caps.tool_result_ref);
+        assert!(!caps.supports(&block));
+
+        let caps: PromptCapabilities =
+            serde_json::from_value(json!({ "toolResultRef": true })).unwrap();
+        assert!(caps.supports(&block));
+        #[cfg_attr(
+            not(any(
+                feature = "unstable_mention",
+                feature = "unstable_reasoning_effort",
+                feature = "unstable_sampling_overrides"
+            )),
+            allow(unused_mut)
+        )]
+        let mut expected = json!({
+            "image": false,
+            "audio": false,
+            "embeddedContext": false,
+            "toolResultRef": true
+        });
+        #[cfg(feature = "unstable_mention")]
+        {
+            expected["mention"] = json!(false);
+        }
+        #[cfg(feature = "unstable_reasoning_effort")]
+        {
+            expected["reasoningEffort"] = json!(false);
+        }
+        #[cfg(feature = "unstable_sampling_overrides")]
+        {
+            expected["samplingOverrides"] = json!(false);
+        }
         assert_eq!(
-            serde_json::from_str::<LlmProtocol>("\"azure\"").unwrap(),
-            LlmProtocol::Azure
+            serde_json::to_value(PromptCapabilities::new().tool_result_ref(true)).unwrap(),
+            expected
         );
+    }
+
+    #[cfg(feature = "unstable_mention")]
+    #[test]
+    fn test_prompt_capabilities_gate_mention() {
+        use crate::v1::{Mention, MentionKind};
+
+        let block = ContentBlock::Mention(Mention::new(
+            MentionKind::File,
+            "main.rs",
+            "file:///home/user/project/src/main.rs",
+        ));
+
+        let caps: PromptCapabilities = serde_json::from_value(json!({})).unwrap();
+        assert!(!caps.mention);
+        assert!(!caps.supports(&block));
+
+        let caps: PromptCapabilities = serde_json::from_value(json!({ "mention": true })).unwrap();
+        assert!(caps.mention);
+        assert!(caps.supports(&block));
         assert_eq!(
-            serde_json::from_str::<LlmProtocol>("\"vertex\"").unwrap(),
-            LlmProtocol::Vertex
+            serde_json::to_value(PromptCapabilities::new().mention(true)).unwrap()["mention"],
+            json!(true)
+        );
+    }
+
+    #[cfg(feature = "unstable_session_context")]
+    #[test]
+    fn test_new_session_request_context_round_trip() {
+        let request = NewSessionRequest::new("/home/user/project").context(
+            SessionContext::new("linux")
+                .shell("/bin/zsh".to_string())
+                .env_allowlist(vec!["PATH".into(), "CARGO_HOME".into()]),
         );
+
+        let json = serde_json::to_value(&request).unwrap();
         assert_eq!(
-            serde_json::from_str::<LlmProtocol>("\"bedrock\"").unwrap(),
-            LlmProtocol::Bedrock
+            json["context"],
+            json!({
+                "os": "linux",
+                "shell": "/bin/zsh",
+                "envAllowlist": ["PATH", "CARGO_HOME"]
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<NewSessionRequest>(json).unwrap(),
+            request
         );
+
+        let request: NewSessionRequest = serde_json::from_value(json!({
+            "cwd": "/home/user/project",
+            "mcpServers": [],
+            "context": { "os": "windows" }
+        }))
+        .unwrap();
+        let context = request.context.unwrap();
+        assert_eq!(context.shell, None);
+        assert!(context.env_allowlist.is_empty());
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_locale")]
     #[test]
-    fn test_llm_protocol_unknown_variant() {
-        let unknown: LlmProtocol = serde_json::from_str("\"cohere\"").unwrap();
-        assert_eq!(unknown, LlmProtocol::Other("cohere".to_string()));
+    fn test_locale_round_trip() {
+        let request = InitializeRequest::new(ProtocolVersion::V1).locale("pt-BR");
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["locale"], "pt-BR");
+        assert_eq!(
+            serde_json::from_value::<InitializeRequest>(json).unwrap(),
+            request
+        );
 
-        let json = serde_json::to_value(&unknown).unwrap();
-        assert_eq!(json, json!("cohere"));
+        let request: InitializeRequest =
+            serde_json::from_value(json!({ "protocolVersion": 1 })).unwrap();
+        assert_eq!(request.locale, None);
+
+        #[cfg(feature = "unstable_session_context")]
+        {
+            let context = SessionContext::new("macos").locale("de-DE");
+            let json = serde_json::to_value(&context).unwrap();
+            assert_eq!(json, json!({ "os": "macos", "locale": "de-DE" }));
+            assert_eq!(
+                serde_json::from_value::<SessionContext>(json).unwrap(),
+                context
+            );
+        }
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_session_context")]
     #[test]
-    fn test_provider_current_config_serialization() {
-        let config =
-            ProviderCurrentConfig::new(LlmProtocol::Anthropic, "https://api.anthropic.com");
+    fn test_session_context_schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(SessionContext)).unwrap();
+        assert_eq!(schema["required"], json!(["os"]));
+        assert_eq!(
+            schema["properties"]["envAllowlist"]["items"],
+            json!({ "type": "string" })
+        );
+    }
 
-        let json = serde_json::to_value(&config).unwrap();
+    #[cfg(feature = "unstable_reasoning_effort")]
+    #[test]
+    fn test_prompt_request_reasoning_effort_round_trip() {
+        for (effort, wire) in [
+            (ReasoningEffort::Low, "low"),
+            (ReasoningEffort::Medium, "medium"),
+            (ReasoningEffort::High, "high"),
+        ] {
+            let request = PromptRequest::new("sess_abc123", vec![]).reasoning_effort(effort);
+            let json = serde_json::to_value(&request).unwrap();
+            assert_eq!(
+                json,
+                json!({
+                    "sessionId": "sess_abc123",
+                    "prompt": [],
+                    "reasoningEffort": wire
+                })
+            );
+            assert_eq!(
+                serde_json::from_value::<PromptRequest>(json).unwrap(),
+                request
+            );
+        }
+    }
+
+    #[cfg(feature = "unstable_reasoning_effort")]
+    #[test]
+    fn test_prompt_request_reasoning_effort_requires_capability() {
+        let request =
+            PromptRequest::new("sess_abc123", vec![]).reasoning_effort(ReasoningEffort::High);
+        let supported = PromptCapabilities::new().reasoning_effort(true);
+        assert_eq!(
+            request.supported_reasoning_effort(&supported),
+            Some(&ReasoningEffort::High)
+        );
+        assert_eq!(
+            request.supported_reasoning_effort(&PromptCapabilities::new()),
+            None
+        );
+
+        let request: PromptRequest = serde_json::from_value(json!({
+            "sessionId": "sess_abc123",
+            "prompt": [],
+            "reasoningEffort": "extreme"
+        }))
+        .unwrap();
+        assert_eq!(request.reasoning_effort, None);
+    }
+
+    #[cfg(feature = "unstable_sampling_overrides")]
+    #[test]
+    fn test_prompt_request_sampling_overrides_round_trip() {
+        let request = PromptRequest::new("sess_abc123", vec![]).overrides(
+            SamplingOverrides::new()
+                .temperature(0.5)
+                .top_p(0.75)
+                .max_tokens(1024),
+        );
+        let json = serde_json::to_value(&request).unwrap();
         assert_eq!(
             json,
             json!({
-                "apiType": "anthropic",
-                "baseUrl": "https://api.anthropic.com"
+                "sessionId": "sess_abc123",
+                "prompt": [],
+                "overrides": {
+                    "temperature": 0.5,
+                    "topP": 0.75,
+                    "maxTokens": 1024
+                }
             })
         );
+        assert_eq!(
+            serde_json::from_value::<PromptRequest>(json).unwrap(),
+            request
+        );
 
-        let deserialized: ProviderCurrentConfig = serde_json::from_value(json).unwrap();
-        assert_eq!(deserialized.api_type, LlmProtocol::Anthropic);
-        assert_eq!(deserialized.base_url, "https://api.anthropic.com");
+        let bounds: SamplingOverrides = serde_json::from_value(json!({
+            "temperature": 2.0,
+            "topP": 0.0,
+            "maxTokens": 1
+        }))
+        .unwrap();
+        assert_eq!(bounds.temperature, Some(2.0));
+        assert_eq!(bounds.top_p, Some(0.0));
+        assert_eq!(bounds.max_tokens, Some(1));
+    }
+
+    #[cfg(feature = "unstable_sampling_overrides")]
+    #[test]
+    fn test_sampling_overrides_reject_out_of_range() {
+        for overrides in [
+            json!({ "temperature": -0.5 }),
+            json!({ "temperature": 2.5 }),
+            json!({ "topP": 1.5 }),
+            json!({ "maxTokens": 0 }),
+        ] {
+            assert!(serde_json::from_value::<SamplingOverrides>(overrides.clone()).is_err());
+
+            let request: PromptRequest = serde_json::from_value(json!({
+                "sessionId": "sess_abc123",
+                "prompt": [],
+                "overrides": overrides
+            }))
+            .unwrap();
+            assert_eq!(request.overrides, None);
+        }
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_sampling_overrides")]
     #[test]
-    fn test_provider_info_with_current_config() {
-        let info = ProviderInfo::new(
-            "main",
-            vec![LlmProtocol::Anthropic, LlmProtocol::OpenAi],
-            true,
-            Some(ProviderCurrentConfig::new(
-                LlmProtocol::Anthropic,
-                "https://api.anthropic.com",
-            )),
+    fn test_prompt_request_sampling_overrides_require_capability() {
+        let request = PromptRequest::new("sess_abc123", vec![])
+            .overrides(SamplingOverrides::new().temperature(0.5));
+        let supported = PromptCapabilities::new().sampling_overrides(true);
+        assert_eq!(
+            request.supported_overrides(&supported),
+            Some(&SamplingOverrides::new().temperature(0.5))
+        );
+        assert_eq!(
+            request.supported_overrides(&PromptCapabilities::new()),
+            None
         );
+    }
 
-        let json = serde_json::to_value(&info).unwrap();
+    #[cfg(feature = "unstable_prompt_deadline")]
+    #[test]
+    fn test_prompt_request_deadline_round_trip() {
+        let request = PromptRequest::new("sess_abc123", vec![]).deadline_ms(30_000);
+        let json = serde_json::to_value(&request).unwrap();
         assert_eq!(
             json,
             json!({
-                "providerId": "main",
-                "supported": ["anthropic", "openai"],
-                "required": true,
-                "current": {
-                    "apiType": "anthropic",
-                    "baseUrl": "https://api.anthropic.com"
-                }
+                "sessionId": "sess_abc123",
+                "prompt": [],
+                "deadlineMs": 30_000
             })
         );
+        assert_eq!(
+            serde_json::from_value::<PromptRequest>(json).unwrap(),
+            request
+        );
 
-        let deserialized: ProviderInfo = serde_json::from_value(json).unwrap();
-        assert_eq!(deserialized.provider_id.to_string(), "main");
-        assert_eq!(deserialized.supported.len(), 2);
-        assert!(deserialized.required);
-        assert!(deserialized.current.is_some());
+        let request: PromptRequest = serde_json::from_value(json!({
+            "sessionId": "sess_abc123",
+            "prompt": [],
+            "deadlineMs": -1
+        }))
+        .unwrap();
+        assert_eq!(request.deadline_ms, None);
+
+        let response = PromptResponse::new(StopReason::Deadline);
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json, json!({ "stopReason": "deadline" }));
         assert_eq!(
-            deserialized.current.as_ref().unwrap().api_type,
-            LlmProtocol::Anthropic
+            serde_json::from_value::<PromptResponse>(json).unwrap(),
+            response
         );
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_concurrent_prompts")]
     #[test]
-    fn test_provider_info_disabled() {
-        let info = ProviderInfo::new(
-            "secondary",
-            vec![LlmProtocol::OpenAi],
-            false,
-            None::<ProviderCurrentConfig>,
-        );
+    fn test_check_prompt_admission() {
+        let session_id = SessionId::new("sess_abc123");
+        let serial = AgentCapabilities::new();
+        assert!(serial.check_prompt_admission(&session_id, 0).is_ok());
 
-        let json = serde_json::to_value(&info).unwrap();
+        let error = serial.check_prompt_admission(&session_id, 1).unwrap_err();
+        assert_eq!(error.code, crate::v1::ErrorCode::SessionBusy);
         assert_eq!(
-            json,
+            serde_json::to_value(&error).unwrap(),
             json!({
-                "providerId": "secondary",
-                "supported": ["openai"],
-                "required": false
+                "code": -32004,
+                "message": "Session busy",
+                "data": { "sessionId": "sess_abc123" }
             })
         );
 
-        let deserialized: ProviderInfo = serde_json::from_value(json).unwrap();
-        assert_eq!(deserialized.provider_id.to_string(), "secondary");
-        assert!(!deserialized.required);
-        assert!(deserialized.current.is_none());
+        let capabilities: AgentCapabilities =
+            serde_json::from_value(json!({ "concurrentPrompts": true })).unwrap();
+        assert!(capabilities.concurrent_prompts);
+        let concurrent = AgentCapabilities::new().concurrent_prompts(true);
+        assert_eq!(capabilities, concurrent);
+        assert!(concurrent.check_prompt_admission(&session_id, 0).is_ok());
+        assert!(concurrent.check_prompt_admission(&session_id, 3).is_ok());
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_accepted_mime_types")]
     #[test]
-    fn test_provider_info_missing_current_defaults_to_none() {
-        // current is optional; omitting it should decode as None
-        let json = json!({
-            "providerId": "main",
-            "supported": ["anthropic"],
-            "required": true
-        });
-        let deserialized: ProviderInfo = serde_json::from_value(json).unwrap();
-        assert!(deserialized.current.is_none());
-    }
+    fn test_accepted_mime_types() {
+        let capabilities: AgentCapabilities = serde_json::from_value(json!({
+            "acceptedMimeTypes": ["image/png", "audio/*", 42]
+        }))
+        .unwrap();
+        assert_eq!(
+            capabilities,
+            AgentCapabilities::new()
+                .accepted_mime_types(vec!["image/png".to_string(), "audio/*".to_string()])
+        );
 
-    #[cfg(feature = "unstable_llm_providers")]
-    #[test]
-    fn test_provider_info_explicit_null_current_decodes_to_none() {
-        // current: null and an omitted current are equivalent on the wire;
-        // both must deserialize into None so the disabled state is preserved
-        // regardless of which form the peer chose to send.
-        let json = json!({
-            "providerId": "main",
-            "supported": ["anthropic"],
-            "required": true,
-            "current": null
-        });
-        let deserialized: ProviderInfo = serde_json::from_value(json).unwrap();
-        assert!(deserialized.current.is_none());
+        // Exact match, ignoring case and parameters.
+        assert_eq!(capabilities.accepts("image/png"), Some(true));
+        assert_eq!(
+            capabilities.accepts("IMAGE/PNG; name=chart.png"),
+            Some(true)
+        );
+        // Wildcard match.
+        assert_eq!(capabilities.accepts("audio/wav"), Some(true));
+        assert_eq!(capabilities.accepts("audio/mpeg"), Some(true));
+        // Rejection.
+        assert_eq!(capabilities.accepts("image/jpeg"), Some(false));
+        assert_eq!(capabilities.accepts("video/mp4"), Some(false));
+        assert_eq!(capabilities.accepts("png"), Some(false));
+        assert_eq!(
+            AgentCapabilities::new()
+                .accepted_mime_types(vec!["*/*".to_string()])
+                .accepts("video/mp4"),
+            Some(true)
+        );
+        // No advertised types leaves the decision to `promptCapabilities`.
+        assert_eq!(AgentCapabilities::new().accepts("image/png"), None);
+        assert_eq!(AgentCapabilities::new().accepts("png"), None);
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_agent_ready")]
     #[test]
-    fn test_list_providers_response_serialization() {
-        let response = ListProvidersResponse::new(vec![ProviderInfo::new(
-            "main",
-            vec![LlmProtocol::Anthropic],
-            true,
-            Some(ProviderCurrentConfig::new(
-                LlmProtocol::Anthropic,
-                "https://api.anthropic.com",
-            )),
-        )]);
+    fn test_agent_ready_transition() {
+        use crate::v1::{AgentNotification, AgentReadyNotification, CLIENT_METHOD_NAMES};
 
-        let json = serde_json::to_value(&response).unwrap();
-        assert_eq!(json["providers"].as_array().unwrap().len(), 1);
-        assert_eq!(json["providers"][0]["providerId"], "main");
+        let response: InitializeResponse = serde_json::from_value(json!({
+            "protocolVersion": 1,
+            "ready": false
+        }))
+        .unwrap();
+        assert_eq!(
+            response,
+            InitializeResponse::new(ProtocolVersion::V1).ready(false)
+        );
+        let mut composer_enabled = response.is_ready();
+        assert!(!composer_enabled);
 
-        let deserialized: ListProvidersResponse = serde_json::from_value(json).unwrap();
-        assert_eq!(deserialized.providers.len(), 1);
+        // The agent keeps warming up for a while, then announces it is ready.
+        let frames = [
+            json!({
+                "jsonrpc": "2.0",
+                "method": "session/update",
+                "params": {
+                    "sessionId": "sess_abc123",
+                    "update": {
+                        "sessionUpdate": "agent_message_chunk",
+                        "content": { "type": "text", "text": "Indexing workspace..." }
+                    }
+                }
+            }),
+            json!({ "jsonrpc": "2.0", "method": "agent/ready" }),
+        ];
+        let mut observed = vec![];
+        for frame in frames {
+            if frame["method"] == CLIENT_METHOD_NAMES.agent_ready {
+                let params = frame.get("params").cloned().unwrap_or_else(|| json!({}));
+                let notification: AgentReadyNotification = serde_json::from_value(params).unwrap();
+                assert_eq!(
+                    AgentNotification::AgentReadyNotification(notification).method(),
+                    "agent/ready"
+                );
+                composer_enabled = true;
+            }
+            observed.push(composer_enabled);
+        }
+        assert_eq!(observed, [false, true]);
+
+        assert!(InitializeResponse::new(ProtocolVersion::V1).is_ready());
+        assert!(
+            InitializeResponse::new(ProtocolVersion::V1)
+                .ready(true)
+                .is_ready()
+        );
+        assert_eq!(
+            serde_json::to_value(AgentReadyNotification::new()).unwrap(),
+            json!({})
+        );
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_turn_id")]
     #[test]
-    fn test_set_provider_request_serialization() {
+    fn test_turn_id_correlates_interleaved_updates() {
         use std::collections::HashMap;
 
-        let mut headers = HashMap::new();
-        headers.insert("Authorization".to_string(), "Bearer sk-test".to_string());
+        use crate::v1::{ContentBlock, SessionNotification, SessionUpdate};
 
-        let request =
-            SetProviderRequest::new("main", LlmProtocol::OpenAi, "https://api.openai.com/v1")
-                .headers(headers);
+        // The client picks a turn id for each concurrent prompt.
+        let requests = [
+            PromptRequest::new("sess_abc123", vec!["Read the file".into()]).turn_id("turn_1"),
+            PromptRequest::new("sess_abc123", vec!["Run the tests".into()]).turn_id("turn_2"),
+        ];
+        assert_eq!(
+            serde_json::to_value(&requests[1]).unwrap(),
+            json!({
+                "sessionId": "sess_abc123",
+                "prompt": [{ "type": "text", "text": "Run the tests" }],
+                "turnId": "turn_2"
+            })
+        );
+        let mut transcripts: HashMap<TurnId, String> = requests
+            .iter()
+            .map(|request| (request.turn_id.clone().unwrap(), String::new()))
+            .collect();
+
+        // Updates stream interleaved, each echoing its prompt's turn id, and
+        // are attributed as they arrive, before either prompt has responded.
+        let notifications: Vec<SessionNotification> = serde_json::from_value(json!([
+            {
+                "sessionId": "sess_abc123",
+                "turnId": "turn_1",
+                "update": {
+                    "sessionUpdate": "agent_message_chunk",
+                    "content": { "type": "text", "text": "Reading " }
+                }
+            },
+            {
+                "sessionId": "sess_abc123",
+                "turnId": "turn_2",
+                "update": {
+                    "sessionUpdate": "agent_message_chunk",
+                    "content": { "type": "text", "text": "Running tests" }
+                }
+            },
+            {
+                "sessionId": "sess_abc123",
+                "turnId": "turn_1",
+                "update": {
+                    "sessionUpdate": "agent_message_chunk",
+                    "content": { "type": "text", "text": "the file" }
+                }
+            }
+        ]))
+        .unwrap();
+        for notification in notifications {
+            let SessionUpdate::AgentMessageChunk(chunk) = notification.update else {
+                panic!("expected an agent message chunk");
+            };
+            let ContentBlock::Text(text) = chunk.content else {
+                panic!("expected text content");
+            };
+            transcripts
+                .get_mut(notification.turn_id.as_ref().expect("turn id"))
+                .expect("update for a prompt the client sent")
+                .push_str(&text.text);
+        }
+        assert_eq!(transcripts[&TurnId::new("turn_1")], "Reading the file");
+        assert_eq!(transcripts[&TurnId::new("turn_2")], "Running tests");
 
-        let json = serde_json::to_value(&request).unwrap();
+        // Responses echo the turn id too, whatever order they arrive in.
+        let response: PromptResponse =
+            serde_json::from_value(json!({ "stopReason": "end_turn", "turnId": "turn_2" }))
+                .unwrap();
+        assert_eq!(
+            response,
+            PromptResponse::new(StopReason::EndTurn).turn_id("turn_2")
+        );
+        assert_eq!(response.turn_id, requests[1].turn_id);
+
+        let notification = SessionNotification::new(
+            "sess_abc123",
+            SessionUpdate::AgentMessageChunk(crate::v1::ContentChunk::new("Hi".into())),
+        )
+        .turn_id("turn_1");
+        assert_eq!(
+            serde_json::to_value(&notification).unwrap()["turnId"],
+            json!("turn_1")
+        );
+    }
+
+    #[cfg(feature = "unstable_edit_conflict")]
+    #[test]
+    fn test_report_edit_conflict_serialization() {
+        let notification = ReportEditConflictNotification::new(
+            "sess_1",
+            "call_1",
+            ConflictReport::new("/src/main.rs", "fn main() {}\n", None),
+        );
+        assert_eq!(
+            ClientNotification::ReportEditConflictNotification(notification.clone()).method(),
+            "session/report_edit_conflict"
+        );
+
+        let json = serde_json::to_value(&notification).unwrap();
         assert_eq!(
             json,
             json!({
-                "providerId": "main",
-                "apiType": "openai",
-                "baseUrl": "https://api.openai.com/v1",
-                "headers": {
-                    "Authorization": "Bearer sk-test"
+                "sessionId": "sess_1",
+                "toolCallId": "call_1",
+                "report": {
+                    "path": "/src/main.rs",
+                    "expected": "fn main() {}\n"
                 }
             })
         );
-
-        let deserialized: SetProviderRequest = serde_json::from_value(json).unwrap();
-        assert_eq!(deserialized.provider_id.to_string(), "main");
-        assert_eq!(deserialized.api_type, LlmProtocol::OpenAi);
-        assert_eq!(deserialized.base_url, "https://api.openai.com/v1");
-        assert_eq!(deserialized.headers.len(), 1);
         assert_eq!(
-            deserialized.headers.get("Authorization").unwrap(),
-            "Bearer sk-test"
+            serde_json::from_value::<ReportEditConflictNotification>(json).unwrap(),
+            notification
         );
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_session_compact")]
     #[test]
-    fn test_set_provider_request_omits_empty_headers() {
-        let request =
-            SetProviderRequest::new("main", LlmProtocol::Anthropic, "https://api.anthropic.com");
+    fn test_compact_session_roundtrip() {
+        let request = CompactSessionRequest::new("sess_1").target_tokens(20_000);
+        let routed = ClientRequest::CompactSessionRequest(request.clone());
+        assert_eq!(routed.method(), AGENT_METHOD_NAMES.session_compact);
+        assert_eq!(AGENT_METHOD_NAMES.session_compact, "session/compact");
 
-        let json = serde_json::to_value(&request).unwrap();
-        // headers should be omitted when empty
-        assert!(!json.as_object().unwrap().contains_key("headers"));
+        let json = serde_json::to_value(&routed).unwrap();
+        assert_eq!(
+            json,
+            json!({ "sessionId": "sess_1", "targetTokens": 20000 })
+        );
+        assert_eq!(
+            serde_json::from_value::<CompactSessionRequest>(json).unwrap(),
+            request
+        );
+        assert_eq!(
+            serde_json::from_value::<CompactSessionRequest>(json!({ "sessionId": "sess_1" }))
+                .unwrap()
+                .target_tokens,
+            None
+        );
+
+        let response = CompactSessionResponse::new(42).summary("Refactored the parser.");
+        let json =
+            serde_json::to_value(AgentResponse::CompactSessionResponse(response.clone())).unwrap();
+        assert_eq!(
+            json,
+            json!({ "removedMessages": 42, "summary": "Refactored the parser." })
+        );
+        assert_eq!(
+            serde_json::from_value::<CompactSessionResponse>(json).unwrap(),
+            response
+        );
+
+        let capabilities = SessionCapabilities::new().compact(SessionCompactCapabilities::new());
+        assert_eq!(
+            serde_json::to_value(&capabilities).unwrap()["compact"],
+            json!({})
+        );
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_session_set_title")]
     #[test]
-    fn test_disable_provider_request_serialization() {
-        let request = DisableProviderRequest::new("secondary");
+    fn test_set_session_title_roundtrip() {
+        let request = SetSessionTitleRequest::new("sess_1", "Fix flaky parser test");
+        let routed = ClientRequest::SetSessionTitleRequest(request.clone());
+        assert_eq!(routed.method(), AGENT_METHOD_NAMES.session_set_title);
+        assert_eq!(AGENT_METHOD_NAMES.session_set_title, "session/set_title");
 
-        let json = serde_json::to_value(&request).unwrap();
-        assert_eq!(json, json!({ "providerId": "secondary" }));
+        let json = serde_json::to_value(&routed).unwrap();
+        assert_eq!(
+            json,
+            json!({ "sessionId": "sess_1", "title": "Fix flaky parser test" })
+        );
+        assert_eq!(
+            serde_json::from_value::<SetSessionTitleRequest>(json).unwrap(),
+            request
+        );
 
-        let deserialized: DisableProviderRequest = serde_json::from_value(json).unwrap();
-        assert_eq!(deserialized.provider_id.to_string(), "secondary");
+        let json = serde_json::to_value(AgentResponse::SetSessionTitleResponse(
+            SetSessionTitleResponse::new(),
+        ))
+        .unwrap();
+        assert_eq!(json, json!({}));
+        assert_eq!(
+            serde_json::from_value::<SetSessionTitleResponse>(json).unwrap(),
+            SetSessionTitleResponse::new()
+        );
+
+        let capabilities = SessionCapabilities::new().set_title(SessionSetTitleCapabilities::new());
+        assert_eq!(
+            serde_json::to_value(&capabilities).unwrap()["setTitle"],
+            json!({})
+        );
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_cancel_tool_call")]
     #[test]
-    fn test_providers_capabilities_serialization() {
-        let caps = ProvidersCapabilities::new();
+    fn test_cancel_one_of_two_tool_calls() {
+        use crate::v1::{ToolCall, ToolCallStatus, ToolCallUpdate, ToolCallUpdateFields};
 
-        let json = serde_json::to_value(&caps).unwrap();
-        assert_eq!(json, json!({}));
+        let request = CancelToolCallRequest::new("sess_1", "call_grep");
+        let routed = ClientRequest::CancelToolCallRequest(request.clone());
+        assert_eq!(
+            AGENT_METHOD_NAMES.session_cancel_tool_call,
+            "session/cancel_tool_call"
+        );
+        assert_eq!(routed.method(), AGENT_METHOD_NAMES.session_cancel_tool_call);
+        let json = serde_json::to_value(&routed).unwrap();
+        assert_eq!(
+            json,
+            json!({ "sessionId": "sess_1", "toolCallId": "call_grep" })
+        );
+        let received: CancelToolCallRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(received, request);
+
+        // The agent stops only the targeted tool call and reports it.
+        let mut tool_calls = [
+            ToolCall::new("call_grep", "Search the repository").status(ToolCallStatus::InProgress),
+            ToolCall::new("call_build", "Build the project").status(ToolCallStatus::InProgress),
+        ];
+        let cancelled = tool_calls
+            .iter_mut()
+            .find(|tool_call| tool_call.tool_call_id == received.tool_call_id)
+            .unwrap();
+        let update = ToolCallUpdate::new(
+            cancelled.tool_call_id.clone(),
+            ToolCallUpdateFields::new().status(ToolCallStatus::Cancelled),
+        );
+        cancelled.update(update.fields.clone());
+        assert_eq!(
+            serde_json::to_value(AgentResponse::CancelToolCallResponse(
+                CancelToolCallResponse::new()
+            ))
+            .unwrap(),
+            json!({})
+        );
 
-        let deserialized: ProvidersCapabilities = serde_json::from_value(json).unwrap();
-        assert!(deserialized.meta.is_none());
+        assert_eq!(tool_calls[0].status, ToolCallStatus::Cancelled);
+        assert_eq!(tool_calls[1].status, ToolCallStatus::InProgress);
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({ "toolCallId": "call_grep", "status": "cancelled" })
+        );
+
+        let capabilities =
+            SessionCapabilities::new().cancel_tool_call(SessionCancelToolCallCapabilities::new());
+        assert_eq!(
+            serde_json::to_value(&capabilities).unwrap()["cancelToolCall"],
+            json!({})
+        );
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_cancel_tool_call")]
     #[test]
-    fn test_agent_capabilities_with_providers() {
-        let caps = AgentCapabilities::new().providers(ProvidersCapabilities::new());
+    fn test_cancel_tool_call_check() {
+        use crate::v1::ToolCallStatus;
 
-        let json = serde_json::to_value(&caps).unwrap();
-        assert_eq!(json["providers"], json!({}));
+        let request = CancelToolCallRequest::new("sess_1", "call_grep");
+        assert_eq!(request.check(Some(&ToolCallStatus::InProgress)), Ok(true));
+        assert_eq!(request.check(Some(&ToolCallStatus::Pending)), Ok(true));
+        assert_eq!(request.check(Some(&ToolCallStatus::Completed)), Ok(false));
+        assert_eq!(request.check(Some(&ToolCallStatus::Cancelled)), Ok(false));
 
-        let deserialized: AgentCapabilities = serde_json::from_value(json).unwrap();
-        assert!(deserialized.providers.is_some());
+        let error = request.check(None).unwrap_err();
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({
+                "code": -32602,
+                "message": "Invalid params",
+                "data": { "toolCallId": "call_grep" }
+            })
+        );
     }
 }
@@ -10,13 +10,48 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_with::{DefaultOnError, VecSkipError, serde_as, skip_serializing_none};
 
+#[cfg(feature = "unstable_write_dry_run")]
+use super::Diff;
+#[cfg(feature = "unstable_diff_hunks")]
+use super::DiffHunkDecision;
+#[cfg(feature = "unstable_json_content")]
+use super::JsonContentCapabilities;
+#[cfg(feature = "unstable_table")]
+use super::TableCapabilities;
+#[cfg(feature = "unstable_tool_calls_cancelled")]
+use super::ToolCallId;
+#[cfg(feature = "unstable_turn_id")]
+use super::TurnId;
+#[cfg(feature = "unstable_clipboard")]
+use super::{
+    CLIPBOARD_READ_METHOD_NAME, CLIPBOARD_WRITE_METHOD_NAME, ClipboardCapabilities,
+    ReadClipboardRequest, ReadClipboardResponse, WriteClipboardRequest, WriteClipboardResponse,
+};
 #[cfg(feature = "unstable_elicitation")]
 use super::{
     CompleteElicitationNotification, CreateElicitationRequest, CreateElicitationResponse,
     ElicitationCapabilities,
 };
+#[cfg(feature = "unstable_sampling")]
+use super::{CreateMessageRequest, CreateMessageResponse, SamplingCapabilities};
+#[cfg(feature = "unstable_env")]
+use super::{ENV_GET_METHOD_NAME, EnvCapabilities, GetEnvRequest, GetEnvResponse};
+#[cfg(feature = "unstable_attachment")]
+use super::{EmbeddedResource, EmbeddedResourceResource};
+#[cfg(feature = "unstable_fs_watch")]
+use super::{FS_WATCH_METHOD_NAME, WatchPathsRequest, WatchPathsResponse};
 use crate::{IntoMaybeUndefined, IntoOption, MaybeUndefined, SkipListener};
 
+#[cfg(any(
+    feature = "unstable_user_message_echo",
+    feature = "unstable_write_dry_run"
+))]
+use super::agent::is_false;
+
+#[cfg(feature = "unstable_plan_entry_update")]
+use super::PlanEntryUpdate;
+#[cfg(feature = "unstable_tool_call_input_delta")]
+use super::ToolCallInputDelta;
 use super::{
     ContentBlock, EnvVariable, ExtNotification, ExtRequest, ExtResponse, Meta, Plan,
     SessionConfigOption, SessionId, SessionModeId, ToolCall, ToolCallUpdate,
@@ -52,6 +87,34 @@ pub struct SessionNotification {
     pub session_id: SessionId,
     /// The actual update content.
     pub update: SessionUpdate,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Position of this notification in the session's stream of updates.
+    ///
+    /// When present, the first notification for a session carries `0` and each
+    /// following notification carries the previous value plus one, so clients can
+    /// restore the original order if notifications are delivered out of order.
+    #[cfg(feature = "unstable_session_update_sequence")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub sequence: Option<u64>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The prompt turn that produced this update.
+    ///
+    /// Echoes the `turnId` of the `session/prompt` request that started the
+    /// turn. Agents MUST set it on every update of a turn whose request carried
+    /// one.
+    #[cfg(feature = "unstable_turn_id")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub turn_id: Option<TurnId>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -71,6 +134,10 @@ impl SessionNotification {
         Self {
             session_id: session_id.into(),
             update,
+            #[cfg(feature = "unstable_session_update_sequence")]
+            sequence: None,
+            #[cfg(feature = "unstable_turn_id")]
+            turn_id: None,
             meta: None,
         }
     }
@@ -85,6 +152,30 @@ impl SessionNotification {
         self.meta = meta.into_option();
         self
     }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Position of this notification in the session's stream of updates.
+    #[cfg(feature = "unstable_session_update_sequence")]
+    #[must_use]
+    pub fn sequence(mut self, sequence: impl IntoOption<u64>) -> Self {
+        self.sequence = sequence.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The prompt turn that produced this update.
+    #[cfg(feature = "unstable_turn_id")]
+    #[must_use]
+    pub fn turn_id(mut self, turn_id: impl IntoOption<TurnId>) -> Self {
+        self.turn_id = turn_id.into_option();
+        self
+    }
 }
 
 /// Different types of updates that can be sent during session processing.
@@ -107,6 +198,13 @@ pub enum SessionUpdate {
     ToolCall(ToolCall),
     /// Update on the status or results of a tool call.
     ToolCallUpdate(ToolCallUpdate),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A fragment of a tool call's raw input being streamed.
+    #[cfg(feature = "unstable_tool_call_input_delta")]
+    ToolCallInputDelta(ToolCallInputDelta),
     /// The agent's execution plan for complex tasks.
     /// See protocol docs: [Agent Plan](https://agentclientprotocol.com/protocol/agent-plan)
     Plan(Plan),
@@ -124,6 +222,13 @@ pub enum SessionUpdate {
     /// Removal notice for a plan identified by ID.
     #[cfg(feature = "unstable_plan_operations")]
     PlanRemoved(PlanRemoved),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A change to a single plan entry.
+    #[cfg(feature = "unstable_plan_entry_update")]
+    PlanEntryUpdate(PlanEntryUpdate),
     /// Available commands are ready or have changed
     AvailableCommandsUpdate(AvailableCommandsUpdate),
     /// The current mode of the session has changed
@@ -136,6 +241,126 @@ pub enum SessionUpdate {
     SessionInfoUpdate(SessionInfoUpdate),
     /// Context window and cost update for the session.
     UsageUpdate(UsageUpdate),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A transient status line that replaces the previous one.
+    #[cfg(feature = "unstable_session_notice")]
+    Notice(Notice),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// In-flight tool calls that were aborted, typically after `session/cancel`.
+    #[cfg(feature = "unstable_tool_calls_cancelled")]
+    ToolCallsCancelled(ToolCallsCancelled),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A file the agent produced, such as a chart or a report, for the client
+    /// to offer for download.
+    #[cfg(feature = "unstable_attachment")]
+    Attachment(Attachment),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A free-form question for the user. The prompt turn waits until the client
+    /// answers with `session/provide_input` or the turn is cancelled.
+    #[cfg(feature = "unstable_input_request")]
+    InputRequest(InputRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Follow-up prompts the user might want to send next. Each update replaces
+    /// the previous list.
+    #[cfg(feature = "unstable_suggested_prompts")]
+    SuggestedPrompts(SuggestedPrompts),
+}
+
+impl SessionUpdate {
+    /// Whether this update belongs in the session's persisted transcript.
+    ///
+    /// Transcript updates are what a client stores and shows again when the
+    /// session is reopened:
+    /// - user message, agent message and thought chunks
+    /// - tool calls, tool call updates and cancelled tool calls
+    /// - plans and plan changes
+    /// - attachments and input requests
+    ///
+    /// The rest are ephemeral. They report state that a later update replaces,
+    /// and can be dropped once applied:
+    /// - available commands, the current mode, config options and session info
+    /// - usage, notices and suggested prompts
+    /// - streamed tool call input, since agents still send the complete
+    ///   `rawInput` in a tool call update
+    #[must_use]
+    pub fn is_transcript(&self) -> bool {
+        match self {
+            Self::UserMessageChunk(_)
+            | Self::AgentMessageChunk(_)
+            | Self::AgentThoughtChunk(_)
+            | Self::ToolCall(_)
+            | Self::ToolCallUpdate(_)
+            | Self::Plan(_) => true,
+            #[cfg(feature = "unstable_plan_operations")]
+            Self::PlanUpdate(_) | Self::PlanRemoved(_) => true,
+            #[cfg(feature = "unstable_plan_entry_update")]
+            Self::PlanEntryUpdate(_) => true,
+            #[cfg(feature = "unstable_tool_calls_cancelled")]
+            Self::ToolCallsCancelled(_) => true,
+            #[cfg(feature = "unstable_attachment")]
+            Self::Attachment(_) => true,
+            #[cfg(feature = "unstable_input_request")]
+            Self::InputRequest(_) => true,
+            Self::AvailableCommandsUpdate(_)
+            | Self::CurrentModeUpdate(_)
+            | Self::ConfigOptionUpdate(_)
+            | Self::SessionInfoUpdate(_)
+            | Self::UsageUpdate(_) => false,
+            #[cfg(feature = "unstable_tool_call_input_delta")]
+            Self::ToolCallInputDelta(_) => false,
+            #[cfg(feature = "unstable_session_notice")]
+            Self::Notice(_) => false,
+            #[cfg(feature = "unstable_suggested_prompts")]
+            Self::SuggestedPrompts(_) => false,
+        }
+    }
+
+    /// Renders the message content of this update as markdown, for exporting a
+    /// conversation.
+    ///
+    /// User and agent message chunks render their content with
+    /// [`ContentBlock::to_markdown`], and thought chunks render it as a
+    /// blockquote. Every other update returns `None`.
+    #[must_use]
+    pub fn to_markdown(&self) -> Option<String> {
+        match self {
+            Self::UserMessageChunk(chunk) | Self::AgentMessageChunk(chunk) => {
+                Some(chunk.content.to_markdown())
+            }
+            Self::AgentThoughtChunk(chunk) => Some(blockquote(&chunk.content.to_markdown())),
+            _ => None,
+        }
+    }
+}
+
+/// Prefixes every line of `markdown` with `>`.
+fn blockquote(markdown: &str) -> String {
+    markdown
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                ">".to_string()
+            } else {
+                format!("> {line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 /// The current mode of the session has changed
@@ -398,23 +623,27 @@ impl Cost {
     }
 }
 
-/// A streamed item of content
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A transient status line for the session, such as "Searching the web..." or
+/// "Reading 42 files".
+///
+/// Notices are not part of the conversation history. Clients render the most
+/// recent notice in place of any previous one, and a notice with empty `text`
+/// clears it.
+#[cfg(feature = "unstable_session_notice")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct ContentChunk {
-    /// A single item of content
-    pub content: ContentBlock,
-    /// A unique identifier for the message this chunk belongs to.
-    ///
-    /// All chunks belonging to the same message share the same `messageId`.
-    /// A change in `messageId` indicates a new message has started.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub message_id: Option<MessageId>,
+pub struct Notice {
+    /// The status text to display. An empty string clears the current notice.
+    pub text: String,
+    /// How prominently the notice should be displayed.
+    pub level: NoticeLevel,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -427,25 +656,28 @@ pub struct ContentChunk {
     pub meta: Option<Meta>,
 }
 
-impl ContentChunk {
-    /// Builds [`ContentChunk`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_session_notice")]
+impl Notice {
+    /// Builds [`Notice`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(content: ContentBlock) -> Self {
+    pub fn new(text: impl Into<String>, level: NoticeLevel) -> Self {
         Self {
-            content,
-            message_id: None,
+            text: text.into(),
+            level,
             meta: None,
         }
     }
 
-    /// A unique identifier for the message this chunk belongs to.
-    ///
-    /// All chunks belonging to the same message share the same `messageId`.
-    /// A change in `messageId` indicates a new message has started.
+    /// Builds a [`Notice`] that clears the current status line.
     #[must_use]
-    pub fn message_id(mut self, message_id: impl IntoOption<MessageId>) -> Self {
-        self.message_id = message_id.into_option();
-        self
+    pub fn clear() -> Self {
+        Self::new(String::new(), NoticeLevel::Info)
+    }
+
+    /// Whether this notice clears the current status line instead of replacing it.
+    #[must_use]
+    pub fn is_clear(&self) -> bool {
+        self.text.is_empty()
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -460,38 +692,41 @@ impl ContentChunk {
     }
 }
 
-/// Unique identifier for a message within a session.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
-#[serde(transparent)]
-#[from(Arc<str>, String, &'static str)]
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Severity of a [`Notice`].
+#[cfg(feature = "unstable_session_notice")]
+#[derive(Deserialize, Serialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
 #[non_exhaustive]
-pub struct MessageId(pub Arc<str>);
-
-impl MessageId {
-    /// Wraps a protocol string as a typed [`MessageId`].
-    #[must_use]
-    pub fn new(id: impl Into<Arc<str>>) -> Self {
-        Self(id.into())
-    }
-}
-
-impl IntoOption<MessageId> for &str {
-    fn into_option(self) -> Option<MessageId> {
-        Some(MessageId::new(self))
-    }
+pub enum NoticeLevel {
+    /// Routine progress information.
+    Info,
+    /// Something the user may want to pay attention to.
+    Warning,
 }
 
-/// Available commands are ready or have changed
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Reports which in-flight tool calls the agent aborted.
+///
+/// Agents send this after `session/cancel` so clients can mark the affected
+/// tool calls as cancelled without waiting for an update per tool call. An
+/// empty `tool_call_ids` list means every tool call that has not finished.
+#[cfg(feature = "unstable_tool_calls_cancelled")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct AvailableCommandsUpdate {
-    /// Commands the agent can execute
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub available_commands: Vec<AvailableCommand>,
+pub struct ToolCallsCancelled {
+    /// The aborted tool calls. An empty list means every unfinished tool call
+    /// in the session.
+    pub tool_call_ids: Vec<ToolCallId>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -504,16 +739,36 @@ pub struct AvailableCommandsUpdate {
     pub meta: Option<Meta>,
 }
 
-impl AvailableCommandsUpdate {
-    /// Builds [`AvailableCommandsUpdate`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_tool_calls_cancelled")]
+impl ToolCallsCancelled {
+    /// Builds [`ToolCallsCancelled`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(available_commands: Vec<AvailableCommand>) -> Self {
+    pub fn new(tool_call_ids: Vec<ToolCallId>) -> Self {
         Self {
-            available_commands,
+            tool_call_ids,
             meta: None,
         }
     }
 
+    /// Builds a [`ToolCallsCancelled`] that covers every unfinished tool call.
+    #[must_use]
+    pub fn all() -> Self {
+        Self::new(Vec::new())
+    }
+
+    /// Whether this update covers every unfinished tool call.
+    #[must_use]
+    pub fn is_all(&self) -> bool {
+        self.tool_call_ids.is_empty()
+    }
+
+    /// Whether the tool call with `tool_call_id` was cancelled, assuming it had
+    /// not finished yet.
+    #[must_use]
+    pub fn contains(&self, tool_call_id: &ToolCallId) -> bool {
+        self.is_all() || self.tool_call_ids.contains(tool_call_id)
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -526,22 +781,27 @@ impl AvailableCommandsUpdate {
     }
 }
 
-/// Information about a command.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A file the agent produced for the user, such as a chart or a report.
+///
+/// Unlike a diff, an attachment is not tied to a path in the workspace. Clients
+/// can offer to open or save it, using [`Attachment::name`] as the suggested
+/// file name.
+#[cfg(feature = "unstable_attachment")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct AvailableCommand {
-    /// Command name (e.g., `create_plan`, `research_codebase`).
+pub struct Attachment {
+    /// The suggested file name, such as `report.pdf`. Clients MUST NOT treat
+    /// it as a path and SHOULD strip any directory components before saving.
     pub name: String,
-    /// Human-readable description of what the command does.
-    pub description: String,
-    /// Input for the command if required
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub input: Option<AvailableCommandInput>,
+    /// The file contents. Binary files SHOULD set `mimeType` on the blob.
+    pub content: EmbeddedResource,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -554,23 +814,33 @@ pub struct AvailableCommand {
     pub meta: Option<Meta>,
 }
 
-impl AvailableCommand {
-    /// Builds [`AvailableCommand`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_attachment")]
+impl Attachment {
+    /// Builds [`Attachment`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+    pub fn new(name: impl Into<String>, content: EmbeddedResource) -> Self {
         Self {
             name: name.into(),
-            description: description.into(),
-            input: None,
+            content,
             meta: None,
         }
     }
 
-    /// Input for the command if required
+    /// The MIME type to save the attachment with.
+    ///
+    /// Falls back to `text/plain` for text contents and
+    /// `application/octet-stream` for binary contents without a `mimeType`.
     #[must_use]
-    pub fn input(mut self, input: impl IntoOption<AvailableCommandInput>) -> Self {
-        self.input = input.into_option();
-        self
+    pub fn mime_type(&self) -> &str {
+        match &self.content.resource {
+            EmbeddedResourceResource::TextResourceContents(contents) => {
+                contents.mime_type.as_deref().unwrap_or("text/plain")
+            }
+            EmbeddedResourceResource::BlobResourceContents(contents) => contents
+                .mime_type
+                .as_deref()
+                .unwrap_or("application/octet-stream"),
+        }
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -585,24 +855,28 @@ impl AvailableCommand {
     }
 }
 
-/// The input specification for a command.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(untagged, rename_all = "camelCase")]
-#[non_exhaustive]
-pub enum AvailableCommandInput {
-    /// All text that was typed after the command name is provided as input.
-    Unstructured(UnstructuredCommandInput),
-}
-
-/// All text that was typed after the command name is provided as input.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A free-form question the agent asks the user in the middle of a prompt turn.
+///
+/// Unlike `session/request_permission`, the answer is text rather than a choice
+/// between options. The agent blocks the turn until the client answers with
+/// `session/provide_input` or the turn is cancelled. Agents MUST NOT send this
+/// unless the client advertises the `inputRequest` capability.
+#[cfg(feature = "unstable_input_request")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct UnstructuredCommandInput {
-    /// A hint to display when the input hasn't been provided yet
-    pub hint: String,
+pub struct InputRequest {
+    /// Identifies the question within the session. Echoed back in
+    /// `session/provide_input`.
+    pub input_id: String,
+    /// The question to show the user.
+    pub prompt: String,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -615,12 +889,14 @@ pub struct UnstructuredCommandInput {
     pub meta: Option<Meta>,
 }
 
-impl UnstructuredCommandInput {
-    /// Builds [`UnstructuredCommandInput`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_input_request")]
+impl InputRequest {
+    /// Builds [`InputRequest`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(hint: impl Into<String>) -> Self {
+    pub fn new(input_id: impl Into<String>, prompt: impl Into<String>) -> Self {
         Self {
-            hint: hint.into(),
+            input_id: input_id.into(),
+            prompt: prompt.into(),
             meta: None,
         }
     }
@@ -637,28 +913,26 @@ impl UnstructuredCommandInput {
     }
 }
 
-// Permission
-
-/// Request for user permission to execute a tool call.
+/// **UNSTABLE**
 ///
-/// Sent when the agent needs authorization before performing a sensitive operation.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// See protocol docs: [Requesting Permission](https://agentclientprotocol.com/protocol/tool-calls#requesting-permission)
+/// Follow-up prompts the agent suggests, typically at the end of a turn.
+///
+/// Clients MAY show them as clickable chips. Selecting one sends its text as a
+/// regular `session/prompt`. Each update replaces the previous suggestions, and
+/// an empty list clears them.
+#[cfg(feature = "unstable_suggested_prompts")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
-#[schemars(extend("x-side" = "client", "x-method" = SESSION_REQUEST_PERMISSION_METHOD_NAME))]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct RequestPermissionRequest {
-    /// The session ID for this request.
-    pub session_id: SessionId,
-    /// Details about the tool call requiring permission.
-    pub tool_call: ToolCallUpdate,
-    /// Available permission options for the user to choose from.
+pub struct SuggestedPrompts {
+    /// The suggested prompts, in display order.
     #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
     #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub options: Vec<PermissionOption>,
+    pub prompts: Vec<String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -671,18 +945,13 @@ pub struct RequestPermissionRequest {
     pub meta: Option<Meta>,
 }
 
-impl RequestPermissionRequest {
-    /// Builds [`RequestPermissionRequest`] with the required request fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_suggested_prompts")]
+impl SuggestedPrompts {
+    /// Builds [`SuggestedPrompts`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(
-        session_id: impl Into<SessionId>,
-        tool_call: ToolCallUpdate,
-        options: Vec<PermissionOption>,
-    ) -> Self {
+    pub fn new(prompts: Vec<String>) -> Self {
         Self {
-            session_id: session_id.into(),
-            tool_call,
-            options,
+            prompts,
             meta: None,
         }
     }
@@ -699,25 +968,386 @@ impl RequestPermissionRequest {
     }
 }
 
-/// An option presented to the user when requesting permission.
+/// A streamed item of content
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct PermissionOption {
-    /// Unique identifier for this permission option.
-    pub option_id: PermissionOptionId,
-    /// Human-readable label to display to the user.
-    pub name: String,
-    /// Hint about the nature of this permission option.
-    pub kind: PermissionOptionKind,
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
-    ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
+pub struct ContentChunk {
+    /// A single item of content
+    pub content: ContentBlock,
+    /// A unique identifier for the message this chunk belongs to.
+    ///
+    /// All chunks belonging to the same message share the same `messageId`.
+    /// A change in `messageId` indicates a new message has started.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub message_id: Option<MessageId>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether this chunk echoes user input the Client has already displayed, such as
+    /// the prompt sent with `session/prompt`.
+    ///
+    /// Clients SHOULD NOT render echoed chunks a second time. Only meaningful on
+    /// `user_message_chunk` updates.
+    ///
+    /// Defaults to `false`.
+    #[cfg(feature = "unstable_user_message_echo")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default, skip_serializing_if = "is_false")]
+    #[schemars(extend("default" = false))]
+    pub echo: bool,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl ContentChunk {
+    /// Builds [`ContentChunk`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(content: ContentBlock) -> Self {
+        Self {
+            content,
+            message_id: None,
+            #[cfg(feature = "unstable_user_message_echo")]
+            echo: false,
+            meta: None,
+        }
+    }
+
+    /// A unique identifier for the message this chunk belongs to.
+    ///
+    /// All chunks belonging to the same message share the same `messageId`.
+    /// A change in `messageId` indicates a new message has started.
+    #[must_use]
+    pub fn message_id(mut self, message_id: impl IntoOption<MessageId>) -> Self {
+        self.message_id = message_id.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether this chunk echoes user input the Client has already displayed, such as
+    /// the prompt sent with `session/prompt`.
+    ///
+    /// Clients SHOULD NOT render echoed chunks a second time. Only meaningful on
+    /// `user_message_chunk` updates.
+    ///
+    /// Defaults to `false`.
+    #[cfg(feature = "unstable_user_message_echo")]
+    #[must_use]
+    pub fn echo(mut self, echo: bool) -> Self {
+        self.echo = echo;
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Unique identifier for a message within a session.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
+#[serde(transparent)]
+#[from(Arc<str>, String, &'static str)]
+#[non_exhaustive]
+pub struct MessageId(pub Arc<str>);
+
+impl MessageId {
+    /// Wraps a protocol string as a typed [`MessageId`].
+    #[must_use]
+    pub fn new(id: impl Into<Arc<str>>) -> Self {
+        Self(id.into())
+    }
+}
+
+impl IntoOption<MessageId> for &str {
+    fn into_option(self) -> Option<MessageId> {
+        Some(MessageId::new(self))
+    }
+}
+
+/// Available commands are ready or have changed
+///
+/// Each update carries the complete list of commands. Clients should replace
+/// any previously advertised commands with the latest list rather than merging
+/// them, so an empty list clears the available commands.
+///
+/// See protocol docs: [Slash Commands](https://agentclientprotocol.com/protocol/slash-commands)
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct AvailableCommandsUpdate {
+    /// Commands the agent can execute.
+    ///
+    /// Replaces the full list from any previous update.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub available_commands: Vec<AvailableCommand>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl AvailableCommandsUpdate {
+    /// Builds [`AvailableCommandsUpdate`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(available_commands: Vec<AvailableCommand>) -> Self {
+        Self {
+            available_commands,
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Information about a command.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct AvailableCommand {
+    /// Command name (e.g., `create_plan`, `research_codebase`).
+    pub name: String,
+    /// Human-readable description of what the command does.
+    pub description: String,
+    /// Input for the command if required
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub input: Option<AvailableCommandInput>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl AvailableCommand {
+    /// Builds [`AvailableCommand`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            input: None,
+            meta: None,
+        }
+    }
+
+    /// Input for the command if required
+    #[must_use]
+    pub fn input(mut self, input: impl IntoOption<AvailableCommandInput>) -> Self {
+        self.input = input.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// The input specification for a command.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(untagged, rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum AvailableCommandInput {
+    /// All text that was typed after the command name is provided as input.
+    Unstructured(UnstructuredCommandInput),
+}
+
+/// All text that was typed after the command name is provided as input.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UnstructuredCommandInput {
+    /// A hint to display when the input hasn't been provided yet
+    pub hint: String,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl UnstructuredCommandInput {
+    /// Builds [`UnstructuredCommandInput`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(hint: impl Into<String>) -> Self {
+        Self {
+            hint: hint.into(),
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+// Permission
+
+/// Request for user permission to execute a tool call.
+///
+/// Sent when the agent needs authorization before performing a sensitive operation.
+///
+/// See protocol docs: [Requesting Permission](https://agentclientprotocol.com/protocol/tool-calls#requesting-permission)
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[schemars(extend("x-side" = "client", "x-method" = SESSION_REQUEST_PERMISSION_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RequestPermissionRequest {
+    /// The session ID for this request.
+    pub session_id: SessionId,
+    /// Details about the tool call requiring permission.
+    pub tool_call: ToolCallUpdate,
+    /// Available permission options for the user to choose from.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub options: Vec<PermissionOption>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl RequestPermissionRequest {
+    /// Builds [`RequestPermissionRequest`] with the required request fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(
+        session_id: impl Into<SessionId>,
+        tool_call: ToolCallUpdate,
+        options: Vec<PermissionOption>,
+    ) -> Self {
+        Self {
+            session_id: session_id.into(),
+            tool_call,
+            options,
+            meta: None,
+        }
+    }
+
+    /// Builds a [`RequestPermissionRequest`] that describes `tool_call` in full.
+    ///
+    /// Unless the client can render inline tool calls, the tool call must
+    /// already have been reported with a `tool_call` session update. Use the
+    /// [`PermissionOption`] presets such as [`PermissionOption::allow_once`]
+    /// for consistent dialogs.
+    #[must_use]
+    pub fn for_tool_call(
+        session_id: impl Into<SessionId>,
+        tool_call: ToolCall,
+        options: Vec<PermissionOption>,
+    ) -> Self {
+        Self::new(session_id, tool_call.into(), options)
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// An option presented to the user when requesting permission.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PermissionOption {
+    /// Unique identifier for this permission option.
+    pub option_id: PermissionOptionId,
+    /// Human-readable label to display to the user.
+    pub name: String,
+    /// Hint about the nature of this permission option.
+    pub kind: PermissionOptionKind,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
     #[serde(rename = "_meta")]
@@ -740,6 +1370,38 @@ impl PermissionOption {
         }
     }
 
+    /// The standard "Allow once" option, with ID `allow-once`.
+    #[must_use]
+    pub fn allow_once() -> Self {
+        Self::new("allow-once", "Allow once", PermissionOptionKind::AllowOnce)
+    }
+
+    /// The standard "Always allow" option, with ID `allow-always`.
+    #[must_use]
+    pub fn allow_always() -> Self {
+        Self::new(
+            "allow-always",
+            "Always allow",
+            PermissionOptionKind::AllowAlways,
+        )
+    }
+
+    /// The standard "Reject" option, with ID `reject-once`.
+    #[must_use]
+    pub fn reject_once() -> Self {
+        Self::new("reject-once", "Reject", PermissionOptionKind::RejectOnce)
+    }
+
+    /// The standard "Always reject" option, with ID `reject-always`.
+    #[must_use]
+    pub fn reject_always() -> Self {
+        Self::new(
+            "reject-always",
+            "Always reject",
+            PermissionOptionKind::RejectAlways,
+        )
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -857,6 +1519,16 @@ pub enum RequestPermissionOutcome {
 pub struct SelectedPermissionOutcome {
     /// The ID of the option the user selected.
     pub option_id: PermissionOptionId,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Per-hunk decisions for diffs in the tool call that carry hunks.
+    ///
+    /// Hunks without a decision here follow the selected option.
+    #[cfg(feature = "unstable_diff_hunks")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hunk_decisions: Vec<DiffHunkDecision>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -875,10 +1547,24 @@ impl SelectedPermissionOutcome {
     pub fn new(option_id: impl Into<PermissionOptionId>) -> Self {
         Self {
             option_id: option_id.into(),
+            #[cfg(feature = "unstable_diff_hunks")]
+            hunk_decisions: Vec::new(),
             meta: None,
         }
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Per-hunk decisions for diffs in the tool call that carry hunks.
+    #[cfg(feature = "unstable_diff_hunks")]
+    #[must_use]
+    pub fn hunk_decisions(mut self, hunk_decisions: Vec<DiffHunkDecision>) -> Self {
+        self.hunk_decisions = hunk_decisions;
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -909,6 +1595,31 @@ pub struct WriteTextFileRequest {
     pub path: PathBuf,
     /// The text content to write to the file.
     pub content: String,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Line ending convention the Client should use when writing `content`.
+    ///
+    /// When omitted, the file is written with `lf` line endings.
+    #[cfg(feature = "unstable_write_line_ending")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub line_ending: Option<LineEnding>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Asks the Client to report what the write would change, as
+    /// [`WriteTextFileResponse::diff`], without touching the file.
+    ///
+    /// Agents MUST NOT set this unless the Client advertises the
+    /// `fs.writeTextFileDryRun` capability. Defaults to `false`.
+    #[cfg(feature = "unstable_write_dry_run")]
+    #[serde(default, skip_serializing_if = "is_false")]
+    #[schemars(extend("default" = false))]
+    pub dry_run: bool,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -933,10 +1644,61 @@ impl WriteTextFileRequest {
             session_id: session_id.into(),
             path: path.into(),
             content: content.into(),
+            #[cfg(feature = "unstable_write_line_ending")]
+            line_ending: None,
+            #[cfg(feature = "unstable_write_dry_run")]
+            dry_run: false,
             meta: None,
         }
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Line ending convention the Client should use when writing `content`.
+    ///
+    /// When omitted, the file is written with `lf` line endings.
+    #[cfg(feature = "unstable_write_line_ending")]
+    #[must_use]
+    pub fn line_ending(mut self, line_ending: impl IntoOption<LineEnding>) -> Self {
+        self.line_ending = line_ending.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Asks the Client to report what the write would change without touching the file.
+    #[cfg(feature = "unstable_write_dry_run")]
+    #[must_use]
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Builds the [`Diff`] a dry run of this request reports.
+    ///
+    /// `existing` is the current contents of the file, or `None` when the file
+    /// does not exist yet. The new text is `content` as it would be written.
+    #[cfg(feature = "unstable_write_dry_run")]
+    #[must_use]
+    pub fn preview(&self, existing: Option<&str>) -> Diff {
+        #[cfg(feature = "unstable_write_line_ending")]
+        let new_text = self
+            .line_ending
+            .unwrap_or_default()
+            .normalize(&self.content, existing);
+        #[cfg(not(feature = "unstable_write_line_ending"))]
+        let new_text = self.content.clone();
+        Diff::new(self.path.clone(), new_text).old_text(existing.map(str::to_owned))
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -949,6 +1711,52 @@ impl WriteTextFileRequest {
     }
 }
 
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Line ending convention for [`WriteTextFileRequest`].
+#[cfg(feature = "unstable_write_line_ending")]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum LineEnding {
+    /// Write `\n` line endings.
+    #[default]
+    Lf,
+    /// Write `\r\n` line endings.
+    #[serde(rename = "crlf")]
+    CrLf,
+    /// Match the dominant line ending of the existing file, falling back to `lf`
+    /// when the file does not exist yet.
+    Preserve,
+}
+
+#[cfg(feature = "unstable_write_line_ending")]
+impl LineEnding {
+    /// Rewrites every line ending in `content` to this convention.
+    ///
+    /// `existing` is the current contents of the file, if it exists, and is only
+    /// consulted for [`LineEnding::Preserve`].
+    #[must_use]
+    pub fn normalize(self, content: &str, existing: Option<&str>) -> String {
+        let crlf = match self {
+            LineEnding::Lf => false,
+            LineEnding::CrLf => true,
+            LineEnding::Preserve => existing.is_some_and(|existing| {
+                let crlf_count = existing.matches("\r\n").count();
+                crlf_count > existing.matches('\n').count() - crlf_count
+            }),
+        };
+        let content = content.replace("\r\n", "\n");
+        if crlf {
+            content.replace('\n', "\r\n")
+        } else {
+            content
+        }
+    }
+}
+
 /// Response to `fs/write_text_file`
 #[serde_as]
 #[skip_serializing_none]
@@ -957,6 +1765,19 @@ impl WriteTextFileRequest {
 #[schemars(extend("x-side" = "client", "x-method" = FS_WRITE_TEXT_FILE_METHOD_NAME))]
 #[non_exhaustive]
 pub struct WriteTextFileResponse {
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// What the write would change, returned for a dry run.
+    ///
+    /// Clients MUST include it when `dryRun` was set and MUST omit it
+    /// otherwise.
+    #[cfg(feature = "unstable_write_dry_run")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub diff: Option<Diff>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -976,6 +1797,18 @@ impl WriteTextFileResponse {
         Self::default()
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// What the write would change, returned for a dry run.
+    #[cfg(feature = "unstable_write_dry_run")]
+    #[must_use]
+    pub fn diff(mut self, diff: impl IntoOption<Diff>) -> Self {
+        self.diff = diff.into_option();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1005,11 +1838,15 @@ pub struct ReadTextFileRequest {
     /// Absolute path to the file to read.
     pub path: PathBuf,
     /// Line number to start reading from (1-based).
+    ///
+    /// `0` is treated as `1`. A line past the end of the file yields empty content.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
     pub line: Option<u32>,
     /// Maximum number of lines to read.
+    ///
+    /// A limit that reaches past the end of the file yields the lines that exist.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
@@ -1040,6 +1877,8 @@ impl ReadTextFileRequest {
     }
 
     /// Line number to start reading from (1-based).
+    ///
+    /// `0` is treated as `1`. A line past the end of the file yields empty content.
     #[must_use]
     pub fn line(mut self, line: impl IntoOption<u32>) -> Self {
         self.line = line.into_option();
@@ -1047,12 +1886,31 @@ impl ReadTextFileRequest {
     }
 
     /// Maximum number of lines to read.
+    ///
+    /// A limit that reaches past the end of the file yields the lines that exist.
     #[must_use]
     pub fn limit(mut self, limit: impl IntoOption<u32>) -> Self {
         self.limit = limit.into_option();
         self
     }
 
+    /// Selects the lines this request asks for from the full file `text`.
+    ///
+    /// Reading starts at [`Self::line`] and takes at most [`Self::limit`] lines,
+    /// keeping their line endings. Neither bound is an error when it falls past
+    /// the end of the file: the result is just shorter, or empty.
+    #[must_use]
+    pub fn select_lines<'a>(&self, text: &'a str) -> &'a str {
+        let skip = self.line.map_or(0, |line| line.saturating_sub(1)) as usize;
+        let mut lines = text.split_inclusive('\n');
+        let start = lines.by_ref().take(skip).map(str::len).sum::<usize>();
+        let len = match self.limit {
+            Some(limit) => lines.take(limit as usize).map(str::len).sum(),
+            None => text.len() - start,
+        };
+        &text[start..start + len]
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1075,6 +1933,26 @@ impl ReadTextFileRequest {
 pub struct ReadTextFileResponse {
     /// Content payload returned by this response.
     pub content: String,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// MIME type of the file (e.g., "text/rust"), as detected by the Client.
+    #[cfg(feature = "unstable_read_file_type")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub mime_type: Option<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The language identifier of the file (e.g., "rust", "python"), as detected by the Client.
+    #[cfg(feature = "unstable_read_file_type")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub language_id: Option<String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1093,10 +1971,38 @@ impl ReadTextFileResponse {
     pub fn new(content: impl Into<String>) -> Self {
         Self {
             content: content.into(),
+            #[cfg(feature = "unstable_read_file_type")]
+            mime_type: None,
+            #[cfg(feature = "unstable_read_file_type")]
+            language_id: None,
             meta: None,
         }
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// MIME type of the file (e.g., "text/rust"), as detected by the Client.
+    #[cfg(feature = "unstable_read_file_type")]
+    #[must_use]
+    pub fn mime_type(mut self, mime_type: impl IntoOption<String>) -> Self {
+        self.mime_type = mime_type.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The language identifier of the file (e.g., "rust", "python"), as detected by the Client.
+    #[cfg(feature = "unstable_read_file_type")]
+    #[must_use]
+    pub fn language_id(mut self, language_id: impl IntoOption<String>) -> Self {
+        self.language_id = language_id.into_option();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1716,6 +2622,54 @@ impl TerminalExitStatus {
     }
 }
 
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Notification that the agent has finished warming up and can take prompts.
+///
+/// Only sent by agents that returned `ready: false` from `initialize`, and at
+/// most once per connection.
+#[cfg(feature = "unstable_agent_ready")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "client", "x-method" = AGENT_READY_NOTIFICATION))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct AgentReadyNotification {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_agent_ready")]
+impl AgentReadyNotification {
+    /// Builds [`AgentReadyNotification`] with the required notification fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
 // Capabilities
 
 /// Capabilities supported by the client.
@@ -1740,82 +2694,176 @@ pub struct ClientCapabilities {
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub terminal: bool,
+    pub terminal: bool,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Session-related capabilities supported by the client.
+    ///
+    /// Optional. Omitted or `null` both mean the client does not advertise any
+    /// session-related extensions.
+    #[cfg(feature = "unstable_boolean_config")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub session: Option<ClientSessionCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the client supports `plan_update` and `plan_removed` session updates.
+    ///
+    /// Optional. Omitted or `null` both mean the client does not advertise support.
+    /// Supplying `{}` means the client can receive both update types.
+    #[cfg(feature = "unstable_plan_operations")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub plan: Option<PlanCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Authentication capabilities supported by the client.
+    /// Determines which authentication method types the agent may include
+    /// in its `InitializeResponse`.
+    #[cfg(feature = "unstable_auth_methods")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub auth: AuthCapabilities,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Elicitation capabilities supported by the client.
+    /// Determines which elicitation modes the agent may use.
+    ///
+    /// Optional. Omitted or `null` both mean the client does not advertise
+    /// elicitation support.
+    #[cfg(feature = "unstable_elicitation")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub elicitation: Option<ElicitationCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Sampling capabilities supported by the client.
+    /// Determines whether the agent may ask the client to generate model completions.
+    ///
+    /// Optional. Omitted or `null` both mean the client does not advertise
+    /// sampling support.
+    #[cfg(feature = "unstable_sampling")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub sampling: Option<SamplingCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// NES (Next Edit Suggestions) capabilities supported by the client.
+    ///
+    /// Optional. Omitted or `null` both mean the client does not advertise any
+    /// NES suggestion-kind extensions.
+    #[cfg(feature = "unstable_nes")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub nes: Option<ClientNesCapabilities>,
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Session-related capabilities supported by the client.
+    /// The position encodings supported by the client, in order of preference.
+    #[cfg(feature = "unstable_nes")]
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub position_encodings: Vec<PositionEncodingKind>,
+    /// **UNSTABLE**
     ///
-    /// Optional. Omitted or `null` both mean the client does not advertise any
-    /// session-related extensions.
-    #[cfg(feature = "unstable_boolean_config")]
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the client can render [`ContentBlock::Table`] content.
+    ///
+    /// Optional. Omitted or `null` both mean the client does not advertise support.
+    /// Supplying `{}` means agents may send tables instead of markdown.
+    #[cfg(feature = "unstable_table")]
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub session: Option<ClientSessionCapabilities>,
+    pub table: Option<TableCapabilities>,
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Whether the client supports `plan_update` and `plan_removed` session updates.
+    /// Whether the client can render [`ContentBlock::Json`] content.
     ///
     /// Optional. Omitted or `null` both mean the client does not advertise support.
-    /// Supplying `{}` means the client can receive both update types.
-    #[cfg(feature = "unstable_plan_operations")]
+    /// Supplying `{}` means agents may send JSON values instead of text.
+    #[cfg(feature = "unstable_json_content")]
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub plan: Option<PlanCapabilities>,
+    pub json_content: Option<JsonContentCapabilities>,
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Authentication capabilities supported by the client.
-    /// Determines which authentication method types the agent may include
-    /// in its `InitializeResponse`.
-    #[cfg(feature = "unstable_auth_methods")]
+    /// How the client handles `session/request_permission` requests.
+    ///
+    /// Optional. Omitted or `null` both mean the client does not advertise any
+    /// permission-related extensions.
+    #[cfg(feature = "unstable_permission_inline_tool_call")]
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub auth: AuthCapabilities,
+    pub permission: Option<PermissionCapabilities>,
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// Elicitation capabilities supported by the client.
-    /// Determines which elicitation modes the agent may use.
+    /// Whether the client can answer `input_request` session updates.
     ///
-    /// Optional. Omitted or `null` both mean the client does not advertise
-    /// elicitation support.
-    #[cfg(feature = "unstable_elicitation")]
+    /// Optional. Omitted or `null` both mean the client does not advertise support.
+    /// Supplying `{}` means agents may ask the user free-form questions mid-turn.
+    #[cfg(feature = "unstable_input_request")]
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub elicitation: Option<ElicitationCapabilities>,
+    pub input_request: Option<InputRequestCapabilities>,
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// NES (Next Edit Suggestions) capabilities supported by the client.
+    /// Clipboard capabilities supported by the client.
+    /// Determines whether the agent may read or write the user's clipboard.
     ///
-    /// Optional. Omitted or `null` both mean the client does not advertise any
-    /// NES suggestion-kind extensions.
-    #[cfg(feature = "unstable_nes")]
+    /// Optional. Omitted or `null` both mean the client does not advertise
+    /// clipboard support.
+    #[cfg(feature = "unstable_clipboard")]
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub nes: Option<ClientNesCapabilities>,
+    pub clipboard: Option<ClipboardCapabilities>,
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
-    /// The position encodings supported by the client, in order of preference.
-    #[cfg(feature = "unstable_nes")]
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub position_encodings: Vec<PositionEncodingKind>,
+    /// Environment variable capabilities supported by the client.
+    /// Determines whether the agent may read variables with `env/get`.
+    ///
+    /// Optional. Omitted or `null` both mean the client does not advertise
+    /// environment variable support.
+    #[cfg(feature = "unstable_env")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub env: Option<EnvCapabilities>,
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
@@ -1836,6 +2884,23 @@ impl ClientCapabilities {
         Self::default()
     }
 
+    /// Advertises every stable client method: reading and writing text files
+    /// and all `terminal/*` methods.
+    #[must_use]
+    pub fn full() -> Self {
+        Self::new()
+            .read_text_file(true)
+            .write_text_file(true)
+            .terminal(true)
+    }
+
+    /// Advertises `fs/read_text_file` only, for clients that never let the
+    /// agent modify the workspace.
+    #[must_use]
+    pub fn read_only() -> Self {
+        Self::new().read_text_file(true)
+    }
+
     /// File system capabilities supported by the client.
     /// Determines which file operations the agent can request.
     #[must_use]
@@ -1851,6 +2916,24 @@ impl ClientCapabilities {
         self
     }
 
+    /// Whether the Client supports `fs/read_text_file` requests.
+    ///
+    /// Shorthand for setting [`FileSystemCapabilities::read_text_file`] on [`Self::fs`].
+    #[must_use]
+    pub fn read_text_file(mut self, read_text_file: bool) -> Self {
+        self.fs.read_text_file = read_text_file;
+        self
+    }
+
+    /// Whether the Client supports `fs/write_text_file` requests.
+    ///
+    /// Shorthand for setting [`FileSystemCapabilities::write_text_file`] on [`Self::fs`].
+    #[must_use]
+    pub fn write_text_file(mut self, write_text_file: bool) -> Self {
+        self.fs.write_text_file = write_text_file;
+        self
+    }
+
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
@@ -1905,6 +2988,19 @@ impl ClientCapabilities {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Sampling capabilities supported by the client.
+    /// Determines whether the agent may ask the client to generate model completions.
+    #[cfg(feature = "unstable_sampling")]
+    #[must_use]
+    pub fn sampling(mut self, sampling: impl IntoOption<SamplingCapabilities>) -> Self {
+        self.sampling = sampling.into_option();
+        self
+    }
+
     /// **UNSTABLE**
     ///
     /// NES (Next Edit Suggestions) capabilities supported by the client.
@@ -1925,6 +3021,102 @@ impl ClientCapabilities {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the client can render [`ContentBlock::Table`] content.
+    ///
+    /// Omitted or `null` both mean the client does not advertise support.
+    /// Supplying `{}` means agents may send tables instead of markdown.
+    #[cfg(feature = "unstable_table")]
+    #[must_use]
+    pub fn table(mut self, table: impl IntoOption<TableCapabilities>) -> Self {
+        self.table = table.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the client can render [`ContentBlock::Json`] content.
+    ///
+    /// Omitted or `null` both mean the client does not advertise support.
+    /// Supplying `{}` means agents may send JSON values instead of text.
+    #[cfg(feature = "unstable_json_content")]
+    #[must_use]
+    pub fn json_content(mut self, json_content: impl IntoOption<JsonContentCapabilities>) -> Self {
+        self.json_content = json_content.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// How the client handles `session/request_permission` requests.
+    #[cfg(feature = "unstable_permission_inline_tool_call")]
+    #[must_use]
+    pub fn permission(mut self, permission: impl IntoOption<PermissionCapabilities>) -> Self {
+        self.permission = permission.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the client can answer `input_request` session updates.
+    ///
+    /// Omitted or `null` both mean the client does not advertise support.
+    /// Supplying `{}` means agents may ask the user free-form questions mid-turn.
+    #[cfg(feature = "unstable_input_request")]
+    #[must_use]
+    pub fn input_request(
+        mut self,
+        input_request: impl IntoOption<InputRequestCapabilities>,
+    ) -> Self {
+        self.input_request = input_request.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Clipboard capabilities supported by the client.
+    /// Determines whether the agent may read or write the user's clipboard.
+    #[cfg(feature = "unstable_clipboard")]
+    #[must_use]
+    pub fn clipboard(mut self, clipboard: impl IntoOption<ClipboardCapabilities>) -> Self {
+        self.clipboard = clipboard.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Environment variable capabilities supported by the client.
+    /// Determines whether the agent may read variables with `env/get`.
+    #[cfg(feature = "unstable_env")]
+    #[must_use]
+    pub fn env(mut self, env: impl IntoOption<EnvCapabilities>) -> Self {
+        self.env = env.into_option();
+        self
+    }
+
+    /// Whether the client can render a permission request for a tool call it
+    /// has not been sent yet.
+    #[cfg(feature = "unstable_permission_inline_tool_call")]
+    #[must_use]
+    pub fn supports_inline_tool_call(&self) -> bool {
+        self.permission
+            .as_ref()
+            .is_some_and(|permission| permission.inline_tool_call)
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2113,6 +3305,113 @@ impl BooleanConfigOptionCapabilities {
     }
 }
 
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Permission request capabilities supported by the client.
+#[cfg(feature = "unstable_permission_inline_tool_call")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PermissionCapabilities {
+    /// Whether the client can render a permission request whose `toolCall`
+    /// describes a tool call it has not been sent yet.
+    ///
+    /// When `false`, agents MUST report the tool call with a `tool_call` session
+    /// update first and reference it by `toolCallId` alone.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub inline_tool_call: bool,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_permission_inline_tool_call")]
+impl PermissionCapabilities {
+    /// Builds an empty [`PermissionCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the client can render a permission request whose `toolCall`
+    /// describes a tool call it has not been sent yet.
+    #[must_use]
+    pub fn inline_tool_call(mut self, inline_tool_call: bool) -> Self {
+        self.inline_tool_call = inline_tool_call;
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for `input_request` session updates.
+///
+/// Supplying `{}` means the client can show the question and answer it with
+/// `session/provide_input`.
+#[cfg(feature = "unstable_input_request")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct InputRequestCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_input_request")]
+impl InputRequestCapabilities {
+    /// Builds an empty [`InputRequestCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
 /// **UNSTABLE**
 ///
 /// This capability is not part of the spec yet, and may be removed or changed at any point.
@@ -2186,6 +3485,7 @@ impl AuthCapabilities {
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
+#[allow(clippy::struct_excessive_bools)]
 pub struct FileSystemCapabilities {
     /// Whether the Client supports `fs/read_text_file` requests.
     #[serde_as(deserialize_as = "DefaultOnError")]
@@ -2197,6 +3497,26 @@ pub struct FileSystemCapabilities {
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
     pub write_text_file: bool,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the Client honors `dryRun` on `fs/write_text_file` requests.
+    #[cfg(feature = "unstable_write_dry_run")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub write_text_file_dry_run: bool,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the Client supports `fs/watch` requests.
+    #[cfg(feature = "unstable_fs_watch")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub watch: bool,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2216,17 +3536,41 @@ impl FileSystemCapabilities {
         Self::default()
     }
 
-    /// Whether the Client supports `fs/read_text_file` requests.
+    /// Whether the Client supports `fs/read_text_file` requests.
+    #[must_use]
+    pub fn read_text_file(mut self, read_text_file: bool) -> Self {
+        self.read_text_file = read_text_file;
+        self
+    }
+
+    /// Whether the Client supports `fs/write_text_file` requests.
+    #[must_use]
+    pub fn write_text_file(mut self, write_text_file: bool) -> Self {
+        self.write_text_file = write_text_file;
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the Client honors `dryRun` on `fs/write_text_file` requests.
+    #[cfg(feature = "unstable_write_dry_run")]
     #[must_use]
-    pub fn read_text_file(mut self, read_text_file: bool) -> Self {
-        self.read_text_file = read_text_file;
+    pub fn write_text_file_dry_run(mut self, write_text_file_dry_run: bool) -> Self {
+        self.write_text_file_dry_run = write_text_file_dry_run;
         self
     }
 
-    /// Whether the Client supports `fs/write_text_file` requests.
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the Client supports `fs/watch` requests.
+    #[cfg(feature = "unstable_fs_watch")]
     #[must_use]
-    pub fn write_text_file(mut self, write_text_file: bool) -> Self {
-        self.write_text_file = write_text_file;
+    pub fn watch(mut self, watch: bool) -> Self {
+        self.watch = watch;
         self
     }
 
@@ -2258,6 +3602,9 @@ pub struct ClientMethodNames {
     pub fs_write_text_file: &'static str,
     /// Method for reading text files.
     pub fs_read_text_file: &'static str,
+    /// Method for watching paths for changes.
+    #[cfg(feature = "unstable_fs_watch")]
+    pub fs_watch: &'static str,
     /// Method for creating new terminals.
     pub terminal_create: &'static str,
     /// Method for getting terminals output.
@@ -2283,6 +3630,21 @@ pub struct ClientMethodNames {
     /// Notification for elicitation completion.
     #[cfg(feature = "unstable_elicitation")]
     pub elicitation_complete: &'static str,
+    /// Method for requesting a model completion from the client.
+    #[cfg(feature = "unstable_sampling")]
+    pub sampling_create_message: &'static str,
+    /// Method for reading the user's clipboard.
+    #[cfg(feature = "unstable_clipboard")]
+    pub clipboard_read: &'static str,
+    /// Method for writing the user's clipboard.
+    #[cfg(feature = "unstable_clipboard")]
+    pub clipboard_write: &'static str,
+    /// Method for reading environment variables.
+    #[cfg(feature = "unstable_env")]
+    pub env_get: &'static str,
+    /// Notification that the agent is ready to take prompts.
+    #[cfg(feature = "unstable_agent_ready")]
+    pub agent_ready: &'static str,
 }
 
 /// Constant containing all client method names.
@@ -2291,6 +3653,8 @@ pub const CLIENT_METHOD_NAMES: ClientMethodNames = ClientMethodNames {
     session_request_permission: SESSION_REQUEST_PERMISSION_METHOD_NAME,
     fs_write_text_file: FS_WRITE_TEXT_FILE_METHOD_NAME,
     fs_read_text_file: FS_READ_TEXT_FILE_METHOD_NAME,
+    #[cfg(feature = "unstable_fs_watch")]
+    fs_watch: FS_WATCH_METHOD_NAME,
     terminal_create: TERMINAL_CREATE_METHOD_NAME,
     terminal_output: TERMINAL_OUTPUT_METHOD_NAME,
     terminal_release: TERMINAL_RELEASE_METHOD_NAME,
@@ -2306,6 +3670,16 @@ pub const CLIENT_METHOD_NAMES: ClientMethodNames = ClientMethodNames {
     elicitation_create: ELICITATION_CREATE_METHOD_NAME,
     #[cfg(feature = "unstable_elicitation")]
     elicitation_complete: ELICITATION_COMPLETE_NOTIFICATION,
+    #[cfg(feature = "unstable_sampling")]
+    sampling_create_message: SAMPLING_CREATE_MESSAGE_METHOD_NAME,
+    #[cfg(feature = "unstable_clipboard")]
+    clipboard_read: CLIPBOARD_READ_METHOD_NAME,
+    #[cfg(feature = "unstable_clipboard")]
+    clipboard_write: CLIPBOARD_WRITE_METHOD_NAME,
+    #[cfg(feature = "unstable_env")]
+    env_get: ENV_GET_METHOD_NAME,
+    #[cfg(feature = "unstable_agent_ready")]
+    agent_ready: AGENT_READY_NOTIFICATION,
 };
 
 /// Notification name for session updates.
@@ -2332,6 +3706,12 @@ pub(crate) const ELICITATION_CREATE_METHOD_NAME: &str = "elicitation/create";
 /// Notification name for elicitation completion.
 #[cfg(feature = "unstable_elicitation")]
 pub(crate) const ELICITATION_COMPLETE_NOTIFICATION: &str = "elicitation/complete";
+/// Method name for requesting a model completion from the client.
+#[cfg(feature = "unstable_sampling")]
+pub(crate) const SAMPLING_CREATE_MESSAGE_METHOD_NAME: &str = "sampling/create_message";
+/// Notification name for the agent finishing its warm-up.
+#[cfg(feature = "unstable_agent_ready")]
+pub(crate) const AGENT_READY_NOTIFICATION: &str = "agent/ready";
 
 /// All possible requests that an agent can send to a client.
 ///
@@ -2450,6 +3830,52 @@ pub enum AgentRequest {
     /// Closes an MCP-over-ACP connection.
     #[cfg(feature = "unstable_mcp_over_acp")]
     DisconnectMcpRequest(DisconnectMcpRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Asks the client to generate a model completion on the agent's behalf.
+    ///
+    /// Only available if the client advertises the `sampling` capability.
+    #[cfg(feature = "unstable_sampling")]
+    CreateMessageRequest(CreateMessageRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Sets the paths the client watches for changes on the agent's behalf.
+    ///
+    /// Only available if the client advertises the `fs.watch` capability.
+    /// Changes are reported with `fs/file_changed` notifications.
+    #[cfg(feature = "unstable_fs_watch")]
+    WatchPathsRequest(WatchPathsRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Reads the user's clipboard.
+    ///
+    /// Only available if the client advertises the `clipboard.read` capability.
+    #[cfg(feature = "unstable_clipboard")]
+    ReadClipboardRequest(ReadClipboardRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Replaces the user's clipboard contents.
+    ///
+    /// Only available if the client advertises the `clipboard.write` capability.
+    #[cfg(feature = "unstable_clipboard")]
+    WriteClipboardRequest(WriteClipboardRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Reads environment variables from the client.
+    ///
+    /// Only available if the client advertises the `env` capability.
+    #[cfg(feature = "unstable_env")]
+    GetEnvRequest(GetEnvRequest),
     /// Handles extension method requests from the agent.
     ///
     /// Allows the Agent to send an arbitrary request that is not part of the ACP spec.
@@ -2481,6 +3907,16 @@ impl AgentRequest {
             Self::MessageMcpRequest(_) => CLIENT_METHOD_NAMES.mcp_message,
             #[cfg(feature = "unstable_mcp_over_acp")]
             Self::DisconnectMcpRequest(_) => CLIENT_METHOD_NAMES.mcp_disconnect,
+            #[cfg(feature = "unstable_sampling")]
+            Self::CreateMessageRequest(_) => CLIENT_METHOD_NAMES.sampling_create_message,
+            #[cfg(feature = "unstable_fs_watch")]
+            Self::WatchPathsRequest(_) => CLIENT_METHOD_NAMES.fs_watch,
+            #[cfg(feature = "unstable_clipboard")]
+            Self::ReadClipboardRequest(_) => CLIENT_METHOD_NAMES.clipboard_read,
+            #[cfg(feature = "unstable_clipboard")]
+            Self::WriteClipboardRequest(_) => CLIENT_METHOD_NAMES.clipboard_write,
+            #[cfg(feature = "unstable_env")]
+            Self::GetEnvRequest(_) => CLIENT_METHOD_NAMES.env_get,
             Self::ExtMethodRequest(ext_request) => &ext_request.method,
         }
     }
@@ -2525,6 +3961,21 @@ pub enum ClientResponse {
     /// Successful result returned by an MCP-over-ACP `mcp/message` request.
     #[cfg(feature = "unstable_mcp_over_acp")]
     MessageMcpResponse(MessageMcpResponse),
+    /// Successful result returned for a `sampling/create_message` request.
+    #[cfg(feature = "unstable_sampling")]
+    CreateMessageResponse(Box<CreateMessageResponse>),
+    /// Successful result returned for a `fs/watch` request.
+    #[cfg(feature = "unstable_fs_watch")]
+    WatchPathsResponse(#[serde(default)] WatchPathsResponse),
+    /// Successful result returned for a `clipboard/read` request.
+    #[cfg(feature = "unstable_clipboard")]
+    ReadClipboardResponse(Box<ReadClipboardResponse>),
+    /// Successful result returned for a `clipboard/write` request.
+    #[cfg(feature = "unstable_clipboard")]
+    WriteClipboardResponse(#[serde(default)] WriteClipboardResponse),
+    /// Successful result returned for a `env/get` request.
+    #[cfg(feature = "unstable_env")]
+    GetEnvResponse(GetEnvResponse),
     /// Successful result returned by an extension method outside the core ACP method set.
     ExtMethodResponse(ExtResponse),
 }
@@ -2567,6 +4018,13 @@ pub enum AgentNotification {
     /// Receives an MCP-over-ACP notification.
     #[cfg(feature = "unstable_mcp_over_acp")]
     MessageMcpNotification(MessageMcpNotification),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Notification that the agent has finished warming up and can take prompts.
+    #[cfg(feature = "unstable_agent_ready")]
+    AgentReadyNotification(AgentReadyNotification),
     /// Handles extension notifications from the agent.
     ///
     /// Allows the Agent to send an arbitrary notification that is not part of the ACP spec.
@@ -2587,6 +4045,8 @@ impl AgentNotification {
             Self::CompleteElicitationNotification(_) => CLIENT_METHOD_NAMES.elicitation_complete,
             #[cfg(feature = "unstable_mcp_over_acp")]
             Self::MessageMcpNotification(_) => CLIENT_METHOD_NAMES.mcp_message,
+            #[cfg(feature = "unstable_agent_ready")]
+            Self::AgentReadyNotification(_) => CLIENT_METHOD_NAMES.agent_ready,
             Self::ExtNotification(ext_notification) => &ext_notification.method,
         }
     }
@@ -2635,6 +4095,124 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_session_update_is_transcript() {
+        use crate::v1::{ContentChunk, ToolCallUpdateFields};
+
+        #[cfg_attr(
+            not(any(
+                feature = "unstable_plan_operations",
+                feature = "unstable_plan_entry_update",
+                feature = "unstable_tool_calls_cancelled",
+                feature = "unstable_attachment",
+                feature = "unstable_input_request"
+            )),
+            allow(unused_mut)
+        )]
+        let mut updates = vec![
+            SessionUpdate::UserMessageChunk(ContentChunk::new("Hi".into())),
+            SessionUpdate::AgentMessageChunk(ContentChunk::new("Hello".into())),
+            SessionUpdate::AgentThoughtChunk(ContentChunk::new("Thinking".into())),
+            SessionUpdate::ToolCall(ToolCall::new("call_1", "Read")),
+            SessionUpdate::ToolCallUpdate(ToolCallUpdate::new(
+                "call_1",
+                ToolCallUpdateFields::new(),
+            )),
+            SessionUpdate::Plan(Plan::new(vec![])),
+        ];
+        #[cfg(feature = "unstable_plan_operations")]
+        updates.extend([
+            SessionUpdate::PlanUpdate(PlanUpdate::new(crate::v1::PlanUpdateContent::items(
+                "plan_1",
+                vec![],
+            ))),
+            SessionUpdate::PlanRemoved(PlanRemoved::new("plan_1")),
+        ]);
+        #[cfg(feature = "unstable_plan_entry_update")]
+        updates.push(SessionUpdate::PlanEntryUpdate(PlanEntryUpdate::new(0)));
+        #[cfg(feature = "unstable_tool_calls_cancelled")]
+        updates.push(SessionUpdate::ToolCallsCancelled(ToolCallsCancelled::new(
+            vec!["call_1".into()],
+        )));
+        #[cfg(feature = "unstable_attachment")]
+        updates.push(SessionUpdate::Attachment(Attachment::new(
+            "report.csv",
+            EmbeddedResource::new(EmbeddedResourceResource::TextResourceContents(
+                crate::v1::TextResourceContents::new("a,b\n", "file:///report.csv"),
+            )),
+        )));
+        #[cfg(feature = "unstable_input_request")]
+        updates.push(SessionUpdate::InputRequest(InputRequest::new(
+            "input_1",
+            "Which branch?",
+        )));
+
+        for update in updates {
+            assert!(update.is_transcript(), "{update:?}");
+        }
+    }
+
+    #[test]
+    fn test_session_update_is_ephemeral() {
+        #[cfg_attr(
+            not(any(
+                feature = "unstable_tool_call_input_delta",
+                feature = "unstable_session_notice",
+                feature = "unstable_suggested_prompts"
+            )),
+            allow(unused_mut)
+        )]
+        let mut updates = vec![
+            SessionUpdate::AvailableCommandsUpdate(AvailableCommandsUpdate::new(vec![])),
+            SessionUpdate::CurrentModeUpdate(CurrentModeUpdate::new("code")),
+            SessionUpdate::ConfigOptionUpdate(ConfigOptionUpdate::new(vec![])),
+            SessionUpdate::SessionInfoUpdate(SessionInfoUpdate::new().title("Refactor")),
+            SessionUpdate::UsageUpdate(UsageUpdate::new(10, 100)),
+        ];
+        #[cfg(feature = "unstable_tool_call_input_delta")]
+        updates.push(SessionUpdate::ToolCallInputDelta(ToolCallInputDelta::new(
+            "call_1", "{\"pa",
+        )));
+        #[cfg(feature = "unstable_session_notice")]
+        updates.push(SessionUpdate::Notice(Notice::new(
+            "Indexing…",
+            NoticeLevel::Info,
+        )));
+        #[cfg(feature = "unstable_suggested_prompts")]
+        updates.push(SessionUpdate::SuggestedPrompts(SuggestedPrompts::new(
+            vec!["Run tests".into()],
+        )));
+
+        for update in updates {
+            assert!(!update.is_transcript(), "{update:?}");
+        }
+    }
+
+    #[test]
+    fn test_session_update_to_markdown() {
+        use crate::v1::{ContentChunk, Plan};
+
+        assert_eq!(
+            SessionUpdate::UserMessageChunk(ContentChunk::new("Fix the *tests*".into()))
+                .to_markdown()
+                .as_deref(),
+            Some("Fix the *tests*")
+        );
+        assert_eq!(
+            SessionUpdate::AgentMessageChunk(ContentChunk::new("Done.".into()))
+                .to_markdown()
+                .as_deref(),
+            Some("Done.")
+        );
+        assert_eq!(
+            SessionUpdate::AgentThoughtChunk(ContentChunk::new("Check CI.\n\nThen rerun.".into()))
+                .to_markdown()
+                .as_deref(),
+            Some("> Check CI.\n>\n> Then rerun.")
+        );
+        assert_eq!(SessionUpdate::Plan(Plan::new(vec![])).to_markdown(), None);
+    }
+
     #[test]
     fn test_serialization_behavior() {
         use serde_json::json;
@@ -2673,116 +4251,506 @@ mod tests {
             json!({})
         );
         assert_eq!(
-            serde_json::to_value(SessionInfoUpdate::new().title("title")).unwrap(),
-            json!({"title": "title"})
+            serde_json::to_value(SessionInfoUpdate::new().title("title")).unwrap(),
+            json!({"title": "title"})
+        );
+        assert_eq!(
+            serde_json::to_value(SessionInfoUpdate::new().title(None)).unwrap(),
+            json!({"title": null})
+        );
+        assert_eq!(
+            serde_json::to_value(
+                SessionInfoUpdate::new()
+                    .title("title")
+                    .title(MaybeUndefined::Undefined)
+            )
+            .unwrap(),
+            json!({})
+        );
+    }
+
+    #[test]
+    fn test_content_chunk_message_id_serialization() {
+        use serde_json::json;
+
+        assert_eq!(
+            serde_json::to_value(SessionUpdate::AgentMessageChunk(ContentChunk::new(
+                ContentBlock::Text(crate::v1::TextContent::new("Hello"))
+            )))
+            .unwrap(),
+            json!({
+                "sessionUpdate": "agent_message_chunk",
+                "content": {
+                    "type": "text",
+                    "text": "Hello"
+                }
+            })
+        );
+
+        assert_eq!(
+            serde_json::to_value(SessionUpdate::AgentMessageChunk(
+                ContentChunk::new(ContentBlock::Text(crate::v1::TextContent::new("Hello")))
+                    .message_id("msg_agent_c42b9")
+            ))
+            .unwrap(),
+            json!({
+                "sessionUpdate": "agent_message_chunk",
+                "messageId": "msg_agent_c42b9",
+                "content": {
+                    "type": "text",
+                    "text": "Hello"
+                }
+            })
+        );
+
+        let SessionUpdate::AgentMessageChunk(chunk) = serde_json::from_value(json!({
+            "sessionUpdate": "agent_message_chunk",
+            "messageId": null,
+            "content": {
+                "type": "text",
+                "text": "Hello"
+            }
+        }))
+        .unwrap() else {
+            panic!("expected agent message chunk");
+        };
+
+        assert_eq!(chunk.message_id, None);
+    }
+
+    #[test]
+    fn test_available_commands_update_roundtrip() {
+        use serde_json::json;
+
+        let update = SessionUpdate::AvailableCommandsUpdate(AvailableCommandsUpdate::new(vec![
+            AvailableCommand::new("compact", "Summarize the conversation so far"),
+            AvailableCommand::new("test", "Run the test suite").input(
+                AvailableCommandInput::Unstructured(UnstructuredCommandInput::new(
+                    "test name filter",
+                )),
+            ),
+        ]));
+
+        let json = serde_json::to_value(&update).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "available_commands_update",
+                "availableCommands": [
+                    {
+                        "name": "compact",
+                        "description": "Summarize the conversation so far"
+                    },
+                    {
+                        "name": "test",
+                        "description": "Run the test suite",
+                        "input": { "hint": "test name filter" }
+                    }
+                ]
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SessionUpdate>(json).unwrap(),
+            update
+        );
+
+        let SessionUpdate::AvailableCommandsUpdate(cleared) = serde_json::from_value(json!({
+            "sessionUpdate": "available_commands_update",
+            "availableCommands": []
+        }))
+        .unwrap() else {
+            panic!("expected available commands update");
+        };
+        assert!(cleared.available_commands.is_empty());
+    }
+
+    #[test]
+    fn test_usage_update_serialization() {
+        use serde_json::json;
+
+        assert_eq!(
+            serde_json::to_value(SessionUpdate::UsageUpdate(UsageUpdate::new(
+                53_000, 200_000
+            )))
+            .unwrap(),
+            json!({
+                "sessionUpdate": "usage_update",
+                "used": 53000,
+                "size": 200_000
+            })
+        );
+
+        assert_eq!(
+            serde_json::to_value(SessionUpdate::UsageUpdate(
+                UsageUpdate::new(53_000, 200_000).cost(Cost::new(0.045, "USD"))
+            ))
+            .unwrap(),
+            json!({
+                "sessionUpdate": "usage_update",
+                "used": 53000,
+                "size": 200_000,
+                "cost": {
+                    "amount": 0.045,
+                    "currency": "USD"
+                }
+            })
+        );
+
+        let SessionUpdate::UsageUpdate(update) = serde_json::from_value(json!({
+            "sessionUpdate": "usage_update",
+            "used": 53000,
+            "size": 200_000,
+            "cost": null
+        }))
+        .unwrap() else {
+            panic!("expected usage update");
+        };
+
+        assert_eq!(update.cost, None);
+    }
+
+    #[cfg(feature = "unstable_session_notice")]
+    #[test]
+    fn test_notice_serialization() {
+        use serde_json::json;
+
+        let notice = SessionUpdate::Notice(Notice::new("Searching the web...", NoticeLevel::Info));
+        let json = serde_json::to_value(&notice).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "notice",
+                "text": "Searching the web...",
+                "level": "info"
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SessionUpdate>(json).unwrap(),
+            notice
+        );
+
+        let SessionUpdate::Notice(cleared) = serde_json::from_value(json!({
+            "sessionUpdate": "notice",
+            "text": "",
+            "level": "warning"
+        }))
+        .unwrap() else {
+            panic!("expected notice");
+        };
+        assert!(cleared.is_clear());
+        assert_eq!(cleared.level, NoticeLevel::Warning);
+        assert!(Notice::clear().is_clear());
+    }
+
+    #[cfg(feature = "unstable_tool_calls_cancelled")]
+    #[test]
+    fn test_tool_calls_cancelled_serialization() {
+        use serde_json::json;
+
+        let update = SessionUpdate::ToolCallsCancelled(ToolCallsCancelled::new(vec![
+            ToolCallId::new("call_001"),
+            ToolCallId::new("call_002"),
+        ]));
+        let json = serde_json::to_value(&update).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "tool_calls_cancelled",
+                "toolCallIds": ["call_001", "call_002"]
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SessionUpdate>(json).unwrap(),
+            update
+        );
+
+        let SessionUpdate::ToolCallsCancelled(all) = serde_json::from_value(json!({
+            "sessionUpdate": "tool_calls_cancelled",
+            "toolCallIds": []
+        }))
+        .unwrap() else {
+            panic!("expected tool_calls_cancelled");
+        };
+        assert_eq!(all, ToolCallsCancelled::all());
+        assert!(all.contains(&ToolCallId::new("call_003")));
+
+        // An invalid id must not be dropped, which would widen the update to
+        // every tool call.
+        assert!(!matches!(
+            serde_json::from_value::<SessionUpdate>(json!({
+                "sessionUpdate": "tool_calls_cancelled",
+                "toolCallIds": [1]
+            })),
+            Ok(SessionUpdate::ToolCallsCancelled(_))
+        ));
+    }
+
+    #[cfg(feature = "unstable_attachment")]
+    #[test]
+    fn test_attachment_serialization() {
+        use crate::v1::{BlobResourceContents, TextResourceContents};
+        use serde_json::json;
+
+        let text = SessionUpdate::Attachment(Attachment::new(
+            "report.md",
+            EmbeddedResource::new(EmbeddedResourceResource::TextResourceContents(
+                TextResourceContents::new("# Report\n", "attachment:report.md"),
+            )),
+        ));
+        let json = serde_json::to_value(&text).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "attachment",
+                "name": "report.md",
+                "content": {
+                    "resource": {
+                        "text": "# Report\n",
+                        "uri": "attachment:report.md"
+                    }
+                }
+            })
+        );
+        assert_eq!(serde_json::from_value::<SessionUpdate>(json).unwrap(), text);
+
+        let blob = SessionUpdate::Attachment(Attachment::new(
+            "chart.png",
+            EmbeddedResource::new(EmbeddedResourceResource::BlobResourceContents(
+                BlobResourceContents::new("iVBORw0KGgo=", "attachment:chart.png")
+                    .mime_type("image/png"),
+            )),
+        ));
+        let json = serde_json::to_value(&blob).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "attachment",
+                "name": "chart.png",
+                "content": {
+                    "resource": {
+                        "blob": "iVBORw0KGgo=",
+                        "mimeType": "image/png",
+                        "uri": "attachment:chart.png"
+                    }
+                }
+            })
+        );
+        assert_eq!(serde_json::from_value::<SessionUpdate>(json).unwrap(), blob);
+
+        let SessionUpdate::Attachment(text) = text else {
+            unreachable!()
+        };
+        assert_eq!(text.mime_type(), "text/plain");
+        let SessionUpdate::Attachment(blob) = blob else {
+            unreachable!()
+        };
+        assert_eq!(blob.mime_type(), "image/png");
+    }
+
+    #[cfg(feature = "unstable_suggested_prompts")]
+    #[test]
+    fn test_suggested_prompts_serialization() {
+        use serde_json::json;
+
+        let update = SessionUpdate::SuggestedPrompts(SuggestedPrompts::new(vec![
+            "Add tests for the parser".to_string(),
+            "Explain the change".to_string(),
+        ]));
+        let json = serde_json::to_value(&update).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "suggested_prompts",
+                "prompts": ["Add tests for the parser", "Explain the change"]
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SessionUpdate>(json).unwrap(),
+            update
         );
+
+        let cleared = SessionUpdate::SuggestedPrompts(SuggestedPrompts::new(vec![]));
+        let json = serde_json::to_value(&cleared).unwrap();
         assert_eq!(
-            serde_json::to_value(SessionInfoUpdate::new().title(None)).unwrap(),
-            json!({"title": null})
+            json,
+            json!({
+                "sessionUpdate": "suggested_prompts",
+                "prompts": []
+            })
         );
         assert_eq!(
-            serde_json::to_value(
-                SessionInfoUpdate::new()
-                    .title("title")
-                    .title(MaybeUndefined::Undefined)
-            )
-            .unwrap(),
-            json!({})
+            serde_json::from_value::<SessionUpdate>(json).unwrap(),
+            cleared
         );
     }
 
     #[test]
-    fn test_content_chunk_message_id_serialization() {
+    fn test_request_permission_for_tool_call_with_presets() {
         use serde_json::json;
 
+        use crate::v1::{ToolCall, ToolKind};
+
+        let request = RequestPermissionRequest::for_tool_call(
+            "sess_1",
+            ToolCall::new("call_1", "Delete build/").kind(ToolKind::Delete),
+            vec![
+                PermissionOption::allow_once(),
+                PermissionOption::allow_always(),
+                PermissionOption::reject_once(),
+                PermissionOption::reject_always(),
+            ],
+        );
+
         assert_eq!(
-            serde_json::to_value(SessionUpdate::AgentMessageChunk(ContentChunk::new(
-                ContentBlock::Text(crate::v1::TextContent::new("Hello"))
-            )))
-            .unwrap(),
+            request
+                .options
+                .iter()
+                .map(|option| option.kind)
+                .collect::<Vec<_>>(),
+            vec![
+                PermissionOptionKind::AllowOnce,
+                PermissionOptionKind::AllowAlways,
+                PermissionOptionKind::RejectOnce,
+                PermissionOptionKind::RejectAlways,
+            ]
+        );
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
             json!({
-                "sessionUpdate": "agent_message_chunk",
-                "content": {
-                    "type": "text",
-                    "text": "Hello"
-                }
+                "sessionId": "sess_1",
+                "toolCall": {
+                    "toolCallId": "call_1",
+                    "title": "Delete build/",
+                    "kind": "delete",
+                    "status": "pending",
+                    "content": [],
+                    "locations": []
+                },
+                "options": [
+                    { "optionId": "allow-once", "name": "Allow once", "kind": "allow_once" },
+                    { "optionId": "allow-always", "name": "Always allow", "kind": "allow_always" },
+                    { "optionId": "reject-once", "name": "Reject", "kind": "reject_once" },
+                    { "optionId": "reject-always", "name": "Always reject", "kind": "reject_always" }
+                ]
             })
         );
+    }
+
+    #[test]
+    fn test_client_capabilities_shorthands_and_presets() {
+        let capabilities = ClientCapabilities::new().read_text_file(true);
+        assert!(capabilities.fs.read_text_file);
+        assert!(!capabilities.fs.write_text_file);
 
+        let capabilities = ClientCapabilities::new().write_text_file(true);
+        assert!(!capabilities.fs.read_text_file);
+        assert!(capabilities.fs.write_text_file);
+
+        let capabilities = ClientCapabilities::new().terminal(true);
+        assert_eq!(capabilities.fs, FileSystemCapabilities::new());
+        assert!(capabilities.terminal);
+
+        let full = ClientCapabilities::full();
         assert_eq!(
-            serde_json::to_value(SessionUpdate::AgentMessageChunk(
-                ContentChunk::new(ContentBlock::Text(crate::v1::TextContent::new("Hello")))
-                    .message_id("msg_agent_c42b9")
-            ))
-            .unwrap(),
-            json!({
-                "sessionUpdate": "agent_message_chunk",
-                "messageId": "msg_agent_c42b9",
-                "content": {
-                    "type": "text",
-                    "text": "Hello"
-                }
-            })
+            full,
+            ClientCapabilities::new()
+                .fs(FileSystemCapabilities::new()
+                    .read_text_file(true)
+                    .write_text_file(true))
+                .terminal(true)
         );
 
-        let SessionUpdate::AgentMessageChunk(chunk) = serde_json::from_value(json!({
-            "sessionUpdate": "agent_message_chunk",
-            "messageId": null,
-            "content": {
-                "type": "text",
-                "text": "Hello"
-            }
-        }))
-        .unwrap() else {
-            panic!("expected agent message chunk");
-        };
+        let read_only = ClientCapabilities::read_only();
+        assert_eq!(
+            read_only.fs,
+            FileSystemCapabilities::new().read_text_file(true)
+        );
+        assert!(!read_only.terminal);
+    }
 
-        assert_eq!(chunk.message_id, None);
+    #[cfg(feature = "unstable_permission_inline_tool_call")]
+    #[test]
+    fn test_permission_inline_tool_call_capability() {
+        use crate::v1::{ToolCallUpdate, ToolCallUpdateFields};
+        use serde_json::json;
+
+        let capabilities = ClientCapabilities::new()
+            .permission(PermissionCapabilities::new().inline_tool_call(true));
+        let json = serde_json::to_value(&capabilities).unwrap();
+        assert_eq!(
+            json["permission"],
+            json!({"permission": {"inlineToolCall": true}})["permission"]
+        );
+        assert_eq!(
+            serde_json::from_value::<ClientCapabilities>(json).unwrap(),
+            capabilities
+        );
+        assert!(capabilities.supports_inline_tool_call());
+        assert!(!ClientCapabilities::new().supports_inline_tool_call());
+
+        let inline = ToolCallUpdate::new(
+            "call_001",
+            ToolCallUpdateFields::new().title("Write config.json"),
+        );
+        assert_eq!(
+            serde_json::to_value(inline.clone().for_permission_request(&capabilities)).unwrap(),
+            json!({"toolCallId": "call_001", "title": "Write config.json"})
+        );
+        assert_eq!(
+            serde_json::to_value(inline.for_permission_request(&ClientCapabilities::new()))
+                .unwrap(),
+            json!({"toolCallId": "call_001"})
+        );
     }
 
+    #[cfg(feature = "unstable_diff_hunks")]
     #[test]
-    fn test_usage_update_serialization() {
+    fn test_permission_response_hunk_decisions_serialization() {
+        use crate::v1::{Diff, DiffHunk, DiffLineRange};
         use serde_json::json;
 
+        let response = RequestPermissionResponse::new(RequestPermissionOutcome::Selected(
+            SelectedPermissionOutcome::new("allow").hunk_decisions(vec![
+                DiffHunkDecision::new("/src/main.rs", "0", true),
+                DiffHunkDecision::new("/src/main.rs", "1", false),
+            ]),
+        ));
+        let json = serde_json::to_value(&response).unwrap();
         assert_eq!(
-            serde_json::to_value(SessionUpdate::UsageUpdate(UsageUpdate::new(
-                53_000, 200_000
-            )))
-            .unwrap(),
+            json,
             json!({
-                "sessionUpdate": "usage_update",
-                "used": 53000,
-                "size": 200_000
+                "outcome": {
+                    "outcome": "selected",
+                    "optionId": "allow",
+                    "hunkDecisions": [
+                        {"path": "/src/main.rs", "hunkId": "0", "accepted": true},
+                        {"path": "/src/main.rs", "hunkId": "1", "accepted": false}
+                    ]
+                }
             })
         );
+        assert_eq!(
+            serde_json::from_value::<RequestPermissionResponse>(json).unwrap(),
+            response
+        );
 
+        let diff = Diff::new("/src/main.rs", "b\n").old_text("a\n").hunks(vec![
+            DiffHunk::new("0", DiffLineRange::new(1, 1), DiffLineRange::new(1, 1)).accepted(true),
+        ]);
         assert_eq!(
-            serde_json::to_value(SessionUpdate::UsageUpdate(
-                UsageUpdate::new(53_000, 200_000).cost(Cost::new(0.045, "USD"))
-            ))
-            .unwrap(),
+            serde_json::to_value(&diff).unwrap(),
             json!({
-                "sessionUpdate": "usage_update",
-                "used": 53000,
-                "size": 200_000,
-                "cost": {
-                    "amount": 0.045,
-                    "currency": "USD"
-                }
+                "path": "/src/main.rs",
+                "oldText": "a\n",
+                "newText": "b\n",
+                "hunks": [{
+                    "id": "0",
+                    "oldRange": {"start": 1, "count": 1},
+                    "newRange": {"start": 1, "count": 1},
+                    "accepted": true
+                }]
             })
         );
-
-        let SessionUpdate::UsageUpdate(update) = serde_json::from_value(json!({
-            "sessionUpdate": "usage_update",
-            "used": 53000,
-            "size": 200_000,
-            "cost": null
-        }))
-        .unwrap() else {
-            panic!("expected usage update");
-        };
-
-        assert_eq!(update.cost, None);
     }
 
     #[cfg(feature = "unstable_nes")]
@@ -2799,6 +4767,34 @@ mod tests {
         assert_eq!(json["positionEncodings"], json!(["utf-32", "utf-16"]));
     }
 
+    #[cfg(feature = "unstable_table")]
+    #[test]
+    fn test_client_capabilities_table_serialization() {
+        use serde_json::json;
+
+        let capabilities = ClientCapabilities::new().table(TableCapabilities::new());
+        let json = serde_json::to_value(&capabilities).unwrap();
+        assert_eq!(json["table"], json!({}));
+
+        let capabilities: ClientCapabilities =
+            serde_json::from_value(json!({ "table": "yes" })).unwrap();
+        assert_eq!(capabilities.table, None);
+    }
+
+    #[cfg(feature = "unstable_json_content")]
+    #[test]
+    fn test_client_capabilities_json_content_serialization() {
+        use serde_json::json;
+
+        let capabilities = ClientCapabilities::new().json_content(JsonContentCapabilities::new());
+        let json = serde_json::to_value(&capabilities).unwrap();
+        assert_eq!(json["jsonContent"], json!({}));
+
+        let capabilities: ClientCapabilities =
+            serde_json::from_value(json!({ "jsonContent": true })).unwrap();
+        assert_eq!(capabilities.json_content, None);
+    }
+
     #[cfg(feature = "unstable_boolean_config")]
     #[test]
     fn test_client_capabilities_boolean_config_options_serialization() {
@@ -2907,6 +4903,103 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "unstable_plan_entry_update")]
+    #[test]
+    fn test_plan_entry_update_serialization() {
+        use serde_json::json;
+
+        use crate::v1::PlanEntryStatus;
+
+        let update = SessionUpdate::PlanEntryUpdate(
+            PlanEntryUpdate::new(2).status(PlanEntryStatus::InProgress),
+        );
+        let json = serde_json::to_value(&update).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "plan_entry_update",
+                "index": 2,
+                "status": "in_progress"
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SessionUpdate>(json).unwrap(),
+            update
+        );
+    }
+
+    #[cfg(feature = "unstable_plan_entry_update")]
+    #[test]
+    fn test_plan_apply_entry_update() {
+        use crate::v1::{Plan, PlanEntry, PlanEntryPriority, PlanEntryStatus};
+
+        let mut plan = Plan::new(vec![
+            PlanEntry::new(
+                "Read the code",
+                PlanEntryPriority::High,
+                PlanEntryStatus::Completed,
+            ),
+            PlanEntry::new(
+                "Write the fix",
+                PlanEntryPriority::High,
+                PlanEntryStatus::Pending,
+            ),
+            PlanEntry::new(
+                "Run the tests",
+                PlanEntryPriority::Medium,
+                PlanEntryStatus::Pending,
+            ),
+        ]);
+
+        plan.apply(PlanEntryUpdate::new(1).status(PlanEntryStatus::InProgress));
+        assert_eq!(
+            plan.entries,
+            vec![
+                PlanEntry::new(
+                    "Read the code",
+                    PlanEntryPriority::High,
+                    PlanEntryStatus::Completed
+                ),
+                PlanEntry::new(
+                    "Write the fix",
+                    PlanEntryPriority::High,
+                    PlanEntryStatus::InProgress
+                ),
+                PlanEntry::new(
+                    "Run the tests",
+                    PlanEntryPriority::Medium,
+                    PlanEntryStatus::Pending
+                ),
+            ]
+        );
+
+        plan.apply(PlanEntryUpdate::new(2).content("Run the unit tests".to_string()));
+        assert_eq!(
+            plan.entries,
+            vec![
+                PlanEntry::new(
+                    "Read the code",
+                    PlanEntryPriority::High,
+                    PlanEntryStatus::Completed
+                ),
+                PlanEntry::new(
+                    "Write the fix",
+                    PlanEntryPriority::High,
+                    PlanEntryStatus::InProgress
+                ),
+                PlanEntry::new(
+                    "Run the unit tests",
+                    PlanEntryPriority::Medium,
+                    PlanEntryStatus::Pending
+                ),
+            ]
+        );
+
+        let before = plan.clone();
+        plan.apply(PlanEntryUpdate::new(3).status(PlanEntryStatus::Completed));
+        assert_eq!(plan, before);
+    }
+
     #[cfg(feature = "unstable_mcp_over_acp")]
     #[test]
     fn test_agent_mcp_request_method_names() {
@@ -2982,4 +5075,237 @@ mod tests {
         .unwrap();
         assert_eq!(request_with_null_params.params, None);
     }
+
+    #[cfg(feature = "unstable_write_line_ending")]
+    #[test]
+    fn test_write_text_file_line_ending() {
+        use serde_json::json;
+
+        let request = WriteTextFileRequest::new("sess_1", "/notes.txt", "a\nb\n")
+            .line_ending(LineEnding::CrLf);
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "sessionId": "sess_1",
+                "path": "/notes.txt",
+                "content": "a\nb\n",
+                "lineEnding": "crlf"
+            })
+        );
+
+        let request: WriteTextFileRequest = serde_json::from_value(json!({
+            "sessionId": "sess_1",
+            "path": "/notes.txt",
+            "content": "a\nb\n",
+            "lineEnding": "native"
+        }))
+        .unwrap();
+        assert_eq!(request.line_ending, None);
+        assert_eq!(request.line_ending.unwrap_or_default(), LineEnding::Lf);
+
+        let content = "a\r\nb\nc";
+        assert_eq!(LineEnding::Lf.normalize(content, None), "a\nb\nc");
+        assert_eq!(LineEnding::CrLf.normalize(content, None), "a\r\nb\r\nc");
+        assert_eq!(
+            LineEnding::Preserve.normalize(content, Some("x\r\ny\r\nz\n")),
+            "a\r\nb\r\nc"
+        );
+        assert_eq!(
+            LineEnding::Preserve.normalize(content, Some("x\ny\nz\r\n")),
+            "a\nb\nc"
+        );
+        assert_eq!(LineEnding::Preserve.normalize(content, None), "a\nb\nc");
+    }
+
+    #[cfg(feature = "unstable_write_dry_run")]
+    #[test]
+    fn test_write_text_file_dry_run() {
+        use serde_json::json;
+
+        let capabilities = FileSystemCapabilities::new()
+            .write_text_file(true)
+            .write_text_file_dry_run(true);
+        #[cfg_attr(not(feature = "unstable_fs_watch"), allow(unused_mut))]
+        let mut expected = json!({
+            "readTextFile": false,
+            "writeTextFile": true,
+            "writeTextFileDryRun": true
+        });
+        #[cfg(feature = "unstable_fs_watch")]
+        {
+            expected["watch"] = json!(false);
+        }
+        assert_eq!(serde_json::to_value(&capabilities).unwrap(), expected);
+
+        let request = WriteTextFileRequest::new("sess_1", "/notes.txt", "a\nB\n").dry_run(true);
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "sessionId": "sess_1",
+                "path": "/notes.txt",
+                "content": "a\nB\n",
+                "dryRun": true
+            })
+        );
+
+        let response = WriteTextFileResponse::new().diff(request.preview(Some("a\nb\n")));
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "diff": {
+                    "path": "/notes.txt",
+                    "oldText": "a\nb\n",
+                    "newText": "a\nB\n"
+                }
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<WriteTextFileResponse>(json).unwrap(),
+            response
+        );
+
+        assert_eq!(request.preview(None).old_text, None);
+    }
+
+    #[cfg(feature = "unstable_write_dry_run")]
+    #[test]
+    fn test_write_text_file_without_dry_run() {
+        use serde_json::json;
+
+        let request = WriteTextFileRequest::new("sess_1", "/notes.txt", "a\n");
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "sessionId": "sess_1",
+                "path": "/notes.txt",
+                "content": "a\n"
+            })
+        );
+
+        let request: WriteTextFileRequest = serde_json::from_value(json!({
+            "sessionId": "sess_1",
+            "path": "/notes.txt",
+            "content": "a\n"
+        }))
+        .unwrap();
+        assert!(!request.dry_run);
+
+        // A malformed flag must not silently become a real write.
+        for dry_run in [json!("true"), json!(1)] {
+            assert!(
+                serde_json::from_value::<WriteTextFileRequest>(json!({
+                    "sessionId": "sess_1",
+                    "path": "/notes.txt",
+                    "content": "a\n",
+                    "dryRun": dry_run
+                }))
+                .is_err()
+            );
+        }
+
+        assert_eq!(
+            serde_json::to_value(WriteTextFileResponse::new()).unwrap(),
+            json!({})
+        );
+        let response: WriteTextFileResponse =
+            serde_json::from_value(json!({ "diff": { "path": "/notes.txt" } })).unwrap();
+        assert_eq!(response.diff, None);
+    }
+
+    #[cfg(all(
+        feature = "unstable_write_dry_run",
+        feature = "unstable_write_line_ending"
+    ))]
+    #[test]
+    fn test_write_text_file_preview_applies_line_ending() {
+        let request = WriteTextFileRequest::new("sess_1", "/notes.txt", "a\nb\n")
+            .line_ending(LineEnding::Preserve)
+            .dry_run(true);
+        assert_eq!(request.preview(Some("a\r\n")).new_text, "a\r\nb\r\n");
+    }
+
+    #[cfg(feature = "unstable_read_file_type")]
+    #[test]
+    fn test_read_text_file_response_file_type() {
+        use serde_json::json;
+
+        let response = ReadTextFileResponse::new("fn main() {}\n")
+            .mime_type("text/rust")
+            .language_id("rust");
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            json!({
+                "content": "fn main() {}\n",
+                "mimeType": "text/rust",
+                "languageId": "rust"
+            })
+        );
+
+        let response: ReadTextFileResponse =
+            serde_json::from_value(json!({"content": "???"})).unwrap();
+        assert_eq!(response.mime_type, None);
+        assert_eq!(response.language_id, None);
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            json!({"content": "???"})
+        );
+    }
+
+    #[test]
+    fn test_read_text_file_select_lines() {
+        let text = "one\ntwo\r\nthree\nfour";
+        let request = ReadTextFileRequest::new("sess", "/file.txt");
+
+        assert_eq!(request.clone().select_lines(text), text);
+        assert_eq!(request.clone().line(1).limit(1).select_lines(text), "one\n");
+        assert_eq!(request.clone().line(0).limit(1).select_lines(text), "one\n");
+        assert_eq!(
+            request.clone().line(2).limit(2).select_lines(text),
+            "two\r\nthree\n"
+        );
+        assert_eq!(
+            request.clone().line(3).limit(10).select_lines(text),
+            "three\nfour"
+        );
+        assert_eq!(request.clone().line(4).select_lines(text), "four");
+        assert_eq!(request.clone().line(5).select_lines(text), "");
+        assert_eq!(request.clone().line(100).limit(1).select_lines(text), "");
+        assert_eq!(request.clone().limit(0).select_lines(text), "");
+        assert_eq!(request.line(2).select_lines(""), "");
+    }
+
+    #[cfg(feature = "unstable_user_message_echo")]
+    #[test]
+    fn test_user_message_chunk_echo() {
+        use serde_json::json;
+
+        let update = SessionUpdate::UserMessageChunk(ContentChunk::new("hello".into()).echo(true));
+        let json = serde_json::to_value(&update).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "user_message_chunk",
+                "content": {"type": "text", "text": "hello"},
+                "echo": true
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SessionUpdate>(json).unwrap(),
+            update
+        );
+
+        let SessionUpdate::UserMessageChunk(chunk) = serde_json::from_value(json!({
+            "sessionUpdate": "user_message_chunk",
+            "content": {"type": "text", "text": "hello"}
+        }))
+        .unwrap() else {
+            panic!("expected a user message chunk");
+        };
+        assert!(!chunk.echo);
+        assert_eq!(
+            serde_json::to_value(&chunk).unwrap(),
+            json!({"content": {"type": "text", "text": "hello"}})
+        );
+    }
 }
@@ -0,0 +1,367 @@
+//! Clipboard types and constants.
+//!
+//! **UNSTABLE**: This module is not part of the spec yet, and may be removed or changed at any point.
+//!
+//! Agents that need what the user copied, or want to hand something back for
+//! pasting, ask the client with `clipboard/read` and `clipboard/write`. The
+//! clipboard sits outside the workspace and often holds secrets, so clients
+//! ask the user before granting either request.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnError, serde_as, skip_serializing_none};
+
+use crate::IntoOption;
+
+use super::{ContentBlock, Meta, SessionId};
+
+// Method name constants
+
+/// Method name for reading the user's clipboard.
+pub(crate) const CLIPBOARD_READ_METHOD_NAME: &str = "clipboard/read";
+/// Method name for writing the user's clipboard.
+pub(crate) const CLIPBOARD_WRITE_METHOD_NAME: &str = "clipboard/write";
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Clipboard capabilities supported by the client.
+///
+/// Supplying `{}` advertises neither method; set `read` or `write` to enable them.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ClipboardCapabilities {
+    /// Whether the Client supports `clipboard/read` requests.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub read: bool,
+    /// Whether the Client supports `clipboard/write` requests.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub write: bool,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl ClipboardCapabilities {
+    /// Builds an empty [`ClipboardCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the Client supports `clipboard/read` requests.
+    #[must_use]
+    pub fn read(mut self, read: bool) -> Self {
+        self.read = read;
+        self
+    }
+
+    /// Whether the Client supports `clipboard/write` requests.
+    #[must_use]
+    pub fn write(mut self, write: bool) -> Self {
+        self.write = write;
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request to read the user's clipboard.
+///
+/// Only available if the client supports the `clipboard.read` capability.
+/// Clients MUST ask the user before returning the clipboard contents, and
+/// respond with an error if the user declines.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "client", "x-method" = CLIPBOARD_READ_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ReadClipboardRequest {
+    /// The session ID for this request.
+    pub session_id: SessionId,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl ReadClipboardRequest {
+    /// Builds [`ReadClipboardRequest`] with the required request fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(session_id: impl Into<SessionId>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response to `clipboard/read`.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[schemars(extend("x-side" = "client", "x-method" = CLIPBOARD_READ_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ReadClipboardResponse {
+    /// The clipboard contents, such as text or an image.
+    pub content: ContentBlock,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl ReadClipboardResponse {
+    /// Builds [`ReadClipboardResponse`] with the required response fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(content: ContentBlock) -> Self {
+        Self {
+            content,
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request to replace the user's clipboard contents.
+///
+/// Only available if the client supports the `clipboard.write` capability.
+/// Clients MUST ask the user before overwriting the clipboard, and respond
+/// with an error if the user declines.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[schemars(extend("x-side" = "client", "x-method" = CLIPBOARD_WRITE_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct WriteClipboardRequest {
+    /// The session ID for this request.
+    pub session_id: SessionId,
+    /// The content to place on the clipboard, such as text or an image.
+    pub content: ContentBlock,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl WriteClipboardRequest {
+    /// Builds [`WriteClipboardRequest`] with the required request fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(session_id: impl Into<SessionId>, content: ContentBlock) -> Self {
+        Self {
+            session_id: session_id.into(),
+            content,
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response to `clipboard/write`.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "client", "x-method" = CLIPBOARD_WRITE_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct WriteClipboardResponse {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl WriteClipboardResponse {
+    /// Builds [`WriteClipboardResponse`] with the required response fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::v1::{AgentRequest, CLIENT_METHOD_NAMES, ClientCapabilities, ImageContent};
+
+    #[test]
+    fn test_clipboard_capability() {
+        let capabilities =
+            ClientCapabilities::new().clipboard(ClipboardCapabilities::new().read(true));
+        let json = serde_json::to_value(&capabilities).unwrap();
+        assert_eq!(json["clipboard"], json!({ "read": true, "write": false }));
+
+        let capabilities: ClientCapabilities =
+            serde_json::from_value(json!({ "clipboard": { "write": "yes" } })).unwrap();
+        assert_eq!(capabilities.clipboard, Some(ClipboardCapabilities::new()));
+    }
+
+    #[test]
+    fn test_read_clipboard_roundtrip() {
+        let request = ReadClipboardRequest::new("sess");
+        let routed = AgentRequest::ReadClipboardRequest(request.clone());
+        assert_eq!(routed.method(), CLIENT_METHOD_NAMES.clipboard_read);
+
+        let json = serde_json::to_value(&routed).unwrap();
+        assert_eq!(json, json!({ "sessionId": "sess" }));
+        assert_eq!(
+            serde_json::from_value::<ReadClipboardRequest>(json).unwrap(),
+            request
+        );
+
+        let response = ReadClipboardResponse::new(ContentBlock::Image(ImageContent::new(
+            "iVBORw0KGgo=",
+            "image/png",
+        )));
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "content": {
+                    "type": "image",
+                    "data": "iVBORw0KGgo=",
+                    "mimeType": "image/png"
+                }
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<ReadClipboardResponse>(json).unwrap(),
+            response
+        );
+    }
+
+    #[test]
+    fn test_write_clipboard_roundtrip() {
+        let request = WriteClipboardRequest::new("sess", "cargo test --workspace".into());
+        let routed = AgentRequest::WriteClipboardRequest(request.clone());
+        assert_eq!(routed.method(), CLIENT_METHOD_NAMES.clipboard_write);
+
+        let json = serde_json::to_value(&routed).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionId": "sess",
+                "content": { "type": "text", "text": "cargo test --workspace" }
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<WriteClipboardRequest>(json).unwrap(),
+            request
+        );
+        assert_eq!(
+            serde_json::to_value(WriteClipboardResponse::new()).unwrap(),
+            json!({})
+        );
+    }
+}
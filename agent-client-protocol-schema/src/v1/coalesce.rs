@@ -0,0 +1,183 @@
+//! Coalescing of streamed message chunks.
+//!
+//! Agents often stream replies as many small `agent_message_chunk` and
+//! `agent_thought_chunk` updates. Clients can pass updates through a
+//! [`ChunkCoalescer`] to merge adjacent text into fewer, larger updates before
+//! rendering them.
+
+use crate::IntoOption;
+
+use super::{ContentBlock, ContentChunk, SessionUpdate};
+
+/// Merges consecutive text chunks of the same kind into a single update.
+///
+/// Adjacent `agent_message_chunk` updates are merged with each other, as are
+/// adjacent `agent_thought_chunk` updates, as long as they belong to the same
+/// message and carry the same annotations and metadata. Any other update ends
+/// the current run: the buffered text is emitted first, followed by the update
+/// itself, so ordering is preserved.
+///
+/// The coalescer has no clock. Clients that want time-based flushing should
+/// call [`ChunkCoalescer::flush`] from their own timer, and at the end of a turn.
+#[derive(Debug, Default)]
+pub struct ChunkCoalescer {
+    pending: Option<SessionUpdate>,
+    max_len: Option<usize>,
+}
+
+impl ChunkCoalescer {
+    /// Builds a [`ChunkCoalescer`] with no size limit.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Emits the buffered text once it reaches this many bytes.
+    #[must_use]
+    pub fn max_len(mut self, max_len: impl IntoOption<usize>) -> Self {
+        self.max_len = max_len.into_option();
+        self
+    }
+
+    /// Feeds an update into the coalescer and returns the updates that are ready
+    /// to be rendered, in order.
+    pub fn push(&mut self, update: SessionUpdate) -> Vec<SessionUpdate> {
+        let mut ready = Vec::new();
+        let merged = self
+            .pending
+            .as_mut()
+            .is_some_and(|pending| merge_text_chunk(pending, &update));
+        if !merged {
+            ready.extend(self.pending.take());
+            if text_len(&update).is_some() {
+                self.pending = Some(update);
+            } else {
+                ready.push(update);
+            }
+        }
+        if self.is_full() {
+            ready.extend(self.pending.take());
+        }
+        ready
+    }
+
+    /// Emits the buffered text, if any.
+    #[must_use]
+    pub fn flush(&mut self) -> Option<SessionUpdate> {
+        self.pending.take()
+    }
+
+    fn is_full(&self) -> bool {
+        let len = self.pending.as_ref().and_then(text_len).unwrap_or(0);
+        self.max_len.is_some_and(|max_len| len >= max_len)
+    }
+}
+
+fn text_len(update: &SessionUpdate) -> Option<usize> {
+    match update {
+        SessionUpdate::AgentMessageChunk(ContentChunk {
+            content: ContentBlock::Text(text),
+            ..
+        })
+        | SessionUpdate::AgentThoughtChunk(ContentChunk {
+            content: ContentBlock::Text(text),
+            ..
+        }) => Some(text.text.len()),
+        _ => None,
+    }
+}
+
+fn merge_text_chunk(pending: &mut SessionUpdate, update: &SessionUpdate) -> bool {
+    let ((SessionUpdate::AgentMessageChunk(into), SessionUpdate::AgentMessageChunk(from))
+    | (SessionUpdate::AgentThoughtChunk(into), SessionUpdate::AgentThoughtChunk(from))) =
+        (pending, update)
+    else {
+        return false;
+    };
+    let (ContentBlock::Text(into_text), ContentBlock::Text(from_text)) =
+        (&mut into.content, &from.content)
+    else {
+        return false;
+    };
+    if into.message_id != from.message_id
+        || into.meta != from.meta
+        || into_text.annotations != from_text.annotations
+        || into_text.meta != from_text.meta
+    {
+        return false;
+    }
+    into_text.push_str(&from_text.text);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::{ImageContent, ToolCall};
+
+    fn message(text: &str) -> SessionUpdate {
+        SessionUpdate::AgentMessageChunk(ContentChunk::new(text.into()))
+    }
+
+    fn thought(text: &str) -> SessionUpdate {
+        SessionUpdate::AgentThoughtChunk(ContentChunk::new(text.into()))
+    }
+
+    #[test]
+    fn test_merges_adjacent_text_chunks() {
+        let mut coalescer = ChunkCoalescer::new();
+        assert!(coalescer.push(message("Hel")).is_empty());
+        assert!(coalescer.push(message("lo, ")).is_empty());
+        assert!(coalescer.push(message("world")).is_empty());
+        assert_eq!(coalescer.flush(), Some(message("Hello, world")));
+        assert_eq!(coalescer.flush(), None);
+    }
+
+    #[test]
+    fn test_flushes_on_boundaries() {
+        let mut coalescer = ChunkCoalescer::new();
+        assert!(coalescer.push(thought("Let me ")).is_empty());
+        assert!(coalescer.push(thought("check.")).is_empty());
+        assert_eq!(
+            coalescer.push(message("Checking")),
+            vec![thought("Let me check.")]
+        );
+
+        let tool_call = SessionUpdate::ToolCall(ToolCall::new("call_1", "Read file"));
+        assert_eq!(
+            coalescer.push(tool_call.clone()),
+            vec![message("Checking"), tool_call]
+        );
+
+        let image = SessionUpdate::AgentMessageChunk(ContentChunk::new(ContentBlock::Image(
+            ImageContent::new("aGVsbG8=", "image/png"),
+        )));
+        assert!(coalescer.push(message("Here:")).is_empty());
+        assert_eq!(coalescer.push(image.clone()), vec![message("Here:"), image]);
+
+        assert!(
+            coalescer
+                .push(SessionUpdate::AgentMessageChunk(
+                    ContentChunk::new("a".into()).message_id("msg_1")
+                ))
+                .is_empty()
+        );
+        assert_eq!(
+            coalescer.push(SessionUpdate::AgentMessageChunk(
+                ContentChunk::new("b".into()).message_id("msg_2")
+            )),
+            vec![SessionUpdate::AgentMessageChunk(
+                ContentChunk::new("a".into()).message_id("msg_1")
+            )]
+        );
+    }
+
+    #[test]
+    fn test_flushes_at_max_len() {
+        let mut coalescer = ChunkCoalescer::new().max_len(8);
+        assert!(coalescer.push(message("abcd")).is_empty());
+        assert_eq!(coalescer.push(message("efgh")), vec![message("abcdefgh")]);
+        assert!(coalescer.push(message("ij")).is_empty());
+        assert_eq!(coalescer.flush(), Some(message("ij")));
+    }
+}
@@ -0,0 +1,251 @@
+//! Environment variable types and constants.
+//!
+//! **UNSTABLE**: This module is not part of the spec yet, and may be removed or changed at any point.
+//!
+//! Agents that need a variable from the user's environment, such as `EDITOR`,
+//! ask the client with `env/get`. The client only reveals names on its own
+//! allowlist and reports every other name as unset.
+
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnError, VecSkipError, serde_as, skip_serializing_none};
+
+use crate::{IntoOption, SkipListener};
+
+use super::{Meta, SessionId};
+
+// Method name constants
+
+/// Method name for reading environment variables from the client.
+pub(crate) const ENV_GET_METHOD_NAME: &str = "env/get";
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Environment variable capabilities supported by the client.
+///
+/// Supplying `{}` means the client can handle `env/get` requests.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct EnvCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl EnvCapabilities {
+    /// Builds an empty [`EnvCapabilities`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request to read environment variables from the client.
+///
+/// Only available if the client supports the `env` capability.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "client", "x-method" = ENV_GET_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetEnvRequest {
+    /// The session ID for this request.
+    pub session_id: SessionId,
+    /// Names of the variables to read, such as `EDITOR`.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub names: Vec<String>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl GetEnvRequest {
+    /// Builds [`GetEnvRequest`] with the required request fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(session_id: impl Into<SessionId>, names: Vec<String>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            names,
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response to `env/get`.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "client", "x-method" = ENV_GET_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GetEnvResponse {
+    /// The value of each requested variable, keyed by name.
+    ///
+    /// Names that are unset, or that the client does not allow the agent to
+    /// read, map to `null`, so the agent cannot tell the two apart.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub values: HashMap<String, Option<String>>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl GetEnvResponse {
+    /// Builds [`GetEnvResponse`] with the required response fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(values: HashMap<String, Option<String>>) -> Self {
+        Self { values, meta: None }
+    }
+
+    /// Returns the value of `name`, or `None` if it is unset, not allowed, or
+    /// was not part of the response.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name)?.as_deref()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::v1::{AgentRequest, CLIENT_METHOD_NAMES, ClientCapabilities};
+
+    #[test]
+    fn test_env_capability() {
+        let capabilities = ClientCapabilities::new().env(EnvCapabilities::new());
+        let json = serde_json::to_value(&capabilities).unwrap();
+        assert_eq!(json["env"], json!({}));
+        assert_eq!(
+            serde_json::from_value::<ClientCapabilities>(json!({}))
+                .unwrap()
+                .env,
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_env_roundtrip() {
+        let request = GetEnvRequest::new(
+            "sess",
+            vec![
+                "EDITOR".to_string(),
+                "GITHUB_TOKEN".to_string(),
+                "NO_SUCH_VAR".to_string(),
+            ],
+        );
+        let routed = AgentRequest::GetEnvRequest(request.clone());
+        assert_eq!(routed.method(), CLIENT_METHOD_NAMES.env_get);
+
+        let json = serde_json::to_value(&routed).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionId": "sess",
+                "names": ["EDITOR", "GITHUB_TOKEN", "NO_SUCH_VAR"]
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<GetEnvRequest>(json).unwrap(),
+            request
+        );
+
+        // `EDITOR` is allowed, `GITHUB_TOKEN` is not on the client's
+        // allowlist, and `NO_SUCH_VAR` is unset.
+        let response = GetEnvResponse::new(HashMap::from([
+            ("EDITOR".to_string(), Some("vim".to_string())),
+            ("GITHUB_TOKEN".to_string(), None),
+            ("NO_SUCH_VAR".to_string(), None),
+        ]));
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "values": {
+                    "EDITOR": "vim",
+                    "GITHUB_TOKEN": null,
+                    "NO_SUCH_VAR": null
+                }
+            })
+        );
+        let response: GetEnvResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(response.get("EDITOR"), Some("vim"));
+        assert_eq!(response.get("GITHUB_TOKEN"), None);
+        assert_eq!(response.get("NO_SUCH_VAR"), None);
+        assert_eq!(response.get("PATH"), None);
+    }
+}
@@ -0,0 +1,269 @@
+//! File watching types and constants.
+//!
+//! **UNSTABLE**: This module is not part of the spec yet, and may be removed or changed at any point.
+//!
+//! Agents that react to changes made outside their own tool calls ask the
+//! client to watch paths with `fs/watch`. The client then reports changes
+//! under those paths with `fs/file_changed` notifications.
+
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnError, VecSkipError, serde_as, skip_serializing_none};
+
+use crate::{IntoOption, SkipListener};
+
+use super::{Meta, SessionId};
+
+// Method name constants
+
+/// Method name for setting the paths watched for a session.
+pub(crate) const FS_WATCH_METHOD_NAME: &str = "fs/watch";
+/// Notification name for changes under watched paths.
+pub(crate) const FS_FILE_CHANGED_NOTIFICATION: &str = "fs/file_changed";
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request to watch paths for changes.
+///
+/// Replaces the set of paths watched for the session; an empty list stops
+/// watching. Only available if the client supports the `fs.watch` capability.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "client", "x-method" = FS_WATCH_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct WatchPathsRequest {
+    /// The session ID for this request.
+    pub session_id: SessionId,
+    /// Absolute paths to watch. A directory covers everything beneath it.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub paths: Vec<PathBuf>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl WatchPathsRequest {
+    /// Builds [`WatchPathsRequest`] with the required request fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(session_id: impl Into<SessionId>, paths: Vec<PathBuf>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            paths,
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response to `fs/watch`.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "client", "x-method" = FS_WATCH_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct WatchPathsResponse {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl WatchPathsResponse {
+    /// Builds [`WatchPathsResponse`] with the required response fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Notification sent when a file under a watched path changes.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = FS_FILE_CHANGED_NOTIFICATION))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct FileChangedNotification {
+    /// The session ID for this notification.
+    pub session_id: SessionId,
+    /// Absolute path of the file that changed.
+    pub path: PathBuf,
+    /// What happened to the file.
+    pub kind: ChangeKind,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl FileChangedNotification {
+    /// Builds [`FileChangedNotification`] with the required notification fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(
+        session_id: impl Into<SessionId>,
+        path: impl Into<PathBuf>,
+        kind: ChangeKind,
+    ) -> Self {
+        Self {
+            session_id: session_id.into(),
+            path: path.into(),
+            kind,
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// The kind of change reported by `fs/file_changed`.
+#[derive(Deserialize, Serialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ChangeKind {
+    /// The file was created.
+    Created,
+    /// The file's contents changed.
+    Modified,
+    /// The file was deleted.
+    Deleted,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::v1::{
+        AGENT_METHOD_NAMES, AgentRequest, CLIENT_METHOD_NAMES, ClientNotification,
+        FileSystemCapabilities,
+    };
+
+    #[test]
+    fn test_watch_capability() {
+        let capabilities = FileSystemCapabilities::new().watch(true);
+        let json = serde_json::to_value(&capabilities).unwrap();
+        assert_eq!(json["watch"], true);
+        assert!(
+            !serde_json::from_value::<FileSystemCapabilities>(json!({}))
+                .unwrap()
+                .watch
+        );
+    }
+
+    #[test]
+    fn test_watch_paths_request_roundtrip() {
+        let request = WatchPathsRequest::new(
+            "sess",
+            vec![
+                PathBuf::from("/project/src"),
+                PathBuf::from("/project/Cargo.toml"),
+            ],
+        );
+        let routed = AgentRequest::WatchPathsRequest(request.clone());
+        assert_eq!(routed.method(), CLIENT_METHOD_NAMES.fs_watch);
+
+        let json = serde_json::to_value(&routed).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionId": "sess",
+                "paths": ["/project/src", "/project/Cargo.toml"]
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<WatchPathsRequest>(json).unwrap(),
+            request
+        );
+    }
+
+    #[test]
+    fn test_file_changed_notification_roundtrip() {
+        let notification =
+            FileChangedNotification::new("sess", "/project/src/lib.rs", ChangeKind::Modified);
+        let routed = ClientNotification::FileChangedNotification(notification.clone());
+        assert_eq!(routed.method(), AGENT_METHOD_NAMES.fs_file_changed);
+
+        let json = serde_json::to_value(&routed).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionId": "sess",
+                "path": "/project/src/lib.rs",
+                "kind": "modified"
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<FileChangedNotification>(json).unwrap(),
+            notification
+        );
+    }
+}
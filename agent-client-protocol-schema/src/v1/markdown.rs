@@ -0,0 +1,330 @@
+//! Markdown-safe buffering of streamed message text.
+//!
+//! Clients that render `agent_message_chunk` text as Markdown while it is still
+//! streaming can mis-render when a chunk ends inside a code fence or a link:
+//! the rest of the reply briefly turns into code, or a half-written link shows
+//! up as raw brackets. A [`MarkdownStreamBuffer`] holds back such constructs
+//! until they are complete.
+
+/// Buffers streamed Markdown and releases it only at safe render boundaries.
+///
+/// Feed the text of each `agent_message_chunk` to [`MarkdownStreamBuffer::push`]
+/// and append what it returns to the rendered message. Text inside an open
+/// fenced code block, an unfinished link or image, a partial line that may
+/// still turn into a fence, or a trailing backslash that may escape the next
+/// chunk's first character is kept back until a later chunk completes it.
+/// Call [`MarkdownStreamBuffer::flush`] at the end of the message to release
+/// whatever is left.
+///
+/// The concatenation of everything returned is always exactly the text that
+/// was pushed.
+#[derive(Debug, Default)]
+pub struct MarkdownStreamBuffer {
+    pending: String,
+    mid_line: bool,
+}
+
+impl MarkdownStreamBuffer {
+    /// Builds an empty [`MarkdownStreamBuffer`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends streamed text and returns the part that is now safe to render,
+    /// which may be empty.
+    pub fn push(&mut self, text: &str) -> String {
+        self.pending.push_str(text);
+        let safe_len = self.safe_len();
+        let rest = self.pending.split_off(safe_len);
+        let ready = std::mem::replace(&mut self.pending, rest);
+        if !ready.is_empty() {
+            self.mid_line = !ready.ends_with('\n');
+        }
+        ready
+    }
+
+    /// Releases all buffered text, complete or not.
+    #[must_use]
+    pub fn flush(&mut self) -> String {
+        self.mid_line = false;
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Returns the length of the longest prefix of the pending text that ends
+    /// outside any fence or link and not on an unpaired backslash.
+    fn safe_len(&self) -> usize {
+        let len = self.construct_free_len();
+        let ready = &self.pending[..len];
+        let backslashes = ready.len() - ready.trim_end_matches('\\').len();
+        // The escape is rescanned together with the character it applies to.
+        len - backslashes % 2
+    }
+
+    /// Returns the length of the longest prefix of the pending text that ends
+    /// outside any fence or link.
+    fn construct_free_len(&self) -> usize {
+        let mut fence: Option<(Fence, usize)> = None;
+        let mut link = LinkScanner::default();
+        let mut offset = 0;
+        let mut at_line_start = !self.mid_line;
+
+        for line in self.pending.split_inclusive('\n') {
+            let complete = line.ends_with('\n');
+            let line_start = offset;
+            offset += line.len();
+
+            if let Some((open, _)) = fence {
+                if complete && open.is_closed_by(line) {
+                    fence = None;
+                }
+                continue;
+            }
+
+            if at_line_start {
+                if !complete && Fence::may_open(line) {
+                    return link.open_start().unwrap_or(line_start);
+                }
+                if let Some(open) = Fence::opened_by(line) {
+                    fence = Some((open, line_start));
+                    link = LinkScanner::default();
+                    continue;
+                }
+                if line.trim().is_empty() {
+                    link = LinkScanner::default();
+                }
+            }
+
+            link.scan(line, line_start);
+            at_line_start = true;
+        }
+
+        let fence_start = fence.map(|(_, start)| start);
+        match (fence_start, link.open_start()) {
+            (Some(fence_start), Some(link_start)) => fence_start.min(link_start),
+            (Some(start), None) | (None, Some(start)) => start,
+            (None, None) => offset,
+        }
+    }
+}
+
+/// An opening code fence: the fence character and the length of its run.
+#[derive(Debug, Clone, Copy)]
+struct Fence {
+    marker: char,
+    len: usize,
+}
+
+impl Fence {
+    /// Parses a complete line as an opening fence.
+    fn opened_by(line: &str) -> Option<Self> {
+        let rest = strip_fence_indent(line)?;
+        let (marker, len) = marker_run(rest)?;
+        let info = &rest[len..];
+        (len >= 3 && !(marker == '`' && info.contains('`'))).then_some(Self { marker, len })
+    }
+
+    /// Whether a partial line could still become an opening fence.
+    fn may_open(line: &str) -> bool {
+        let Some(rest) = strip_fence_indent(line) else {
+            return false;
+        };
+        match marker_run(rest) {
+            Some((_, len)) => len == rest.len() || len >= 3,
+            None => rest.is_empty(),
+        }
+    }
+
+    /// Whether a complete line closes this fence.
+    fn is_closed_by(self, line: &str) -> bool {
+        let Some(rest) = strip_fence_indent(line) else {
+            return false;
+        };
+        marker_run(rest).is_some_and(|(marker, len)| {
+            marker == self.marker && len >= self.len && rest[len..].trim().is_empty()
+        })
+    }
+}
+
+/// Strips up to three spaces of indentation; more makes the line indented code.
+fn strip_fence_indent(line: &str) -> Option<&str> {
+    let rest = line.trim_start_matches(' ');
+    (line.len() - rest.len() <= 3).then_some(rest)
+}
+
+/// Returns the fence character starting `text` and how many times it repeats.
+fn marker_run(text: &str) -> Option<(char, usize)> {
+    let marker = text.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let len = text.len() - text.trim_start_matches(marker).len();
+    Some((marker, len))
+}
+
+/// Tracks whether the text scanned so far ends inside a link or image.
+#[derive(Debug, Default)]
+struct LinkScanner {
+    state: LinkState,
+    escaped: bool,
+}
+
+#[derive(Debug, Default)]
+enum LinkState {
+    #[default]
+    Text,
+    Label {
+        start: usize,
+        depth: usize,
+    },
+    AfterLabel {
+        start: usize,
+    },
+    Destination {
+        start: usize,
+        depth: usize,
+    },
+}
+
+impl LinkScanner {
+    fn scan(&mut self, text: &str, offset: usize) {
+        for (index, c) in text.char_indices() {
+            if self.escaped {
+                self.escaped = false;
+                continue;
+            }
+            if let LinkState::AfterLabel { start } = self.state {
+                if c == '(' {
+                    self.state = LinkState::Destination { start, depth: 1 };
+                    continue;
+                }
+                self.state = LinkState::Text;
+            }
+            match (&mut self.state, c) {
+                (_, '\\') => self.escaped = true,
+                (LinkState::Text, '[') => {
+                    self.state = LinkState::Label {
+                        start: offset + index,
+                        depth: 1,
+                    };
+                }
+                (LinkState::Label { depth, .. }, '[')
+                | (LinkState::Destination { depth, .. }, '(') => *depth += 1,
+                (LinkState::Label { start, depth }, ']') => {
+                    *depth -= 1;
+                    if *depth == 0 {
+                        self.state = LinkState::AfterLabel { start: *start };
+                    }
+                }
+                (LinkState::Destination { depth, .. }, ')') => {
+                    *depth -= 1;
+                    if *depth == 0 {
+                        self.state = LinkState::Text;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Where the unfinished link starts, if the scanned text ends inside one.
+    fn open_start(&self) -> Option<usize> {
+        match self.state {
+            LinkState::Text => None,
+            LinkState::Label { start, .. }
+            | LinkState::AfterLabel { start }
+            | LinkState::Destination { start, .. } => Some(start),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stream(chunks: &[&str]) -> Vec<String> {
+        let mut buffer = MarkdownStreamBuffer::new();
+        let mut ready: Vec<String> = chunks.iter().map(|chunk| buffer.push(chunk)).collect();
+        ready.push(buffer.flush());
+        ready
+    }
+
+    #[test]
+    fn test_holds_code_fence_split_across_chunks() {
+        assert_eq!(
+            stream(&[
+                "Here is the fix:\n``",
+                "`rust\nfn main() {\n",
+                "    println!(\"hi\");\n}\n`",
+                "``\nDone.",
+            ]),
+            vec![
+                "Here is the fix:\n",
+                "",
+                "",
+                "```rust\nfn main() {\n    println!(\"hi\");\n}\n```\nDone.",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fence_needs_matching_close() {
+        assert_eq!(
+            stream(&["~~~~\n", "```\n~~~\n", "~~~~\nafter"]),
+            vec!["", "", "~~~~\n```\n~~~\n~~~~\nafter", ""]
+        );
+    }
+
+    #[test]
+    fn test_holds_link_split_across_chunks() {
+        assert_eq!(
+            stream(&[
+                "See [the do",
+                "cs](https://agentclientprotocol.com/pro",
+                "tocol) for more.",
+            ]),
+            vec![
+                "See ",
+                "",
+                "[the docs](https://agentclientprotocol.com/protocol) for more.",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_releases_brackets_that_are_not_links() {
+        assert_eq!(
+            stream(&["Use `items[0]", "` and \\[escaped", " text"]),
+            vec!["Use `items", "[0]` and \\[escaped", " text", ""]
+        );
+    }
+
+    #[test]
+    fn test_carries_escape_split_across_chunks() {
+        assert_eq!(
+            stream(&["Not a link: \\", "[x](y", ") done"]),
+            vec!["Not a link: ", "\\[x](y", ") done", ""]
+        );
+        // An escaped backslash escapes nothing after it.
+        assert_eq!(
+            stream(&["C:\\\\", "[docs](", "x)"]),
+            vec!["C:\\\\", "", "[docs](x)", ""]
+        );
+        assert_eq!(stream(&["trailing \\"]), vec!["trailing ", "\\"]);
+    }
+
+    #[test]
+    fn test_blank_line_ends_unclosed_link() {
+        assert_eq!(
+            stream(&["An [unclosed\n", "\nNext paragraph"]),
+            vec!["An ", "[unclosed\n\nNext paragraph", ""]
+        );
+    }
+
+    #[test]
+    fn test_flush_releases_incomplete_text() {
+        assert_eq!(
+            stream(&["```python\nprint(1)"]),
+            vec!["", "```python\nprint(1)"]
+        );
+    }
+}
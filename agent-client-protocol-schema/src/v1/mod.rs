@@ -2,40 +2,114 @@
 
 mod agent;
 mod client;
+#[cfg(feature = "unstable_clipboard")]
+mod clipboard;
+mod coalesce;
 mod content;
 #[cfg(feature = "unstable_elicitation")]
 mod elicitation;
+#[cfg(feature = "unstable_env")]
+mod env;
 mod error;
 mod ext;
+#[cfg(feature = "unstable_fs_watch")]
+mod fs_watch;
+mod markdown;
 #[cfg(feature = "unstable_mcp_over_acp")]
 mod mcp;
 #[cfg(feature = "unstable_nes")]
 mod nes;
 mod plan;
 mod protocol_level;
+mod redact;
+#[cfg(feature = "unstable_sampling")]
+mod sampling;
+#[cfg(feature = "unstable_session_update_sequence")]
+mod sequence;
 mod tool_call;
+mod transcript;
 
 pub use crate::rpc::{JsonRpcBatch, JsonRpcMessage, Notification, Request, RequestId};
 pub use agent::*;
 pub use client::*;
+#[cfg(feature = "unstable_clipboard")]
+pub use clipboard::*;
+pub use coalesce::*;
 pub use content::*;
 use derive_more::{Display, From};
 #[cfg(feature = "unstable_elicitation")]
 pub use elicitation::*;
+#[cfg(feature = "unstable_env")]
+pub use env::*;
 pub use error::*;
 pub use ext::*;
+#[cfg(feature = "unstable_fs_watch")]
+pub use fs_watch::*;
+pub use markdown::*;
 #[cfg(feature = "unstable_mcp_over_acp")]
 pub use mcp::*;
 #[cfg(feature = "unstable_nes")]
 pub use nes::*;
 pub use plan::*;
 pub use protocol_level::*;
+pub use redact::*;
+#[cfg(feature = "unstable_sampling")]
+pub use sampling::*;
+#[cfg(feature = "unstable_session_update_sequence")]
+pub use sequence::*;
 pub use serde_json::value::RawValue;
 pub use tool_call::*;
+pub use transcript::*;
 
 /// JSON-RPC response envelope using this protocol version's error type.
 pub type Response<Result> = crate::rpc::Response<Result, Error>;
 
+/// All messages that an agent can send to a client.
+pub type AgentOutgoingMessage =
+    crate::rpc::Message<AgentRequest, AgentResponse, AgentNotification, Error>;
+
+/// All messages that a client can send to an agent.
+pub type ClientOutgoingMessage =
+    crate::rpc::Message<ClientRequest, ClientResponse, ClientNotification, Error>;
+
+/// A message from either side with its params and result left as JSON.
+///
+/// Useful for recording, replaying, or proxying frames without decoding them.
+/// Unlike the routing enums, which pick a params type by shape, this lets the
+/// caller decode params according to `method`.
+pub type RawMessage =
+    crate::rpc::Message<serde_json::Value, serde_json::Value, serde_json::Value, Error>;
+
+/// Returns the JSON Schemas for one method's params and result, or `None` if
+/// this protocol version has no method with that name.
+///
+/// ```
+/// use agent_client_protocol_schema::v1::{CLIENT_METHOD_NAMES, method_schema};
+///
+/// let schema = method_schema(CLIENT_METHOD_NAMES.session_request_permission).unwrap();
+/// assert!(schema.params.as_value()["properties"].get("toolCall").is_some());
+/// assert!(schema.result.unwrap().as_value()["properties"].get("outcome").is_some());
+/// ```
+#[must_use]
+pub fn method_schema(method: &str) -> Option<crate::MethodSchema> {
+    use schemars::SchemaGenerator;
+
+    crate::method_schema::method_schema(
+        method,
+        &[
+            SchemaGenerator::subschema_for::<AgentRequest>,
+            SchemaGenerator::subschema_for::<ClientRequest>,
+            SchemaGenerator::subschema_for::<AgentNotification>,
+            SchemaGenerator::subschema_for::<ClientNotification>,
+            SchemaGenerator::subschema_for::<ProtocolLevelNotification>,
+        ],
+        &[
+            SchemaGenerator::subschema_for::<AgentResponse>,
+            SchemaGenerator::subschema_for::<ClientResponse>,
+        ],
+    )
+}
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
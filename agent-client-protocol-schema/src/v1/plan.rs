@@ -60,6 +60,141 @@ impl Plan {
         }
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Applies a [`PlanEntryUpdate`] to the matching entry, leaving the others intact.
+    ///
+    /// Updates for an index past the end of the plan are ignored, as are
+    /// updates that name a plan ID.
+    #[cfg(feature = "unstable_plan_entry_update")]
+    pub fn apply(&mut self, update: PlanEntryUpdate) {
+        #[cfg(feature = "unstable_plan_operations")]
+        if update.plan_id.is_some() {
+            return;
+        }
+        update.patch(&mut self.entries);
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Patches a single entry of the current [`Plan`] instead of resending every entry.
+///
+/// Entry indices are stable within a turn: agents append new entries and send a
+/// full plan before reordering or removing any.
+#[cfg(feature = "unstable_plan_entry_update")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PlanEntryUpdate {
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The plan whose entry to update.
+    ///
+    /// When omitted, the update targets the session's single [`Plan`].
+    #[cfg(feature = "unstable_plan_operations")]
+    #[serde(default)]
+    pub plan_id: Option<PlanId>,
+    /// Zero-based index of the entry to update.
+    pub index: u32,
+    /// The entry's new status, if it changed.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub status: Option<PlanEntryStatus>,
+    /// The entry's new description, if it changed.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub content: Option<String>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_plan_entry_update")]
+impl PlanEntryUpdate {
+    /// Builds [`PlanEntryUpdate`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(index: u32) -> Self {
+        Self {
+            #[cfg(feature = "unstable_plan_operations")]
+            plan_id: None,
+            index,
+            status: None,
+            content: None,
+            meta: None,
+        }
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The plan whose entry to update.
+    #[cfg(feature = "unstable_plan_operations")]
+    #[must_use]
+    pub fn plan_id(mut self, plan_id: impl IntoOption<PlanId>) -> Self {
+        self.plan_id = plan_id.into_option();
+        self
+    }
+
+    /// Copies the update's status and content onto `entries[index]`, if it exists.
+    fn patch(self, entries: &mut [PlanEntry]) {
+        let Some(entry) = usize::try_from(self.index)
+            .ok()
+            .and_then(|index| entries.get_mut(index))
+        else {
+            return;
+        };
+        if let Some(status) = self.status {
+            entry.status = status;
+        }
+        if let Some(content) = self.content {
+            entry.content = content;
+        }
+    }
+
+    /// The entry's new status, if it changed.
+    #[must_use]
+    pub fn status(mut self, status: impl IntoOption<PlanEntryStatus>) -> Self {
+        self.status = status.into_option();
+        self
+    }
+
+    /// The entry's new description, if it changed.
+    #[must_use]
+    pub fn content(mut self, content: impl IntoOption<String>) -> Self {
+        self.content = content.into_option();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -224,6 +359,21 @@ impl PlanItems {
         }
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Applies a [`PlanEntryUpdate`] to the matching entry, leaving the others intact.
+    ///
+    /// Updates for another plan or for an index past the end of the plan are ignored.
+    #[cfg(feature = "unstable_plan_entry_update")]
+    pub fn apply(&mut self, update: PlanEntryUpdate) {
+        if update.plan_id.as_ref() != Some(&self.plan_id) {
+            return;
+        }
+        update.patch(&mut self.entries);
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -18,6 +18,19 @@ use super::{Meta, RequestId};
 pub struct CancelRequestNotification {
     /// The ID of the request to cancel.
     pub request_id: RequestId,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Why the sender is cancelling.
+    ///
+    /// Optional. Receivers MUST NOT require it and SHOULD treat an omitted or
+    /// unrecognized reason the same as a plain cancellation.
+    #[cfg(feature = "unstable_cancel_reason")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub reason: Option<CancelReason>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -36,10 +49,24 @@ impl CancelRequestNotification {
     pub fn new(request_id: impl Into<RequestId>) -> Self {
         Self {
             request_id: request_id.into(),
+            #[cfg(feature = "unstable_cancel_reason")]
+            reason: None,
             meta: None,
         }
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Why the sender is cancelling.
+    #[cfg(feature = "unstable_cancel_reason")]
+    #[must_use]
+    pub fn reason(mut self, reason: impl IntoOption<CancelReason>) -> Self {
+        self.reason = reason.into_option();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -52,6 +79,28 @@ impl CancelRequestNotification {
     }
 }
 
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Why a request or session turn is being cancelled.
+///
+/// Receivers can use the reason for logging or to decide how much cleanup to do.
+#[cfg(feature = "unstable_cancel_reason")]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CancelReason {
+    /// The user explicitly asked to stop.
+    UserRequested,
+    /// The work was replaced by a newer request, such as a follow-up message.
+    Superseded,
+    /// The sender is shutting down.
+    Shutdown,
+    /// The sender gave up waiting for the work to finish.
+    Timeout,
+}
+
 // Method schema
 
 /// Names of all methods that agents handle.
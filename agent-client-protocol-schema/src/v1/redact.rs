@@ -0,0 +1,100 @@
+//! Redaction of sensitive content before it is logged.
+//!
+//! A [`Redactor`] rewrites file contents and other sensitive payloads in place.
+//! Apply it to a clone of a message that is about to be logged, never to the
+//! value that is sent on the wire.
+
+use super::{ContentBlock, Diff, EmbeddedResource, EmbeddedResourceResource};
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Masks sensitive payloads in protocol values.
+///
+/// The provided methods replace embedded text resource contents and diff
+/// bodies with a fixed placeholder. Implementors can override either method to
+/// mask more or less.
+pub trait Redactor {
+    /// Redacts a content block in place.
+    ///
+    /// By default, replaces the text of embedded text resources.
+    fn redact_content(&self, block: &mut ContentBlock) {
+        if let ContentBlock::Resource(EmbeddedResource {
+            resource: EmbeddedResourceResource::TextResourceContents(contents),
+            ..
+        }) = block
+        {
+            contents.text = REDACTED.to_string();
+        }
+    }
+
+    /// Redacts a diff in place.
+    ///
+    /// By default, replaces both the original and the new file contents.
+    fn redact_diff(&self, diff: &mut Diff) {
+        if let Some(old_text) = &mut diff.old_text {
+            *old_text = REDACTED.to_string();
+        }
+        diff.new_text = REDACTED.to_string();
+    }
+}
+
+/// A [`Redactor`] that uses the provided masking behaviour.
+///
+/// Construct it with [`DefaultRedactor::default`].
+#[derive(Debug, Default, Clone, Copy)]
+#[non_exhaustive]
+pub struct DefaultRedactor;
+
+impl Redactor for DefaultRedactor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::TextResourceContents;
+
+    #[test]
+    fn test_redacts_logged_copy_only() {
+        let block = ContentBlock::Resource(EmbeddedResource::new(
+            EmbeddedResourceResource::TextResourceContents(TextResourceContents::new(
+                "API_KEY=secret",
+                "file:///project/.env",
+            )),
+        ));
+        let mut logged = block.clone();
+        DefaultRedactor.redact_content(&mut logged);
+
+        let ContentBlock::Resource(EmbeddedResource {
+            resource: EmbeddedResourceResource::TextResourceContents(contents),
+            ..
+        }) = &logged
+        else {
+            panic!("expected a text resource");
+        };
+        assert_eq!(contents.text, "[REDACTED]");
+        assert_eq!(contents.uri, "file:///project/.env");
+        assert_eq!(
+            serde_json::to_value(&block).unwrap()["resource"]["text"],
+            "API_KEY=secret"
+        );
+
+        let mut text = ContentBlock::from("hello");
+        DefaultRedactor.redact_content(&mut text);
+        assert_eq!(text, ContentBlock::from("hello"));
+    }
+
+    #[test]
+    fn test_redacts_diff_bodies() {
+        let diff = Diff::new("/project/.env", "API_KEY=new").old_text("API_KEY=old");
+        let mut logged = diff.clone();
+        DefaultRedactor.redact_diff(&mut logged);
+        assert_eq!(
+            logged,
+            Diff::new("/project/.env", "[REDACTED]").old_text("[REDACTED]")
+        );
+        assert_eq!(diff.new_text, "API_KEY=new");
+
+        let mut new_file = Diff::new("/project/new.rs", "fn main() {}");
+        DefaultRedactor.redact_diff(&mut new_file);
+        assert_eq!(new_file.old_text, None);
+    }
+}
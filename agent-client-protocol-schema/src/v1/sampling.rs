@@ -0,0 +1,470 @@
+//! Sampling types for client-provided model completions.
+//!
+//! **UNSTABLE**: This module is not part of the spec yet, and may be removed or changed at any point.
+//!
+//! This module defines the types used when an agent delegates inference to the
+//! client, mirroring MCP sampling. The client holds the model credentials and
+//! performs the completion on the agent's behalf.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_with::{DefaultOnError, VecSkipError, serde_as, skip_serializing_none};
+
+use crate::IntoOption;
+use crate::SkipListener;
+
+use super::{ContentBlock, Meta, Role, SAMPLING_CREATE_MESSAGE_METHOD_NAME, SessionId, StopReason};
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Sampling capabilities supported by the client.
+///
+/// Supplying `{}` means the client can handle `sampling/create_message` requests.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SamplingCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SamplingCapabilities {
+    /// Builds an empty [`SamplingCapabilities`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A single message in the conversation sent to the client for sampling.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SamplingMessage {
+    /// Who authored this message.
+    pub role: Role,
+    /// The content of the message.
+    pub content: ContentBlock,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SamplingMessage {
+    /// Builds [`SamplingMessage`] from a role and its content.
+    #[must_use]
+    pub fn new(role: Role, content: impl Into<ContentBlock>) -> Self {
+        Self {
+            role,
+            content: content.into(),
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// The agent's preferences for which model the client should use.
+///
+/// These are advisory only. The client makes the final model selection and
+/// MAY ignore them entirely.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ModelPreferences {
+    /// Hints for model selection, in order of preference.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hints: Vec<ModelHint>,
+    /// How much to prioritize cost when selecting a model, from `0` to `1`.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub cost_priority: Option<f64>,
+    /// How much to prioritize response speed when selecting a model, from `0` to `1`.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub speed_priority: Option<f64>,
+    /// How much to prioritize capability when selecting a model, from `0` to `1`.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub intelligence_priority: Option<f64>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl ModelPreferences {
+    /// Builds empty [`ModelPreferences`]; use builder methods to express preferences.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hints for model selection, in order of preference.
+    #[must_use]
+    pub fn hints(mut self, hints: Vec<ModelHint>) -> Self {
+        self.hints = hints;
+        self
+    }
+
+    /// How much to prioritize cost when selecting a model, from `0` to `1`.
+    #[must_use]
+    pub fn cost_priority(mut self, cost_priority: impl IntoOption<f64>) -> Self {
+        self.cost_priority = cost_priority.into_option();
+        self
+    }
+
+    /// How much to prioritize response speed when selecting a model, from `0` to `1`.
+    #[must_use]
+    pub fn speed_priority(mut self, speed_priority: impl IntoOption<f64>) -> Self {
+        self.speed_priority = speed_priority.into_option();
+        self
+    }
+
+    /// How much to prioritize capability when selecting a model, from `0` to `1`.
+    #[must_use]
+    pub fn intelligence_priority(mut self, intelligence_priority: impl IntoOption<f64>) -> Self {
+        self.intelligence_priority = intelligence_priority.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A hint used by the client when selecting a model.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ModelHint {
+    /// A full or partial model name, e.g. `"sonnet"` or `"gpt-4o"`.
+    ///
+    /// Clients MAY map the hint to an equivalent model from a different provider.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl ModelHint {
+    /// Builds an empty [`ModelHint`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A full or partial model name.
+    #[must_use]
+    pub fn name(mut self, name: impl IntoOption<String>) -> Self {
+        self.name = name.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request from the agent asking the client to generate a model completion.
+///
+/// Only available if the client advertises the `sampling` capability.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[schemars(extend("x-side" = "client", "x-method" = SAMPLING_CREATE_MESSAGE_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CreateMessageRequest {
+    /// The session this completion is requested for.
+    pub session_id: SessionId,
+    /// The conversation to complete, in chronological order.
+    pub messages: Vec<SamplingMessage>,
+    /// The maximum number of tokens the client should sample.
+    pub max_tokens: u32,
+    /// The agent's preferences for which model to use.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub model_preferences: Option<ModelPreferences>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl CreateMessageRequest {
+    /// Builds [`CreateMessageRequest`] with the required request fields set; optional fields start unset.
+    #[must_use]
+    pub fn new(
+        session_id: impl Into<SessionId>,
+        messages: Vec<SamplingMessage>,
+        max_tokens: u32,
+    ) -> Self {
+        Self {
+            session_id: session_id.into(),
+            messages,
+            max_tokens,
+            model_preferences: None,
+            meta: None,
+        }
+    }
+
+    /// The agent's preferences for which model to use.
+    #[must_use]
+    pub fn model_preferences(
+        mut self,
+        model_preferences: impl IntoOption<ModelPreferences>,
+    ) -> Self {
+        self.model_preferences = model_preferences.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response from the client containing the sampled completion.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[schemars(extend("x-side" = "client", "x-method" = SAMPLING_CREATE_MESSAGE_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CreateMessageResponse {
+    /// The content generated by the model.
+    pub content: ContentBlock,
+    /// Why the model stopped generating.
+    pub stop_reason: StopReason,
+    /// The name of the model the client used, if it chooses to disclose it.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub model: Option<String>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl CreateMessageResponse {
+    /// Builds [`CreateMessageResponse`] from the generated content and stop reason.
+    #[must_use]
+    pub fn new(content: impl Into<ContentBlock>, stop_reason: StopReason) -> Self {
+        Self {
+            content: content.into(),
+            stop_reason,
+            model: None,
+            meta: None,
+        }
+    }
+
+    /// The name of the model the client used.
+    #[must_use]
+    pub fn model(mut self, model: impl IntoOption<String>) -> Self {
+        self.model = model.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn create_message_request_serialization() {
+        let req = CreateMessageRequest::new(
+            "sess_1",
+            vec![SamplingMessage::new(Role::User, "Hello")],
+            256,
+        )
+        .model_preferences(
+            ModelPreferences::new()
+                .hints(vec![ModelHint::new().name("sonnet")])
+                .speed_priority(0.5),
+        );
+
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionId": "sess_1",
+                "messages": [
+                    {"role": "user", "content": {"type": "text", "text": "Hello"}}
+                ],
+                "maxTokens": 256,
+                "modelPreferences": {
+                    "hints": [{"name": "sonnet"}],
+                    "speedPriority": 0.5
+                }
+            })
+        );
+
+        let roundtripped: CreateMessageRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(roundtripped, req);
+    }
+
+    #[test]
+    fn create_message_request_rejects_invalid_messages() {
+        let result = serde_json::from_value::<CreateMessageRequest>(json!({
+            "sessionId": "sess_1",
+            "messages": [
+                {"role": "user", "content": {"type": "text", "text": "Hello"}},
+                {"role": "user"}
+            ],
+            "maxTokens": 256
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn create_message_response_serialization() {
+        let response =
+            CreateMessageResponse::new("Hello back!", StopReason::EndTurn).model("canned-model");
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "content": {"type": "text", "text": "Hello back!"},
+                "stopReason": "end_turn",
+                "model": "canned-model"
+            })
+        );
+
+        let roundtripped: CreateMessageResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(roundtripped, response);
+    }
+}
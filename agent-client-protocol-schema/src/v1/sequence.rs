@@ -0,0 +1,97 @@
+//! Reordering of sequenced session notifications.
+//!
+//! When an agent numbers its `session/update` notifications with
+//! [`SessionNotification::sequence`], a client can pass them through a
+//! [`SessionNotificationReorderer`] to process them in the order they were
+//! sent, even if the transport delivered them out of order.
+
+use std::collections::BTreeMap;
+
+use super::SessionNotification;
+
+/// Restores the original order of sequenced notifications for one session.
+///
+/// Notifications are released as soon as every notification before them has
+/// been seen. Notifications without a sequence number are released
+/// immediately, and duplicates of an already released sequence number are
+/// dropped.
+#[derive(Debug, Default)]
+pub struct SessionNotificationReorderer {
+    next: u64,
+    pending: BTreeMap<u64, SessionNotification>,
+}
+
+impl SessionNotificationReorderer {
+    /// Builds a [`SessionNotificationReorderer`] expecting sequence number `0` first.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds a notification into the reorderer and returns the notifications
+    /// that are ready to be processed, in order.
+    pub fn push(&mut self, notification: SessionNotification) -> Vec<SessionNotification> {
+        let Some(sequence) = notification.sequence else {
+            return vec![notification];
+        };
+        if sequence >= self.next {
+            self.pending.entry(sequence).or_insert(notification);
+        }
+        let mut ready = Vec::new();
+        while let Some(notification) = self.pending.remove(&self.next) {
+            ready.push(notification);
+            self.next += 1;
+        }
+        ready
+    }
+
+    /// The sequence number the reorderer is waiting for, if any later
+    /// notification is being held back.
+    #[must_use]
+    pub fn missing(&self) -> Option<u64> {
+        (!self.pending.is_empty()).then_some(self.next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::{ContentChunk, SessionUpdate};
+
+    fn chunk(text: &str, sequence: impl crate::IntoOption<u64>) -> SessionNotification {
+        SessionNotification::new(
+            "sess_1",
+            SessionUpdate::AgentMessageChunk(ContentChunk::new(text.into())),
+        )
+        .sequence(sequence)
+    }
+
+    #[test]
+    fn test_sequence_round_trips() {
+        let json = serde_json::to_value(chunk("a", 3)).unwrap();
+        assert_eq!(json["sequence"], 3);
+        let parsed: SessionNotification = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.sequence, Some(3));
+
+        let json = serde_json::to_value(chunk("a", None)).unwrap();
+        assert!(json.get("sequence").is_none());
+    }
+
+    #[test]
+    fn test_reorders_out_of_order_delivery() {
+        let mut reorderer = SessionNotificationReorderer::new();
+        assert_eq!(reorderer.push(chunk("a", 0)), vec![chunk("a", 0)]);
+        assert!(reorderer.push(chunk("c", 2)).is_empty());
+        assert!(reorderer.push(chunk("d", 3)).is_empty());
+        assert_eq!(reorderer.missing(), Some(1));
+        assert_eq!(
+            reorderer.push(chunk("b", 1)),
+            vec![chunk("b", 1), chunk("c", 2), chunk("d", 3)]
+        );
+        assert_eq!(reorderer.missing(), None);
+
+        assert!(reorderer.push(chunk("b", 1)).is_empty());
+        assert_eq!(reorderer.push(chunk("x", None)), vec![chunk("x", None)]);
+        assert_eq!(reorderer.push(chunk("e", 4)), vec![chunk("e", 4)]);
+    }
+}
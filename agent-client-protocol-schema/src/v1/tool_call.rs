@@ -4,6 +4,13 @@
 //! running code, or fetching data—it generates tool calls that the agent executes on its behalf.
 //!
 /// See protocol docs: [Tool Calls](https://agentclientprotocol.com/protocol/tool-calls)
+#[cfg(any(
+    feature = "unstable_tool_call_group",
+    feature = "unstable_tool_call_parent"
+))]
+use std::collections::HashMap;
+#[cfg(feature = "unstable_tool_call_group")]
+use std::collections::hash_map::Entry;
 use std::{path::PathBuf, sync::Arc};
 
 use derive_more::{Display, From};
@@ -13,7 +20,9 @@ use serde_with::{DefaultOnError, VecSkipError, serde_as, skip_serializing_none};
 
 use crate::{IntoOption, SkipListener};
 
-use super::{ContentBlock, Error, Meta, TerminalId};
+#[cfg(feature = "unstable_permission_inline_tool_call")]
+use super::ClientCapabilities;
+use super::{ContentBlock, Error, Meta, Result, TerminalId};
 
 /// Represents a tool call that the language model has requested.
 ///
@@ -37,6 +46,46 @@ pub struct ToolCall {
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default, skip_serializing_if = "ToolKind::is_default")]
     pub kind: ToolKind,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A free-form label refining [`ToolKind`], such as `"database query"`.
+    ///
+    /// Clients MAY use it for display or icon selection and SHOULD fall back to
+    /// `kind` when they don't recognize it.
+    #[cfg(feature = "unstable_tool_kind_label")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub custom_kind: Option<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Groups related tool calls, such as retries of the same command.
+    ///
+    /// Clients MAY collapse tool calls that share a `groupId` into a single
+    /// entry. Ids are chosen by the Agent and only meaningful within a session.
+    #[cfg(feature = "unstable_tool_call_group")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub group_id: Option<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The tool call that started this one, such as the sub-agent call that
+    /// ran it.
+    ///
+    /// Clients MAY render tool calls as a tree under their parent. A tool call
+    /// whose parent is unknown is shown at the top level.
+    #[cfg(feature = "unstable_tool_call_parent")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub parent_id: Option<ToolCallId>,
     /// Current execution status of the tool call.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
@@ -63,6 +112,62 @@ pub struct ToolCall {
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
     pub raw_output: Option<serde_json::Value>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// JSON Schema describing `rawInput`.
+    ///
+    /// Lets clients validate the tool's arguments and render them as a form.
+    #[cfg(feature = "unstable_tool_call_schema")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub input_schema: Option<serde_json::Value>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// JSON Schema describing `rawOutput`.
+    #[cfg(feature = "unstable_tool_call_schema")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub output_schema: Option<serde_json::Value>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// ISO 8601 timestamp of when the tool call was created.
+    #[cfg(feature = "unstable_tool_call_timestamps")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// ISO 8601 timestamp of when the tool call reached `completed` or `failed`.
+    ///
+    /// Agents SHOULD send it in the same update that moves `status` to a terminal state.
+    #[cfg(feature = "unstable_tool_call_timestamps")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub completed_at: Option<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Details about why the tool call failed.
+    ///
+    /// Agents SHOULD set this alongside a `failed` status.
+    #[cfg(feature = "unstable_tool_call_error")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub error: Option<ToolCallError>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -83,11 +188,27 @@ impl ToolCall {
             tool_call_id: tool_call_id.into(),
             title: title.into(),
             kind: ToolKind::default(),
+            #[cfg(feature = "unstable_tool_kind_label")]
+            custom_kind: None,
+            #[cfg(feature = "unstable_tool_call_group")]
+            group_id: None,
+            #[cfg(feature = "unstable_tool_call_parent")]
+            parent_id: None,
             status: ToolCallStatus::default(),
             content: Vec::default(),
             locations: Vec::default(),
             raw_input: None,
             raw_output: None,
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            created_at: None,
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            completed_at: None,
+            #[cfg(feature = "unstable_tool_call_error")]
+            error: None,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            input_schema: None,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            output_schema: None,
             meta: None,
         }
     }
@@ -100,6 +221,43 @@ impl ToolCall {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A free-form label refining [`ToolKind`], such as `"database query"`.
+    #[cfg(feature = "unstable_tool_kind_label")]
+    #[must_use]
+    pub fn custom_kind(mut self, custom_kind: impl IntoOption<String>) -> Self {
+        self.custom_kind = custom_kind.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Groups related tool calls, such as retries of the same command.
+    #[cfg(feature = "unstable_tool_call_group")]
+    #[must_use]
+    pub fn group_id(mut self, group_id: impl IntoOption<String>) -> Self {
+        self.group_id = group_id.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The tool call that started this one, such as the sub-agent call that
+    /// ran it.
+    #[cfg(feature = "unstable_tool_call_parent")]
+    #[must_use]
+    pub fn parent_id(mut self, parent_id: impl IntoOption<ToolCallId>) -> Self {
+        self.parent_id = parent_id.into_option();
+        self
+    }
+
     /// Current execution status of the tool call.
     #[must_use]
     pub fn status(mut self, status: ToolCallStatus) -> Self {
@@ -136,6 +294,66 @@ impl ToolCall {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// JSON Schema describing `rawInput`.
+    #[cfg(feature = "unstable_tool_call_schema")]
+    #[must_use]
+    pub fn input_schema(mut self, input_schema: impl IntoOption<serde_json::Value>) -> Self {
+        self.input_schema = input_schema.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// JSON Schema describing `rawOutput`.
+    #[cfg(feature = "unstable_tool_call_schema")]
+    #[must_use]
+    pub fn output_schema(mut self, output_schema: impl IntoOption<serde_json::Value>) -> Self {
+        self.output_schema = output_schema.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// ISO 8601 timestamp of when the tool call was created.
+    #[cfg(feature = "unstable_tool_call_timestamps")]
+    #[must_use]
+    pub fn created_at(mut self, created_at: impl IntoOption<String>) -> Self {
+        self.created_at = created_at.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// ISO 8601 timestamp of when the tool call reached `completed` or `failed`.
+    #[cfg(feature = "unstable_tool_call_timestamps")]
+    #[must_use]
+    pub fn completed_at(mut self, completed_at: impl IntoOption<String>) -> Self {
+        self.completed_at = completed_at.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Details about why the tool call failed.
+    #[cfg(feature = "unstable_tool_call_error")]
+    #[must_use]
+    pub fn error(mut self, error: impl IntoOption<ToolCallError>) -> Self {
+        self.error = error.into_option();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -156,6 +374,18 @@ impl ToolCall {
         if let Some(kind) = fields.kind {
             self.kind = kind;
         }
+        #[cfg(feature = "unstable_tool_kind_label")]
+        if let Some(custom_kind) = fields.custom_kind {
+            self.custom_kind = Some(custom_kind);
+        }
+        #[cfg(feature = "unstable_tool_call_group")]
+        if let Some(group_id) = fields.group_id {
+            self.group_id = Some(group_id);
+        }
+        #[cfg(feature = "unstable_tool_call_parent")]
+        if let Some(parent_id) = fields.parent_id {
+            self.parent_id = Some(parent_id);
+        }
         if let Some(status) = fields.status {
             self.status = status;
         }
@@ -171,6 +401,345 @@ impl ToolCall {
         if let Some(raw_output) = fields.raw_output {
             self.raw_output = Some(raw_output);
         }
+        #[cfg(feature = "unstable_raw_output_patch")]
+        if let Some(patch) = fields.raw_output_patch {
+            crate::merge_patch::merge_patch(
+                self.raw_output.get_or_insert(serde_json::Value::Null),
+                &patch,
+            );
+        }
+        #[cfg(feature = "unstable_tool_call_schema")]
+        if let Some(input_schema) = fields.input_schema {
+            self.input_schema = Some(input_schema);
+        }
+        #[cfg(feature = "unstable_tool_call_schema")]
+        if let Some(output_schema) = fields.output_schema {
+            self.output_schema = Some(output_schema);
+        }
+        #[cfg(feature = "unstable_tool_call_timestamps")]
+        if let Some(completed_at) = fields.completed_at {
+            self.completed_at = Some(completed_at);
+        }
+        #[cfg(feature = "unstable_tool_call_error")]
+        if let Some(error) = fields.error {
+            self.error = Some(error);
+        }
+    }
+
+    /// The smallest update that brings `previous` up to date with this tool
+    /// call, or `None` if nothing changed.
+    ///
+    /// Only fields that differ are set, so applying the result to `previous`
+    /// with [`ToolCall::update`] reproduces `self`. `_meta` and `created_at`
+    /// are not compared.
+    ///
+    /// Updates cannot clear a field, so cleared fields are not represented: a
+    /// value that was removed is left out rather than reset. A tool call whose
+    /// only change is a cleared field still yields an update, with no fields set.
+    #[must_use]
+    pub fn diff_update(&self, previous: &ToolCall) -> Option<ToolCallUpdate> {
+        fn changed<T: PartialEq + Clone>(current: &T, previous: &T) -> Option<T> {
+            (current != previous).then(|| current.clone())
+        }
+
+        let mut compared = self.clone();
+        compared.meta.clone_from(&previous.meta);
+        #[cfg(feature = "unstable_tool_call_timestamps")]
+        compared.created_at.clone_from(&previous.created_at);
+        if compared == *previous {
+            return None;
+        }
+
+        let fields = ToolCallUpdateFields {
+            kind: changed(&self.kind, &previous.kind),
+            #[cfg(feature = "unstable_tool_kind_label")]
+            custom_kind: changed(&self.custom_kind, &previous.custom_kind).flatten(),
+            #[cfg(feature = "unstable_tool_call_group")]
+            group_id: changed(&self.group_id, &previous.group_id).flatten(),
+            #[cfg(feature = "unstable_tool_call_parent")]
+            parent_id: changed(&self.parent_id, &previous.parent_id).flatten(),
+            status: changed(&self.status, &previous.status),
+            title: changed(&self.title, &previous.title),
+            content: changed(&self.content, &previous.content),
+            locations: changed(&self.locations, &previous.locations),
+            raw_input: changed(&self.raw_input, &previous.raw_input).flatten(),
+            raw_output: changed(&self.raw_output, &previous.raw_output).flatten(),
+            #[cfg(feature = "unstable_raw_output_patch")]
+            raw_output_patch: None,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            input_schema: changed(&self.input_schema, &previous.input_schema).flatten(),
+            #[cfg(feature = "unstable_tool_call_schema")]
+            output_schema: changed(&self.output_schema, &previous.output_schema).flatten(),
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            completed_at: changed(&self.completed_at, &previous.completed_at).flatten(),
+            #[cfg(feature = "unstable_tool_call_error")]
+            error: changed(&self.error, &previous.error).flatten(),
+        };
+        Some(ToolCallUpdate::new(self.tool_call_id.clone(), fields))
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Checks `raw_input` against `input_schema`.
+    ///
+    /// Succeeds when either one is missing, since there is nothing to check.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::invalid_params`] error when `input_schema` is not a
+    /// valid JSON Schema, or when `raw_input` does not conform to it. In the
+    /// second case the error data lists each violation's `path` and `message`.
+    #[cfg(all(feature = "jsonschema", feature = "unstable_tool_call_schema"))]
+    pub fn validate_input(&self) -> Result<()> {
+        let (Some(schema), Some(input)) = (&self.input_schema, &self.raw_input) else {
+            return Ok(());
+        };
+        let validator = jsonschema::validator_for(schema).map_err(|err| {
+            Error::invalid_params().data(serde_json::json!({
+                "reason": "input schema is not a valid JSON Schema",
+                "message": err.to_string(),
+            }))
+        })?;
+        let violations: Vec<_> = validator
+            .iter_errors(input)
+            .map(|err| {
+                serde_json::json!({
+                    "path": err.instance_path().to_string(),
+                    "message": err.to_string(),
+                })
+            })
+            .collect();
+        if violations.is_empty() {
+            return Ok(());
+        }
+        Err(Error::invalid_params().data(serde_json::json!({
+            "reason": "raw input does not match the input schema",
+            "violations": violations,
+        })))
+    }
+
+    /// Whether the tool call has finished. See [`ToolCallStatus::is_terminal`].
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        self.status.is_terminal()
+    }
+
+    /// The places this tool call edits, in order, for clients that offer a
+    /// jump list.
+    ///
+    /// Each [`Diff`] in `content` contributes its path and the first line it
+    /// changes, followed by the explicit `locations`. Repeated path and line
+    /// pairs are listed once, at their first position.
+    #[must_use]
+    pub fn edit_locations(&self) -> Vec<ToolCallLocation> {
+        let diff_locations = self.content.iter().filter_map(|content| match content {
+            ToolCallContent::Diff(diff) => {
+                Some(ToolCallLocation::new(diff.path.clone()).line(diff.first_changed_line()))
+            }
+            _ => None,
+        });
+        let mut locations: Vec<ToolCallLocation> = Vec::new();
+        for location in diff_locations.chain(self.locations.iter().cloned()) {
+            if !locations
+                .iter()
+                .any(|seen| seen.path == location.path && seen.line == location.line)
+            {
+                locations.push(location);
+            }
+        }
+        locations
+    }
+
+    /// This tool call's content as a flat, ordered list of items to render.
+    ///
+    /// Content blocks are unwrapped from [`ToolCallContent::Content`]. Each
+    /// diff carries the entries of `locations` in the same file, so clients
+    /// can show them alongside it.
+    #[must_use]
+    pub fn render_items(&self) -> Vec<RenderItem<'_>> {
+        self.content
+            .iter()
+            .map(|content| match content {
+                ToolCallContent::Content(content) => RenderItem::Content(&content.content),
+                ToolCallContent::Diff(diff) => RenderItem::Diff {
+                    diff,
+                    locations: self
+                        .locations
+                        .iter()
+                        .filter(|location| location.path == diff.path)
+                        .collect(),
+                },
+                ToolCallContent::Terminal(terminal) => RenderItem::Terminal(terminal),
+            })
+            .collect()
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Splits `tool_calls` into groups for display, keeping their order.
+    ///
+    /// Tool calls that share a `group_id` are gathered at the position of the
+    /// first of them. A tool call without a `group_id` is a group of its own.
+    #[cfg(feature = "unstable_tool_call_group")]
+    #[must_use]
+    pub fn group_by(tool_calls: &[Self]) -> Vec<Vec<&Self>> {
+        let mut groups: Vec<Vec<&Self>> = Vec::new();
+        let mut group_indices: HashMap<&str, usize> = HashMap::new();
+        for tool_call in tool_calls {
+            let Some(group_id) = tool_call.group_id.as_deref() else {
+                groups.push(vec![tool_call]);
+                continue;
+            };
+            match group_indices.entry(group_id) {
+                Entry::Occupied(entry) => groups[*entry.get()].push(tool_call),
+                Entry::Vacant(entry) => {
+                    entry.insert(groups.len());
+                    groups.push(vec![tool_call]);
+                }
+            }
+        }
+        groups
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Arranges `tool_calls` into a tree for display, keeping their order.
+    ///
+    /// Each tool call becomes a child of the one its `parent_id` names. Tool
+    /// calls without a `parent_id`, and orphans whose parent is not in
+    /// `tool_calls`, are returned as roots. Every tool call appears exactly
+    /// once, so a chain of parents that loops back on itself is broken at its
+    /// first member.
+    #[cfg(feature = "unstable_tool_call_parent")]
+    #[must_use]
+    pub fn build_tree(tool_calls: &[Self]) -> Vec<ToolCallNode<'_>> {
+        let mut indices: HashMap<&ToolCallId, usize> = HashMap::new();
+        for (index, tool_call) in tool_calls.iter().enumerate() {
+            indices.entry(&tool_call.tool_call_id).or_insert(index);
+        }
+        let parents: Vec<Option<usize>> = tool_calls
+            .iter()
+            .enumerate()
+            .map(|(index, tool_call)| {
+                tool_call
+                    .parent_id
+                    .as_ref()
+                    .and_then(|parent_id| indices.get(parent_id).copied())
+                    .filter(|&parent| parent != index)
+            })
+            .collect();
+        let mut children = vec![Vec::new(); tool_calls.len()];
+        for (index, parent) in parents.iter().enumerate() {
+            if let Some(parent) = parent {
+                children[*parent].push(index);
+            }
+        }
+
+        let mut visited = vec![false; tool_calls.len()];
+        let mut nodes: Vec<Option<ToolCallNode<'_>>> = vec![None; tool_calls.len()];
+        let mut roots = Vec::new();
+        // Tool calls whose parents form a cycle are not reachable from a root,
+        // so they start trees of their own once every root is placed.
+        let starts = (0..tool_calls.len())
+            .filter(|&index| parents[index].is_none())
+            .chain(0..tool_calls.len());
+        for start in starts {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            // Walk the tree depth first so every tool call comes after its parent.
+            let mut order = Vec::new();
+            let mut stack = vec![start];
+            while let Some(index) = stack.pop() {
+                order.push(index);
+                for &child in children[index].iter().rev() {
+                    if !visited[child] {
+                        visited[child] = true;
+                        stack.push(child);
+                    }
+                }
+            }
+            // Build the nodes bottom-up, so children are complete before their parent.
+            for &index in order.iter().rev() {
+                let children = children[index]
+                    .iter()
+                    .filter_map(|&child| nodes[child].take())
+                    .collect();
+                nodes[index] = Some(ToolCallNode {
+                    tool_call: &tool_calls[index],
+                    children,
+                });
+            }
+            roots.extend(nodes[start].take());
+        }
+        roots
+    }
+}
+
+/// One renderable piece of a tool call's content.
+///
+/// See [`ToolCall::render_items`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum RenderItem<'a> {
+    /// A content block, such as text or an image.
+    Content(&'a ContentBlock),
+    /// A file modification.
+    Diff {
+        /// The diff to show.
+        diff: &'a Diff,
+        /// The tool call's locations in the diff's file.
+        locations: Vec<&'a ToolCallLocation>,
+    },
+    /// A terminal embedded with [`ToolCallContent::Terminal`].
+    Terminal(&'a Terminal),
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A tool call and the tool calls it started.
+///
+/// See [`ToolCall::build_tree`].
+#[cfg(feature = "unstable_tool_call_parent")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolCallNode<'a> {
+    tool_call: &'a ToolCall,
+    children: Vec<ToolCallNode<'a>>,
+}
+
+#[cfg(feature = "unstable_tool_call_parent")]
+impl<'a> ToolCallNode<'a> {
+    /// The tool call at this node.
+    #[must_use]
+    pub fn tool_call(&self) -> &'a ToolCall {
+        self.tool_call
+    }
+
+    /// The tool calls whose parent is this one, in order.
+    #[must_use]
+    pub fn children(&self) -> &[ToolCallNode<'a>] {
+        &self.children
+    }
+}
+
+#[cfg(feature = "unstable_tool_call_parent")]
+impl Drop for ToolCallNode<'_> {
+    fn drop(&mut self) {
+        // Detach descendants one level at a time, so dropping a deep tree
+        // doesn't recurse once per level.
+        let mut descendants = std::mem::take(&mut self.children);
+        while let Some(mut node) = descendants.pop() {
+            descendants.append(&mut node.children);
+        }
     }
 }
 
@@ -214,6 +783,26 @@ impl ToolCallUpdate {
         }
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Prepares this update to be sent in a `session/request_permission` request
+    /// to a client with `capabilities`.
+    ///
+    /// Returns the update unchanged if the client can render inline tool calls.
+    /// Otherwise keeps only the `toolCallId`, so the agent must already have
+    /// reported the tool call with a `tool_call` session update.
+    #[cfg(feature = "unstable_permission_inline_tool_call")]
+    #[must_use]
+    pub fn for_permission_request(self, capabilities: &ClientCapabilities) -> Self {
+        if capabilities.supports_inline_tool_call() {
+            self
+        } else {
+            Self::new(self.tool_call_id, ToolCallUpdateFields::new()).meta(self.meta)
+        }
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -243,6 +832,36 @@ pub struct ToolCallUpdateFields {
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
     pub kind: Option<ToolKind>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Update the free-form label refining the tool kind.
+    #[cfg(feature = "unstable_tool_kind_label")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub custom_kind: Option<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Update the group of related tool calls this one belongs to.
+    #[cfg(feature = "unstable_tool_call_group")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub group_id: Option<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Update the tool call that started this one.
+    #[cfg(feature = "unstable_tool_call_parent")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub parent_id: Option<ToolCallId>,
     /// Update the execution status.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
@@ -273,6 +892,66 @@ pub struct ToolCallUpdateFields {
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
     pub raw_output: Option<serde_json::Value>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Update the raw output by merging this patch into it.
+    ///
+    /// Applied with JSON Merge Patch (RFC 7386) semantics, after `rawOutput` when
+    /// both are present. Keys in the patch are added or overwritten, keys set to
+    /// `null` are removed, and nested objects are merged recursively. Any other
+    /// value, including an array, replaces the target outright, so arrays are
+    /// resent in full rather than appended to.
+    #[cfg(feature = "unstable_raw_output_patch")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub raw_output_patch: Option<serde_json::Value>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Update the JSON Schema describing `rawInput`.
+    #[cfg(feature = "unstable_tool_call_schema")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub input_schema: Option<serde_json::Value>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Update the JSON Schema describing `rawOutput`.
+    #[cfg(feature = "unstable_tool_call_schema")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub output_schema: Option<serde_json::Value>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Update the ISO 8601 timestamp of when the tool call reached `completed` or `failed`.
+    ///
+    /// Agents SHOULD send it in the same update that moves `status` to a terminal state.
+    #[cfg(feature = "unstable_tool_call_timestamps")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub completed_at: Option<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Details about why the tool call failed.
+    ///
+    /// Agents SHOULD set this alongside a `failed` status.
+    #[cfg(feature = "unstable_tool_call_error")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub error: Option<ToolCallError>,
 }
 
 impl ToolCallUpdateFields {
@@ -289,6 +968,42 @@ impl ToolCallUpdateFields {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Update the free-form label refining the tool kind.
+    #[cfg(feature = "unstable_tool_kind_label")]
+    #[must_use]
+    pub fn custom_kind(mut self, custom_kind: impl IntoOption<String>) -> Self {
+        self.custom_kind = custom_kind.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Update the group of related tool calls this one belongs to.
+    #[cfg(feature = "unstable_tool_call_group")]
+    #[must_use]
+    pub fn group_id(mut self, group_id: impl IntoOption<String>) -> Self {
+        self.group_id = group_id.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Update the tool call that started this one.
+    #[cfg(feature = "unstable_tool_call_parent")]
+    #[must_use]
+    pub fn parent_id(mut self, parent_id: impl IntoOption<ToolCallId>) -> Self {
+        self.parent_id = parent_id.into_option();
+        self
+    }
+
     /// Update the execution status.
     #[must_use]
     pub fn status(mut self, status: impl IntoOption<ToolCallStatus>) -> Self {
@@ -330,28 +1045,116 @@ impl ToolCallUpdateFields {
         self.raw_output = raw_output.into_option();
         self
     }
-}
 
-/// If a given tool call doesn't exist yet, allows for attempting to construct
-/// one from a tool call update if possible.
-impl TryFrom<ToolCallUpdate> for ToolCall {
-    type Error = Error;
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Update the raw output by merging this JSON Merge Patch into it.
+    #[cfg(feature = "unstable_raw_output_patch")]
+    #[must_use]
+    pub fn raw_output_patch(
+        mut self,
+        raw_output_patch: impl IntoOption<serde_json::Value>,
+    ) -> Self {
+        self.raw_output_patch = raw_output_patch.into_option();
+        self
+    }
 
-    fn try_from(update: ToolCallUpdate) -> Result<Self, Self::Error> {
-        let ToolCallUpdate {
-            tool_call_id,
-            fields:
-                ToolCallUpdateFields {
-                    kind,
-                    status,
-                    title,
-                    content,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Update the JSON Schema describing `rawInput`.
+    #[cfg(feature = "unstable_tool_call_schema")]
+    #[must_use]
+    pub fn input_schema(mut self, input_schema: impl IntoOption<serde_json::Value>) -> Self {
+        self.input_schema = input_schema.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Update the JSON Schema describing `rawOutput`.
+    #[cfg(feature = "unstable_tool_call_schema")]
+    #[must_use]
+    pub fn output_schema(mut self, output_schema: impl IntoOption<serde_json::Value>) -> Self {
+        self.output_schema = output_schema.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Update the ISO 8601 timestamp of when the tool call reached `completed` or `failed`.
+    #[cfg(feature = "unstable_tool_call_timestamps")]
+    #[must_use]
+    pub fn completed_at(mut self, completed_at: impl IntoOption<String>) -> Self {
+        self.completed_at = completed_at.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Update the details about why the tool call failed.
+    #[cfg(feature = "unstable_tool_call_error")]
+    #[must_use]
+    pub fn error(mut self, error: impl IntoOption<ToolCallError>) -> Self {
+        self.error = error.into_option();
+        self
+    }
+}
+
+/// If a given tool call doesn't exist yet, allows for attempting to construct
+/// one from a tool call update if possible.
+impl TryFrom<ToolCallUpdate> for ToolCall {
+    type Error = Error;
+
+    fn try_from(update: ToolCallUpdate) -> Result<Self, Self::Error> {
+        let ToolCallUpdate {
+            tool_call_id,
+            fields:
+                ToolCallUpdateFields {
+                    kind,
+                    #[cfg(feature = "unstable_tool_kind_label")]
+                    custom_kind,
+                    #[cfg(feature = "unstable_tool_call_group")]
+                    group_id,
+                    #[cfg(feature = "unstable_tool_call_parent")]
+                    parent_id,
+                    status,
+                    title,
+                    content,
                     locations,
                     raw_input,
                     raw_output,
+                    #[cfg(feature = "unstable_raw_output_patch")]
+                    raw_output_patch,
+                    #[cfg(feature = "unstable_tool_call_timestamps")]
+                    completed_at,
+                    #[cfg(feature = "unstable_tool_call_error")]
+                    error,
+                    #[cfg(feature = "unstable_tool_call_schema")]
+                    input_schema,
+                    #[cfg(feature = "unstable_tool_call_schema")]
+                    output_schema,
                 },
             meta,
         } = update;
+        #[cfg(feature = "unstable_raw_output_patch")]
+        let raw_output = match raw_output_patch {
+            Some(patch) => {
+                let mut raw_output = raw_output.unwrap_or_default();
+                crate::merge_patch::merge_patch(&mut raw_output, &patch);
+                Some(raw_output)
+            }
+            None => raw_output,
+        };
 
         Ok(Self {
             tool_call_id,
@@ -359,45 +1162,201 @@ impl TryFrom<ToolCallUpdate> for ToolCall {
                 Error::invalid_params().data(serde_json::json!("title is required for a tool call"))
             })?,
             kind: kind.unwrap_or_default(),
+            #[cfg(feature = "unstable_tool_kind_label")]
+            custom_kind,
+            #[cfg(feature = "unstable_tool_call_group")]
+            group_id,
+            #[cfg(feature = "unstable_tool_call_parent")]
+            parent_id,
             status: status.unwrap_or_default(),
             content: content.unwrap_or_default(),
             locations: locations.unwrap_or_default(),
             raw_input,
             raw_output,
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            created_at: None,
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            completed_at,
+            #[cfg(feature = "unstable_tool_call_error")]
+            error,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            input_schema,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            output_schema,
             meta,
         })
     }
 }
 
+/// Converts a tool call into an update that sets each of its fields.
+///
+/// Updates do not carry `createdAt`, so the start time is not part of the
+/// update, and a tool call rebuilt from it with [`ToolCall::try_from`] has no
+/// `created_at`.
 impl From<ToolCall> for ToolCallUpdate {
     fn from(value: ToolCall) -> Self {
         let ToolCall {
             tool_call_id,
             title,
             kind,
+            #[cfg(feature = "unstable_tool_kind_label")]
+            custom_kind,
+            #[cfg(feature = "unstable_tool_call_group")]
+            group_id,
+            #[cfg(feature = "unstable_tool_call_parent")]
+            parent_id,
             status,
             content,
             locations,
             raw_input,
             raw_output,
+            // Updates only carry `completedAt`: the start time is fixed once the
+            // tool call is created, so there is nothing to update it with.
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+                created_at: _,
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            completed_at,
+            #[cfg(feature = "unstable_tool_call_error")]
+            error,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            input_schema,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            output_schema,
             meta,
         } = value;
         Self {
             tool_call_id,
             fields: ToolCallUpdateFields {
                 kind: Some(kind),
+                #[cfg(feature = "unstable_tool_kind_label")]
+                custom_kind,
+                #[cfg(feature = "unstable_tool_call_group")]
+                group_id,
+                #[cfg(feature = "unstable_tool_call_parent")]
+                parent_id,
                 status: Some(status),
                 title: Some(title),
                 content: Some(content),
                 locations: Some(locations),
                 raw_input,
                 raw_output,
+                #[cfg(feature = "unstable_raw_output_patch")]
+                raw_output_patch: None,
+                #[cfg(feature = "unstable_tool_call_timestamps")]
+                completed_at,
+                #[cfg(feature = "unstable_tool_call_error")]
+                error,
+                #[cfg(feature = "unstable_tool_call_schema")]
+                input_schema,
+                #[cfg(feature = "unstable_tool_call_schema")]
+                output_schema,
             },
             meta,
         }
     }
 }
 
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A fragment of a tool call's raw input, streamed as the model generates it.
+///
+/// Concatenating the fragments for a tool call, in order, yields its raw input as
+/// JSON text. Agents SHOULD still send the complete `rawInput` once it is known.
+#[cfg(feature = "unstable_tool_call_input_delta")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ToolCallInputDelta {
+    /// The ID of the tool call this input belongs to.
+    pub tool_call_id: ToolCallId,
+    /// The next piece of the raw input JSON text.
+    pub json_fragment: String,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_tool_call_input_delta")]
+impl ToolCallInputDelta {
+    /// Builds [`ToolCallInputDelta`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(tool_call_id: impl Into<ToolCallId>, json_fragment: impl Into<String>) -> Self {
+        Self {
+            tool_call_id: tool_call_id.into(),
+            json_fragment: json_fragment.into(),
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Accumulates [`ToolCallInputDelta`] fragments for a single tool call.
+///
+/// Clients keep one assembler per tool call ID and can call
+/// [`ToolCallInputAssembler::parse`] after each fragment to show the arguments
+/// as soon as they form a complete JSON value.
+#[cfg(feature = "unstable_tool_call_input_delta")]
+#[derive(Debug, Default, Clone)]
+pub struct ToolCallInputAssembler {
+    buffer: String,
+}
+
+#[cfg(feature = "unstable_tool_call_input_delta")]
+impl ToolCallInputAssembler {
+    /// Builds an empty [`ToolCallInputAssembler`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the fragment carried by `delta`.
+    pub fn push(&mut self, delta: &ToolCallInputDelta) {
+        self.buffer.push_str(&delta.json_fragment);
+    }
+
+    /// The raw input text received so far.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Parses the raw input received so far.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is not a complete JSON value. When
+    /// [`serde_json::Error::is_eof`] is `true` the input is only incomplete and
+    /// more fragments may still arrive; any other error means it is malformed.
+    pub fn parse(&self) -> serde_json::Result<serde_json::Value> {
+        serde_json::from_str(&self.buffer)
+    }
+}
+
 /// Unique identifier for a tool call within a session.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
 #[serde(transparent)]
@@ -479,6 +1438,26 @@ pub enum ToolCallStatus {
     Completed,
     /// The tool call failed with an error.
     Failed,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The tool call was aborted before it finished, typically because the
+    /// prompt turn was cancelled.
+    #[cfg(feature = "unstable_tool_call_cancelled")]
+    Cancelled,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The tool call is blocked on a permission request and waits for the user
+    /// to approve it.
+    ///
+    /// Unlike `pending`, the input is complete. The tool call moves to
+    /// `in_progress` once approved, or to `cancelled` if the user declines or
+    /// the prompt turn is cancelled.
+    #[cfg(feature = "unstable_tool_call_awaiting_confirmation")]
+    AwaitingConfirmation,
 }
 
 impl ToolCallStatus {
@@ -486,6 +1465,87 @@ impl ToolCallStatus {
     fn is_default(&self) -> bool {
         matches!(self, ToolCallStatus::Pending)
     }
+
+    /// Whether the tool call has finished, successfully, with an error, or by
+    /// being cancelled.
+    ///
+    /// Terminal tool calls receive no further status changes.
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        match self {
+            ToolCallStatus::Completed | ToolCallStatus::Failed => true,
+            #[cfg(feature = "unstable_tool_call_cancelled")]
+            ToolCallStatus::Cancelled => true,
+            ToolCallStatus::Pending | ToolCallStatus::InProgress => false,
+            #[cfg(feature = "unstable_tool_call_awaiting_confirmation")]
+            ToolCallStatus::AwaitingConfirmation => false,
+        }
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Details about why a tool call failed.
+///
+/// Sent alongside a [`ToolCallStatus::Failed`] status so clients can explain the
+/// failure instead of only marking the tool call as failed.
+#[cfg(feature = "unstable_tool_call_error")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ToolCallError {
+    /// Human-readable description of the failure.
+    pub message: String,
+    /// Additional structured information about the failure.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub detail: Option<serde_json::Value>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_tool_call_error")]
+impl ToolCallError {
+    /// Builds [`ToolCallError`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            detail: None,
+            meta: None,
+        }
+    }
+
+    /// Additional structured information about the failure.
+    #[must_use]
+    pub fn detail(mut self, detail: impl IntoOption<serde_json::Value>) -> Self {
+        self.detail = detail.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
 }
 
 /// Content produced by a tool call.
@@ -634,6 +1694,20 @@ pub struct Diff {
     pub old_text: Option<String>,
     /// The new content after modification.
     pub new_text: String,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The changed regions of the file, for clients that let users accept or
+    /// reject each one.
+    ///
+    /// Agents that send hunks MUST honor per-hunk decisions in the permission
+    /// response. Omitted or empty means the diff can only be reviewed as a whole.
+    #[cfg(feature = "unstable_diff_hunks")]
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hunks: Vec<DiffHunk>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -654,6 +1728,8 @@ impl Diff {
             path: path.into(),
             old_text: None,
             new_text: new_text.into(),
+            #[cfg(feature = "unstable_diff_hunks")]
+            hunks: Vec::new(),
             meta: None,
         }
     }
@@ -665,6 +1741,492 @@ impl Diff {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The changed regions of the file, for clients that let users accept or
+    /// reject each one.
+    #[cfg(feature = "unstable_diff_hunks")]
+    #[must_use]
+    pub fn hunks(mut self, hunks: Vec<DiffHunk>) -> Self {
+        self.hunks = hunks;
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Splits this diff into hunks of consecutive changed lines.
+    ///
+    /// Each hunk's id is its index in the returned list, and no hunk has a
+    /// decision yet. A missing `old_text` is treated as an empty file. Very
+    /// large rewrites may come back as a single hunk.
+    #[cfg(feature = "unstable_diff_hunks")]
+    #[must_use]
+    pub fn compute_hunks(&self) -> Vec<DiffHunk> {
+        crate::line_diff::line_hunks(self.old_text.as_deref().unwrap_or_default(), &self.new_text)
+            .into_iter()
+            .enumerate()
+            .map(|(index, hunk)| {
+                DiffHunk::new(
+                    index.to_string(),
+                    DiffLineRange::from_offset(hunk.old_start, hunk.old_count),
+                    DiffLineRange::from_offset(hunk.new_start, hunk.new_count),
+                )
+            })
+            .collect()
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Records the decisions in `decisions` that target this diff's hunks.
+    ///
+    /// Decisions for other files or unknown hunk ids are ignored.
+    #[cfg(feature = "unstable_diff_hunks")]
+    pub fn apply_hunk_decisions(&mut self, decisions: &[DiffHunkDecision]) {
+        for decision in decisions
+            .iter()
+            .filter(|decision| decision.path == self.path)
+        {
+            if let Some(hunk) = self
+                .hunks
+                .iter_mut()
+                .find(|hunk| hunk.id == decision.hunk_id)
+            {
+                hunk.accepted = Some(decision.accepted);
+            }
+        }
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The file contents after this diff with only the hunks the user did not
+    /// reject: each hunk whose `accepted` is `false` keeps its lines from
+    /// `old_text`, while accepted and undecided hunks take theirs from
+    /// `new_text`.
+    ///
+    /// Returns `None` when a hunk is rejected and the hunks don't line up with
+    /// the text, for example because their ranges overlap, run past the end,
+    /// or miss a changed line.
+    #[cfg(feature = "unstable_diff_hunks")]
+    #[must_use]
+    pub fn accepted_text(&self) -> Option<String> {
+        if self.hunks.iter().all(|hunk| hunk.accepted != Some(false)) {
+            return Some(self.new_text.clone());
+        }
+
+        let old_lines: Vec<&str> = self
+            .old_text
+            .as_deref()
+            .unwrap_or_default()
+            .split_inclusive('\n')
+            .collect();
+        let new_lines: Vec<&str> = self.new_text.split_inclusive('\n').collect();
+        let range = |range: &DiffLineRange| {
+            let start = usize::try_from(range.start).ok()?.checked_sub(1)?;
+            Some(start..start.checked_add(usize::try_from(range.count).ok()?)?)
+        };
+
+        let mut hunks: Vec<&DiffHunk> = self.hunks.iter().collect();
+        hunks.sort_by_key(|hunk| hunk.old_range.start);
+        let mut text = String::new();
+        let (mut old_end, mut new_end) = (0, 0);
+        for hunk in hunks {
+            let old_range = range(&hunk.old_range)?;
+            let new_range = range(&hunk.new_range)?;
+            // Lines between hunks are unchanged, so both sides must agree on them.
+            let unchanged = old_lines.get(old_end..old_range.start)?;
+            if new_lines.get(new_end..new_range.start)? != unchanged {
+                return None;
+            }
+            text.extend(unchanged.iter().copied());
+            let old = old_lines.get(old_range.clone())?;
+            let new = new_lines.get(new_range.clone())?;
+            let kept = if hunk.accepted == Some(false) {
+                old
+            } else {
+                new
+            };
+            text.extend(kept.iter().copied());
+            (old_end, new_end) = (old_range.end, new_range.end);
+        }
+        let unchanged = old_lines.get(old_end..)?;
+        if new_lines.get(new_end..)? != unchanged {
+            return None;
+        }
+        text.extend(unchanged.iter().copied());
+        Some(text)
+    }
+
+    /// Reconstructs the file contents after this diff, given the file as it is now.
+    ///
+    /// `current` is the file's content, or `None` if it does not exist. When
+    /// [`Diff::old_text`] is present, `current` must match it exactly; otherwise the
+    /// diff was computed against a different version of the file and applying it would
+    /// silently discard changes. When `old_text` is absent (a new file), the file must
+    /// not exist yet. This is the same rule `ConflictReport::check` uses.
+    ///
+    /// Hunks the user rejected are left as they are in `old_text`, see
+    /// [`Diff::accepted_text`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DiffConflictError`] naming the `path` when `current` does not
+    /// match `old_text`, so clients can warn about a stale edit, or when rejected
+    /// hunks do not line up with the diff's text. It converts into an
+    /// [`Error::invalid_params`] error for replying to a request.
+    pub fn apply(&self, current: Option<&str>) -> std::result::Result<String, DiffConflictError> {
+        if let Some(reason) = self.conflict_reason(current) {
+            return Err(DiffConflictError {
+                path: self.path.clone(),
+                reason,
+            });
+        }
+        #[cfg(feature = "unstable_diff_hunks")]
+        let new_text = self.accepted_text().ok_or_else(|| DiffConflictError {
+            path: self.path.clone(),
+            reason: "the diff's hunks do not match its text",
+        })?;
+        #[cfg(not(feature = "unstable_diff_hunks"))]
+        let new_text = self.new_text.clone();
+        Ok(new_text)
+    }
+
+    /// Why this diff cannot be applied to a file holding `current`, `None` if
+    /// the file does not exist. Returns `None` when it can be applied.
+    fn conflict_reason(&self, current: Option<&str>) -> Option<&'static str> {
+        match (self.old_text.as_deref(), current) {
+            (Some(_), None) => Some("the file does not exist"),
+            (None, Some(_)) => Some("the file already exists"),
+            (old_text, current) => (old_text != current)
+                .then_some("file contents do not match the diff's original text"),
+        }
+    }
+
+    /// The 1-based line of `newText` where this diff's first change starts.
+    ///
+    /// A missing `oldText` is treated as an empty file. Returns `None` when
+    /// the diff changes nothing.
+    fn first_changed_line(&self) -> Option<u32> {
+        let old_text = self.old_text.as_deref().unwrap_or_default();
+        if old_text == self.new_text {
+            return None;
+        }
+        let unchanged = old_text
+            .split_inclusive('\n')
+            .zip(self.new_text.split_inclusive('\n'))
+            .take_while(|(old_line, new_line)| old_line == new_line)
+            .count();
+        u32::try_from(unchanged + 1).ok()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Error returned by [`Diff::apply`] when the file no longer holds the
+/// diff's `oldText`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DiffConflictError {
+    path: PathBuf,
+    reason: &'static str,
+}
+
+impl DiffConflictError {
+    /// Returns the absolute path of the file the diff targets.
+    #[must_use]
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+
+    /// Returns the human-readable reason the diff could not be applied.
+    #[must_use]
+    pub fn reason(&self) -> &str {
+        self.reason
+    }
+}
+
+impl std::fmt::Display for DiffConflictError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{}: {}", self.path.display(), self.reason)
+    }
+}
+
+impl std::error::Error for DiffConflictError {}
+
+impl From<DiffConflictError> for Error {
+    fn from(error: DiffConflictError) -> Self {
+        Error::invalid_params().data(serde_json::json!({
+            "reason": error.reason,
+            "path": error.path,
+        }))
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A [`Diff`] that could not be applied because the file no longer matches it.
+#[cfg(feature = "unstable_edit_conflict")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ConflictReport {
+    /// The absolute path of the file the diff targets.
+    pub path: PathBuf,
+    /// The content the diff expected to replace, its `oldText`. Omitted when
+    /// the diff creates the file.
+    #[serde(default)]
+    pub expected: Option<String>,
+    /// The file's content when the client tried to apply the diff. Omitted when
+    /// the file does not exist.
+    #[serde(default)]
+    pub actual: Option<String>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_edit_conflict")]
+impl ConflictReport {
+    /// Builds [`ConflictReport`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(
+        path: impl Into<PathBuf>,
+        expected: impl IntoOption<String>,
+        actual: impl IntoOption<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            expected: expected.into_option(),
+            actual: actual.into_option(),
+            meta: None,
+        }
+    }
+
+    /// Compares `diff` with the file's `actual` content, `None` if the file
+    /// does not exist, returning a report if the file no longer holds the
+    /// diff's `oldText`.
+    ///
+    /// Diffs without `oldText` create a file, so they conflict when the file
+    /// already exists.
+    #[must_use]
+    pub fn check(diff: &Diff, actual: Option<&str>) -> Option<Self> {
+        diff.conflict_reason(actual)?;
+        Some(Self::new(
+            diff.path.clone(),
+            diff.old_text.clone(),
+            actual.map(str::to_owned),
+        ))
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A region of a [`Diff`] that the user can accept or reject on its own.
+#[cfg(feature = "unstable_diff_hunks")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DiffHunk {
+    /// Identifies the hunk within its diff. Stays the same for the lifetime
+    /// of the tool call, so decisions can refer to it.
+    pub id: String,
+    /// The lines this hunk replaces in `oldText`.
+    pub old_range: DiffLineRange,
+    /// The lines this hunk introduces in `newText`.
+    pub new_range: DiffLineRange,
+    /// Whether the user accepted the hunk. Omitted while undecided.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub accepted: Option<bool>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_diff_hunks")]
+impl DiffHunk {
+    /// Builds [`DiffHunk`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(id: impl Into<String>, old_range: DiffLineRange, new_range: DiffLineRange) -> Self {
+        Self {
+            id: id.into(),
+            old_range,
+            new_range,
+            accepted: None,
+            meta: None,
+        }
+    }
+
+    /// Whether the user accepted the hunk. Omitted while undecided.
+    #[must_use]
+    pub fn accepted(mut self, accepted: impl IntoOption<bool>) -> Self {
+        self.accepted = accepted.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A run of lines on one side of a [`DiffHunk`].
+#[cfg(feature = "unstable_diff_hunks")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DiffLineRange {
+    /// The 1-based number of the first line in the range. For an empty range,
+    /// the number of the line it sits before.
+    pub start: u32,
+    /// How many lines the range covers. Zero for a pure insertion or deletion.
+    pub count: u32,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_diff_hunks")]
+impl DiffLineRange {
+    /// Builds [`DiffLineRange`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(start: u32, count: u32) -> Self {
+        Self {
+            start,
+            count,
+            meta: None,
+        }
+    }
+
+    fn from_offset(offset: usize, count: usize) -> Self {
+        let to_u32 = |value: usize| u32::try_from(value).unwrap_or(u32::MAX);
+        Self::new(to_u32(offset.saturating_add(1)), to_u32(count))
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// The user's decision on one [`DiffHunk`], sent in a permission response.
+#[cfg(feature = "unstable_diff_hunks")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DiffHunkDecision {
+    /// The file the hunk belongs to, matching [`Diff::path`].
+    pub path: PathBuf,
+    /// The [`DiffHunk::id`] this decision applies to.
+    pub hunk_id: String,
+    /// Whether the user accepted the hunk.
+    pub accepted: bool,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_diff_hunks")]
+impl DiffHunkDecision {
+    /// Builds [`DiffHunkDecision`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>, hunk_id: impl Into<String>, accepted: bool) -> Self {
+        Self {
+            path: path.into(),
+            hunk_id: hunk_id.into(),
+            accepted,
+            meta: None,
+        }
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -737,3 +2299,692 @@ impl ToolCallLocation {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_tool_call_status_is_terminal() {
+        for (status, terminal) in [
+            (ToolCallStatus::Pending, false),
+            (ToolCallStatus::InProgress, false),
+            (ToolCallStatus::Completed, true),
+            (ToolCallStatus::Failed, true),
+            #[cfg(feature = "unstable_tool_call_cancelled")]
+            (ToolCallStatus::Cancelled, true),
+            #[cfg(feature = "unstable_tool_call_awaiting_confirmation")]
+            (ToolCallStatus::AwaitingConfirmation, false),
+        ] {
+            assert_eq!(status.is_terminal(), terminal, "{status:?}");
+            assert_eq!(
+                ToolCall::new("call_001", "Read file")
+                    .status(status)
+                    .is_terminal(),
+                terminal,
+                "{status:?}"
+            );
+        }
+    }
+
+    #[cfg(feature = "unstable_tool_call_cancelled")]
+    #[test]
+    fn test_tool_call_status_cancelled_roundtrip() {
+        assert_eq!(
+            serde_json::to_value(ToolCallStatus::Cancelled).unwrap(),
+            "cancelled"
+        );
+        assert_eq!(
+            serde_json::from_value::<ToolCallStatus>("cancelled".into()).unwrap(),
+            ToolCallStatus::Cancelled
+        );
+
+        let tool_call = ToolCall::new("call_001", "Run tests").status(ToolCallStatus::Cancelled);
+        let json = serde_json::to_value(&tool_call).unwrap();
+        assert_eq!(json["status"], "cancelled");
+        assert_eq!(serde_json::from_value::<ToolCall>(json).unwrap(), tool_call);
+    }
+
+    #[cfg(feature = "unstable_tool_call_awaiting_confirmation")]
+    #[test]
+    fn test_tool_call_status_awaiting_confirmation_roundtrip() {
+        assert_eq!(
+            serde_json::to_value(ToolCallStatus::AwaitingConfirmation).unwrap(),
+            "awaiting_confirmation"
+        );
+        assert_eq!(
+            serde_json::from_value::<ToolCallStatus>("awaiting_confirmation".into()).unwrap(),
+            ToolCallStatus::AwaitingConfirmation
+        );
+        assert!(!ToolCallStatus::AwaitingConfirmation.is_terminal());
+
+        let tool_call = ToolCall::new("call_001", "Delete build directory")
+            .status(ToolCallStatus::AwaitingConfirmation);
+        let json = serde_json::to_value(&tool_call).unwrap();
+        assert_eq!(json["status"], "awaiting_confirmation");
+        assert_eq!(serde_json::from_value::<ToolCall>(json).unwrap(), tool_call);
+    }
+
+    #[test]
+    fn test_edit_locations() {
+        let tool_call = ToolCall::new("call_001", "Rename config field")
+            .content(vec![
+                ToolCallContent::from("Renamed `port` to `listen_port`."),
+                Diff::new(
+                    "/project/src/config.rs",
+                    "use std::net;\n\nlet listen_port = 80;\n",
+                )
+                .old_text("use std::net;\n\nlet port = 80;\n")
+                .into(),
+                Diff::new("/project/CHANGELOG.md", "- Renamed `port`.\n").into(),
+            ])
+            .locations(vec![
+                ToolCallLocation::new("/project/src/config.rs").line(3),
+                ToolCallLocation::new("/project/src/main.rs").line(10),
+                ToolCallLocation::new("/project/CHANGELOG.md").line(1),
+            ]);
+
+        assert_eq!(
+            tool_call.edit_locations(),
+            vec![
+                ToolCallLocation::new("/project/src/config.rs").line(3),
+                ToolCallLocation::new("/project/CHANGELOG.md").line(1),
+                ToolCallLocation::new("/project/src/main.rs").line(10),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_edit_locations_omits_line_for_unchanged_diff() {
+        let tool_call = ToolCall::new("call_001", "Rename config field").content(vec![
+            Diff::new("/project/README.md", "same\n")
+                .old_text("same\n")
+                .into(),
+        ]);
+
+        assert_eq!(
+            tool_call.edit_locations(),
+            vec![ToolCallLocation::new("/project/README.md")]
+        );
+    }
+
+    #[test]
+    fn test_render_items() {
+        let config_diff = Diff::new("/project/src/config.rs", "let listen_port = 80;\n")
+            .old_text("let port = 80;\n");
+        let changelog_diff = Diff::new("/project/CHANGELOG.md", "- Renamed `port`.\n");
+        let tool_call = ToolCall::new("call_001", "Rename config field")
+            .content(vec![
+                ToolCallContent::from("Renamed `port` to `listen_port`."),
+                config_diff.clone().into(),
+                ToolCallContent::Terminal(Terminal::new("term_1")),
+                changelog_diff.clone().into(),
+            ])
+            .locations(vec![
+                ToolCallLocation::new("/project/src/config.rs").line(1),
+                ToolCallLocation::new("/project/src/main.rs").line(10),
+                ToolCallLocation::new("/project/src/config.rs").line(20),
+            ]);
+
+        assert_eq!(
+            tool_call.render_items(),
+            vec![
+                RenderItem::Content(&ContentBlock::from("Renamed `port` to `listen_port`.")),
+                RenderItem::Diff {
+                    diff: &config_diff,
+                    locations: vec![
+                        &ToolCallLocation::new("/project/src/config.rs").line(1),
+                        &ToolCallLocation::new("/project/src/config.rs").line(20),
+                    ],
+                },
+                RenderItem::Terminal(&Terminal::new("term_1")),
+                RenderItem::Diff {
+                    diff: &changelog_diff,
+                    locations: vec![],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_update() {
+        let previous = ToolCall::new("call_001", "Run tests")
+            .kind(ToolKind::Execute)
+            .status(ToolCallStatus::InProgress)
+            .content(vec!["Compiling...".into()])
+            .raw_input(json!({ "command": "cargo test" }));
+
+        // No change.
+        assert_eq!(previous.clone().diff_update(&previous), None);
+
+        // Status only.
+        let current = previous.clone().status(ToolCallStatus::Completed);
+        let update = current.diff_update(&previous).unwrap();
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({ "toolCallId": "call_001", "status": "completed" })
+        );
+        let mut applied = previous.clone();
+        applied.update(update.fields);
+        assert_eq!(applied, current);
+
+        // Content only.
+        let current = previous
+            .clone()
+            .content(vec!["Compiling...".into(), "Running 12 tests".into()]);
+        let update = current.diff_update(&previous).unwrap();
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({
+                "toolCallId": "call_001",
+                "content": [
+                    { "type": "content", "content": { "type": "text", "text": "Compiling..." } },
+                    { "type": "content", "content": { "type": "text", "text": "Running 12 tests" } }
+                ]
+            })
+        );
+        let mut applied = previous.clone();
+        applied.update(update.fields);
+        assert_eq!(applied, current);
+
+        // A cleared field still counts as a change, but cannot be represented.
+        let mut current = previous.clone();
+        current.raw_input = None;
+        let update = current.diff_update(&previous).unwrap();
+        assert_eq!(update.fields, ToolCallUpdateFields::default());
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({ "toolCallId": "call_001" })
+        );
+
+        // `_meta` is not compared.
+        let current = previous.clone().meta(Meta::new());
+        assert_eq!(current.diff_update(&previous), None);
+    }
+
+    #[test]
+    fn test_plain_other_kind_is_default() {
+        assert!(ToolKind::Other.is_default());
+        assert_eq!(
+            serde_json::to_value(ToolKind::Other).unwrap(),
+            json!("other")
+        );
+
+        let tool_call: ToolCall = serde_json::from_value(
+            json!({"toolCallId": "call_1", "title": "Query", "kind": "other"}),
+        )
+        .unwrap();
+        assert_eq!(tool_call.kind, ToolKind::Other);
+        assert_eq!(
+            serde_json::to_value(&tool_call).unwrap(),
+            json!({"toolCallId": "call_1", "title": "Query"})
+        );
+    }
+
+    #[cfg(feature = "unstable_tool_kind_label")]
+    #[test]
+    fn test_labeled_other_kind_roundtrip() {
+        let tool_call = ToolCall::new("call_1", "Query").custom_kind("database query");
+        let json = serde_json::to_value(&tool_call).unwrap();
+        assert_eq!(
+            json,
+            json!({"toolCallId": "call_1", "title": "Query", "customKind": "database query"})
+        );
+        assert!(tool_call.kind.is_default());
+
+        let parsed: ToolCall = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, tool_call);
+
+        let mut updated = ToolCall::new("call_1", "Query");
+        updated.update(ToolCallUpdateFields::new().custom_kind("database query"));
+        assert_eq!(updated, tool_call);
+    }
+
+    #[cfg(feature = "unstable_tool_call_group")]
+    #[test]
+    fn test_tool_call_group_roundtrip() {
+        let first = ToolCall::new("call_1", "Run tests").group_id("tests");
+        let retry = ToolCall::new("call_2", "Run tests").group_id("tests");
+        for tool_call in [&first, &retry] {
+            let json = serde_json::to_value(tool_call).unwrap();
+            assert_eq!(json["groupId"], "tests");
+            assert_eq!(
+                &serde_json::from_value::<ToolCall>(json).unwrap(),
+                tool_call
+            );
+        }
+        assert_eq!(
+            serde_json::to_value(&first).unwrap(),
+            json!({"toolCallId": "call_1", "title": "Run tests", "groupId": "tests"})
+        );
+
+        let mut updated = ToolCall::new("call_1", "Run tests");
+        updated.update(ToolCallUpdateFields::new().group_id("tests"));
+        assert_eq!(updated, first);
+    }
+
+    #[cfg(feature = "unstable_tool_call_group")]
+    #[test]
+    fn test_tool_call_group_by() {
+        let tool_calls = [
+            ToolCall::new("call_1", "Run tests").group_id("tests"),
+            ToolCall::new("call_2", "Read file"),
+            ToolCall::new("call_3", "Run tests").group_id("tests"),
+            ToolCall::new("call_4", "Search"),
+        ];
+
+        let groups = ToolCall::group_by(&tool_calls);
+        assert_eq!(
+            groups,
+            [
+                vec![&tool_calls[0], &tool_calls[2]],
+                vec![&tool_calls[1]],
+                vec![&tool_calls[3]],
+            ]
+        );
+    }
+
+    #[cfg(feature = "unstable_tool_call_parent")]
+    #[test]
+    fn test_tool_call_parent_roundtrip() {
+        let child = ToolCall::new("call_grep", "Search for TODOs").parent_id("call_agent");
+        let json = serde_json::to_value(&child).unwrap();
+        assert_eq!(
+            json,
+            json!({"toolCallId": "call_grep", "title": "Search for TODOs", "parentId": "call_agent"})
+        );
+        assert_eq!(serde_json::from_value::<ToolCall>(json).unwrap(), child);
+
+        let mut updated = ToolCall::new("call_grep", "Search for TODOs");
+        updated.update(ToolCallUpdateFields::new().parent_id("call_agent"));
+        assert_eq!(updated, child);
+    }
+
+    #[cfg(feature = "unstable_tool_call_parent")]
+    #[test]
+    fn test_tool_call_build_tree() {
+        let tool_calls = [
+            ToolCall::new("call_agent", "Run sub-agent"),
+            ToolCall::new("call_grep", "Search for TODOs").parent_id("call_agent"),
+            ToolCall::new("call_lint", "Run linter").parent_id("call_missing"),
+            ToolCall::new("call_read", "Read file").parent_id("call_agent"),
+            ToolCall::new("call_build", "Build"),
+        ];
+
+        let tree = ToolCall::build_tree(&tool_calls);
+        let roots: Vec<_> = tree.iter().map(ToolCallNode::tool_call).collect();
+        // The orphan is kept as a root, in its original position.
+        assert_eq!(roots, [&tool_calls[0], &tool_calls[2], &tool_calls[4]]);
+        let children: Vec<_> = tree[0]
+            .children()
+            .iter()
+            .map(ToolCallNode::tool_call)
+            .collect();
+        assert_eq!(children, [&tool_calls[1], &tool_calls[3]]);
+        assert!(
+            tree[0]
+                .children()
+                .iter()
+                .all(|node| node.children().is_empty())
+        );
+        assert!(tree[1].children().is_empty());
+        assert!(tree[2].children().is_empty());
+
+        // A cycle is broken at its first member rather than dropped.
+        let cycle = [
+            ToolCall::new("call_a", "A").parent_id("call_b"),
+            ToolCall::new("call_b", "B").parent_id("call_a"),
+        ];
+        let tree = ToolCall::build_tree(&cycle);
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].tool_call(), &cycle[0]);
+        assert_eq!(tree[0].children().len(), 1);
+        assert_eq!(tree[0].children()[0].tool_call(), &cycle[1]);
+        assert!(tree[0].children()[0].children().is_empty());
+
+        // A long chain of sub-agents is built and dropped without recursing per level.
+        let chain: Vec<_> = (0..100_000_usize)
+            .map(|index| {
+                ToolCall::new(format!("call_{index}"), "Run sub-agent").parent_id(
+                    index
+                        .checked_sub(1)
+                        .map(|parent| ToolCallId::new(format!("call_{parent}"))),
+                )
+            })
+            .collect();
+        let tree = ToolCall::build_tree(&chain);
+        assert_eq!(tree.len(), 1);
+        let mut depth = 1;
+        let mut node = &tree[0];
+        while let [child] = node.children() {
+            depth += 1;
+            node = child;
+        }
+        assert_eq!(depth, chain.len());
+        assert_eq!(node.tool_call(), chain.last().unwrap());
+    }
+
+    #[cfg(feature = "unstable_raw_output_patch")]
+    #[test]
+    fn test_raw_output_patch_adds_overwrites_and_deletes_keys() {
+        let mut tool_call = ToolCall::new("call_1", "Run tests")
+            .raw_output(json!({"status": "running", "log": ["collecting"], "pid": 42}));
+        let patch = ToolCallUpdateFields::new().raw_output_patch(json!({
+            "status": "passed",
+            "log": ["collecting", "1 passed"],
+            "summary": {"passed": 1},
+            "pid": null
+        }));
+        assert_eq!(
+            serde_json::to_value(&patch).unwrap()["rawOutputPatch"]["pid"],
+            json!(null)
+        );
+        tool_call.update(patch);
+        assert_eq!(
+            tool_call.raw_output,
+            Some(
+                json!({"status": "passed", "log": ["collecting", "1 passed"], "summary": {"passed": 1}})
+            )
+        );
+    }
+
+    #[cfg(feature = "unstable_raw_output_patch")]
+    #[test]
+    fn test_raw_output_patch_without_existing_output() {
+        let mut tool_call = ToolCall::new("call_1", "Run tests");
+        tool_call.update(
+            ToolCallUpdateFields::new().raw_output_patch(json!({"status": "running", "pid": null})),
+        );
+        assert_eq!(tool_call.raw_output, Some(json!({"status": "running"})));
+
+        let tool_call = ToolCall::try_from(ToolCallUpdate::new(
+            "call_1",
+            ToolCallUpdateFields::new()
+                .title("Run tests")
+                .raw_output(json!({"status": "running"}))
+                .raw_output_patch(json!({"status": "passed"})),
+        ))
+        .unwrap();
+        assert_eq!(tool_call.raw_output, Some(json!({"status": "passed"})));
+    }
+
+    #[cfg(feature = "unstable_tool_call_timestamps")]
+    #[test]
+    fn test_tool_call_timestamps() {
+        let mut tool_call = ToolCall::new("call_1", "Query").created_at("2025-01-01T00:00:00Z");
+        assert_eq!(
+            serde_json::to_value(&tool_call).unwrap(),
+            json!({"toolCallId": "call_1", "title": "Query", "createdAt": "2025-01-01T00:00:00Z"})
+        );
+
+        tool_call.update(
+            ToolCallUpdateFields::new()
+                .status(ToolCallStatus::Completed)
+                .completed_at("2025-01-01T00:00:05Z"),
+        );
+        assert_eq!(tool_call.status, ToolCallStatus::Completed);
+        assert_eq!(
+            tool_call.created_at.as_deref(),
+            Some("2025-01-01T00:00:00Z")
+        );
+        assert_eq!(
+            tool_call.completed_at.as_deref(),
+            Some("2025-01-01T00:00:05Z")
+        );
+
+        let parsed: ToolCall =
+            serde_json::from_value(serde_json::to_value(&tool_call).unwrap()).unwrap();
+        assert_eq!(parsed, tool_call);
+
+        let rebuilt = ToolCall::try_from(ToolCallUpdate::from(tool_call.clone())).unwrap();
+        assert_eq!(rebuilt.created_at, None);
+        assert_eq!(rebuilt.completed_at, tool_call.completed_at);
+    }
+
+    #[cfg(feature = "unstable_tool_call_schema")]
+    #[test]
+    fn test_tool_call_schemas() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"path": {"type": "string"}},
+            "required": ["path"]
+        });
+        let mut tool_call = ToolCall::new("call_1", "Read").input_schema(schema.clone());
+        tool_call.update(ToolCallUpdateFields::new().output_schema(json!({"type": "string"})));
+        assert_eq!(
+            serde_json::to_value(&tool_call).unwrap(),
+            json!({
+                "toolCallId": "call_1",
+                "title": "Read",
+                "inputSchema": schema,
+                "outputSchema": {"type": "string"}
+            })
+        );
+
+        let parsed: ToolCall =
+            serde_json::from_value(serde_json::to_value(&tool_call).unwrap()).unwrap();
+        assert_eq!(parsed, tool_call);
+    }
+
+    #[cfg(all(feature = "jsonschema", feature = "unstable_tool_call_schema"))]
+    #[test]
+    fn test_tool_call_validate_input() {
+        let tool_call = ToolCall::new("call_1", "Read").input_schema(json!({
+            "type": "object",
+            "properties": {"path": {"type": "string"}},
+            "required": ["path"]
+        }));
+        assert!(tool_call.validate_input().is_ok());
+
+        let conforming = tool_call.clone().raw_input(json!({"path": "/a.rs"}));
+        assert!(conforming.validate_input().is_ok());
+
+        let err = tool_call
+            .clone()
+            .raw_input(json!({"path": 1}))
+            .validate_input()
+            .unwrap_err();
+        assert_eq!(err.code, Error::invalid_params().code);
+        let violations = &err.data.unwrap()["violations"];
+        assert_eq!(violations.as_array().unwrap().len(), 1);
+        assert_eq!(violations[0]["path"], "/path");
+
+        let invalid_schema = tool_call
+            .input_schema(json!({"type": 5}))
+            .raw_input(json!({"path": "/a.rs"}));
+        assert_eq!(
+            invalid_schema.validate_input().unwrap_err().code,
+            Error::invalid_params().code
+        );
+    }
+
+    #[cfg(feature = "unstable_tool_call_error")]
+    #[test]
+    fn test_tool_call_error() {
+        let mut tool_call = ToolCall::new("call_1", "Query");
+        tool_call.update(
+            ToolCallUpdateFields::new()
+                .status(ToolCallStatus::Failed)
+                .error(ToolCallError::new("connection refused").detail(json!({"port": 5432}))),
+        );
+        assert_eq!(tool_call.status, ToolCallStatus::Failed);
+        assert_eq!(
+            serde_json::to_value(&tool_call).unwrap(),
+            json!({
+                "toolCallId": "call_1",
+                "title": "Query",
+                "status": "failed",
+                "error": {"message": "connection refused", "detail": {"port": 5432}}
+            })
+        );
+
+        let parsed: ToolCall =
+            serde_json::from_value(serde_json::to_value(&tool_call).unwrap()).unwrap();
+        assert_eq!(parsed, tool_call);
+    }
+
+    #[test]
+    fn test_diff_apply() {
+        let diff = Diff::new("/src/main.rs", "fn main() {}\n").old_text("fn main() {\n}\n");
+        assert_eq!(
+            diff.apply(Some("fn main() {\n}\n")).unwrap(),
+            "fn main() {}\n"
+        );
+        assert_eq!(
+            diff.apply(None).unwrap_err().reason(),
+            "the file does not exist"
+        );
+
+        let new_file = Diff::new("/src/lib.rs", "pub mod cli;\n");
+        assert_eq!(new_file.apply(None).unwrap(), "pub mod cli;\n");
+        // Creating a file that appeared in the meantime would overwrite it.
+        for current in ["pub mod old;\n", ""] {
+            assert_eq!(
+                new_file.apply(Some(current)).unwrap_err().reason(),
+                "the file already exists"
+            );
+        }
+
+        let err = diff.apply(Some("fn main() { todo!() }\n")).unwrap_err();
+        assert_eq!(err.path(), std::path::Path::new("/src/main.rs"));
+        assert_eq!(
+            err.to_string(),
+            "/src/main.rs: file contents do not match the diff's original text"
+        );
+
+        // Replying to a request still sends `invalid_params` naming the path.
+        let err = Error::from(err);
+        assert_eq!(err.code, crate::v1::ErrorCode::InvalidParams);
+        assert_eq!(
+            err.data.unwrap(),
+            json!({
+                "reason": "file contents do not match the diff's original text",
+                "path": "/src/main.rs"
+            })
+        );
+    }
+
+    #[cfg(feature = "unstable_edit_conflict")]
+    #[test]
+    fn test_conflict_report_check() {
+        let diff = Diff::new("/src/main.rs", "fn main() {}\n").old_text("fn main() {\n}\n");
+        assert_eq!(ConflictReport::check(&diff, Some("fn main() {\n}\n")), None);
+        assert_eq!(
+            ConflictReport::check(&diff, Some("fn main() { todo!() }\n")),
+            Some(ConflictReport::new(
+                "/src/main.rs",
+                "fn main() {\n}\n",
+                "fn main() { todo!() }\n"
+            ))
+        );
+        // The file was deleted since the diff was produced.
+        assert_eq!(
+            ConflictReport::check(&diff, None),
+            Some(ConflictReport::new(
+                "/src/main.rs",
+                "fn main() {\n}\n",
+                None
+            ))
+        );
+
+        let new_file = Diff::new("/src/lib.rs", "pub mod cli;\n");
+        assert_eq!(ConflictReport::check(&new_file, None), None);
+        // The file was created since the diff was produced.
+        let report = ConflictReport::check(&new_file, Some("pub mod old;\n")).unwrap();
+        assert_eq!(
+            report,
+            ConflictReport::new("/src/lib.rs", None, "pub mod old;\n")
+        );
+        assert_eq!(
+            serde_json::to_value(&report).unwrap(),
+            json!({"path": "/src/lib.rs", "actual": "pub mod old;\n"})
+        );
+    }
+
+    #[cfg(feature = "unstable_diff_hunks")]
+    #[test]
+    fn test_diff_hunks() {
+        let diff = Diff::new("/src/main.rs", "a\nB\nc\nd\nE\nf\n").old_text("a\nb\nc\nd\ne\n");
+        let hunks = diff.compute_hunks();
+        assert_eq!(
+            hunks,
+            vec![
+                DiffHunk::new("0", DiffLineRange::new(2, 1), DiffLineRange::new(2, 1)),
+                DiffHunk::new("1", DiffLineRange::new(5, 1), DiffLineRange::new(5, 2)),
+            ]
+        );
+
+        let mut diff = diff.hunks(hunks);
+        diff.apply_hunk_decisions(&[
+            DiffHunkDecision::new("/src/main.rs", "1", false),
+            DiffHunkDecision::new("/src/other.rs", "0", true),
+        ]);
+        assert_eq!(diff.hunks[0].accepted, None);
+        assert_eq!(diff.hunks[1].accepted, Some(false));
+
+        // The rejected hunk keeps its original lines; the undecided one is applied.
+        assert_eq!(diff.accepted_text().unwrap(), "a\nB\nc\nd\ne\n");
+        assert_eq!(
+            diff.apply(Some("a\nb\nc\nd\ne\n")).unwrap(),
+            "a\nB\nc\nd\ne\n"
+        );
+        diff.apply_hunk_decisions(&[
+            DiffHunkDecision::new("/src/main.rs", "0", false),
+            DiffHunkDecision::new("/src/main.rs", "1", true),
+        ]);
+        assert_eq!(diff.accepted_text().unwrap(), "a\nb\nc\nd\nE\nf\n");
+
+        // Hunks that miss a changed line cannot be applied selectively.
+        let mut partial = diff.clone().hunks(vec![diff.hunks[0].clone()]);
+        assert_eq!(partial.accepted_text(), None);
+        let err = partial.apply(Some("a\nb\nc\nd\ne\n")).unwrap_err();
+        assert_eq!(err.reason(), "the diff's hunks do not match its text");
+        partial.hunks[0].accepted = Some(true);
+        assert_eq!(partial.accepted_text().unwrap(), diff.new_text);
+
+        let new_file = Diff::new("/src/lib.rs", "pub mod cli;\n");
+        assert_eq!(
+            new_file.compute_hunks(),
+            vec![DiffHunk::new(
+                "0",
+                DiffLineRange::new(1, 0),
+                DiffLineRange::new(1, 1)
+            )]
+        );
+    }
+
+    #[cfg(feature = "unstable_tool_call_input_delta")]
+    #[test]
+    fn test_tool_call_input_delta_assembly() {
+        let update = crate::v1::SessionUpdate::ToolCallInputDelta(ToolCallInputDelta::new(
+            "call_1",
+            "{\"path\": \"/sr",
+        ));
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({
+                "sessionUpdate": "tool_call_input_delta",
+                "toolCallId": "call_1",
+                "jsonFragment": "{\"path\": \"/sr"
+            })
+        );
+
+        let mut assembler = ToolCallInputAssembler::new();
+        for fragment in ["{\"path\": \"/sr", "c/main.rs\", \"li", "ne\": 3}"] {
+            assert!(assembler.parse().unwrap_err().is_eof());
+            assembler.push(&ToolCallInputDelta::new("call_1", fragment));
+        }
+        assert_eq!(assembler.as_str(), r#"{"path": "/src/main.rs", "line": 3}"#);
+        assert_eq!(
+            assembler.parse().unwrap(),
+            json!({"path": "/src/main.rs", "line": 3})
+        );
+
+        let mut malformed = ToolCallInputAssembler::new();
+        malformed.push(&ToolCallInputDelta::new("call_1", "{\"path\": }"));
+        let err = malformed.parse().unwrap_err();
+        assert!(!err.is_eof());
+        assert!(err.is_syntax());
+    }
+}
@@ -0,0 +1,283 @@
+//! Size-bounded buffering of a turn's streamed content.
+//!
+//! Clients that keep a turn's full text, for search, copy, or export, would
+//! otherwise grow without limit when an agent runs away. A
+//! [`BoundedTranscript`] caps the bytes it holds and applies an
+//! [`OverflowPolicy`] once the cap is reached.
+
+use std::collections::VecDeque;
+
+use super::{ContentBlock, ContentChunk, SessionUpdate};
+
+/// What a [`BoundedTranscript`] does with a chunk that would exceed its cap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum OverflowPolicy {
+    /// Evicts the oldest chunks until the new one fits.
+    #[default]
+    DropOldest,
+    /// Refuses the new chunk and keeps what is already stored.
+    Reject,
+    /// Keeps as much of the new chunk's text as fits and refuses the rest.
+    ///
+    /// Chunks that are not text are refused whole.
+    Truncate,
+}
+
+/// Stores the message and thought chunks of a turn, up to a byte cap.
+///
+/// Text counts its UTF-8 length; any other content counts the length of its
+/// JSON encoding. When a chunk would take the total past the cap, the
+/// [`OverflowPolicy`] decides what gives, and [`BoundedTranscript::is_truncated`]
+/// starts reporting `true`. The stored size never exceeds the cap.
+#[derive(Debug, Clone)]
+pub struct BoundedTranscript {
+    chunks: VecDeque<(SessionUpdate, usize)>,
+    len: usize,
+    max_bytes: usize,
+    policy: OverflowPolicy,
+    truncated: bool,
+}
+
+impl BoundedTranscript {
+    /// Builds an empty [`BoundedTranscript`] holding at most `max_bytes` of content.
+    #[must_use]
+    pub fn new(max_bytes: usize, policy: OverflowPolicy) -> Self {
+        Self {
+            chunks: VecDeque::new(),
+            len: 0,
+            max_bytes,
+            policy,
+            truncated: false,
+        }
+    }
+
+    /// Stores a `user_message_chunk`, `agent_message_chunk`, or
+    /// `agent_thought_chunk` update.
+    ///
+    /// Returns `false` if the update was not kept in full: the policy refused
+    /// or cut it, or it is not one of those chunk updates.
+    pub fn push(&mut self, mut update: SessionUpdate) -> bool {
+        let Some(chunk) = content_chunk(&update) else {
+            return false;
+        };
+        let size = content_len(&chunk.content);
+        if self.len + size <= self.max_bytes {
+            self.store(update, size);
+            return true;
+        }
+
+        self.truncated = true;
+        match self.policy {
+            OverflowPolicy::DropOldest if size <= self.max_bytes => {
+                while self.len + size > self.max_bytes {
+                    let Some((_, dropped)) = self.chunks.pop_front() else {
+                        break;
+                    };
+                    self.len -= dropped;
+                }
+                self.store(update, size);
+                true
+            }
+            OverflowPolicy::Truncate => {
+                let room = self.max_bytes - self.len;
+                if let Some(ContentChunk {
+                    content: ContentBlock::Text(text),
+                    ..
+                }) = content_chunk_mut(&mut update)
+                    && room > 0
+                {
+                    let mut end = room;
+                    while !text.text.is_char_boundary(end) {
+                        end -= 1;
+                    }
+                    if end > 0 {
+                        text.text.truncate(end);
+                        self.store(update, end);
+                    }
+                }
+                false
+            }
+            OverflowPolicy::DropOldest | OverflowPolicy::Reject => false,
+        }
+    }
+
+    /// The stored updates, oldest first.
+    pub fn updates(&self) -> impl Iterator<Item = &SessionUpdate> {
+        self.chunks.iter().map(|(update, _)| update)
+    }
+
+    /// The bytes of content currently stored.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether nothing is stored.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    /// The cap on stored bytes.
+    #[must_use]
+    pub fn max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+
+    /// Whether any content was dropped, refused, or cut to respect the cap.
+    #[must_use]
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Removes everything and resets [`Self::is_truncated`], ready for the next turn.
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+        self.len = 0;
+        self.truncated = false;
+    }
+
+    fn store(&mut self, update: SessionUpdate, size: usize) {
+        self.chunks.push_back((update, size));
+        self.len += size;
+    }
+}
+
+fn content_chunk(update: &SessionUpdate) -> Option<&ContentChunk> {
+    match update {
+        SessionUpdate::UserMessageChunk(chunk)
+        | SessionUpdate::AgentMessageChunk(chunk)
+        | SessionUpdate::AgentThoughtChunk(chunk) => Some(chunk),
+        _ => None,
+    }
+}
+
+fn content_chunk_mut(update: &mut SessionUpdate) -> Option<&mut ContentChunk> {
+    match update {
+        SessionUpdate::UserMessageChunk(chunk)
+        | SessionUpdate::AgentMessageChunk(chunk)
+        | SessionUpdate::AgentThoughtChunk(chunk) => Some(chunk),
+        _ => None,
+    }
+}
+
+fn content_len(content: &ContentBlock) -> usize {
+    match content {
+        ContentBlock::Text(text) => text.text.len(),
+        _ => serde_json::to_vec(content).map_or(0, |json| json.len()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1::{ImageContent, ToolCall};
+
+    fn message(text: &str) -> SessionUpdate {
+        SessionUpdate::AgentMessageChunk(ContentChunk::new(text.into()))
+    }
+
+    fn thought(text: &str) -> SessionUpdate {
+        SessionUpdate::AgentThoughtChunk(ContentChunk::new(text.into()))
+    }
+
+    fn texts(transcript: &BoundedTranscript) -> Vec<&str> {
+        transcript
+            .updates()
+            .filter_map(|update| match content_chunk(update)?.content {
+                ContentBlock::Text(ref text) => Some(text.text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_stores_chunks_within_cap() {
+        let mut transcript = BoundedTranscript::new(16, OverflowPolicy::Reject);
+        assert!(transcript.push(thought("Plan: ")));
+        assert!(transcript.push(message("done.")));
+        assert!(!transcript.push(SessionUpdate::ToolCall(ToolCall::new("call_1", "Read"))));
+
+        assert_eq!(texts(&transcript), ["Plan: ", "done."]);
+        assert_eq!(transcript.len(), 11);
+        assert!(!transcript.is_truncated());
+    }
+
+    #[test]
+    fn test_drop_oldest() {
+        let mut transcript = BoundedTranscript::new(10, OverflowPolicy::DropOldest);
+        assert!(transcript.push(message("abcd")));
+        assert!(transcript.push(message("efgh")));
+        assert!(transcript.push(message("ijkl")));
+
+        assert_eq!(texts(&transcript), ["efgh", "ijkl"]);
+        assert_eq!(transcript.len(), 8);
+        assert!(transcript.is_truncated());
+
+        assert!(!transcript.push(message("a chunk over the cap")));
+        assert_eq!(texts(&transcript), ["efgh", "ijkl"]);
+    }
+
+    #[test]
+    fn test_reject() {
+        let mut transcript = BoundedTranscript::new(10, OverflowPolicy::Reject);
+        assert!(transcript.push(message("abcd")));
+        assert!(transcript.push(message("efgh")));
+        assert!(!transcript.push(message("ijkl")));
+        assert!(transcript.push(message("mn")));
+
+        assert_eq!(texts(&transcript), ["abcd", "efgh", "mn"]);
+        assert_eq!(transcript.len(), 10);
+        assert!(transcript.is_truncated());
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut transcript = BoundedTranscript::new(10, OverflowPolicy::Truncate);
+        assert!(transcript.push(message("abcd")));
+        assert!(!transcript.push(message("efgh-ijkl")));
+        assert!(!transcript.push(message("mn")));
+
+        assert_eq!(texts(&transcript), ["abcd", "efgh-i"]);
+        assert_eq!(transcript.len(), 10);
+        assert!(transcript.is_truncated());
+
+        let mut transcript = BoundedTranscript::new(5, OverflowPolicy::Truncate);
+        assert!(transcript.push(message("añb")));
+        assert!(!transcript.push(message("ñx")));
+        assert!(
+            !transcript.push(SessionUpdate::AgentMessageChunk(ContentChunk::new(
+                ContentBlock::Image(ImageContent::new("aGk=", "image/png"))
+            )))
+        );
+        assert_eq!(texts(&transcript), ["añb"]);
+        assert_eq!(transcript.len(), 4);
+
+        let mut transcript = BoundedTranscript::new(3, OverflowPolicy::Truncate);
+        assert!(!transcript.push(message("ab€")));
+        assert_eq!(texts(&transcript), ["ab"]);
+    }
+
+    #[test]
+    fn test_cap_holds_across_many_chunks() {
+        for policy in [
+            OverflowPolicy::DropOldest,
+            OverflowPolicy::Reject,
+            OverflowPolicy::Truncate,
+        ] {
+            let mut transcript = BoundedTranscript::new(1000, policy);
+            for index in 0..10_000 {
+                transcript.push(message(&format!("chunk {index}; ")));
+                assert!(transcript.len() <= transcript.max_bytes(), "{policy:?}");
+            }
+            let stored: usize = texts(&transcript).iter().map(|text| text.len()).sum();
+            assert_eq!(stored, transcript.len(), "{policy:?}");
+            assert!(transcript.is_truncated(), "{policy:?}");
+
+            transcript.clear();
+            assert!(transcript.is_empty());
+            assert!(!transcript.is_truncated());
+        }
+    }
+}
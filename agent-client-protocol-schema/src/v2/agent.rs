@@ -20,6 +20,21 @@ use super::{
 use crate::DefaultTrueOnError;
 use crate::{IntoOption, ProtocolVersion, SkipListener};
 
+#[cfg(feature = "unstable_cancel_reason")]
+use super::CancelReason;
+
+#[cfg(feature = "unstable_edit_conflict")]
+use super::ConflictReport;
+
+#[cfg(any(
+    feature = "unstable_edit_conflict",
+    feature = "unstable_cancel_tool_call"
+))]
+use super::ToolCallId;
+
+#[cfg(feature = "unstable_cancel_tool_call")]
+use super::ToolCallStatus;
+
 #[cfg(feature = "unstable_mcp_over_acp")]
 use super::mcp::{
     MCP_MESSAGE_METHOD_NAME, MessageMcpNotification, MessageMcpRequest, MessageMcpResponse,
@@ -64,6 +79,20 @@ pub struct InitializeRequest {
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
     pub capabilities: ClientCapabilities,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The user's preferred locale as a BCP 47 language tag, such as `en-US` or
+    /// `pt-BR`.
+    ///
+    /// Advisory only: agents MAY use it to localize notices, errors and
+    /// suggestions, and fall back to English for locales they don't support.
+    #[cfg(feature = "unstable_locale")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub locale: Option<String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -84,6 +113,8 @@ impl InitializeRequest {
             protocol_version,
             capabilities: ClientCapabilities::default(),
             info,
+            #[cfg(feature = "unstable_locale")]
+            locale: None,
             meta: None,
         }
     }
@@ -95,6 +126,14 @@ impl InitializeRequest {
         self
     }
 
+    /// The user's preferred locale as a BCP 47 language tag, such as `en-US`.
+    #[cfg(feature = "unstable_locale")]
+    #[must_use]
+    pub fn locale(mut self, locale: impl IntoOption<String>) -> Self {
+        self.locale = locale.into_option();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -136,6 +175,20 @@ pub struct InitializeResponse {
     #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub auth_methods: Vec<AuthMethod>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent can accept prompts yet.
+    ///
+    /// Omitted or `true` means the agent is ready. `false` means it is still
+    /// warming up, for example loading a model or indexing the workspace, and
+    /// will send an `agent/ready` notification once it can take prompts.
+    #[cfg(feature = "unstable_agent_ready")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub ready: Option<bool>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -157,6 +210,8 @@ impl InitializeResponse {
             capabilities: AgentCapabilities::default(),
             auth_methods: vec![],
             info,
+            #[cfg(feature = "unstable_agent_ready")]
+            ready: None,
             meta: None,
         }
     }
@@ -175,6 +230,33 @@ impl InitializeResponse {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent can accept prompts yet.
+    ///
+    /// Set `false` while warming up and send `agent/ready` once done.
+    #[cfg(feature = "unstable_agent_ready")]
+    #[must_use]
+    pub fn ready(mut self, ready: impl IntoOption<bool>) -> Self {
+        self.ready = ready.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent reported itself ready to take prompts.
+    ///
+    /// Only an explicit `ready: false` counts as not ready.
+    #[cfg(feature = "unstable_agent_ready")]
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.ready != Some(false)
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -928,9 +1010,12 @@ fn is_true(v: &bool) -> bool {
     *v
 }
 
-#[cfg(feature = "unstable_auth_methods")]
+#[cfg(any(
+    feature = "unstable_auth_methods",
+    feature = "unstable_user_message_echo"
+))]
 #[expect(clippy::trivially_copy_pass_by_ref)]
-fn is_false(v: &bool) -> bool {
+pub(crate) fn is_false(v: &bool) -> bool {
     !*v
 }
 
@@ -1103,6 +1188,17 @@ pub struct NewSessionRequest {
     #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub mcp_servers: Vec<McpServer>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The client's environment, shared up front so the agent does not have to
+    /// probe for it.
+    #[cfg(feature = "unstable_session_context")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub context: Option<SessionContext>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1123,6 +1219,8 @@ impl NewSessionRequest {
             cwd: cwd.into(),
             additional_directories: vec![],
             mcp_servers: vec![],
+            #[cfg(feature = "unstable_session_context")]
+            context: None,
             meta: None,
         }
     }
@@ -1141,6 +1239,127 @@ impl NewSessionRequest {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The client's environment, shared up front so the agent does not have to
+    /// probe for it.
+    #[cfg(feature = "unstable_session_context")]
+    #[must_use]
+    pub fn context(mut self, context: impl IntoOption<SessionContext>) -> Self {
+        self.context = context.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// The client's environment at session start.
+///
+/// The working directory is not repeated here; it is the `cwd` of the request
+/// that carries this context.
+#[cfg(feature = "unstable_session_context")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SessionContext {
+    /// The operating system the client runs on, such as `linux`, `macos` or
+    /// `windows`.
+    pub os: String,
+    /// The user's default shell, such as `/bin/zsh` or `pwsh`.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub shell: Option<String>,
+    /// Names of environment variables the agent may read and pass on to the
+    /// tools it runs.
+    ///
+    /// The client only lists variables the user is willing to share. Agents
+    /// SHOULD NOT read or forward environment variables missing from this list.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env_allowlist: Vec<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The user's preferred locale for this session as a BCP 47 language tag,
+    /// such as `en-US`. Overrides the `locale` sent in `initialize`.
+    ///
+    /// Advisory only: agents fall back to English for locales they don't
+    /// support.
+    #[cfg(feature = "unstable_locale")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_session_context")]
+impl SessionContext {
+    /// Builds [`SessionContext`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(os: impl Into<String>) -> Self {
+        Self {
+            os: os.into(),
+            shell: None,
+            env_allowlist: vec![],
+            #[cfg(feature = "unstable_locale")]
+            locale: None,
+            meta: None,
+        }
+    }
+
+    /// The user's default shell, such as `/bin/zsh` or `pwsh`.
+    #[must_use]
+    pub fn shell(mut self, shell: impl IntoOption<String>) -> Self {
+        self.shell = shell.into_option();
+        self
+    }
+
+    /// Names of environment variables the agent may read and pass on to the
+    /// tools it runs.
+    #[must_use]
+    pub fn env_allowlist(mut self, env_allowlist: Vec<String>) -> Self {
+        self.env_allowlist = env_allowlist;
+        self
+    }
+
+    /// The user's preferred locale for this session as a BCP 47 language tag.
+    #[cfg(feature = "unstable_locale")]
+    #[must_use]
+    pub fn locale(mut self, locale: impl IntoOption<String>) -> Self {
+        self.locale = locale.into_option();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1247,6 +1466,33 @@ pub struct LoadSessionRequest {
     #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub mcp_servers: Vec<McpServer>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Opaque cursor token from a previous response's nextCursor field to continue replaying
+    /// the session history where the previous page stopped.
+    ///
+    /// Clients MUST only send a cursor returned by the Agent for this session. If the cursor
+    /// is invalid, expired or was issued for another session, the Agent MUST respond with an
+    /// `invalid_params` error.
+    ///
+    /// Only honored when the Agent advertises the `loadPagination` session capability.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[serde(default)]
+    pub cursor: Option<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Maximum number of history updates the Agent should replay before responding.
+    ///
+    /// Agents MAY replay fewer updates. When omitted, the Agent chooses the page size.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub limit: Option<u32>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1268,6 +1514,10 @@ impl LoadSessionRequest {
             cwd: cwd.into(),
             additional_directories: vec![],
             session_id: session_id.into(),
+            #[cfg(feature = "unstable_session_load_pagination")]
+            cursor: None,
+            #[cfg(feature = "unstable_session_load_pagination")]
+            limit: None,
             meta: None,
         }
     }
@@ -1286,6 +1536,82 @@ impl LoadSessionRequest {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Opaque cursor token from a previous response's nextCursor field to continue replaying
+    /// the session history where the previous page stopped.
+    ///
+    /// Clients MUST only send a cursor returned by the Agent for this session. If the cursor
+    /// is invalid, expired or was issued for another session, the Agent MUST respond with an
+    /// `invalid_params` error.
+    ///
+    /// Only honored when the Agent advertises the `loadPagination` session capability.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[must_use]
+    pub fn cursor(mut self, cursor: impl IntoOption<String>) -> Self {
+        self.cursor = cursor.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Maximum number of history updates the Agent should replay before responding.
+    ///
+    /// Agents MAY replay fewer updates. When omitted, the Agent chooses the page size.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[must_use]
+    pub fn limit(mut self, limit: impl IntoOption<u32>) -> Self {
+        self.limit = limit.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Resolves the page of a `history_len`-update history that this request
+    /// should replay, along with the `nextCursor` to return.
+    ///
+    /// Cursors produced here encode the session ID and the offset of the next
+    /// update, so consecutive pages never overlap or skip updates. Without a
+    /// `limit` the rest of the history is replayed; a `limit` of `0` still
+    /// replays one update so the replay always makes progress.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::invalid_params`](super::Error::invalid_params) when the
+    /// cursor is malformed, was issued for another session, or points past the
+    /// end of the history.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    pub fn replay_page(
+        &self,
+        history_len: usize,
+    ) -> Result<(std::ops::Range<usize>, Option<String>), super::Error> {
+        let start = match &self.cursor {
+            None => 0,
+            Some(cursor) => cursor
+                .rsplit_once(':')
+                .filter(|(session_id, _)| *session_id == &*self.session_id.0)
+                .and_then(|(_, offset)| offset.parse::<usize>().ok())
+                .filter(|offset| *offset <= history_len)
+                .ok_or_else(|| {
+                    super::Error::invalid_params().data(serde_json::json!({ "cursor": cursor }))
+                })?,
+        };
+        let end = match self.limit {
+            Some(limit) => start
+                .saturating_add(usize::try_from(limit.max(1)).unwrap_or(usize::MAX))
+                .min(history_len),
+            None => history_len,
+        };
+        let next_cursor = (end < history_len).then(|| format!("{}:{end}", self.session_id.0));
+        Ok((start..end, next_cursor))
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1311,6 +1637,20 @@ pub struct LoadSessionResponse {
     #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub config_options: Vec<SessionConfigOption>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Opaque cursor token. If present, more history remains; pass this in the next
+    /// request's cursor parameter to replay the next page. If absent, the replay is complete.
+    ///
+    /// Updates are replayed in their original order, and each page resumes exactly after
+    /// the last update of the previous page.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub next_cursor: Option<String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1337,6 +1677,22 @@ impl LoadSessionResponse {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Opaque cursor token. If present, more history remains; pass this in the next
+    /// request's cursor parameter to replay the next page. If absent, the replay is complete.
+    ///
+    /// Updates are replayed in their original order, and each page resumes exactly after
+    /// the last update of the previous page.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[must_use]
+    pub fn next_cursor(mut self, next_cursor: impl IntoOption<String>) -> Self {
+        self.next_cursor = next_cursor.into_option();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1729,28 +2085,37 @@ impl CloseSessionResponse {
     }
 }
 
-// List sessions
+// Compact session
 
-/// Request parameters for listing existing sessions.
+/// **UNSTABLE**
 ///
-/// Only available if the Agent supports the `session.list` capability.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request parameters for compacting a session's history.
+///
+/// The agent compacts its own context for the session, typically by
+/// summarizing or dropping earlier messages, so that long sessions stay within
+/// the model's context window. Subsequent `session/prompt` requests use the
+/// compacted context.
+///
+/// Only available if the agent advertises the `session.compact` capability.
+#[cfg(feature = "unstable_session_compact")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_LIST_METHOD_NAME))]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_COMPACT_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct ListSessionsRequest {
-    /// Filter sessions by working directory. Must be an absolute path.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub cwd: Option<PathBuf>,
-    /// Opaque cursor token from a previous response's nextCursor field for cursor-based pagination
+pub struct CompactSessionRequest {
+    /// The ID of the session to compact.
+    pub session_id: SessionId,
+    /// The number of tokens the compacted context should fit in.
+    ///
+    /// A hint only. When omitted, the agent picks its own target.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub cursor: Option<String>,
+    pub target_tokens: Option<u64>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1763,24 +2128,22 @@ pub struct ListSessionsRequest {
     pub meta: Option<Meta>,
 }
 
-impl ListSessionsRequest {
-    /// Builds [`ListSessionsRequest`] with the required request fields set; optional fields start unset or empty.
-    #[must_use]
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    /// Filter sessions by working directory. Must be an absolute path.
+#[cfg(feature = "unstable_session_compact")]
+impl CompactSessionRequest {
+    /// Builds [`CompactSessionRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn cwd(mut self, cwd: impl IntoOption<PathBuf>) -> Self {
-        self.cwd = cwd.into_option();
-        self
+    pub fn new(session_id: impl Into<SessionId>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            target_tokens: None,
+            meta: None,
+        }
     }
 
-    /// Opaque cursor token from a previous response's nextCursor field for cursor-based pagination
+    /// The number of tokens the compacted context should fit in.
     #[must_use]
-    pub fn cursor(mut self, cursor: impl IntoOption<String>) -> Self {
-        self.cursor = cursor.into_option();
+    pub fn target_tokens(mut self, target_tokens: impl IntoOption<u64>) -> Self {
+        self.target_tokens = target_tokens.into_option();
         self
     }
 
@@ -1796,24 +2159,26 @@ impl ListSessionsRequest {
     }
 }
 
-/// Response from listing sessions.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response from compacting a session.
+#[cfg(feature = "unstable_session_compact")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_LIST_METHOD_NAME))]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_COMPACT_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct ListSessionsResponse {
-    /// Array of session information objects.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub sessions: Vec<SessionInfo>,
-    /// Opaque cursor token. If present, pass this in the next request's cursor parameter
-    /// to fetch the next page. If absent, there are no more results.
+pub struct CompactSessionResponse {
+    /// How many messages were removed from, or folded out of, the agent's context.
+    pub removed_messages: u32,
+    /// A summary of the compacted history, for the client to display.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub next_cursor: Option<String>,
+    pub summary: Option<String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1826,21 +2191,22 @@ pub struct ListSessionsResponse {
     pub meta: Option<Meta>,
 }
 
-impl ListSessionsResponse {
-    /// Builds [`ListSessionsResponse`] with the required response fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_session_compact")]
+impl CompactSessionResponse {
+    /// Builds [`CompactSessionResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(sessions: Vec<SessionInfo>) -> Self {
+    pub fn new(removed_messages: u32) -> Self {
         Self {
-            sessions,
-            next_cursor: None,
+            removed_messages,
+            summary: None,
             meta: None,
         }
     }
 
-    /// Sets or clears the optional `nextCursor` field.
+    /// A summary of the compacted history, for the client to display.
     #[must_use]
-    pub fn next_cursor(mut self, next_cursor: impl IntoOption<String>) -> Self {
-        self.next_cursor = next_cursor.into_option();
+    pub fn summary(mut self, summary: impl IntoOption<String>) -> Self {
+        self.summary = summary.into_option();
         self
     }
 
@@ -1856,20 +2222,31 @@ impl ListSessionsResponse {
     }
 }
 
-// Delete session
+// Set session title
 
-/// Request parameters for deleting an existing session from `session/list`.
+/// **UNSTABLE**
 ///
-/// Only available if the Agent supports the `session.delete` capability.
-#[serde_as]
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request parameters for renaming a session.
+///
+/// The agent persists the new title, so it is reflected in `session/list`
+/// and survives loading or resuming the session. The agent MAY also send a
+/// `session_info_update` with the new title to keep every client in sync.
+///
+/// Only available if the agent advertises the `session.setTitle` capability.
+#[cfg(feature = "unstable_session_set_title")]
+#[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_DELETE_METHOD_NAME))]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_TITLE_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct DeleteSessionRequest {
-    /// The ID of the session to delete.
+pub struct SetSessionTitleRequest {
+    /// The ID of the session to rename.
     pub session_id: SessionId,
+    /// The new human-readable title for the session.
+    pub title: String,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1882,12 +2259,14 @@ pub struct DeleteSessionRequest {
     pub meta: Option<Meta>,
 }
 
-impl DeleteSessionRequest {
-    /// Builds [`DeleteSessionRequest`] with the required request fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_session_set_title")]
+impl SetSessionTitleRequest {
+    /// Builds [`SetSessionTitleRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(session_id: impl Into<SessionId>) -> Self {
+    pub fn new(session_id: impl Into<SessionId>, title: impl Into<String>) -> Self {
         Self {
             session_id: session_id.into(),
+            title: title.into(),
             meta: None,
         }
     }
@@ -1904,14 +2283,19 @@ impl DeleteSessionRequest {
     }
 }
 
-/// Response from deleting a session.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response from renaming a session.
+#[cfg(feature = "unstable_session_set_title")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_DELETE_METHOD_NAME))]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_TITLE_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct DeleteSessionResponse {
+pub struct SetSessionTitleResponse {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1924,8 +2308,9 @@ pub struct DeleteSessionResponse {
     pub meta: Option<Meta>,
 }
 
-impl DeleteSessionResponse {
-    /// Builds [`DeleteSessionResponse`] with the required response fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_session_set_title")]
+impl SetSessionTitleResponse {
+    /// Builds [`SetSessionTitleResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -1943,37 +2328,35 @@ impl DeleteSessionResponse {
     }
 }
 
-/// Information about a session returned by session/list
+// Cancel tool call
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request parameters for cancelling a single tool call.
+///
+/// Unlike `session/cancel`, which stops the whole prompt turn, this stops only
+/// the given tool call. The agent aborts it, reports it with a `cancelled`
+/// status, and carries on with the rest of the turn.
+///
+/// If the tool call already finished, the agent leaves its status unchanged and
+/// still responds with success. If the session has no tool call with this ID,
+/// the agent responds with an `invalid_params` error.
+///
+/// Only available if the agent advertises the `session.cancelToolCall` capability.
+#[cfg(feature = "unstable_cancel_tool_call")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_CANCEL_TOOL_CALL_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionInfo {
-    /// Unique identifier for the session
+pub struct CancelToolCallRequest {
+    /// The session the tool call belongs to.
     pub session_id: SessionId,
-    /// The working directory for this session. Must be an absolute path.
-    pub cwd: PathBuf,
-    /// Additional workspace roots reported for this session. Each path must be absolute.
-    ///
-    /// When present, this is the complete ordered additional-root list reported
-    /// by the Agent. Omitted and empty values are equivalent: the response
-    /// reports no additional roots.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub additional_directories: Vec<PathBuf>,
-
-    /// Human-readable title for the session
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub title: Option<String>,
-    /// ISO 8601 timestamp of last activity
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub updated_at: Option<String>,
+    /// The tool call to cancel.
+    pub tool_call_id: ToolCallId,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1986,39 +2369,34 @@ pub struct SessionInfo {
     pub meta: Option<Meta>,
 }
 
-impl SessionInfo {
-    /// Builds [`SessionInfo`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_cancel_tool_call")]
+impl CancelToolCallRequest {
+    /// Builds [`CancelToolCallRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(session_id: impl Into<SessionId>, cwd: impl Into<PathBuf>) -> Self {
+    pub fn new(session_id: impl Into<SessionId>, tool_call_id: impl Into<ToolCallId>) -> Self {
         Self {
             session_id: session_id.into(),
-            cwd: cwd.into(),
-            additional_directories: vec![],
-            title: None,
-            updated_at: None,
+            tool_call_id: tool_call_id.into(),
             meta: None,
         }
     }
 
-    /// Additional workspace roots reported for this session. Each path must be absolute.
-    #[must_use]
-    pub fn additional_directories(mut self, additional_directories: Vec<PathBuf>) -> Self {
-        self.additional_directories = additional_directories;
-        self
-    }
-
-    /// Human-readable title for the session
-    #[must_use]
-    pub fn title(mut self, title: impl IntoOption<String>) -> Self {
-        self.title = title.into_option();
-        self
-    }
-
-    /// ISO 8601 timestamp of last activity
-    #[must_use]
-    pub fn updated_at(mut self, updated_at: impl IntoOption<String>) -> Self {
-        self.updated_at = updated_at.into_option();
-        self
+    /// Decides how to answer this request given the current status of the
+    /// targeted tool call, or `None` if the session has no tool call with its ID.
+    ///
+    /// Returns `Ok(true)` when the agent must abort the tool call, and `Ok(false)`
+    /// when the tool call already finished and the request is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `invalid_params` error naming the `toolCallId` when the tool
+    /// call is unknown.
+    pub fn check(&self, status: Option<&ToolCallStatus>) -> Result<bool, super::Error> {
+        let Some(status) = status else {
+            return Err(super::Error::invalid_params()
+                .data(serde_json::json!({ "toolCallId": self.tool_call_id })));
+        };
+        Ok(!status.is_terminal())
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -2033,69 +2411,83 @@ impl SessionInfo {
     }
 }
 
-// Session config options
-
-/// Unique identifier for a session configuration option.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, From, Display)]
-#[serde(transparent)]
-#[from(Arc<str>, String, &'static str)]
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response from cancelling a tool call.
+#[cfg(feature = "unstable_cancel_tool_call")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_CANCEL_TOOL_CALL_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionConfigId(pub Arc<str>);
-
-impl SessionConfigId {
-    /// Wraps a protocol string as a typed [`SessionConfigId`].
-    #[must_use]
-    pub fn new(id: impl Into<Arc<str>>) -> Self {
-        Self(id.into())
-    }
+pub struct CancelToolCallResponse {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-/// Unique identifier for a session configuration option value.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, From, Display)]
-#[serde(transparent)]
-#[from(Arc<str>, String, &'static str)]
-#[non_exhaustive]
-pub struct SessionConfigValueId(pub Arc<str>);
-
-impl SessionConfigValueId {
-    /// Wraps a protocol string as a typed [`SessionConfigValueId`].
+#[cfg(feature = "unstable_cancel_tool_call")]
+impl CancelToolCallResponse {
+    /// Builds [`CancelToolCallResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(id: impl Into<Arc<str>>) -> Self {
-        Self(id.into())
+    pub fn new() -> Self {
+        Self::default()
     }
-}
-
-/// Unique identifier for a session configuration option value group.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, From, Display)]
-#[serde(transparent)]
-#[from(Arc<str>, String, &'static str)]
-#[non_exhaustive]
-pub struct SessionConfigGroupId(pub Arc<str>);
 
-impl SessionConfigGroupId {
-    /// Wraps a protocol string as a typed [`SessionConfigGroupId`].
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn new(id: impl Into<Arc<str>>) -> Self {
-        Self(id.into())
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
     }
 }
 
-/// A possible value for a session configuration option.
+// Invoke command
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request parameters for invoking one of the agent's available commands.
+///
+/// Lets a client run a command advertised through an `available_commands_update`
+/// session notification directly, instead of sending it as prompt text.
+///
+/// Agents respond with a `command_not_found` error when the command name is not
+/// one they currently advertise for the session.
+#[cfg(feature = "unstable_command_invocation")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = COMMAND_INVOKE_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionConfigSelectOption {
-    /// Unique identifier for this option value.
-    pub value: SessionConfigValueId,
-    /// Human-readable label for this option value.
-    pub name: String,
-    /// Optional description for this option value.
+pub struct InvokeCommandRequest {
+    /// The ID of the session to run the command in.
+    pub session_id: SessionId,
+    /// The name of the command to invoke, as advertised in `AvailableCommand.name`.
+    pub command: String,
+    /// Arguments for the command, if any.
+    ///
+    /// The shape of this value is defined by the command itself.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub description: Option<String>,
+    pub arguments: Option<serde_json::Value>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2108,22 +2500,23 @@ pub struct SessionConfigSelectOption {
     pub meta: Option<Meta>,
 }
 
-impl SessionConfigSelectOption {
-    /// Builds [`SessionConfigSelectOption`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_command_invocation")]
+impl InvokeCommandRequest {
+    /// Builds [`InvokeCommandRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(value: impl Into<SessionConfigValueId>, name: impl Into<String>) -> Self {
+    pub fn new(session_id: impl Into<SessionId>, command: impl Into<String>) -> Self {
         Self {
-            value: value.into(),
-            name: name.into(),
-            description: None,
+            session_id: session_id.into(),
+            command: command.into(),
+            arguments: None,
             meta: None,
         }
     }
 
-    /// Sets or clears the optional `description` field.
+    /// Arguments for the command, if any.
     #[must_use]
-    pub fn description(mut self, description: impl IntoOption<String>) -> Self {
-        self.description = description.into_option();
+    pub fn arguments(mut self, arguments: impl IntoOption<serde_json::Value>) -> Self {
+        self.arguments = arguments.into_option();
         self
     }
 
@@ -2139,21 +2532,22 @@ impl SessionConfigSelectOption {
     }
 }
 
-/// A group of possible values for a session configuration option.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response from invoking a command.
+///
+/// Any output the command produces is streamed through `session/update`
+/// notifications before this response is sent.
+#[cfg(feature = "unstable_command_invocation")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = COMMAND_INVOKE_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionConfigSelectGroup {
-    /// Unique identifier for this group.
-    pub group_id: SessionConfigGroupId,
-    /// Human-readable label for this group.
-    pub name: String,
-    /// The set of option values in this group.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub options: Vec<SessionConfigSelectOption>,
+pub struct InvokeCommandResponse {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2166,20 +2560,12 @@ pub struct SessionConfigSelectGroup {
     pub meta: Option<Meta>,
 }
 
-impl SessionConfigSelectGroup {
-    /// Builds [`SessionConfigSelectGroup`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_command_invocation")]
+impl InvokeCommandResponse {
+    /// Builds [`InvokeCommandResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(
-        group_id: impl Into<SessionConfigGroupId>,
-        name: impl Into<String>,
-        options: Vec<SessionConfigSelectOption>,
-    ) -> Self {
-        Self {
-            group_id: group_id.into(),
-            name: name.into(),
-            options,
-            meta: None,
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -2194,237 +2580,200 @@ impl SessionConfigSelectGroup {
     }
 }
 
-/// Possible values for a session configuration option.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(untagged)]
-#[non_exhaustive]
-pub enum SessionConfigSelectOptions {
-    /// A flat list of options with no grouping.
-    Ungrouped(Vec<SessionConfigSelectOption>),
-    /// A list of options grouped under headers.
-    Grouped(Vec<SessionConfigSelectGroup>),
-}
-
-impl From<Vec<SessionConfigSelectOption>> for SessionConfigSelectOptions {
-    fn from(options: Vec<SessionConfigSelectOption>) -> Self {
-        SessionConfigSelectOptions::Ungrouped(options)
-    }
-}
-
-impl From<Vec<SessionConfigSelectGroup>> for SessionConfigSelectOptions {
-    fn from(groups: Vec<SessionConfigSelectGroup>) -> Self {
-        SessionConfigSelectOptions::Grouped(groups)
-    }
-}
-
-/// A single-value selector (dropdown) session configuration option payload.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request parameters for answering an `input_request` session update.
+///
+/// The agent resumes the prompt turn that asked the question once it receives
+/// the answer. Agents respond with an `invalid_params` error when `input_id`
+/// does not match a question that is still waiting for an answer.
+#[cfg(feature = "unstable_input_request")]
+#[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_PROVIDE_INPUT_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionConfigSelect {
-    /// The currently selected value.
-    pub current_value: SessionConfigValueId,
-    /// The set of selectable options.
-    pub options: SessionConfigSelectOptions,
+pub struct ProvideInputRequest {
+    /// The ID of the session the question was asked in.
+    pub session_id: SessionId,
+    /// The `inputId` of the `input_request` being answered.
+    pub input_id: String,
+    /// The user's answer.
+    pub text: String,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-impl SessionConfigSelect {
-    /// Builds [`SessionConfigSelect`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_input_request")]
+impl ProvideInputRequest {
+    /// Builds [`ProvideInputRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
     pub fn new(
-        current_value: impl Into<SessionConfigValueId>,
-        options: impl Into<SessionConfigSelectOptions>,
+        session_id: impl Into<SessionId>,
+        input_id: impl Into<String>,
+        text: impl Into<String>,
     ) -> Self {
         Self {
-            current_value: current_value.into(),
-            options: options.into(),
+            session_id: session_id.into(),
+            input_id: input_id.into(),
+            text: text.into(),
+            meta: None,
         }
     }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
 }
 
 /// **UNSTABLE**
 ///
 /// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// A boolean on/off toggle session configuration option payload.
-#[cfg(feature = "unstable_boolean_config")]
+/// Response to a `session/provide_input` request.
+#[cfg(feature = "unstable_input_request")]
+#[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_PROVIDE_INPUT_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionConfigBoolean {
-    /// The current value of the boolean option.
-    pub current_value: bool,
+pub struct ProvideInputResponse {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_boolean_config")]
-impl SessionConfigBoolean {
-    /// Builds [`SessionConfigBoolean`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_input_request")]
+impl ProvideInputResponse {
+    /// Builds [`ProvideInputResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(current_value: bool) -> Self {
-        Self { current_value }
+    pub fn new() -> Self {
+        Self::default()
     }
-}
 
-/// Semantic category for a session configuration option.
-///
-/// This is intended to help Clients distinguish broadly common selectors (e.g. model selector vs
-/// session mode selector vs thought/reasoning level) for UX purposes (keyboard shortcuts, icons,
-/// placement). It MUST NOT be required for correctness. Clients MUST handle missing or unknown
-/// categories gracefully.
-///
-/// Category names beginning with `_` are free for custom use, like other ACP extension methods.
-/// Category names that do not begin with `_` are reserved for the ACP spec.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "snake_case")]
-#[non_exhaustive]
-pub enum SessionConfigOptionCategory {
-    /// Session mode selector.
-    Mode,
-    /// Model selector.
-    Model,
-    /// Model-related configuration parameter.
-    ModelConfig,
-    /// Thought/reasoning level selector.
-    ThoughtLevel,
-    /// Custom or future category.
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// Values beginning with `_` are reserved for implementation-specific
-    /// extensions. Unknown values that do not begin with `_` are reserved for
-    /// future ACP variants.
-    #[serde(untagged)]
-    Other(String),
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
 }
 
-/// Type-specific session configuration option payload.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(tag = "type", rename_all = "snake_case")]
-#[schemars(extend("discriminator" = {"propertyName": "type"}))]
-#[non_exhaustive]
-pub enum SessionConfigKind {
-    /// Single-value selector (dropdown).
-    Select(SessionConfigSelect),
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
-    ///
-    /// Boolean on/off toggle.
-    #[cfg(feature = "unstable_boolean_config")]
-    Boolean(SessionConfigBoolean),
-    /// Custom or future session configuration option payload.
-    ///
-    /// Values beginning with `_` are reserved for implementation-specific
-    /// extensions. Unknown values that do not begin with `_` are reserved for
-    /// future ACP variants.
-    ///
-    /// Clients that do not understand this option type should preserve the raw
-    /// payload when storing, replaying, proxying, or forwarding configuration
-    /// data, and otherwise ignore the option or display it generically.
-    #[serde(untagged)]
-    Other(OtherSessionConfigKind),
-}
+// List sessions
 
-/// Custom or future session configuration option payload.
-#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Eq)]
-#[schemars(inline)]
-#[schemars(transform = other_session_config_kind_schema)]
+/// Request parameters for listing existing sessions.
+///
+/// Only available if the Agent supports the `session.list` capability.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_LIST_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct OtherSessionConfigKind {
-    /// Custom or future session configuration option type.
+pub struct ListSessionsRequest {
+    /// Filter sessions by working directory. Must be an absolute path.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub cwd: Option<PathBuf>,
+    /// Opaque cursor token from a previous response's nextCursor field for cursor-based pagination
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub cursor: Option<String>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// Values beginning with `_` are reserved for implementation-specific
-    /// extensions. Unknown values that do not begin with `_` are reserved for
-    /// future ACP variants.
-    #[serde(rename = "type")]
-    pub type_: String,
-    /// Additional fields from the unknown session configuration option payload.
-    #[serde(flatten)]
-    pub fields: BTreeMap<String, serde_json::Value>,
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-impl OtherSessionConfigKind {
-    /// Builds [`OtherSessionConfigKind`] from an unknown discriminator and preserves the remaining extension fields.
+impl ListSessionsRequest {
+    /// Builds [`ListSessionsRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(type_: impl Into<String>, mut fields: BTreeMap<String, serde_json::Value>) -> Self {
-        fields.remove("type");
-        fields.remove("_meta");
-        Self {
-            type_: type_.into(),
-            fields,
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
-}
-
-impl<'de> Deserialize<'de> for OtherSessionConfigKind {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        let mut fields = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
-        let type_ = fields
-            .remove("type")
-            .ok_or_else(|| serde::de::Error::missing_field("type"))?;
-        let serde_json::Value::String(type_) = type_ else {
-            return Err(serde::de::Error::custom("`type` must be a string"));
-        };
 
-        if is_known_session_config_kind_type(&type_) {
-            return Err(serde::de::Error::custom(format!(
-                "known session configuration option `{type_}` did not match its schema"
-            )));
-        }
-
-        Ok(Self { type_, fields })
+    /// Filter sessions by working directory. Must be an absolute path.
+    #[must_use]
+    pub fn cwd(mut self, cwd: impl IntoOption<PathBuf>) -> Self {
+        self.cwd = cwd.into_option();
+        self
     }
-}
 
-fn is_known_session_config_kind_type(type_: &str) -> bool {
-    match type_ {
-        "select" => true,
-        #[cfg(feature = "unstable_boolean_config")]
-        "boolean" => true,
-        _ => false,
+    /// Opaque cursor token from a previous response's nextCursor field for cursor-based pagination
+    #[must_use]
+    pub fn cursor(mut self, cursor: impl IntoOption<String>) -> Self {
+        self.cursor = cursor.into_option();
+        self
     }
-}
 
-fn other_session_config_kind_schema(schema: &mut Schema) {
-    super::schema_util::reject_known_string_discriminators(
-        schema,
-        "type",
-        &[
-            "select",
-            #[cfg(feature = "unstable_boolean_config")]
-            "boolean",
-        ],
-    );
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
 }
 
-/// A session configuration option selector and its current state.
+/// Response from listing sessions.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_LIST_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionConfigOption {
-    /// Unique identifier for the configuration option.
-    pub config_id: SessionConfigId,
-    /// Human-readable label for the option.
-    pub name: String,
-    /// Optional description for the Client to display to the user.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub description: Option<String>,
-    /// Optional semantic category for this option (UX only).
+pub struct ListSessionsResponse {
+    /// Array of session information objects.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub sessions: Vec<SessionInfo>,
+    /// Opaque cursor token. If present, pass this in the next request's cursor parameter
+    /// to fetch the next page. If absent, there are no more results.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub category: Option<SessionConfigOptionCategory>,
-    /// Type-specific fields for this configuration option.
-    #[serde(flatten)]
-    pub kind: SessionConfigKind,
+    pub next_cursor: Option<String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2437,67 +2786,1676 @@ pub struct SessionConfigOption {
     pub meta: Option<Meta>,
 }
 
-impl SessionConfigOption {
-    /// Builds [`SessionConfigOption`] with the required fields set; optional fields start unset or empty.
+impl ListSessionsResponse {
+    /// Builds [`ListSessionsResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(
-        config_id: impl Into<SessionConfigId>,
-        name: impl Into<String>,
-        kind: SessionConfigKind,
-    ) -> Self {
+    pub fn new(sessions: Vec<SessionInfo>) -> Self {
+        Self {
+            sessions,
+            next_cursor: None,
+            meta: None,
+        }
+    }
+
+    /// Sets or clears the optional `nextCursor` field.
+    #[must_use]
+    pub fn next_cursor(mut self, next_cursor: impl IntoOption<String>) -> Self {
+        self.next_cursor = next_cursor.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+// Delete session
+
+/// Request parameters for deleting an existing session from `session/list`.
+///
+/// Only available if the Agent supports the `session.delete` capability.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_DELETE_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DeleteSessionRequest {
+    /// The ID of the session to delete.
+    pub session_id: SessionId,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl DeleteSessionRequest {
+    /// Builds [`DeleteSessionRequest`] with the required request fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(session_id: impl Into<SessionId>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Response from deleting a session.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_DELETE_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DeleteSessionResponse {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl DeleteSessionResponse {
+    /// Builds [`DeleteSessionResponse`] with the required response fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Information about a session returned by session/list
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SessionInfo {
+    /// Unique identifier for the session
+    pub session_id: SessionId,
+    /// The working directory for this session. Must be an absolute path.
+    pub cwd: PathBuf,
+    /// Additional workspace roots reported for this session. Each path must be absolute.
+    ///
+    /// When present, this is the complete ordered additional-root list reported
+    /// by the Agent. Omitted and empty values are equivalent: the response
+    /// reports no additional roots.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_directories: Vec<PathBuf>,
+
+    /// Human-readable title for the session
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub title: Option<String>,
+    /// ISO 8601 timestamp of last activity
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SessionInfo {
+    /// Builds [`SessionInfo`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(session_id: impl Into<SessionId>, cwd: impl Into<PathBuf>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            cwd: cwd.into(),
+            additional_directories: vec![],
+            title: None,
+            updated_at: None,
+            meta: None,
+        }
+    }
+
+    /// Additional workspace roots reported for this session. Each path must be absolute.
+    #[must_use]
+    pub fn additional_directories(mut self, additional_directories: Vec<PathBuf>) -> Self {
+        self.additional_directories = additional_directories;
+        self
+    }
+
+    /// Human-readable title for the session
+    #[must_use]
+    pub fn title(mut self, title: impl IntoOption<String>) -> Self {
+        self.title = title.into_option();
+        self
+    }
+
+    /// ISO 8601 timestamp of last activity
+    #[must_use]
+    pub fn updated_at(mut self, updated_at: impl IntoOption<String>) -> Self {
+        self.updated_at = updated_at.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+// Session config options
+
+/// Unique identifier for a session configuration option.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, From, Display)]
+#[serde(transparent)]
+#[from(Arc<str>, String, &'static str)]
+#[non_exhaustive]
+pub struct SessionConfigId(pub Arc<str>);
+
+impl SessionConfigId {
+    /// Wraps a protocol string as a typed [`SessionConfigId`].
+    #[must_use]
+    pub fn new(id: impl Into<Arc<str>>) -> Self {
+        Self(id.into())
+    }
+}
+
+/// Unique identifier for a session configuration option value.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, From, Display)]
+#[serde(transparent)]
+#[from(Arc<str>, String, &'static str)]
+#[non_exhaustive]
+pub struct SessionConfigValueId(pub Arc<str>);
+
+impl SessionConfigValueId {
+    /// Wraps a protocol string as a typed [`SessionConfigValueId`].
+    #[must_use]
+    pub fn new(id: impl Into<Arc<str>>) -> Self {
+        Self(id.into())
+    }
+}
+
+/// Unique identifier for a session configuration option value group.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, From, Display)]
+#[serde(transparent)]
+#[from(Arc<str>, String, &'static str)]
+#[non_exhaustive]
+pub struct SessionConfigGroupId(pub Arc<str>);
+
+impl SessionConfigGroupId {
+    /// Wraps a protocol string as a typed [`SessionConfigGroupId`].
+    #[must_use]
+    pub fn new(id: impl Into<Arc<str>>) -> Self {
+        Self(id.into())
+    }
+}
+
+/// A possible value for a session configuration option.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SessionConfigSelectOption {
+    /// Unique identifier for this option value.
+    pub value: SessionConfigValueId,
+    /// Human-readable label for this option value.
+    pub name: String,
+    /// Optional description for this option value.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SessionConfigSelectOption {
+    /// Builds [`SessionConfigSelectOption`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(value: impl Into<SessionConfigValueId>, name: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            name: name.into(),
+            description: None,
+            meta: None,
+        }
+    }
+
+    /// Sets or clears the optional `description` field.
+    #[must_use]
+    pub fn description(mut self, description: impl IntoOption<String>) -> Self {
+        self.description = description.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// A group of possible values for a session configuration option.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SessionConfigSelectGroup {
+    /// Unique identifier for this group.
+    pub group_id: SessionConfigGroupId,
+    /// Human-readable label for this group.
+    pub name: String,
+    /// The set of option values in this group.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub options: Vec<SessionConfigSelectOption>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SessionConfigSelectGroup {
+    /// Builds [`SessionConfigSelectGroup`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(
+        group_id: impl Into<SessionConfigGroupId>,
+        name: impl Into<String>,
+        options: Vec<SessionConfigSelectOption>,
+    ) -> Self {
+        Self {
+            group_id: group_id.into(),
+            name: name.into(),
+            options,
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Possible values for a session configuration option.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum SessionConfigSelectOptions {
+    /// A flat list of options with no grouping.
+    Ungrouped(Vec<SessionConfigSelectOption>),
+    /// A list of options grouped under headers.
+    Grouped(Vec<SessionConfigSelectGroup>),
+}
+
+impl From<Vec<SessionConfigSelectOption>> for SessionConfigSelectOptions {
+    fn from(options: Vec<SessionConfigSelectOption>) -> Self {
+        SessionConfigSelectOptions::Ungrouped(options)
+    }
+}
+
+impl From<Vec<SessionConfigSelectGroup>> for SessionConfigSelectOptions {
+    fn from(groups: Vec<SessionConfigSelectGroup>) -> Self {
+        SessionConfigSelectOptions::Grouped(groups)
+    }
+}
+
+/// A single-value selector (dropdown) session configuration option payload.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SessionConfigSelect {
+    /// The currently selected value.
+    pub current_value: SessionConfigValueId,
+    /// The set of selectable options.
+    pub options: SessionConfigSelectOptions,
+}
+
+impl SessionConfigSelect {
+    /// Builds [`SessionConfigSelect`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(
+        current_value: impl Into<SessionConfigValueId>,
+        options: impl Into<SessionConfigSelectOptions>,
+    ) -> Self {
+        Self {
+            current_value: current_value.into(),
+            options: options.into(),
+        }
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A boolean on/off toggle session configuration option payload.
+#[cfg(feature = "unstable_boolean_config")]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SessionConfigBoolean {
+    /// The current value of the boolean option.
+    pub current_value: bool,
+}
+
+#[cfg(feature = "unstable_boolean_config")]
+impl SessionConfigBoolean {
+    /// Builds [`SessionConfigBoolean`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(current_value: bool) -> Self {
+        Self { current_value }
+    }
+}
+
+/// Semantic category for a session configuration option.
+///
+/// This is intended to help Clients distinguish broadly common selectors (e.g. model selector vs
+/// session mode selector vs thought/reasoning level) for UX purposes (keyboard shortcuts, icons,
+/// placement). It MUST NOT be required for correctness. Clients MUST handle missing or unknown
+/// categories gracefully.
+///
+/// Category names beginning with `_` are free for custom use, like other ACP extension methods.
+/// Category names that do not begin with `_` are reserved for the ACP spec.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SessionConfigOptionCategory {
+    /// Session mode selector.
+    Mode,
+    /// Model selector.
+    ///
+    /// Agents expose the models a user can pick from as a `select` option in this
+    /// category. Clients switch models with `session/set_config_option`, and
+    /// agent-driven changes arrive as `config_option_update` session updates.
+    Model,
+    /// Model-related configuration parameter.
+    ModelConfig,
+    /// Thought/reasoning level selector.
+    ThoughtLevel,
+    /// Custom or future category.
+    ///
+    /// Values beginning with `_` are reserved for implementation-specific
+    /// extensions. Unknown values that do not begin with `_` are reserved for
+    /// future ACP variants.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Type-specific session configuration option payload.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[schemars(extend("discriminator" = {"propertyName": "type"}))]
+#[non_exhaustive]
+pub enum SessionConfigKind {
+    /// Single-value selector (dropdown).
+    Select(SessionConfigSelect),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Boolean on/off toggle.
+    #[cfg(feature = "unstable_boolean_config")]
+    Boolean(SessionConfigBoolean),
+    /// Custom or future session configuration option payload.
+    ///
+    /// Values beginning with `_` are reserved for implementation-specific
+    /// extensions. Unknown values that do not begin with `_` are reserved for
+    /// future ACP variants.
+    ///
+    /// Clients that do not understand this option type should preserve the raw
+    /// payload when storing, replaying, proxying, or forwarding configuration
+    /// data, and otherwise ignore the option or display it generically.
+    #[serde(untagged)]
+    Other(OtherSessionConfigKind),
+}
+
+/// Custom or future session configuration option payload.
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Eq)]
+#[schemars(inline)]
+#[schemars(transform = other_session_config_kind_schema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct OtherSessionConfigKind {
+    /// Custom or future session configuration option type.
+    ///
+    /// Values beginning with `_` are reserved for implementation-specific
+    /// extensions. Unknown values that do not begin with `_` are reserved for
+    /// future ACP variants.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Additional fields from the unknown session configuration option payload.
+    #[serde(flatten)]
+    pub fields: BTreeMap<String, serde_json::Value>,
+}
+
+impl OtherSessionConfigKind {
+    /// Builds [`OtherSessionConfigKind`] from an unknown discriminator and preserves the remaining extension fields.
+    #[must_use]
+    pub fn new(type_: impl Into<String>, mut fields: BTreeMap<String, serde_json::Value>) -> Self {
+        fields.remove("type");
+        fields.remove("_meta");
+        Self {
+            type_: type_.into(),
+            fields,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OtherSessionConfigKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut fields = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
+        let type_ = fields
+            .remove("type")
+            .ok_or_else(|| serde::de::Error::missing_field("type"))?;
+        let serde_json::Value::String(type_) = type_ else {
+            return Err(serde::de::Error::custom("`type` must be a string"));
+        };
+
+        if is_known_session_config_kind_type(&type_) {
+            return Err(serde::de::Error::custom(format!(
+                "known session configuration option `{type_}` did not match its schema"
+            )));
+        }
+
+        Ok(Self { type_, fields })
+    }
+}
+
+fn is_known_session_config_kind_type(type_: &str) -> bool {
+    match type_ {
+        "select" => true,
+        #[cfg(feature = "unstable_boolean_config")]
+        "boolean" => true,
+        _ => false,
+    }
+}
+
+fn other_session_config_kind_schema(schema: &mut Schema) {
+    super::schema_util::reject_known_string_discriminators(
+        schema,
+        "type",
+        &[
+            "select",
+            #[cfg(feature = "unstable_boolean_config")]
+            "boolean",
+        ],
+    );
+}
+
+/// A session configuration option selector and its current state.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SessionConfigOption {
+    /// Unique identifier for the configuration option.
+    pub config_id: SessionConfigId,
+    /// Human-readable label for the option.
+    pub name: String,
+    /// Optional description for the Client to display to the user.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Optional semantic category for this option (UX only).
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub category: Option<SessionConfigOptionCategory>,
+    /// Type-specific fields for this configuration option.
+    #[serde(flatten)]
+    pub kind: SessionConfigKind,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SessionConfigOption {
+    /// Builds [`SessionConfigOption`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(
+        config_id: impl Into<SessionConfigId>,
+        name: impl Into<String>,
+        kind: SessionConfigKind,
+    ) -> Self {
+        Self {
+            config_id: config_id.into(),
+            name: name.into(),
+            description: None,
+            category: None,
+            kind,
+            meta: None,
+        }
+    }
+
+    /// Builds a select-style session configuration option with its current value and choices.
+    #[must_use]
+    pub fn select(
+        config_id: impl Into<SessionConfigId>,
+        name: impl Into<String>,
+        current_value: impl Into<SessionConfigValueId>,
+        options: impl Into<SessionConfigSelectOptions>,
+    ) -> Self {
+        Self::new(
+            config_id,
+            name,
+            SessionConfigKind::Select(SessionConfigSelect::new(current_value, options)),
+        )
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    #[cfg(feature = "unstable_boolean_config")]
+    #[must_use]
+    pub fn boolean(
+        config_id: impl Into<SessionConfigId>,
+        name: impl Into<String>,
+        current_value: bool,
+    ) -> Self {
+        Self::new(
+            config_id,
+            name,
+            SessionConfigKind::Boolean(SessionConfigBoolean::new(current_value)),
+        )
+    }
+
+    /// Sets or clears the optional `description` field.
+    #[must_use]
+    pub fn description(mut self, description: impl IntoOption<String>) -> Self {
+        self.description = description.into_option();
+        self
+    }
+
+    /// Sets or clears the optional `category` field.
+    #[must_use]
+    pub fn category(mut self, category: impl IntoOption<SessionConfigOptionCategory>) -> Self {
+        self.category = category.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// The value to set for a session configuration option.
+///
+/// The `type` field acts as the discriminator in the serialized JSON form.
+///
+/// The `type` discriminator describes the *shape* of the value, not the option
+/// kind. For example every option kind that picks from a list of ids
+/// (`select`, `radio`, …) would use [`Id`](Self::Id), while a future freeform
+/// text option would get its own variant.
+#[cfg(feature = "unstable_boolean_config")]
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[schemars(extend("discriminator" = {"propertyName": "type"}))]
+#[non_exhaustive]
+pub enum SessionConfigOptionValue {
+    /// A [`SessionConfigValueId`] string value (`type: "id"`).
+    Id {
+        /// The value ID.
+        value: SessionConfigValueId,
+    },
+    /// A boolean value (`type: "boolean"`).
+    Boolean {
+        /// The boolean value.
+        value: bool,
+    },
+    /// Custom or future session configuration option value payload.
+    ///
+    /// Values beginning with `_` are reserved for implementation-specific
+    /// extensions. Unknown values that do not begin with `_` are reserved for
+    /// future ACP variants.
+    #[serde(untagged)]
+    Other(OtherSessionConfigOptionValue),
+}
+
+/// Custom or future session configuration option value payload.
+#[cfg(feature = "unstable_boolean_config")]
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Eq)]
+#[schemars(inline)]
+#[schemars(transform = other_session_config_option_value_schema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct OtherSessionConfigOptionValue {
+    /// Custom or future session configuration option value type.
+    ///
+    /// Values beginning with `_` are reserved for implementation-specific
+    /// extensions. Unknown values that do not begin with `_` are reserved for
+    /// future ACP variants.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Raw value payload for the custom or future value type.
+    pub value: serde_json::Value,
+    /// Additional fields from the unknown session configuration option value payload.
+    #[serde(flatten)]
+    pub fields: BTreeMap<String, serde_json::Value>,
+}
+
+#[cfg(feature = "unstable_boolean_config")]
+impl OtherSessionConfigOptionValue {
+    /// Builds [`OtherSessionConfigOptionValue`] from an unknown discriminator and preserves the remaining extension fields.
+    #[must_use]
+    pub fn new(
+        type_: impl Into<String>,
+        value: serde_json::Value,
+        mut fields: BTreeMap<String, serde_json::Value>,
+    ) -> Self {
+        fields.remove("type");
+        fields.remove("value");
+        fields.remove("_meta");
+        Self {
+            type_: type_.into(),
+            value,
+            fields,
+        }
+    }
+}
+
+#[cfg(feature = "unstable_boolean_config")]
+impl<'de> Deserialize<'de> for OtherSessionConfigOptionValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut fields = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
+        let type_ = fields
+            .remove("type")
+            .ok_or_else(|| serde::de::Error::missing_field("type"))?;
+        let serde_json::Value::String(type_) = type_ else {
+            return Err(serde::de::Error::custom("`type` must be a string"));
+        };
+
+        if is_known_session_config_option_value_type(&type_) {
+            return Err(serde::de::Error::custom(format!(
+                "known session configuration option value `{type_}` did not match its schema"
+            )));
+        }
+
+        let value = fields
+            .remove("value")
+            .ok_or_else(|| serde::de::Error::missing_field("value"))?;
+
+        Ok(Self {
+            type_,
+            value,
+            fields,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_boolean_config")]
+impl<'de> Deserialize<'de> for SessionConfigOptionValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut fields = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
+        let type_ = fields.remove("type");
+        let value = fields
+            .remove("value")
+            .ok_or_else(|| serde::de::Error::missing_field("value"))?;
+
+        let type_ = type_.ok_or_else(|| serde::de::Error::missing_field("type"))?;
+
+        let serde_json::Value::String(type_) = type_ else {
+            return Err(serde::de::Error::custom("`type` must be a string"));
+        };
+
+        match type_.as_str() {
+            "id" => {
+                let value = serde_json::from_value(value).map_err(|error| {
+                    serde::de::Error::custom(format!(
+                        "`value` must be a string for `type: id`: {error}"
+                    ))
+                })?;
+                Ok(Self::Id { value })
+            }
+            "boolean" => {
+                let value = serde_json::from_value(value).map_err(|error| {
+                    serde::de::Error::custom(format!(
+                        "`value` must be a boolean for `type: boolean`: {error}"
+                    ))
+                })?;
+                Ok(Self::Boolean { value })
+            }
+            _ => Ok(Self::Other(OtherSessionConfigOptionValue {
+                type_,
+                value,
+                fields,
+            })),
+        }
+    }
+}
+
+#[cfg(feature = "unstable_boolean_config")]
+fn is_known_session_config_option_value_type(type_: &str) -> bool {
+    matches!(type_, "id" | "boolean")
+}
+
+#[cfg(feature = "unstable_boolean_config")]
+fn other_session_config_option_value_schema(schema: &mut Schema) {
+    super::schema_util::reject_known_string_discriminators(schema, "type", &["id", "boolean"]);
+}
+
+#[cfg(feature = "unstable_boolean_config")]
+impl SessionConfigOptionValue {
+    /// Create an id option value (used by `select` and other id-based option types).
+    #[must_use]
+    pub fn id(id: impl Into<SessionConfigValueId>) -> Self {
+        Self::Id { value: id.into() }
+    }
+
+    /// Create a boolean option value.
+    #[must_use]
+    pub fn boolean(val: bool) -> Self {
+        Self::Boolean { value: val }
+    }
+
+    /// Return the inner [`SessionConfigValueId`] if this is a
+    /// [`Id`](Self::Id) value.
+    #[must_use]
+    pub fn as_id(&self) -> Option<&SessionConfigValueId> {
+        match self {
+            Self::Id { value } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Return the inner [`bool`] if this is a [`Boolean`](Self::Boolean) value.
+    #[must_use]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean { value } => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "unstable_boolean_config")]
+impl From<SessionConfigValueId> for SessionConfigOptionValue {
+    fn from(value: SessionConfigValueId) -> Self {
+        Self::Id { value }
+    }
+}
+
+#[cfg(feature = "unstable_boolean_config")]
+impl From<bool> for SessionConfigOptionValue {
+    fn from(value: bool) -> Self {
+        Self::Boolean { value }
+    }
+}
+
+#[cfg(feature = "unstable_boolean_config")]
+impl From<&str> for SessionConfigOptionValue {
+    fn from(value: &str) -> Self {
+        Self::Id {
+            value: SessionConfigValueId::new(value),
+        }
+    }
+}
+
+/// Request parameters for setting a session configuration option.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_CONFIG_OPTION_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SetSessionConfigOptionRequest {
+    /// The ID of the session to set the configuration option for.
+    pub session_id: SessionId,
+    /// The ID of the configuration option to set.
+    pub config_id: SessionConfigId,
+    /// The value to set, including a `type` discriminator and the raw `value`.
+    ///
+    /// Payloads must send `type: "id"` for id-based options.
+    #[cfg(feature = "unstable_boolean_config")]
+    #[serde(flatten)]
+    pub value: SessionConfigOptionValue,
+    /// The ID of the configuration option value to set.
+    #[cfg(not(feature = "unstable_boolean_config"))]
+    pub value: SessionConfigValueId,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SetSessionConfigOptionRequest {
+    /// Builds [`SetSessionConfigOptionRequest`] with the required request fields set; optional fields start unset or empty.
+    #[cfg(feature = "unstable_boolean_config")]
+    #[must_use]
+    pub fn new(
+        session_id: impl Into<SessionId>,
+        config_id: impl Into<SessionConfigId>,
+        value: impl Into<SessionConfigOptionValue>,
+    ) -> Self {
+        Self {
+            session_id: session_id.into(),
+            config_id: config_id.into(),
+            value: value.into(),
+            meta: None,
+        }
+    }
+
+    /// Builds a select-value `session/set_config_option` request for crates built
+    /// without boolean session configuration support.
+    #[cfg(not(feature = "unstable_boolean_config"))]
+    #[must_use]
+    pub fn new(
+        session_id: impl Into<SessionId>,
+        config_id: impl Into<SessionConfigId>,
+        value: impl Into<SessionConfigValueId>,
+    ) -> Self {
+        Self {
+            session_id: session_id.into(),
+            config_id: config_id.into(),
+            value: value.into(),
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Response to `session/set_config_option` method.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_CONFIG_OPTION_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SetSessionConfigOptionResponse {
+    /// The full set of configuration options and their current values.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub config_options: Vec<SessionConfigOption>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SetSessionConfigOptionResponse {
+    /// Builds [`SetSessionConfigOptionResponse`] with the required response fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(config_options: Vec<SessionConfigOption>) -> Self {
+        Self {
+            config_options,
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+// MCP
+
+/// Configuration for connecting to an MCP (Model Context Protocol) server.
+///
+/// MCP servers provide tools and context that the agent can use when
+/// processing prompts.
+///
+/// See protocol docs: [MCP Servers](https://agentclientprotocol.com/protocol/session-setup#mcp-servers)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[schemars(extend("discriminator" = {"propertyName": "type"}))]
+#[non_exhaustive]
+pub enum McpServer {
+    /// HTTP transport configuration
+    ///
+    /// Only available when the Agent capabilities include `session.mcp.http`.
+    Http(McpServerHttp),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// ACP transport configuration
+    ///
+    /// Only available when the Agent capabilities include `session.mcp.acp`.
+    /// The MCP server is provided by an ACP component and communicates over the ACP channel.
+    #[cfg(feature = "unstable_mcp_over_acp")]
+    Acp(McpServerAcp),
+    /// Stdio transport configuration
+    ///
+    /// Only available when the Agent capabilities include `session.mcp.stdio`.
+    Stdio(McpServerStdio),
+    /// Custom or future MCP server transport configuration.
+    ///
+    /// Values beginning with `_` are reserved for implementation-specific
+    /// extensions. Unknown values that do not begin with `_` are reserved for
+    /// future ACP variants.
+    ///
+    /// Receivers that do not understand this transport should preserve the raw
+    /// payload when storing, replaying, proxying, or forwarding session setup
+    /// data, and otherwise ignore it or reject the server configuration.
+    #[serde(untagged)]
+    Other(OtherMcpServer),
+}
+
+/// Custom or future MCP server transport payload.
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Eq)]
+#[schemars(inline)]
+#[schemars(transform = other_mcp_server_schema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct OtherMcpServer {
+    /// Custom or future MCP server transport type.
+    ///
+    /// Values beginning with `_` are reserved for implementation-specific
+    /// extensions. Unknown values that do not begin with `_` are reserved for
+    /// future ACP variants.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Additional fields from the unknown MCP server transport payload.
+    #[serde(flatten)]
+    pub fields: BTreeMap<String, serde_json::Value>,
+}
+
+impl OtherMcpServer {
+    /// Builds [`OtherMcpServer`] from an unknown discriminator and preserves the remaining extension fields.
+    #[must_use]
+    pub fn new(type_: impl Into<String>, mut fields: BTreeMap<String, serde_json::Value>) -> Self {
+        fields.remove("type");
+        Self {
+            type_: type_.into(),
+            fields,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OtherMcpServer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut fields = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
+        let type_ = fields
+            .remove("type")
+            .ok_or_else(|| serde::de::Error::missing_field("type"))?;
+        let serde_json::Value::String(type_) = type_ else {
+            return Err(serde::de::Error::custom("`type` must be a string"));
+        };
+
+        if is_known_mcp_server_type(&type_) {
+            return Err(serde::de::Error::custom(format!(
+                "known MCP server transport `{type_}` did not match its schema"
+            )));
+        }
+
+        Ok(Self { type_, fields })
+    }
+}
+
+fn is_known_mcp_server_type(type_: &str) -> bool {
+    match type_ {
+        "http" | "stdio" => true,
+        #[cfg(feature = "unstable_mcp_over_acp")]
+        "acp" => true,
+        _ => false,
+    }
+}
+
+fn other_mcp_server_schema(schema: &mut Schema) {
+    super::schema_util::reject_known_string_discriminators(
+        schema,
+        "type",
+        &[
+            "http",
+            "stdio",
+            #[cfg(feature = "unstable_mcp_over_acp")]
+            "acp",
+        ],
+    );
+}
+
+/// HTTP transport configuration for MCP.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct McpServerHttp {
+    /// Human-readable name identifying this MCP server.
+    pub name: String,
+    /// URL to the MCP server.
+    pub url: String,
+    /// HTTP headers to set when making requests to the MCP server.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<HttpHeader>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl McpServerHttp {
+    /// Builds [`McpServerHttp`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            url: url.into(),
+            headers: Vec::new(),
+            meta: None,
+        }
+    }
+
+    /// HTTP headers to set when making requests to the MCP server.
+    #[must_use]
+    pub fn headers(mut self, headers: Vec<HttpHeader>) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Unique identifier for an MCP server using the ACP transport.
+///
+/// The value is opaque and generated by the ACP component providing the MCP server. It is
+/// used by `mcp/connect` to route connection requests back to the component that declared the
+/// server.
+#[cfg(feature = "unstable_mcp_over_acp")]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
+#[serde(transparent)]
+#[from(Arc<str>, String, &'static str)]
+#[non_exhaustive]
+pub struct McpServerAcpId(pub Arc<str>);
+
+#[cfg(feature = "unstable_mcp_over_acp")]
+impl McpServerAcpId {
+    /// Wraps a protocol string as a typed [`McpServerAcpId`].
+    #[must_use]
+    pub fn new(id: impl Into<Arc<str>>) -> Self {
+        Self(id.into())
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// ACP transport configuration for MCP.
+///
+/// The MCP server is provided by an ACP component and communicates over the ACP channel
+/// using `mcp/connect`, `mcp/message`, and `mcp/disconnect`.
+#[serde_as]
+#[skip_serializing_none]
+#[cfg(feature = "unstable_mcp_over_acp")]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct McpServerAcp {
+    /// Human-readable name identifying this MCP server.
+    pub name: String,
+    /// Unique identifier for this MCP server, generated by the component providing it.
+    ///
+    /// Providers MUST NOT reuse an ID for multiple ACP-transport MCP servers that are visible
+    /// on the same ACP connection.
+    pub server_id: McpServerAcpId,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_mcp_over_acp")]
+impl McpServerAcp {
+    /// Builds [`McpServerAcp`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(name: impl Into<String>, server_id: impl Into<McpServerAcpId>) -> Self {
+        Self {
+            name: name.into(),
+            server_id: server_id.into(),
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Stdio transport configuration for MCP.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct McpServerStdio {
+    /// Human-readable name identifying this MCP server.
+    pub name: String,
+    /// Absolute path to the MCP server executable.
+    pub command: PathBuf,
+    /// Command-line arguments to pass to the MCP server.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    /// Environment variables to set when launching the MCP server.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env: Vec<EnvVariable>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl McpServerStdio {
+    /// Builds [`McpServerStdio`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(name: impl Into<String>, command: impl Into<PathBuf>) -> Self {
+        Self {
+            name: name.into(),
+            command: command.into(),
+            args: Vec::new(),
+            env: Vec::new(),
+            meta: None,
+        }
+    }
+
+    /// Command-line arguments to pass to the MCP server.
+    #[must_use]
+    pub fn args(mut self, args: Vec<String>) -> Self {
+        self.args = args;
+        self
+    }
+
+    /// Environment variables to set when launching the MCP server.
+    #[must_use]
+    pub fn env(mut self, env: Vec<EnvVariable>) -> Self {
+        self.env = env;
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// An environment variable to set when launching an MCP server.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct EnvVariable {
+    /// The name of the environment variable.
+    pub name: String,
+    /// The value to set for the environment variable.
+    pub value: String,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl EnvVariable {
+    /// Builds [`EnvVariable`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// An HTTP header to set when making requests to the MCP server.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct HttpHeader {
+    /// The name of the HTTP header.
+    pub name: String,
+    /// The value to set for the HTTP header.
+    pub value: String,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl HttpHeader {
+    /// Builds [`HttpHeader`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
         Self {
-            config_id: config_id.into(),
             name: name.into(),
-            description: None,
-            category: None,
-            kind,
+            value: value.into(),
             meta: None,
         }
     }
 
-    /// Builds a select-style session configuration option with its current value and choices.
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn select(
-        config_id: impl Into<SessionConfigId>,
-        name: impl Into<String>,
-        current_value: impl Into<SessionConfigValueId>,
-        options: impl Into<SessionConfigSelectOptions>,
-    ) -> Self {
-        Self::new(
-            config_id,
-            name,
-            SessionConfigKind::Select(SessionConfigSelect::new(current_value, options)),
-        )
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
     }
+}
+
+// Prompt
 
+/// Request parameters for sending a user prompt to the agent.
+///
+/// Contains the user's message and any additional context.
+///
+/// See protocol docs: [User Message](https://agentclientprotocol.com/protocol/prompt-lifecycle#1-user-message)
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_PROMPT_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PromptRequest {
+    /// The ID of the session to send this user message to
+    pub session_id: SessionId,
+    /// The blocks of content that compose the user's message.
+    ///
+    /// As a baseline, the Agent MUST support [`ContentBlock::Text`] and [`ContentBlock::ResourceLink`],
+    /// while other variants are optionally enabled via [`PromptCapabilities`].
+    ///
+    /// The Client MUST adapt its interface according to [`PromptCapabilities`].
+    ///
+    /// The client MAY include referenced pieces of context as either
+    /// [`ContentBlock::Resource`] or [`ContentBlock::ResourceLink`].
+    ///
+    /// When available, [`ContentBlock::Resource`] is preferred
+    /// as it avoids extra round-trips and allows the message to include
+    /// pieces of context from sources the agent may not have access to.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub prompt: Vec<ContentBlock>,
     /// **UNSTABLE**
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
-    #[cfg(feature = "unstable_boolean_config")]
+    ///
+    /// How much reasoning the agent should spend on this prompt.
+    ///
+    /// A hint for agents backed by reasoning models. Agents that do not
+    /// advertise the `reasoningEffort` prompt capability, or do not recognize
+    /// the value, ignore it.
+    #[cfg(feature = "unstable_reasoning_effort")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub reasoning_effort: Option<ReasoningEffort>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Sampling parameters that override the session defaults for this prompt
+    /// only.
+    ///
+    /// Agents that do not advertise the `samplingOverrides` prompt capability
+    /// ignore them. Out-of-range values deserialize as unset instead of failing
+    /// the request.
+    #[cfg(feature = "unstable_sampling_overrides")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub overrides: Option<SamplingOverrides>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// How long the client is willing to wait for this prompt, in milliseconds
+    /// from when the agent receives it.
+    ///
+    /// Advisory. Agents that honor it SHOULD wind down before the deadline,
+    /// keep the partial results they have, and report [`StopReason::Deadline`] when they go idle.
+    /// Agents MAY ignore it.
+    #[cfg(feature = "unstable_prompt_deadline")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub deadline_ms: Option<u64>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Identifies the turn this prompt starts.
+    ///
+    /// Chosen by the Client and unique among the session's turns. The Agent
+    /// MUST echo it as the `turnId` of every `session/update` notification the
+    /// turn produces and of the `session/prompt` response, so Clients can
+    /// attribute updates to this prompt while they stream. Clients that send
+    /// concurrent prompts MUST set it.
+    #[cfg(feature = "unstable_turn_id")]
+    #[serde(default)]
+    pub turn_id: Option<TurnId>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl PromptRequest {
+    /// Builds [`PromptRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn boolean(
-        config_id: impl Into<SessionConfigId>,
-        name: impl Into<String>,
-        current_value: bool,
-    ) -> Self {
-        Self::new(
-            config_id,
-            name,
-            SessionConfigKind::Boolean(SessionConfigBoolean::new(current_value)),
-        )
+    pub fn new(session_id: impl Into<SessionId>, prompt: Vec<ContentBlock>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            prompt,
+            #[cfg(feature = "unstable_reasoning_effort")]
+            reasoning_effort: None,
+            #[cfg(feature = "unstable_sampling_overrides")]
+            overrides: None,
+            #[cfg(feature = "unstable_prompt_deadline")]
+            deadline_ms: None,
+            #[cfg(feature = "unstable_turn_id")]
+            turn_id: None,
+            meta: None,
+        }
     }
 
-    /// Sets or clears the optional `description` field.
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// How much reasoning the agent should spend on this prompt.
+    #[cfg(feature = "unstable_reasoning_effort")]
     #[must_use]
-    pub fn description(mut self, description: impl IntoOption<String>) -> Self {
-        self.description = description.into_option();
+    pub fn reasoning_effort(mut self, reasoning_effort: impl IntoOption<ReasoningEffort>) -> Self {
+        self.reasoning_effort = reasoning_effort.into_option();
         self
     }
 
-    /// Sets or clears the optional `category` field.
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The reasoning effort the agent should apply under `capabilities`.
+    ///
+    /// Returns `None` when no hint was sent, when the agent does not advertise
+    /// the `reasoningEffort` prompt capability, or when the level is a custom or
+    /// future value.
+    #[cfg(feature = "unstable_reasoning_effort")]
     #[must_use]
-    pub fn category(mut self, category: impl IntoOption<SessionConfigOptionCategory>) -> Self {
-        self.category = category.into_option();
+    pub fn supported_reasoning_effort(
+        &self,
+        capabilities: &PromptCapabilities,
+    ) -> Option<&ReasoningEffort> {
+        self.reasoning_effort.as_ref().filter(|effort| {
+            capabilities.reasoning_effort.is_some() && !matches!(effort, ReasoningEffort::Other(_))
+        })
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Sampling parameters that override the session defaults for this prompt
+    /// only.
+    #[cfg(feature = "unstable_sampling_overrides")]
+    #[must_use]
+    pub fn overrides(mut self, overrides: impl IntoOption<SamplingOverrides>) -> Self {
+        self.overrides = overrides.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The sampling overrides the agent should apply under `capabilities`.
+    ///
+    /// Returns `None` when no overrides were sent or when the agent does not
+    /// advertise the `samplingOverrides` prompt capability.
+    #[cfg(feature = "unstable_sampling_overrides")]
+    #[must_use]
+    pub fn supported_overrides(
+        &self,
+        capabilities: &PromptCapabilities,
+    ) -> Option<&SamplingOverrides> {
+        self.overrides
+            .as_ref()
+            .filter(|_| capabilities.sampling_overrides.is_some())
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// How long the client is willing to wait for this prompt, in milliseconds.
+    #[cfg(feature = "unstable_prompt_deadline")]
+    #[must_use]
+    pub fn deadline_ms(mut self, deadline_ms: impl IntoOption<u64>) -> Self {
+        self.deadline_ms = deadline_ms.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Identifies the turn this prompt starts.
+    #[cfg(feature = "unstable_turn_id")]
+    #[must_use]
+    pub fn turn_id(mut self, turn_id: impl IntoOption<TurnId>) -> Self {
+        self.turn_id = turn_id.into_option();
         self
     }
 
@@ -2517,245 +4475,333 @@ impl SessionConfigOption {
 ///
 /// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// The value to set for a session configuration option.
-///
-/// The `type` field acts as the discriminator in the serialized JSON form.
+/// How much reasoning an agent backed by a reasoning model should spend on a
+/// prompt.
 ///
-/// The `type` discriminator describes the *shape* of the value, not the option
-/// kind. For example every option kind that picks from a list of ids
-/// (`select`, `radio`, …) would use [`Id`](Self::Id), while a future freeform
-/// text option would get its own variant.
-#[cfg(feature = "unstable_boolean_config")]
-#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Eq)]
-#[serde(tag = "type", rename_all = "snake_case")]
-#[schemars(extend("discriminator" = {"propertyName": "type"}))]
+/// Agents map each level onto their model's own setting, such as a thinking
+/// token budget.
+#[cfg(feature = "unstable_reasoning_effort")]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
 #[non_exhaustive]
-pub enum SessionConfigOptionValue {
-    /// A [`SessionConfigValueId`] string value (`type: "id"`).
-    Id {
-        /// The value ID.
-        value: SessionConfigValueId,
-    },
-    /// A boolean value (`type: "boolean"`).
-    Boolean {
-        /// The boolean value.
-        value: bool,
-    },
-    /// Custom or future session configuration option value payload.
+pub enum ReasoningEffort {
+    /// Answer quickly with minimal reasoning.
+    Low,
+    /// The agent's balanced default.
+    Medium,
+    /// Reason thoroughly, at the cost of latency and tokens.
+    High,
+    /// Custom or future reasoning effort.
     ///
     /// Values beginning with `_` are reserved for implementation-specific
     /// extensions. Unknown values that do not begin with `_` are reserved for
     /// future ACP variants.
     #[serde(untagged)]
-    Other(OtherSessionConfigOptionValue),
+    Other(String),
 }
 
-/// Custom or future session configuration option value payload.
-#[cfg(feature = "unstable_boolean_config")]
-#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Eq)]
-#[schemars(inline)]
-#[schemars(transform = other_session_config_option_value_schema)]
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Sampling parameters that override the session defaults for a single
+/// prompt.
+///
+/// Unset fields keep the session default. Deserialization rejects values
+/// outside the documented ranges.
+#[cfg(feature = "unstable_sampling_overrides")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, JsonSchema, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct OtherSessionConfigOptionValue {
-    /// Custom or future session configuration option value type.
+pub struct SamplingOverrides {
+    /// Sampling temperature, from `0.0` to `2.0`.
+    #[schemars(range(min = 0.0, max = 2.0))]
+    pub temperature: Option<f32>,
+    /// Nucleus sampling probability mass, from `0.0` to `1.0`.
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub top_p: Option<f32>,
+    /// Maximum number of tokens to generate, at least `1`.
+    #[schemars(range(min = 1))]
+    pub max_tokens: Option<u32>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// Values beginning with `_` are reserved for implementation-specific
-    /// extensions. Unknown values that do not begin with `_` are reserved for
-    /// future ACP variants.
-    #[serde(rename = "type")]
-    pub type_: String,
-    /// Raw value payload for the custom or future value type.
-    pub value: serde_json::Value,
-    /// Additional fields from the unknown session configuration option value payload.
-    #[serde(flatten)]
-    pub fields: BTreeMap<String, serde_json::Value>,
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_boolean_config")]
-impl OtherSessionConfigOptionValue {
-    /// Builds [`OtherSessionConfigOptionValue`] from an unknown discriminator and preserves the remaining extension fields.
+#[cfg(feature = "unstable_sampling_overrides")]
+impl SamplingOverrides {
+    /// Builds an empty [`SamplingOverrides`]; every parameter keeps the session default.
     #[must_use]
-    pub fn new(
-        type_: impl Into<String>,
-        value: serde_json::Value,
-        mut fields: BTreeMap<String, serde_json::Value>,
-    ) -> Self {
-        fields.remove("type");
-        fields.remove("value");
-        fields.remove("_meta");
-        Self {
-            type_: type_.into(),
-            value,
-            fields,
-        }
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sampling temperature, from `0.0` to `2.0`.
+    #[must_use]
+    pub fn temperature(mut self, temperature: impl IntoOption<f32>) -> Self {
+        self.temperature = temperature.into_option();
+        self
     }
-}
 
-#[cfg(feature = "unstable_boolean_config")]
-impl<'de> Deserialize<'de> for OtherSessionConfigOptionValue {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        let mut fields = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
-        let type_ = fields
-            .remove("type")
-            .ok_or_else(|| serde::de::Error::missing_field("type"))?;
-        let serde_json::Value::String(type_) = type_ else {
-            return Err(serde::de::Error::custom("`type` must be a string"));
-        };
+    /// Nucleus sampling probability mass, from `0.0` to `1.0`.
+    #[must_use]
+    pub fn top_p(mut self, top_p: impl IntoOption<f32>) -> Self {
+        self.top_p = top_p.into_option();
+        self
+    }
 
-        if is_known_session_config_option_value_type(&type_) {
-            return Err(serde::de::Error::custom(format!(
-                "known session configuration option value `{type_}` did not match its schema"
-            )));
-        }
+    /// Maximum number of tokens to generate, at least `1`.
+    #[must_use]
+    pub fn max_tokens(mut self, max_tokens: impl IntoOption<u32>) -> Self {
+        self.max_tokens = max_tokens.into_option();
+        self
+    }
 
-        let value = fields
-            .remove("value")
-            .ok_or_else(|| serde::de::Error::missing_field("value"))?;
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
 
-        Ok(Self {
-            type_,
-            value,
-            fields,
-        })
+    fn out_of_range(&self) -> Option<&'static str> {
+        if self
+            .temperature
+            .is_some_and(|temperature| !(0.0..=2.0).contains(&temperature))
+        {
+            Some("`temperature` must be between 0.0 and 2.0")
+        } else if self
+            .top_p
+            .is_some_and(|top_p| !(0.0..=1.0).contains(&top_p))
+        {
+            Some("`topP` must be between 0.0 and 1.0")
+        } else if self.max_tokens == Some(0) {
+            Some("`maxTokens` must be at least 1")
+        } else {
+            None
+        }
     }
 }
 
-#[cfg(feature = "unstable_boolean_config")]
-impl<'de> Deserialize<'de> for SessionConfigOptionValue {
+#[cfg(feature = "unstable_sampling_overrides")]
+impl<'de> Deserialize<'de> for SamplingOverrides {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let mut fields = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
-        let type_ = fields.remove("type");
-        let value = fields
-            .remove("value")
-            .ok_or_else(|| serde::de::Error::missing_field("value"))?;
-
-        let type_ = type_.ok_or_else(|| serde::de::Error::missing_field("type"))?;
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Wire {
+            temperature: Option<f32>,
+            top_p: Option<f32>,
+            max_tokens: Option<u32>,
+            #[serde(default, rename = "_meta", with = "serde_with::As::<DefaultOnError>")]
+            meta: Option<Meta>,
+        }
 
-        let serde_json::Value::String(type_) = type_ else {
-            return Err(serde::de::Error::custom("`type` must be a string"));
+        let wire = Wire::deserialize(deserializer)?;
+        let overrides = Self {
+            temperature: wire.temperature,
+            top_p: wire.top_p,
+            max_tokens: wire.max_tokens,
+            meta: wire.meta,
         };
-
-        match type_.as_str() {
-            "id" => {
-                let value = serde_json::from_value(value).map_err(|error| {
-                    serde::de::Error::custom(format!(
-                        "`value` must be a string for `type: id`: {error}"
-                    ))
-                })?;
-                Ok(Self::Id { value })
-            }
-            "boolean" => {
-                let value = serde_json::from_value(value).map_err(|error| {
-                    serde::de::Error::custom(format!(
-                        "`value` must be a boolean for `type: boolean`: {error}"
-                    ))
-                })?;
-                Ok(Self::Boolean { value })
-            }
-            _ => Ok(Self::Other(OtherSessionConfigOptionValue {
-                type_,
-                value,
-                fields,
-            })),
+        if let Some(message) = overrides.out_of_range() {
+            return Err(serde::de::Error::custom(message));
         }
+        Ok(overrides)
     }
 }
 
-#[cfg(feature = "unstable_boolean_config")]
-fn is_known_session_config_option_value_type(type_: &str) -> bool {
-    matches!(type_, "id" | "boolean")
-}
-
-#[cfg(feature = "unstable_boolean_config")]
-fn other_session_config_option_value_schema(schema: &mut Schema) {
-    super::schema_util::reject_known_string_discriminators(schema, "type", &["id", "boolean"]);
+/// Response acknowledging that a user prompt was accepted.
+///
+/// This response does not indicate that the agent has finished processing.
+/// Agents report session state through `state_update` session updates.
+///
+/// See protocol docs: [Prompt Accepted](https://agentclientprotocol.com/protocol/prompt-lifecycle#2-prompt-accepted)
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_PROMPT_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PromptResponse {
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The turn this response completes.
+    ///
+    /// Agents MUST echo the `turnId` of the `session/prompt` request here when
+    /// the request carried one.
+    #[cfg(feature = "unstable_turn_id")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub turn_id: Option<TurnId>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_boolean_config")]
-impl SessionConfigOptionValue {
-    /// Create an id option value (used by `select` and other id-based option types).
-    #[must_use]
-    pub fn id(id: impl Into<SessionConfigValueId>) -> Self {
-        Self::Id { value: id.into() }
-    }
-
-    /// Create a boolean option value.
+impl PromptResponse {
+    /// Builds [`PromptResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn boolean(val: bool) -> Self {
-        Self::Boolean { value: val }
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    /// Return the inner [`SessionConfigValueId`] if this is a
-    /// [`Id`](Self::Id) value.
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The turn this response completes.
+    #[cfg(feature = "unstable_turn_id")]
     #[must_use]
-    pub fn as_id(&self) -> Option<&SessionConfigValueId> {
-        match self {
-            Self::Id { value } => Some(value),
-            _ => None,
-        }
+    pub fn turn_id(mut self, turn_id: impl IntoOption<TurnId>) -> Self {
+        self.turn_id = turn_id.into_option();
+        self
     }
 
-    /// Return the inner [`bool`] if this is a [`Boolean`](Self::Boolean) value.
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn as_bool(&self) -> Option<bool> {
-        match self {
-            Self::Boolean { value } => Some(*value),
-            _ => None,
-        }
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
     }
 }
 
-#[cfg(feature = "unstable_boolean_config")]
-impl From<SessionConfigValueId> for SessionConfigOptionValue {
-    fn from(value: SessionConfigValueId) -> Self {
-        Self::Id { value }
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Identifies one prompt turn within a session.
+///
+/// Clients choose turn IDs when they send `session/prompt`, and Agents echo
+/// them on the turn's `session/update` notifications and on the response, so
+/// updates can be attributed to their prompt while they stream.
+#[cfg(feature = "unstable_turn_id")]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
+#[serde(transparent)]
+#[from(Arc<str>, String, &'static str)]
+#[non_exhaustive]
+pub struct TurnId(pub Arc<str>);
+
+#[cfg(feature = "unstable_turn_id")]
+impl TurnId {
+    /// Wraps a protocol string as a typed [`TurnId`].
+    #[must_use]
+    pub fn new(id: impl Into<Arc<str>>) -> Self {
+        Self(id.into())
     }
 }
 
-#[cfg(feature = "unstable_boolean_config")]
-impl From<bool> for SessionConfigOptionValue {
-    fn from(value: bool) -> Self {
-        Self::Boolean { value }
+#[cfg(feature = "unstable_turn_id")]
+impl IntoOption<TurnId> for &str {
+    fn into_option(self) -> Option<TurnId> {
+        Some(TurnId::new(self))
     }
 }
 
-#[cfg(feature = "unstable_boolean_config")]
-impl From<&str> for SessionConfigOptionValue {
-    fn from(value: &str) -> Self {
-        Self::Id {
-            value: SessionConfigValueId::new(value),
-        }
-    }
+/// Reasons why an agent stops active session work.
+///
+/// See protocol docs: [Stop Reasons](https://agentclientprotocol.com/protocol/prompt-lifecycle#stop-reasons)
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum StopReason {
+    /// The active work ended successfully.
+    EndTurn,
+    /// The active work ended because the agent reached the maximum number of tokens.
+    MaxTokens,
+    /// The active work ended because the agent reached the maximum number of
+    /// allowed agent requests before returning idle.
+    MaxTurnRequests,
+    /// The active work ended because the agent refused to continue. The user
+    /// prompt and everything that comes after it won't be included in the next
+    /// prompt, so this should be reflected in the UI.
+    Refusal,
+    /// Active session work was cancelled by the client via `session/cancel`.
+    ///
+    /// Agents should report this stop reason on an idle `state_update` session update
+    /// when cancellation succeeds, even if cancellation causes exceptions in
+    /// underlying operations.
+    Cancelled,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Active work ended early because the prompt's `deadlineMs` ran out.
+    ///
+    /// Agents SHOULD keep the partial results produced so far.
+    #[cfg(feature = "unstable_prompt_deadline")]
+    Deadline,
+    /// Custom or future stop reason.
+    ///
+    /// Values beginning with `_` are reserved for implementation-specific
+    /// extensions. Unknown values that do not begin with `_` are reserved for
+    /// future ACP variants.
+    #[serde(untagged)]
+    Other(String),
 }
 
-/// Request parameters for setting a session configuration option.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Token usage information for completed session work.
+#[cfg(feature = "unstable_end_turn_token_usage")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_CONFIG_OPTION_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SetSessionConfigOptionRequest {
-    /// The ID of the session to set the configuration option for.
-    pub session_id: SessionId,
-    /// The ID of the configuration option to set.
-    pub config_id: SessionConfigId,
-    /// The value to set, including a `type` discriminator and the raw `value`.
-    ///
-    /// Payloads must send `type: "id"` for id-based options.
-    #[cfg(feature = "unstable_boolean_config")]
-    #[serde(flatten)]
-    pub value: SessionConfigOptionValue,
-    /// The ID of the configuration option value to set.
-    #[cfg(not(feature = "unstable_boolean_config"))]
-    pub value: SessionConfigValueId,
+pub struct Usage {
+    /// Sum of all token types across session.
+    pub total_tokens: u64,
+    /// Total input tokens.
+    pub input_tokens: u64,
+    /// Total output tokens.
+    pub output_tokens: u64,
+    /// Total thought/reasoning tokens
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub thought_tokens: Option<u64>,
+    /// Total cache read tokens.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub cached_read_tokens: Option<u64>,
+    /// Total cache write tokens.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub cached_write_tokens: Option<u64>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -2768,84 +4814,41 @@ pub struct SetSessionConfigOptionRequest {
     pub meta: Option<Meta>,
 }
 
-impl SetSessionConfigOptionRequest {
-    /// Builds [`SetSessionConfigOptionRequest`] with the required request fields set; optional fields start unset or empty.
-    #[cfg(feature = "unstable_boolean_config")]
+#[cfg(feature = "unstable_end_turn_token_usage")]
+impl Usage {
+    /// Builds [`Usage`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(
-        session_id: impl Into<SessionId>,
-        config_id: impl Into<SessionConfigId>,
-        value: impl Into<SessionConfigOptionValue>,
-    ) -> Self {
+    pub fn new(total_tokens: u64, input_tokens: u64, output_tokens: u64) -> Self {
         Self {
-            session_id: session_id.into(),
-            config_id: config_id.into(),
-            value: value.into(),
+            total_tokens,
+            input_tokens,
+            output_tokens,
+            thought_tokens: None,
+            cached_read_tokens: None,
+            cached_write_tokens: None,
             meta: None,
         }
     }
 
-    /// Builds a select-value `session/set_config_option` request for crates built
-    /// without boolean session configuration support.
-    #[cfg(not(feature = "unstable_boolean_config"))]
+    /// Total thought/reasoning tokens
     #[must_use]
-    pub fn new(
-        session_id: impl Into<SessionId>,
-        config_id: impl Into<SessionConfigId>,
-        value: impl Into<SessionConfigValueId>,
-    ) -> Self {
-        Self {
-            session_id: session_id.into(),
-            config_id: config_id.into(),
-            value: value.into(),
-            meta: None,
-        }
+    pub fn thought_tokens(mut self, thought_tokens: impl IntoOption<u64>) -> Self {
+        self.thought_tokens = thought_tokens.into_option();
+        self
     }
 
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
-    ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    /// Total cache read tokens.
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
+    pub fn cached_read_tokens(mut self, cached_read_tokens: impl IntoOption<u64>) -> Self {
+        self.cached_read_tokens = cached_read_tokens.into_option();
         self
     }
-}
-
-/// Response to `session/set_config_option` method.
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_SET_CONFIG_OPTION_METHOD_NAME))]
-#[serde(rename_all = "camelCase")]
-#[non_exhaustive]
-pub struct SetSessionConfigOptionResponse {
-    /// The full set of configuration options and their current values.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub config_options: Vec<SessionConfigOption>,
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
-    ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
-}
 
-impl SetSessionConfigOptionResponse {
-    /// Builds [`SetSessionConfigOptionResponse`] with the required response fields set; optional fields start unset or empty.
+    /// Total cache write tokens.
     #[must_use]
-    pub fn new(config_options: Vec<SessionConfigOption>) -> Self {
-        Self {
-            config_options,
-            meta: None,
-        }
+    pub fn cached_write_tokens(mut self, cached_write_tokens: impl IntoOption<u64>) -> Self {
+        self.cached_write_tokens = cached_write_tokens.into_option();
+        self
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -2860,142 +4863,60 @@ impl SetSessionConfigOptionResponse {
     }
 }
 
-// MCP
+// Providers
 
-/// Configuration for connecting to an MCP (Model Context Protocol) server.
+/// **UNSTABLE**
 ///
-/// MCP servers provide tools and context that the agent can use when
-/// processing prompts.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// See protocol docs: [MCP Servers](https://agentclientprotocol.com/protocol/session-setup#mcp-servers)
+/// Well-known API protocol identifiers for LLM providers.
+///
+/// Agents and clients MUST handle unknown protocol identifiers gracefully.
+///
+/// Protocol names beginning with `_` are free for custom use, like other ACP extension methods.
+/// Protocol names that do not begin with `_` are reserved for the ACP spec.
+#[cfg(feature = "unstable_llm_providers")]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(tag = "type", rename_all = "snake_case")]
-#[schemars(extend("discriminator" = {"propertyName": "type"}))]
+#[serde(rename_all = "snake_case")]
 #[non_exhaustive]
-pub enum McpServer {
-    /// HTTP transport configuration
-    ///
-    /// Only available when the Agent capabilities include `session.mcp.http`.
-    Http(McpServerHttp),
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
-    ///
-    /// ACP transport configuration
-    ///
-    /// Only available when the Agent capabilities include `session.mcp.acp`.
-    /// The MCP server is provided by an ACP component and communicates over the ACP channel.
-    #[cfg(feature = "unstable_mcp_over_acp")]
-    Acp(McpServerAcp),
-    /// Stdio transport configuration
-    ///
-    /// Only available when the Agent capabilities include `session.mcp.stdio`.
-    Stdio(McpServerStdio),
-    /// Custom or future MCP server transport configuration.
+#[expect(clippy::doc_markdown)]
+pub enum LlmProtocol {
+    /// Anthropic API protocol.
+    Anthropic,
+    /// OpenAI API protocol.
+    #[serde(rename = "openai")]
+    OpenAi,
+    /// Azure OpenAI API protocol.
+    Azure,
+    /// Google Vertex AI API protocol.
+    Vertex,
+    /// AWS Bedrock API protocol.
+    Bedrock,
+    /// Custom or future protocol.
     ///
     /// Values beginning with `_` are reserved for implementation-specific
     /// extensions. Unknown values that do not begin with `_` are reserved for
     /// future ACP variants.
-    ///
-    /// Receivers that do not understand this transport should preserve the raw
-    /// payload when storing, replaying, proxying, or forwarding session setup
-    /// data, and otherwise ignore it or reject the server configuration.
     #[serde(untagged)]
-    Other(OtherMcpServer),
-}
-
-/// Custom or future MCP server transport payload.
-#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Eq)]
-#[schemars(inline)]
-#[schemars(transform = other_mcp_server_schema)]
-#[serde(rename_all = "camelCase")]
-#[non_exhaustive]
-pub struct OtherMcpServer {
-    /// Custom or future MCP server transport type.
-    ///
-    /// Values beginning with `_` are reserved for implementation-specific
-    /// extensions. Unknown values that do not begin with `_` are reserved for
-    /// future ACP variants.
-    #[serde(rename = "type")]
-    pub type_: String,
-    /// Additional fields from the unknown MCP server transport payload.
-    #[serde(flatten)]
-    pub fields: BTreeMap<String, serde_json::Value>,
-}
-
-impl OtherMcpServer {
-    /// Builds [`OtherMcpServer`] from an unknown discriminator and preserves the remaining extension fields.
-    #[must_use]
-    pub fn new(type_: impl Into<String>, mut fields: BTreeMap<String, serde_json::Value>) -> Self {
-        fields.remove("type");
-        Self {
-            type_: type_.into(),
-            fields,
-        }
-    }
-}
-
-impl<'de> Deserialize<'de> for OtherMcpServer {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        let mut fields = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
-        let type_ = fields
-            .remove("type")
-            .ok_or_else(|| serde::de::Error::missing_field("type"))?;
-        let serde_json::Value::String(type_) = type_ else {
-            return Err(serde::de::Error::custom("`type` must be a string"));
-        };
-
-        if is_known_mcp_server_type(&type_) {
-            return Err(serde::de::Error::custom(format!(
-                "known MCP server transport `{type_}` did not match its schema"
-            )));
-        }
-
-        Ok(Self { type_, fields })
-    }
-}
-
-fn is_known_mcp_server_type(type_: &str) -> bool {
-    match type_ {
-        "http" | "stdio" => true,
-        #[cfg(feature = "unstable_mcp_over_acp")]
-        "acp" => true,
-        _ => false,
-    }
-}
-
-fn other_mcp_server_schema(schema: &mut Schema) {
-    super::schema_util::reject_known_string_discriminators(
-        schema,
-        "type",
-        &[
-            "http",
-            "stdio",
-            #[cfg(feature = "unstable_mcp_over_acp")]
-            "acp",
-        ],
-    );
+    Other(String),
 }
 
-/// HTTP transport configuration for MCP.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Current effective non-secret routing configuration for a provider.
+#[cfg(feature = "unstable_llm_providers")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
-#[non_exhaustive]
-pub struct McpServerHttp {
-    /// Human-readable name identifying this MCP server.
-    pub name: String,
-    /// URL to the MCP server.
-    pub url: String,
-    /// HTTP headers to set when making requests to the MCP server.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub headers: Vec<HttpHeader>,
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ProviderCurrentConfig {
+    /// Protocol currently used by this provider.
+    pub api_type: LlmProtocol,
+    /// Base URL currently used by this provider.
+    pub base_url: String,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3008,25 +4929,18 @@ pub struct McpServerHttp {
     pub meta: Option<Meta>,
 }
 
-impl McpServerHttp {
-    /// Builds [`McpServerHttp`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_llm_providers")]
+impl ProviderCurrentConfig {
+    /// Builds [`ProviderCurrentConfig`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(name: impl Into<String>, url: impl Into<String>) -> Self {
+    pub fn new(api_type: LlmProtocol, base_url: impl Into<String>) -> Self {
         Self {
-            name: name.into(),
-            url: url.into(),
-            headers: Vec::new(),
+            api_type,
+            base_url: base_url.into(),
             meta: None,
         }
     }
 
-    /// HTTP headers to set when making requests to the MCP server.
-    #[must_use]
-    pub fn headers(mut self, headers: Vec<HttpHeader>) -> Self {
-        self.headers = headers;
-        self
-    }
-
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3043,21 +4957,17 @@ impl McpServerHttp {
 ///
 /// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// Unique identifier for an MCP server using the ACP transport.
-///
-/// The value is opaque and generated by the ACP component providing the MCP server. It is
-/// used by `mcp/connect` to route connection requests back to the component that declared the
-/// server.
-#[cfg(feature = "unstable_mcp_over_acp")]
+/// Unique identifier for a configurable LLM provider.
+#[cfg(feature = "unstable_llm_providers")]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
 #[serde(transparent)]
 #[from(Arc<str>, String, &'static str)]
 #[non_exhaustive]
-pub struct McpServerAcpId(pub Arc<str>);
+pub struct ProviderId(pub Arc<str>);
 
-#[cfg(feature = "unstable_mcp_over_acp")]
-impl McpServerAcpId {
-    /// Wraps a protocol string as a typed [`McpServerAcpId`].
+#[cfg(feature = "unstable_llm_providers")]
+impl ProviderId {
+    /// Wraps a protocol string as a typed [`ProviderId`].
     #[must_use]
     pub fn new(id: impl Into<Arc<str>>) -> Self {
         Self(id.into())
@@ -3068,24 +4978,29 @@ impl McpServerAcpId {
 ///
 /// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// ACP transport configuration for MCP.
-///
-/// The MCP server is provided by an ACP component and communicates over the ACP channel
-/// using `mcp/connect`, `mcp/message`, and `mcp/disconnect`.
+/// Information about a configurable LLM provider.
+#[cfg(feature = "unstable_llm_providers")]
 #[serde_as]
 #[skip_serializing_none]
-#[cfg(feature = "unstable_mcp_over_acp")]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct McpServerAcp {
-    /// Human-readable name identifying this MCP server.
-    pub name: String,
-    /// Unique identifier for this MCP server, generated by the component providing it.
-    ///
-    /// Providers MUST NOT reuse an ID for multiple ACP-transport MCP servers that are visible
-    /// on the same ACP connection.
-    pub server_id: McpServerAcpId,
+pub struct ProviderInfo {
+    /// Provider identifier, for example "main" or "openai".
+    pub provider_id: ProviderId,
+    /// Supported protocol types for this provider.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub supported: Vec<LlmProtocol>,
+    /// Whether this provider is mandatory and cannot be disabled via `providers/disable`.
+    /// If true, clients must not call `providers/disable` for this provider ID.
+    pub required: bool,
+    /// Current effective non-secret routing config.
+    /// Null or omitted means provider is disabled.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub current: Option<ProviderCurrentConfig>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3098,14 +5013,21 @@ pub struct McpServerAcp {
     pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_mcp_over_acp")]
-impl McpServerAcp {
-    /// Builds [`McpServerAcp`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_llm_providers")]
+impl ProviderInfo {
+    /// Builds [`ProviderInfo`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(name: impl Into<String>, server_id: impl Into<McpServerAcpId>) -> Self {
+    pub fn new(
+        provider_id: impl Into<ProviderId>,
+        supported: Vec<LlmProtocol>,
+        required: bool,
+        current: impl IntoOption<ProviderCurrentConfig>,
+    ) -> Self {
         Self {
-            name: name.into(),
-            server_id: server_id.into(),
+            provider_id: provider_id.into(),
+            supported,
+            required,
+            current: current.into_option(),
             meta: None,
         }
     }
@@ -3122,27 +5044,19 @@ impl McpServerAcp {
     }
 }
 
-/// Stdio transport configuration for MCP.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request parameters for `providers/list`.
+#[cfg(feature = "unstable_llm_providers")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_LIST_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct McpServerStdio {
-    /// Human-readable name identifying this MCP server.
-    pub name: String,
-    /// Absolute path to the MCP server executable.
-    pub command: PathBuf,
-    /// Command-line arguments to pass to the MCP server.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub args: Vec<String>,
-    /// Environment variables to set when launching the MCP server.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub env: Vec<EnvVariable>,
+pub struct ListProvidersRequest {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3155,31 +5069,64 @@ pub struct McpServerStdio {
     pub meta: Option<Meta>,
 }
 
-impl McpServerStdio {
-    /// Builds [`McpServerStdio`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_llm_providers")]
+impl ListProvidersRequest {
+    /// Builds [`ListProvidersRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(name: impl Into<String>, command: impl Into<PathBuf>) -> Self {
-        Self {
-            name: name.into(),
-            command: command.into(),
-            args: Vec::new(),
-            env: Vec::new(),
-            meta: None,
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    /// Command-line arguments to pass to the MCP server.
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn args(mut self, args: Vec<String>) -> Self {
-        self.args = args;
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
         self
     }
+}
 
-    /// Environment variables to set when launching the MCP server.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response to `providers/list`.
+#[cfg(feature = "unstable_llm_providers")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_LIST_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ListProvidersResponse {
+    /// Configurable providers with current routing info suitable for UI display.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub providers: Vec<ProviderInfo>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_llm_providers")]
+impl ListProvidersResponse {
+    /// Builds [`ListProvidersResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn env(mut self, env: Vec<EnvVariable>) -> Self {
-        self.env = env;
-        self
+    pub fn new(providers: Vec<ProviderInfo>) -> Self {
+        Self {
+            providers,
+            meta: None,
+        }
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -3194,17 +5141,33 @@ impl McpServerStdio {
     }
 }
 
-/// An environment variable to set when launching an MCP server.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Request parameters for `providers/set`.
+///
+/// Replaces the full configuration for one provider ID.
+#[cfg(feature = "unstable_llm_providers")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_SET_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct EnvVariable {
-    /// The name of the environment variable.
-    pub name: String,
-    /// The value to set for the environment variable.
-    pub value: String,
+pub struct SetProviderRequest {
+    /// Provider ID to configure.
+    pub provider_id: ProviderId,
+    /// Protocol type for this provider.
+    pub api_type: LlmProtocol,
+    /// Base URL for requests sent through this provider.
+    pub base_url: String,
+    /// Full headers map for this provider.
+    /// May include authorization, routing, or other integration-specific headers.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3217,17 +5180,32 @@ pub struct EnvVariable {
     pub meta: Option<Meta>,
 }
 
-impl EnvVariable {
-    /// Builds [`EnvVariable`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_llm_providers")]
+impl SetProviderRequest {
+    /// Builds [`SetProviderRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+    pub fn new(
+        provider_id: impl Into<ProviderId>,
+        api_type: LlmProtocol,
+        base_url: impl Into<String>,
+    ) -> Self {
         Self {
-            name: name.into(),
-            value: value.into(),
+            provider_id: provider_id.into(),
+            api_type,
+            base_url: base_url.into(),
+            headers: HashMap::new(),
             meta: None,
         }
     }
 
+    /// Full headers map for this provider.
+    /// May include authorization, routing, or other integration-specific headers.
+    #[must_use]
+    pub fn headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.headers = headers;
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3240,17 +5218,19 @@ impl EnvVariable {
     }
 }
 
-/// An HTTP header to set when making requests to the MCP server.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Response to `providers/set`.
+#[cfg(feature = "unstable_llm_providers")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_SET_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct HttpHeader {
-    /// The name of the HTTP header.
-    pub name: String,
-    /// The value to set for the HTTP header.
-    pub value: String,
+pub struct SetProviderResponse {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3263,15 +5243,12 @@ pub struct HttpHeader {
     pub meta: Option<Meta>,
 }
 
-impl HttpHeader {
-    /// Builds [`HttpHeader`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_llm_providers")]
+impl SetProviderResponse {
+    /// Builds [`SetProviderResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
-        Self {
-            name: name.into(),
-            value: value.into(),
-            meta: None,
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -3286,38 +5263,21 @@ impl HttpHeader {
     }
 }
 
-// Prompt
-
-/// Request parameters for sending a user prompt to the agent.
+/// **UNSTABLE**
 ///
-/// Contains the user's message and any additional context.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// See protocol docs: [User Message](https://agentclientprotocol.com/protocol/prompt-lifecycle#1-user-message)
+/// Request parameters for `providers/disable`.
+#[cfg(feature = "unstable_llm_providers")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_PROMPT_METHOD_NAME))]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_DISABLE_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct PromptRequest {
-    /// The ID of the session to send this user message to
-    pub session_id: SessionId,
-    /// The blocks of content that compose the user's message.
-    ///
-    /// As a baseline, the Agent MUST support [`ContentBlock::Text`] and [`ContentBlock::ResourceLink`],
-    /// while other variants are optionally enabled via [`PromptCapabilities`].
-    ///
-    /// The Client MUST adapt its interface according to [`PromptCapabilities`].
-    ///
-    /// The client MAY include referenced pieces of context as either
-    /// [`ContentBlock::Resource`] or [`ContentBlock::ResourceLink`].
-    ///
-    /// When available, [`ContentBlock::Resource`] is preferred
-    /// as it avoids extra round-trips and allows the message to include
-    /// pieces of context from sources the agent may not have access to.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub prompt: Vec<ContentBlock>,
+pub struct DisableProviderRequest {
+    /// Provider ID to disable.
+    pub provider_id: ProviderId,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3330,13 +5290,13 @@ pub struct PromptRequest {
     pub meta: Option<Meta>,
 }
 
-impl PromptRequest {
-    /// Builds [`PromptRequest`] with the required request fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_llm_providers")]
+impl DisableProviderRequest {
+    /// Builds [`DisableProviderRequest`] with the required request fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(session_id: impl Into<SessionId>, prompt: Vec<ContentBlock>) -> Self {
+    pub fn new(provider_id: impl Into<ProviderId>) -> Self {
         Self {
-            session_id: session_id.into(),
-            prompt,
+            provider_id: provider_id.into(),
             meta: None,
         }
     }
@@ -3353,19 +5313,19 @@ impl PromptRequest {
     }
 }
 
-/// Response acknowledging that a user prompt was accepted.
+/// **UNSTABLE**
 ///
-/// This response does not indicate that the agent has finished processing.
-/// Agents report session state through `state_update` session updates.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// See protocol docs: [Prompt Accepted](https://agentclientprotocol.com/protocol/prompt-lifecycle#2-prompt-accepted)
+/// Response to `providers/disable`.
+#[cfg(feature = "unstable_llm_providers")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = SESSION_PROMPT_METHOD_NAME))]
+#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_DISABLE_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct PromptResponse {
+pub struct DisableProviderResponse {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3378,8 +5338,9 @@ pub struct PromptResponse {
     pub meta: Option<Meta>,
 }
 
-impl PromptResponse {
-    /// Builds [`PromptResponse`] with the required response fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_llm_providers")]
+impl DisableProviderResponse {
+    /// Builds [`DisableProviderResponse`] with the required response fields set; optional fields start unset or empty.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -3397,72 +5358,106 @@ impl PromptResponse {
     }
 }
 
-/// Reasons why an agent stops active session work.
-///
-/// See protocol docs: [Stop Reasons](https://agentclientprotocol.com/protocol/prompt-lifecycle#stop-reasons)
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
-#[serde(rename_all = "snake_case")]
-#[non_exhaustive]
-pub enum StopReason {
-    /// The active work ended successfully.
-    EndTurn,
-    /// The active work ended because the agent reached the maximum number of tokens.
-    MaxTokens,
-    /// The active work ended because the agent reached the maximum number of
-    /// allowed agent requests before returning idle.
-    MaxTurnRequests,
-    /// The active work ended because the agent refused to continue. The user
-    /// prompt and everything that comes after it won't be included in the next
-    /// prompt, so this should be reflected in the UI.
-    Refusal,
-    /// Active session work was cancelled by the client via `session/cancel`.
-    ///
-    /// Agents should report this stop reason on an idle `state_update` session update
-    /// when cancellation succeeds, even if cancellation causes exceptions in
-    /// underlying operations.
-    Cancelled,
-    /// Custom or future stop reason.
-    ///
-    /// Values beginning with `_` are reserved for implementation-specific
-    /// extensions. Unknown values that do not begin with `_` are reserved for
-    /// future ACP variants.
-    #[serde(untagged)]
-    Other(String),
-}
+// Capabilities
 
-/// **UNSTABLE**
+/// Capabilities supported by the agent.
 ///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
+/// Advertised during initialization to inform the client about
+/// available features and content types.
 ///
-/// Token usage information for completed session work.
-#[cfg(feature = "unstable_end_turn_token_usage")]
+/// See protocol docs: [Agent Capabilities](https://agentclientprotocol.com/protocol/initialization#agent-capabilities)
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct Usage {
-    /// Sum of all token types across session.
-    pub total_tokens: u64,
-    /// Total input tokens.
-    pub input_tokens: u64,
-    /// Total output tokens.
-    pub output_tokens: u64,
-    /// Total thought/reasoning tokens
+pub struct AgentCapabilities {
+    /// Session capabilities supported by the agent.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not support the
+    /// `session/*` method surface. Supplying `{}` means the agent supports the
+    /// baseline session methods: `session/new`, `session/prompt`,
+    /// `session/cancel`, and `session/update`.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub thought_tokens: Option<u64>,
-    /// Total cache read tokens.
+    pub session: Option<SessionCapabilities>,
+    /// Authentication-related capabilities supported by the agent.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise any
+    /// authentication-related extensions.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub cached_read_tokens: Option<u64>,
-    /// Total cache write tokens.
+    pub auth: Option<AgentAuthCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent processes several `session/prompt` requests for the
+    /// same session at once.
+    ///
+    /// Optional. Omitted or `null` both mean prompts are processed one at a
+    /// time, and a prompt that arrives while another one is in flight is
+    /// rejected with a `SessionBusy` error. Supplying `{}` means the agent
+    /// accepts concurrent prompts.
+    #[cfg(feature = "unstable_concurrent_prompts")]
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub cached_write_tokens: Option<u64>,
+    pub concurrent_prompts: Option<ConcurrentPromptsCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Provider configuration capabilities supported by the agent.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports provider configuration methods.
+    #[cfg(feature = "unstable_llm_providers")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub providers: Option<ProvidersCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// NES (Next Edit Suggestions) capabilities supported by the agent.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support
+    /// for NES methods.
+    #[cfg(feature = "unstable_nes")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub nes: Option<NesCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The position encoding selected by the agent from the client's supported encodings.
+    #[cfg(feature = "unstable_nes")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub position_encoding: Option<PositionEncodingKind>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// MIME types the agent accepts in prompt content, such as `image/png` or
+    /// `audio/wav`.
+    ///
+    /// An entry may use `*` for the subtype, such as `image/*`, to accept every
+    /// subtype. Clients SHOULD NOT send images, audio or embedded resources of
+    /// other types. An empty list means the agent does not advertise any, and
+    /// clients fall back to `session.prompt`.
+    #[cfg(feature = "unstable_accepted_mime_types")]
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accepted_mime_types: Vec<String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3475,41 +5470,156 @@ pub struct Usage {
     pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_end_turn_token_usage")]
-impl Usage {
-    /// Builds [`Usage`] with the required fields set; optional fields start unset or empty.
+impl AgentCapabilities {
+    /// Builds an empty [`AgentCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
-    pub fn new(total_tokens: u64, input_tokens: u64, output_tokens: u64) -> Self {
-        Self {
-            total_tokens,
-            input_tokens,
-            output_tokens,
-            thought_tokens: None,
-            cached_read_tokens: None,
-            cached_write_tokens: None,
-            meta: None,
-        }
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Session capabilities supported by the agent.
+    ///
+    /// Omitted or `null` both mean the agent does not support the `session/*`
+    /// method surface. Supplying `{}` means the agent supports the baseline
+    /// session methods: `session/new`, `session/prompt`, `session/cancel`, and
+    /// `session/update`.
+    #[must_use]
+    pub fn session(mut self, session: impl IntoOption<SessionCapabilities>) -> Self {
+        self.session = session.into_option();
+        self
+    }
+
+    /// Authentication-related capabilities supported by the agent.
+    #[must_use]
+    pub fn auth(mut self, auth: impl IntoOption<AgentAuthCapabilities>) -> Self {
+        self.auth = auth.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent processes several `session/prompt` requests for the
+    /// same session at once.
+    ///
+    /// Omitted or `null` both mean prompts are processed one at a time.
+    /// Supplying `{}` means the agent accepts concurrent prompts.
+    #[cfg(feature = "unstable_concurrent_prompts")]
+    #[must_use]
+    pub fn concurrent_prompts(
+        mut self,
+        concurrent_prompts: impl IntoOption<ConcurrentPromptsCapabilities>,
+    ) -> Self {
+        self.concurrent_prompts = concurrent_prompts.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Provider configuration capabilities supported by the agent.
+    #[cfg(feature = "unstable_llm_providers")]
+    #[must_use]
+    pub fn providers(mut self, providers: impl IntoOption<ProvidersCapabilities>) -> Self {
+        self.providers = providers.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// NES (Next Edit Suggestions) capabilities supported by the agent.
+    #[cfg(feature = "unstable_nes")]
+    #[must_use]
+    pub fn nes(mut self, nes: impl IntoOption<NesCapabilities>) -> Self {
+        self.nes = nes.into_option();
+        self
     }
 
-    /// Total thought/reasoning tokens
+    /// **UNSTABLE**
+    ///
+    /// The position encoding selected by the agent from the client's supported encodings.
+    #[cfg(feature = "unstable_nes")]
     #[must_use]
-    pub fn thought_tokens(mut self, thought_tokens: impl IntoOption<u64>) -> Self {
-        self.thought_tokens = thought_tokens.into_option();
+    pub fn position_encoding(
+        mut self,
+        position_encoding: impl IntoOption<PositionEncodingKind>,
+    ) -> Self {
+        self.position_encoding = position_encoding.into_option();
         self
     }
 
-    /// Total cache read tokens.
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// MIME types the agent accepts in prompt content, such as `image/png` or
+    /// `image/*`.
+    #[cfg(feature = "unstable_accepted_mime_types")]
     #[must_use]
-    pub fn cached_read_tokens(mut self, cached_read_tokens: impl IntoOption<u64>) -> Self {
-        self.cached_read_tokens = cached_read_tokens.into_option();
+    pub fn accepted_mime_types(mut self, accepted_mime_types: Vec<String>) -> Self {
+        self.accepted_mime_types = accepted_mime_types;
         self
     }
 
-    /// Total cache write tokens.
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Checks whether a new `session/prompt` for `session_id` may start while
+    /// `in_flight` prompts are still being processed in that session.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::session_busy`](super::Error::session_busy) when a prompt
+    /// is in flight and the agent does not advertise `concurrentPrompts`.
+    #[cfg(feature = "unstable_concurrent_prompts")]
+    pub fn check_prompt_admission(
+        &self,
+        session_id: &SessionId,
+        in_flight: usize,
+    ) -> Result<(), super::Error> {
+        if in_flight > 0 && self.concurrent_prompts.is_none() {
+            return Err(super::Error::session_busy(session_id.clone()));
+        }
+        Ok(())
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether `mime` matches one of [`Self::accepted_mime_types`].
+    ///
+    /// Matching ignores case and any parameters such as `; charset=utf-8`. An
+    /// entry whose subtype is `*`, such as `image/*`, matches every subtype of
+    /// that type, and `*/*` matches everything.
+    ///
+    /// Returns `None` when the agent does not advertise any MIME types, in
+    /// which case callers fall back to `promptCapabilities`.
+    #[cfg(feature = "unstable_accepted_mime_types")]
     #[must_use]
-    pub fn cached_write_tokens(mut self, cached_write_tokens: impl IntoOption<u64>) -> Self {
-        self.cached_write_tokens = cached_write_tokens.into_option();
-        self
+    pub fn accepts(&self, mime: &str) -> Option<bool> {
+        fn split(mime: &str) -> Option<(&str, &str)> {
+            let essence = mime.split(';').next().unwrap_or_default().trim();
+            essence.split_once('/')
+        }
+
+        if self.accepted_mime_types.is_empty() {
+            return None;
+        }
+        let Some((kind, subtype)) = split(mime) else {
+            return Some(false);
+        };
+        Some(self.accepted_mime_types.iter().any(|accepted| {
+            split(accepted).is_some_and(|(accepted_kind, accepted_subtype)| {
+                (accepted_kind == "*" || accepted_kind.eq_ignore_ascii_case(kind))
+                    && (accepted_subtype == "*" || accepted_subtype.eq_ignore_ascii_case(subtype))
+            })
+        }))
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -3524,60 +5634,19 @@ impl Usage {
     }
 }
 
-// Providers
-
 /// **UNSTABLE**
 ///
 /// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// Well-known API protocol identifiers for LLM providers.
-///
-/// Agents and clients MUST handle unknown protocol identifiers gracefully.
-///
-/// Protocol names beginning with `_` are free for custom use, like other ACP extension methods.
-/// Protocol names that do not begin with `_` are reserved for the ACP spec.
-#[cfg(feature = "unstable_llm_providers")]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "snake_case")]
-#[non_exhaustive]
-#[expect(clippy::doc_markdown)]
-pub enum LlmProtocol {
-    /// Anthropic API protocol.
-    Anthropic,
-    /// OpenAI API protocol.
-    #[serde(rename = "openai")]
-    OpenAi,
-    /// Azure OpenAI API protocol.
-    Azure,
-    /// Google Vertex AI API protocol.
-    Vertex,
-    /// AWS Bedrock API protocol.
-    Bedrock,
-    /// Custom or future protocol.
-    ///
-    /// Values beginning with `_` are reserved for implementation-specific
-    /// extensions. Unknown values that do not begin with `_` are reserved for
-    /// future ACP variants.
-    #[serde(untagged)]
-    Other(String),
-}
-
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
+/// Provider configuration capabilities supported by the agent.
 ///
-/// Current effective non-secret routing configuration for a provider.
+/// Supplying `{}` means the agent supports provider configuration methods.
 #[cfg(feature = "unstable_llm_providers")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct ProviderCurrentConfig {
-    /// Protocol currently used by this provider.
-    pub api_type: LlmProtocol,
-    /// Base URL currently used by this provider.
-    pub base_url: String,
+pub struct ProvidersCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3591,15 +5660,11 @@ pub struct ProviderCurrentConfig {
 }
 
 #[cfg(feature = "unstable_llm_providers")]
-impl ProviderCurrentConfig {
-    /// Builds [`ProviderCurrentConfig`] with the required fields set; optional fields start unset or empty.
+impl ProvidersCapabilities {
+    /// Builds an empty [`ProvidersCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
-    pub fn new(api_type: LlmProtocol, base_url: impl Into<String>) -> Self {
-        Self {
-            api_type,
-            base_url: base_url.into(),
-            meta: None,
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -3618,50 +5683,198 @@ impl ProviderCurrentConfig {
 ///
 /// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// Unique identifier for a configurable LLM provider.
-#[cfg(feature = "unstable_llm_providers")]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
-#[serde(transparent)]
-#[from(Arc<str>, String, &'static str)]
+/// Capabilities for processing several prompts in one session at once.
+///
+/// Supplying `{}` means the agent accepts concurrent prompts.
+#[cfg(feature = "unstable_concurrent_prompts")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct ProviderId(pub Arc<str>);
+pub struct ConcurrentPromptsCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
 
-#[cfg(feature = "unstable_llm_providers")]
-impl ProviderId {
-    /// Wraps a protocol string as a typed [`ProviderId`].
+#[cfg(feature = "unstable_concurrent_prompts")]
+impl ConcurrentPromptsCapabilities {
+    /// Builds an empty [`ConcurrentPromptsCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
-    pub fn new(id: impl Into<Arc<str>>) -> Self {
-        Self(id.into())
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
     }
 }
 
-/// **UNSTABLE**
+/// Session capabilities supported by the agent.
 ///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
+/// Supplying `{}` means the agent supports the baseline session methods:
+/// `session/new`, `session/prompt`, `session/cancel`, and `session/update`.
 ///
-/// Information about a configurable LLM provider.
-#[cfg(feature = "unstable_llm_providers")]
+/// Agents that support sessions **MAY** support additional session methods,
+/// prompt content types, and MCP transports by specifying additional
+/// capabilities.
+///
+/// See protocol docs: [Session Capabilities](https://agentclientprotocol.com/protocol/initialization#session-capabilities)
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct ProviderInfo {
-    /// Provider identifier, for example "main" or "openai".
-    pub provider_id: ProviderId,
-    /// Supported protocol types for this provider.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub supported: Vec<LlmProtocol>,
-    /// Whether this provider is mandatory and cannot be disabled via `providers/disable`.
-    /// If true, clients must not call `providers/disable` for this provider ID.
-    pub required: bool,
-    /// Current effective non-secret routing config.
-    /// Null or omitted means provider is disabled.
+pub struct SessionCapabilities {
+    /// Prompt capabilities supported by the agent in `session/prompt` requests.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise any
+    /// prompt extensions beyond the baseline text and resource-link content
+    /// required by `session/prompt`.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub prompt: Option<PromptCapabilities>,
+    /// MCP capabilities supported by the agent for session lifecycle requests.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise MCP
+    /// server transport support for sessions.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub mcp: Option<McpCapabilities>,
+    /// Whether the agent supports `session/load`.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports loading sessions.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub load: Option<SessionLoadCapabilities>,
+    /// Whether the agent supports `session/list`.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports listing sessions.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub list: Option<SessionListCapabilities>,
+    /// Whether the agent supports `session/delete`.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports deleting sessions from `session/list`.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub delete: Option<SessionDeleteCapabilities>,
+    /// Whether the agent supports `additionalDirectories` on supported session lifecycle requests.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports `additionalDirectories` on
+    /// supported session lifecycle requests.
+    ///
+    /// Agents that also support `session/list` may return
+    /// `SessionInfo.additionalDirectories` to report the complete ordered
+    /// additional-root list associated with a listed session.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub additional_directories: Option<SessionAdditionalDirectoriesCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent supports `session/fork`.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports forking sessions.
+    #[cfg(feature = "unstable_session_fork")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub fork: Option<SessionForkCapabilities>,
+    /// Whether the agent supports `session/resume`.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports resuming sessions.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub resume: Option<SessionResumeCapabilities>,
+    /// Whether the agent supports `session/close`.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports closing sessions.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default)]
-    pub current: Option<ProviderCurrentConfig>,
+    pub close: Option<SessionCloseCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent supports `session/compact`.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports compacting sessions.
+    #[cfg(feature = "unstable_session_compact")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub compact: Option<SessionCompactCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent supports `session/set_title`.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports renaming sessions.
+    #[cfg(feature = "unstable_session_set_title")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub set_title: Option<SessionSetTitleCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent supports `session/cancel_tool_call`.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports cancelling individual tool calls.
+    #[cfg(feature = "unstable_cancel_tool_call")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub cancel_tool_call: Option<SessionCancelToolCallCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent pages the history it replays on `session/load`.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent honors `cursor` and `limit` on `session/load`.
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub load_pagination: Option<SessionLoadPaginationCapabilities>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3674,292 +5887,177 @@ pub struct ProviderInfo {
     pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl ProviderInfo {
-    /// Builds [`ProviderInfo`] with the required fields set; optional fields start unset or empty.
+impl SessionCapabilities {
+    /// Builds an empty [`SessionCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
-    pub fn new(
-        provider_id: impl Into<ProviderId>,
-        supported: Vec<LlmProtocol>,
-        required: bool,
-        current: impl IntoOption<ProviderCurrentConfig>,
-    ) -> Self {
-        Self {
-            provider_id: provider_id.into(),
-            supported,
-            required,
-            current: current.into_option(),
-            meta: None,
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+    /// Prompt capabilities supported by the agent in `session/prompt` requests.
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    /// Omitted or `null` both mean the agent does not advertise any prompt
+    /// extensions beyond the baseline text and resource-link content required by
+    /// `session/prompt`.
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
+    pub fn prompt(mut self, prompt: impl IntoOption<PromptCapabilities>) -> Self {
+        self.prompt = prompt.into_option();
         self
     }
-}
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// Request parameters for `providers/list`.
-#[cfg(feature = "unstable_llm_providers")]
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_LIST_METHOD_NAME))]
-#[serde(rename_all = "camelCase")]
-#[non_exhaustive]
-pub struct ListProvidersRequest {
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+    /// MCP capabilities supported by the agent for session lifecycle requests.
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
-}
-
-#[cfg(feature = "unstable_llm_providers")]
-impl ListProvidersRequest {
-    /// Builds [`ListProvidersRequest`] with the required request fields set; optional fields start unset or empty.
+    /// Omitted or `null` both mean the agent does not advertise MCP server
+    /// transport support for sessions.
     #[must_use]
-    pub fn new() -> Self {
-        Self::default()
+    pub fn mcp(mut self, mcp: impl IntoOption<McpCapabilities>) -> Self {
+        self.mcp = mcp.into_option();
+        self
     }
 
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+    /// Whether the agent supports `session/load`.
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports loading sessions.
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
+    pub fn load(mut self, load: impl IntoOption<SessionLoadCapabilities>) -> Self {
+        self.load = load.into_option();
         self
     }
-}
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// Response to `providers/list`.
-#[cfg(feature = "unstable_llm_providers")]
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_LIST_METHOD_NAME))]
-#[serde(rename_all = "camelCase")]
-#[non_exhaustive]
-pub struct ListProvidersResponse {
-    /// Configurable providers with current routing info suitable for UI display.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub providers: Vec<ProviderInfo>,
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+    /// Whether the agent supports `session/list`.
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
-}
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports listing sessions.
+    #[must_use]
+    pub fn list(mut self, list: impl IntoOption<SessionListCapabilities>) -> Self {
+        self.list = list.into_option();
+        self
+    }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl ListProvidersResponse {
-    /// Builds [`ListProvidersResponse`] with the required response fields set; optional fields start unset or empty.
+    /// Whether the agent supports `session/delete`.
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports deleting sessions from `session/list`.
     #[must_use]
-    pub fn new(providers: Vec<ProviderInfo>) -> Self {
-        Self {
-            providers,
-            meta: None,
-        }
+    pub fn delete(mut self, delete: impl IntoOption<SessionDeleteCapabilities>) -> Self {
+        self.delete = delete.into_option();
+        self
     }
 
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+    /// Whether the agent supports `additionalDirectories` on supported session lifecycle requests.
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports `additionalDirectories` on
+    /// supported session lifecycle requests.
+    ///
+    /// Agents that also support `session/list` may return
+    /// `SessionInfo.additionalDirectories` to report the complete ordered
+    /// additional-root list associated with a listed session.
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
+    pub fn additional_directories(
+        mut self,
+        additional_directories: impl IntoOption<SessionAdditionalDirectoriesCapabilities>,
+    ) -> Self {
+        self.additional_directories = additional_directories.into_option();
         self
     }
-}
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// Request parameters for `providers/set`.
-///
-/// Replaces the full configuration for one provider ID.
-#[cfg(feature = "unstable_llm_providers")]
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_SET_METHOD_NAME))]
-#[serde(rename_all = "camelCase")]
-#[non_exhaustive]
-pub struct SetProviderRequest {
-    /// Provider ID to configure.
-    pub provider_id: ProviderId,
-    /// Protocol type for this provider.
-    pub api_type: LlmProtocol,
-    /// Base URL for requests sent through this provider.
-    pub base_url: String,
-    /// Full headers map for this provider.
-    /// May include authorization, routing, or other integration-specific headers.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub headers: HashMap<String, String>,
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+    #[cfg(feature = "unstable_session_fork")]
+    /// Whether the agent supports `session/fork`.
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
-}
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports forking sessions.
+    #[must_use]
+    pub fn fork(mut self, fork: impl IntoOption<SessionForkCapabilities>) -> Self {
+        self.fork = fork.into_option();
+        self
+    }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl SetProviderRequest {
-    /// Builds [`SetProviderRequest`] with the required request fields set; optional fields start unset or empty.
+    /// Whether the agent supports `session/resume`.
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports resuming sessions.
     #[must_use]
-    pub fn new(
-        provider_id: impl Into<ProviderId>,
-        api_type: LlmProtocol,
-        base_url: impl Into<String>,
-    ) -> Self {
-        Self {
-            provider_id: provider_id.into(),
-            api_type,
-            base_url: base_url.into(),
-            headers: HashMap::new(),
-            meta: None,
-        }
+    pub fn resume(mut self, resume: impl IntoOption<SessionResumeCapabilities>) -> Self {
+        self.resume = resume.into_option();
+        self
     }
 
-    /// Full headers map for this provider.
-    /// May include authorization, routing, or other integration-specific headers.
+    /// Whether the agent supports `session/close`.
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports closing sessions.
     #[must_use]
-    pub fn headers(mut self, headers: HashMap<String, String>) -> Self {
-        self.headers = headers;
+    pub fn close(mut self, close: impl IntoOption<SessionCloseCapabilities>) -> Self {
+        self.close = close.into_option();
         self
     }
 
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+    /// **UNSTABLE**
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent supports `session/compact`.
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports compacting sessions.
+    #[cfg(feature = "unstable_session_compact")]
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
+    pub fn compact(mut self, compact: impl IntoOption<SessionCompactCapabilities>) -> Self {
+        self.compact = compact.into_option();
         self
     }
-}
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// Response to `providers/set`.
-#[cfg(feature = "unstable_llm_providers")]
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_SET_METHOD_NAME))]
-#[serde(rename_all = "camelCase")]
-#[non_exhaustive]
-pub struct SetProviderResponse {
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+    /// **UNSTABLE**
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
-}
-
-#[cfg(feature = "unstable_llm_providers")]
-impl SetProviderResponse {
-    /// Builds [`SetProviderResponse`] with the required response fields set; optional fields start unset or empty.
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent supports `session/set_title`.
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports renaming sessions.
+    #[cfg(feature = "unstable_session_set_title")]
     #[must_use]
-    pub fn new() -> Self {
-        Self::default()
+    pub fn set_title(mut self, set_title: impl IntoOption<SessionSetTitleCapabilities>) -> Self {
+        self.set_title = set_title.into_option();
+        self
     }
 
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+    /// **UNSTABLE**
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent supports `session/cancel_tool_call`.
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports cancelling individual tool calls.
+    #[cfg(feature = "unstable_cancel_tool_call")]
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
+    pub fn cancel_tool_call(
+        mut self,
+        cancel_tool_call: impl IntoOption<SessionCancelToolCallCapabilities>,
+    ) -> Self {
+        self.cancel_tool_call = cancel_tool_call.into_option();
         self
     }
-}
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// Request parameters for `providers/disable`.
-#[cfg(feature = "unstable_llm_providers")]
-#[serde_as]
-#[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_DISABLE_METHOD_NAME))]
-#[serde(rename_all = "camelCase")]
-#[non_exhaustive]
-pub struct DisableProviderRequest {
-    /// Provider ID to disable.
-    pub provider_id: ProviderId,
-    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
-    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
-    /// these keys.
+    /// **UNSTABLE**
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
-}
-
-#[cfg(feature = "unstable_llm_providers")]
-impl DisableProviderRequest {
-    /// Builds [`DisableProviderRequest`] with the required request fields set; optional fields start unset or empty.
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the agent pages the history it replays on `session/load`.
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent honors `cursor` and `limit` on `session/load`.
+    #[cfg(feature = "unstable_session_load_pagination")]
     #[must_use]
-    pub fn new(provider_id: impl Into<ProviderId>) -> Self {
-        Self {
-            provider_id: provider_id.into(),
-            meta: None,
-        }
+    pub fn load_pagination(
+        mut self,
+        load_pagination: impl IntoOption<SessionLoadPaginationCapabilities>,
+    ) -> Self {
+        self.load_pagination = load_pagination.into_option();
+        self
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -3974,19 +6072,14 @@ impl DisableProviderRequest {
     }
 }
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
+/// Capabilities for the `session/load` method.
 ///
-/// Response to `providers/disable`.
-#[cfg(feature = "unstable_llm_providers")]
+/// Supplying `{}` means the agent supports loading sessions.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "agent", "x-method" = PROVIDERS_DISABLE_METHOD_NAME))]
-#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct DisableProviderResponse {
+pub struct SessionLoadCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -3999,9 +6092,8 @@ pub struct DisableProviderResponse {
     pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl DisableProviderResponse {
-    /// Builds [`DisableProviderResponse`] with the required response fields set; optional fields start unset or empty.
+impl SessionLoadCapabilities {
+    /// Builds an empty [`SessionLoadCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -4019,147 +6111,31 @@ impl DisableProviderResponse {
     }
 }
 
-// Capabilities
-
-/// Capabilities supported by the agent.
-///
-/// Advertised during initialization to inform the client about
-/// available features and content types.
+/// Capabilities for the `session/list` method.
 ///
-/// See protocol docs: [Agent Capabilities](https://agentclientprotocol.com/protocol/initialization#agent-capabilities)
+/// Supplying `{}` means the agent supports listing sessions.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct AgentCapabilities {
-    /// Session capabilities supported by the agent.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not support the
-    /// `session/*` method surface. Supplying `{}` means the agent supports the
-    /// baseline session methods: `session/new`, `session/prompt`,
-    /// `session/cancel`, and `session/update`.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub session: Option<SessionCapabilities>,
-    /// Authentication-related capabilities supported by the agent.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise any
-    /// authentication-related extensions.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub auth: Option<AgentAuthCapabilities>,
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
-    ///
-    /// Provider configuration capabilities supported by the agent.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports provider configuration methods.
-    #[cfg(feature = "unstable_llm_providers")]
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub providers: Option<ProvidersCapabilities>,
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
-    ///
-    /// NES (Next Edit Suggestions) capabilities supported by the agent.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support
-    /// for NES methods.
-    #[cfg(feature = "unstable_nes")]
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub nes: Option<NesCapabilities>,
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
-    ///
-    /// The position encoding selected by the agent from the client's supported encodings.
-    #[cfg(feature = "unstable_nes")]
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub position_encoding: Option<PositionEncodingKind>,
+pub struct SessionListCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
-}
-
-impl AgentCapabilities {
-    /// Builds an empty [`AgentCapabilities`]; use builder methods to advertise supported sub-capabilities.
-    #[must_use]
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    /// Session capabilities supported by the agent.
-    ///
-    /// Omitted or `null` both mean the agent does not support the `session/*`
-    /// method surface. Supplying `{}` means the agent supports the baseline
-    /// session methods: `session/new`, `session/prompt`, `session/cancel`, and
-    /// `session/update`.
-    #[must_use]
-    pub fn session(mut self, session: impl IntoOption<SessionCapabilities>) -> Self {
-        self.session = session.into_option();
-        self
-    }
-
-    /// Authentication-related capabilities supported by the agent.
-    #[must_use]
-    pub fn auth(mut self, auth: impl IntoOption<AgentAuthCapabilities>) -> Self {
-        self.auth = auth.into_option();
-        self
-    }
-
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
-    ///
-    /// Provider configuration capabilities supported by the agent.
-    #[cfg(feature = "unstable_llm_providers")]
-    #[must_use]
-    pub fn providers(mut self, providers: impl IntoOption<ProvidersCapabilities>) -> Self {
-        self.providers = providers.into_option();
-        self
-    }
-
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
-    ///
-    /// NES (Next Edit Suggestions) capabilities supported by the agent.
-    #[cfg(feature = "unstable_nes")]
-    #[must_use]
-    pub fn nes(mut self, nes: impl IntoOption<NesCapabilities>) -> Self {
-        self.nes = nes.into_option();
-        self
-    }
-
-    /// **UNSTABLE**
-    ///
-    /// The position encoding selected by the agent from the client's supported encodings.
-    #[cfg(feature = "unstable_nes")]
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SessionListCapabilities {
+    /// Builds an empty [`SessionListCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
-    pub fn position_encoding(
-        mut self,
-        position_encoding: impl IntoOption<PositionEncodingKind>,
-    ) -> Self {
-        self.position_encoding = position_encoding.into_option();
-        self
+    pub fn new() -> Self {
+        Self::default()
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -4174,19 +6150,14 @@ impl AgentCapabilities {
     }
 }
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// Provider configuration capabilities supported by the agent.
+/// Capabilities for the `session/delete` method.
 ///
-/// Supplying `{}` means the agent supports provider configuration methods.
-#[cfg(feature = "unstable_llm_providers")]
+/// Supplying `{}` means the agent supports deleting sessions from `session/list`.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct ProvidersCapabilities {
+pub struct SessionDeleteCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4199,9 +6170,8 @@ pub struct ProvidersCapabilities {
     pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl ProvidersCapabilities {
-    /// Builds an empty [`ProvidersCapabilities`]; use builder methods to advertise supported sub-capabilities.
+impl SessionDeleteCapabilities {
+    /// Builds an empty [`SessionDeleteCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -4219,105 +6189,17 @@ impl ProvidersCapabilities {
     }
 }
 
-/// Session capabilities supported by the agent.
-///
-/// Supplying `{}` means the agent supports the baseline session methods:
-/// `session/new`, `session/prompt`, `session/cancel`, and `session/update`.
-///
-/// Agents that support sessions **MAY** support additional session methods,
-/// prompt content types, and MCP transports by specifying additional
-/// capabilities.
+/// Capabilities for additional session directories support.
 ///
-/// See protocol docs: [Session Capabilities](https://agentclientprotocol.com/protocol/initialization#session-capabilities)
+/// Supplying `{}` means the agent supports the `additionalDirectories` field on
+/// supported session lifecycle requests. Agents that also support
+/// `session/list` may return `SessionInfo.additionalDirectories` to report the
+/// complete ordered additional-root list associated with a listed session.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionCapabilities {
-    /// Prompt capabilities supported by the agent in `session/prompt` requests.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise any
-    /// prompt extensions beyond the baseline text and resource-link content
-    /// required by `session/prompt`.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub prompt: Option<PromptCapabilities>,
-    /// MCP capabilities supported by the agent for session lifecycle requests.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise MCP
-    /// server transport support for sessions.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub mcp: Option<McpCapabilities>,
-    /// Whether the agent supports `session/load`.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports loading sessions.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub load: Option<SessionLoadCapabilities>,
-    /// Whether the agent supports `session/list`.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports listing sessions.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub list: Option<SessionListCapabilities>,
-    /// Whether the agent supports `session/delete`.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports deleting sessions from `session/list`.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub delete: Option<SessionDeleteCapabilities>,
-    /// Whether the agent supports `additionalDirectories` on supported session lifecycle requests.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports `additionalDirectories` on
-    /// supported session lifecycle requests.
-    ///
-    /// Agents that also support `session/list` may return
-    /// `SessionInfo.additionalDirectories` to report the complete ordered
-    /// additional-root list associated with a listed session.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub additional_directories: Option<SessionAdditionalDirectoriesCapabilities>,
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
-    ///
-    /// Whether the agent supports `session/fork`.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports forking sessions.
-    #[cfg(feature = "unstable_session_fork")]
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub fork: Option<SessionForkCapabilities>,
-    /// Whether the agent supports `session/resume`.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports resuming sessions.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub resume: Option<SessionResumeCapabilities>,
-    /// Whether the agent supports `session/close`.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports closing sessions.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub close: Option<SessionCloseCapabilities>,
+pub struct SessionAdditionalDirectoriesCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4330,111 +6212,179 @@ pub struct SessionCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl SessionCapabilities {
-    /// Builds an empty [`SessionCapabilities`]; use builder methods to advertise supported sub-capabilities.
+impl SessionAdditionalDirectoriesCapabilities {
+    /// Builds an empty [`SessionAdditionalDirectoriesCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
     }
 
-    /// Prompt capabilities supported by the agent in `session/prompt` requests.
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// Omitted or `null` both mean the agent does not advertise any prompt
-    /// extensions beyond the baseline text and resource-link content required by
-    /// `session/prompt`.
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn prompt(mut self, prompt: impl IntoOption<PromptCapabilities>) -> Self {
-        self.prompt = prompt.into_option();
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
         self
     }
+}
 
-    /// MCP capabilities supported by the agent for session lifecycle requests.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for the `session/fork` method.
+///
+/// Supplying `{}` means the agent supports forking sessions.
+#[cfg(feature = "unstable_session_fork")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SessionForkCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// Omitted or `null` both mean the agent does not advertise MCP server
-    /// transport support for sessions.
-    #[must_use]
-    pub fn mcp(mut self, mcp: impl IntoOption<McpCapabilities>) -> Self {
-        self.mcp = mcp.into_option();
-        self
-    }
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
 
-    /// Whether the agent supports `session/load`.
-    ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports loading sessions.
+#[cfg(feature = "unstable_session_fork")]
+impl SessionForkCapabilities {
+    /// Builds an empty [`SessionForkCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
-    pub fn load(mut self, load: impl IntoOption<SessionLoadCapabilities>) -> Self {
-        self.load = load.into_option();
-        self
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    /// Whether the agent supports `session/list`.
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports listing sessions.
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn list(mut self, list: impl IntoOption<SessionListCapabilities>) -> Self {
-        self.list = list.into_option();
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
         self
     }
+}
 
-    /// Whether the agent supports `session/delete`.
+/// Capabilities for the `session/resume` method.
+///
+/// Supplying `{}` means the agent supports resuming sessions.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SessionResumeCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports deleting sessions from `session/list`.
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SessionResumeCapabilities {
+    /// Builds an empty [`SessionResumeCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
-    pub fn delete(mut self, delete: impl IntoOption<SessionDeleteCapabilities>) -> Self {
-        self.delete = delete.into_option();
-        self
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    /// Whether the agent supports `additionalDirectories` on supported session lifecycle requests.
-    ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports `additionalDirectories` on
-    /// supported session lifecycle requests.
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// Agents that also support `session/list` may return
-    /// `SessionInfo.additionalDirectories` to report the complete ordered
-    /// additional-root list associated with a listed session.
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn additional_directories(
-        mut self,
-        additional_directories: impl IntoOption<SessionAdditionalDirectoriesCapabilities>,
-    ) -> Self {
-        self.additional_directories = additional_directories.into_option();
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
         self
     }
+}
 
-    #[cfg(feature = "unstable_session_fork")]
-    /// Whether the agent supports `session/fork`.
+/// Capabilities for the `session/close` method.
+///
+/// Supplying `{}` means the agent supports closing sessions.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SessionCloseCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports forking sessions.
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SessionCloseCapabilities {
+    /// Builds an empty [`SessionCloseCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
-    pub fn fork(mut self, fork: impl IntoOption<SessionForkCapabilities>) -> Self {
-        self.fork = fork.into_option();
-        self
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    /// Whether the agent supports `session/resume`.
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports resuming sessions.
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn resume(mut self, resume: impl IntoOption<SessionResumeCapabilities>) -> Self {
-        self.resume = resume.into_option();
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
         self
     }
+}
 
-    /// Whether the agent supports `session/close`.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for the `session/compact` method.
+///
+/// Supplying `{}` means the agent supports compacting sessions.
+#[cfg(feature = "unstable_session_compact")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SessionCompactCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports closing sessions.
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_session_compact")]
+impl SessionCompactCapabilities {
+    /// Builds an empty [`SessionCompactCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
-    pub fn close(mut self, close: impl IntoOption<SessionCloseCapabilities>) -> Self {
-        self.close = close.into_option();
-        self
+    pub fn new() -> Self {
+        Self::default()
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -4449,14 +6399,19 @@ impl SessionCapabilities {
     }
 }
 
-/// Capabilities for the `session/load` method.
+/// **UNSTABLE**
 ///
-/// Supplying `{}` means the agent supports loading sessions.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for the `session/set_title` method.
+///
+/// Supplying `{}` means the agent supports renaming sessions.
+#[cfg(feature = "unstable_session_set_title")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct SessionLoadCapabilities {
+pub struct SessionSetTitleCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4469,8 +6424,9 @@ pub struct SessionLoadCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl SessionLoadCapabilities {
-    /// Builds an empty [`SessionLoadCapabilities`]; use builder methods to advertise supported sub-capabilities.
+#[cfg(feature = "unstable_session_set_title")]
+impl SessionSetTitleCapabilities {
+    /// Builds an empty [`SessionSetTitleCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -4488,14 +6444,19 @@ impl SessionLoadCapabilities {
     }
 }
 
-/// Capabilities for the `session/list` method.
+/// **UNSTABLE**
 ///
-/// Supplying `{}` means the agent supports listing sessions.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for the `session/cancel_tool_call` method.
+///
+/// Supplying `{}` means the agent supports cancelling individual tool calls.
+#[cfg(feature = "unstable_cancel_tool_call")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct SessionListCapabilities {
+pub struct SessionCancelToolCallCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4508,8 +6469,9 @@ pub struct SessionListCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl SessionListCapabilities {
-    /// Builds an empty [`SessionListCapabilities`]; use builder methods to advertise supported sub-capabilities.
+#[cfg(feature = "unstable_cancel_tool_call")]
+impl SessionCancelToolCallCapabilities {
+    /// Builds an empty [`SessionCancelToolCallCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -4527,14 +6489,19 @@ impl SessionListCapabilities {
     }
 }
 
-/// Capabilities for the `session/delete` method.
+/// **UNSTABLE**
 ///
-/// Supplying `{}` means the agent supports deleting sessions from `session/list`.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for paging the history replayed by `session/load`.
+///
+/// Supplying `{}` means the agent honors `cursor` and `limit` on `session/load`.
+#[cfg(feature = "unstable_session_load_pagination")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct SessionDeleteCapabilities {
+pub struct SessionLoadPaginationCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4547,8 +6514,9 @@ pub struct SessionDeleteCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl SessionDeleteCapabilities {
-    /// Builds an empty [`SessionDeleteCapabilities`]; use builder methods to advertise supported sub-capabilities.
+#[cfg(feature = "unstable_session_load_pagination")]
+impl SessionLoadPaginationCapabilities {
+    /// Builds an empty [`SessionLoadPaginationCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -4566,34 +6534,248 @@ impl SessionDeleteCapabilities {
     }
 }
 
-/// Capabilities for additional session directories support.
+/// Prompt capabilities supported by the agent in `session/prompt` requests.
 ///
-/// Supplying `{}` means the agent supports the `additionalDirectories` field on
-/// supported session lifecycle requests. Agents that also support
-/// `session/list` may return `SessionInfo.additionalDirectories` to report the
-/// complete ordered additional-root list associated with a listed session.
+/// Baseline agent functionality requires support for [`ContentBlock::Text`]
+/// and [`ContentBlock::ResourceLink`] in prompt requests.
+///
+/// Other variants must be explicitly opted in to.
+/// Capabilities for different types of content in prompt requests.
+///
+/// Indicates which content types beyond the baseline (text and resource links)
+/// the agent can process.
+///
+/// See protocol docs: [Prompt Capabilities](https://agentclientprotocol.com/protocol/initialization#prompt-capabilities)
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SessionAdditionalDirectoriesCapabilities {
+pub struct PromptCapabilities {
+    /// Agent supports [`ContentBlock::Image`].
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports image content in prompts.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub image: Option<PromptImageCapabilities>,
+    /// Agent supports [`ContentBlock::Audio`].
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports audio content in prompts.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub audio: Option<PromptAudioCapabilities>,
+    /// Agent supports embedded context in `session/prompt` requests.
+    ///
+    /// When enabled, the Client is allowed to include [`ContentBlock::Resource`]
+    /// in prompt requests for pieces of context that are referenced in the message.
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports embedded context in prompts.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub embedded_context: Option<PromptEmbeddedContextCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent supports [`ContentBlock::ToolResultRef`].
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent resolves tool result references in prompts.
+    #[cfg(feature = "unstable_tool_result_ref")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub tool_result_ref: Option<PromptToolResultRefCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent supports [`ContentBlock::Mention`].
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent resolves mentions in prompts.
+    #[cfg(feature = "unstable_mention")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub mention: Option<PromptMentionCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent honors [`PromptRequest::reasoning_effort`].
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent maps each known level onto its model.
+    #[cfg(feature = "unstable_reasoning_effort")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub reasoning_effort: Option<PromptReasoningEffortCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent honors [`PromptRequest::overrides`].
+    ///
+    /// Optional. Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent applies per-prompt sampling overrides.
+    #[cfg(feature = "unstable_sampling_overrides")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub sampling_overrides: Option<PromptSamplingOverridesCapabilities>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
     ///
-    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
-}
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl PromptCapabilities {
+    /// Builds an empty [`PromptCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Agent supports [`ContentBlock::Image`].
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports image content in prompts.
+    #[must_use]
+    pub fn image(mut self, image: impl IntoOption<PromptImageCapabilities>) -> Self {
+        self.image = image.into_option();
+        self
+    }
+
+    /// Agent supports [`ContentBlock::Audio`].
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports audio content in prompts.
+    #[must_use]
+    pub fn audio(mut self, audio: impl IntoOption<PromptAudioCapabilities>) -> Self {
+        self.audio = audio.into_option();
+        self
+    }
+
+    /// Agent supports embedded context in `session/prompt` requests.
+    ///
+    /// When enabled, the Client is allowed to include [`ContentBlock::Resource`]
+    /// in prompt requests for pieces of context that are referenced in the message.
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent supports embedded context in prompts.
+    #[must_use]
+    pub fn embedded_context(
+        mut self,
+        embedded_context: impl IntoOption<PromptEmbeddedContextCapabilities>,
+    ) -> Self {
+        self.embedded_context = embedded_context.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent supports [`ContentBlock::ToolResultRef`].
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent resolves tool result references in prompts.
+    #[cfg(feature = "unstable_tool_result_ref")]
+    #[must_use]
+    pub fn tool_result_ref(
+        mut self,
+        tool_result_ref: impl IntoOption<PromptToolResultRefCapabilities>,
+    ) -> Self {
+        self.tool_result_ref = tool_result_ref.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent supports [`ContentBlock::Mention`].
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent resolves mentions in prompts.
+    #[cfg(feature = "unstable_mention")]
+    #[must_use]
+    pub fn mention(mut self, mention: impl IntoOption<PromptMentionCapabilities>) -> Self {
+        self.mention = mention.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent honors [`PromptRequest::reasoning_effort`].
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent maps each known level onto its model.
+    #[cfg(feature = "unstable_reasoning_effort")]
+    #[must_use]
+    pub fn reasoning_effort(
+        mut self,
+        reasoning_effort: impl IntoOption<PromptReasoningEffortCapabilities>,
+    ) -> Self {
+        self.reasoning_effort = reasoning_effort.into_option();
+        self
+    }
 
-impl SessionAdditionalDirectoriesCapabilities {
-    /// Builds an empty [`SessionAdditionalDirectoriesCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Agent honors [`PromptRequest::overrides`].
+    ///
+    /// Omitted or `null` both mean the agent does not advertise support.
+    /// Supplying `{}` means the agent applies per-prompt sampling overrides.
+    #[cfg(feature = "unstable_sampling_overrides")]
     #[must_use]
-    pub fn new() -> Self {
-        Self::default()
+    pub fn sampling_overrides(
+        mut self,
+        sampling_overrides: impl IntoOption<PromptSamplingOverridesCapabilities>,
+    ) -> Self {
+        self.sampling_overrides = sampling_overrides.into_option();
+        self
+    }
+
+    /// Whether a prompt may include `block` under these capabilities.
+    ///
+    /// Text and resource links are always allowed. Tables and JSON values are
+    /// agent output and are never allowed. Every other block type, including custom or future ones, requires its matching capability.
+    #[must_use]
+    pub fn supports(&self, block: &ContentBlock) -> bool {
+        match block {
+            ContentBlock::Text(_) | ContentBlock::ResourceLink(_) => true,
+            #[cfg(feature = "unstable_mention")]
+            ContentBlock::Mention(_) => self.mention.is_some(),
+            #[cfg(feature = "unstable_table")]
+            ContentBlock::Table(_) => false,
+            #[cfg(feature = "unstable_json_content")]
+            ContentBlock::Json(_) => false,
+            ContentBlock::Image(_) => self.image.is_some(),
+            ContentBlock::Audio(_) => self.audio.is_some(),
+            ContentBlock::Resource(_) => self.embedded_context.is_some(),
+            #[cfg(feature = "unstable_tool_result_ref")]
+            ContentBlock::ToolResultRef(_) => self.tool_result_ref.is_some(),
+            ContentBlock::Other(_) => false,
+        }
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -4608,19 +6790,14 @@ impl SessionAdditionalDirectoriesCapabilities {
     }
 }
 
-/// **UNSTABLE**
-///
-/// This capability is not part of the spec yet, and may be removed or changed at any point.
-///
-/// Capabilities for the `session/fork` method.
+/// Capabilities for image content in prompt requests.
 ///
-/// Supplying `{}` means the agent supports forking sessions.
-#[cfg(feature = "unstable_session_fork")]
+/// Supplying `{}` means the agent supports image content in prompts.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct SessionForkCapabilities {
+pub struct PromptImageCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4633,9 +6810,8 @@ pub struct SessionForkCapabilities {
     pub meta: Option<Meta>,
 }
 
-#[cfg(feature = "unstable_session_fork")]
-impl SessionForkCapabilities {
-    /// Builds an empty [`SessionForkCapabilities`]; use builder methods to advertise supported sub-capabilities.
+impl PromptImageCapabilities {
+    /// Builds an empty [`PromptImageCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -4653,14 +6829,14 @@ impl SessionForkCapabilities {
     }
 }
 
-/// Capabilities for the `session/resume` method.
+/// Capabilities for audio content in prompt requests.
 ///
-/// Supplying `{}` means the agent supports resuming sessions.
+/// Supplying `{}` means the agent supports audio content in prompts.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct SessionResumeCapabilities {
+pub struct PromptAudioCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4673,8 +6849,8 @@ pub struct SessionResumeCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl SessionResumeCapabilities {
-    /// Builds an empty [`SessionResumeCapabilities`]; use builder methods to advertise supported sub-capabilities.
+impl PromptAudioCapabilities {
+    /// Builds an empty [`PromptAudioCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -4692,14 +6868,14 @@ impl SessionResumeCapabilities {
     }
 }
 
-/// Capabilities for the `session/close` method.
+/// Capabilities for embedded context in prompt requests.
 ///
-/// Supplying `{}` means the agent supports closing sessions.
+/// Supplying `{}` means the agent supports embedded context in prompts.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct SessionCloseCapabilities {
+pub struct PromptEmbeddedContextCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4712,8 +6888,8 @@ pub struct SessionCloseCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl SessionCloseCapabilities {
-    /// Builds an empty [`SessionCloseCapabilities`]; use builder methods to advertise supported sub-capabilities.
+impl PromptEmbeddedContextCapabilities {
+    /// Builds an empty [`PromptEmbeddedContextCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -4731,51 +6907,19 @@ impl SessionCloseCapabilities {
     }
 }
 
-/// Prompt capabilities supported by the agent in `session/prompt` requests.
-///
-/// Baseline agent functionality requires support for [`ContentBlock::Text`]
-/// and [`ContentBlock::ResourceLink`] in prompt requests.
+/// **UNSTABLE**
 ///
-/// Other variants must be explicitly opted in to.
-/// Capabilities for different types of content in prompt requests.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// Indicates which content types beyond the baseline (text and resource links)
-/// the agent can process.
+/// Capabilities for tool result references in prompt requests.
 ///
-/// See protocol docs: [Prompt Capabilities](https://agentclientprotocol.com/protocol/initialization#prompt-capabilities)
+/// Supplying `{}` means the agent resolves tool result references in prompts.
+#[cfg(feature = "unstable_tool_result_ref")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct PromptCapabilities {
-    /// Agent supports [`ContentBlock::Image`].
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports image content in prompts.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub image: Option<PromptImageCapabilities>,
-    /// Agent supports [`ContentBlock::Audio`].
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports audio content in prompts.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub audio: Option<PromptAudioCapabilities>,
-    /// Agent supports embedded context in `session/prompt` requests.
-    ///
-    /// When enabled, the Client is allowed to include [`ContentBlock::Resource`]
-    /// in prompt requests for pieces of context that are referenced in the message.
-    ///
-    /// Optional. Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports embedded context in prompts.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub embedded_context: Option<PromptEmbeddedContextCapabilities>,
+pub struct PromptToolResultRefCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4788,49 +6932,14 @@ pub struct PromptCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl PromptCapabilities {
-    /// Builds an empty [`PromptCapabilities`]; use builder methods to advertise supported sub-capabilities.
+#[cfg(feature = "unstable_tool_result_ref")]
+impl PromptToolResultRefCapabilities {
+    /// Builds an empty [`PromptToolResultRefCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
     }
 
-    /// Agent supports [`ContentBlock::Image`].
-    ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports image content in prompts.
-    #[must_use]
-    pub fn image(mut self, image: impl IntoOption<PromptImageCapabilities>) -> Self {
-        self.image = image.into_option();
-        self
-    }
-
-    /// Agent supports [`ContentBlock::Audio`].
-    ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports audio content in prompts.
-    #[must_use]
-    pub fn audio(mut self, audio: impl IntoOption<PromptAudioCapabilities>) -> Self {
-        self.audio = audio.into_option();
-        self
-    }
-
-    /// Agent supports embedded context in `session/prompt` requests.
-    ///
-    /// When enabled, the Client is allowed to include [`ContentBlock::Resource`]
-    /// in prompt requests for pieces of context that are referenced in the message.
-    ///
-    /// Omitted or `null` both mean the agent does not advertise support.
-    /// Supplying `{}` means the agent supports embedded context in prompts.
-    #[must_use]
-    pub fn embedded_context(
-        mut self,
-        embedded_context: impl IntoOption<PromptEmbeddedContextCapabilities>,
-    ) -> Self {
-        self.embedded_context = embedded_context.into_option();
-        self
-    }
-
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4843,14 +6952,19 @@ impl PromptCapabilities {
     }
 }
 
-/// Capabilities for image content in prompt requests.
+/// **UNSTABLE**
 ///
-/// Supplying `{}` means the agent supports image content in prompts.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for mentions in prompt requests.
+///
+/// Supplying `{}` means the agent resolves mentions in prompts.
+#[cfg(feature = "unstable_mention")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct PromptImageCapabilities {
+pub struct PromptMentionCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4863,8 +6977,9 @@ pub struct PromptImageCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl PromptImageCapabilities {
-    /// Builds an empty [`PromptImageCapabilities`]; use builder methods to advertise supported sub-capabilities.
+#[cfg(feature = "unstable_mention")]
+impl PromptMentionCapabilities {
+    /// Builds an empty [`PromptMentionCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -4882,14 +6997,19 @@ impl PromptImageCapabilities {
     }
 }
 
-/// Capabilities for audio content in prompt requests.
+/// **UNSTABLE**
 ///
-/// Supplying `{}` means the agent supports audio content in prompts.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for reasoning effort hints in prompt requests.
+///
+/// Supplying `{}` means the agent maps each known level onto its model.
+#[cfg(feature = "unstable_reasoning_effort")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct PromptAudioCapabilities {
+pub struct PromptReasoningEffortCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4902,8 +7022,9 @@ pub struct PromptAudioCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl PromptAudioCapabilities {
-    /// Builds an empty [`PromptAudioCapabilities`]; use builder methods to advertise supported sub-capabilities.
+#[cfg(feature = "unstable_reasoning_effort")]
+impl PromptReasoningEffortCapabilities {
+    /// Builds an empty [`PromptReasoningEffortCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -4921,14 +7042,19 @@ impl PromptAudioCapabilities {
     }
 }
 
-/// Capabilities for embedded context in prompt requests.
+/// **UNSTABLE**
 ///
-/// Supplying `{}` means the agent supports embedded context in prompts.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for per-prompt sampling overrides.
+///
+/// Supplying `{}` means the agent applies per-prompt sampling overrides.
+#[cfg(feature = "unstable_sampling_overrides")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct PromptEmbeddedContextCapabilities {
+pub struct PromptSamplingOverridesCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -4941,8 +7067,9 @@ pub struct PromptEmbeddedContextCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl PromptEmbeddedContextCapabilities {
-    /// Builds an empty [`PromptEmbeddedContextCapabilities`]; use builder methods to advertise supported sub-capabilities.
+#[cfg(feature = "unstable_sampling_overrides")]
+impl PromptSamplingOverridesCapabilities {
+    /// Builds an empty [`PromptSamplingOverridesCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -5197,6 +7324,19 @@ impl McpAcpCapabilities {
 pub struct CancelSessionNotification {
     /// The ID of the session to cancel operations for.
     pub session_id: SessionId,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Why the sender is cancelling.
+    ///
+    /// Optional. Receivers MUST NOT require it and SHOULD treat an omitted or
+    /// unrecognized reason the same as a plain cancellation.
+    #[cfg(feature = "unstable_cancel_reason")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub reason: Option<CancelReason>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -5215,6 +7355,86 @@ impl CancelSessionNotification {
     pub fn new(session_id: impl Into<SessionId>) -> Self {
         Self {
             session_id: session_id.into(),
+            #[cfg(feature = "unstable_cancel_reason")]
+            reason: None,
+            meta: None,
+        }
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Why the sender is cancelling.
+    #[cfg(feature = "unstable_cancel_reason")]
+    #[must_use]
+    pub fn reason(mut self, reason: impl IntoOption<CancelReason>) -> Self {
+        self.reason = reason.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Notification that a tool call's diff could not be applied because the file
+/// changed since the agent produced it.
+///
+/// Clients send it instead of applying the stale diff, so the agent can re-read
+/// the file and re-plan the edit.
+///
+/// See protocol docs: [Edit Conflicts](https://agentclientprotocol.com/protocol/tool-calls#edit-conflicts)
+#[cfg(feature = "unstable_edit_conflict")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "agent", "x-method" = SESSION_REPORT_EDIT_CONFLICT_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ReportEditConflictNotification {
+    /// The session the tool call belongs to.
+    pub session_id: SessionId,
+    /// The tool call whose diff could not be applied.
+    pub tool_call_id: ToolCallId,
+    /// What the diff expected and what the client found.
+    pub report: ConflictReport,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_edit_conflict")]
+impl ReportEditConflictNotification {
+    /// Builds [`ReportEditConflictNotification`] with the required notification fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(
+        session_id: impl Into<SessionId>,
+        tool_call_id: impl Into<ToolCallId>,
+        report: ConflictReport,
+    ) -> Self {
+        Self {
+            session_id: session_id.into(),
+            tool_call_id: tool_call_id.into(),
+            report,
             meta: None,
         }
     }
@@ -5262,6 +7482,9 @@ pub struct AgentMethodNames {
     pub session_prompt: &'static str,
     /// Notification for cancelling operations.
     pub session_cancel: &'static str,
+    /// Notification for reporting a diff that could not be applied.
+    #[cfg(feature = "unstable_edit_conflict")]
+    pub session_report_edit_conflict: &'static str,
     /// Method for exchanging MCP-over-ACP messages.
     #[cfg(feature = "unstable_mcp_over_acp")]
     pub mcp_message: &'static str,
@@ -5276,6 +7499,21 @@ pub struct AgentMethodNames {
     pub session_resume: &'static str,
     /// Method for closing an active session.
     pub session_close: &'static str,
+    /// Method for compacting a session's history.
+    #[cfg(feature = "unstable_session_compact")]
+    pub session_compact: &'static str,
+    /// Method for renaming a session.
+    #[cfg(feature = "unstable_session_set_title")]
+    pub session_set_title: &'static str,
+    /// Method for cancelling a single tool call.
+    #[cfg(feature = "unstable_cancel_tool_call")]
+    pub session_cancel_tool_call: &'static str,
+    /// Method for invoking an available command.
+    #[cfg(feature = "unstable_command_invocation")]
+    pub command_invoke: &'static str,
+    /// Method for answering an input request.
+    #[cfg(feature = "unstable_input_request")]
+    pub session_provide_input: &'static str,
     /// Method for logging out of an authenticated session.
     pub auth_logout: &'static str,
     /// Method for starting an NES session.
@@ -5325,6 +7563,8 @@ pub const AGENT_METHOD_NAMES: AgentMethodNames = AgentMethodNames {
     session_set_config_option: SESSION_SET_CONFIG_OPTION_METHOD_NAME,
     session_prompt: SESSION_PROMPT_METHOD_NAME,
     session_cancel: SESSION_CANCEL_METHOD_NAME,
+    #[cfg(feature = "unstable_edit_conflict")]
+    session_report_edit_conflict: SESSION_REPORT_EDIT_CONFLICT_METHOD_NAME,
     #[cfg(feature = "unstable_mcp_over_acp")]
     mcp_message: MCP_MESSAGE_METHOD_NAME,
     session_list: SESSION_LIST_METHOD_NAME,
@@ -5333,6 +7573,16 @@ pub const AGENT_METHOD_NAMES: AgentMethodNames = AgentMethodNames {
     session_fork: SESSION_FORK_METHOD_NAME,
     session_resume: SESSION_RESUME_METHOD_NAME,
     session_close: SESSION_CLOSE_METHOD_NAME,
+    #[cfg(feature = "unstable_session_compact")]
+    session_compact: SESSION_COMPACT_METHOD_NAME,
+    #[cfg(feature = "unstable_session_set_title")]
+    session_set_title: SESSION_SET_TITLE_METHOD_NAME,
+    #[cfg(feature = "unstable_cancel_tool_call")]
+    session_cancel_tool_call: SESSION_CANCEL_TOOL_CALL_METHOD_NAME,
+    #[cfg(feature = "unstable_command_invocation")]
+    command_invoke: COMMAND_INVOKE_METHOD_NAME,
+    #[cfg(feature = "unstable_input_request")]
+    session_provide_input: SESSION_PROVIDE_INPUT_METHOD_NAME,
     auth_logout: AUTH_LOGOUT_METHOD_NAME,
     #[cfg(feature = "unstable_nes")]
     nes_start: NES_START_METHOD_NAME,
@@ -5379,6 +7629,9 @@ pub(crate) const SESSION_SET_CONFIG_OPTION_METHOD_NAME: &str = "session/set_conf
 pub(crate) const SESSION_PROMPT_METHOD_NAME: &str = "session/prompt";
 /// Method name for the cancel notification.
 pub(crate) const SESSION_CANCEL_METHOD_NAME: &str = "session/cancel";
+/// Method name for reporting a diff that could not be applied.
+#[cfg(feature = "unstable_edit_conflict")]
+pub(crate) const SESSION_REPORT_EDIT_CONFLICT_METHOD_NAME: &str = "session/report_edit_conflict";
 /// Method name for listing existing sessions.
 pub(crate) const SESSION_LIST_METHOD_NAME: &str = "session/list";
 /// Method name for deleting an existing session.
@@ -5390,6 +7643,21 @@ pub(crate) const SESSION_FORK_METHOD_NAME: &str = "session/fork";
 pub(crate) const SESSION_RESUME_METHOD_NAME: &str = "session/resume";
 /// Method name for closing an active session.
 pub(crate) const SESSION_CLOSE_METHOD_NAME: &str = "session/close";
+/// Method name for compacting a session's history.
+#[cfg(feature = "unstable_session_compact")]
+pub(crate) const SESSION_COMPACT_METHOD_NAME: &str = "session/compact";
+/// Method name for renaming a session.
+#[cfg(feature = "unstable_session_set_title")]
+pub(crate) const SESSION_SET_TITLE_METHOD_NAME: &str = "session/set_title";
+/// Method name for cancelling a single tool call.
+#[cfg(feature = "unstable_cancel_tool_call")]
+pub(crate) const SESSION_CANCEL_TOOL_CALL_METHOD_NAME: &str = "session/cancel_tool_call";
+/// Method name for invoking an available command.
+#[cfg(feature = "unstable_command_invocation")]
+pub(crate) const COMMAND_INVOKE_METHOD_NAME: &str = "command/invoke";
+/// Method name for answering an input request.
+#[cfg(feature = "unstable_input_request")]
+pub(crate) const SESSION_PROVIDE_INPUT_METHOD_NAME: &str = "session/provide_input";
 /// Method name for the `auth/logout` request.
 pub(crate) const AUTH_LOGOUT_METHOD_NAME: &str = "auth/logout";
 
@@ -5512,6 +7780,54 @@ pub enum ClientRequest {
     /// The agent must cancel any ongoing work (as if `session/cancel` was called)
     /// and then free up any resources associated with the session.
     CloseSessionRequest(Box<CloseSessionRequest>),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Compacts the agent's context for a session.
+    ///
+    /// This method is only available if the agent advertises the `session.compact` capability.
+    ///
+    /// Subsequent prompts in the session use the compacted context.
+    #[cfg(feature = "unstable_session_compact")]
+    CompactSessionRequest(Box<CompactSessionRequest>),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Renames a session.
+    ///
+    /// This method is only available if the agent advertises the `session.setTitle` capability.
+    #[cfg(feature = "unstable_session_set_title")]
+    SetSessionTitleRequest(Box<SetSessionTitleRequest>),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Cancels a single tool call without cancelling the prompt turn.
+    ///
+    /// This method is only available if the agent advertises the `session.cancelToolCall` capability.
+    #[cfg(feature = "unstable_cancel_tool_call")]
+    CancelToolCallRequest(Box<CancelToolCallRequest>),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Invokes one of the commands the agent advertised via `available_commands_update`.
+    ///
+    /// Returns a `command_not_found` error if the command is not currently available.
+    #[cfg(feature = "unstable_command_invocation")]
+    InvokeCommandRequest(Box<InvokeCommandRequest>),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Answers a question the agent asked with an `input_request` session update.
+    ///
+    /// The prompt turn that asked the question resumes once the agent receives
+    /// the answer.
+    #[cfg(feature = "unstable_input_request")]
+    ProvideInputRequest(Box<ProvideInputRequest>),
     /// Sets the current value for a session configuration option.
     SetSessionConfigOptionRequest(Box<SetSessionConfigOptionRequest>),
     /// Processes a user prompt within a session.
@@ -5588,6 +7904,16 @@ impl ClientRequest {
             Self::ForkSessionRequest(_) => AGENT_METHOD_NAMES.session_fork,
             Self::ResumeSessionRequest(_) => AGENT_METHOD_NAMES.session_resume,
             Self::CloseSessionRequest(_) => AGENT_METHOD_NAMES.session_close,
+            #[cfg(feature = "unstable_session_compact")]
+            Self::CompactSessionRequest(_) => AGENT_METHOD_NAMES.session_compact,
+            #[cfg(feature = "unstable_session_set_title")]
+            Self::SetSessionTitleRequest(_) => AGENT_METHOD_NAMES.session_set_title,
+            #[cfg(feature = "unstable_cancel_tool_call")]
+            Self::CancelToolCallRequest(_) => AGENT_METHOD_NAMES.session_cancel_tool_call,
+            #[cfg(feature = "unstable_command_invocation")]
+            Self::InvokeCommandRequest(_) => AGENT_METHOD_NAMES.command_invoke,
+            #[cfg(feature = "unstable_input_request")]
+            Self::ProvideInputRequest(_) => AGENT_METHOD_NAMES.session_provide_input,
             Self::SetSessionConfigOptionRequest(_) => AGENT_METHOD_NAMES.session_set_config_option,
             Self::PromptRequest(_) => AGENT_METHOD_NAMES.session_prompt,
             #[cfg(feature = "unstable_nes")]
@@ -5644,6 +7970,21 @@ pub enum AgentResponse {
     ResumeSessionResponse(#[serde(default)] Box<ResumeSessionResponse>),
     /// Successful result returned for a `session/close` request.
     CloseSessionResponse(#[serde(default)] Box<CloseSessionResponse>),
+    /// Successful result returned for a `session/compact` request.
+    #[cfg(feature = "unstable_session_compact")]
+    CompactSessionResponse(Box<CompactSessionResponse>),
+    /// Successful result returned for a `session/set_title` request.
+    #[cfg(feature = "unstable_session_set_title")]
+    SetSessionTitleResponse(#[serde(default)] Box<SetSessionTitleResponse>),
+    /// Successful result returned for a `session/cancel_tool_call` request.
+    #[cfg(feature = "unstable_cancel_tool_call")]
+    CancelToolCallResponse(#[serde(default)] Box<CancelToolCallResponse>),
+    /// Successful result returned for a `command/invoke` request.
+    #[cfg(feature = "unstable_command_invocation")]
+    InvokeCommandResponse(#[serde(default)] Box<InvokeCommandResponse>),
+    /// Successful result returned for a `session/provide_input` request.
+    #[cfg(feature = "unstable_input_request")]
+    ProvideInputResponse(#[serde(default)] Box<ProvideInputResponse>),
     /// Successful result returned for a `session/set_config_option` request.
     SetSessionConfigOptionResponse(Box<SetSessionConfigOptionResponse>),
     /// Successful result returned for a `session/prompt` request.
@@ -5731,6 +8072,13 @@ pub enum ClientNotification {
     /// Sends an MCP-over-ACP notification.
     #[cfg(feature = "unstable_mcp_over_acp")]
     MessageMcpNotification(Box<MessageMcpNotification>),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Reports that a tool call's diff could not be applied because the file changed.
+    #[cfg(feature = "unstable_edit_conflict")]
+    ReportEditConflictNotification(Box<ReportEditConflictNotification>),
     /// Handles extension notifications from the client.
     ///
     /// Extension notifications provide a way to send one-way messages for custom functionality
@@ -5762,6 +8110,10 @@ impl ClientNotification {
             Self::RejectNesNotification(_) => AGENT_METHOD_NAMES.nes_reject,
             #[cfg(feature = "unstable_mcp_over_acp")]
             Self::MessageMcpNotification(_) => AGENT_METHOD_NAMES.mcp_message,
+            #[cfg(feature = "unstable_edit_conflict")]
+            Self::ReportEditConflictNotification(_) => {
+                AGENT_METHOD_NAMES.session_report_edit_conflict
+            }
             Self::ExtNotification(ext_notification) => &ext_notification.method,
         }
     }
@@ -6345,6 +8697,7 @@ mod test_serialization {
             })
         );
     }
+
     #[test]
     fn test_session_additional_directories_serialization() {
         assert_eq!(
@@ -7196,187 +9549,808 @@ mod test_serialization {
         assert_eq!(json["providers"].as_array().unwrap().len(), 1);
         assert_eq!(json["providers"][0]["providerId"], "main");
 
-        let deserialized: ListProvidersResponse = serde_json::from_value(json).unwrap();
-        assert_eq!(deserialized.providers.len(), 1);
+        let deserialized: ListProvidersResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.providers.len(), 1);
+    }
+
+    #[cfg(feature = "unstable_llm_providers")]
+    #[test]
+    fn test_set_provider_request_serialization() {
+        use std::collections::HashMap;
+
+        let mut headers = HashMap::new();
+        headers.insert("Authorization".to_string(), "Bearer sk-test".to_string());
+
+        let request =
+            SetProviderRequest::new("main", LlmProtocol::OpenAi, "https://api.openai.com/v1")
+                .headers(headers);
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "providerId": "main",
+                "apiType": "openai",
+                "baseUrl": "https://api.openai.com/v1",
+                "headers": {
+                    "Authorization": "Bearer sk-test"
+                }
+            })
+        );
+
+        let deserialized: SetProviderRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.provider_id.to_string(), "main");
+        assert_eq!(deserialized.api_type, LlmProtocol::OpenAi);
+        assert_eq!(deserialized.base_url, "https://api.openai.com/v1");
+        assert_eq!(deserialized.headers.len(), 1);
+        assert_eq!(
+            deserialized.headers.get("Authorization").unwrap(),
+            "Bearer sk-test"
+        );
+    }
+
+    #[cfg(feature = "unstable_llm_providers")]
+    #[test]
+    fn test_set_provider_request_omits_empty_headers() {
+        let request =
+            SetProviderRequest::new("main", LlmProtocol::Anthropic, "https://api.anthropic.com");
+
+        let json = serde_json::to_value(&request).unwrap();
+        // headers should be omitted when empty
+        assert!(!json.as_object().unwrap().contains_key("headers"));
+    }
+
+    #[cfg(feature = "unstable_llm_providers")]
+    #[test]
+    fn test_disable_provider_request_serialization() {
+        let request = DisableProviderRequest::new("secondary");
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json, json!({ "providerId": "secondary" }));
+
+        let deserialized: DisableProviderRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.provider_id.to_string(), "secondary");
+    }
+
+    #[cfg(feature = "unstable_llm_providers")]
+    #[test]
+    fn test_providers_capabilities_serialization() {
+        let caps = ProvidersCapabilities::new();
+
+        let json = serde_json::to_value(&caps).unwrap();
+        assert_eq!(json, json!({}));
+
+        let deserialized: ProvidersCapabilities = serde_json::from_value(json).unwrap();
+        assert!(deserialized.meta.is_none());
+    }
+
+    #[cfg(feature = "unstable_llm_providers")]
+    #[test]
+    fn test_agent_capabilities_with_providers() {
+        let caps = AgentCapabilities::new().providers(ProvidersCapabilities::new());
+
+        let json = serde_json::to_value(&caps).unwrap();
+        assert_eq!(json["providers"], json!({}));
+
+        let deserialized: AgentCapabilities = serde_json::from_value(json).unwrap();
+        assert!(deserialized.providers.is_some());
+    }
+
+    #[test]
+    fn test_agent_capabilities_session_is_explicit() {
+        let json = serde_json::to_value(AgentCapabilities::new()).unwrap();
+        assert!(json.get("session").is_none());
+
+        let caps = AgentCapabilities::new().session(
+            SessionCapabilities::new()
+                .prompt(PromptCapabilities::new().image(PromptImageCapabilities::new()))
+                .mcp(McpCapabilities::new().stdio(McpStdioCapabilities::new()))
+                .load(SessionLoadCapabilities::new()),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&caps).unwrap(),
+            json!({
+                "session": {
+                    "prompt": {
+                        "image": {}
+                    },
+                    "mcp": {
+                        "stdio": {}
+                    },
+                    "load": {}
+                }
+            })
+        );
+
+        let deserialized: AgentCapabilities = serde_json::from_value(json!({
+            "session": false
+        }))
+        .unwrap();
+        assert!(deserialized.session.is_none());
+    }
+
+    #[test]
+    fn test_prompt_capabilities_serialize_supported_content_as_objects() {
+        let caps = PromptCapabilities::new()
+            .image(PromptImageCapabilities::new())
+            .audio(PromptAudioCapabilities::new())
+            .embedded_context(PromptEmbeddedContextCapabilities::new());
+
+        assert_eq!(
+            serde_json::to_value(&caps).unwrap(),
+            json!({
+                "image": {},
+                "audio": {},
+                "embeddedContext": {}
+            })
+        );
+
+        let deserialized: PromptCapabilities = serde_json::from_value(json!({
+            "image": null,
+            "audio": false,
+            "embeddedContext": {}
+        }))
+        .unwrap();
+        assert!(deserialized.image.is_none());
+        assert!(deserialized.audio.is_none());
+        assert!(deserialized.embedded_context.is_some());
+    }
+
+    #[test]
+    fn test_mcp_capabilities_serialize_supported_transports_as_objects() {
+        let caps = McpCapabilities::new()
+            .stdio(McpStdioCapabilities::new())
+            .http(McpHttpCapabilities::new());
+
+        assert_eq!(
+            serde_json::to_value(&caps).unwrap(),
+            json!({
+                "stdio": {},
+                "http": {}
+            })
+        );
+
+        let deserialized: McpCapabilities = serde_json::from_value(json!({
+            "stdio": null,
+            "http": false
+        }))
+        .unwrap();
+        assert!(deserialized.stdio.is_none());
+        assert!(deserialized.http.is_none());
+    }
+
+    #[cfg(feature = "unstable_mcp_over_acp")]
+    #[test]
+    fn test_mcp_capabilities_serialize_acp_support_as_object() {
+        let caps = McpCapabilities::new().acp(McpAcpCapabilities::new());
+
+        assert_eq!(
+            serde_json::to_value(&caps).unwrap(),
+            json!({
+                "acp": {}
+            })
+        );
+    }
+
+    #[test]
+    fn test_prompt_capabilities_gate_content_blocks() {
+        use crate::v2::ImageContent;
+
+        let image = ContentBlock::Image(ImageContent::new("aW1n", "image/png"));
+        let text = ContentBlock::from("hello");
+
+        let caps = PromptCapabilities::new();
+        assert!(caps.supports(&text));
+        assert!(!caps.supports(&image));
+        assert!(
+            PromptCapabilities::new()
+                .image(PromptImageCapabilities::new())
+                .supports(&image)
+        );
+    }
+
+    #[cfg(feature = "unstable_tool_result_ref")]
+    #[test]
+    fn test_prompt_capabilities_gate_tool_result_ref() {
+        use crate::v2::ToolResultRef;
+
+        let block = ContentBlock::ToolResultRef(ToolResultRef::new("call_001"));
+
+        let caps: PromptCapabilities = serde_json::from_value(json!({})).unwrap();
+        assert!(caps.tool_result_ref.is_none());
+        assert!(!caps.supports(&block));
+
+        let caps =
+            PromptCapabilities::new().tool_result_ref(PromptToolResultRefCapabilities::new());
+        assert_eq!(
+            serde_json::to_value(&caps).unwrap(),
+            json!({ "toolResultRef": {} })
+        );
+        assert!(caps.supports(&block));
+    }
+
+    #[cfg(feature = "unstable_mention")]
+    #[test]
+    fn test_prompt_capabilities_gate_mention() {
+        use crate::v2::{Mention, MentionKind};
+
+        let block = ContentBlock::Mention(Mention::new(
+            MentionKind::File,
+            "main.rs",
+            "file:///home/user/project/src/main.rs",
+        ));
+
+        let caps: PromptCapabilities = serde_json::from_value(json!({})).unwrap();
+        assert!(caps.mention.is_none());
+        assert!(!caps.supports(&block));
+
+        let caps = PromptCapabilities::new().mention(PromptMentionCapabilities::new());
+        assert_eq!(
+            serde_json::to_value(&caps).unwrap(),
+            json!({ "mention": {} })
+        );
+        assert!(caps.supports(&block));
+    }
+
+    #[cfg(feature = "unstable_session_context")]
+    #[test]
+    fn test_new_session_request_context_round_trip() {
+        let request = NewSessionRequest::new("/home/user/project").context(
+            SessionContext::new("linux")
+                .shell("/bin/zsh".to_string())
+                .env_allowlist(vec!["PATH".into(), "CARGO_HOME".into()]),
+        );
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json["context"],
+            json!({
+                "os": "linux",
+                "shell": "/bin/zsh",
+                "envAllowlist": ["PATH", "CARGO_HOME"]
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<NewSessionRequest>(json).unwrap(),
+            request
+        );
+
+        let request: NewSessionRequest = serde_json::from_value(json!({
+            "cwd": "/home/user/project",
+            "mcpServers": [],
+            "context": { "os": "windows" }
+        }))
+        .unwrap();
+        let context = request.context.unwrap();
+        assert_eq!(context.shell, None);
+        assert!(context.env_allowlist.is_empty());
+    }
+
+    #[cfg(feature = "unstable_reasoning_effort")]
+    #[test]
+    fn test_prompt_request_reasoning_effort_round_trip() {
+        for (effort, wire) in [
+            (ReasoningEffort::Low, "low"),
+            (ReasoningEffort::Medium, "medium"),
+            (ReasoningEffort::High, "high"),
+        ] {
+            let request = PromptRequest::new("sess_abc123", vec![]).reasoning_effort(effort);
+            let json = serde_json::to_value(&request).unwrap();
+            assert_eq!(
+                json,
+                json!({
+                    "sessionId": "sess_abc123",
+                    "prompt": [],
+                    "reasoningEffort": wire
+                })
+            );
+            assert_eq!(
+                serde_json::from_value::<PromptRequest>(json).unwrap(),
+                request
+            );
+        }
+    }
+
+    #[cfg(feature = "unstable_reasoning_effort")]
+    #[test]
+    fn test_prompt_request_reasoning_effort_requires_capability() {
+        let request =
+            PromptRequest::new("sess_abc123", vec![]).reasoning_effort(ReasoningEffort::High);
+        let supported =
+            PromptCapabilities::new().reasoning_effort(PromptReasoningEffortCapabilities::new());
+        assert_eq!(
+            request.supported_reasoning_effort(&supported),
+            Some(&ReasoningEffort::High)
+        );
+        assert_eq!(
+            request.supported_reasoning_effort(&PromptCapabilities::new()),
+            None
+        );
+
+        let request: PromptRequest = serde_json::from_value(json!({
+            "sessionId": "sess_abc123",
+            "prompt": [],
+            "reasoningEffort": "extreme"
+        }))
+        .unwrap();
+        assert_eq!(
+            request.reasoning_effort,
+            Some(ReasoningEffort::Other("extreme".to_string()))
+        );
+        assert_eq!(request.supported_reasoning_effort(&supported), None);
+    }
+
+    #[cfg(feature = "unstable_sampling_overrides")]
+    #[test]
+    fn test_prompt_request_sampling_overrides_round_trip() {
+        let request = PromptRequest::new("sess_abc123", vec![]).overrides(
+            SamplingOverrides::new()
+                .temperature(0.5)
+                .top_p(0.75)
+                .max_tokens(1024),
+        );
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionId": "sess_abc123",
+                "prompt": [],
+                "overrides": {
+                    "temperature": 0.5,
+                    "topP": 0.75,
+                    "maxTokens": 1024
+                }
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<PromptRequest>(json).unwrap(),
+            request
+        );
+
+        let bounds: SamplingOverrides = serde_json::from_value(json!({
+            "temperature": 2.0,
+            "topP": 0.0,
+            "maxTokens": 1
+        }))
+        .unwrap();
+        assert_eq!(bounds.temperature, Some(2.0));
+        assert_eq!(bounds.top_p, Some(0.0));
+        assert_eq!(bounds.max_tokens, Some(1));
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_sampling_overrides")]
     #[test]
-    fn test_set_provider_request_serialization() {
-        use std::collections::HashMap;
-
-        let mut headers = HashMap::new();
-        headers.insert("Authorization".to_string(), "Bearer sk-test".to_string());
+    fn test_sampling_overrides_reject_out_of_range() {
+        for overrides in [
+            json!({ "temperature": -0.5 }),
+            json!({ "temperature": 2.5 }),
+            json!({ "topP": 1.5 }),
+            json!({ "maxTokens": 0 }),
+        ] {
+            assert!(serde_json::from_value::<SamplingOverrides>(overrides.clone()).is_err());
+
+            let request: PromptRequest = serde_json::from_value(json!({
+                "sessionId": "sess_abc123",
+                "prompt": [],
+                "overrides": overrides
+            }))
+            .unwrap();
+            assert_eq!(request.overrides, None);
+        }
+    }
 
-        let request =
-            SetProviderRequest::new("main", LlmProtocol::OpenAi, "https://api.openai.com/v1")
-                .headers(headers);
+    #[cfg(feature = "unstable_sampling_overrides")]
+    #[test]
+    fn test_prompt_request_sampling_overrides_require_capability() {
+        let request = PromptRequest::new("sess_abc123", vec![])
+            .overrides(SamplingOverrides::new().temperature(0.5));
+        let supported = PromptCapabilities::new()
+            .sampling_overrides(PromptSamplingOverridesCapabilities::new());
+        assert_eq!(
+            request.supported_overrides(&supported),
+            Some(&SamplingOverrides::new().temperature(0.5))
+        );
+        assert_eq!(
+            request.supported_overrides(&PromptCapabilities::new()),
+            None
+        );
+    }
 
+    #[cfg(feature = "unstable_prompt_deadline")]
+    #[test]
+    fn test_prompt_request_deadline_round_trip() {
+        let request = PromptRequest::new("sess_abc123", vec![]).deadline_ms(30_000);
         let json = serde_json::to_value(&request).unwrap();
         assert_eq!(
             json,
             json!({
-                "providerId": "main",
-                "apiType": "openai",
-                "baseUrl": "https://api.openai.com/v1",
-                "headers": {
-                    "Authorization": "Bearer sk-test"
-                }
+                "sessionId": "sess_abc123",
+                "prompt": [],
+                "deadlineMs": 30_000
             })
         );
-
-        let deserialized: SetProviderRequest = serde_json::from_value(json).unwrap();
-        assert_eq!(deserialized.provider_id.to_string(), "main");
-        assert_eq!(deserialized.api_type, LlmProtocol::OpenAi);
-        assert_eq!(deserialized.base_url, "https://api.openai.com/v1");
-        assert_eq!(deserialized.headers.len(), 1);
         assert_eq!(
-            deserialized.headers.get("Authorization").unwrap(),
-            "Bearer sk-test"
+            serde_json::from_value::<PromptRequest>(json).unwrap(),
+            request
         );
-    }
 
-    #[cfg(feature = "unstable_llm_providers")]
-    #[test]
-    fn test_set_provider_request_omits_empty_headers() {
-        let request =
-            SetProviderRequest::new("main", LlmProtocol::Anthropic, "https://api.anthropic.com");
+        let request: PromptRequest = serde_json::from_value(json!({
+            "sessionId": "sess_abc123",
+            "prompt": [],
+            "deadlineMs": -1
+        }))
+        .unwrap();
+        assert_eq!(request.deadline_ms, None);
 
-        let json = serde_json::to_value(&request).unwrap();
-        // headers should be omitted when empty
-        assert!(!json.as_object().unwrap().contains_key("headers"));
+        let json = serde_json::to_value(StopReason::Deadline).unwrap();
+        assert_eq!(json, json!("deadline"));
+        assert_eq!(
+            serde_json::from_value::<StopReason>(json).unwrap(),
+            StopReason::Deadline
+        );
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_concurrent_prompts")]
     #[test]
-    fn test_disable_provider_request_serialization() {
-        let request = DisableProviderRequest::new("secondary");
+    fn test_check_prompt_admission() {
+        let session_id = SessionId::new("sess_abc123");
+        let serial = AgentCapabilities::new();
+        assert!(serial.check_prompt_admission(&session_id, 0).is_ok());
 
-        let json = serde_json::to_value(&request).unwrap();
-        assert_eq!(json, json!({ "providerId": "secondary" }));
+        let error = serial.check_prompt_admission(&session_id, 1).unwrap_err();
+        assert_eq!(error.code, crate::v2::ErrorCode::SessionBusy);
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({
+                "code": -32004,
+                "message": "Session busy",
+                "data": { "sessionId": "sess_abc123" }
+            })
+        );
 
-        let deserialized: DisableProviderRequest = serde_json::from_value(json).unwrap();
-        assert_eq!(deserialized.provider_id.to_string(), "secondary");
+        let capabilities: AgentCapabilities =
+            serde_json::from_value(json!({ "concurrentPrompts": {} })).unwrap();
+        let concurrent =
+            AgentCapabilities::new().concurrent_prompts(ConcurrentPromptsCapabilities::new());
+        assert_eq!(capabilities, concurrent);
+        assert!(concurrent.check_prompt_admission(&session_id, 0).is_ok());
+        assert!(concurrent.check_prompt_admission(&session_id, 3).is_ok());
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_accepted_mime_types")]
     #[test]
-    fn test_providers_capabilities_serialization() {
-        let caps = ProvidersCapabilities::new();
-
-        let json = serde_json::to_value(&caps).unwrap();
-        assert_eq!(json, json!({}));
+    fn test_accepted_mime_types() {
+        let capabilities: AgentCapabilities = serde_json::from_value(json!({
+            "acceptedMimeTypes": ["image/png", "audio/*", 42]
+        }))
+        .unwrap();
+        assert_eq!(
+            capabilities,
+            AgentCapabilities::new()
+                .accepted_mime_types(vec!["image/png".to_string(), "audio/*".to_string()])
+        );
 
-        let deserialized: ProvidersCapabilities = serde_json::from_value(json).unwrap();
-        assert!(deserialized.meta.is_none());
+        // Exact match, ignoring case and parameters.
+        assert_eq!(capabilities.accepts("image/png"), Some(true));
+        assert_eq!(
+            capabilities.accepts("IMAGE/PNG; name=chart.png"),
+            Some(true)
+        );
+        // Wildcard match.
+        assert_eq!(capabilities.accepts("audio/wav"), Some(true));
+        assert_eq!(capabilities.accepts("audio/mpeg"), Some(true));
+        // Rejection.
+        assert_eq!(capabilities.accepts("image/jpeg"), Some(false));
+        assert_eq!(capabilities.accepts("video/mp4"), Some(false));
+        assert_eq!(capabilities.accepts("png"), Some(false));
+        assert_eq!(
+            AgentCapabilities::new()
+                .accepted_mime_types(vec!["*/*".to_string()])
+                .accepts("video/mp4"),
+            Some(true)
+        );
+        // No advertised types leaves the decision to `promptCapabilities`.
+        assert_eq!(AgentCapabilities::new().accepts("image/png"), None);
+        assert_eq!(AgentCapabilities::new().accepts("png"), None);
     }
 
-    #[cfg(feature = "unstable_llm_providers")]
+    #[cfg(feature = "unstable_input_request")]
     #[test]
-    fn test_agent_capabilities_with_providers() {
-        let caps = AgentCapabilities::new().providers(ProvidersCapabilities::new());
+    fn test_input_request_answer_roundtrip() {
+        use crate::v2::{InputRequest, SessionUpdate};
 
-        let json = serde_json::to_value(&caps).unwrap();
-        assert_eq!(json["providers"], json!({}));
+        let question = SessionUpdate::InputRequest(InputRequest::new(
+            "input_1",
+            "Which branch should I deploy?",
+        ));
+        let json = serde_json::to_value(&question).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "input_request",
+                "inputId": "input_1",
+                "prompt": "Which branch should I deploy?"
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SessionUpdate>(json).unwrap(),
+            question
+        );
 
-        let deserialized: AgentCapabilities = serde_json::from_value(json).unwrap();
-        assert!(deserialized.providers.is_some());
+        let answer = ClientRequest::ProvideInputRequest(
+            ProvideInputRequest::new("sess_1", "input_1", "main").into(),
+        );
+        assert_eq!(answer.method(), "session/provide_input");
+        assert_eq!(
+            AGENT_METHOD_NAMES.session_provide_input,
+            "session/provide_input"
+        );
+
+        let ClientRequest::ProvideInputRequest(params) = answer else {
+            unreachable!();
+        };
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionId": "sess_1",
+                "inputId": "input_1",
+                "text": "main"
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<ProvideInputRequest>(json).unwrap(),
+            *params
+        );
+        assert_eq!(
+            serde_json::to_value(ProvideInputResponse::new()).unwrap(),
+            json!({})
+        );
     }
 
+    #[cfg(feature = "unstable_edit_conflict")]
     #[test]
-    fn test_agent_capabilities_session_is_explicit() {
-        let json = serde_json::to_value(AgentCapabilities::new()).unwrap();
-        assert!(json.get("session").is_none());
-
-        let caps = AgentCapabilities::new().session(
-            SessionCapabilities::new()
-                .prompt(PromptCapabilities::new().image(PromptImageCapabilities::new()))
-                .mcp(McpCapabilities::new().stdio(McpStdioCapabilities::new()))
-                .load(SessionLoadCapabilities::new()),
+    fn test_report_edit_conflict_serialization() {
+        let notification = ReportEditConflictNotification::new(
+            "sess_1",
+            "call_1",
+            ConflictReport::new("/src/main.rs", "fn main() {}\n", None),
+        );
+        assert_eq!(
+            ClientNotification::ReportEditConflictNotification(Box::new(notification.clone()))
+                .method(),
+            "session/report_edit_conflict"
         );
 
+        let json = serde_json::to_value(&notification).unwrap();
         assert_eq!(
-            serde_json::to_value(&caps).unwrap(),
+            json,
             json!({
-                "session": {
-                    "prompt": {
-                        "image": {}
-                    },
-                    "mcp": {
-                        "stdio": {}
-                    },
-                    "load": {}
+                "sessionId": "sess_1",
+                "toolCallId": "call_1",
+                "report": {
+                    "path": "/src/main.rs",
+                    "expected": "fn main() {}\n"
                 }
             })
         );
+        assert_eq!(
+            serde_json::from_value::<ReportEditConflictNotification>(json).unwrap(),
+            notification
+        );
+    }
 
-        let deserialized: AgentCapabilities = serde_json::from_value(json!({
-            "session": false
-        }))
+    #[cfg(feature = "unstable_session_compact")]
+    #[test]
+    fn test_compact_session_roundtrip() {
+        let request = CompactSessionRequest::new("sess_1").target_tokens(20_000);
+        let routed = ClientRequest::CompactSessionRequest(Box::new(request.clone()));
+        assert_eq!(routed.method(), AGENT_METHOD_NAMES.session_compact);
+        assert_eq!(AGENT_METHOD_NAMES.session_compact, "session/compact");
+
+        let json = serde_json::to_value(&routed).unwrap();
+        assert_eq!(
+            json,
+            json!({ "sessionId": "sess_1", "targetTokens": 20000 })
+        );
+        assert_eq!(
+            serde_json::from_value::<CompactSessionRequest>(json).unwrap(),
+            request
+        );
+        assert_eq!(
+            serde_json::from_value::<CompactSessionRequest>(json!({ "sessionId": "sess_1" }))
+                .unwrap()
+                .target_tokens,
+            None
+        );
+
+        let response = CompactSessionResponse::new(42).summary("Refactored the parser.");
+        let json = serde_json::to_value(AgentResponse::CompactSessionResponse(Box::new(
+            response.clone(),
+        )))
         .unwrap();
-        assert!(deserialized.session.is_none());
+        assert_eq!(
+            json,
+            json!({ "removedMessages": 42, "summary": "Refactored the parser." })
+        );
+        assert_eq!(
+            serde_json::from_value::<CompactSessionResponse>(json).unwrap(),
+            response
+        );
+
+        let capabilities = SessionCapabilities::new().compact(SessionCompactCapabilities::new());
+        assert_eq!(
+            serde_json::to_value(&capabilities).unwrap()["compact"],
+            json!({})
+        );
     }
 
+    #[cfg(feature = "unstable_session_set_title")]
     #[test]
-    fn test_prompt_capabilities_serialize_supported_content_as_objects() {
-        let caps = PromptCapabilities::new()
-            .image(PromptImageCapabilities::new())
-            .audio(PromptAudioCapabilities::new())
-            .embedded_context(PromptEmbeddedContextCapabilities::new());
+    fn test_set_session_title_roundtrip() {
+        let request = SetSessionTitleRequest::new("sess_1", "Fix flaky parser test");
+        let routed = ClientRequest::SetSessionTitleRequest(Box::new(request.clone()));
+        assert_eq!(routed.method(), AGENT_METHOD_NAMES.session_set_title);
+        assert_eq!(AGENT_METHOD_NAMES.session_set_title, "session/set_title");
 
+        let json = serde_json::to_value(&routed).unwrap();
         assert_eq!(
-            serde_json::to_value(&caps).unwrap(),
-            json!({
-                "image": {},
-                "audio": {},
-                "embeddedContext": {}
-            })
+            json,
+            json!({ "sessionId": "sess_1", "title": "Fix flaky parser test" })
+        );
+        assert_eq!(
+            serde_json::from_value::<SetSessionTitleRequest>(json).unwrap(),
+            request
         );
 
-        let deserialized: PromptCapabilities = serde_json::from_value(json!({
-            "image": null,
-            "audio": false,
-            "embeddedContext": {}
-        }))
+        let json = serde_json::to_value(AgentResponse::SetSessionTitleResponse(Box::new(
+            SetSessionTitleResponse::new(),
+        )))
         .unwrap();
-        assert!(deserialized.image.is_none());
-        assert!(deserialized.audio.is_none());
-        assert!(deserialized.embedded_context.is_some());
+        assert_eq!(json, json!({}));
+        assert_eq!(
+            serde_json::from_value::<SetSessionTitleResponse>(json).unwrap(),
+            SetSessionTitleResponse::new()
+        );
+
+        let capabilities = SessionCapabilities::new().set_title(SessionSetTitleCapabilities::new());
+        assert_eq!(
+            serde_json::to_value(&capabilities).unwrap()["setTitle"],
+            json!({})
+        );
     }
 
+    #[cfg(feature = "unstable_cancel_tool_call")]
     #[test]
-    fn test_mcp_capabilities_serialize_supported_transports_as_objects() {
-        let caps = McpCapabilities::new()
-            .stdio(McpStdioCapabilities::new())
-            .http(McpHttpCapabilities::new());
+    fn test_cancel_tool_call_roundtrip() {
+        let request = CancelToolCallRequest::new("sess_1", "call_grep");
+        let routed = ClientRequest::CancelToolCallRequest(Box::new(request.clone()));
+        assert_eq!(routed.method(), AGENT_METHOD_NAMES.session_cancel_tool_call);
+        assert_eq!(
+            AGENT_METHOD_NAMES.session_cancel_tool_call,
+            "session/cancel_tool_call"
+        );
 
+        let json = serde_json::to_value(&routed).unwrap();
         assert_eq!(
-            serde_json::to_value(&caps).unwrap(),
-            json!({
-                "stdio": {},
-                "http": {}
-            })
+            json,
+            json!({ "sessionId": "sess_1", "toolCallId": "call_grep" })
+        );
+        assert_eq!(
+            serde_json::from_value::<CancelToolCallRequest>(json).unwrap(),
+            request
         );
 
-        let deserialized: McpCapabilities = serde_json::from_value(json!({
-            "stdio": null,
-            "http": false
-        }))
+        let json = serde_json::to_value(AgentResponse::CancelToolCallResponse(Box::new(
+            CancelToolCallResponse::new(),
+        )))
         .unwrap();
-        assert!(deserialized.stdio.is_none());
-        assert!(deserialized.http.is_none());
+        assert_eq!(json, json!({}));
+
+        let capabilities =
+            SessionCapabilities::new().cancel_tool_call(SessionCancelToolCallCapabilities::new());
+        assert_eq!(
+            serde_json::to_value(&capabilities).unwrap()["cancelToolCall"],
+            json!({})
+        );
     }
 
-    #[cfg(feature = "unstable_mcp_over_acp")]
+    #[cfg(feature = "unstable_cancel_tool_call")]
     #[test]
-    fn test_mcp_capabilities_serialize_acp_support_as_object() {
-        let caps = McpCapabilities::new().acp(McpAcpCapabilities::new());
+    fn test_cancel_tool_call_check() {
+        use crate::v2::ToolCallStatus;
 
+        let request = CancelToolCallRequest::new("sess_1", "call_grep");
+        assert_eq!(request.check(Some(&ToolCallStatus::InProgress)), Ok(true));
+        assert_eq!(request.check(Some(&ToolCallStatus::Pending)), Ok(true));
+        assert_eq!(request.check(Some(&ToolCallStatus::Completed)), Ok(false));
+        assert_eq!(request.check(Some(&ToolCallStatus::Cancelled)), Ok(false));
+
+        let error = request.check(None).unwrap_err();
         assert_eq!(
-            serde_json::to_value(&caps).unwrap(),
+            serde_json::to_value(&error).unwrap(),
             json!({
-                "acp": {}
+                "code": -32602,
+                "message": "Invalid params",
+                "data": { "toolCallId": "call_grep" }
             })
         );
     }
+
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[test]
+    fn test_load_session_replay_pages() {
+        let history: Vec<usize> = (0..5).collect();
+
+        let first = LoadSessionRequest::new("sess_1", "/workspace").limit(3);
+        let (range, next_cursor) = first.replay_page(history.len()).unwrap();
+        let mut replayed = history[range].to_vec();
+        let next_cursor = next_cursor.expect("more history remains after the first page");
+
+        let second = LoadSessionRequest::new("sess_1", "/workspace")
+            .cursor(next_cursor)
+            .limit(3);
+        let (range, next_cursor) = second.replay_page(history.len()).unwrap();
+        replayed.extend_from_slice(&history[range]);
+
+        // Two pages replay every update exactly once, in order.
+        assert_eq!(next_cursor, None);
+        assert_eq!(replayed, history);
+
+        // Without a limit the whole history fits in one page.
+        assert_eq!(
+            LoadSessionRequest::new("sess_1", "/workspace")
+                .replay_page(history.len())
+                .unwrap(),
+            (0..5, None)
+        );
+        assert_eq!(
+            SessionCapabilities::new().load_pagination(SessionLoadPaginationCapabilities::new()),
+            serde_json::from_value(json!({"loadPagination": {}})).unwrap()
+        );
+    }
+
+    #[cfg(feature = "unstable_session_load_pagination")]
+    #[test]
+    fn test_load_session_invalid_cursor() {
+        assert_eq!(
+            serde_json::from_value::<LoadSessionRequest>(json!({
+                "sessionId": "sess_1",
+                "cwd": "/workspace",
+                "cursor": "sess_1:2"
+            }))
+            .unwrap(),
+            LoadSessionRequest::new("sess_1", "/workspace").cursor("sess_1:2")
+        );
+        assert!(
+            serde_json::from_value::<LoadSessionRequest>(json!({
+                "sessionId": "sess_1",
+                "cwd": "/workspace",
+                "cursor": 42
+            }))
+            .is_err()
+        );
+
+        let (_, next_cursor) = LoadSessionRequest::new("sess_1", "/workspace")
+            .limit(1)
+            .replay_page(3)
+            .unwrap();
+        let foreign_cursor = next_cursor.unwrap();
+
+        for cursor in [foreign_cursor.as_str(), "garbage", "sess_2:9"] {
+            let error = LoadSessionRequest::new("sess_2", "/workspace")
+                .cursor(cursor)
+                .replay_page(3)
+                .unwrap_err();
+            assert_eq!(
+                serde_json::to_value(&error).unwrap(),
+                json!({
+                    "code": -32602,
+                    "message": "Invalid params",
+                    "data": {"cursor": cursor}
+                })
+            );
+        }
+    }
 }
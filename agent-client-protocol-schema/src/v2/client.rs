@@ -10,8 +10,22 @@ use schemars::{JsonSchema, Schema};
 use serde::{Deserialize, Serialize};
 use serde_with::{DefaultOnError, VecSkipError, serde_as, skip_serializing_none};
 
+#[cfg(feature = "unstable_diff_hunks")]
+use super::DiffHunkDecision;
+#[cfg(feature = "unstable_json_content")]
+use super::JsonContentCapabilities;
+#[cfg(feature = "unstable_plan_entry_update")]
+use super::PlanEntryUpdate;
 #[cfg(feature = "unstable_plan_operations")]
 use super::PlanRemoved;
+#[cfg(feature = "unstable_table")]
+use super::TableCapabilities;
+#[cfg(feature = "unstable_tool_calls_cancelled")]
+use super::ToolCallId;
+#[cfg(feature = "unstable_tool_call_input_delta")]
+use super::ToolCallInputDelta;
+#[cfg(feature = "unstable_turn_id")]
+use super::TurnId;
 #[cfg(feature = "unstable_end_turn_token_usage")]
 use super::Usage;
 #[cfg(feature = "unstable_elicitation")]
@@ -23,8 +37,15 @@ use super::{
     ContentBlock, ExtNotification, ExtRequest, ExtResponse, Meta, PlanUpdate, SessionConfigOption,
     SessionId, StopReason, ToolCallContentChunk, ToolCallUpdate,
 };
+#[cfg(feature = "unstable_sampling")]
+use super::{CreateMessageRequest, CreateMessageResponse, SamplingCapabilities};
+#[cfg(feature = "unstable_attachment")]
+use super::{EmbeddedResource, EmbeddedResourceResource};
 use crate::{IntoMaybeUndefined, IntoOption, MaybeUndefined, SkipListener};
 
+#[cfg(feature = "unstable_user_message_echo")]
+use super::agent::is_false;
+
 #[cfg(feature = "unstable_mcp_over_acp")]
 use super::mcp::{
     ConnectMcpRequest, ConnectMcpResponse, DisconnectMcpRequest, DisconnectMcpResponse,
@@ -53,6 +74,34 @@ pub struct UpdateSessionNotification {
     pub session_id: SessionId,
     /// The actual update content.
     pub update: SessionUpdate,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Position of this notification in the session's stream of updates.
+    ///
+    /// When present, the first notification for a session carries `0` and each
+    /// following notification carries the previous value plus one, so clients can
+    /// restore the original order if notifications are delivered out of order.
+    #[cfg(feature = "unstable_session_update_sequence")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub sequence: Option<u64>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The prompt turn that produced this update.
+    ///
+    /// Echoes the `turnId` of the `session/prompt` request that started the
+    /// turn. Agents MUST set it on every update of a turn whose request carried
+    /// one.
+    #[cfg(feature = "unstable_turn_id")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub turn_id: Option<TurnId>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -72,6 +121,10 @@ impl UpdateSessionNotification {
         Self {
             session_id: session_id.into(),
             update,
+            #[cfg(feature = "unstable_session_update_sequence")]
+            sequence: None,
+            #[cfg(feature = "unstable_turn_id")]
+            turn_id: None,
             meta: None,
         }
     }
@@ -86,6 +139,30 @@ impl UpdateSessionNotification {
         self.meta = meta.into_option();
         self
     }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Position of this notification in the session's stream of updates.
+    #[cfg(feature = "unstable_session_update_sequence")]
+    #[must_use]
+    pub fn sequence(mut self, sequence: impl IntoOption<u64>) -> Self {
+        self.sequence = sequence.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The prompt turn that produced this update.
+    #[cfg(feature = "unstable_turn_id")]
+    #[must_use]
+    pub fn turn_id(mut self, turn_id: impl IntoOption<TurnId>) -> Self {
+        self.turn_id = turn_id.into_option();
+        self
+    }
 }
 
 /// Different types of updates that can be sent during session processing.
@@ -97,6 +174,7 @@ impl UpdateSessionNotification {
 #[serde(tag = "sessionUpdate", rename_all = "snake_case")]
 #[schemars(extend("discriminator" = {"propertyName": "sessionUpdate"}))]
 #[non_exhaustive]
+#[allow(clippy::large_enum_variant)]
 pub enum SessionUpdate {
     /// A chunk of the user's message being streamed.
     UserMessageChunk(ContentChunk),
@@ -132,6 +210,13 @@ pub enum SessionUpdate {
     ToolCallContentChunk(ToolCallContentChunk),
     /// A tool call has been created or updated.
     ToolCallUpdate(ToolCallUpdate),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A fragment of a tool call's raw input being streamed.
+    #[cfg(feature = "unstable_tool_call_input_delta")]
+    ToolCallInputDelta(ToolCallInputDelta),
     /// A content update for a plan identified by ID.
     /// See protocol docs: [Agent Plan](https://agentclientprotocol.com/protocol/agent-plan)
     PlanUpdate(PlanUpdate),
@@ -142,6 +227,13 @@ pub enum SessionUpdate {
     /// Removal notice for a plan identified by ID.
     #[cfg(feature = "unstable_plan_operations")]
     PlanRemoved(PlanRemoved),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A change to a single plan entry.
+    #[cfg(feature = "unstable_plan_entry_update")]
+    PlanEntryUpdate(PlanEntryUpdate),
     /// Available commands are ready or have changed
     AvailableCommandsUpdate(AvailableCommandsUpdate),
     /// Session configuration options have been updated.
@@ -150,6 +242,44 @@ pub enum SessionUpdate {
     SessionInfoUpdate(SessionInfoUpdate),
     /// Context window and cost update for the session.
     UsageUpdate(UsageUpdate),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A transient status line that replaces the previous one.
+    #[cfg(feature = "unstable_session_notice")]
+    Notice(Notice),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// In-flight tool calls that were aborted, typically after `session/cancel`.
+    #[cfg(feature = "unstable_tool_calls_cancelled")]
+    ToolCallsCancelled(ToolCallsCancelled),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A file the agent produced, such as a chart or a report, for the client
+    /// to offer for download.
+    #[cfg(feature = "unstable_attachment")]
+    Attachment(Attachment),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A free-form question for the user. The prompt turn waits until the client
+    /// answers with `session/provide_input` or the turn is cancelled.
+    #[cfg(feature = "unstable_input_request")]
+    InputRequest(InputRequest),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Follow-up prompts the user might want to send next. Each update replaces
+    /// the previous list.
+    #[cfg(feature = "unstable_suggested_prompts")]
+    SuggestedPrompts(SuggestedPrompts),
     /// Custom or future session update.
     ///
     /// Values beginning with `_` are reserved for implementation-specific
@@ -163,6 +293,110 @@ pub enum SessionUpdate {
     Other(OtherSessionUpdate),
 }
 
+impl SessionUpdate {
+    /// Whether this update belongs in the session's persisted transcript.
+    ///
+    /// Transcript updates are what a client stores and shows again when the
+    /// session is reopened:
+    /// - user, agent and thought messages and their chunks
+    /// - tool call content chunks, tool call updates and cancelled tool calls
+    /// - plan updates and plan changes
+    /// - attachments and input requests
+    /// - custom or future updates, so that storing and replaying history
+    ///   preserves them
+    ///
+    /// The rest are ephemeral. They report state that a later update replaces,
+    /// and can be dropped once applied:
+    /// - state updates, available commands, config options and session info
+    /// - usage, notices and suggested prompts
+    /// - streamed tool call input, since agents still send the complete
+    ///   `rawInput` in a tool call update
+    #[must_use]
+    pub fn is_transcript(&self) -> bool {
+        match self {
+            Self::UserMessageChunk(_)
+            | Self::UserMessage(_)
+            | Self::AgentMessageChunk(_)
+            | Self::AgentMessage(_)
+            | Self::AgentThoughtChunk(_)
+            | Self::AgentThought(_)
+            | Self::ToolCallContentChunk(_)
+            | Self::ToolCallUpdate(_)
+            | Self::PlanUpdate(_)
+            | Self::Other(_) => true,
+            #[cfg(feature = "unstable_plan_operations")]
+            Self::PlanRemoved(_) => true,
+            #[cfg(feature = "unstable_plan_entry_update")]
+            Self::PlanEntryUpdate(_) => true,
+            #[cfg(feature = "unstable_tool_calls_cancelled")]
+            Self::ToolCallsCancelled(_) => true,
+            #[cfg(feature = "unstable_attachment")]
+            Self::Attachment(_) => true,
+            #[cfg(feature = "unstable_input_request")]
+            Self::InputRequest(_) => true,
+            Self::StateUpdate(_)
+            | Self::AvailableCommandsUpdate(_)
+            | Self::ConfigOptionUpdate(_)
+            | Self::SessionInfoUpdate(_)
+            | Self::UsageUpdate(_) => false,
+            #[cfg(feature = "unstable_tool_call_input_delta")]
+            Self::ToolCallInputDelta(_) => false,
+            #[cfg(feature = "unstable_session_notice")]
+            Self::Notice(_) => false,
+            #[cfg(feature = "unstable_suggested_prompts")]
+            Self::SuggestedPrompts(_) => false,
+        }
+    }
+
+    /// Renders the message content of this update as markdown, for exporting a
+    /// conversation.
+    ///
+    /// User and agent messages and their chunks render their content with
+    /// [`ContentBlock::to_markdown`], joining the blocks of a whole message with
+    /// blank lines. Thoughts render the same way inside a blockquote. Messages
+    /// that do not replace their content, and every other update, return
+    /// `None`.
+    #[must_use]
+    pub fn to_markdown(&self) -> Option<String> {
+        fn join(blocks: &[ContentBlock]) -> String {
+            blocks
+                .iter()
+                .map(ContentBlock::to_markdown)
+                .collect::<Vec<_>>()
+                .join("\n\n")
+        }
+
+        match self {
+            Self::UserMessageChunk(chunk) | Self::AgentMessageChunk(chunk) => {
+                Some(chunk.content.to_markdown())
+            }
+            Self::UserMessage(message) => message.content.value().map(|blocks| join(blocks)),
+            Self::AgentMessage(message) => message.content.value().map(|blocks| join(blocks)),
+            Self::AgentThoughtChunk(chunk) => Some(blockquote(&chunk.content.to_markdown())),
+            Self::AgentThought(thought) => thought
+                .content
+                .value()
+                .map(|blocks| blockquote(&join(blocks))),
+            _ => None,
+        }
+    }
+}
+
+/// Prefixes every line of `markdown` with `>`.
+fn blockquote(markdown: &str) -> String {
+    markdown
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                ">".to_string()
+            } else {
+                format!("> {line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Custom or future session update payload.
 ///
 /// This preserves the unknown `sessionUpdate` discriminator and the rest of the
@@ -261,13 +495,27 @@ fn other_session_update_schema(schema: &mut Schema) {
             "state_update",
             "tool_call_content_chunk",
             "tool_call_update",
+            #[cfg(feature = "unstable_tool_call_input_delta")]
+            "tool_call_input_delta",
             "plan_update",
             "available_commands_update",
             "config_option_update",
             "session_info_update",
             #[cfg(feature = "unstable_plan_operations")]
             "plan_removed",
+            #[cfg(feature = "unstable_plan_entry_update")]
+            "plan_entry_update",
             "usage_update",
+            #[cfg(feature = "unstable_session_notice")]
+            "notice",
+            #[cfg(feature = "unstable_tool_calls_cancelled")]
+            "tool_calls_cancelled",
+            #[cfg(feature = "unstable_attachment")]
+            "attachment",
+            #[cfg(feature = "unstable_input_request")]
+            "input_request",
+            #[cfg(feature = "unstable_suggested_prompts")]
+            "suggested_prompts",
         ],
     );
 }
@@ -736,20 +984,27 @@ impl Cost {
     }
 }
 
-/// A streamed item of content
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A transient status line for the session, such as "Searching the web..." or
+/// "Reading 42 files".
+///
+/// Notices are not part of the conversation history. Clients render the most
+/// recent notice in place of any previous one, and a notice with empty `text`
+/// clears it.
+#[cfg(feature = "unstable_session_notice")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct ContentChunk {
-    /// A unique identifier for the message this chunk belongs to.
-    ///
-    /// All chunks belonging to the same message share the same `messageId`.
-    /// A change in `messageId` indicates a new message has started.
-    pub message_id: MessageId,
-    /// A single item of content
-    pub content: ContentBlock,
+pub struct Notice {
+    /// The status text to display. An empty string clears the current notice.
+    pub text: String,
+    /// How prominently the notice should be displayed.
+    pub level: NoticeLevel,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -762,17 +1017,30 @@ pub struct ContentChunk {
     pub meta: Option<Meta>,
 }
 
-impl ContentChunk {
-    /// Builds [`ContentChunk`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_session_notice")]
+impl Notice {
+    /// Builds [`Notice`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(content: ContentBlock, message_id: impl Into<MessageId>) -> Self {
+    pub fn new(text: impl Into<String>, level: NoticeLevel) -> Self {
         Self {
-            content,
-            message_id: message_id.into(),
+            text: text.into(),
+            level,
             meta: None,
         }
     }
 
+    /// Builds a [`Notice`] that clears the current status line.
+    #[must_use]
+    pub fn clear() -> Self {
+        Self::new(String::new(), NoticeLevel::Info)
+    }
+
+    /// Whether this notice clears the current status line instead of replacing it.
+    #[must_use]
+    pub fn is_clear(&self) -> bool {
+        self.text.is_empty()
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -785,63 +1053,88 @@ impl ContentChunk {
     }
 }
 
-/// A user message upsert.
+/// **UNSTABLE**
 ///
-/// Only [`UserMessage::message_id`] is required. Other fields have patch
-/// semantics: omitted fields leave the existing message value unchanged, `null`
-/// clears or unsets the value, and concrete values replace the previous value.
-/// For a new `messageId`, omitted fields use client defaults. `content` is
-/// replaced as a whole array; send `[]` or `null` to clear it.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// Message updates and chunks are applied in the order they are received. When
-/// a `user_message` update includes `content`, that array replaces any content
-/// previously accumulated for the message, including content from earlier
-/// chunks. Later chunks with the same `messageId` append to the current
-/// content.
+/// Severity of a [`Notice`].
+#[cfg(feature = "unstable_session_notice")]
+#[derive(Deserialize, Serialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum NoticeLevel {
+    /// Routine progress information.
+    Info,
+    /// Something the user may want to pay attention to.
+    Warning,
+    /// Custom or future notice level.
+    ///
+    /// Values beginning with `_` are reserved for implementation-specific
+    /// extensions. Unknown values that do not begin with `_` are reserved for
+    /// future ACP variants.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Reports which in-flight tool calls the agent aborted.
+///
+/// Agents send this after `session/cancel` so clients can mark the affected
+/// tool calls as cancelled without waiting for an update per tool call. An
+/// empty `tool_call_ids` list means every tool call that has not finished.
+#[cfg(feature = "unstable_tool_calls_cancelled")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct UserMessage {
-    /// A unique identifier for the message.
-    pub message_id: MessageId,
-    /// Complete replacement content for this message.
-    #[serde_as(deserialize_as = "DefaultOnError<MaybeUndefined<VecSkipError<_, SkipListener>>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
-    pub content: MaybeUndefined<Vec<ContentBlock>>,
+pub struct ToolCallsCancelled {
+    /// The aborted tool calls. An empty list means every unfinished tool call
+    /// in the session.
+    pub tool_call_ids: Vec<ToolCallId>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
     ///
     /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError<MaybeUndefined<_>>")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(
-        rename = "_meta",
-        default,
-        skip_serializing_if = "MaybeUndefined::is_undefined"
-    )]
-    pub meta: MaybeUndefined<Meta>,
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-impl UserMessage {
-    /// Builds [`UserMessage`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_tool_calls_cancelled")]
+impl ToolCallsCancelled {
+    /// Builds [`ToolCallsCancelled`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(message_id: impl Into<MessageId>) -> Self {
+    pub fn new(tool_call_ids: Vec<ToolCallId>) -> Self {
         Self {
-            message_id: message_id.into(),
-            content: MaybeUndefined::Undefined,
-            meta: MaybeUndefined::Undefined,
+            tool_call_ids,
+            meta: None,
         }
     }
 
-    /// Complete replacement content for this message.
+    /// Builds a [`ToolCallsCancelled`] that covers every unfinished tool call.
     #[must_use]
-    pub fn content(mut self, content: impl IntoMaybeUndefined<Vec<ContentBlock>>) -> Self {
-        self.content = content.into_maybe_undefined();
-        self
+    pub fn all() -> Self {
+        Self::new(Vec::new())
+    }
+
+    /// Whether this update covers every unfinished tool call.
+    #[must_use]
+    pub fn is_all(&self) -> bool {
+        self.tool_call_ids.is_empty()
+    }
+
+    /// Whether the tool call with `tool_call_id` was cancelled, assuming it had
+    /// not finished yet.
+    #[must_use]
+    pub fn contains(&self, tool_call_id: &ToolCallId) -> bool {
+        self.is_all() || self.tool_call_ids.contains(tool_call_id)
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -850,69 +1143,72 @@ impl UserMessage {
     ///
     /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoMaybeUndefined<Meta>) -> Self {
-        self.meta = meta.into_maybe_undefined();
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
         self
     }
 }
 
-/// An agent message upsert.
+/// **UNSTABLE**
 ///
-/// Only [`AgentMessage::message_id`] is required. Other fields have patch
-/// semantics: omitted fields leave the existing message value unchanged, `null`
-/// clears or unsets the value, and concrete values replace the previous value.
-/// For a new `messageId`, omitted fields use client defaults. `content` is
-/// replaced as a whole array; send `[]` or `null` to clear it.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// Message updates and chunks are applied in the order they are received. When
-/// an `agent_message` update includes `content`, that array replaces any
-/// content previously accumulated for the message, including content from
-/// earlier chunks. Later chunks with the same `messageId` append to the current
-/// content.
+/// A file the agent produced for the user, such as a chart or a report.
+///
+/// Unlike a diff, an attachment is not tied to a path in the workspace. Clients
+/// can offer to open or save it, using [`Attachment::name`] as the suggested
+/// file name.
+#[cfg(feature = "unstable_attachment")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct AgentMessage {
-    /// A unique identifier for the message.
-    pub message_id: MessageId,
-    /// Complete replacement content for this message.
-    #[serde_as(deserialize_as = "DefaultOnError<MaybeUndefined<VecSkipError<_, SkipListener>>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
-    pub content: MaybeUndefined<Vec<ContentBlock>>,
+pub struct Attachment {
+    /// The suggested file name, such as `report.pdf`. Clients MUST NOT treat
+    /// it as a path and SHOULD strip any directory components before saving.
+    pub name: String,
+    /// The file contents. Binary files SHOULD set `mimeType` on the blob.
+    pub content: EmbeddedResource,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
     ///
     /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError<MaybeUndefined<_>>")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(
-        rename = "_meta",
-        default,
-        skip_serializing_if = "MaybeUndefined::is_undefined"
-    )]
-    pub meta: MaybeUndefined<Meta>,
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-impl AgentMessage {
-    /// Builds [`AgentMessage`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_attachment")]
+impl Attachment {
+    /// Builds [`Attachment`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(message_id: impl Into<MessageId>) -> Self {
+    pub fn new(name: impl Into<String>, content: EmbeddedResource) -> Self {
         Self {
-            message_id: message_id.into(),
-            content: MaybeUndefined::Undefined,
-            meta: MaybeUndefined::Undefined,
+            name: name.into(),
+            content,
+            meta: None,
         }
     }
 
-    /// Complete replacement content for this message.
+    /// The MIME type to save the attachment with.
+    ///
+    /// Falls back to `text/plain` for text contents and
+    /// `application/octet-stream` for binary contents without a `mimeType`.
     #[must_use]
-    pub fn content(mut self, content: impl IntoMaybeUndefined<Vec<ContentBlock>>) -> Self {
-        self.content = content.into_maybe_undefined();
-        self
+    pub fn mime_type(&self) -> &str {
+        match &self.content.resource {
+            EmbeddedResourceResource::TextResourceContents(contents) => {
+                contents.mime_type.as_deref().unwrap_or("text/plain")
+            }
+            EmbeddedResourceResource::BlobResourceContents(contents) => contents
+                .mime_type
+                .as_deref()
+                .unwrap_or("application/octet-stream"),
+        }
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -921,109 +1217,90 @@ impl AgentMessage {
     ///
     /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoMaybeUndefined<Meta>) -> Self {
-        self.meta = meta.into_maybe_undefined();
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
         self
     }
 }
 
-/// An agent thought or reasoning message upsert.
+/// **UNSTABLE**
 ///
-/// Only [`AgentThought::message_id`] is required. Other fields have patch
-/// semantics: omitted fields leave the existing thought value unchanged, `null`
-/// clears or unsets the value, and concrete values replace the previous value.
-/// For a new `messageId`, omitted fields use client defaults. `content` is
-/// replaced as a whole array; send `[]` or `null` to clear it.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// Message updates and chunks are applied in the order they are received. When
-/// an `agent_thought` update includes `content`, that array replaces any
-/// content previously accumulated for the thought, including content from
-/// earlier chunks. Later chunks with the same `messageId` append to the current
-/// content.
+/// A free-form question the agent asks the user in the middle of a prompt turn.
+///
+/// Unlike `session/request_permission`, the answer is text rather than a choice
+/// between options. The agent blocks the turn until the client answers with
+/// `session/provide_input` or the turn is cancelled. Agents MUST NOT send this
+/// unless the client advertises the `inputRequest` capability.
+#[cfg(feature = "unstable_input_request")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct AgentThought {
-    /// A unique identifier for the thought message.
-    pub message_id: MessageId,
-    /// Complete replacement content for this thought message.
-    #[serde_as(deserialize_as = "DefaultOnError<MaybeUndefined<VecSkipError<_, SkipListener>>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
-    pub content: MaybeUndefined<Vec<ContentBlock>>,
+pub struct InputRequest {
+    /// Identifies the question within the session. Echoed back in
+    /// `session/provide_input`.
+    pub input_id: String,
+    /// The question to show the user.
+    pub prompt: String,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
     ///
     /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError<MaybeUndefined<_>>")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(
-        rename = "_meta",
-        default,
-        skip_serializing_if = "MaybeUndefined::is_undefined"
-    )]
-    pub meta: MaybeUndefined<Meta>,
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-impl AgentThought {
-    /// Builds [`AgentThought`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_input_request")]
+impl InputRequest {
+    /// Builds [`InputRequest`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(message_id: impl Into<MessageId>) -> Self {
+    pub fn new(input_id: impl Into<String>, prompt: impl Into<String>) -> Self {
         Self {
-            message_id: message_id.into(),
-            content: MaybeUndefined::Undefined,
-            meta: MaybeUndefined::Undefined,
+            input_id: input_id.into(),
+            prompt: prompt.into(),
+            meta: None,
         }
     }
 
-    /// Complete replacement content for this thought message.
-    #[must_use]
-    pub fn content(mut self, content: impl IntoMaybeUndefined<Vec<ContentBlock>>) -> Self {
-        self.content = content.into_maybe_undefined();
-        self
-    }
-
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
     ///
     /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoMaybeUndefined<Meta>) -> Self {
-        self.meta = meta.into_maybe_undefined();
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
         self
     }
 }
 
-/// Unique identifier for a message within a session.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
-#[serde(transparent)]
-#[from(Arc<str>, String, &'static str)]
-#[non_exhaustive]
-pub struct MessageId(pub Arc<str>);
-
-impl MessageId {
-    /// Wraps a protocol string as a typed [`MessageId`].
-    #[must_use]
-    pub fn new(id: impl Into<Arc<str>>) -> Self {
-        Self(id.into())
-    }
-}
-
-/// Available commands are ready or have changed
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Follow-up prompts the agent suggests, typically at the end of a turn.
+///
+/// Clients MAY show them as clickable chips. Selecting one sends its text as a
+/// regular `session/prompt`. Each update replaces the previous suggestions, and
+/// an empty list clears them.
+#[cfg(feature = "unstable_suggested_prompts")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct AvailableCommandsUpdate {
-    /// Commands the agent can execute.
+pub struct SuggestedPrompts {
+    /// The suggested prompts, in display order.
     #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
     #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub available_commands: Vec<AvailableCommand>,
+    pub prompts: Vec<String>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1036,12 +1313,13 @@ pub struct AvailableCommandsUpdate {
     pub meta: Option<Meta>,
 }
 
-impl AvailableCommandsUpdate {
-    /// Builds [`AvailableCommandsUpdate`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_suggested_prompts")]
+impl SuggestedPrompts {
+    /// Builds [`SuggestedPrompts`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(available_commands: Vec<AvailableCommand>) -> Self {
+    pub fn new(prompts: Vec<String>) -> Self {
         Self {
-            available_commands,
+            prompts,
             meta: None,
         }
     }
@@ -1058,22 +1336,37 @@ impl AvailableCommandsUpdate {
     }
 }
 
-/// Information about a command.
+/// A streamed item of content
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct AvailableCommand {
-    /// Command name (e.g., `create_plan`, `research_codebase`).
-    pub name: String,
-    /// Human-readable description of what the command does.
-    pub description: String,
-    /// Input for the command if required
+pub struct ContentChunk {
+    /// A unique identifier for the message this chunk belongs to.
+    ///
+    /// All chunks belonging to the same message share the same `messageId`.
+    /// A change in `messageId` indicates a new message has started.
+    pub message_id: MessageId,
+    /// A single item of content
+    pub content: ContentBlock,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether this chunk echoes user input the Client has already displayed, such as
+    /// the prompt sent with `session/prompt`.
+    ///
+    /// Clients SHOULD NOT render echoed chunks a second time. Only meaningful on
+    /// `user_message_chunk` updates.
+    ///
+    /// Defaults to `false`.
+    #[cfg(feature = "unstable_user_message_echo")]
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub input: Option<AvailableCommandInput>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    #[schemars(extend("default" = false))]
+    pub echo: bool,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1086,22 +1379,34 @@ pub struct AvailableCommand {
     pub meta: Option<Meta>,
 }
 
-impl AvailableCommand {
-    /// Builds [`AvailableCommand`] with the required fields set; optional fields start unset or empty.
+impl ContentChunk {
+    /// Builds [`ContentChunk`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+    pub fn new(content: ContentBlock, message_id: impl Into<MessageId>) -> Self {
         Self {
-            name: name.into(),
-            description: description.into(),
-            input: None,
+            content,
+            message_id: message_id.into(),
+            #[cfg(feature = "unstable_user_message_echo")]
+            echo: false,
             meta: None,
         }
     }
 
-    /// Input for the command if required
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether this chunk echoes user input the Client has already displayed, such as
+    /// the prompt sent with `session/prompt`.
+    ///
+    /// Clients SHOULD NOT render echoed chunks a second time. Only meaningful on
+    /// `user_message_chunk` updates.
+    ///
+    /// Defaults to `false`.
+    #[cfg(feature = "unstable_user_message_echo")]
     #[must_use]
-    pub fn input(mut self, input: impl IntoOption<AvailableCommandInput>) -> Self {
-        self.input = input.into_option();
+    pub fn echo(mut self, echo: bool) -> Self {
+        self.echo = echo;
         self
     }
 
@@ -1117,304 +1422,303 @@ impl AvailableCommand {
     }
 }
 
-/// The input specification for a command.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(tag = "type", rename_all = "snake_case")]
-#[schemars(extend("discriminator" = {"propertyName": "type"}))]
-#[non_exhaustive]
-pub enum AvailableCommandInput {
-    /// All text that was typed after the command name is provided as input.
-    #[serde(rename = "text")]
-    Text(TextCommandInput),
-    /// Custom or future command input specification.
-    ///
-    /// Values beginning with `_` are reserved for implementation-specific
-    /// extensions. Unknown values that do not begin with `_` are reserved for
-    /// future ACP variants.
-    ///
-    /// Clients that do not understand this input type should preserve the raw
-    /// payload when storing, replaying, proxying, or forwarding command
-    /// metadata, and otherwise ignore the input specification or display the
-    /// command without structured input.
-    #[serde(untagged)]
-    Other(OtherAvailableCommandInput),
-}
-
-/// Custom or future command input specification.
-#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Eq)]
-#[schemars(inline)]
-#[schemars(transform = other_available_command_input_schema)]
-#[serde(rename_all = "camelCase")]
-#[non_exhaustive]
-pub struct OtherAvailableCommandInput {
-    /// Custom or future command input type.
-    ///
-    /// Values beginning with `_` are reserved for implementation-specific
-    /// extensions. Unknown values that do not begin with `_` are reserved for
-    /// future ACP variants.
-    #[serde(rename = "type")]
-    pub type_: String,
-    /// Additional fields from the unknown command input payload.
-    #[serde(flatten)]
-    pub fields: BTreeMap<String, serde_json::Value>,
-}
-
-impl OtherAvailableCommandInput {
-    /// Builds [`OtherAvailableCommandInput`] from an unknown discriminator and preserves the remaining extension fields.
-    #[must_use]
-    pub fn new(type_: impl Into<String>, mut fields: BTreeMap<String, serde_json::Value>) -> Self {
-        fields.remove("type");
-        Self {
-            type_: type_.into(),
-            fields,
-        }
-    }
-}
-
-impl<'de> Deserialize<'de> for OtherAvailableCommandInput {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        let mut fields = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
-        let type_ = fields
-            .remove("type")
-            .ok_or_else(|| serde::de::Error::missing_field("type"))?;
-        let serde_json::Value::String(type_) = type_ else {
-            return Err(serde::de::Error::custom("`type` must be a string"));
-        };
-
-        if is_known_available_command_input_type(&type_) {
-            return Err(serde::de::Error::custom(format!(
-                "known available command input type `{type_}` did not match its schema"
-            )));
-        }
-
-        Ok(Self { type_, fields })
-    }
-}
-
-const KNOWN_AVAILABLE_COMMAND_INPUT_TYPES: &[&str] = &["text"];
-
-fn is_known_available_command_input_type(type_: &str) -> bool {
-    KNOWN_AVAILABLE_COMMAND_INPUT_TYPES.contains(&type_)
-}
-
-fn other_available_command_input_schema(schema: &mut Schema) {
-    super::schema_util::reject_known_string_discriminators(
-        schema,
-        "type",
-        KNOWN_AVAILABLE_COMMAND_INPUT_TYPES,
-    );
-}
-
-/// All text that was typed after the command name is provided as input.
+/// A user message upsert.
+///
+/// Only [`UserMessage::message_id`] is required. Other fields have patch
+/// semantics: omitted fields leave the existing message value unchanged, `null`
+/// clears or unsets the value, and concrete values replace the previous value.
+/// For a new `messageId`, omitted fields use client defaults. `content` is
+/// replaced as a whole array; send `[]` or `null` to clear it.
+///
+/// Message updates and chunks are applied in the order they are received. When
+/// a `user_message` update includes `content`, that array replaces any content
+/// previously accumulated for the message, including content from earlier
+/// chunks. Later chunks with the same `messageId` append to the current
+/// content.
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct TextCommandInput {
-    /// A hint to display when the input hasn't been provided yet
-    pub hint: String,
+pub struct UserMessage {
+    /// A unique identifier for the message.
+    pub message_id: MessageId,
+    /// Complete replacement content for this message.
+    #[serde_as(deserialize_as = "DefaultOnError<MaybeUndefined<VecSkipError<_, SkipListener>>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub content: MaybeUndefined<Vec<ContentBlock>>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
     ///
     /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[serde_as(deserialize_as = "DefaultOnError<MaybeUndefined<_>>")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
+    #[serde(
+        rename = "_meta",
+        default,
+        skip_serializing_if = "MaybeUndefined::is_undefined"
+    )]
+    pub meta: MaybeUndefined<Meta>,
 }
 
-impl TextCommandInput {
-    /// Builds [`TextCommandInput`] with the required fields set; optional fields start unset or empty.
+impl UserMessage {
+    /// Builds [`UserMessage`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(hint: impl Into<String>) -> Self {
+    pub fn new(message_id: impl Into<MessageId>) -> Self {
         Self {
-            hint: hint.into(),
-            meta: None,
+            message_id: message_id.into(),
+            content: MaybeUndefined::Undefined,
+            meta: MaybeUndefined::Undefined,
         }
     }
 
+    /// Complete replacement content for this message.
+    #[must_use]
+    pub fn content(mut self, content: impl IntoMaybeUndefined<Vec<ContentBlock>>) -> Self {
+        self.content = content.into_maybe_undefined();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
     ///
     /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
+    pub fn meta(mut self, meta: impl IntoMaybeUndefined<Meta>) -> Self {
+        self.meta = meta.into_maybe_undefined();
         self
     }
 }
 
-// Permission
-
-/// Request for user permission to execute a tool call.
+/// An agent message upsert.
 ///
-/// Sent when the agent needs authorization before performing a sensitive operation.
+/// Only [`AgentMessage::message_id`] is required. Other fields have patch
+/// semantics: omitted fields leave the existing message value unchanged, `null`
+/// clears or unsets the value, and concrete values replace the previous value.
+/// For a new `messageId`, omitted fields use client defaults. `content` is
+/// replaced as a whole array; send `[]` or `null` to clear it.
 ///
-/// See protocol docs: [Requesting Permission](https://agentclientprotocol.com/protocol/tool-calls#requesting-permission)
+/// Message updates and chunks are applied in the order they are received. When
+/// an `agent_message` update includes `content`, that array replaces any
+/// content previously accumulated for the message, including content from
+/// earlier chunks. Later chunks with the same `messageId` append to the current
+/// content.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
-#[schemars(extend("x-side" = "client", "x-method" = SESSION_REQUEST_PERMISSION_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct RequestPermissionRequest {
-    /// The session ID for this request.
-    pub session_id: SessionId,
-    /// Details about the tool call requiring permission.
-    pub tool_call: ToolCallUpdate,
-    /// Available permission options for the user to choose from.
-    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+pub struct AgentMessage {
+    /// A unique identifier for the message.
+    pub message_id: MessageId,
+    /// Complete replacement content for this message.
+    #[serde_as(deserialize_as = "DefaultOnError<MaybeUndefined<VecSkipError<_, SkipListener>>>")]
     #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    pub options: Vec<PermissionOption>,
+    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub content: MaybeUndefined<Vec<ContentBlock>>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
     ///
     /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[serde_as(deserialize_as = "DefaultOnError<MaybeUndefined<_>>")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
+    #[serde(
+        rename = "_meta",
+        default,
+        skip_serializing_if = "MaybeUndefined::is_undefined"
+    )]
+    pub meta: MaybeUndefined<Meta>,
 }
 
-impl RequestPermissionRequest {
-    /// Builds [`RequestPermissionRequest`] with the required request fields set; optional fields start unset or empty.
+impl AgentMessage {
+    /// Builds [`AgentMessage`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(
-        session_id: impl Into<SessionId>,
-        tool_call: ToolCallUpdate,
-        options: Vec<PermissionOption>,
-    ) -> Self {
+    pub fn new(message_id: impl Into<MessageId>) -> Self {
         Self {
-            session_id: session_id.into(),
-            tool_call,
-            options,
-            meta: None,
+            message_id: message_id.into(),
+            content: MaybeUndefined::Undefined,
+            meta: MaybeUndefined::Undefined,
         }
     }
 
+    /// Complete replacement content for this message.
+    #[must_use]
+    pub fn content(mut self, content: impl IntoMaybeUndefined<Vec<ContentBlock>>) -> Self {
+        self.content = content.into_maybe_undefined();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
     ///
     /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
+    pub fn meta(mut self, meta: impl IntoMaybeUndefined<Meta>) -> Self {
+        self.meta = meta.into_maybe_undefined();
         self
     }
 }
 
-/// An option presented to the user when requesting permission.
+/// An agent thought or reasoning message upsert.
+///
+/// Only [`AgentThought::message_id`] is required. Other fields have patch
+/// semantics: omitted fields leave the existing thought value unchanged, `null`
+/// clears or unsets the value, and concrete values replace the previous value.
+/// For a new `messageId`, omitted fields use client defaults. `content` is
+/// replaced as a whole array; send `[]` or `null` to clear it.
+///
+/// Message updates and chunks are applied in the order they are received. When
+/// an `agent_thought` update includes `content`, that array replaces any
+/// content previously accumulated for the thought, including content from
+/// earlier chunks. Later chunks with the same `messageId` append to the current
+/// content.
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct PermissionOption {
-    /// Unique identifier for this permission option.
-    pub option_id: PermissionOptionId,
-    /// Human-readable label to display to the user.
-    pub name: String,
-    /// Hint about the nature of this permission option.
-    pub kind: PermissionOptionKind,
+pub struct AgentThought {
+    /// A unique identifier for the thought message.
+    pub message_id: MessageId,
+    /// Complete replacement content for this thought message.
+    #[serde_as(deserialize_as = "DefaultOnError<MaybeUndefined<VecSkipError<_, SkipListener>>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub content: MaybeUndefined<Vec<ContentBlock>>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
     ///
     /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
-    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[serde_as(deserialize_as = "DefaultOnError<MaybeUndefined<_>>")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    #[serde(rename = "_meta")]
-    pub meta: Option<Meta>,
+    #[serde(
+        rename = "_meta",
+        default,
+        skip_serializing_if = "MaybeUndefined::is_undefined"
+    )]
+    pub meta: MaybeUndefined<Meta>,
 }
 
-impl PermissionOption {
-    /// Builds [`PermissionOption`] with the required fields set; optional fields start unset or empty.
+impl AgentThought {
+    /// Builds [`AgentThought`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(
-        option_id: impl Into<PermissionOptionId>,
-        name: impl Into<String>,
-        kind: PermissionOptionKind,
-    ) -> Self {
+    pub fn new(message_id: impl Into<MessageId>) -> Self {
         Self {
-            option_id: option_id.into(),
-            name: name.into(),
-            kind,
-            meta: None,
+            message_id: message_id.into(),
+            content: MaybeUndefined::Undefined,
+            meta: MaybeUndefined::Undefined,
         }
     }
 
+    /// Complete replacement content for this thought message.
+    #[must_use]
+    pub fn content(mut self, content: impl IntoMaybeUndefined<Vec<ContentBlock>>) -> Self {
+        self.content = content.into_maybe_undefined();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
     ///
     /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
-        self.meta = meta.into_option();
+    pub fn meta(mut self, meta: impl IntoMaybeUndefined<Meta>) -> Self {
+        self.meta = meta.into_maybe_undefined();
         self
     }
 }
 
-/// Unique identifier for a permission option.
+/// Unique identifier for a message within a session.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
 #[serde(transparent)]
 #[from(Arc<str>, String, &'static str)]
 #[non_exhaustive]
-pub struct PermissionOptionId(pub Arc<str>);
+pub struct MessageId(pub Arc<str>);
 
-impl PermissionOptionId {
-    /// Wraps a protocol string as a typed [`PermissionOptionId`].
+impl MessageId {
+    /// Wraps a protocol string as a typed [`MessageId`].
     #[must_use]
     pub fn new(id: impl Into<Arc<str>>) -> Self {
         Self(id.into())
     }
 }
 
-/// The type of permission option being presented to the user.
+/// Available commands are ready or have changed
 ///
-/// Helps clients choose appropriate icons and UI treatment.
+/// Each update carries the complete list of commands. Clients should replace
+/// any previously advertised commands with the latest list rather than merging
+/// them, so an empty list clears the available commands.
+///
+/// See protocol docs: [Slash Commands](https://agentclientprotocol.com/protocol/slash-commands)
+#[serde_as]
+#[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "snake_case")]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub enum PermissionOptionKind {
-    /// Allow this operation only this time.
-    AllowOnce,
-    /// Allow this operation and remember the choice.
-    AllowAlways,
-    /// Reject this operation only this time.
-    RejectOnce,
-    /// Reject this operation and remember the choice.
-    RejectAlways,
-    /// Custom or future permission option kind.
+pub struct AvailableCommandsUpdate {
+    /// Commands the agent can execute.
     ///
-    /// Values beginning with `_` are reserved for implementation-specific
-    /// extensions. Unknown values that do not begin with `_` are reserved for
-    /// future ACP variants.
-    #[serde(untagged)]
-    Other(String),
+    /// Replaces the full list from any previous update.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub available_commands: Vec<AvailableCommand>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-/// Response to a permission request.
+impl AvailableCommandsUpdate {
+    /// Builds [`AvailableCommandsUpdate`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(available_commands: Vec<AvailableCommand>) -> Self {
+        Self {
+            available_commands,
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Information about a command.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[schemars(extend("x-side" = "client", "x-method" = SESSION_REQUEST_PERMISSION_METHOD_NAME))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct RequestPermissionResponse {
-    /// The user's decision on the permission request.
-    pub outcome: RequestPermissionOutcome,
+pub struct AvailableCommand {
+    /// Command name (e.g., `create_plan`, `research_codebase`).
+    pub name: String,
+    /// Human-readable description of what the command does.
+    pub description: String,
+    /// Input for the command if required
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub input: Option<AvailableCommandInput>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1427,16 +1731,25 @@ pub struct RequestPermissionResponse {
     pub meta: Option<Meta>,
 }
 
-impl RequestPermissionResponse {
-    /// Builds [`RequestPermissionResponse`] with the required response fields set; optional fields start unset or empty.
+impl AvailableCommand {
+    /// Builds [`AvailableCommand`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(outcome: RequestPermissionOutcome) -> Self {
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
         Self {
-            outcome,
+            name: name.into(),
+            description: description.into(),
+            input: None,
             meta: None,
         }
     }
 
+    /// Input for the command if required
+    #[must_use]
+    pub fn input(mut self, input: impl IntoOption<AvailableCommandInput>) -> Self {
+        self.input = input.into_option();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1449,15 +1762,379 @@ impl RequestPermissionResponse {
     }
 }
 
-/// The outcome of a permission request.
+/// The input specification for a command.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(tag = "outcome", rename_all = "snake_case")]
-#[schemars(extend("discriminator" = {"propertyName": "outcome"}))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[schemars(extend("discriminator" = {"propertyName": "type"}))]
 #[non_exhaustive]
-pub enum RequestPermissionOutcome {
-    /// Active session work was cancelled before the user responded.
+pub enum AvailableCommandInput {
+    /// All text that was typed after the command name is provided as input.
+    #[serde(rename = "text")]
+    Text(TextCommandInput),
+    /// Custom or future command input specification.
     ///
-    /// When a client sends a `session/cancel` notification to cancel active
+    /// Values beginning with `_` are reserved for implementation-specific
+    /// extensions. Unknown values that do not begin with `_` are reserved for
+    /// future ACP variants.
+    ///
+    /// Clients that do not understand this input type should preserve the raw
+    /// payload when storing, replaying, proxying, or forwarding command
+    /// metadata, and otherwise ignore the input specification or display the
+    /// command without structured input.
+    #[serde(untagged)]
+    Other(OtherAvailableCommandInput),
+}
+
+/// Custom or future command input specification.
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq, Eq)]
+#[schemars(inline)]
+#[schemars(transform = other_available_command_input_schema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct OtherAvailableCommandInput {
+    /// Custom or future command input type.
+    ///
+    /// Values beginning with `_` are reserved for implementation-specific
+    /// extensions. Unknown values that do not begin with `_` are reserved for
+    /// future ACP variants.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Additional fields from the unknown command input payload.
+    #[serde(flatten)]
+    pub fields: BTreeMap<String, serde_json::Value>,
+}
+
+impl OtherAvailableCommandInput {
+    /// Builds [`OtherAvailableCommandInput`] from an unknown discriminator and preserves the remaining extension fields.
+    #[must_use]
+    pub fn new(type_: impl Into<String>, mut fields: BTreeMap<String, serde_json::Value>) -> Self {
+        fields.remove("type");
+        Self {
+            type_: type_.into(),
+            fields,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OtherAvailableCommandInput {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut fields = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
+        let type_ = fields
+            .remove("type")
+            .ok_or_else(|| serde::de::Error::missing_field("type"))?;
+        let serde_json::Value::String(type_) = type_ else {
+            return Err(serde::de::Error::custom("`type` must be a string"));
+        };
+
+        if is_known_available_command_input_type(&type_) {
+            return Err(serde::de::Error::custom(format!(
+                "known available command input type `{type_}` did not match its schema"
+            )));
+        }
+
+        Ok(Self { type_, fields })
+    }
+}
+
+const KNOWN_AVAILABLE_COMMAND_INPUT_TYPES: &[&str] = &["text"];
+
+fn is_known_available_command_input_type(type_: &str) -> bool {
+    KNOWN_AVAILABLE_COMMAND_INPUT_TYPES.contains(&type_)
+}
+
+fn other_available_command_input_schema(schema: &mut Schema) {
+    super::schema_util::reject_known_string_discriminators(
+        schema,
+        "type",
+        KNOWN_AVAILABLE_COMMAND_INPUT_TYPES,
+    );
+}
+
+/// All text that was typed after the command name is provided as input.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TextCommandInput {
+    /// A hint to display when the input hasn't been provided yet
+    pub hint: String,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl TextCommandInput {
+    /// Builds [`TextCommandInput`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(hint: impl Into<String>) -> Self {
+        Self {
+            hint: hint.into(),
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+// Permission
+
+/// Request for user permission to execute a tool call.
+///
+/// Sent when the agent needs authorization before performing a sensitive operation.
+///
+/// See protocol docs: [Requesting Permission](https://agentclientprotocol.com/protocol/tool-calls#requesting-permission)
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[schemars(extend("x-side" = "client", "x-method" = SESSION_REQUEST_PERMISSION_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RequestPermissionRequest {
+    /// The session ID for this request.
+    pub session_id: SessionId,
+    /// Details about the tool call requiring permission.
+    pub tool_call: ToolCallUpdate,
+    /// Available permission options for the user to choose from.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    pub options: Vec<PermissionOption>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl RequestPermissionRequest {
+    /// Builds [`RequestPermissionRequest`] with the required request fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(
+        session_id: impl Into<SessionId>,
+        tool_call: ToolCallUpdate,
+        options: Vec<PermissionOption>,
+    ) -> Self {
+        Self {
+            session_id: session_id.into(),
+            tool_call,
+            options,
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// An option presented to the user when requesting permission.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PermissionOption {
+    /// Unique identifier for this permission option.
+    pub option_id: PermissionOptionId,
+    /// Human-readable label to display to the user.
+    pub name: String,
+    /// Hint about the nature of this permission option.
+    pub kind: PermissionOptionKind,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl PermissionOption {
+    /// Builds [`PermissionOption`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(
+        option_id: impl Into<PermissionOptionId>,
+        name: impl Into<String>,
+        kind: PermissionOptionKind,
+    ) -> Self {
+        Self {
+            option_id: option_id.into(),
+            name: name.into(),
+            kind,
+            meta: None,
+        }
+    }
+
+    /// The standard "Allow once" option, with ID `allow-once`.
+    #[must_use]
+    pub fn allow_once() -> Self {
+        Self::new("allow-once", "Allow once", PermissionOptionKind::AllowOnce)
+    }
+
+    /// The standard "Always allow" option, with ID `allow-always`.
+    #[must_use]
+    pub fn allow_always() -> Self {
+        Self::new(
+            "allow-always",
+            "Always allow",
+            PermissionOptionKind::AllowAlways,
+        )
+    }
+
+    /// The standard "Reject" option, with ID `reject-once`.
+    #[must_use]
+    pub fn reject_once() -> Self {
+        Self::new("reject-once", "Reject", PermissionOptionKind::RejectOnce)
+    }
+
+    /// The standard "Always reject" option, with ID `reject-always`.
+    #[must_use]
+    pub fn reject_always() -> Self {
+        Self::new(
+            "reject-always",
+            "Always reject",
+            PermissionOptionKind::RejectAlways,
+        )
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Unique identifier for a permission option.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
+#[serde(transparent)]
+#[from(Arc<str>, String, &'static str)]
+#[non_exhaustive]
+pub struct PermissionOptionId(pub Arc<str>);
+
+impl PermissionOptionId {
+    /// Wraps a protocol string as a typed [`PermissionOptionId`].
+    #[must_use]
+    pub fn new(id: impl Into<Arc<str>>) -> Self {
+        Self(id.into())
+    }
+}
+
+/// The type of permission option being presented to the user.
+///
+/// Helps clients choose appropriate icons and UI treatment.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PermissionOptionKind {
+    /// Allow this operation only this time.
+    AllowOnce,
+    /// Allow this operation and remember the choice.
+    AllowAlways,
+    /// Reject this operation only this time.
+    RejectOnce,
+    /// Reject this operation and remember the choice.
+    RejectAlways,
+    /// Custom or future permission option kind.
+    ///
+    /// Values beginning with `_` are reserved for implementation-specific
+    /// extensions. Unknown values that do not begin with `_` are reserved for
+    /// future ACP variants.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Response to a permission request.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "client", "x-method" = SESSION_REQUEST_PERMISSION_METHOD_NAME))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RequestPermissionResponse {
+    /// The user's decision on the permission request.
+    pub outcome: RequestPermissionOutcome,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl RequestPermissionResponse {
+    /// Builds [`RequestPermissionResponse`] with the required response fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(outcome: RequestPermissionOutcome) -> Self {
+        Self {
+            outcome,
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// The outcome of a permission request.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+#[schemars(extend("discriminator" = {"propertyName": "outcome"}))]
+#[non_exhaustive]
+pub enum RequestPermissionOutcome {
+    /// Active session work was cancelled before the user responded.
+    ///
+    /// When a client sends a `session/cancel` notification to cancel active
     /// session work, it MUST respond to all pending `session/request_permission`
     /// requests with this `Cancelled` outcome.
     ///
@@ -1552,15 +2229,89 @@ fn other_request_permission_outcome_schema(schema: &mut Schema) {
     );
 }
 
-/// The user selected one of the provided options.
+/// The user selected one of the provided options.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SelectedPermissionOutcome {
+    /// The ID of the option the user selected.
+    pub option_id: PermissionOptionId,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Per-hunk decisions for diffs in the tool call that carry hunks.
+    ///
+    /// Hunks without a decision here follow the selected option.
+    #[cfg(feature = "unstable_diff_hunks")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hunk_decisions: Vec<DiffHunkDecision>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl SelectedPermissionOutcome {
+    /// Builds [`SelectedPermissionOutcome`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(option_id: impl Into<PermissionOptionId>) -> Self {
+        Self {
+            option_id: option_id.into(),
+            #[cfg(feature = "unstable_diff_hunks")]
+            hunk_decisions: Vec::new(),
+            meta: None,
+        }
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Per-hunk decisions for diffs in the tool call that carry hunks.
+    #[cfg(feature = "unstable_diff_hunks")]
+    #[must_use]
+    pub fn hunk_decisions(mut self, hunk_decisions: Vec<DiffHunkDecision>) -> Self {
+        self.hunk_decisions = hunk_decisions;
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Notification that the agent has finished warming up and can take prompts.
+///
+/// Only sent by agents that returned `ready: false` from `initialize`, and at
+/// most once per connection.
+#[cfg(feature = "unstable_agent_ready")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[schemars(extend("x-side" = "client", "x-method" = AGENT_READY_NOTIFICATION))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct SelectedPermissionOutcome {
-    /// The ID of the option the user selected.
-    pub option_id: PermissionOptionId,
+pub struct AgentReadyNotification {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1573,14 +2324,12 @@ pub struct SelectedPermissionOutcome {
     pub meta: Option<Meta>,
 }
 
-impl SelectedPermissionOutcome {
-    /// Builds [`SelectedPermissionOutcome`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_agent_ready")]
+impl AgentReadyNotification {
+    /// Builds [`AgentReadyNotification`] with the required notification fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(option_id: impl Into<PermissionOptionId>) -> Self {
-        Self {
-            option_id: option_id.into(),
-            meta: None,
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -1642,6 +2391,20 @@ pub struct ClientCapabilities {
     ///
     /// This capability is not part of the spec yet, and may be removed or changed at any point.
     ///
+    /// Sampling capabilities supported by the client.
+    /// Determines whether the agent may ask the client to generate model completions.
+    ///
+    /// Optional. Omitted or `null` both mean the client does not advertise
+    /// sampling support.
+    #[cfg(feature = "unstable_sampling")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub sampling: Option<SamplingCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
     /// NES (Next Edit Suggestions) capabilities supported by the client.
     ///
     /// Optional. Omitted or `null` both mean the client does not advertise any
@@ -1661,7 +2424,301 @@ pub struct ClientCapabilities {
     #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub position_encodings: Vec<PositionEncodingKind>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the client can render [`ContentBlock::Table`] content.
+    ///
+    /// Optional. Omitted or `null` both mean the client does not advertise support.
+    /// Supplying `{}` means agents may send tables instead of markdown.
+    #[cfg(feature = "unstable_table")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub table: Option<TableCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the client can render [`ContentBlock::Json`] content.
+    ///
+    /// Optional. Omitted or `null` both mean the client does not advertise support.
+    /// Supplying `{}` means agents may send JSON values instead of text.
+    #[cfg(feature = "unstable_json_content")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub json_content: Option<JsonContentCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// How the client handles `session/request_permission` requests.
+    ///
+    /// Optional. Omitted or `null` both mean the client does not advertise any
+    /// permission-related extensions.
+    #[cfg(feature = "unstable_permission_inline_tool_call")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub permission: Option<PermissionCapabilities>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the client can answer `input_request` session updates.
+    ///
+    /// Optional. Omitted or `null` both mean the client does not advertise support.
+    /// Supplying `{}` means agents may ask the user free-form questions mid-turn.
+    #[cfg(feature = "unstable_input_request")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub input_request: Option<InputRequestCapabilities>,
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl ClientCapabilities {
+    /// Builds an empty [`ClientCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Authentication capabilities supported by the client.
+    /// Determines which authentication method types the agent may include
+    /// in its `InitializeResponse`.
+    #[cfg(feature = "unstable_auth_methods")]
+    #[must_use]
+    pub fn auth(mut self, auth: impl IntoOption<AuthCapabilities>) -> Self {
+        self.auth = auth.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Elicitation capabilities supported by the client.
+    /// Determines which elicitation modes the agent may use.
+    #[cfg(feature = "unstable_elicitation")]
+    #[must_use]
+    pub fn elicitation(mut self, elicitation: impl IntoOption<ElicitationCapabilities>) -> Self {
+        self.elicitation = elicitation.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Sampling capabilities supported by the client.
+    /// Determines whether the agent may ask the client to generate model completions.
+    #[cfg(feature = "unstable_sampling")]
+    #[must_use]
+    pub fn sampling(mut self, sampling: impl IntoOption<SamplingCapabilities>) -> Self {
+        self.sampling = sampling.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// NES (Next Edit Suggestions) capabilities supported by the client.
+    #[cfg(feature = "unstable_nes")]
+    #[must_use]
+    pub fn nes(mut self, nes: impl IntoOption<ClientNesCapabilities>) -> Self {
+        self.nes = nes.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// The position encodings supported by the client, in order of preference.
+    #[cfg(feature = "unstable_nes")]
+    #[must_use]
+    pub fn position_encodings(mut self, position_encodings: Vec<PositionEncodingKind>) -> Self {
+        self.position_encodings = position_encodings;
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the client can render [`ContentBlock::Table`] content.
+    ///
+    /// Omitted or `null` both mean the client does not advertise support.
+    /// Supplying `{}` means agents may send tables instead of markdown.
+    #[cfg(feature = "unstable_table")]
+    #[must_use]
+    pub fn table(mut self, table: impl IntoOption<TableCapabilities>) -> Self {
+        self.table = table.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the client can render [`ContentBlock::Json`] content.
+    ///
+    /// Omitted or `null` both mean the client does not advertise support.
+    /// Supplying `{}` means agents may send JSON values instead of text.
+    #[cfg(feature = "unstable_json_content")]
+    #[must_use]
+    pub fn json_content(mut self, json_content: impl IntoOption<JsonContentCapabilities>) -> Self {
+        self.json_content = json_content.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// How the client handles `session/request_permission` requests.
+    #[cfg(feature = "unstable_permission_inline_tool_call")]
+    #[must_use]
+    pub fn permission(mut self, permission: impl IntoOption<PermissionCapabilities>) -> Self {
+        self.permission = permission.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the client can answer `input_request` session updates.
+    ///
+    /// Omitted or `null` both mean the client does not advertise support.
+    /// Supplying `{}` means agents may ask the user free-form questions mid-turn.
+    #[cfg(feature = "unstable_input_request")]
+    #[must_use]
+    pub fn input_request(
+        mut self,
+        input_request: impl IntoOption<InputRequestCapabilities>,
+    ) -> Self {
+        self.input_request = input_request.into_option();
+        self
+    }
+
+    /// Whether the client can render a permission request for a tool call it
+    /// has not been sent yet.
+    #[cfg(feature = "unstable_permission_inline_tool_call")]
+    #[must_use]
+    pub fn supports_inline_tool_call(&self) -> bool {
+        self.permission
+            .as_ref()
+            .is_some_and(|permission| permission.inline_tool_call.is_some())
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Permission request capabilities supported by the client.
+#[cfg(feature = "unstable_permission_inline_tool_call")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PermissionCapabilities {
+    /// Whether the client can render a permission request whose `toolCall`
+    /// describes a tool call it has not been sent yet.
+    ///
+    /// Optional. Omitted or `null` both mean the client does not advertise support,
+    /// so agents MUST report the tool call with a `tool_call_update` first and
+    /// reference it by `toolCallId` alone.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub inline_tool_call: Option<PermissionInlineToolCallCapabilities>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_permission_inline_tool_call")]
+impl PermissionCapabilities {
+    /// Builds an empty [`PermissionCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the client can render a permission request whose `toolCall`
+    /// describes a tool call it has not been sent yet.
+    ///
+    /// Omitted or `null` both mean the client does not advertise support.
+    #[must_use]
+    pub fn inline_tool_call(
+        mut self,
+        inline_tool_call: impl IntoOption<PermissionInlineToolCallCapabilities>,
+    ) -> Self {
+        self.inline_tool_call = inline_tool_call.into_option();
+        self
+    }
 
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for inline tool calls in permission requests.
+///
+/// Supplying `{}` means the client can render a permission request for a tool
+/// call it has not been sent yet.
+#[cfg(feature = "unstable_permission_inline_tool_call")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PermissionInlineToolCallCapabilities {
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -1674,58 +2731,58 @@ pub struct ClientCapabilities {
     pub meta: Option<Meta>,
 }
 
-impl ClientCapabilities {
-    /// Builds an empty [`ClientCapabilities`]; use builder methods to advertise supported sub-capabilities.
+#[cfg(feature = "unstable_permission_inline_tool_call")]
+impl PermissionInlineToolCallCapabilities {
+    /// Builds an empty [`PermissionInlineToolCallCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
     }
 
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
-    ///
-    /// Authentication capabilities supported by the client.
-    /// Determines which authentication method types the agent may include
-    /// in its `InitializeResponse`.
-    #[cfg(feature = "unstable_auth_methods")]
-    #[must_use]
-    pub fn auth(mut self, auth: impl IntoOption<AuthCapabilities>) -> Self {
-        self.auth = auth.into_option();
-        self
-    }
-
-    /// **UNSTABLE**
-    ///
-    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// Elicitation capabilities supported by the client.
-    /// Determines which elicitation modes the agent may use.
-    #[cfg(feature = "unstable_elicitation")]
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn elicitation(mut self, elicitation: impl IntoOption<ElicitationCapabilities>) -> Self {
-        self.elicitation = elicitation.into_option();
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
         self
     }
+}
 
-    /// **UNSTABLE**
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for `input_request` session updates.
+///
+/// Supplying `{}` means the client can show the question and answer it with
+/// `session/provide_input`.
+#[cfg(feature = "unstable_input_request")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct InputRequestCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// NES (Next Edit Suggestions) capabilities supported by the client.
-    #[cfg(feature = "unstable_nes")]
-    #[must_use]
-    pub fn nes(mut self, nes: impl IntoOption<ClientNesCapabilities>) -> Self {
-        self.nes = nes.into_option();
-        self
-    }
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
 
-    /// **UNSTABLE**
-    ///
-    /// The position encodings supported by the client, in order of preference.
-    #[cfg(feature = "unstable_nes")]
+#[cfg(feature = "unstable_input_request")]
+impl InputRequestCapabilities {
+    /// Builds an empty [`InputRequestCapabilities`]; use builder methods to advertise supported sub-capabilities.
     #[must_use]
-    pub fn position_encodings(mut self, position_encodings: Vec<PositionEncodingKind>) -> Self {
-        self.position_encodings = position_encodings;
-        self
+    pub fn new() -> Self {
+        Self::default()
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -1878,6 +2935,12 @@ pub struct ClientMethodNames {
     /// Notification for elicitation completion.
     #[cfg(feature = "unstable_elicitation")]
     pub elicitation_complete: &'static str,
+    /// Method for requesting a model completion from the client.
+    #[cfg(feature = "unstable_sampling")]
+    pub sampling_create_message: &'static str,
+    /// Notification that the agent is ready to take prompts.
+    #[cfg(feature = "unstable_agent_ready")]
+    pub agent_ready: &'static str,
 }
 
 /// Constant containing all client method names.
@@ -1894,6 +2957,10 @@ pub const CLIENT_METHOD_NAMES: ClientMethodNames = ClientMethodNames {
     elicitation_create: ELICITATION_CREATE_METHOD_NAME,
     #[cfg(feature = "unstable_elicitation")]
     elicitation_complete: ELICITATION_COMPLETE_NOTIFICATION,
+    #[cfg(feature = "unstable_sampling")]
+    sampling_create_message: SAMPLING_CREATE_MESSAGE_METHOD_NAME,
+    #[cfg(feature = "unstable_agent_ready")]
+    agent_ready: AGENT_READY_NOTIFICATION,
 };
 
 /// Notification name for session updates.
@@ -1906,6 +2973,12 @@ pub(crate) const ELICITATION_CREATE_METHOD_NAME: &str = "elicitation/create";
 /// Notification name for elicitation completion.
 #[cfg(feature = "unstable_elicitation")]
 pub(crate) const ELICITATION_COMPLETE_NOTIFICATION: &str = "elicitation/complete";
+/// Method name for requesting a model completion from the client.
+#[cfg(feature = "unstable_sampling")]
+pub(crate) const SAMPLING_CREATE_MESSAGE_METHOD_NAME: &str = "sampling/create_message";
+/// Notification name for the agent finishing its warm-up.
+#[cfg(feature = "unstable_agent_ready")]
+pub(crate) const AGENT_READY_NOTIFICATION: &str = "agent/ready";
 
 /// All possible requests that an agent can send to a client.
 ///
@@ -1957,6 +3030,15 @@ pub enum AgentRequest {
     /// Closes an MCP-over-ACP connection.
     #[cfg(feature = "unstable_mcp_over_acp")]
     DisconnectMcpRequest(Box<DisconnectMcpRequest>),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Asks the client to generate a model completion on the agent's behalf.
+    ///
+    /// Only available if the client advertises the `sampling` capability.
+    #[cfg(feature = "unstable_sampling")]
+    CreateMessageRequest(Box<CreateMessageRequest>),
     /// Handles extension method requests from the agent.
     ///
     /// Allows the Agent to send an arbitrary request that is not part of the ACP spec.
@@ -1981,6 +3063,8 @@ impl AgentRequest {
             Self::MessageMcpRequest(_) => CLIENT_METHOD_NAMES.mcp_message,
             #[cfg(feature = "unstable_mcp_over_acp")]
             Self::DisconnectMcpRequest(_) => CLIENT_METHOD_NAMES.mcp_disconnect,
+            #[cfg(feature = "unstable_sampling")]
+            Self::CreateMessageRequest(_) => CLIENT_METHOD_NAMES.sampling_create_message,
             Self::ExtMethodRequest(ext_request) => &ext_request.method,
         }
     }
@@ -2011,6 +3095,9 @@ pub enum ClientResponse {
     /// Successful result returned by an MCP-over-ACP `mcp/message` request.
     #[cfg(feature = "unstable_mcp_over_acp")]
     MessageMcpResponse(Box<MessageMcpResponse>),
+    /// Successful result returned for a `sampling/create_message` request.
+    #[cfg(feature = "unstable_sampling")]
+    CreateMessageResponse(Box<CreateMessageResponse>),
     /// Successful result returned by an extension method outside the core ACP method set.
     ExtMethodResponse(Box<ExtResponse>),
 }
@@ -2053,6 +3140,13 @@ pub enum AgentNotification {
     /// Receives an MCP-over-ACP notification.
     #[cfg(feature = "unstable_mcp_over_acp")]
     MessageMcpNotification(Box<MessageMcpNotification>),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Notification that the agent has finished warming up and can take prompts.
+    #[cfg(feature = "unstable_agent_ready")]
+    AgentReadyNotification(Box<AgentReadyNotification>),
     /// Handles extension notifications from the agent.
     ///
     /// Allows the Agent to send an arbitrary notification that is not part of the ACP spec.
@@ -2073,6 +3167,8 @@ impl AgentNotification {
             Self::CompleteElicitationNotification(_) => CLIENT_METHOD_NAMES.elicitation_complete,
             #[cfg(feature = "unstable_mcp_over_acp")]
             Self::MessageMcpNotification(_) => CLIENT_METHOD_NAMES.mcp_message,
+            #[cfg(feature = "unstable_agent_ready")]
+            Self::AgentReadyNotification(_) => CLIENT_METHOD_NAMES.agent_ready,
             Self::ExtNotification(ext_notification) => &ext_notification.method,
         }
     }
@@ -2095,6 +3191,138 @@ mod tests {
         assert_eq!(capabilities.auth, None);
     }
 
+    #[test]
+    fn test_session_update_is_transcript() {
+        use crate::v2::{ContentChunk, PlanUpdateContent, ToolCallContentChunk};
+
+        #[cfg_attr(
+            not(any(
+                feature = "unstable_plan_operations",
+                feature = "unstable_plan_entry_update",
+                feature = "unstable_tool_calls_cancelled",
+                feature = "unstable_attachment",
+                feature = "unstable_input_request"
+            )),
+            allow(unused_mut)
+        )]
+        let mut updates = vec![
+            SessionUpdate::UserMessageChunk(ContentChunk::new("Hi".into(), "msg_1")),
+            SessionUpdate::UserMessage(UserMessage::new("msg_1")),
+            SessionUpdate::AgentMessageChunk(ContentChunk::new("Hello".into(), "msg_2")),
+            SessionUpdate::AgentMessage(AgentMessage::new("msg_2")),
+            SessionUpdate::AgentThoughtChunk(ContentChunk::new("Thinking".into(), "msg_3")),
+            SessionUpdate::AgentThought(AgentThought::new("msg_3")),
+            SessionUpdate::ToolCallContentChunk(ToolCallContentChunk::new(
+                "call_1",
+                ContentBlock::from("output"),
+            )),
+            SessionUpdate::ToolCallUpdate(ToolCallUpdate::new("call_1")),
+            SessionUpdate::PlanUpdate(PlanUpdate::new(PlanUpdateContent::items("plan_1", vec![]))),
+            SessionUpdate::Other(OtherSessionUpdate::new(
+                "_vendor/checkpoint",
+                BTreeMap::new(),
+            )),
+        ];
+        #[cfg(feature = "unstable_plan_operations")]
+        updates.push(SessionUpdate::PlanRemoved(PlanRemoved::new("plan_1")));
+        #[cfg(feature = "unstable_plan_entry_update")]
+        updates.push(SessionUpdate::PlanEntryUpdate(PlanEntryUpdate::new(
+            "plan_1", 0,
+        )));
+        #[cfg(feature = "unstable_tool_calls_cancelled")]
+        updates.push(SessionUpdate::ToolCallsCancelled(ToolCallsCancelled::new(
+            vec!["call_1".into()],
+        )));
+        #[cfg(feature = "unstable_attachment")]
+        updates.push(SessionUpdate::Attachment(Attachment::new(
+            "report.csv",
+            EmbeddedResource::new(EmbeddedResourceResource::TextResourceContents(
+                crate::v2::TextResourceContents::new("a,b\n", "file:///report.csv"),
+            )),
+        )));
+        #[cfg(feature = "unstable_input_request")]
+        updates.push(SessionUpdate::InputRequest(InputRequest::new(
+            "input_1",
+            "Which branch?",
+        )));
+
+        for update in updates {
+            assert!(update.is_transcript(), "{update:?}");
+        }
+    }
+
+    #[test]
+    fn test_session_update_is_ephemeral() {
+        #[cfg_attr(
+            not(any(
+                feature = "unstable_tool_call_input_delta",
+                feature = "unstable_session_notice",
+                feature = "unstable_suggested_prompts"
+            )),
+            allow(unused_mut)
+        )]
+        let mut updates = vec![
+            SessionUpdate::StateUpdate(StateUpdate::Running(RunningStateUpdate::default())),
+            SessionUpdate::AvailableCommandsUpdate(AvailableCommandsUpdate::new(vec![])),
+            SessionUpdate::ConfigOptionUpdate(ConfigOptionUpdate::new(vec![])),
+            SessionUpdate::SessionInfoUpdate(SessionInfoUpdate::new().title("Refactor")),
+            SessionUpdate::UsageUpdate(UsageUpdate::new(10, 100)),
+        ];
+        #[cfg(feature = "unstable_tool_call_input_delta")]
+        updates.push(SessionUpdate::ToolCallInputDelta(ToolCallInputDelta::new(
+            "call_1", "{\"pa",
+        )));
+        #[cfg(feature = "unstable_session_notice")]
+        updates.push(SessionUpdate::Notice(Notice::new(
+            "Indexing…",
+            NoticeLevel::Info,
+        )));
+        #[cfg(feature = "unstable_suggested_prompts")]
+        updates.push(SessionUpdate::SuggestedPrompts(SuggestedPrompts::new(
+            vec!["Run tests".into()],
+        )));
+
+        for update in updates {
+            assert!(!update.is_transcript(), "{update:?}");
+        }
+    }
+
+    #[test]
+    fn test_session_update_to_markdown() {
+        use crate::v2::{AgentMessage, AgentThought, ContentChunk, UserMessage};
+
+        assert_eq!(
+            SessionUpdate::UserMessageChunk(ContentChunk::new("Fix the *tests*".into(), "msg_1"))
+                .to_markdown()
+                .as_deref(),
+            Some("Fix the *tests*")
+        );
+        assert_eq!(
+            SessionUpdate::UserMessage(
+                UserMessage::new("msg_1").content(vec!["Fix".into(), "the tests".into()])
+            )
+            .to_markdown()
+            .as_deref(),
+            Some("Fix\n\nthe tests")
+        );
+        assert_eq!(
+            SessionUpdate::AgentMessage(AgentMessage::new("msg_2")).to_markdown(),
+            None
+        );
+        assert_eq!(
+            SessionUpdate::AgentThought(
+                AgentThought::new("msg_3").content(vec!["Check CI.\n\nThen rerun.".into()])
+            )
+            .to_markdown()
+            .as_deref(),
+            Some("> Check CI.\n>\n> Then rerun.")
+        );
+        assert_eq!(
+            SessionUpdate::UsageUpdate(UsageUpdate::new(10, 100)).to_markdown(),
+            None
+        );
+    }
+
     #[test]
     fn test_serialization_behavior() {
         use serde_json::json;
@@ -2418,6 +3646,294 @@ mod tests {
         assert_eq!(update.cost, None);
     }
 
+    #[cfg(feature = "unstable_session_notice")]
+    #[test]
+    fn test_notice_serialization() {
+        use serde_json::json;
+
+        let notice = SessionUpdate::Notice(Notice::new("Searching the web...", NoticeLevel::Info));
+        let json = serde_json::to_value(&notice).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "notice",
+                "text": "Searching the web...",
+                "level": "info"
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SessionUpdate>(json).unwrap(),
+            notice
+        );
+
+        let SessionUpdate::Notice(cleared) = serde_json::from_value(json!({
+            "sessionUpdate": "notice",
+            "text": "",
+            "level": "warning"
+        }))
+        .unwrap() else {
+            panic!("expected notice");
+        };
+        assert!(cleared.is_clear());
+        assert_eq!(cleared.level, NoticeLevel::Warning);
+        assert!(Notice::clear().is_clear());
+    }
+
+    #[cfg(feature = "unstable_tool_calls_cancelled")]
+    #[test]
+    fn test_tool_calls_cancelled_serialization() {
+        use serde_json::json;
+
+        let update = SessionUpdate::ToolCallsCancelled(ToolCallsCancelled::new(vec![
+            ToolCallId::new("call_001"),
+            ToolCallId::new("call_002"),
+        ]));
+        let json = serde_json::to_value(&update).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "tool_calls_cancelled",
+                "toolCallIds": ["call_001", "call_002"]
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SessionUpdate>(json).unwrap(),
+            update
+        );
+
+        let SessionUpdate::ToolCallsCancelled(all) = serde_json::from_value(json!({
+            "sessionUpdate": "tool_calls_cancelled",
+            "toolCallIds": []
+        }))
+        .unwrap() else {
+            panic!("expected tool_calls_cancelled");
+        };
+        assert_eq!(all, ToolCallsCancelled::all());
+        assert!(all.contains(&ToolCallId::new("call_003")));
+
+        // An invalid id must not be dropped, which would widen the update to
+        // every tool call.
+        assert!(!matches!(
+            serde_json::from_value::<SessionUpdate>(json!({
+                "sessionUpdate": "tool_calls_cancelled",
+                "toolCallIds": [1]
+            })),
+            Ok(SessionUpdate::ToolCallsCancelled(_))
+        ));
+    }
+
+    #[cfg(feature = "unstable_attachment")]
+    #[test]
+    fn test_attachment_serialization() {
+        use crate::v2::{BlobResourceContents, TextResourceContents};
+        use serde_json::json;
+
+        let text = SessionUpdate::Attachment(Attachment::new(
+            "report.md",
+            EmbeddedResource::new(EmbeddedResourceResource::TextResourceContents(
+                TextResourceContents::new("# Report\n", "attachment:report.md"),
+            )),
+        ));
+        let json = serde_json::to_value(&text).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "attachment",
+                "name": "report.md",
+                "content": {
+                    "resource": {
+                        "text": "# Report\n",
+                        "uri": "attachment:report.md"
+                    }
+                }
+            })
+        );
+        assert_eq!(serde_json::from_value::<SessionUpdate>(json).unwrap(), text);
+
+        let blob = SessionUpdate::Attachment(Attachment::new(
+            "chart.png",
+            EmbeddedResource::new(EmbeddedResourceResource::BlobResourceContents(
+                BlobResourceContents::new("iVBORw0KGgo=", "attachment:chart.png")
+                    .mime_type("image/png"),
+            )),
+        ));
+        let json = serde_json::to_value(&blob).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "attachment",
+                "name": "chart.png",
+                "content": {
+                    "resource": {
+                        "blob": "iVBORw0KGgo=",
+                        "mimeType": "image/png",
+                        "uri": "attachment:chart.png"
+                    }
+                }
+            })
+        );
+        assert_eq!(serde_json::from_value::<SessionUpdate>(json).unwrap(), blob);
+
+        let SessionUpdate::Attachment(text) = text else {
+            unreachable!()
+        };
+        assert_eq!(text.mime_type(), "text/plain");
+        let SessionUpdate::Attachment(blob) = blob else {
+            unreachable!()
+        };
+        assert_eq!(blob.mime_type(), "image/png");
+    }
+
+    #[cfg(feature = "unstable_suggested_prompts")]
+    #[test]
+    fn test_suggested_prompts_serialization() {
+        use serde_json::json;
+
+        let update = SessionUpdate::SuggestedPrompts(SuggestedPrompts::new(vec![
+            "Add tests for the parser".to_string(),
+            "Explain the change".to_string(),
+        ]));
+        let json = serde_json::to_value(&update).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "suggested_prompts",
+                "prompts": ["Add tests for the parser", "Explain the change"]
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SessionUpdate>(json).unwrap(),
+            update
+        );
+
+        let cleared = SessionUpdate::SuggestedPrompts(SuggestedPrompts::new(vec![]));
+        let json = serde_json::to_value(&cleared).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "suggested_prompts",
+                "prompts": []
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SessionUpdate>(json).unwrap(),
+            cleared
+        );
+    }
+
+    #[test]
+    fn test_permission_option_presets() {
+        let options = [
+            PermissionOption::allow_once(),
+            PermissionOption::allow_always(),
+            PermissionOption::reject_once(),
+            PermissionOption::reject_always(),
+        ];
+
+        assert_eq!(
+            options
+                .iter()
+                .map(|option| (option.option_id.to_string(), option.kind.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("allow-once".to_string(), PermissionOptionKind::AllowOnce),
+                (
+                    "allow-always".to_string(),
+                    PermissionOptionKind::AllowAlways
+                ),
+                ("reject-once".to_string(), PermissionOptionKind::RejectOnce),
+                (
+                    "reject-always".to_string(),
+                    PermissionOptionKind::RejectAlways
+                ),
+            ]
+        );
+    }
+
+    #[cfg(feature = "unstable_permission_inline_tool_call")]
+    #[test]
+    fn test_permission_inline_tool_call_capability() {
+        use crate::v2::ToolCallUpdate;
+        use serde_json::json;
+
+        let capabilities = ClientCapabilities::new().permission(
+            PermissionCapabilities::new()
+                .inline_tool_call(PermissionInlineToolCallCapabilities::new()),
+        );
+        let json = serde_json::to_value(&capabilities).unwrap();
+        assert_eq!(
+            json["permission"],
+            json!({"permission": {"inlineToolCall": {}}})["permission"]
+        );
+        assert_eq!(
+            serde_json::from_value::<ClientCapabilities>(json).unwrap(),
+            capabilities
+        );
+        assert!(capabilities.supports_inline_tool_call());
+        assert!(!ClientCapabilities::new().supports_inline_tool_call());
+
+        let inline = ToolCallUpdate::new("call_001").title("Write config.json");
+        assert_eq!(
+            serde_json::to_value(inline.clone().for_permission_request(&capabilities)).unwrap(),
+            json!({"toolCallId": "call_001", "title": "Write config.json"})
+        );
+        assert_eq!(
+            serde_json::to_value(inline.for_permission_request(&ClientCapabilities::new()))
+                .unwrap(),
+            json!({"toolCallId": "call_001"})
+        );
+    }
+
+    #[cfg(feature = "unstable_diff_hunks")]
+    #[test]
+    fn test_permission_response_hunk_decisions_serialization() {
+        use crate::v2::{Diff, DiffHunk, DiffLineRange};
+        use serde_json::json;
+
+        let response = RequestPermissionResponse::new(RequestPermissionOutcome::Selected(
+            SelectedPermissionOutcome::new("allow").hunk_decisions(vec![
+                DiffHunkDecision::new("/src/main.rs", "0", true),
+                DiffHunkDecision::new("/src/main.rs", "1", false),
+            ]),
+        ));
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "outcome": {
+                    "outcome": "selected",
+                    "optionId": "allow",
+                    "hunkDecisions": [
+                        {"path": "/src/main.rs", "hunkId": "0", "accepted": true},
+                        {"path": "/src/main.rs", "hunkId": "1", "accepted": false}
+                    ]
+                }
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<RequestPermissionResponse>(json).unwrap(),
+            response
+        );
+
+        let diff = Diff::new("/src/main.rs", "b\n").old_text("a\n").hunks(vec![
+            DiffHunk::new("0", DiffLineRange::new(1, 1), DiffLineRange::new(1, 1)).accepted(true),
+        ]);
+        assert_eq!(
+            serde_json::to_value(&diff).unwrap(),
+            json!({
+                "path": "/src/main.rs",
+                "oldText": "a\n",
+                "newText": "b\n",
+                "hunks": [{
+                    "id": "0",
+                    "oldRange": {"start": 1, "count": 1},
+                    "newRange": {"start": 1, "count": 1},
+                    "accepted": true
+                }]
+            })
+        );
+    }
+
     #[test]
     fn test_state_update_serialization() {
         use serde_json::json;
@@ -2557,6 +4073,32 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "unstable_plan_entry_update")]
+    #[test]
+    fn test_plan_entry_update_serialization() {
+        use serde_json::json;
+
+        use crate::v2::PlanEntryStatus;
+
+        let update = SessionUpdate::PlanEntryUpdate(
+            PlanEntryUpdate::new("plan-1", 2).status(PlanEntryStatus::InProgress),
+        );
+        let json = serde_json::to_value(&update).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "sessionUpdate": "plan_entry_update",
+                "planId": "plan-1",
+                "index": 2,
+                "status": "in_progress"
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SessionUpdate>(json).unwrap(),
+            update
+        );
+    }
+
     #[test]
     fn available_command_input_preserves_unknown_typed_variant() {
         use serde_json::json;
@@ -2694,6 +4236,34 @@ mod tests {
         assert_eq!(json["positionEncodings"], json!(["utf-32", "utf-16"]));
     }
 
+    #[cfg(feature = "unstable_table")]
+    #[test]
+    fn test_client_capabilities_table_serialization() {
+        use serde_json::json;
+
+        let capabilities = ClientCapabilities::new().table(TableCapabilities::new());
+        let json = serde_json::to_value(&capabilities).unwrap();
+        assert_eq!(json["table"], json!({}));
+
+        let capabilities: ClientCapabilities =
+            serde_json::from_value(json!({ "table": "yes" })).unwrap();
+        assert_eq!(capabilities.table, None);
+    }
+
+    #[cfg(feature = "unstable_json_content")]
+    #[test]
+    fn test_client_capabilities_json_content_serialization() {
+        use serde_json::json;
+
+        let capabilities = ClientCapabilities::new().json_content(JsonContentCapabilities::new());
+        let json = serde_json::to_value(&capabilities).unwrap();
+        assert_eq!(json["jsonContent"], json!({}));
+
+        let capabilities: ClientCapabilities =
+            serde_json::from_value(json!({ "jsonContent": true })).unwrap();
+        assert_eq!(capabilities.json_content, None);
+    }
+
     #[cfg(feature = "unstable_mcp_over_acp")]
     #[test]
     fn test_agent_mcp_request_method_names() {
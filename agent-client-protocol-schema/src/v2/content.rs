@@ -15,8 +15,12 @@ use schemars::{JsonSchema, Schema};
 use serde::{Deserialize, Serialize};
 use serde_with::{DefaultOnError, VecSkipError, serde_as, skip_serializing_none};
 
-use super::Meta;
-use crate::{IntoOption, SkipListener};
+#[cfg(feature = "unstable_tool_result_ref")]
+use super::ToolCallId;
+use super::{Error, Meta, Result};
+#[cfg(feature = "tokenizer")]
+use crate::Tokenizer;
+use crate::{AUDIO_TOKEN_ESTIMATE, IMAGE_TOKEN_ESTIMATE, IntoOption, ResourceUri, SkipListener};
 
 /// Content blocks represent displayable information in the Agent Client Protocol.
 ///
@@ -61,6 +65,50 @@ pub enum ContentBlock {
     ///
     /// Requires the `embeddedContext` prompt capability when included in prompts.
     Resource(EmbeddedResource),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A reference to the output of an earlier tool call in the same session.
+    ///
+    /// Lets the client cite a previous result without embedding it again. The
+    /// agent resolves the reference against its own session state.
+    ///
+    /// Requires the `toolResultRef` prompt capability when included in prompts.
+    #[cfg(feature = "unstable_tool_result_ref")]
+    ToolResultRef(ToolResultRef),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A structured `@`-mention of a file, symbol or URL from the prompt box.
+    ///
+    /// Clients SHOULD render mentions as chips showing `label`. Requires the
+    /// `mention` prompt capability when included in prompts.
+    #[cfg(feature = "unstable_mention")]
+    Mention(Mention),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Structured tabular data, such as query results or file listings.
+    ///
+    /// Agents SHOULD only send tables to clients that advertise the `table`
+    /// client capability, and fall back to [`Table::to_markdown`] otherwise.
+    /// Tables are not allowed in prompts.
+    #[cfg(feature = "unstable_table")]
+    Table(Table),
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A structured JSON value, such as an MCP tool's `structuredContent`.
+    ///
+    /// Agents SHOULD only send JSON blocks to clients that advertise the
+    /// `jsonContent` client capability, and fall back to
+    /// [`JsonContent::to_text`] otherwise. JSON blocks are not allowed in prompts.
+    #[cfg(feature = "unstable_json_content")]
+    Json(JsonContent),
     /// Custom or future content block.
     ///
     /// Values beginning with `_` are reserved for implementation-specific
@@ -139,14 +187,100 @@ fn other_content_block_schema(schema: &mut Schema) {
     super::schema_util::reject_known_string_discriminators(
         schema,
         "type",
-        &["text", "image", "audio", "resource_link", "resource"],
+        &[
+            "text",
+            "image",
+            "audio",
+            "resource_link",
+            "resource",
+            #[cfg(feature = "unstable_tool_result_ref")]
+            "tool_result_ref",
+            #[cfg(feature = "unstable_mention")]
+            "mention",
+            #[cfg(feature = "unstable_table")]
+            "table",
+            #[cfg(feature = "unstable_json_content")]
+            "json",
+        ],
     );
 }
 
+/// The kind of a [`ContentBlock`], without its payload.
+///
+/// Returned by [`ContentBlock::kind`], so callers can branch on the kind of a
+/// block while only borrowing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContentKind {
+    /// A [`ContentBlock::Text`] block.
+    Text,
+    /// A [`ContentBlock::Image`] block.
+    Image,
+    /// A [`ContentBlock::Audio`] block.
+    Audio,
+    /// A [`ContentBlock::ResourceLink`] block.
+    ResourceLink,
+    /// A [`ContentBlock::Resource`] block.
+    Resource,
+    /// A `ContentBlock::ToolResultRef` block.
+    #[cfg(feature = "unstable_tool_result_ref")]
+    ToolResultRef,
+    /// A `ContentBlock::Mention` block.
+    #[cfg(feature = "unstable_mention")]
+    Mention,
+    /// A `ContentBlock::Table` block.
+    #[cfg(feature = "unstable_table")]
+    Table,
+    /// A `ContentBlock::Json` block.
+    #[cfg(feature = "unstable_json_content")]
+    Json,
+    /// A custom or future content block, whose type is in
+    /// [`OtherContentBlock::type_`].
+    Other,
+}
+
+/// Error returned by [`ContentBlock::validate`] when a block breaks one of
+/// its invariants.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ContentValidationError {
+    kind: ContentKind,
+    message: String,
+}
+
+impl ContentValidationError {
+    fn new(kind: ContentKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+
+    /// Returns the kind of the invalid block.
+    #[must_use]
+    pub fn kind(&self) -> ContentKind {
+        self.kind
+    }
+
+    /// Returns the human-readable validation error message.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl std::fmt::Display for ContentValidationError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ContentValidationError {}
+
 /// Text provided to or from an LLM.
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[non_exhaustive]
 pub struct TextContent {
     /// Text payload carried by this content block.
@@ -196,6 +330,32 @@ impl TextContent {
         self.meta = meta.into_option();
         self
     }
+
+    /// Appends streamed text to the end of this content.
+    pub fn push_str(&mut self, text: &str) {
+        self.text.push_str(text);
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Keeps at most `max` characters of the text, appending `…` if anything was cut.
+    ///
+    /// Cutting also sets the `truncated` annotation so clients know the text is
+    /// incomplete. Returns whether the text was cut.
+    #[cfg(feature = "unstable_content_truncation")]
+    pub fn truncate_chars(&mut self, max: usize) -> bool {
+        let Some((end, _)) = self.text.char_indices().nth(max) else {
+            return false;
+        };
+        self.text.truncate(end);
+        self.text.push('…');
+        self.annotations
+            .get_or_insert_with(Annotations::default)
+            .truncated = Some(true);
+        true
+    }
 }
 
 impl<T: Into<String>> From<T> for ContentBlock {
@@ -204,6 +364,278 @@ impl<T: Into<String>> From<T> for ContentBlock {
     }
 }
 
+impl ContentBlock {
+    /// Returns the kind of this block.
+    ///
+    /// Both enums are `#[non_exhaustive]`, so matches on the kind need a
+    /// wildcard arm for kinds added in later versions.
+    ///
+    /// ```rust
+    /// use agent_client_protocol_schema::v2::{ContentBlock, ContentKind};
+    ///
+    /// fn label(block: &ContentBlock) -> &'static str {
+    ///     match block.kind() {
+    ///         ContentKind::Text => "text",
+    ///         ContentKind::Image | ContentKind::Audio => "media",
+    ///         _ => "other",
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(label(&ContentBlock::from("Hello")), "text");
+    /// ```
+    #[must_use]
+    pub fn kind(&self) -> ContentKind {
+        match self {
+            ContentBlock::Text(_) => ContentKind::Text,
+            ContentBlock::Image(_) => ContentKind::Image,
+            ContentBlock::Audio(_) => ContentKind::Audio,
+            ContentBlock::ResourceLink(_) => ContentKind::ResourceLink,
+            ContentBlock::Resource(_) => ContentKind::Resource,
+            #[cfg(feature = "unstable_tool_result_ref")]
+            ContentBlock::ToolResultRef(_) => ContentKind::ToolResultRef,
+            #[cfg(feature = "unstable_mention")]
+            ContentBlock::Mention(_) => ContentKind::Mention,
+            #[cfg(feature = "unstable_table")]
+            ContentBlock::Table(_) => ContentKind::Table,
+            #[cfg(feature = "unstable_json_content")]
+            ContentBlock::Json(_) => ContentKind::Json,
+            ContentBlock::Other(_) => ContentKind::Other,
+        }
+    }
+
+    /// Checks the invariants that the schema alone does not enforce.
+    ///
+    /// Images need non-empty `data` or a `uri`, and audio needs non-empty
+    /// `data`. Resource links, embedded resources and mentions need a non-empty
+    /// `uri`, every table row needs one cell per column, and a JSON block's
+    /// `schema` must be an object or a boolean. Text blocks are always valid.
+    /// Custom blocks are not checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ContentValidationError`] describing the broken invariant.
+    pub fn validate(&self) -> Result<(), ContentValidationError> {
+        let invalid = |message: String| Err(ContentValidationError::new(self.kind(), message));
+        match self {
+            ContentBlock::Image(image)
+                if image.data.is_empty() && image.uri.as_deref().is_none_or(str::is_empty) =>
+            {
+                invalid("image has neither `data` nor `uri`".to_string())
+            }
+            ContentBlock::Audio(audio) if audio.data.is_empty() => {
+                invalid("audio `data` is empty".to_string())
+            }
+            ContentBlock::ResourceLink(link) if link.uri.is_empty() => {
+                invalid("resource link `uri` is empty".to_string())
+            }
+            ContentBlock::Resource(resource) => {
+                let uri = match &resource.resource {
+                    EmbeddedResourceResource::TextResourceContents(contents) => &contents.uri,
+                    EmbeddedResourceResource::BlobResourceContents(contents) => &contents.uri,
+                };
+                if uri.is_empty() {
+                    invalid("embedded resource `uri` is empty".to_string())
+                } else {
+                    Ok(())
+                }
+            }
+            #[cfg(feature = "unstable_mention")]
+            ContentBlock::Mention(mention) if mention.uri.is_empty() => {
+                invalid("mention `uri` is empty".to_string())
+            }
+            #[cfg(feature = "unstable_table")]
+            ContentBlock::Table(table) => match table
+                .rows
+                .iter()
+                .position(|row| row.len() != table.columns.len())
+            {
+                Some(index) => invalid(format!(
+                    "table row {index} does not have one cell per column"
+                )),
+                None => Ok(()),
+            },
+            #[cfg(feature = "unstable_json_content")]
+            ContentBlock::Json(JsonContent {
+                schema: Some(schema),
+                ..
+            }) if !schema.is_object() && !schema.is_boolean() => {
+                invalid("json `schema` is neither an object nor a boolean".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Appends streamed text if this is a [`ContentBlock::Text`] block.
+    ///
+    /// Returns `false`, leaving the block unchanged, for any other kind of content.
+    pub fn append_text(&mut self, text: &str) -> bool {
+        match self {
+            ContentBlock::Text(content) => {
+                content.push_str(text);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Renders this block as markdown, for logging or exporting a conversation.
+    ///
+    /// Text is copied verbatim. Images with a `uri` become `![image](uri)`,
+    /// while other images and audio become a placeholder naming their MIME
+    /// type. Resource links and mentions become links, text resources a fenced
+    /// code block tagged with their `uri`, and binary resources a link to their
+    /// `uri`. Link labels and destinations are escaped so they render intact.
+    /// Tables use [`Table::to_markdown`] and JSON values a fenced `json` block.
+    /// Custom or future blocks become a placeholder naming their `type`.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        match self {
+            ContentBlock::Text(content) => content.text.clone(),
+            ContentBlock::Image(image) => match image.uri.as_deref() {
+                Some(uri) if !uri.is_empty() => format!("!{}", markdown_link("image", uri)),
+                _ => format!("[image: {}]", image.mime_type),
+            },
+            ContentBlock::Audio(audio) => format!("[audio: {}]", audio.mime_type),
+            ContentBlock::ResourceLink(link) => {
+                markdown_link(link.title.as_deref().unwrap_or(&link.name), &link.uri)
+            }
+            ContentBlock::Resource(resource) => match &resource.resource {
+                EmbeddedResourceResource::TextResourceContents(contents) => {
+                    fenced_block(&contents.uri, &contents.text)
+                }
+                EmbeddedResourceResource::BlobResourceContents(contents) => {
+                    markdown_link(&contents.uri, &contents.uri)
+                }
+            },
+            #[cfg(feature = "unstable_tool_result_ref")]
+            ContentBlock::ToolResultRef(reference) => {
+                format!("[tool result: {}]", reference.tool_call_id)
+            }
+            #[cfg(feature = "unstable_mention")]
+            ContentBlock::Mention(mention) => markdown_link(&mention.label, &mention.uri),
+            #[cfg(feature = "unstable_table")]
+            ContentBlock::Table(table) => table.to_markdown(),
+            #[cfg(feature = "unstable_json_content")]
+            ContentBlock::Json(json) => fenced_block("json", &json.to_text()),
+            ContentBlock::Other(other) => format!("[{} content]", other.type_),
+        }
+    }
+
+    /// Roughly estimates how many tokens this block adds to a prompt.
+    ///
+    /// Text counts one token per four characters. Images and audio count a
+    /// fixed [`IMAGE_TOKEN_ESTIMATE`] or [`AUDIO_TOKEN_ESTIMATE`], and so do
+    /// embedded binary resources. Links and mentions count their text, not
+    /// the content they point at.
+    #[must_use]
+    pub fn estimate_tokens(&self) -> usize {
+        self.token_count(&crate::tokens::estimate_text_tokens)
+    }
+
+    /// Counts how many tokens this block adds to a prompt, using `tokenizer`
+    /// for its text.
+    ///
+    /// Media is charged the same fixed amounts as [`Self::estimate_tokens`].
+    #[cfg(feature = "tokenizer")]
+    #[must_use]
+    pub fn count_tokens(&self, tokenizer: &impl Tokenizer) -> usize {
+        self.token_count(&|text| tokenizer.count_tokens(text))
+    }
+
+    fn token_count(&self, count_text: &dyn Fn(&str) -> usize) -> usize {
+        match self {
+            ContentBlock::Text(content) => count_text(&content.text),
+            ContentBlock::Image(_) => IMAGE_TOKEN_ESTIMATE,
+            ContentBlock::Audio(_) => AUDIO_TOKEN_ESTIMATE,
+            ContentBlock::ResourceLink(link) => count_text(&link.name) + count_text(&link.uri),
+            ContentBlock::Resource(resource) => match &resource.resource {
+                EmbeddedResourceResource::TextResourceContents(contents) => {
+                    count_text(&contents.text)
+                }
+                EmbeddedResourceResource::BlobResourceContents(contents) => {
+                    crate::tokens::media_tokens(contents.mime_type.as_deref())
+                }
+            },
+            #[cfg(feature = "unstable_tool_result_ref")]
+            ContentBlock::ToolResultRef(reference) => count_text(&reference.tool_call_id.0),
+            #[cfg(feature = "unstable_mention")]
+            ContentBlock::Mention(mention) => count_text(&mention.label) + count_text(&mention.uri),
+            #[cfg(feature = "unstable_table")]
+            ContentBlock::Table(table) => count_text(&table.to_markdown()),
+            #[cfg(feature = "unstable_json_content")]
+            ContentBlock::Json(json) => count_text(&json.value.to_string()),
+            ContentBlock::Other(other) => {
+                count_text(&serde_json::to_string(&other.fields).unwrap_or_default())
+            }
+        }
+    }
+}
+
+/// Renders a markdown link, escaping the label and destination so neither can
+/// end the link early.
+///
+/// Backslashes and brackets in the label are escaped and line breaks become
+/// spaces. In the destination, backslashes, parentheses and angle brackets are
+/// escaped and whitespace is percent-encoded.
+fn markdown_link(label: &str, destination: &str) -> String {
+    let mut out = String::from("[");
+    for ch in label.chars() {
+        match ch {
+            '\\' | '[' | ']' => {
+                out.push('\\');
+                out.push(ch);
+            }
+            '\n' | '\r' => out.push(' '),
+            _ => out.push(ch),
+        }
+    }
+    out.push_str("](");
+    for ch in destination.chars() {
+        match ch {
+            '\\' | '(' | ')' | '<' | '>' => {
+                out.push('\\');
+                out.push(ch);
+            }
+            ' ' => out.push_str("%20"),
+            '\t' => out.push_str("%09"),
+            '\n' => out.push_str("%0A"),
+            '\r' => out.push_str("%0D"),
+            _ => out.push(ch),
+        }
+    }
+    out.push(')');
+    out
+}
+
+/// Wraps `body` in a code fence longer than any backtick run inside it.
+fn fenced_block(info: &str, body: &str) -> String {
+    let longest_run = body.split(|ch| ch != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run.max(2) + 1);
+    let newline = if body.ends_with('\n') { "" } else { "\n" };
+    format!("{fence}{info}\n{body}{newline}{fence}")
+}
+
+/// Roughly estimates how many tokens `blocks` take up as a prompt.
+///
+/// Sums [`ContentBlock::estimate_tokens`] over every block.
+#[must_use]
+pub fn estimate_prompt_tokens(blocks: &[ContentBlock]) -> usize {
+    blocks.iter().map(ContentBlock::estimate_tokens).sum()
+}
+
+/// Counts how many tokens `blocks` take up as a prompt, using `tokenizer` for
+/// their text.
+///
+/// Sums [`ContentBlock::count_tokens`] over every block.
+#[cfg(feature = "tokenizer")]
+#[must_use]
+pub fn count_prompt_tokens(blocks: &[ContentBlock], tokenizer: &impl Tokenizer) -> usize {
+    blocks
+        .iter()
+        .map(|block| block.count_tokens(tokenizer))
+        .sum()
+}
+
 /// An image provided to or from an LLM.
 #[serde_as]
 #[skip_serializing_none]
@@ -441,6 +873,12 @@ impl TextResourceContents {
         }
     }
 
+    /// Parses `uri` to tell local files, web URLs, and other schemes apart.
+    #[must_use]
+    pub fn resource_uri(&self) -> ResourceUri {
+        ResourceUri::new(self.uri.clone())
+    }
+
     /// Sets or clears the optional `mimeType` field.
     #[must_use]
     pub fn mime_type(mut self, mime_type: impl IntoOption<String>) -> Self {
@@ -500,6 +938,12 @@ impl BlobResourceContents {
         }
     }
 
+    /// Parses `uri` to tell local files, web URLs, and other schemes apart.
+    #[must_use]
+    pub fn resource_uri(&self) -> ResourceUri {
+        ResourceUri::new(self.uri.clone())
+    }
+
     /// Sets or clears the optional `mimeType` field.
     #[must_use]
     pub fn mime_type(mut self, mime_type: impl IntoOption<String>) -> Self {
@@ -507,6 +951,29 @@ impl BlobResourceContents {
         self
     }
 
+    /// Rejects this resource if its encoded `blob` is longer than `max_bytes`.
+    ///
+    /// This is a post-parse check on the base64 text: by the time it runs the
+    /// message has already been deserialized, so it does not bound memory use
+    /// (cap the transport's message size for that). Peers may use it to refuse
+    /// oversized embedded resources before decoding or storing them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::invalid_params`] error whose data names the resource
+    /// `uri`, its `size` and the configured `limit`.
+    pub fn check_blob_size(&self, max_bytes: usize) -> Result<()> {
+        if self.blob.len() <= max_bytes {
+            return Ok(());
+        }
+        Err(Error::invalid_params().data(serde_json::json!({
+            "reason": "blob exceeds the maximum allowed size",
+            "uri": self.uri,
+            "size": self.blob.len(),
+            "limit": max_bytes,
+        })))
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -583,6 +1050,12 @@ impl ResourceLink {
         }
     }
 
+    /// Parses `uri` to tell local files, web URLs, and other schemes apart.
+    #[must_use]
+    pub fn resource_uri(&self) -> ResourceUri {
+        ResourceUri::new(self.uri.clone())
+    }
+
     /// Sets or clears the optional `annotations` field.
     #[must_use]
     pub fn annotations(mut self, annotations: impl IntoOption<Annotations>) -> Self {
@@ -630,28 +1103,20 @@ impl ResourceLink {
     }
 }
 
-/// Optional annotations for the client. The client can use annotations to inform how objects are used or displayed
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A reference to the output of an earlier tool call in the same session.
+#[cfg(feature = "unstable_tool_result_ref")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct Annotations {
-    /// Intended recipients for this content, such as the user or assistant.
-    #[serde_as(deserialize_as = "DefaultOnError<Option<VecSkipError<_, SkipListener>>>")]
-    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
-    #[serde(default)]
-    pub audience: Option<Vec<Role>>,
-    /// Timestamp indicating when the underlying resource was last modified.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub last_modified: Option<String>,
-    /// Relative importance of this content when clients choose what to surface.
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub priority: Option<f64>,
+pub struct ToolResultRef {
+    /// The ID of the tool call whose output is being referenced.
+    pub tool_call_id: ToolCallId,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -664,32 +1129,77 @@ pub struct Annotations {
     pub meta: Option<Meta>,
 }
 
-impl Annotations {
-    /// Creates annotations with no audience, priority, or timestamp hints set.
+#[cfg(feature = "unstable_tool_result_ref")]
+impl ToolResultRef {
+    /// Builds [`ToolResultRef`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new() -> Self {
-        Self::default()
+    pub fn new(tool_call_id: impl Into<ToolCallId>) -> Self {
+        Self {
+            tool_call_id: tool_call_id.into(),
+            meta: None,
+        }
     }
 
-    /// Sets or clears the optional `audience` field.
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
     #[must_use]
-    pub fn audience(mut self, audience: impl IntoOption<Vec<Role>>) -> Self {
-        self.audience = audience.into_option();
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
         self
     }
+}
 
-    /// Sets or clears the optional `lastModified` field.
-    #[must_use]
-    pub fn last_modified(mut self, last_modified: impl IntoOption<String>) -> Self {
-        self.last_modified = last_modified.into_option();
-        self
-    }
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A structured `@`-mention of a file, symbol or URL.
+///
+/// Editors let users mention things in the prompt box with `@`. Sending them as
+/// mentions instead of plain text lets agents resolve the target by `uri`
+/// while clients keep rendering the `label` the user picked.
+#[cfg(feature = "unstable_mention")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Mention {
+    /// What the mention refers to.
+    pub kind: MentionKind,
+    /// The text shown for the mention, such as `main.rs` or `parse_args`.
+    pub label: String,
+    /// The URI of the mentioned target.
+    ///
+    /// For symbols this is the URI of the file containing the symbol, typically
+    /// with a fragment locating it.
+    pub uri: String,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
 
-    /// Sets or clears the optional `priority` field.
+#[cfg(feature = "unstable_mention")]
+impl Mention {
+    /// Builds [`Mention`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn priority(mut self, priority: impl IntoOption<f64>) -> Self {
-        self.priority = priority.into_option();
-        self
+    pub fn new(kind: MentionKind, label: impl Into<String>, uri: impl Into<String>) -> Self {
+        Self {
+            kind,
+            label: label.into(),
+            uri: uri.into(),
+            meta: None,
+        }
     }
 
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
@@ -704,16 +1214,23 @@ impl Annotations {
     }
 }
 
-/// The sender or recipient of messages and data in a conversation.
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// The kind of target a [`Mention`] refers to.
+#[cfg(feature = "unstable_mention")]
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "snake_case")]
 #[non_exhaustive]
-pub enum Role {
-    /// The assistant side of a conversation.
-    Assistant,
-    /// The user side of a conversation.
-    User,
-    /// Custom or future role.
+pub enum MentionKind {
+    /// A file or directory in the workspace.
+    File,
+    /// A code symbol, such as a function or type.
+    Symbol,
+    /// A web page or other URL.
+    Url,
+    /// Custom or future mention kind.
     ///
     /// Values beginning with `_` are reserved for implementation-specific
     /// extensions. Unknown values that do not begin with `_` are reserved for
@@ -722,38 +1239,933 @@ pub enum Role {
     Other(String),
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Structured tabular data, such as query results or file listings.
+///
+/// Sending rows as cells instead of pre-rendered markdown lets clients render
+/// a real table and sort or filter it. Every cell is plain text.
+#[cfg(feature = "unstable_table")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Table {
+    /// The column headers, in display order.
+    pub columns: Vec<String>,
+    /// The table rows.
+    ///
+    /// Each row holds one cell per column, in the same order as `columns`.
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rows: Vec<Vec<String>>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
 
-    #[test]
-    fn test_text_content_roundtrip() {
-        let content = TextContent::new("hello world");
-        let json = serde_json::to_value(&content).unwrap();
-        let parsed: TextContent = serde_json::from_value(json).unwrap();
-        assert_eq!(content, parsed);
+#[cfg(feature = "unstable_table")]
+impl Table {
+    /// Builds [`Table`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(columns: Vec<String>, rows: Vec<Vec<String>>) -> Self {
+        Self {
+            columns,
+            rows,
+            meta: None,
+        }
     }
 
-    #[test]
-    fn test_text_content_omits_optional_fields() {
-        let content = TextContent::new("hello");
-        let json = serde_json::to_value(&content).unwrap();
-        assert!(!json.as_object().unwrap().contains_key("annotations"));
-        assert!(!json.as_object().unwrap().contains_key("meta"));
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
     }
 
-    #[test]
-    fn test_text_content_meta_defaults_on_missing_or_malformed_value() {
-        let missing: TextContent = serde_json::from_value(serde_json::json!({
-            "text": "hello"
-        }))
-        .unwrap();
-        assert_eq!(missing.meta, None);
+    /// Renders the table as a GitHub-flavored markdown table.
+    ///
+    /// This is the fallback for clients that do not advertise the `table`
+    /// capability. Backslashes and pipes in cells are escaped and line breaks
+    /// become spaces. A table without columns renders as an empty string, since
+    /// markdown has no table without a header row.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        fn push_row<'a>(out: &mut String, cells: impl IntoIterator<Item = &'a str>) {
+            out.push('|');
+            for cell in cells {
+                out.push(' ');
+                for ch in cell.chars() {
+                    match ch {
+                        '\\' => out.push_str("\\\\"),
+                        '|' => out.push_str("\\|"),
+                        '\n' | '\r' => out.push(' '),
+                        _ => out.push(ch),
+                    }
+                }
+                out.push_str(" |");
+            }
+            out.push('\n');
+        }
 
-        let malformed: TextContent = serde_json::from_value(serde_json::json!({
-            "text": "hello",
-            "_meta": false
-        }))
+        let mut out = String::new();
+        if self.columns.is_empty() {
+            return out;
+        }
+        push_row(&mut out, self.columns.iter().map(String::as_str));
+        push_row(&mut out, self.columns.iter().map(|_| "---"));
+        for row in &self.rows {
+            push_row(&mut out, row.iter().map(String::as_str));
+        }
+        out
+    }
+}
+
+/// Builds a [`Table`] from rows of cells, using the first row as the column
+/// headers.
+///
+/// An empty list gives a table with no columns and no rows.
+#[cfg(feature = "unstable_table")]
+impl From<Vec<Vec<String>>> for Table {
+    fn from(mut rows: Vec<Vec<String>>) -> Self {
+        if rows.is_empty() {
+            return Self::new(Vec::new(), Vec::new());
+        }
+        let columns = rows.remove(0);
+        Self::new(columns, rows)
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for receiving [`ContentBlock::Table`] content.
+///
+/// Supplying `{}` means the client can render tables.
+#[cfg(feature = "unstable_table")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TableCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_table")]
+impl TableCapabilities {
+    /// Builds an empty [`TableCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A structured JSON value, such as an MCP tool's `structuredContent`.
+///
+/// Agents forward the value as-is instead of stringifying it, so clients can
+/// render it as a collapsible tree.
+#[cfg(feature = "unstable_json_content")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct JsonContent {
+    /// The JSON value. Any JSON value is allowed, including `null`.
+    pub value: serde_json::Value,
+    /// A JSON Schema describing `value`, such as an MCP tool's `outputSchema`.
+    ///
+    /// Clients MAY use it to label or validate the value.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub schema: Option<serde_json::Value>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_json_content")]
+impl JsonContent {
+    /// Builds [`JsonContent`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(value: serde_json::Value) -> Self {
+        Self {
+            value,
+            schema: None,
+            meta: None,
+        }
+    }
+
+    /// A JSON Schema describing `value`, such as an MCP tool's `outputSchema`.
+    #[must_use]
+    pub fn schema(mut self, schema: impl IntoOption<serde_json::Value>) -> Self {
+        self.schema = schema.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+
+    /// Renders the value as pretty-printed JSON.
+    ///
+    /// This is the fallback for clients that do not advertise the
+    /// `jsonContent` capability. The schema is not included.
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        serde_json::to_string_pretty(&self.value).unwrap_or_else(|_| self.value.to_string())
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Capabilities for receiving [`ContentBlock::Json`] content.
+///
+/// Supplying `{}` means the client can render JSON values.
+#[cfg(feature = "unstable_json_content")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct JsonContentCapabilities {
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_json_content")]
+impl JsonContentCapabilities {
+    /// Builds an empty [`JsonContentCapabilities`]; use builder methods to advertise supported sub-capabilities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Optional annotations for the client. The client can use annotations to inform how objects are used or displayed
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema, Default)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Annotations {
+    /// Intended recipients for this content, such as the user or assistant.
+    #[serde_as(deserialize_as = "DefaultOnError<Option<VecSkipError<_, SkipListener>>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default)]
+    pub audience: Option<Vec<Role>>,
+    /// Timestamp indicating when the underlying resource was last modified.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub last_modified: Option<String>,
+    /// Relative importance of this content when clients choose what to surface.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub priority: Option<f64>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Whether the content was cut short before being sent.
+    ///
+    /// Lets clients show that something is missing instead of silently
+    /// presenting partial content as complete.
+    #[cfg(feature = "unstable_content_truncation")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub truncated: Option<bool>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl Annotations {
+    /// Creates annotations with no audience, priority, or timestamp hints set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets or clears the optional `audience` field.
+    #[must_use]
+    pub fn audience(mut self, audience: impl IntoOption<Vec<Role>>) -> Self {
+        self.audience = audience.into_option();
+        self
+    }
+
+    /// Sets or clears the optional `lastModified` field.
+    #[must_use]
+    pub fn last_modified(mut self, last_modified: impl IntoOption<String>) -> Self {
+        self.last_modified = last_modified.into_option();
+        self
+    }
+
+    /// Sets or clears the optional `priority` field.
+    #[must_use]
+    pub fn priority(mut self, priority: impl IntoOption<f64>) -> Self {
+        self.priority = priority.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Sets or clears the optional `truncated` field.
+    #[cfg(feature = "unstable_content_truncation")]
+    #[must_use]
+    pub fn truncated(mut self, truncated: impl IntoOption<bool>) -> Self {
+        self.truncated = truncated.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// The sender or recipient of messages and data in a conversation.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum Role {
+    /// The assistant side of a conversation.
+    Assistant,
+    /// The user side of a conversation.
+    User,
+    /// Custom or future role.
+    ///
+    /// Values beginning with `_` are reserved for implementation-specific
+    /// extensions. Unknown values that do not begin with `_` are reserved for
+    /// future ACP variants.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Error returned when content cannot be converted between ACP and the
+/// [`rmcp`] content model.
+#[cfg(feature = "mcp")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct McpConversionError {
+    message: String,
+}
+
+#[cfg(feature = "mcp")]
+impl McpConversionError {
+    /// Creates a conversion error with a human-readable message.
+    #[must_use]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    /// Returns the human-readable conversion error message.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    fn unsupported_field(type_name: &str, field: &str) -> Self {
+        Self::new(format!("{type_name} `{field}` has no MCP equivalent"))
+    }
+}
+
+#[cfg(feature = "mcp")]
+impl std::fmt::Display for McpConversionError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.message)
+    }
+}
+
+#[cfg(feature = "mcp")]
+impl std::error::Error for McpConversionError {}
+
+/// Conversion from the [`rmcp`] content model into ACP content.
+///
+/// This is a crate trait rather than a `TryFrom` impl because
+/// `impl<T: Into<String>> From<T> for ContentBlock` would overlap with it.
+#[cfg(feature = "mcp")]
+pub trait FromMcp<T>: Sized {
+    /// Converts an MCP value into its ACP equivalent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the MCP value carries data ACP cannot represent.
+    fn from_mcp(value: T) -> Result<Self, McpConversionError>;
+}
+
+/// Converts MCP content into the matching [`ContentBlock`] variant.
+///
+/// Fails if a resource link carries `icons`, which ACP resource links do not
+/// define.
+#[cfg(feature = "mcp")]
+impl FromMcp<rmcp::model::Content> for ContentBlock {
+    fn from_mcp(content: rmcp::model::Content) -> Result<Self, McpConversionError> {
+        use rmcp::model::RawContent;
+
+        let annotations = content.annotations.map(Annotations::from_mcp).transpose()?;
+        Ok(match content.raw {
+            RawContent::Text(text) => Self::Text(
+                TextContent::new(text.text)
+                    .annotations(annotations)
+                    .meta(text.meta.map(|meta| meta.0)),
+            ),
+            RawContent::Image(image) => Self::Image(
+                ImageContent::new(image.data, image.mime_type)
+                    .annotations(annotations)
+                    .meta(image.meta.map(|meta| meta.0)),
+            ),
+            RawContent::Audio(audio) => {
+                Self::Audio(AudioContent::new(audio.data, audio.mime_type).annotations(annotations))
+            }
+            RawContent::ResourceLink(link) => {
+                if link.icons.is_some_and(|icons| !icons.is_empty()) {
+                    return Err(McpConversionError::new(
+                        "MCP resource link `icons` have no ACP equivalent",
+                    ));
+                }
+                Self::ResourceLink(
+                    ResourceLink::new(link.name, link.uri)
+                        .annotations(annotations)
+                        .description(link.description)
+                        .mime_type(link.mime_type)
+                        .size(link.size.map(i64::from))
+                        .title(link.title),
+                )
+            }
+            RawContent::Resource(resource) => Self::Resource(
+                EmbeddedResource::new(resource.resource.into())
+                    .annotations(annotations)
+                    .meta(resource.meta.map(|meta| meta.0)),
+            ),
+        })
+    }
+}
+
+/// Converts MCP annotations into ACP [`Annotations`].
+///
+/// `lastModified` is written as an RFC 3339 timestamp.
+#[cfg(feature = "mcp")]
+impl FromMcp<rmcp::model::Annotations> for Annotations {
+    fn from_mcp(annotations: rmcp::model::Annotations) -> Result<Self, McpConversionError> {
+        Ok(Self::new()
+            .audience(annotations.audience.map(|audience| {
+                audience
+                    .into_iter()
+                    .map(|role| match role {
+                        rmcp::model::Role::Assistant => Role::Assistant,
+                        rmcp::model::Role::User => Role::User,
+                    })
+                    .collect()
+            }))
+            .last_modified(
+                annotations
+                    .last_modified
+                    .map(|last_modified| last_modified.to_rfc3339()),
+            )
+            .priority(annotations.priority.map(f64::from)))
+    }
+}
+
+/// Converts a [`ContentBlock`] into the matching MCP content variant.
+///
+/// Fails instead of dropping data when the block cannot be represented in
+/// MCP: ACP-only content types, an image `uri`, `_meta` on audio content,
+/// resource links or annotations, a resource link `size` that does not fit in
+/// a `u32`, or an annotation `lastModified` that is not an RFC 3339 timestamp.
+#[cfg(feature = "mcp")]
+impl TryFrom<ContentBlock> for rmcp::model::Content {
+    type Error = McpConversionError;
+
+    fn try_from(block: ContentBlock) -> Result<Self, Self::Error> {
+        use rmcp::model::{
+            RawAudioContent, RawContent, RawEmbeddedResource, RawImageContent, RawResource,
+            RawTextContent,
+        };
+
+        let (raw, annotations) = match block {
+            ContentBlock::Text(text) => (
+                RawContent::Text(RawTextContent {
+                    text: text.text,
+                    meta: text.meta.map(rmcp::model::Meta),
+                }),
+                text.annotations,
+            ),
+            ContentBlock::Image(image) => {
+                if image.uri.is_some() {
+                    return Err(McpConversionError::unsupported_field("image", "uri"));
+                }
+                (
+                    RawContent::Image(RawImageContent {
+                        data: image.data,
+                        mime_type: image.mime_type,
+                        meta: image.meta.map(rmcp::model::Meta),
+                    }),
+                    image.annotations,
+                )
+            }
+            ContentBlock::Audio(audio) => {
+                if audio.meta.is_some() {
+                    return Err(McpConversionError::unsupported_field("audio", "_meta"));
+                }
+                (
+                    RawContent::Audio(RawAudioContent {
+                        data: audio.data,
+                        mime_type: audio.mime_type,
+                    }),
+                    audio.annotations,
+                )
+            }
+            ContentBlock::ResourceLink(link) => {
+                if link.meta.is_some() {
+                    return Err(McpConversionError::unsupported_field(
+                        "resource link",
+                        "_meta",
+                    ));
+                }
+                let size = link
+                    .size
+                    .map(|size| {
+                        u32::try_from(size).map_err(|_| {
+                            McpConversionError::new(format!(
+                                "resource link `size` {size} does not fit in an MCP resource size"
+                            ))
+                        })
+                    })
+                    .transpose()?;
+                (
+                    RawContent::ResourceLink(RawResource {
+                        uri: link.uri,
+                        name: link.name,
+                        title: link.title,
+                        description: link.description,
+                        mime_type: link.mime_type,
+                        size,
+                        icons: None,
+                    }),
+                    link.annotations,
+                )
+            }
+            ContentBlock::Resource(resource) => (
+                RawContent::Resource(RawEmbeddedResource {
+                    meta: resource.meta.map(rmcp::model::Meta),
+                    resource: resource.resource.into(),
+                }),
+                resource.annotations,
+            ),
+            #[allow(unreachable_patterns, reason = "ACP-only variants are feature-gated")]
+            _ => {
+                return Err(McpConversionError::new(
+                    "content block type has no MCP equivalent",
+                ));
+            }
+        };
+        Ok(rmcp::model::Annotated {
+            raw,
+            annotations: annotations
+                .map(rmcp::model::Annotations::try_from)
+                .transpose()?,
+        })
+    }
+}
+
+/// Converts ACP [`Annotations`] into MCP annotations.
+///
+/// Fails if the annotations carry `_meta` or `truncated`, an audience role MCP
+/// does not define, or a `lastModified` that is not an RFC 3339 timestamp.
+#[cfg(feature = "mcp")]
+impl TryFrom<Annotations> for rmcp::model::Annotations {
+    type Error = McpConversionError;
+
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "MCP stores priorities as f32"
+    )]
+    fn try_from(annotations: Annotations) -> Result<Self, Self::Error> {
+        if annotations.meta.is_some() {
+            return Err(McpConversionError::unsupported_field(
+                "annotations",
+                "_meta",
+            ));
+        }
+        #[cfg(feature = "unstable_content_truncation")]
+        if annotations.truncated.is_some() {
+            return Err(McpConversionError::unsupported_field(
+                "annotations",
+                "truncated",
+            ));
+        }
+        let last_modified = annotations
+            .last_modified
+            .map(|last_modified| {
+                last_modified.parse().map_err(|_| {
+                    McpConversionError::new(format!(
+                        "annotation `lastModified` `{last_modified}` is not an RFC 3339 timestamp"
+                    ))
+                })
+            })
+            .transpose()?;
+        let audience = annotations
+            .audience
+            .map(|audience| {
+                audience
+                    .into_iter()
+                    .map(|role| match role {
+                        Role::Assistant => Ok(rmcp::model::Role::Assistant),
+                        Role::User => Ok(rmcp::model::Role::User),
+                        Role::Other(role) => Err(McpConversionError::new(format!(
+                            "annotation audience role `{role}` has no MCP equivalent"
+                        ))),
+                    })
+                    .collect::<Result<_, _>>()
+            })
+            .transpose()?;
+        Ok(Self {
+            audience,
+            priority: annotations.priority.map(|priority| priority as f32),
+            last_modified,
+        })
+    }
+}
+
+/// Converts MCP resource contents into ACP [`EmbeddedResourceResource`].
+#[cfg(feature = "mcp")]
+impl From<rmcp::model::ResourceContents> for EmbeddedResourceResource {
+    fn from(contents: rmcp::model::ResourceContents) -> Self {
+        match contents {
+            rmcp::model::ResourceContents::TextResourceContents {
+                uri,
+                mime_type,
+                text,
+                meta,
+            } => Self::TextResourceContents(
+                TextResourceContents::new(text, uri)
+                    .mime_type(mime_type)
+                    .meta(meta.map(|meta| meta.0)),
+            ),
+            rmcp::model::ResourceContents::BlobResourceContents {
+                uri,
+                mime_type,
+                blob,
+                meta,
+            } => Self::BlobResourceContents(
+                BlobResourceContents::new(blob, uri)
+                    .mime_type(mime_type)
+                    .meta(meta.map(|meta| meta.0)),
+            ),
+        }
+    }
+}
+
+/// Converts ACP [`EmbeddedResourceResource`] into MCP resource contents.
+#[cfg(feature = "mcp")]
+impl From<EmbeddedResourceResource> for rmcp::model::ResourceContents {
+    fn from(contents: EmbeddedResourceResource) -> Self {
+        match contents {
+            EmbeddedResourceResource::TextResourceContents(contents) => {
+                Self::TextResourceContents {
+                    uri: contents.uri,
+                    mime_type: contents.mime_type,
+                    text: contents.text,
+                    meta: contents.meta.map(rmcp::model::Meta),
+                }
+            }
+            EmbeddedResourceResource::BlobResourceContents(contents) => {
+                Self::BlobResourceContents {
+                    uri: contents.uri,
+                    mime_type: contents.mime_type,
+                    blob: contents.blob,
+                    meta: contents.meta.map(rmcp::model::Meta),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_prompt_tokens() {
+        let prompt = vec![
+            ContentBlock::from("Hello, world!"),
+            ContentBlock::Image(ImageContent::new("iVBORw0KGgo=", "image/png")),
+            ContentBlock::Audio(AudioContent::new("UklGRg==", "audio/wav")),
+            ContentBlock::ResourceLink(ResourceLink::new("main.rs", "file:///src/main.rs")),
+            ContentBlock::Resource(EmbeddedResource::new(
+                EmbeddedResourceResource::TextResourceContents(TextResourceContents::new(
+                    "fn main() {}\n",
+                    "file:///src/main.rs",
+                )),
+            )),
+            ContentBlock::Resource(EmbeddedResource::new(
+                EmbeddedResourceResource::BlobResourceContents(
+                    BlobResourceContents::new("UklGRg==", "file:///voice.wav")
+                        .mime_type("audio/wav"),
+                ),
+            )),
+        ];
+
+        let estimates: Vec<usize> = prompt.iter().map(ContentBlock::estimate_tokens).collect();
+        assert_eq!(
+            estimates,
+            [
+                4,
+                IMAGE_TOKEN_ESTIMATE,
+                AUDIO_TOKEN_ESTIMATE,
+                7,
+                4,
+                AUDIO_TOKEN_ESTIMATE
+            ]
+        );
+        assert_eq!(estimate_prompt_tokens(&prompt), 3015);
+        assert_eq!(estimate_prompt_tokens(&[]), 0);
+    }
+
+    #[cfg(feature = "tokenizer")]
+    #[test]
+    fn test_count_prompt_tokens_with_tokenizer() {
+        struct Words;
+
+        impl Tokenizer for Words {
+            fn count_tokens(&self, text: &str) -> usize {
+                text.split_whitespace().count()
+            }
+        }
+
+        let prompt = vec![
+            ContentBlock::from("Hello, world!"),
+            ContentBlock::Image(ImageContent::new("iVBORw0KGgo=", "image/png")),
+        ];
+        assert_eq!(
+            count_prompt_tokens(&prompt, &Words),
+            2 + IMAGE_TOKEN_ESTIMATE
+        );
+    }
+
+    #[cfg(feature = "mcp")]
+    fn assert_mcp_roundtrip(block: &ContentBlock) {
+        let mcp = rmcp::model::Content::try_from(block.clone()).unwrap();
+        assert_eq!(
+            serde_json::to_value(&mcp).unwrap(),
+            serde_json::to_value(block).unwrap()
+        );
+        assert_eq!(&ContentBlock::from_mcp(mcp).unwrap(), block);
+    }
+
+    #[cfg(feature = "mcp")]
+    #[test]
+    fn test_mcp_text_roundtrip() {
+        assert_mcp_roundtrip(&ContentBlock::Text(
+            TextContent::new("hello")
+                .annotations(Annotations::new().audience(vec![Role::User]).priority(0.5)),
+        ));
+    }
+
+    #[cfg(feature = "mcp")]
+    #[test]
+    fn test_mcp_image_and_audio_roundtrip_preserve_mime_type() {
+        assert_mcp_roundtrip(&ContentBlock::Image(ImageContent::new("aW1n", "image/png")));
+        assert_mcp_roundtrip(&ContentBlock::Audio(AudioContent::new("YXVk", "audio/wav")));
+    }
+
+    #[cfg(feature = "mcp")]
+    #[test]
+    fn test_mcp_resource_roundtrip() {
+        assert_mcp_roundtrip(&ContentBlock::ResourceLink(
+            ResourceLink::new("notes", "file:///notes.md")
+                .mime_type("text/markdown")
+                .size(42),
+        ));
+        assert_mcp_roundtrip(&ContentBlock::Resource(EmbeddedResource::new(
+            EmbeddedResourceResource::TextResourceContents(
+                TextResourceContents::new("# Notes", "file:///notes.md").mime_type("text/markdown"),
+            ),
+        )));
+        assert_mcp_roundtrip(&ContentBlock::Resource(EmbeddedResource::new(
+            EmbeddedResourceResource::BlobResourceContents(
+                BlobResourceContents::new("aGVsbG8=", "file:///hello.bin")
+                    .mime_type("application/octet-stream"),
+            ),
+        )));
+    }
+
+    #[cfg(feature = "mcp")]
+    #[test]
+    fn test_mcp_last_modified_roundtrip() {
+        let block = ContentBlock::Text(
+            TextContent::new("hello")
+                .annotations(Annotations::new().last_modified("2025-01-02T03:04:05Z")),
+        );
+        let mcp = rmcp::model::Content::try_from(block).unwrap();
+        let ContentBlock::Text(text) = ContentBlock::from_mcp(mcp).unwrap() else {
+            panic!("expected text content");
+        };
+        assert_eq!(
+            text.annotations.unwrap().last_modified.as_deref(),
+            Some("2025-01-02T03:04:05+00:00")
+        );
+    }
+
+    #[cfg(feature = "mcp")]
+    #[test]
+    fn test_mcp_rejects_unrepresentable_fields() {
+        let image = ContentBlock::Image(
+            ImageContent::new("aW1n", "image/png").uri("https://example.com/a.png"),
+        );
+        assert_eq!(
+            rmcp::model::Content::try_from(image).unwrap_err().message(),
+            "image `uri` has no MCP equivalent"
+        );
+
+        let last_modified = ContentBlock::Text(
+            TextContent::new("hello").annotations(Annotations::new().last_modified("yesterday")),
+        );
+        assert!(rmcp::model::Content::try_from(last_modified).is_err());
+
+        let size = ContentBlock::ResourceLink(
+            ResourceLink::new("big", "file:///big.bin").size(i64::from(u32::MAX) + 1),
+        );
+        assert!(rmcp::model::Content::try_from(size).is_err());
+    }
+
+    #[cfg(all(feature = "mcp", feature = "unstable_content_truncation"))]
+    #[test]
+    fn test_mcp_rejects_truncated_annotation() {
+        let truncated = ContentBlock::Text(
+            TextContent::new("hel").annotations(Annotations::new().truncated(true)),
+        );
+        assert_eq!(
+            rmcp::model::Content::try_from(truncated)
+                .unwrap_err()
+                .message(),
+            "annotations `truncated` has no MCP equivalent"
+        );
+    }
+
+    #[test]
+    fn test_text_content_roundtrip() {
+        let content = TextContent::new("hello world");
+        let json = serde_json::to_value(&content).unwrap();
+        let parsed: TextContent = serde_json::from_value(json).unwrap();
+        assert_eq!(content, parsed);
+    }
+
+    #[test]
+    fn test_text_content_omits_optional_fields() {
+        let content = TextContent::new("hello");
+        let json = serde_json::to_value(&content).unwrap();
+        assert!(!json.as_object().unwrap().contains_key("annotations"));
+        assert!(!json.as_object().unwrap().contains_key("meta"));
+    }
+
+    #[test]
+    fn test_text_content_meta_defaults_on_missing_or_malformed_value() {
+        let missing: TextContent = serde_json::from_value(serde_json::json!({
+            "text": "hello"
+        }))
+        .unwrap();
+        assert_eq!(missing.meta, None);
+
+        let malformed: TextContent = serde_json::from_value(serde_json::json!({
+            "text": "hello",
+            "_meta": false
+        }))
         .unwrap();
         assert_eq!(malformed.meta, None);
     }
@@ -851,4 +2263,531 @@ mod tests {
         assert!(!json.as_object().unwrap().contains_key("annotations"));
         assert!(!json.as_object().unwrap().contains_key("meta"));
     }
+
+    #[test]
+    fn test_append_text() {
+        let mut text = TextContent::default();
+        text.push_str("Hello");
+        assert_eq!(text, TextContent::new("Hello"));
+
+        let mut block = ContentBlock::Text(text);
+        assert!(block.append_text(", world"));
+        assert_eq!(block, ContentBlock::Text(TextContent::new("Hello, world")));
+
+        let mut image = ContentBlock::Image(ImageContent::new("aGVsbG8=", "image/png"));
+        assert!(!image.append_text("ignored"));
+        assert_eq!(
+            image,
+            ContentBlock::Image(ImageContent::new("aGVsbG8=", "image/png"))
+        );
+    }
+
+    #[test]
+    fn test_blob_size_guard() {
+        let blob = BlobResourceContents::new("aGVsbG8=", "file:///hello.bin");
+        assert!(blob.check_blob_size(8).is_ok());
+
+        let err = blob.check_blob_size(4).unwrap_err();
+        assert_eq!(err.code, crate::v2::ErrorCode::InvalidParams);
+        let data = err.data.unwrap();
+        assert_eq!(data["uri"], "file:///hello.bin");
+        assert_eq!(data["size"], 8);
+        assert_eq!(data["limit"], 4);
+    }
+
+    #[test]
+    fn test_content_block_kind() {
+        #[cfg_attr(
+            not(any(
+                feature = "unstable_tool_result_ref",
+                feature = "unstable_mention",
+                feature = "unstable_table",
+                feature = "unstable_json_content"
+            )),
+            allow(unused_mut)
+        )]
+        let mut cases = vec![
+            (ContentBlock::from("Hello"), ContentKind::Text),
+            (
+                ContentBlock::Image(ImageContent::new("aGVsbG8=", "image/png")),
+                ContentKind::Image,
+            ),
+            (
+                ContentBlock::Audio(AudioContent::new("UklGRg==", "audio/wav")),
+                ContentKind::Audio,
+            ),
+            (
+                ContentBlock::ResourceLink(ResourceLink::new("main.rs", "file:///src/main.rs")),
+                ContentKind::ResourceLink,
+            ),
+            (
+                ContentBlock::Resource(EmbeddedResource::new(
+                    EmbeddedResourceResource::TextResourceContents(TextResourceContents::new(
+                        "fn main() {}\n",
+                        "file:///src/main.rs",
+                    )),
+                )),
+                ContentKind::Resource,
+            ),
+            (
+                ContentBlock::Other(OtherContentBlock::new("_custom", BTreeMap::new())),
+                ContentKind::Other,
+            ),
+        ];
+        #[cfg(feature = "unstable_tool_result_ref")]
+        cases.push((
+            ContentBlock::ToolResultRef(ToolResultRef::new("call_001")),
+            ContentKind::ToolResultRef,
+        ));
+        #[cfg(feature = "unstable_mention")]
+        cases.push((
+            ContentBlock::Mention(Mention::new(
+                MentionKind::File,
+                "main.rs",
+                "file:///src/main.rs",
+            )),
+            ContentKind::Mention,
+        ));
+        #[cfg(feature = "unstable_table")]
+        cases.push((
+            ContentBlock::Table(Table::new(vec!["name".to_string()], vec![])),
+            ContentKind::Table,
+        ));
+        #[cfg(feature = "unstable_json_content")]
+        cases.push((
+            ContentBlock::Json(JsonContent::new(serde_json::json!({ "ok": true }))),
+            ContentKind::Json,
+        ));
+
+        for (block, kind) in cases {
+            assert_eq!(block.kind(), kind, "{block:?}");
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_valid_blocks() {
+        #[cfg_attr(
+            not(any(
+                feature = "unstable_tool_result_ref",
+                feature = "unstable_mention",
+                feature = "unstable_table",
+                feature = "unstable_json_content"
+            )),
+            allow(unused_mut)
+        )]
+        let mut blocks = vec![
+            ContentBlock::from(""),
+            ContentBlock::Image(ImageContent::new("iVBORw0KGgo=", "image/png")),
+            ContentBlock::Image(
+                ImageContent::new("", "image/png").uri("https://example.com/a.png"),
+            ),
+            ContentBlock::Audio(AudioContent::new("UklGRg==", "audio/wav")),
+            ContentBlock::ResourceLink(ResourceLink::new("main.rs", "file:///src/main.rs")),
+            ContentBlock::Resource(EmbeddedResource::new(
+                EmbeddedResourceResource::TextResourceContents(TextResourceContents::new(
+                    "fn main() {}\n",
+                    "file:///src/main.rs",
+                )),
+            )),
+            ContentBlock::Resource(EmbeddedResource::new(
+                EmbeddedResourceResource::BlobResourceContents(BlobResourceContents::new(
+                    "iVBORw0KGgo=",
+                    "file:///logo.png",
+                )),
+            )),
+            ContentBlock::Other(OtherContentBlock::new("_custom", BTreeMap::new())),
+        ];
+        #[cfg(feature = "unstable_tool_result_ref")]
+        blocks.push(ContentBlock::ToolResultRef(ToolResultRef::new("call_001")));
+        #[cfg(feature = "unstable_mention")]
+        blocks.push(ContentBlock::Mention(Mention::new(
+            MentionKind::File,
+            "main.rs",
+            "file:///src/main.rs",
+        )));
+        #[cfg(feature = "unstable_table")]
+        blocks.push(ContentBlock::Table(Table::new(
+            vec!["name".to_string(), "rows".to_string()],
+            vec![vec!["users".to_string(), "3".to_string()]],
+        )));
+        #[cfg(feature = "unstable_json_content")]
+        blocks.push(ContentBlock::Json(JsonContent::new(
+            serde_json::Value::Null,
+        )));
+        #[cfg(feature = "unstable_json_content")]
+        blocks.push(ContentBlock::Json(
+            JsonContent::new(serde_json::json!([1, 2])).schema(serde_json::json!(true)),
+        ));
+
+        for block in blocks {
+            assert_eq!(block.validate(), Ok(()), "{block:?}");
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_blocks() {
+        #[cfg_attr(
+            not(any(
+                feature = "unstable_mention",
+                feature = "unstable_table",
+                feature = "unstable_json_content"
+            )),
+            allow(unused_mut)
+        )]
+        let mut cases = vec![
+            (
+                ContentBlock::Image(ImageContent::new("", "image/png")),
+                "image has neither `data` nor `uri`",
+            ),
+            (
+                ContentBlock::Image(ImageContent::new("", "image/png").uri("")),
+                "image has neither `data` nor `uri`",
+            ),
+            (
+                ContentBlock::Audio(AudioContent::new("", "audio/wav")),
+                "audio `data` is empty",
+            ),
+            (
+                ContentBlock::ResourceLink(ResourceLink::new("main.rs", "")),
+                "resource link `uri` is empty",
+            ),
+            (
+                ContentBlock::Resource(EmbeddedResource::new(
+                    EmbeddedResourceResource::TextResourceContents(TextResourceContents::new(
+                        "fn main() {}\n",
+                        "",
+                    )),
+                )),
+                "embedded resource `uri` is empty",
+            ),
+            (
+                ContentBlock::Resource(EmbeddedResource::new(
+                    EmbeddedResourceResource::BlobResourceContents(BlobResourceContents::new(
+                        "iVBORw0KGgo=",
+                        "",
+                    )),
+                )),
+                "embedded resource `uri` is empty",
+            ),
+        ];
+        #[cfg(feature = "unstable_mention")]
+        cases.push((
+            ContentBlock::Mention(Mention::new(MentionKind::File, "main.rs", "")),
+            "mention `uri` is empty",
+        ));
+        #[cfg(feature = "unstable_table")]
+        cases.push((
+            ContentBlock::Table(Table::new(
+                vec!["name".to_string(), "rows".to_string()],
+                vec![
+                    vec!["users".to_string(), "3".to_string()],
+                    vec!["orders".to_string()],
+                ],
+            )),
+            "table row 1 does not have one cell per column",
+        ));
+        #[cfg(feature = "unstable_json_content")]
+        cases.push((
+            ContentBlock::Json(
+                JsonContent::new(serde_json::json!({})).schema(serde_json::json!("object")),
+            ),
+            "json `schema` is neither an object nor a boolean",
+        ));
+
+        for (block, message) in cases {
+            let error = block.validate().unwrap_err();
+            assert_eq!(error.kind(), block.kind());
+            assert_eq!(error.message(), message);
+            assert_eq!(error.to_string(), message);
+        }
+    }
+
+    #[cfg(feature = "unstable_content_truncation")]
+    #[test]
+    fn test_text_content_truncate_chars() {
+        let mut text = TextContent::new("héllo wörld");
+        assert!(!text.truncate_chars(11));
+        assert_eq!(text.text, "héllo wörld");
+        assert_eq!(text.annotations, None);
+
+        // The cut lands right after the multibyte `ö`.
+        assert!(text.truncate_chars(8));
+        assert_eq!(text.text, "héllo wö…");
+        assert_eq!(text.annotations.as_ref().unwrap().truncated, Some(true));
+
+        let mut text = TextContent::new("日本語").annotations(Annotations::new().priority(0.5));
+        assert!(text.truncate_chars(0));
+        assert_eq!(text.text, "…");
+        assert_eq!(
+            serde_json::to_value(&text).unwrap(),
+            serde_json::json!({
+                "text": "…",
+                "annotations": {"priority": 0.5, "truncated": true}
+            })
+        );
+    }
+
+    #[cfg(feature = "unstable_tool_result_ref")]
+    #[test]
+    fn test_tool_result_ref_serialization() {
+        let block = ContentBlock::ToolResultRef(ToolResultRef::new("call_001"));
+        let json = serde_json::to_value(&block).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "tool_result_ref",
+                "toolCallId": "call_001"
+            })
+        );
+        assert_eq!(serde_json::from_value::<ContentBlock>(json).unwrap(), block);
+    }
+
+    #[cfg(feature = "unstable_mention")]
+    #[test]
+    fn test_mention_serialization() {
+        for (kind, wire, label, uri) in [
+            (
+                MentionKind::File,
+                "file",
+                "main.rs",
+                "file:///project/src/main.rs",
+            ),
+            (
+                MentionKind::Symbol,
+                "symbol",
+                "parse_args",
+                "file:///project/src/cli.rs#L12",
+            ),
+            (MentionKind::Url, "url", "docs", "https://example.com/docs"),
+        ] {
+            let block = ContentBlock::Mention(Mention::new(kind, label, uri));
+            let json = serde_json::to_value(&block).unwrap();
+            assert_eq!(
+                json,
+                serde_json::json!({
+                    "type": "mention",
+                    "kind": wire,
+                    "label": label,
+                    "uri": uri
+                })
+            );
+            assert_eq!(serde_json::from_value::<ContentBlock>(json).unwrap(), block);
+        }
+    }
+
+    #[cfg(feature = "unstable_table")]
+    #[test]
+    fn test_table_serialization() {
+        let table = Table::from(vec![
+            vec!["name".to_string(), "size".to_string()],
+            vec!["Cargo.toml".to_string(), "1.2 KB".to_string()],
+            vec!["src".to_string(), String::new()],
+        ]);
+        assert_eq!(table.columns, ["name", "size"]);
+        assert_eq!(table.rows.len(), 2);
+
+        let block = ContentBlock::Table(table);
+        let json = serde_json::to_value(&block).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "table",
+                "columns": ["name", "size"],
+                "rows": [["Cargo.toml", "1.2 KB"], ["src", ""]]
+            })
+        );
+        assert_eq!(serde_json::from_value::<ContentBlock>(json).unwrap(), block);
+
+        let empty = ContentBlock::Table(Table::from(Vec::new()));
+        let json = serde_json::to_value(&empty).unwrap();
+        assert_eq!(json, serde_json::json!({ "type": "table", "columns": [] }));
+        assert_eq!(serde_json::from_value::<ContentBlock>(json).unwrap(), empty);
+    }
+
+    #[cfg(feature = "unstable_table")]
+    #[test]
+    fn test_table_to_markdown() {
+        let table = Table::new(
+            vec!["query".to_string(), "rows".to_string()],
+            vec![vec!["a | b".to_string(), "line 1\nline 2".to_string()]],
+        );
+        assert_eq!(
+            table.to_markdown(),
+            "| query | rows |\n| --- | --- |\n| a \\| b | line 1 line 2 |\n"
+        );
+
+        // Escaping backslashes keeps a cell holding `\|` from ending early.
+        let table = Table::new(
+            vec!["path".to_string()],
+            vec![vec!["C:\\".to_string()], vec!["a\\|b".to_string()]],
+        );
+        assert_eq!(
+            table.to_markdown(),
+            "| path |\n| --- |\n| C:\\\\ |\n| a\\\\\\|b |\n"
+        );
+
+        assert_eq!(Table::new(Vec::new(), Vec::new()).to_markdown(), "");
+        assert_eq!(Table::from(Vec::new()).to_markdown(), "");
+    }
+
+    #[cfg(feature = "unstable_json_content")]
+    #[test]
+    fn test_json_content_serialization() {
+        let value = serde_json::json!({
+            "count": 2,
+            "ratio": 0.5,
+            "items": [{ "id": "a", "tags": [] }, null],
+            "nested": { "empty": {}, "flag": false, "text": "line 1\nline 2" }
+        });
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": { "count": { "type": "integer" } }
+        });
+
+        let block = ContentBlock::Json(JsonContent::new(value.clone()).schema(schema.clone()));
+        let json = serde_json::to_value(&block).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "type": "json", "value": value, "schema": schema })
+        );
+        assert_eq!(serde_json::from_value::<ContentBlock>(json).unwrap(), block);
+
+        for value in [
+            serde_json::Value::Null,
+            serde_json::json!("text"),
+            serde_json::json!([1, "two", [3]]),
+        ] {
+            let block = ContentBlock::Json(JsonContent::new(value.clone()));
+            let json = serde_json::to_value(&block).unwrap();
+            assert_eq!(json, serde_json::json!({ "type": "json", "value": value }));
+            assert_eq!(serde_json::from_value::<ContentBlock>(json).unwrap(), block);
+        }
+    }
+
+    #[cfg(feature = "unstable_json_content")]
+    #[test]
+    fn test_json_content_to_text() {
+        let content = JsonContent::new(serde_json::json!({ "ok": true, "items": [1] }))
+            .schema(serde_json::json!({ "type": "object" }));
+        assert_eq!(
+            content.to_text(),
+            "{\n  \"ok\": true,\n  \"items\": [\n    1\n  ]\n}"
+        );
+    }
+
+    #[test]
+    fn test_content_block_to_markdown() {
+        assert_eq!(ContentBlock::from("**bold**").to_markdown(), "**bold**");
+        assert_eq!(
+            ContentBlock::Image(
+                ImageContent::new("", "image/png").uri("https://example.com/a.png".to_string())
+            )
+            .to_markdown(),
+            "![image](https://example.com/a.png)"
+        );
+        assert_eq!(
+            ContentBlock::Image(ImageContent::new("iVBORw0KGgo=", "image/png")).to_markdown(),
+            "[image: image/png]"
+        );
+        assert_eq!(
+            ContentBlock::Audio(AudioContent::new("UklGRg==", "audio/wav")).to_markdown(),
+            "[audio: audio/wav]"
+        );
+        assert_eq!(
+            ContentBlock::ResourceLink(ResourceLink::new("main.rs", "file:///src/main.rs"))
+                .to_markdown(),
+            "[main.rs](file:///src/main.rs)"
+        );
+        assert_eq!(
+            ContentBlock::ResourceLink(
+                ResourceLink::new("main.rs", "file:///src/main.rs")
+                    .title("Entry point".to_string())
+            )
+            .to_markdown(),
+            "[Entry point](file:///src/main.rs)"
+        );
+        assert_eq!(
+            ContentBlock::Resource(EmbeddedResource::new(
+                EmbeddedResourceResource::BlobResourceContents(BlobResourceContents::new(
+                    "UklGRg==",
+                    "file:///voice.wav"
+                )),
+            ))
+            .to_markdown(),
+            "[file:///voice.wav](file:///voice.wav)"
+        );
+        assert_eq!(
+            ContentBlock::ResourceLink(
+                ResourceLink::new("notes", "file:///My Notes/a (1).md")
+                    .title("[draft] notes\nv2".to_string())
+            )
+            .to_markdown(),
+            r"[\[draft\] notes v2](file:///My%20Notes/a%20\(1\).md)"
+        );
+        assert_eq!(
+            ContentBlock::Image(
+                ImageContent::new("", "image/png").uri("https://example.com/a b).png".to_string())
+            )
+            .to_markdown(),
+            r"![image](https://example.com/a%20b\).png)"
+        );
+        let other: ContentBlock =
+            serde_json::from_value(serde_json::json!({ "type": "_chart", "points": [] })).unwrap();
+        assert_eq!(other.to_markdown(), "[_chart content]");
+    }
+
+    #[test]
+    fn test_text_resource_to_markdown_fences_contents() {
+        let resource = |text: &str| {
+            ContentBlock::Resource(EmbeddedResource::new(
+                EmbeddedResourceResource::TextResourceContents(TextResourceContents::new(
+                    text,
+                    "file:///src/main.rs",
+                )),
+            ))
+        };
+        assert_eq!(
+            resource("fn main() {}\n").to_markdown(),
+            "```file:///src/main.rs\nfn main() {}\n```"
+        );
+        assert_eq!(
+            resource("```rust\nfn main() {}\n```").to_markdown(),
+            "````file:///src/main.rs\n```rust\nfn main() {}\n```\n````"
+        );
+    }
+
+    #[cfg(all(
+        feature = "unstable_tool_result_ref",
+        feature = "unstable_mention",
+        feature = "unstable_table",
+        feature = "unstable_json_content"
+    ))]
+    #[test]
+    fn test_unstable_content_block_to_markdown() {
+        assert_eq!(
+            ContentBlock::ToolResultRef(ToolResultRef::new("call_001")).to_markdown(),
+            "[tool result: call_001]"
+        );
+        assert_eq!(
+            ContentBlock::Mention(Mention::new(
+                MentionKind::File,
+                "main.rs",
+                "file:///src/main.rs"
+            ))
+            .to_markdown(),
+            "[main.rs](file:///src/main.rs)"
+        );
+        assert_eq!(
+            ContentBlock::Table(Table::new(
+                vec!["name".into()],
+                vec![vec!["main.rs".into()]]
+            ))
+            .to_markdown(),
+            "| name |\n| --- |\n| main.rs |\n"
+        );
+        assert_eq!(
+            ContentBlock::Json(JsonContent::new(serde_json::json!({ "ok": true }))).to_markdown(),
+            "```json\n{\n  \"ok\": true\n}\n```"
+        );
+    }
 }
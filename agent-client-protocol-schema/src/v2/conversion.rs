@@ -590,6 +590,24 @@ impl IntoV2 for crate::v1::SessionId {
     }
 }
 
+#[cfg(feature = "unstable_turn_id")]
+impl IntoV1 for super::TurnId {
+    type Output = crate::v1::TurnId;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        Ok(crate::v1::TurnId(self.0.into_v1()?))
+    }
+}
+
+#[cfg(feature = "unstable_turn_id")]
+impl IntoV2 for crate::v1::TurnId {
+    type Output = super::TurnId;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        Ok(super::TurnId(self.0.into_v2()?))
+    }
+}
+
 impl IntoV1 for super::MessageId {
     type Output = crate::v1::MessageId;
 
@@ -830,6 +848,65 @@ impl IntoV2 for crate::v1::PlanRemoved {
     }
 }
 
+#[cfg(feature = "unstable_plan_entry_update")]
+impl IntoV1 for super::PlanEntryUpdate {
+    type Output = crate::v1::PlanEntryUpdate;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            plan_id,
+            index,
+            status,
+            content,
+            meta,
+        } = self;
+        // Without plan operations, v1 only knows the session's single plan.
+        #[cfg(not(feature = "unstable_plan_operations"))]
+        if &*plan_id.0 != LEGACY_V1_PLAN_ID {
+            return Err(ProtocolConversionError::new(format!(
+                "v2 PlanEntryUpdate for plan `{plan_id}` cannot be represented in v1 without plan operations"
+            )));
+        }
+        Ok(crate::v1::PlanEntryUpdate {
+            #[cfg(feature = "unstable_plan_operations")]
+            plan_id: Some(plan_id.into_v1()?),
+            index,
+            status: status.into_v1()?,
+            content,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_plan_entry_update")]
+impl IntoV2 for crate::v1::PlanEntryUpdate {
+    type Output = super::PlanEntryUpdate;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            #[cfg(feature = "unstable_plan_operations")]
+            plan_id,
+            index,
+            status,
+            content,
+            meta,
+        } = self;
+        #[cfg(feature = "unstable_plan_operations")]
+        let plan_id = plan_id
+            .into_v2()?
+            .unwrap_or_else(|| super::PlanId::new(LEGACY_V1_PLAN_ID));
+        #[cfg(not(feature = "unstable_plan_operations"))]
+        let plan_id = super::PlanId::new(LEGACY_V1_PLAN_ID);
+        Ok(super::PlanEntryUpdate {
+            plan_id,
+            index,
+            status: status.into_v2()?,
+            content,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
 impl IntoV1 for super::PlanEntry {
     type Output = crate::v1::PlanEntry;
 
@@ -924,9 +1001,16 @@ impl IntoV1 for super::CancelRequestNotification {
     type Output = crate::v1::CancelRequestNotification;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { request_id, meta } = self;
+        let Self {
+            request_id,
+            #[cfg(feature = "unstable_cancel_reason")]
+            reason,
+            meta,
+        } = self;
         Ok(crate::v1::CancelRequestNotification {
             request_id: request_id.into_v1()?,
+            #[cfg(feature = "unstable_cancel_reason")]
+            reason: into_v1_default_on_error(reason),
             meta: meta.into_v1()?,
         })
     }
@@ -936,14 +1020,50 @@ impl IntoV2 for crate::v1::CancelRequestNotification {
     type Output = super::CancelRequestNotification;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { request_id, meta } = self;
+        let Self {
+            request_id,
+            #[cfg(feature = "unstable_cancel_reason")]
+            reason,
+            meta,
+        } = self;
         Ok(super::CancelRequestNotification {
             request_id: request_id.into_v2()?,
+            #[cfg(feature = "unstable_cancel_reason")]
+            reason: into_v2_default_on_error(reason),
             meta: meta.into_v2()?,
         })
     }
 }
 
+#[cfg(feature = "unstable_cancel_reason")]
+impl IntoV1 for super::CancelReason {
+    type Output = crate::v1::CancelReason;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        Ok(match self {
+            Self::UserRequested => crate::v1::CancelReason::UserRequested,
+            Self::Superseded => crate::v1::CancelReason::Superseded,
+            Self::Shutdown => crate::v1::CancelReason::Shutdown,
+            Self::Timeout => crate::v1::CancelReason::Timeout,
+            Self::Other(value) => return Err(unknown_v2_enum_variant("CancelReason", &value)),
+        })
+    }
+}
+
+#[cfg(feature = "unstable_cancel_reason")]
+impl IntoV2 for crate::v1::CancelReason {
+    type Output = super::CancelReason;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        Ok(match self {
+            Self::UserRequested => super::CancelReason::UserRequested,
+            Self::Superseded => super::CancelReason::Superseded,
+            Self::Shutdown => super::CancelReason::Shutdown,
+            Self::Timeout => super::CancelReason::Timeout,
+        })
+    }
+}
+
 impl IntoV1 for super::ProtocolLevelNotification {
     type Output = crate::v1::ProtocolLevelNotification;
 
@@ -975,17 +1095,36 @@ impl IntoV1Many for super::UpdateSessionNotification {
         let Self {
             session_id,
             update,
+            #[cfg(feature = "unstable_session_update_sequence")]
+            sequence,
+            #[cfg(feature = "unstable_turn_id")]
+            turn_id,
             meta,
         } = self;
         let session_id = session_id.into_v1()?;
         let meta = meta.into_v1()?;
-        update
-            .into_v1_many()?
+        let updates = update.into_v1_many()?;
+        // A sequence number identifies exactly one notification. Dropping it
+        // would leave a gap that stalls a v1 client's reorderer, and renumbering
+        // would shift every later notification, so the fan-out is rejected.
+        #[cfg(feature = "unstable_session_update_sequence")]
+        if sequence.is_some() && updates.len() > 1 {
+            return Err(ProtocolConversionError::new(
+                "v2 session/update with a sequence number fans out to several v1 notifications",
+            ));
+        }
+        #[cfg(feature = "unstable_turn_id")]
+        let turn_id = turn_id.into_v1()?;
+        updates
             .into_iter()
             .map(|update| {
                 Ok(crate::v1::SessionNotification {
                     session_id: session_id.clone(),
                     update,
+                    #[cfg(feature = "unstable_session_update_sequence")]
+                    sequence,
+                    #[cfg(feature = "unstable_turn_id")]
+                    turn_id: turn_id.clone(),
                     meta: meta.clone(),
                 })
             })
@@ -1000,11 +1139,19 @@ impl IntoV2 for crate::v1::SessionNotification {
         let Self {
             session_id,
             update,
+            #[cfg(feature = "unstable_session_update_sequence")]
+            sequence,
+            #[cfg(feature = "unstable_turn_id")]
+            turn_id,
             meta,
         } = self;
         Ok(super::UpdateSessionNotification {
             session_id: session_id.into_v2()?,
             update: update.into_v2()?,
+            #[cfg(feature = "unstable_session_update_sequence")]
+            sequence,
+            #[cfg(feature = "unstable_turn_id")]
+            turn_id: turn_id.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
@@ -1013,6 +1160,8 @@ impl IntoV2 for crate::v1::SessionNotification {
 impl IntoV1Many for super::SessionUpdate {
     type Output = crate::v1::SessionUpdate;
 
+    // One arm per variant, so the length grows with the enabled features.
+    #[allow(clippy::too_many_lines)]
     fn into_v1_many(self) -> Result<Vec<Self::Output>> {
         Ok(match self {
             Self::UserMessageChunk(value) => {
@@ -1062,6 +1211,12 @@ impl IntoV1Many for super::SessionUpdate {
             Self::ToolCallUpdate(value) => {
                 vec![crate::v1::SessionUpdate::ToolCallUpdate(value.into_v1()?)]
             }
+            #[cfg(feature = "unstable_tool_call_input_delta")]
+            Self::ToolCallInputDelta(value) => {
+                vec![crate::v1::SessionUpdate::ToolCallInputDelta(
+                    value.into_v1()?,
+                )]
+            }
             #[cfg(feature = "unstable_plan_operations")]
             Self::PlanUpdate(value) => vec![crate::v1::SessionUpdate::PlanUpdate(value.into_v1()?)],
             #[cfg(not(feature = "unstable_plan_operations"))]
@@ -1070,6 +1225,10 @@ impl IntoV1Many for super::SessionUpdate {
             Self::PlanRemoved(value) => {
                 vec![crate::v1::SessionUpdate::PlanRemoved(value.into_v1()?)]
             }
+            #[cfg(feature = "unstable_plan_entry_update")]
+            Self::PlanEntryUpdate(value) => {
+                vec![crate::v1::SessionUpdate::PlanEntryUpdate(value.into_v1()?)]
+            }
             Self::AvailableCommandsUpdate(value) => {
                 vec![crate::v1::SessionUpdate::AvailableCommandsUpdate(
                     value.into_v1()?,
@@ -1088,6 +1247,24 @@ impl IntoV1Many for super::SessionUpdate {
             Self::UsageUpdate(value) => {
                 vec![crate::v1::SessionUpdate::UsageUpdate(value.into_v1()?)]
             }
+            #[cfg(feature = "unstable_session_notice")]
+            Self::Notice(value) => vec![crate::v1::SessionUpdate::Notice(value.into_v1()?)],
+            #[cfg(feature = "unstable_tool_calls_cancelled")]
+            Self::ToolCallsCancelled(value) => {
+                vec![crate::v1::SessionUpdate::ToolCallsCancelled(
+                    value.into_v1()?,
+                )]
+            }
+            #[cfg(feature = "unstable_attachment")]
+            Self::Attachment(value) => vec![crate::v1::SessionUpdate::Attachment(value.into_v1()?)],
+            #[cfg(feature = "unstable_input_request")]
+            Self::InputRequest(value) => {
+                vec![crate::v1::SessionUpdate::InputRequest(value.into_v1()?)]
+            }
+            #[cfg(feature = "unstable_suggested_prompts")]
+            Self::SuggestedPrompts(value) => {
+                vec![crate::v1::SessionUpdate::SuggestedPrompts(value.into_v1()?)]
+            }
             Self::Other(value) => {
                 return Err(unknown_v2_enum_variant(
                     "SessionUpdate",
@@ -1140,6 +1317,8 @@ fn v2_message_update_into_v1_chunks(
             Ok(wrap(crate::v1::ContentChunk {
                 content: content.into_v1()?,
                 message_id: Some(message_id.clone()),
+                #[cfg(feature = "unstable_user_message_echo")]
+                echo: false,
                 meta: meta.clone(),
             }))
         })
@@ -1162,6 +1341,10 @@ impl IntoV2 for crate::v1::SessionUpdate {
             }
             Self::ToolCall(value) => super::SessionUpdate::ToolCallUpdate(value.into_v2()?),
             Self::ToolCallUpdate(value) => super::SessionUpdate::ToolCallUpdate(value.into_v2()?),
+            #[cfg(feature = "unstable_tool_call_input_delta")]
+            Self::ToolCallInputDelta(value) => {
+                super::SessionUpdate::ToolCallInputDelta(value.into_v2()?)
+            }
             Self::Plan(value) => {
                 let crate::v1::Plan { entries, meta } = value;
                 super::SessionUpdate::PlanUpdate(super::PlanUpdate {
@@ -1173,6 +1356,8 @@ impl IntoV2 for crate::v1::SessionUpdate {
             Self::PlanUpdate(value) => super::SessionUpdate::PlanUpdate(value.into_v2()?),
             #[cfg(feature = "unstable_plan_operations")]
             Self::PlanRemoved(value) => super::SessionUpdate::PlanRemoved(value.into_v2()?),
+            #[cfg(feature = "unstable_plan_entry_update")]
+            Self::PlanEntryUpdate(value) => super::SessionUpdate::PlanEntryUpdate(value.into_v2()?),
             Self::AvailableCommandsUpdate(value) => {
                 super::SessionUpdate::AvailableCommandsUpdate(value.into_v2()?)
             }
@@ -1189,6 +1374,20 @@ impl IntoV2 for crate::v1::SessionUpdate {
                 super::SessionUpdate::SessionInfoUpdate(value.into_v2()?)
             }
             Self::UsageUpdate(value) => super::SessionUpdate::UsageUpdate(value.into_v2()?),
+            #[cfg(feature = "unstable_session_notice")]
+            Self::Notice(value) => super::SessionUpdate::Notice(value.into_v2()?),
+            #[cfg(feature = "unstable_tool_calls_cancelled")]
+            Self::ToolCallsCancelled(value) => {
+                super::SessionUpdate::ToolCallsCancelled(value.into_v2()?)
+            }
+            #[cfg(feature = "unstable_attachment")]
+            Self::Attachment(value) => super::SessionUpdate::Attachment(value.into_v2()?),
+            #[cfg(feature = "unstable_input_request")]
+            Self::InputRequest(value) => super::SessionUpdate::InputRequest(value.into_v2()?),
+            #[cfg(feature = "unstable_suggested_prompts")]
+            Self::SuggestedPrompts(value) => {
+                super::SessionUpdate::SuggestedPrompts(value.into_v2()?)
+            }
         })
     }
 }
@@ -1295,6 +1494,191 @@ impl IntoV2 for crate::v1::UsageUpdate {
     }
 }
 
+#[cfg(feature = "unstable_session_notice")]
+impl IntoV1 for super::Notice {
+    type Output = crate::v1::Notice;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { text, level, meta } = self;
+        Ok(crate::v1::Notice {
+            text: text.into_v1()?,
+            level: level.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_session_notice")]
+impl IntoV2 for crate::v1::Notice {
+    type Output = super::Notice;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { text, level, meta } = self;
+        Ok(super::Notice {
+            text: text.into_v2()?,
+            level: level.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_session_notice")]
+impl IntoV1 for super::NoticeLevel {
+    type Output = crate::v1::NoticeLevel;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        Ok(match self {
+            Self::Info => crate::v1::NoticeLevel::Info,
+            Self::Warning => crate::v1::NoticeLevel::Warning,
+            Self::Other(value) => {
+                return Err(unknown_v2_enum_variant("NoticeLevel", &value));
+            }
+        })
+    }
+}
+
+#[cfg(feature = "unstable_session_notice")]
+impl IntoV2 for crate::v1::NoticeLevel {
+    type Output = super::NoticeLevel;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        Ok(match self {
+            Self::Info => super::NoticeLevel::Info,
+            Self::Warning => super::NoticeLevel::Warning,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_tool_calls_cancelled")]
+impl IntoV1 for super::ToolCallsCancelled {
+    type Output = crate::v1::ToolCallsCancelled;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            tool_call_ids,
+            meta,
+        } = self;
+        Ok(crate::v1::ToolCallsCancelled {
+            tool_call_ids: tool_call_ids.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_tool_calls_cancelled")]
+impl IntoV2 for crate::v1::ToolCallsCancelled {
+    type Output = super::ToolCallsCancelled;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            tool_call_ids,
+            meta,
+        } = self;
+        Ok(super::ToolCallsCancelled {
+            tool_call_ids: tool_call_ids.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_input_request")]
+impl IntoV1 for super::InputRequest {
+    type Output = crate::v1::InputRequest;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            input_id,
+            prompt,
+            meta,
+        } = self;
+        Ok(crate::v1::InputRequest {
+            input_id: input_id.into_v1()?,
+            prompt: prompt.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_input_request")]
+impl IntoV2 for crate::v1::InputRequest {
+    type Output = super::InputRequest;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            input_id,
+            prompt,
+            meta,
+        } = self;
+        Ok(super::InputRequest {
+            input_id: input_id.into_v2()?,
+            prompt: prompt.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_suggested_prompts")]
+impl IntoV1 for super::SuggestedPrompts {
+    type Output = crate::v1::SuggestedPrompts;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { prompts, meta } = self;
+        Ok(crate::v1::SuggestedPrompts {
+            prompts: prompts.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_suggested_prompts")]
+impl IntoV2 for crate::v1::SuggestedPrompts {
+    type Output = super::SuggestedPrompts;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { prompts, meta } = self;
+        Ok(super::SuggestedPrompts {
+            prompts: prompts.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_attachment")]
+impl IntoV1 for super::Attachment {
+    type Output = crate::v1::Attachment;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            name,
+            content,
+            meta,
+        } = self;
+        Ok(crate::v1::Attachment {
+            name: name.into_v1()?,
+            content: content.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_attachment")]
+impl IntoV2 for crate::v1::Attachment {
+    type Output = super::Attachment;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            name,
+            content,
+            meta,
+        } = self;
+        Ok(super::Attachment {
+            name: name.into_v2()?,
+            content: content.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
 impl IntoV1 for super::Cost {
     type Output = crate::v1::Cost;
 
@@ -1336,11 +1720,15 @@ impl IntoV1 for super::ContentChunk {
         let Self {
             content,
             message_id,
+            #[cfg(feature = "unstable_user_message_echo")]
+            echo,
             meta,
         } = self;
         Ok(crate::v1::ContentChunk {
             content: content.into_v1()?,
             message_id: Some(message_id.into_v1()?),
+            #[cfg(feature = "unstable_user_message_echo")]
+            echo,
             meta: meta.into_v1()?,
         })
     }
@@ -1353,10 +1741,14 @@ impl IntoV2 for crate::v1::ContentChunk {
         let Self {
             content,
             message_id,
+            #[cfg(feature = "unstable_user_message_echo")]
+            echo,
             meta,
         } = self;
         Ok(super::ContentChunk {
             content: content.into_v2()?,
+            #[cfg(feature = "unstable_user_message_echo")]
+            echo,
             message_id: message_id
                 .ok_or_else(|| {
                     ProtocolConversionError::new(
@@ -1666,9 +2058,16 @@ impl IntoV1 for super::SelectedPermissionOutcome {
     type Output = crate::v1::SelectedPermissionOutcome;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { option_id, meta } = self;
+        let Self {
+            option_id,
+            #[cfg(feature = "unstable_diff_hunks")]
+            hunk_decisions,
+            meta,
+        } = self;
         Ok(crate::v1::SelectedPermissionOutcome {
             option_id: option_id.into_v1()?,
+            #[cfg(feature = "unstable_diff_hunks")]
+            hunk_decisions: hunk_decisions.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
@@ -1678,9 +2077,16 @@ impl IntoV2 for crate::v1::SelectedPermissionOutcome {
     type Output = super::SelectedPermissionOutcome;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { option_id, meta } = self;
+        let Self {
+            option_id,
+            #[cfg(feature = "unstable_diff_hunks")]
+            hunk_decisions,
+            meta,
+        } = self;
         Ok(super::SelectedPermissionOutcome {
             option_id: option_id.into_v2()?,
+            #[cfg(feature = "unstable_diff_hunks")]
+            hunk_decisions: hunk_decisions.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
@@ -1909,10 +2315,20 @@ impl IntoV1 for super::ClientCapabilities {
             auth,
             #[cfg(feature = "unstable_elicitation")]
             elicitation,
+            #[cfg(feature = "unstable_sampling")]
+            sampling,
             #[cfg(feature = "unstable_nes")]
             nes,
             #[cfg(feature = "unstable_nes")]
             position_encodings,
+            #[cfg(feature = "unstable_table")]
+            table,
+            #[cfg(feature = "unstable_json_content")]
+            json_content,
+            #[cfg(feature = "unstable_permission_inline_tool_call")]
+            permission,
+            #[cfg(feature = "unstable_input_request")]
+            input_request,
             meta,
         } = self;
         Ok(crate::v1::ClientCapabilities {
@@ -1931,10 +2347,24 @@ impl IntoV1 for super::ClientCapabilities {
             auth: auth.map(IntoV1::into_v1).transpose()?.unwrap_or_default(),
             #[cfg(feature = "unstable_elicitation")]
             elicitation: into_v1_default_on_error(elicitation),
+            #[cfg(feature = "unstable_sampling")]
+            sampling: into_v1_default_on_error(sampling),
             #[cfg(feature = "unstable_nes")]
             nes: into_v1_default_on_error(nes),
             #[cfg(feature = "unstable_nes")]
             position_encodings: into_v1_vec_skip_errors(position_encodings),
+            #[cfg(feature = "unstable_table")]
+            table: into_v1_default_on_error(table),
+            #[cfg(feature = "unstable_json_content")]
+            json_content: into_v1_default_on_error(json_content),
+            #[cfg(feature = "unstable_permission_inline_tool_call")]
+            permission: into_v1_default_on_error(permission),
+            #[cfg(feature = "unstable_input_request")]
+            input_request: into_v1_default_on_error(input_request),
+            #[cfg(feature = "unstable_clipboard")]
+            clipboard: None,
+            #[cfg(feature = "unstable_env")]
+            env: None,
             meta: meta.into_v1()?,
         })
     }
@@ -1951,14 +2381,28 @@ impl IntoV2 for crate::v1::ClientCapabilities {
                 session: _,
             #[cfg(feature = "unstable_plan_operations")]
                 plan: _,
+            #[cfg(feature = "unstable_clipboard")]
+                clipboard: _,
+            #[cfg(feature = "unstable_env")]
+                env: _,
             #[cfg(feature = "unstable_auth_methods")]
             auth,
             #[cfg(feature = "unstable_elicitation")]
             elicitation,
+            #[cfg(feature = "unstable_sampling")]
+            sampling,
             #[cfg(feature = "unstable_nes")]
             nes,
             #[cfg(feature = "unstable_nes")]
             position_encodings,
+            #[cfg(feature = "unstable_table")]
+            table,
+            #[cfg(feature = "unstable_json_content")]
+            json_content,
+            #[cfg(feature = "unstable_permission_inline_tool_call")]
+            permission,
+            #[cfg(feature = "unstable_input_request")]
+            input_request,
             meta,
         } = self;
         Ok(super::ClientCapabilities {
@@ -1966,10 +2410,77 @@ impl IntoV2 for crate::v1::ClientCapabilities {
             auth: Some(auth.into_v2()?),
             #[cfg(feature = "unstable_elicitation")]
             elicitation: into_v2_default_on_error(elicitation),
+            #[cfg(feature = "unstable_sampling")]
+            sampling: into_v2_default_on_error(sampling),
             #[cfg(feature = "unstable_nes")]
             nes: into_v2_default_on_error(nes),
             #[cfg(feature = "unstable_nes")]
             position_encodings: into_v2_vec_skip_errors(position_encodings),
+            #[cfg(feature = "unstable_table")]
+            table: into_v2_default_on_error(table),
+            #[cfg(feature = "unstable_json_content")]
+            json_content: into_v2_default_on_error(json_content),
+            #[cfg(feature = "unstable_permission_inline_tool_call")]
+            permission: into_v2_default_on_error(permission),
+            #[cfg(feature = "unstable_input_request")]
+            input_request: into_v2_default_on_error(input_request),
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_input_request")]
+impl IntoV1 for super::InputRequestCapabilities {
+    type Output = crate::v1::InputRequestCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(crate::v1::InputRequestCapabilities {
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_input_request")]
+impl IntoV2 for crate::v1::InputRequestCapabilities {
+    type Output = super::InputRequestCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(super::InputRequestCapabilities {
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_permission_inline_tool_call")]
+impl IntoV1 for super::PermissionCapabilities {
+    type Output = crate::v1::PermissionCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            inline_tool_call,
+            meta,
+        } = self;
+        Ok(crate::v1::PermissionCapabilities {
+            inline_tool_call: inline_tool_call.is_some(),
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_permission_inline_tool_call")]
+impl IntoV2 for crate::v1::PermissionCapabilities {
+    type Output = super::PermissionCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            inline_tool_call,
+            meta,
+        } = self;
+        Ok(super::PermissionCapabilities {
+            inline_tool_call: inline_tool_call
+                .then(super::PermissionInlineToolCallCapabilities::new),
             meta: meta.into_v2()?,
         })
     }
@@ -2025,6 +2536,10 @@ impl IntoV1 for super::AgentRequest {
             Self::DisconnectMcpRequest(value) => {
                 crate::v1::AgentRequest::DisconnectMcpRequest(value.into_v1()?)
             }
+            #[cfg(feature = "unstable_sampling")]
+            Self::CreateMessageRequest(value) => {
+                crate::v1::AgentRequest::CreateMessageRequest(value.into_v1()?)
+            }
             Self::ExtMethodRequest(value) => {
                 crate::v1::AgentRequest::ExtMethodRequest(value.into_v1()?)
             }
@@ -2083,11 +2598,31 @@ impl IntoV2 for crate::v1::AgentRequest {
             Self::DisconnectMcpRequest(value) => {
                 super::AgentRequest::DisconnectMcpRequest(Box::new(value.into_v2()?))
             }
-            Self::ExtMethodRequest(value) => {
-                super::AgentRequest::ExtMethodRequest(Box::new(value.into_v2()?))
+            #[cfg(feature = "unstable_sampling")]
+            Self::CreateMessageRequest(value) => {
+                super::AgentRequest::CreateMessageRequest(Box::new(value.into_v2()?))
             }
-        })
-    }
+            #[cfg(feature = "unstable_fs_watch")]
+            Self::WatchPathsRequest(_) => {
+                return Err(removed_v1_enum_variant("AgentRequest", "fs/watch"));
+            }
+            #[cfg(feature = "unstable_clipboard")]
+            Self::ReadClipboardRequest(_) => {
+                return Err(removed_v1_enum_variant("AgentRequest", "clipboard/read"));
+            }
+            #[cfg(feature = "unstable_clipboard")]
+            Self::WriteClipboardRequest(_) => {
+                return Err(removed_v1_enum_variant("AgentRequest", "clipboard/write"));
+            }
+            #[cfg(feature = "unstable_env")]
+            Self::GetEnvRequest(_) => {
+                return Err(removed_v1_enum_variant("AgentRequest", "env/get"));
+            }
+            Self::ExtMethodRequest(value) => {
+                super::AgentRequest::ExtMethodRequest(Box::new(value.into_v2()?))
+            }
+        })
+    }
 }
 
 impl IntoV1 for super::ClientResponse {
@@ -2114,6 +2649,10 @@ impl IntoV1 for super::ClientResponse {
             Self::DisconnectMcpResponse(value) => {
                 crate::v1::ClientResponse::DisconnectMcpResponse(value.into_v1()?)
             }
+            #[cfg(feature = "unstable_sampling")]
+            Self::CreateMessageResponse(value) => {
+                crate::v1::ClientResponse::CreateMessageResponse(Box::new(value.into_v1()?))
+            }
             Self::ExtMethodResponse(value) => {
                 crate::v1::ClientResponse::ExtMethodResponse(value.into_v1()?)
             }
@@ -2178,6 +2717,26 @@ impl IntoV2 for crate::v1::ClientResponse {
             Self::DisconnectMcpResponse(value) => {
                 super::ClientResponse::DisconnectMcpResponse(Box::new(value.into_v2()?))
             }
+            #[cfg(feature = "unstable_sampling")]
+            Self::CreateMessageResponse(value) => {
+                super::ClientResponse::CreateMessageResponse(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_fs_watch")]
+            Self::WatchPathsResponse(_) => {
+                return Err(removed_v1_enum_variant("ClientResponse", "fs/watch"));
+            }
+            #[cfg(feature = "unstable_clipboard")]
+            Self::ReadClipboardResponse(_) => {
+                return Err(removed_v1_enum_variant("ClientResponse", "clipboard/read"));
+            }
+            #[cfg(feature = "unstable_clipboard")]
+            Self::WriteClipboardResponse(_) => {
+                return Err(removed_v1_enum_variant("ClientResponse", "clipboard/write"));
+            }
+            #[cfg(feature = "unstable_env")]
+            Self::GetEnvResponse(_) => {
+                return Err(removed_v1_enum_variant("ClientResponse", "env/get"));
+            }
             Self::ExtMethodResponse(value) => {
                 super::ClientResponse::ExtMethodResponse(Box::new(value.into_v2()?))
             }
@@ -2209,6 +2768,12 @@ impl IntoV1Many for super::AgentNotification {
                     value.into_v1()?,
                 )]
             }
+            #[cfg(feature = "unstable_agent_ready")]
+            Self::AgentReadyNotification(value) => {
+                vec![crate::v1::AgentNotification::AgentReadyNotification(
+                    value.into_v1()?,
+                )]
+            }
             Self::ExtNotification(value) => {
                 vec![crate::v1::AgentNotification::ExtNotification(
                     value.into_v1()?,
@@ -2236,6 +2801,10 @@ impl IntoV2 for crate::v1::AgentNotification {
             Self::MessageMcpNotification(value) => {
                 super::AgentNotification::MessageMcpNotification(Box::new(value.into_v2()?))
             }
+            #[cfg(feature = "unstable_agent_ready")]
+            Self::AgentReadyNotification(value) => {
+                super::AgentNotification::AgentReadyNotification(Box::new(value.into_v2()?))
+            }
             Self::ExtNotification(value) => {
                 super::AgentNotification::ExtNotification(Box::new(value.into_v2()?))
             }
@@ -2423,23 +2992,58 @@ impl IntoV1 for super::ToolCallUpdate {
             tool_call_id,
             title,
             kind,
+            #[cfg(feature = "unstable_tool_kind_label")]
+            custom_kind,
+            #[cfg(feature = "unstable_tool_call_group")]
+            group_id,
+            #[cfg(feature = "unstable_tool_call_parent")]
+            parent_id,
             status,
             content,
             locations,
             raw_input,
             raw_output,
+            #[cfg(feature = "unstable_raw_output_patch")]
+            raw_output_patch,
+            // v1 updates only carry `completedAt`, so the start time is dropped.
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+                created_at: _,
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            completed_at,
+            #[cfg(feature = "unstable_tool_call_error")]
+            error,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            input_schema,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            output_schema,
             meta,
         } = self;
         Ok(crate::v1::ToolCallUpdate {
             tool_call_id: tool_call_id.into_v1()?,
             fields: crate::v1::ToolCallUpdateFields {
                 kind: maybe_undefined_value_into_v1_option(kind),
+                #[cfg(feature = "unstable_tool_kind_label")]
+                custom_kind: maybe_undefined_value_into_v1_option(custom_kind),
+                #[cfg(feature = "unstable_tool_call_group")]
+                group_id: maybe_undefined_value_into_v1_option(group_id),
+                #[cfg(feature = "unstable_tool_call_parent")]
+                parent_id: maybe_undefined_value_into_v1_option(parent_id),
                 status: maybe_undefined_value_into_v1_option(status),
                 title: maybe_undefined_value_into_v1_option(title),
                 content: maybe_undefined_vec_into_v1_option(content),
                 locations: maybe_undefined_vec_into_v1_option(locations),
                 raw_input: maybe_undefined_value_into_v1_option(raw_input),
                 raw_output: maybe_undefined_value_into_v1_option(raw_output),
+                #[cfg(feature = "unstable_raw_output_patch")]
+                raw_output_patch: maybe_undefined_value_into_v1_option(raw_output_patch),
+                #[cfg(feature = "unstable_tool_call_timestamps")]
+                completed_at: maybe_undefined_value_into_v1_option(completed_at),
+                #[cfg(feature = "unstable_tool_call_error")]
+                error: maybe_undefined_value_into_v1_option(error),
+                #[cfg(feature = "unstable_tool_call_schema")]
+                input_schema: maybe_undefined_value_into_v1_option(input_schema),
+                #[cfg(feature = "unstable_tool_call_schema")]
+                output_schema: maybe_undefined_value_into_v1_option(output_schema),
             },
             meta: meta.into_v1()?,
         })
@@ -2454,11 +3058,27 @@ impl IntoV2 for crate::v1::ToolCall {
             tool_call_id,
             title,
             kind,
+            #[cfg(feature = "unstable_tool_kind_label")]
+            custom_kind,
+            #[cfg(feature = "unstable_tool_call_group")]
+            group_id,
+            #[cfg(feature = "unstable_tool_call_parent")]
+            parent_id,
             status,
             content,
             locations,
             raw_input,
             raw_output,
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            created_at,
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            completed_at,
+            #[cfg(feature = "unstable_tool_call_error")]
+            error,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            input_schema,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            output_schema,
             meta,
         } = self;
         Ok(super::ToolCallUpdate {
@@ -2469,6 +3089,12 @@ impl IntoV2 for crate::v1::ToolCall {
             } else {
                 crate::MaybeUndefined::Value(kind.into_v2()?)
             },
+            #[cfg(feature = "unstable_tool_kind_label")]
+            custom_kind: option_into_v2_maybe_undefined(custom_kind)?,
+            #[cfg(feature = "unstable_tool_call_group")]
+            group_id: option_into_v2_maybe_undefined(group_id)?,
+            #[cfg(feature = "unstable_tool_call_parent")]
+            parent_id: option_into_v2_maybe_undefined(parent_id)?,
             status: if matches!(status, crate::v1::ToolCallStatus::Pending) {
                 crate::MaybeUndefined::Undefined
             } else {
@@ -2478,6 +3104,18 @@ impl IntoV2 for crate::v1::ToolCall {
             locations: vec_into_v2_maybe_undefined_skip_errors(locations),
             raw_input: option_into_v2_maybe_undefined(raw_input)?,
             raw_output: option_into_v2_maybe_undefined(raw_output)?,
+            #[cfg(feature = "unstable_raw_output_patch")]
+            raw_output_patch: crate::MaybeUndefined::Undefined,
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            created_at: option_into_v2_maybe_undefined(created_at)?,
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            completed_at: option_into_v2_maybe_undefined(completed_at)?,
+            #[cfg(feature = "unstable_tool_call_error")]
+            error: option_into_v2_maybe_undefined(error)?,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            input_schema: option_into_v2_maybe_undefined(input_schema)?,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            output_schema: option_into_v2_maybe_undefined(output_schema)?,
             meta: meta.into_v2()?,
         })
     }
@@ -2494,22 +3132,92 @@ impl IntoV2 for crate::v1::ToolCallUpdate {
         } = self;
         let crate::v1::ToolCallUpdateFields {
             kind,
+            #[cfg(feature = "unstable_tool_kind_label")]
+            custom_kind,
+            #[cfg(feature = "unstable_tool_call_group")]
+            group_id,
+            #[cfg(feature = "unstable_tool_call_parent")]
+            parent_id,
             status,
             title,
             content,
             locations,
             raw_input,
             raw_output,
+            #[cfg(feature = "unstable_raw_output_patch")]
+            raw_output_patch,
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            completed_at,
+            #[cfg(feature = "unstable_tool_call_error")]
+            error,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            input_schema,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            output_schema,
         } = fields;
         Ok(super::ToolCallUpdate {
             tool_call_id: tool_call_id.into_v2()?,
             kind: option_into_v2_maybe_undefined(kind)?,
+            #[cfg(feature = "unstable_tool_kind_label")]
+            custom_kind: option_into_v2_maybe_undefined(custom_kind)?,
+            #[cfg(feature = "unstable_tool_call_group")]
+            group_id: option_into_v2_maybe_undefined(group_id)?,
+            #[cfg(feature = "unstable_tool_call_parent")]
+            parent_id: option_into_v2_maybe_undefined(parent_id)?,
             status: option_into_v2_maybe_undefined(status)?,
             title: option_into_v2_maybe_undefined(title)?,
             content: option_vec_into_v2_maybe_undefined_skip_errors(content),
             locations: option_vec_into_v2_maybe_undefined_skip_errors(locations),
             raw_input: option_into_v2_maybe_undefined(raw_input)?,
             raw_output: option_into_v2_maybe_undefined(raw_output)?,
+            #[cfg(feature = "unstable_raw_output_patch")]
+            raw_output_patch: option_into_v2_maybe_undefined(raw_output_patch)?,
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            created_at: crate::MaybeUndefined::Undefined,
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            completed_at: option_into_v2_maybe_undefined(completed_at)?,
+            #[cfg(feature = "unstable_tool_call_error")]
+            error: option_into_v2_maybe_undefined(error)?,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            input_schema: option_into_v2_maybe_undefined(input_schema)?,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            output_schema: option_into_v2_maybe_undefined(output_schema)?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_tool_call_input_delta")]
+impl IntoV1 for super::ToolCallInputDelta {
+    type Output = crate::v1::ToolCallInputDelta;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            tool_call_id,
+            json_fragment,
+            meta,
+        } = self;
+        Ok(crate::v1::ToolCallInputDelta {
+            tool_call_id: tool_call_id.into_v1()?,
+            json_fragment: json_fragment.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_tool_call_input_delta")]
+impl IntoV2 for crate::v1::ToolCallInputDelta {
+    type Output = super::ToolCallInputDelta;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            tool_call_id,
+            json_fragment,
+            meta,
+        } = self;
+        Ok(super::ToolCallInputDelta {
+            tool_call_id: tool_call_id.into_v2()?,
+            json_fragment: json_fragment.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
@@ -2579,6 +3287,10 @@ impl IntoV1 for super::ToolCallStatus {
             Self::InProgress => crate::v1::ToolCallStatus::InProgress,
             Self::Completed => crate::v1::ToolCallStatus::Completed,
             Self::Failed => crate::v1::ToolCallStatus::Failed,
+            #[cfg(feature = "unstable_tool_call_cancelled")]
+            Self::Cancelled => crate::v1::ToolCallStatus::Cancelled,
+            #[cfg(feature = "unstable_tool_call_awaiting_confirmation")]
+            Self::AwaitingConfirmation => crate::v1::ToolCallStatus::AwaitingConfirmation,
             Self::Other(value) => return Err(unknown_v2_enum_variant("ToolCallStatus", &value)),
         })
     }
@@ -2593,6 +3305,10 @@ impl IntoV2 for crate::v1::ToolCallStatus {
             Self::InProgress => super::ToolCallStatus::InProgress,
             Self::Completed => super::ToolCallStatus::Completed,
             Self::Failed => super::ToolCallStatus::Failed,
+            #[cfg(feature = "unstable_tool_call_cancelled")]
+            Self::Cancelled => super::ToolCallStatus::Cancelled,
+            #[cfg(feature = "unstable_tool_call_awaiting_confirmation")]
+            Self::AwaitingConfirmation => super::ToolCallStatus::AwaitingConfirmation,
         })
     }
 }
@@ -2657,12 +3373,16 @@ impl IntoV1 for super::Diff {
             path,
             old_text,
             new_text,
+            #[cfg(feature = "unstable_diff_hunks")]
+            hunks,
             meta,
         } = self;
         Ok(crate::v1::Diff {
             path: path.into_v1()?,
             old_text: old_text.into_v1()?,
             new_text: new_text.into_v1()?,
+            #[cfg(feature = "unstable_diff_hunks")]
+            hunks: hunks.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
@@ -2676,12 +3396,168 @@ impl IntoV2 for crate::v1::Diff {
             path,
             old_text,
             new_text,
+            #[cfg(feature = "unstable_diff_hunks")]
+            hunks,
             meta,
         } = self;
         Ok(super::Diff {
             path: path.into_v2()?,
             old_text: old_text.into_v2()?,
             new_text: new_text.into_v2()?,
+            #[cfg(feature = "unstable_diff_hunks")]
+            hunks: hunks.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_edit_conflict")]
+impl IntoV1 for super::ConflictReport {
+    type Output = crate::v1::ConflictReport;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            path,
+            expected,
+            actual,
+            meta,
+        } = self;
+        Ok(crate::v1::ConflictReport {
+            path: path.into_v1()?,
+            expected: expected.into_v1()?,
+            actual: actual.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_edit_conflict")]
+impl IntoV2 for crate::v1::ConflictReport {
+    type Output = super::ConflictReport;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            path,
+            expected,
+            actual,
+            meta,
+        } = self;
+        Ok(super::ConflictReport {
+            path: path.into_v2()?,
+            expected: expected.into_v2()?,
+            actual: actual.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_diff_hunks")]
+impl IntoV1 for super::DiffHunk {
+    type Output = crate::v1::DiffHunk;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            id,
+            old_range,
+            new_range,
+            accepted,
+            meta,
+        } = self;
+        Ok(crate::v1::DiffHunk {
+            id: id.into_v1()?,
+            old_range: old_range.into_v1()?,
+            new_range: new_range.into_v1()?,
+            accepted: accepted.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_diff_hunks")]
+impl IntoV2 for crate::v1::DiffHunk {
+    type Output = super::DiffHunk;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            id,
+            old_range,
+            new_range,
+            accepted,
+            meta,
+        } = self;
+        Ok(super::DiffHunk {
+            id: id.into_v2()?,
+            old_range: old_range.into_v2()?,
+            new_range: new_range.into_v2()?,
+            accepted: accepted.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_diff_hunks")]
+impl IntoV1 for super::DiffLineRange {
+    type Output = crate::v1::DiffLineRange;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { start, count, meta } = self;
+        Ok(crate::v1::DiffLineRange {
+            start: start.into_v1()?,
+            count: count.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_diff_hunks")]
+impl IntoV2 for crate::v1::DiffLineRange {
+    type Output = super::DiffLineRange;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { start, count, meta } = self;
+        Ok(super::DiffLineRange {
+            start: start.into_v2()?,
+            count: count.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_diff_hunks")]
+impl IntoV1 for super::DiffHunkDecision {
+    type Output = crate::v1::DiffHunkDecision;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            path,
+            hunk_id,
+            accepted,
+            meta,
+        } = self;
+        Ok(crate::v1::DiffHunkDecision {
+            path: path.into_v1()?,
+            hunk_id: hunk_id.into_v1()?,
+            accepted: accepted.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_diff_hunks")]
+impl IntoV2 for crate::v1::DiffHunkDecision {
+    type Output = super::DiffHunkDecision;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            path,
+            hunk_id,
+            accepted,
+            meta,
+        } = self;
+        Ok(super::DiffHunkDecision {
+            path: path.into_v2()?,
+            hunk_id: hunk_id.into_v2()?,
+            accepted: accepted.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
@@ -2713,6 +3589,42 @@ impl IntoV2 for crate::v1::ToolCallLocation {
     }
 }
 
+#[cfg(feature = "unstable_tool_call_error")]
+impl IntoV1 for super::ToolCallError {
+    type Output = crate::v1::ToolCallError;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            message,
+            detail,
+            meta,
+        } = self;
+        Ok(crate::v1::ToolCallError {
+            message: message.into_v1()?,
+            detail: detail.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_tool_call_error")]
+impl IntoV2 for crate::v1::ToolCallError {
+    type Output = super::ToolCallError;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            message,
+            detail,
+            meta,
+        } = self;
+        Ok(super::ToolCallError {
+            message: message.into_v2()?,
+            detail: detail.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
 impl IntoV1 for super::InitializeRequest {
     type Output = crate::v1::InitializeRequest;
 
@@ -2721,12 +3633,16 @@ impl IntoV1 for super::InitializeRequest {
             protocol_version,
             capabilities,
             info,
+            #[cfg(feature = "unstable_locale")]
+            locale,
             meta,
         } = self;
         Ok(crate::v1::InitializeRequest {
             protocol_version: protocol_version.into_v1()?,
             client_capabilities: capabilities.into_v1()?,
             client_info: Some(info.into_v1()?),
+            #[cfg(feature = "unstable_locale")]
+            locale: locale.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
@@ -2740,6 +3656,8 @@ impl IntoV2 for crate::v1::InitializeRequest {
             protocol_version,
             client_capabilities,
             client_info,
+            #[cfg(feature = "unstable_locale")]
+            locale,
             meta,
         } = self;
         let info = match client_info {
@@ -2754,6 +3672,8 @@ impl IntoV2 for crate::v1::InitializeRequest {
             protocol_version: protocol_version.into_v2()?,
             capabilities: client_capabilities.into_v2()?,
             info,
+            #[cfg(feature = "unstable_locale")]
+            locale: locale.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
@@ -2768,6 +3688,8 @@ impl IntoV1 for super::InitializeResponse {
             capabilities: agent_capabilities,
             auth_methods,
             info,
+            #[cfg(feature = "unstable_agent_ready")]
+            ready,
             meta,
         } = self;
         Ok(crate::v1::InitializeResponse {
@@ -2775,6 +3697,8 @@ impl IntoV1 for super::InitializeResponse {
             agent_capabilities: agent_capabilities.into_v1()?,
             auth_methods: into_v1_vec_skip_errors(auth_methods),
             agent_info: Some(info.into_v1()?),
+            #[cfg(feature = "unstable_agent_ready")]
+            ready,
             meta: meta.into_v1()?,
         })
     }
@@ -2789,6 +3713,8 @@ impl IntoV2 for crate::v1::InitializeResponse {
             agent_capabilities,
             auth_methods,
             agent_info,
+            #[cfg(feature = "unstable_agent_ready")]
+            ready,
             meta,
         } = self;
         let info = match agent_info {
@@ -2804,6 +3730,8 @@ impl IntoV2 for crate::v1::InitializeResponse {
             capabilities: agent_capabilities.into_v2()?,
             auth_methods: into_v2_vec_skip_errors(auth_methods),
             info,
+            #[cfg(feature = "unstable_agent_ready")]
+            ready,
             meta: meta.into_v2()?,
         })
     }
@@ -3218,12 +4146,16 @@ impl IntoV1 for super::NewSessionRequest {
             cwd,
             additional_directories,
             mcp_servers,
+            #[cfg(feature = "unstable_session_context")]
+            context,
             meta,
         } = self;
         Ok(crate::v1::NewSessionRequest {
             cwd: cwd.into_v1()?,
             additional_directories: additional_directories.into_v1()?,
             mcp_servers: mcp_servers.into_v1()?,
+            #[cfg(feature = "unstable_session_context")]
+            context: context.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
@@ -3237,12 +4169,64 @@ impl IntoV2 for crate::v1::NewSessionRequest {
             cwd,
             additional_directories,
             mcp_servers,
+            #[cfg(feature = "unstable_session_context")]
+            context,
             meta,
         } = self;
         Ok(super::NewSessionRequest {
             cwd: cwd.into_v2()?,
             additional_directories: additional_directories.into_v2()?,
             mcp_servers: mcp_servers.into_v2()?,
+            #[cfg(feature = "unstable_session_context")]
+            context: context.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_session_context")]
+impl IntoV1 for super::SessionContext {
+    type Output = crate::v1::SessionContext;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            os,
+            shell,
+            env_allowlist,
+            #[cfg(feature = "unstable_locale")]
+            locale,
+            meta,
+        } = self;
+        Ok(crate::v1::SessionContext {
+            os: os.into_v1()?,
+            shell: shell.into_v1()?,
+            env_allowlist: env_allowlist.into_v1()?,
+            #[cfg(feature = "unstable_locale")]
+            locale: locale.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_session_context")]
+impl IntoV2 for crate::v1::SessionContext {
+    type Output = super::SessionContext;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            os,
+            shell,
+            env_allowlist,
+            #[cfg(feature = "unstable_locale")]
+            locale,
+            meta,
+        } = self;
+        Ok(super::SessionContext {
+            os: os.into_v2()?,
+            shell: shell.into_v2()?,
+            env_allowlist: env_allowlist.into_v2()?,
+            #[cfg(feature = "unstable_locale")]
+            locale: locale.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
@@ -3293,6 +4277,10 @@ impl IntoV1 for super::LoadSessionRequest {
             cwd,
             additional_directories,
             session_id,
+            #[cfg(feature = "unstable_session_load_pagination")]
+            cursor,
+            #[cfg(feature = "unstable_session_load_pagination")]
+            limit,
             meta,
         } = self;
         Ok(crate::v1::LoadSessionRequest {
@@ -3300,6 +4288,10 @@ impl IntoV1 for super::LoadSessionRequest {
             cwd: cwd.into_v1()?,
             additional_directories: additional_directories.into_v1()?,
             session_id: session_id.into_v1()?,
+            #[cfg(feature = "unstable_session_load_pagination")]
+            cursor: cursor.into_v1()?,
+            #[cfg(feature = "unstable_session_load_pagination")]
+            limit: limit.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
@@ -3314,6 +4306,10 @@ impl IntoV2 for crate::v1::LoadSessionRequest {
             cwd,
             additional_directories,
             session_id,
+            #[cfg(feature = "unstable_session_load_pagination")]
+            cursor,
+            #[cfg(feature = "unstable_session_load_pagination")]
+            limit,
             meta,
         } = self;
         Ok(super::LoadSessionRequest {
@@ -3321,6 +4317,10 @@ impl IntoV2 for crate::v1::LoadSessionRequest {
             cwd: cwd.into_v2()?,
             additional_directories: additional_directories.into_v2()?,
             session_id: session_id.into_v2()?,
+            #[cfg(feature = "unstable_session_load_pagination")]
+            cursor: cursor.into_v2()?,
+            #[cfg(feature = "unstable_session_load_pagination")]
+            limit: limit.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
@@ -3332,11 +4332,15 @@ impl IntoV1 for super::LoadSessionResponse {
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
             config_options,
+            #[cfg(feature = "unstable_session_load_pagination")]
+            next_cursor,
             meta,
         } = self;
         Ok(crate::v1::LoadSessionResponse {
             modes: None,
             config_options: Some(into_v1_vec_skip_errors(config_options)),
+            #[cfg(feature = "unstable_session_load_pagination")]
+            next_cursor: next_cursor.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
@@ -3349,10 +4353,14 @@ impl IntoV2 for crate::v1::LoadSessionResponse {
         let Self {
             modes: _,
             config_options,
+            #[cfg(feature = "unstable_session_load_pagination")]
+            next_cursor,
             meta,
         } = self;
         Ok(super::LoadSessionResponse {
             config_options: option_vec_into_v2_default_skip_errors(config_options),
+            #[cfg(feature = "unstable_session_load_pagination")]
+            next_cursor: next_cursor.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
@@ -3560,5166 +4568,6388 @@ impl IntoV2 for crate::v1::CloseSessionResponse {
     }
 }
 
-impl IntoV1 for super::DeleteSessionRequest {
-    type Output = crate::v1::DeleteSessionRequest;
+#[cfg(feature = "unstable_session_compact")]
+impl IntoV1 for super::CompactSessionRequest {
+    type Output = crate::v1::CompactSessionRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { session_id, meta } = self;
-        Ok(crate::v1::DeleteSessionRequest {
+        let Self {
+            session_id,
+            target_tokens,
+            meta,
+        } = self;
+        Ok(crate::v1::CompactSessionRequest {
             session_id: session_id.into_v1()?,
+            target_tokens: target_tokens.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::DeleteSessionRequest {
-    type Output = super::DeleteSessionRequest;
+#[cfg(feature = "unstable_session_compact")]
+impl IntoV2 for crate::v1::CompactSessionRequest {
+    type Output = super::CompactSessionRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { session_id, meta } = self;
-        Ok(super::DeleteSessionRequest {
+        let Self {
+            session_id,
+            target_tokens,
+            meta,
+        } = self;
+        Ok(super::CompactSessionRequest {
             session_id: session_id.into_v2()?,
+            target_tokens: target_tokens.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::DeleteSessionResponse {
-    type Output = crate::v1::DeleteSessionResponse;
+#[cfg(feature = "unstable_session_compact")]
+impl IntoV1 for super::CompactSessionResponse {
+    type Output = crate::v1::CompactSessionResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::DeleteSessionResponse {
+        let Self {
+            removed_messages,
+            summary,
+            meta,
+        } = self;
+        Ok(crate::v1::CompactSessionResponse {
+            removed_messages: removed_messages.into_v1()?,
+            summary: summary.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::DeleteSessionResponse {
-    type Output = super::DeleteSessionResponse;
+#[cfg(feature = "unstable_session_compact")]
+impl IntoV2 for crate::v1::CompactSessionResponse {
+    type Output = super::CompactSessionResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::DeleteSessionResponse {
+        let Self {
+            removed_messages,
+            summary,
+            meta,
+        } = self;
+        Ok(super::CompactSessionResponse {
+            removed_messages: removed_messages.into_v2()?,
+            summary: summary.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::ListSessionsRequest {
-    type Output = crate::v1::ListSessionsRequest;
+#[cfg(feature = "unstable_session_compact")]
+impl IntoV1 for super::SessionCompactCapabilities {
+    type Output = crate::v1::SessionCompactCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { cwd, cursor, meta } = self;
-        Ok(crate::v1::ListSessionsRequest {
-            cwd: cwd.into_v1()?,
-            cursor: cursor.into_v1()?,
+        let Self { meta } = self;
+        Ok(crate::v1::SessionCompactCapabilities {
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::ListSessionsRequest {
-    type Output = super::ListSessionsRequest;
+#[cfg(feature = "unstable_session_compact")]
+impl IntoV2 for crate::v1::SessionCompactCapabilities {
+    type Output = super::SessionCompactCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { cwd, cursor, meta } = self;
-        Ok(super::ListSessionsRequest {
-            cwd: cwd.into_v2()?,
-            cursor: cursor.into_v2()?,
+        let Self { meta } = self;
+        Ok(super::SessionCompactCapabilities {
             meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::ListSessionsResponse {
-    type Output = crate::v1::ListSessionsResponse;
+#[cfg(feature = "unstable_session_set_title")]
+impl IntoV1 for super::SetSessionTitleRequest {
+    type Output = crate::v1::SetSessionTitleRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            sessions,
-            next_cursor,
+            session_id,
+            title,
             meta,
         } = self;
-        Ok(crate::v1::ListSessionsResponse {
-            sessions: into_v1_vec_skip_errors(sessions),
-            next_cursor: next_cursor.into_v1()?,
+        Ok(crate::v1::SetSessionTitleRequest {
+            session_id: session_id.into_v1()?,
+            title: title.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::ListSessionsResponse {
-    type Output = super::ListSessionsResponse;
+#[cfg(feature = "unstable_session_set_title")]
+impl IntoV2 for crate::v1::SetSessionTitleRequest {
+    type Output = super::SetSessionTitleRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            sessions,
-            next_cursor,
+            session_id,
+            title,
             meta,
         } = self;
-        Ok(super::ListSessionsResponse {
-            sessions: into_v2_vec_skip_errors(sessions),
-            next_cursor: next_cursor.into_v2()?,
+        Ok(super::SetSessionTitleRequest {
+            session_id: session_id.into_v2()?,
+            title: title.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::SessionInfo {
-    type Output = crate::v1::SessionInfo;
+#[cfg(feature = "unstable_session_set_title")]
+impl IntoV1 for super::SetSessionTitleResponse {
+    type Output = crate::v1::SetSessionTitleResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            session_id,
-            cwd,
-            additional_directories,
-            title,
-            updated_at,
-            meta,
-        } = self;
-        Ok(crate::v1::SessionInfo {
-            session_id: session_id.into_v1()?,
-            cwd: cwd.into_v1()?,
-            additional_directories: additional_directories.into_v1()?,
-            title: into_v1_default_on_error(title),
-            updated_at: into_v1_default_on_error(updated_at),
+        let Self { meta } = self;
+        Ok(crate::v1::SetSessionTitleResponse {
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::SessionInfo {
-    type Output = super::SessionInfo;
+#[cfg(feature = "unstable_session_set_title")]
+impl IntoV2 for crate::v1::SetSessionTitleResponse {
+    type Output = super::SetSessionTitleResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            session_id,
-            cwd,
-            additional_directories,
-            title,
-            updated_at,
-            meta,
-        } = self;
-        Ok(super::SessionInfo {
-            session_id: session_id.into_v2()?,
-            cwd: cwd.into_v2()?,
-            additional_directories: additional_directories.into_v2()?,
-            title: into_v2_default_on_error(title),
-            updated_at: into_v2_default_on_error(updated_at),
+        let Self { meta } = self;
+        Ok(super::SetSessionTitleResponse {
             meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::SessionConfigId {
-    type Output = crate::v1::SessionConfigId;
+#[cfg(feature = "unstable_session_set_title")]
+impl IntoV1 for super::SessionSetTitleCapabilities {
+    type Output = crate::v1::SessionSetTitleCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(crate::v1::SessionConfigId(self.0.into_v1()?))
+        let Self { meta } = self;
+        Ok(crate::v1::SessionSetTitleCapabilities {
+            meta: meta.into_v1()?,
+        })
     }
 }
 
-impl IntoV2 for crate::v1::SessionConfigId {
-    type Output = super::SessionConfigId;
+#[cfg(feature = "unstable_session_set_title")]
+impl IntoV2 for crate::v1::SessionSetTitleCapabilities {
+    type Output = super::SessionSetTitleCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(super::SessionConfigId(self.0.into_v2()?))
+        let Self { meta } = self;
+        Ok(super::SessionSetTitleCapabilities {
+            meta: meta.into_v2()?,
+        })
     }
 }
 
-impl IntoV1 for super::SessionConfigValueId {
-    type Output = crate::v1::SessionConfigValueId;
+#[cfg(feature = "unstable_cancel_tool_call")]
+impl IntoV1 for super::CancelToolCallRequest {
+    type Output = crate::v1::CancelToolCallRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(crate::v1::SessionConfigValueId(self.0.into_v1()?))
+        let Self {
+            session_id,
+            tool_call_id,
+            meta,
+        } = self;
+        Ok(crate::v1::CancelToolCallRequest {
+            session_id: session_id.into_v1()?,
+            tool_call_id: tool_call_id.into_v1()?,
+            meta: meta.into_v1()?,
+        })
     }
 }
 
-impl IntoV2 for crate::v1::SessionConfigValueId {
-    type Output = super::SessionConfigValueId;
+#[cfg(feature = "unstable_cancel_tool_call")]
+impl IntoV2 for crate::v1::CancelToolCallRequest {
+    type Output = super::CancelToolCallRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(super::SessionConfigValueId(self.0.into_v2()?))
+        let Self {
+            session_id,
+            tool_call_id,
+            meta,
+        } = self;
+        Ok(super::CancelToolCallRequest {
+            session_id: session_id.into_v2()?,
+            tool_call_id: tool_call_id.into_v2()?,
+            meta: meta.into_v2()?,
+        })
     }
 }
 
-impl IntoV1 for super::SessionConfigGroupId {
-    type Output = crate::v1::SessionConfigGroupId;
+#[cfg(feature = "unstable_cancel_tool_call")]
+impl IntoV1 for super::CancelToolCallResponse {
+    type Output = crate::v1::CancelToolCallResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(crate::v1::SessionConfigGroupId(self.0.into_v1()?))
+        let Self { meta } = self;
+        Ok(crate::v1::CancelToolCallResponse {
+            meta: meta.into_v1()?,
+        })
     }
 }
 
-impl IntoV2 for crate::v1::SessionConfigGroupId {
-    type Output = super::SessionConfigGroupId;
+#[cfg(feature = "unstable_cancel_tool_call")]
+impl IntoV2 for crate::v1::CancelToolCallResponse {
+    type Output = super::CancelToolCallResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(super::SessionConfigGroupId(self.0.into_v2()?))
+        let Self { meta } = self;
+        Ok(super::CancelToolCallResponse {
+            meta: meta.into_v2()?,
+        })
     }
 }
 
-impl IntoV1 for super::SessionConfigSelectOption {
-    type Output = crate::v1::SessionConfigSelectOption;
+#[cfg(feature = "unstable_cancel_tool_call")]
+impl IntoV1 for super::SessionCancelToolCallCapabilities {
+    type Output = crate::v1::SessionCancelToolCallCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            value,
-            name,
-            description,
-            meta,
-        } = self;
-        Ok(crate::v1::SessionConfigSelectOption {
-            value: value.into_v1()?,
-            name: name.into_v1()?,
-            description: description.into_v1()?,
+        let Self { meta } = self;
+        Ok(crate::v1::SessionCancelToolCallCapabilities {
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::SessionConfigSelectOption {
-    type Output = super::SessionConfigSelectOption;
+#[cfg(feature = "unstable_cancel_tool_call")]
+impl IntoV2 for crate::v1::SessionCancelToolCallCapabilities {
+    type Output = super::SessionCancelToolCallCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            value,
-            name,
-            description,
-            meta,
-        } = self;
-        Ok(super::SessionConfigSelectOption {
-            value: value.into_v2()?,
-            name: name.into_v2()?,
-            description: description.into_v2()?,
+        let Self { meta } = self;
+        Ok(super::SessionCancelToolCallCapabilities {
             meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::SessionConfigSelectGroup {
-    type Output = crate::v1::SessionConfigSelectGroup;
+#[cfg(feature = "unstable_session_load_pagination")]
+impl IntoV1 for super::SessionLoadPaginationCapabilities {
+    type Output = crate::v1::SessionLoadPaginationCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(crate::v1::SessionLoadPaginationCapabilities {
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_session_load_pagination")]
+impl IntoV2 for crate::v1::SessionLoadPaginationCapabilities {
+    type Output = super::SessionLoadPaginationCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(super::SessionLoadPaginationCapabilities {
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_input_request")]
+impl IntoV1 for super::ProvideInputRequest {
+    type Output = crate::v1::ProvideInputRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            group_id,
-            name,
-            options,
+            session_id,
+            input_id,
+            text,
             meta,
         } = self;
-        Ok(crate::v1::SessionConfigSelectGroup {
-            group: group_id.into_v1()?,
-            name: name.into_v1()?,
-            options: options.into_v1()?,
+        Ok(crate::v1::ProvideInputRequest {
+            session_id: session_id.into_v1()?,
+            input_id: input_id.into_v1()?,
+            text: text.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::SessionConfigSelectGroup {
-    type Output = super::SessionConfigSelectGroup;
+#[cfg(feature = "unstable_input_request")]
+impl IntoV2 for crate::v1::ProvideInputRequest {
+    type Output = super::ProvideInputRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            group,
-            name,
-            options,
+            session_id,
+            input_id,
+            text,
             meta,
         } = self;
-        Ok(super::SessionConfigSelectGroup {
-            group_id: group.into_v2()?,
-            name: name.into_v2()?,
-            options: options.into_v2()?,
+        Ok(super::ProvideInputRequest {
+            session_id: session_id.into_v2()?,
+            input_id: input_id.into_v2()?,
+            text: text.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::SessionConfigSelectOptions {
-    type Output = crate::v1::SessionConfigSelectOptions;
+#[cfg(feature = "unstable_input_request")]
+impl IntoV1 for super::ProvideInputResponse {
+    type Output = crate::v1::ProvideInputResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Ungrouped(value) => {
-                crate::v1::SessionConfigSelectOptions::Ungrouped(value.into_v1()?)
-            }
-            Self::Grouped(value) => {
-                crate::v1::SessionConfigSelectOptions::Grouped(value.into_v1()?)
-            }
+        let Self { meta } = self;
+        Ok(crate::v1::ProvideInputResponse {
+            meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::SessionConfigSelectOptions {
-    type Output = super::SessionConfigSelectOptions;
+#[cfg(feature = "unstable_input_request")]
+impl IntoV2 for crate::v1::ProvideInputResponse {
+    type Output = super::ProvideInputResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Ungrouped(value) => {
-                super::SessionConfigSelectOptions::Ungrouped(value.into_v2()?)
-            }
-            Self::Grouped(value) => super::SessionConfigSelectOptions::Grouped(value.into_v2()?),
+        let Self { meta } = self;
+        Ok(super::ProvideInputResponse {
+            meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::SessionConfigSelect {
-    type Output = crate::v1::SessionConfigSelect;
+#[cfg(feature = "unstable_command_invocation")]
+impl IntoV1 for super::InvokeCommandRequest {
+    type Output = crate::v1::InvokeCommandRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            current_value,
-            options,
+            session_id,
+            command,
+            arguments,
+            meta,
         } = self;
-        Ok(crate::v1::SessionConfigSelect {
-            current_value: current_value.into_v1()?,
-            options: options.into_v1()?,
+        Ok(crate::v1::InvokeCommandRequest {
+            session_id: session_id.into_v1()?,
+            command: command.into_v1()?,
+            arguments: arguments.into_v1()?,
+            meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::SessionConfigSelect {
-    type Output = super::SessionConfigSelect;
+#[cfg(feature = "unstable_command_invocation")]
+impl IntoV2 for crate::v1::InvokeCommandRequest {
+    type Output = super::InvokeCommandRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            current_value,
-            options,
+            session_id,
+            command,
+            arguments,
+            meta,
         } = self;
-        Ok(super::SessionConfigSelect {
-            current_value: current_value.into_v2()?,
-            options: options.into_v2()?,
+        Ok(super::InvokeCommandRequest {
+            session_id: session_id.into_v2()?,
+            command: command.into_v2()?,
+            arguments: arguments.into_v2()?,
+            meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_boolean_config")]
-impl IntoV1 for super::SessionConfigBoolean {
-    type Output = crate::v1::SessionConfigBoolean;
+#[cfg(feature = "unstable_command_invocation")]
+impl IntoV1 for super::InvokeCommandResponse {
+    type Output = crate::v1::InvokeCommandResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { current_value } = self;
-        Ok(crate::v1::SessionConfigBoolean {
-            current_value: current_value.into_v1()?,
+        let Self { meta } = self;
+        Ok(crate::v1::InvokeCommandResponse {
+            meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_boolean_config")]
-impl IntoV2 for crate::v1::SessionConfigBoolean {
-    type Output = super::SessionConfigBoolean;
+#[cfg(feature = "unstable_command_invocation")]
+impl IntoV2 for crate::v1::InvokeCommandResponse {
+    type Output = super::InvokeCommandResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { current_value } = self;
-        Ok(super::SessionConfigBoolean {
-            current_value: current_value.into_v2()?,
+        let Self { meta } = self;
+        Ok(super::InvokeCommandResponse {
+            meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::SessionConfigOptionCategory {
-    type Output = crate::v1::SessionConfigOptionCategory;
+impl IntoV1 for super::DeleteSessionRequest {
+    type Output = crate::v1::DeleteSessionRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Mode => crate::v1::SessionConfigOptionCategory::Mode,
-            Self::Model => crate::v1::SessionConfigOptionCategory::Model,
-            Self::ModelConfig => crate::v1::SessionConfigOptionCategory::ModelConfig,
-            Self::ThoughtLevel => crate::v1::SessionConfigOptionCategory::ThoughtLevel,
-            Self::Other(value) => crate::v1::SessionConfigOptionCategory::Other(value.into_v1()?),
+        let Self { session_id, meta } = self;
+        Ok(crate::v1::DeleteSessionRequest {
+            session_id: session_id.into_v1()?,
+            meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::SessionConfigOptionCategory {
-    type Output = super::SessionConfigOptionCategory;
+impl IntoV2 for crate::v1::DeleteSessionRequest {
+    type Output = super::DeleteSessionRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Mode => super::SessionConfigOptionCategory::Mode,
-            Self::Model => super::SessionConfigOptionCategory::Model,
-            Self::ModelConfig => super::SessionConfigOptionCategory::ModelConfig,
-            Self::ThoughtLevel => super::SessionConfigOptionCategory::ThoughtLevel,
-            Self::Other(value) => super::SessionConfigOptionCategory::Other(value.into_v2()?),
+        let Self { session_id, meta } = self;
+        Ok(super::DeleteSessionRequest {
+            session_id: session_id.into_v2()?,
+            meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::SessionConfigKind {
-    type Output = crate::v1::SessionConfigKind;
+impl IntoV1 for super::DeleteSessionResponse {
+    type Output = crate::v1::DeleteSessionResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Select(value) => crate::v1::SessionConfigKind::Select(value.into_v1()?),
-            #[cfg(feature = "unstable_boolean_config")]
-            Self::Boolean(value) => crate::v1::SessionConfigKind::Boolean(value.into_v1()?),
-            Self::Other(value) => {
-                return Err(unknown_v2_enum_variant("SessionConfigKind", &value.type_));
-            }
+        let Self { meta } = self;
+        Ok(crate::v1::DeleteSessionResponse {
+            meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::SessionConfigKind {
-    type Output = super::SessionConfigKind;
+impl IntoV2 for crate::v1::DeleteSessionResponse {
+    type Output = super::DeleteSessionResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Select(value) => super::SessionConfigKind::Select(value.into_v2()?),
-            #[cfg(feature = "unstable_boolean_config")]
-            Self::Boolean(value) => super::SessionConfigKind::Boolean(value.into_v2()?),
+        let Self { meta } = self;
+        Ok(super::DeleteSessionResponse {
+            meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::SessionConfigOption {
-    type Output = crate::v1::SessionConfigOption;
+impl IntoV1 for super::ListSessionsRequest {
+    type Output = crate::v1::ListSessionsRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            config_id,
-            name,
-            description,
-            category,
-            kind,
-            meta,
-        } = self;
-        Ok(crate::v1::SessionConfigOption {
-            id: config_id.into_v1()?,
-            name: name.into_v1()?,
-            description: description.into_v1()?,
-            category: into_v1_default_on_error(category),
-            kind: kind.into_v1()?,
+        let Self { cwd, cursor, meta } = self;
+        Ok(crate::v1::ListSessionsRequest {
+            cwd: cwd.into_v1()?,
+            cursor: cursor.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::SessionConfigOption {
-    type Output = super::SessionConfigOption;
+impl IntoV2 for crate::v1::ListSessionsRequest {
+    type Output = super::ListSessionsRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            id,
-            name,
-            description,
-            category,
-            kind,
-            meta,
-        } = self;
-        Ok(super::SessionConfigOption {
-            config_id: id.into_v2()?,
-            name: name.into_v2()?,
-            description: description.into_v2()?,
-            category: into_v2_default_on_error(category),
-            kind: kind.into_v2()?,
+        let Self { cwd, cursor, meta } = self;
+        Ok(super::ListSessionsRequest {
+            cwd: cwd.into_v2()?,
+            cursor: cursor.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_boolean_config")]
-impl IntoV1 for super::SessionConfigOptionValue {
-    type Output = crate::v1::SessionConfigOptionValue;
+impl IntoV1 for super::ListSessionsResponse {
+    type Output = crate::v1::ListSessionsResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Id { value } => crate::v1::SessionConfigOptionValue::ValueId {
-                value: value.into_v1()?,
-            },
-            Self::Boolean { value } => crate::v1::SessionConfigOptionValue::Boolean {
-                value: value.into_v1()?,
-            },
-            Self::Other(value) => {
-                return Err(unknown_v2_enum_variant(
-                    "SessionConfigOptionValue",
-                    &value.type_,
-                ));
-            }
+        let Self {
+            sessions,
+            next_cursor,
+            meta,
+        } = self;
+        Ok(crate::v1::ListSessionsResponse {
+            sessions: into_v1_vec_skip_errors(sessions),
+            next_cursor: next_cursor.into_v1()?,
+            meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_boolean_config")]
-impl IntoV2 for crate::v1::SessionConfigOptionValue {
-    type Output = super::SessionConfigOptionValue;
+impl IntoV2 for crate::v1::ListSessionsResponse {
+    type Output = super::ListSessionsResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Boolean { value } => super::SessionConfigOptionValue::Boolean {
-                value: value.into_v2()?,
-            },
-            Self::ValueId { value } => super::SessionConfigOptionValue::Id {
-                value: value.into_v2()?,
-            },
+        let Self {
+            sessions,
+            next_cursor,
+            meta,
+        } = self;
+        Ok(super::ListSessionsResponse {
+            sessions: into_v2_vec_skip_errors(sessions),
+            next_cursor: next_cursor.into_v2()?,
+            meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::SetSessionConfigOptionRequest {
-    type Output = crate::v1::SetSessionConfigOptionRequest;
+impl IntoV1 for super::SessionInfo {
+    type Output = crate::v1::SessionInfo;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
             session_id,
-            config_id,
-            value,
+            cwd,
+            additional_directories,
+            title,
+            updated_at,
             meta,
         } = self;
-        Ok(crate::v1::SetSessionConfigOptionRequest {
+        Ok(crate::v1::SessionInfo {
             session_id: session_id.into_v1()?,
-            config_id: config_id.into_v1()?,
-            value: value.into_v1()?,
+            cwd: cwd.into_v1()?,
+            additional_directories: additional_directories.into_v1()?,
+            title: into_v1_default_on_error(title),
+            updated_at: into_v1_default_on_error(updated_at),
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::SetSessionConfigOptionRequest {
-    type Output = super::SetSessionConfigOptionRequest;
+impl IntoV2 for crate::v1::SessionInfo {
+    type Output = super::SessionInfo;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
             session_id,
-            config_id,
-            value,
+            cwd,
+            additional_directories,
+            title,
+            updated_at,
             meta,
         } = self;
-        Ok(super::SetSessionConfigOptionRequest {
+        Ok(super::SessionInfo {
             session_id: session_id.into_v2()?,
-            config_id: config_id.into_v2()?,
-            value: value.into_v2()?,
+            cwd: cwd.into_v2()?,
+            additional_directories: additional_directories.into_v2()?,
+            title: into_v2_default_on_error(title),
+            updated_at: into_v2_default_on_error(updated_at),
             meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::SetSessionConfigOptionResponse {
-    type Output = crate::v1::SetSessionConfigOptionResponse;
+impl IntoV1 for super::SessionConfigId {
+    type Output = crate::v1::SessionConfigId;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            config_options,
-            meta,
-        } = self;
-        Ok(crate::v1::SetSessionConfigOptionResponse {
-            config_options: into_v1_vec_skip_errors(config_options),
-            meta: meta.into_v1()?,
-        })
+        Ok(crate::v1::SessionConfigId(self.0.into_v1()?))
     }
 }
 
-impl IntoV2 for crate::v1::SetSessionConfigOptionResponse {
-    type Output = super::SetSessionConfigOptionResponse;
+impl IntoV2 for crate::v1::SessionConfigId {
+    type Output = super::SessionConfigId;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            config_options,
-            meta,
-        } = self;
-        Ok(super::SetSessionConfigOptionResponse {
-            config_options: into_v2_vec_skip_errors(config_options),
-            meta: meta.into_v2()?,
-        })
+        Ok(super::SessionConfigId(self.0.into_v2()?))
     }
 }
 
-impl IntoV1 for super::McpServer {
-    type Output = crate::v1::McpServer;
+impl IntoV1 for super::SessionConfigValueId {
+    type Output = crate::v1::SessionConfigValueId;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Http(value) => crate::v1::McpServer::Http(value.into_v1()?),
-            #[cfg(feature = "unstable_mcp_over_acp")]
-            Self::Acp(value) => crate::v1::McpServer::Acp(value.into_v1()?),
-            Self::Stdio(value) => crate::v1::McpServer::Stdio(value.into_v1()?),
-            Self::Other(value) => return Err(unknown_v2_enum_variant("McpServer", &value.type_)),
-        })
+        Ok(crate::v1::SessionConfigValueId(self.0.into_v1()?))
     }
 }
 
-impl IntoV2 for crate::v1::McpServer {
-    type Output = super::McpServer;
+impl IntoV2 for crate::v1::SessionConfigValueId {
+    type Output = super::SessionConfigValueId;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Http(value) => super::McpServer::Http(value.into_v2()?),
-            Self::Sse(_) => return Err(removed_v1_enum_variant("McpServer", "sse")),
-            #[cfg(feature = "unstable_mcp_over_acp")]
-            Self::Acp(value) => super::McpServer::Acp(value.into_v2()?),
-            Self::Stdio(value) => super::McpServer::Stdio(value.into_v2()?),
-        })
+        Ok(super::SessionConfigValueId(self.0.into_v2()?))
     }
 }
 
-impl IntoV1 for super::McpServerHttp {
-    type Output = crate::v1::McpServerHttp;
+impl IntoV1 for super::SessionConfigGroupId {
+    type Output = crate::v1::SessionConfigGroupId;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        Ok(crate::v1::SessionConfigGroupId(self.0.into_v1()?))
+    }
+}
+
+impl IntoV2 for crate::v1::SessionConfigGroupId {
+    type Output = super::SessionConfigGroupId;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        Ok(super::SessionConfigGroupId(self.0.into_v2()?))
+    }
+}
+
+impl IntoV1 for super::SessionConfigSelectOption {
+    type Output = crate::v1::SessionConfigSelectOption;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
+            value,
             name,
-            url,
-            headers,
+            description,
             meta,
         } = self;
-        Ok(crate::v1::McpServerHttp {
+        Ok(crate::v1::SessionConfigSelectOption {
+            value: value.into_v1()?,
             name: name.into_v1()?,
-            url: url.into_v1()?,
-            headers: headers.into_v1()?,
+            description: description.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::McpServerHttp {
-    type Output = super::McpServerHttp;
+impl IntoV2 for crate::v1::SessionConfigSelectOption {
+    type Output = super::SessionConfigSelectOption;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
+            value,
             name,
-            url,
-            headers,
+            description,
             meta,
         } = self;
-        Ok(super::McpServerHttp {
+        Ok(super::SessionConfigSelectOption {
+            value: value.into_v2()?,
             name: name.into_v2()?,
-            url: url.into_v2()?,
-            headers: headers.into_v2()?,
+            description: description.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_mcp_over_acp")]
-impl IntoV1 for super::McpServerAcpId {
-    type Output = crate::v1::McpServerAcpId;
+impl IntoV1 for super::SessionConfigSelectGroup {
+    type Output = crate::v1::SessionConfigSelectGroup;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(crate::v1::McpServerAcpId(self.0.into_v1()?))
+        let Self {
+            group_id,
+            name,
+            options,
+            meta,
+        } = self;
+        Ok(crate::v1::SessionConfigSelectGroup {
+            group: group_id.into_v1()?,
+            name: name.into_v1()?,
+            options: options.into_v1()?,
+            meta: meta.into_v1()?,
+        })
     }
 }
 
-#[cfg(feature = "unstable_mcp_over_acp")]
-impl IntoV2 for crate::v1::McpServerAcpId {
-    type Output = super::McpServerAcpId;
+impl IntoV2 for crate::v1::SessionConfigSelectGroup {
+    type Output = super::SessionConfigSelectGroup;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(super::McpServerAcpId(self.0.into_v2()?))
-    }
-}
-
-#[cfg(feature = "unstable_mcp_over_acp")]
-impl IntoV1 for super::McpConnectionId {
-    type Output = crate::v1::McpConnectionId;
-
+        let Self {
+            group,
+            name,
+            options,
+            meta,
+        } = self;
+        Ok(super::SessionConfigSelectGroup {
+            group_id: group.into_v2()?,
+            name: name.into_v2()?,
+            options: options.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+impl IntoV1 for super::SessionConfigSelectOptions {
+    type Output = crate::v1::SessionConfigSelectOptions;
+
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(crate::v1::McpConnectionId(self.0.into_v1()?))
+        Ok(match self {
+            Self::Ungrouped(value) => {
+                crate::v1::SessionConfigSelectOptions::Ungrouped(value.into_v1()?)
+            }
+            Self::Grouped(value) => {
+                crate::v1::SessionConfigSelectOptions::Grouped(value.into_v1()?)
+            }
+        })
     }
 }
 
-#[cfg(feature = "unstable_mcp_over_acp")]
-impl IntoV2 for crate::v1::McpConnectionId {
-    type Output = super::McpConnectionId;
+impl IntoV2 for crate::v1::SessionConfigSelectOptions {
+    type Output = super::SessionConfigSelectOptions;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(super::McpConnectionId(self.0.into_v2()?))
+        Ok(match self {
+            Self::Ungrouped(value) => {
+                super::SessionConfigSelectOptions::Ungrouped(value.into_v2()?)
+            }
+            Self::Grouped(value) => super::SessionConfigSelectOptions::Grouped(value.into_v2()?),
+        })
     }
 }
 
-#[cfg(feature = "unstable_mcp_over_acp")]
-impl IntoV1 for super::McpServerAcp {
-    type Output = crate::v1::McpServerAcp;
+impl IntoV1 for super::SessionConfigSelect {
+    type Output = crate::v1::SessionConfigSelect;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            name,
-            server_id,
-            meta,
+            current_value,
+            options,
         } = self;
-        Ok(crate::v1::McpServerAcp {
-            name: name.into_v1()?,
-            server_id: server_id.into_v1()?,
-            meta: meta.into_v1()?,
+        Ok(crate::v1::SessionConfigSelect {
+            current_value: current_value.into_v1()?,
+            options: options.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_mcp_over_acp")]
-impl IntoV2 for crate::v1::McpServerAcp {
-    type Output = super::McpServerAcp;
+impl IntoV2 for crate::v1::SessionConfigSelect {
+    type Output = super::SessionConfigSelect;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            name,
-            server_id,
-            meta,
+            current_value,
+            options,
         } = self;
-        Ok(super::McpServerAcp {
-            name: name.into_v2()?,
-            server_id: server_id.into_v2()?,
-            meta: meta.into_v2()?,
+        Ok(super::SessionConfigSelect {
+            current_value: current_value.into_v2()?,
+            options: options.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::McpServerStdio {
-    type Output = crate::v1::McpServerStdio;
+#[cfg(feature = "unstable_boolean_config")]
+impl IntoV1 for super::SessionConfigBoolean {
+    type Output = crate::v1::SessionConfigBoolean;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            name,
-            command,
-            args,
-            env,
-            meta,
-        } = self;
-        Ok(crate::v1::McpServerStdio {
-            name: name.into_v1()?,
-            command: command.into_v1()?,
-            args: args.into_v1()?,
-            env: env.into_v1()?,
-            meta: meta.into_v1()?,
+        let Self { current_value } = self;
+        Ok(crate::v1::SessionConfigBoolean {
+            current_value: current_value.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::McpServerStdio {
-    type Output = super::McpServerStdio;
+#[cfg(feature = "unstable_boolean_config")]
+impl IntoV2 for crate::v1::SessionConfigBoolean {
+    type Output = super::SessionConfigBoolean;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            name,
-            command,
-            args,
-            env,
-            meta,
-        } = self;
-        Ok(super::McpServerStdio {
-            name: name.into_v2()?,
-            command: command.into_v2()?,
-            args: args.into_v2()?,
-            env: env.into_v2()?,
-            meta: meta.into_v2()?,
+        let Self { current_value } = self;
+        Ok(super::SessionConfigBoolean {
+            current_value: current_value.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::EnvVariable {
-    type Output = crate::v1::EnvVariable;
+impl IntoV1 for super::SessionConfigOptionCategory {
+    type Output = crate::v1::SessionConfigOptionCategory;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { name, value, meta } = self;
-        Ok(crate::v1::EnvVariable {
-            name: name.into_v1()?,
-            value: value.into_v1()?,
-            meta: meta.into_v1()?,
+        Ok(match self {
+            Self::Mode => crate::v1::SessionConfigOptionCategory::Mode,
+            Self::Model => crate::v1::SessionConfigOptionCategory::Model,
+            Self::ModelConfig => crate::v1::SessionConfigOptionCategory::ModelConfig,
+            Self::ThoughtLevel => crate::v1::SessionConfigOptionCategory::ThoughtLevel,
+            Self::Other(value) => crate::v1::SessionConfigOptionCategory::Other(value.into_v1()?),
         })
     }
 }
 
-impl IntoV2 for crate::v1::EnvVariable {
-    type Output = super::EnvVariable;
+impl IntoV2 for crate::v1::SessionConfigOptionCategory {
+    type Output = super::SessionConfigOptionCategory;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { name, value, meta } = self;
-        Ok(super::EnvVariable {
-            name: name.into_v2()?,
-            value: value.into_v2()?,
-            meta: meta.into_v2()?,
+        Ok(match self {
+            Self::Mode => super::SessionConfigOptionCategory::Mode,
+            Self::Model => super::SessionConfigOptionCategory::Model,
+            Self::ModelConfig => super::SessionConfigOptionCategory::ModelConfig,
+            Self::ThoughtLevel => super::SessionConfigOptionCategory::ThoughtLevel,
+            Self::Other(value) => super::SessionConfigOptionCategory::Other(value.into_v2()?),
         })
     }
 }
 
-impl IntoV1 for super::HttpHeader {
-    type Output = crate::v1::HttpHeader;
+impl IntoV1 for super::SessionConfigKind {
+    type Output = crate::v1::SessionConfigKind;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { name, value, meta } = self;
-        Ok(crate::v1::HttpHeader {
-            name: name.into_v1()?,
-            value: value.into_v1()?,
-            meta: meta.into_v1()?,
+        Ok(match self {
+            Self::Select(value) => crate::v1::SessionConfigKind::Select(value.into_v1()?),
+            #[cfg(feature = "unstable_boolean_config")]
+            Self::Boolean(value) => crate::v1::SessionConfigKind::Boolean(value.into_v1()?),
+            Self::Other(value) => {
+                return Err(unknown_v2_enum_variant("SessionConfigKind", &value.type_));
+            }
         })
     }
 }
 
-impl IntoV2 for crate::v1::HttpHeader {
-    type Output = super::HttpHeader;
+impl IntoV2 for crate::v1::SessionConfigKind {
+    type Output = super::SessionConfigKind;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { name, value, meta } = self;
-        Ok(super::HttpHeader {
-            name: name.into_v2()?,
-            value: value.into_v2()?,
-            meta: meta.into_v2()?,
+        Ok(match self {
+            Self::Select(value) => super::SessionConfigKind::Select(value.into_v2()?),
+            #[cfg(feature = "unstable_boolean_config")]
+            Self::Boolean(value) => super::SessionConfigKind::Boolean(value.into_v2()?),
         })
     }
 }
 
-impl IntoV1 for super::PromptRequest {
-    type Output = crate::v1::PromptRequest;
+impl IntoV1 for super::SessionConfigOption {
+    type Output = crate::v1::SessionConfigOption;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            session_id,
-            prompt,
+            config_id,
+            name,
+            description,
+            category,
+            kind,
             meta,
         } = self;
-        Ok(crate::v1::PromptRequest {
-            session_id: session_id.into_v1()?,
-            prompt: prompt.into_v1()?,
+        Ok(crate::v1::SessionConfigOption {
+            id: config_id.into_v1()?,
+            name: name.into_v1()?,
+            description: description.into_v1()?,
+            category: into_v1_default_on_error(category),
+            kind: kind.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::PromptRequest {
-    type Output = super::PromptRequest;
+impl IntoV2 for crate::v1::SessionConfigOption {
+    type Output = super::SessionConfigOption;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            session_id,
-            prompt,
+            id,
+            name,
+            description,
+            category,
+            kind,
             meta,
         } = self;
-        Ok(super::PromptRequest {
-            session_id: session_id.into_v2()?,
-            prompt: prompt.into_v2()?,
+        Ok(super::SessionConfigOption {
+            config_id: id.into_v2()?,
+            name: name.into_v2()?,
+            description: description.into_v2()?,
+            category: into_v2_default_on_error(category),
+            kind: kind.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::PromptResponse {
-    type Output = crate::v1::PromptResponse;
+#[cfg(feature = "unstable_boolean_config")]
+impl IntoV1 for super::SessionConfigOptionValue {
+    type Output = crate::v1::SessionConfigOptionValue;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Err(ProtocolConversionError::new(
-            "v2 PromptResponse cannot be represented in v1 because v2 reports completion with state_update session updates",
-        ))
+        Ok(match self {
+            Self::Id { value } => crate::v1::SessionConfigOptionValue::ValueId {
+                value: value.into_v1()?,
+            },
+            Self::Boolean { value } => crate::v1::SessionConfigOptionValue::Boolean {
+                value: value.into_v1()?,
+            },
+            Self::Other(value) => {
+                return Err(unknown_v2_enum_variant(
+                    "SessionConfigOptionValue",
+                    &value.type_,
+                ));
+            }
+        })
     }
 }
 
-impl IntoV2 for crate::v1::PromptResponse {
-    type Output = super::PromptResponse;
+#[cfg(feature = "unstable_boolean_config")]
+impl IntoV2 for crate::v1::SessionConfigOptionValue {
+    type Output = super::SessionConfigOptionValue;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Err(ProtocolConversionError::new(
-            "v1 PromptResponse cannot be represented in v2 by itself because v2 reports completion with state_update session updates",
-        ))
+        Ok(match self {
+            Self::Boolean { value } => super::SessionConfigOptionValue::Boolean {
+                value: value.into_v2()?,
+            },
+            Self::ValueId { value } => super::SessionConfigOptionValue::Id {
+                value: value.into_v2()?,
+            },
+        })
     }
 }
 
-impl IntoV1 for super::StopReason {
-    type Output = crate::v1::StopReason;
+impl IntoV1 for super::SetSessionConfigOptionRequest {
+    type Output = crate::v1::SetSessionConfigOptionRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::EndTurn => crate::v1::StopReason::EndTurn,
-            Self::MaxTokens => crate::v1::StopReason::MaxTokens,
-            Self::MaxTurnRequests => crate::v1::StopReason::MaxTurnRequests,
-            Self::Refusal => crate::v1::StopReason::Refusal,
-            Self::Cancelled => crate::v1::StopReason::Cancelled,
-            Self::Other(value) => return Err(unknown_v2_enum_variant("StopReason", &value)),
+        let Self {
+            session_id,
+            config_id,
+            value,
+            meta,
+        } = self;
+        Ok(crate::v1::SetSessionConfigOptionRequest {
+            session_id: session_id.into_v1()?,
+            config_id: config_id.into_v1()?,
+            value: value.into_v1()?,
+            meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::StopReason {
-    type Output = super::StopReason;
+impl IntoV2 for crate::v1::SetSessionConfigOptionRequest {
+    type Output = super::SetSessionConfigOptionRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::EndTurn => super::StopReason::EndTurn,
-            Self::MaxTokens => super::StopReason::MaxTokens,
-            Self::MaxTurnRequests => super::StopReason::MaxTurnRequests,
-            Self::Refusal => super::StopReason::Refusal,
-            Self::Cancelled => super::StopReason::Cancelled,
+        let Self {
+            session_id,
+            config_id,
+            value,
+            meta,
+        } = self;
+        Ok(super::SetSessionConfigOptionRequest {
+            session_id: session_id.into_v2()?,
+            config_id: config_id.into_v2()?,
+            value: value.into_v2()?,
+            meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_end_turn_token_usage")]
-impl IntoV1 for super::Usage {
-    type Output = crate::v1::Usage;
+impl IntoV1 for super::SetSessionConfigOptionResponse {
+    type Output = crate::v1::SetSessionConfigOptionResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            total_tokens,
-            input_tokens,
-            output_tokens,
-            thought_tokens,
-            cached_read_tokens,
-            cached_write_tokens,
+            config_options,
             meta,
         } = self;
-        Ok(crate::v1::Usage {
-            total_tokens: total_tokens.into_v1()?,
-            input_tokens: input_tokens.into_v1()?,
-            output_tokens: output_tokens.into_v1()?,
-            thought_tokens: thought_tokens.into_v1()?,
-            cached_read_tokens: cached_read_tokens.into_v1()?,
-            cached_write_tokens: cached_write_tokens.into_v1()?,
+        Ok(crate::v1::SetSessionConfigOptionResponse {
+            config_options: into_v1_vec_skip_errors(config_options),
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_end_turn_token_usage")]
-impl IntoV2 for crate::v1::Usage {
-    type Output = super::Usage;
+impl IntoV2 for crate::v1::SetSessionConfigOptionResponse {
+    type Output = super::SetSessionConfigOptionResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            total_tokens,
-            input_tokens,
-            output_tokens,
-            thought_tokens,
-            cached_read_tokens,
-            cached_write_tokens,
+            config_options,
             meta,
         } = self;
-        Ok(super::Usage {
-            total_tokens: total_tokens.into_v2()?,
-            input_tokens: input_tokens.into_v2()?,
-            output_tokens: output_tokens.into_v2()?,
-            thought_tokens: thought_tokens.into_v2()?,
-            cached_read_tokens: cached_read_tokens.into_v2()?,
-            cached_write_tokens: cached_write_tokens.into_v2()?,
+        Ok(super::SetSessionConfigOptionResponse {
+            config_options: into_v2_vec_skip_errors(config_options),
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV1 for super::LlmProtocol {
-    type Output = crate::v1::LlmProtocol;
+impl IntoV1 for super::McpServer {
+    type Output = crate::v1::McpServer;
 
     fn into_v1(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::Anthropic => crate::v1::LlmProtocol::Anthropic,
-            Self::OpenAi => crate::v1::LlmProtocol::OpenAi,
-            Self::Azure => crate::v1::LlmProtocol::Azure,
-            Self::Vertex => crate::v1::LlmProtocol::Vertex,
-            Self::Bedrock => crate::v1::LlmProtocol::Bedrock,
-            Self::Other(value) => crate::v1::LlmProtocol::Other(value.into_v1()?),
+            Self::Http(value) => crate::v1::McpServer::Http(value.into_v1()?),
+            #[cfg(feature = "unstable_mcp_over_acp")]
+            Self::Acp(value) => crate::v1::McpServer::Acp(value.into_v1()?),
+            Self::Stdio(value) => crate::v1::McpServer::Stdio(value.into_v1()?),
+            Self::Other(value) => return Err(unknown_v2_enum_variant("McpServer", &value.type_)),
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV2 for crate::v1::LlmProtocol {
-    type Output = super::LlmProtocol;
+impl IntoV2 for crate::v1::McpServer {
+    type Output = super::McpServer;
 
     fn into_v2(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::Anthropic => super::LlmProtocol::Anthropic,
-            Self::OpenAi => super::LlmProtocol::OpenAi,
-            Self::Azure => super::LlmProtocol::Azure,
-            Self::Vertex => super::LlmProtocol::Vertex,
-            Self::Bedrock => super::LlmProtocol::Bedrock,
-            Self::Other(value) => super::LlmProtocol::Other(value.into_v2()?),
+            Self::Http(value) => super::McpServer::Http(value.into_v2()?),
+            Self::Sse(_) => return Err(removed_v1_enum_variant("McpServer", "sse")),
+            #[cfg(feature = "unstable_mcp_over_acp")]
+            Self::Acp(value) => super::McpServer::Acp(value.into_v2()?),
+            Self::Stdio(value) => super::McpServer::Stdio(value.into_v2()?),
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV1 for super::ProviderCurrentConfig {
-    type Output = crate::v1::ProviderCurrentConfig;
+impl IntoV1 for super::McpServerHttp {
+    type Output = crate::v1::McpServerHttp;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            api_type,
-            base_url,
+            name,
+            url,
+            headers,
             meta,
         } = self;
-        Ok(crate::v1::ProviderCurrentConfig {
-            api_type: api_type.into_v1()?,
-            base_url: base_url.into_v1()?,
+        Ok(crate::v1::McpServerHttp {
+            name: name.into_v1()?,
+            url: url.into_v1()?,
+            headers: headers.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV2 for crate::v1::ProviderCurrentConfig {
-    type Output = super::ProviderCurrentConfig;
+impl IntoV2 for crate::v1::McpServerHttp {
+    type Output = super::McpServerHttp;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            api_type,
-            base_url,
+            name,
+            url,
+            headers,
             meta,
         } = self;
-        Ok(super::ProviderCurrentConfig {
-            api_type: api_type.into_v2()?,
-            base_url: base_url.into_v2()?,
+        Ok(super::McpServerHttp {
+            name: name.into_v2()?,
+            url: url.into_v2()?,
+            headers: headers.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV1 for super::ProviderId {
-    type Output = crate::v1::ProviderId;
+#[cfg(feature = "unstable_mcp_over_acp")]
+impl IntoV1 for super::McpServerAcpId {
+    type Output = crate::v1::McpServerAcpId;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(crate::v1::ProviderId(self.0.into_v1()?))
+        Ok(crate::v1::McpServerAcpId(self.0.into_v1()?))
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV2 for crate::v1::ProviderId {
-    type Output = super::ProviderId;
+#[cfg(feature = "unstable_mcp_over_acp")]
+impl IntoV2 for crate::v1::McpServerAcpId {
+    type Output = super::McpServerAcpId;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(super::ProviderId(self.0.into_v2()?))
+        Ok(super::McpServerAcpId(self.0.into_v2()?))
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV1 for super::ProviderInfo {
-    type Output = crate::v1::ProviderInfo;
+#[cfg(feature = "unstable_mcp_over_acp")]
+impl IntoV1 for super::McpConnectionId {
+    type Output = crate::v1::McpConnectionId;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        Ok(crate::v1::McpConnectionId(self.0.into_v1()?))
+    }
+}
+
+#[cfg(feature = "unstable_mcp_over_acp")]
+impl IntoV2 for crate::v1::McpConnectionId {
+    type Output = super::McpConnectionId;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        Ok(super::McpConnectionId(self.0.into_v2()?))
+    }
+}
+
+#[cfg(feature = "unstable_mcp_over_acp")]
+impl IntoV1 for super::McpServerAcp {
+    type Output = crate::v1::McpServerAcp;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            provider_id,
-            supported,
-            required,
-            current,
+            name,
+            server_id,
             meta,
         } = self;
-        Ok(crate::v1::ProviderInfo {
-            provider_id: provider_id.into_v1()?,
-            supported: into_v1_vec_skip_errors(supported),
-            required: required.into_v1()?,
-            current: current.into_v1()?,
+        Ok(crate::v1::McpServerAcp {
+            name: name.into_v1()?,
+            server_id: server_id.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV2 for crate::v1::ProviderInfo {
-    type Output = super::ProviderInfo;
+#[cfg(feature = "unstable_mcp_over_acp")]
+impl IntoV2 for crate::v1::McpServerAcp {
+    type Output = super::McpServerAcp;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            provider_id,
-            supported,
-            required,
-            current,
+            name,
+            server_id,
             meta,
         } = self;
-        Ok(super::ProviderInfo {
-            provider_id: provider_id.into_v2()?,
-            supported: into_v2_vec_skip_errors(supported),
-            required: required.into_v2()?,
-            current: current.into_v2()?,
+        Ok(super::McpServerAcp {
+            name: name.into_v2()?,
+            server_id: server_id.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV1 for super::ListProvidersRequest {
-    type Output = crate::v1::ListProvidersRequest;
+impl IntoV1 for super::McpServerStdio {
+    type Output = crate::v1::McpServerStdio;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::ListProvidersRequest {
+        let Self {
+            name,
+            command,
+            args,
+            env,
+            meta,
+        } = self;
+        Ok(crate::v1::McpServerStdio {
+            name: name.into_v1()?,
+            command: command.into_v1()?,
+            args: args.into_v1()?,
+            env: env.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV2 for crate::v1::ListProvidersRequest {
-    type Output = super::ListProvidersRequest;
+impl IntoV2 for crate::v1::McpServerStdio {
+    type Output = super::McpServerStdio;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::ListProvidersRequest {
-            meta: meta.into_v2()?,
-        })
-    }
-}
-
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV1 for super::ListProvidersResponse {
-    type Output = crate::v1::ListProvidersResponse;
-
+        let Self {
+            name,
+            command,
+            args,
+            env,
+            meta,
+        } = self;
+        Ok(super::McpServerStdio {
+            name: name.into_v2()?,
+            command: command.into_v2()?,
+            args: args.into_v2()?,
+            env: env.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+impl IntoV1 for super::EnvVariable {
+    type Output = crate::v1::EnvVariable;
+
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { providers, meta } = self;
-        Ok(crate::v1::ListProvidersResponse {
-            providers: into_v1_vec_skip_errors(providers),
+        let Self { name, value, meta } = self;
+        Ok(crate::v1::EnvVariable {
+            name: name.into_v1()?,
+            value: value.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV2 for crate::v1::ListProvidersResponse {
-    type Output = super::ListProvidersResponse;
+impl IntoV2 for crate::v1::EnvVariable {
+    type Output = super::EnvVariable;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { providers, meta } = self;
-        Ok(super::ListProvidersResponse {
-            providers: into_v2_vec_skip_errors(providers),
+        let Self { name, value, meta } = self;
+        Ok(super::EnvVariable {
+            name: name.into_v2()?,
+            value: value.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV1 for super::SetProviderRequest {
-    type Output = crate::v1::SetProviderRequest;
+impl IntoV1 for super::HttpHeader {
+    type Output = crate::v1::HttpHeader;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            provider_id,
-            api_type,
-            base_url,
-            headers,
-            meta,
-        } = self;
-        Ok(crate::v1::SetProviderRequest {
-            provider_id: provider_id.into_v1()?,
-            api_type: api_type.into_v1()?,
-            base_url: base_url.into_v1()?,
-            headers: headers.into_v1()?,
+        let Self { name, value, meta } = self;
+        Ok(crate::v1::HttpHeader {
+            name: name.into_v1()?,
+            value: value.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV2 for crate::v1::SetProviderRequest {
-    type Output = super::SetProviderRequest;
+impl IntoV2 for crate::v1::HttpHeader {
+    type Output = super::HttpHeader;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            provider_id,
-            api_type,
-            base_url,
-            headers,
-            meta,
-        } = self;
-        Ok(super::SetProviderRequest {
-            provider_id: provider_id.into_v2()?,
-            api_type: api_type.into_v2()?,
-            base_url: base_url.into_v2()?,
-            headers: headers.into_v2()?,
+        let Self { name, value, meta } = self;
+        Ok(super::HttpHeader {
+            name: name.into_v2()?,
+            value: value.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV1 for super::SetProviderResponse {
-    type Output = crate::v1::SetProviderResponse;
+impl IntoV1 for super::PromptRequest {
+    type Output = crate::v1::PromptRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::SetProviderResponse {
+        let Self {
+            session_id,
+            prompt,
+            #[cfg(feature = "unstable_reasoning_effort")]
+            reasoning_effort,
+            #[cfg(feature = "unstable_sampling_overrides")]
+            overrides,
+            #[cfg(feature = "unstable_prompt_deadline")]
+            deadline_ms,
+            #[cfg(feature = "unstable_turn_id")]
+            turn_id,
+            meta,
+        } = self;
+        Ok(crate::v1::PromptRequest {
+            session_id: session_id.into_v1()?,
+            prompt: prompt.into_v1()?,
+            #[cfg(feature = "unstable_reasoning_effort")]
+            reasoning_effort: into_v1_default_on_error(reasoning_effort),
+            #[cfg(feature = "unstable_sampling_overrides")]
+            overrides: overrides.into_v1()?,
+            #[cfg(feature = "unstable_prompt_deadline")]
+            deadline_ms,
+            #[cfg(feature = "unstable_turn_id")]
+            turn_id: turn_id.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV2 for crate::v1::SetProviderResponse {
-    type Output = super::SetProviderResponse;
+impl IntoV2 for crate::v1::PromptRequest {
+    type Output = super::PromptRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::SetProviderResponse {
+        let Self {
+            session_id,
+            prompt,
+            #[cfg(feature = "unstable_reasoning_effort")]
+            reasoning_effort,
+            #[cfg(feature = "unstable_sampling_overrides")]
+            overrides,
+            #[cfg(feature = "unstable_prompt_deadline")]
+            deadline_ms,
+            #[cfg(feature = "unstable_turn_id")]
+            turn_id,
+            meta,
+        } = self;
+        Ok(super::PromptRequest {
+            session_id: session_id.into_v2()?,
+            prompt: prompt.into_v2()?,
+            #[cfg(feature = "unstable_reasoning_effort")]
+            reasoning_effort: reasoning_effort.into_v2()?,
+            #[cfg(feature = "unstable_sampling_overrides")]
+            overrides: overrides.into_v2()?,
+            #[cfg(feature = "unstable_prompt_deadline")]
+            deadline_ms,
+            #[cfg(feature = "unstable_turn_id")]
+            turn_id: turn_id.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV1 for super::DisableProviderRequest {
-    type Output = crate::v1::DisableProviderRequest;
+#[cfg(feature = "unstable_sampling_overrides")]
+impl IntoV1 for super::SamplingOverrides {
+    type Output = crate::v1::SamplingOverrides;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { provider_id, meta } = self;
-        Ok(crate::v1::DisableProviderRequest {
-            provider_id: provider_id.into_v1()?,
+        let Self {
+            temperature,
+            top_p,
+            max_tokens,
+            meta,
+        } = self;
+        Ok(crate::v1::SamplingOverrides {
+            temperature,
+            top_p,
+            max_tokens,
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV2 for crate::v1::DisableProviderRequest {
-    type Output = super::DisableProviderRequest;
+#[cfg(feature = "unstable_sampling_overrides")]
+impl IntoV2 for crate::v1::SamplingOverrides {
+    type Output = super::SamplingOverrides;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { provider_id, meta } = self;
-        Ok(super::DisableProviderRequest {
-            provider_id: provider_id.into_v2()?,
+        let Self {
+            temperature,
+            top_p,
+            max_tokens,
+            meta,
+        } = self;
+        Ok(super::SamplingOverrides {
+            temperature,
+            top_p,
+            max_tokens,
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV1 for super::DisableProviderResponse {
-    type Output = crate::v1::DisableProviderResponse;
+#[cfg(feature = "unstable_reasoning_effort")]
+impl IntoV1 for super::ReasoningEffort {
+    type Output = crate::v1::ReasoningEffort;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::DisableProviderResponse {
-            meta: meta.into_v1()?,
+        Ok(match self {
+            Self::Low => crate::v1::ReasoningEffort::Low,
+            Self::Medium => crate::v1::ReasoningEffort::Medium,
+            Self::High => crate::v1::ReasoningEffort::High,
+            Self::Other(value) => {
+                return Err(unknown_v2_enum_variant("ReasoningEffort", &value));
+            }
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV2 for crate::v1::DisableProviderResponse {
-    type Output = super::DisableProviderResponse;
+#[cfg(feature = "unstable_reasoning_effort")]
+impl IntoV2 for crate::v1::ReasoningEffort {
+    type Output = super::ReasoningEffort;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::DisableProviderResponse {
-            meta: meta.into_v2()?,
+        Ok(match self {
+            Self::Low => super::ReasoningEffort::Low,
+            Self::Medium => super::ReasoningEffort::Medium,
+            Self::High => super::ReasoningEffort::High,
         })
     }
 }
 
-impl IntoV1 for super::AgentCapabilities {
-    type Output = crate::v1::AgentCapabilities;
+impl IntoV1 for super::PromptResponse {
+    type Output = crate::v1::PromptResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            session,
-            auth,
-            #[cfg(feature = "unstable_llm_providers")]
-            providers,
-            #[cfg(feature = "unstable_nes")]
-            nes,
-            #[cfg(feature = "unstable_nes")]
-            position_encoding,
-            meta,
-        } = self;
-        let Some(session) = session else {
-            return Err(ProtocolConversionError::new(
-                "v2 AgentCapabilities without `session` cannot be represented in v1",
-            ));
-        };
-        let V1SessionCapabilityParts {
-            session_capabilities,
-            prompt_capabilities,
-            load_session,
-            mcp_capabilities,
-        } = session.into_v1()?;
-
-        Ok(crate::v1::AgentCapabilities {
-            load_session: load_session.into_v1()?,
-            prompt_capabilities,
-            mcp_capabilities,
-            session_capabilities,
-            auth: auth.map(IntoV1::into_v1).transpose()?.unwrap_or_default(),
-            #[cfg(feature = "unstable_llm_providers")]
-            providers: into_v1_default_on_error(providers),
-            #[cfg(feature = "unstable_nes")]
-            nes: into_v1_default_on_error(nes),
-            #[cfg(feature = "unstable_nes")]
-            position_encoding: into_v1_default_on_error(position_encoding),
-            meta: meta.into_v1()?,
-        })
+        Err(ProtocolConversionError::new(
+            "v2 PromptResponse cannot be represented in v1 because v2 reports completion with state_update session updates",
+        ))
     }
 }
 
-impl IntoV2 for crate::v1::AgentCapabilities {
-    type Output = super::AgentCapabilities;
+impl IntoV2 for crate::v1::PromptResponse {
+    type Output = super::PromptResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            load_session,
-            prompt_capabilities,
-            mcp_capabilities,
-            session_capabilities,
-            auth,
-            #[cfg(feature = "unstable_llm_providers")]
-            providers,
-            #[cfg(feature = "unstable_nes")]
-            nes,
-            #[cfg(feature = "unstable_nes")]
-            position_encoding,
-            meta,
-        } = self;
-        let session = super::SessionCapabilities::from_v1(
-            session_capabilities,
-            prompt_capabilities,
-            load_session,
-            mcp_capabilities,
-        )?;
-
-        Ok(super::AgentCapabilities {
-            session: Some(session),
-            auth: Some(auth.into_v2()?),
-            #[cfg(feature = "unstable_llm_providers")]
-            providers: into_v2_default_on_error(providers),
-            #[cfg(feature = "unstable_nes")]
-            nes: into_v2_default_on_error(nes),
-            #[cfg(feature = "unstable_nes")]
-            position_encoding: into_v2_default_on_error(position_encoding),
-            meta: meta.into_v2()?,
-        })
+        Err(ProtocolConversionError::new(
+            "v1 PromptResponse cannot be represented in v2 by itself because v2 reports completion with state_update session updates",
+        ))
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV1 for super::ProvidersCapabilities {
-    type Output = crate::v1::ProvidersCapabilities;
+impl IntoV1 for super::StopReason {
+    type Output = crate::v1::StopReason;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::ProvidersCapabilities {
-            meta: meta.into_v1()?,
+        Ok(match self {
+            Self::EndTurn => crate::v1::StopReason::EndTurn,
+            Self::MaxTokens => crate::v1::StopReason::MaxTokens,
+            Self::MaxTurnRequests => crate::v1::StopReason::MaxTurnRequests,
+            Self::Refusal => crate::v1::StopReason::Refusal,
+            Self::Cancelled => crate::v1::StopReason::Cancelled,
+            #[cfg(feature = "unstable_prompt_deadline")]
+            Self::Deadline => crate::v1::StopReason::Deadline,
+            Self::Other(value) => return Err(unknown_v2_enum_variant("StopReason", &value)),
         })
     }
 }
 
-#[cfg(feature = "unstable_llm_providers")]
-impl IntoV2 for crate::v1::ProvidersCapabilities {
-    type Output = super::ProvidersCapabilities;
+impl IntoV2 for crate::v1::StopReason {
+    type Output = super::StopReason;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::ProvidersCapabilities {
-            meta: meta.into_v2()?,
-        })
-    }
-}
-
-/// The v1 capability fields represented by v2 `SessionCapabilities`.
-#[derive(Debug, Clone, PartialEq, Eq)]
-#[non_exhaustive]
-pub struct V1SessionCapabilityParts {
-    /// Session-specific v1 capabilities.
-    pub session_capabilities: crate::v1::SessionCapabilities,
-    /// Prompt capabilities for v1 `session/prompt` requests.
-    pub prompt_capabilities: crate::v1::PromptCapabilities,
-    /// Whether v1 `session/load` is supported.
-    pub load_session: bool,
-    /// MCP capabilities for v1 session lifecycle requests.
-    pub mcp_capabilities: crate::v1::McpCapabilities,
+        Ok(match self {
+            Self::EndTurn => super::StopReason::EndTurn,
+            Self::MaxTokens => super::StopReason::MaxTokens,
+            Self::MaxTurnRequests => super::StopReason::MaxTurnRequests,
+            Self::Refusal => super::StopReason::Refusal,
+            Self::Cancelled => super::StopReason::Cancelled,
+            #[cfg(feature = "unstable_prompt_deadline")]
+            Self::Deadline => super::StopReason::Deadline,
+        })
+    }
 }
 
-impl super::SessionCapabilities {
-    /// Converts these v2 draft session capabilities into the v1 capability
-    /// fields they represent.
-    ///
-    /// # Errors
-    ///
-    /// Returns [`ProtocolConversionError`] when any contained capability field
-    /// cannot be represented in v1.
-    pub fn into_v1(self) -> Result<V1SessionCapabilityParts> {
+#[cfg(feature = "unstable_end_turn_token_usage")]
+impl IntoV1 for super::Usage {
+    type Output = crate::v1::Usage;
+
+    fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            prompt,
-            mcp,
-            load,
-            list,
-            delete,
-            additional_directories,
-            #[cfg(feature = "unstable_session_fork")]
-            fork,
-            resume,
-            close,
+            total_tokens,
+            input_tokens,
+            output_tokens,
+            thought_tokens,
+            cached_read_tokens,
+            cached_write_tokens,
             meta,
         } = self;
-
-        Ok(V1SessionCapabilityParts {
-            session_capabilities: crate::v1::SessionCapabilities {
-                list: into_v1_default_on_error(list),
-                delete: into_v1_default_on_error(delete),
-                additional_directories: into_v1_default_on_error(additional_directories),
-                #[cfg(feature = "unstable_session_fork")]
-                fork: into_v1_default_on_error(fork),
-                resume: into_v1_default_on_error(resume),
-                close: into_v1_default_on_error(close),
-                meta: meta.into_v1()?,
-            },
-            prompt_capabilities: prompt.unwrap_or_default().into_v1()?,
-            load_session: load.is_some(),
-            mcp_capabilities: mcp.unwrap_or_default().into_v1()?,
+        Ok(crate::v1::Usage {
+            total_tokens: total_tokens.into_v1()?,
+            input_tokens: input_tokens.into_v1()?,
+            output_tokens: output_tokens.into_v1()?,
+            thought_tokens: thought_tokens.into_v1()?,
+            cached_read_tokens: cached_read_tokens.into_v1()?,
+            cached_write_tokens: cached_write_tokens.into_v1()?,
+            meta: meta.into_v1()?,
         })
     }
+}
 
-    /// Builds v2 draft session capabilities from the v1 agent capability fields
-    /// that now live under `session` in v2.
-    ///
-    /// # Errors
-    ///
-    /// Returns [`ProtocolConversionError`] when any of the supplied v1
-    /// capability fields cannot be represented in v2.
-    pub fn from_v1(
-        session_capabilities: crate::v1::SessionCapabilities,
-        prompt_capabilities: crate::v1::PromptCapabilities,
-        load_session: bool,
-        mcp_capabilities: crate::v1::McpCapabilities,
-    ) -> Result<Self> {
-        let crate::v1::SessionCapabilities {
-            list,
-            delete,
-            additional_directories,
-            #[cfg(feature = "unstable_session_fork")]
-            fork,
-            resume,
-            close,
-            meta,
-        } = session_capabilities;
+#[cfg(feature = "unstable_end_turn_token_usage")]
+impl IntoV2 for crate::v1::Usage {
+    type Output = super::Usage;
 
-        Ok(super::SessionCapabilities {
-            prompt: Some(prompt_capabilities.into_v2()?),
-            mcp: Some(mcp_capabilities.into_v2()?),
-            load: load_session.then(super::SessionLoadCapabilities::new),
-            list: into_v2_default_on_error(list),
-            delete: into_v2_default_on_error(delete),
-            additional_directories: into_v2_default_on_error(additional_directories),
-            #[cfg(feature = "unstable_session_fork")]
-            fork: into_v2_default_on_error(fork),
-            resume: into_v2_default_on_error(resume),
-            close: into_v2_default_on_error(close),
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            total_tokens,
+            input_tokens,
+            output_tokens,
+            thought_tokens,
+            cached_read_tokens,
+            cached_write_tokens,
+            meta,
+        } = self;
+        Ok(super::Usage {
+            total_tokens: total_tokens.into_v2()?,
+            input_tokens: input_tokens.into_v2()?,
+            output_tokens: output_tokens.into_v2()?,
+            thought_tokens: thought_tokens.into_v2()?,
+            cached_read_tokens: cached_read_tokens.into_v2()?,
+            cached_write_tokens: cached_write_tokens.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::SessionListCapabilities {
-    type Output = crate::v1::SessionListCapabilities;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV1 for super::LlmProtocol {
+    type Output = crate::v1::LlmProtocol;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::SessionListCapabilities {
-            meta: meta.into_v1()?,
+        Ok(match self {
+            Self::Anthropic => crate::v1::LlmProtocol::Anthropic,
+            Self::OpenAi => crate::v1::LlmProtocol::OpenAi,
+            Self::Azure => crate::v1::LlmProtocol::Azure,
+            Self::Vertex => crate::v1::LlmProtocol::Vertex,
+            Self::Bedrock => crate::v1::LlmProtocol::Bedrock,
+            Self::Other(value) => crate::v1::LlmProtocol::Other(value.into_v1()?),
         })
     }
 }
 
-impl IntoV2 for crate::v1::SessionListCapabilities {
-    type Output = super::SessionListCapabilities;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV2 for crate::v1::LlmProtocol {
+    type Output = super::LlmProtocol;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::SessionListCapabilities {
-            meta: meta.into_v2()?,
+        Ok(match self {
+            Self::Anthropic => super::LlmProtocol::Anthropic,
+            Self::OpenAi => super::LlmProtocol::OpenAi,
+            Self::Azure => super::LlmProtocol::Azure,
+            Self::Vertex => super::LlmProtocol::Vertex,
+            Self::Bedrock => super::LlmProtocol::Bedrock,
+            Self::Other(value) => super::LlmProtocol::Other(value.into_v2()?),
         })
     }
 }
 
-impl IntoV1 for super::SessionDeleteCapabilities {
-    type Output = crate::v1::SessionDeleteCapabilities;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV1 for super::ProviderCurrentConfig {
+    type Output = crate::v1::ProviderCurrentConfig;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::SessionDeleteCapabilities {
+        let Self {
+            api_type,
+            base_url,
+            meta,
+        } = self;
+        Ok(crate::v1::ProviderCurrentConfig {
+            api_type: api_type.into_v1()?,
+            base_url: base_url.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::SessionDeleteCapabilities {
-    type Output = super::SessionDeleteCapabilities;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV2 for crate::v1::ProviderCurrentConfig {
+    type Output = super::ProviderCurrentConfig;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::SessionDeleteCapabilities {
+        let Self {
+            api_type,
+            base_url,
+            meta,
+        } = self;
+        Ok(super::ProviderCurrentConfig {
+            api_type: api_type.into_v2()?,
+            base_url: base_url.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
-impl IntoV1 for super::SessionAdditionalDirectoriesCapabilities {
-    type Output = crate::v1::SessionAdditionalDirectoriesCapabilities;
+
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV1 for super::ProviderId {
+    type Output = crate::v1::ProviderId;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::SessionAdditionalDirectoriesCapabilities {
-            meta: meta.into_v1()?,
-        })
+        Ok(crate::v1::ProviderId(self.0.into_v1()?))
     }
 }
 
-impl IntoV2 for crate::v1::SessionAdditionalDirectoriesCapabilities {
-    type Output = super::SessionAdditionalDirectoriesCapabilities;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV2 for crate::v1::ProviderId {
+    type Output = super::ProviderId;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::SessionAdditionalDirectoriesCapabilities {
-            meta: meta.into_v2()?,
-        })
+        Ok(super::ProviderId(self.0.into_v2()?))
     }
 }
 
-#[cfg(feature = "unstable_session_fork")]
-impl IntoV1 for super::SessionForkCapabilities {
-    type Output = crate::v1::SessionForkCapabilities;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV1 for super::ProviderInfo {
+    type Output = crate::v1::ProviderInfo;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::SessionForkCapabilities {
+        let Self {
+            provider_id,
+            supported,
+            required,
+            current,
+            meta,
+        } = self;
+        Ok(crate::v1::ProviderInfo {
+            provider_id: provider_id.into_v1()?,
+            supported: into_v1_vec_skip_errors(supported),
+            required: required.into_v1()?,
+            current: current.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_session_fork")]
-impl IntoV2 for crate::v1::SessionForkCapabilities {
-    type Output = super::SessionForkCapabilities;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV2 for crate::v1::ProviderInfo {
+    type Output = super::ProviderInfo;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::SessionForkCapabilities {
+        let Self {
+            provider_id,
+            supported,
+            required,
+            current,
+            meta,
+        } = self;
+        Ok(super::ProviderInfo {
+            provider_id: provider_id.into_v2()?,
+            supported: into_v2_vec_skip_errors(supported),
+            required: required.into_v2()?,
+            current: current.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::SessionResumeCapabilities {
-    type Output = crate::v1::SessionResumeCapabilities;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV1 for super::ListProvidersRequest {
+    type Output = crate::v1::ListProvidersRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self { meta } = self;
-        Ok(crate::v1::SessionResumeCapabilities {
+        Ok(crate::v1::ListProvidersRequest {
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::SessionResumeCapabilities {
-    type Output = super::SessionResumeCapabilities;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV2 for crate::v1::ListProvidersRequest {
+    type Output = super::ListProvidersRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self { meta } = self;
-        Ok(super::SessionResumeCapabilities {
+        Ok(super::ListProvidersRequest {
             meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::SessionCloseCapabilities {
-    type Output = crate::v1::SessionCloseCapabilities;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV1 for super::ListProvidersResponse {
+    type Output = crate::v1::ListProvidersResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::SessionCloseCapabilities {
+        let Self { providers, meta } = self;
+        Ok(crate::v1::ListProvidersResponse {
+            providers: into_v1_vec_skip_errors(providers),
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::SessionCloseCapabilities {
-    type Output = super::SessionCloseCapabilities;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV2 for crate::v1::ListProvidersResponse {
+    type Output = super::ListProvidersResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::SessionCloseCapabilities {
+        let Self { providers, meta } = self;
+        Ok(super::ListProvidersResponse {
+            providers: into_v2_vec_skip_errors(providers),
             meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::PromptCapabilities {
-    type Output = crate::v1::PromptCapabilities;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV1 for super::SetProviderRequest {
+    type Output = crate::v1::SetProviderRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            image,
-            audio,
-            embedded_context,
+            provider_id,
+            api_type,
+            base_url,
+            headers,
             meta,
         } = self;
-        Ok(crate::v1::PromptCapabilities {
-            image: image.is_some(),
-            audio: audio.is_some(),
-            embedded_context: embedded_context.is_some(),
+        Ok(crate::v1::SetProviderRequest {
+            provider_id: provider_id.into_v1()?,
+            api_type: api_type.into_v1()?,
+            base_url: base_url.into_v1()?,
+            headers: headers.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::PromptCapabilities {
-    type Output = super::PromptCapabilities;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV2 for crate::v1::SetProviderRequest {
+    type Output = super::SetProviderRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            image,
-            audio,
-            embedded_context,
+            provider_id,
+            api_type,
+            base_url,
+            headers,
             meta,
         } = self;
-        Ok(super::PromptCapabilities {
-            image: image.then(super::PromptImageCapabilities::new),
-            audio: audio.then(super::PromptAudioCapabilities::new),
-            embedded_context: embedded_context.then(super::PromptEmbeddedContextCapabilities::new),
+        Ok(super::SetProviderRequest {
+            provider_id: provider_id.into_v2()?,
+            api_type: api_type.into_v2()?,
+            base_url: base_url.into_v2()?,
+            headers: headers.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::McpCapabilities {
-    type Output = crate::v1::McpCapabilities;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV1 for super::SetProviderResponse {
+    type Output = crate::v1::SetProviderResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            stdio: _,
-            http,
-            #[cfg(feature = "unstable_mcp_over_acp")]
-            acp,
-            meta,
-        } = self;
-        Ok(crate::v1::McpCapabilities {
-            http: http.is_some(),
-            sse: false,
-            #[cfg(feature = "unstable_mcp_over_acp")]
-            acp: acp.is_some(),
+        let Self { meta } = self;
+        Ok(crate::v1::SetProviderResponse {
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::McpCapabilities {
-    type Output = super::McpCapabilities;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV2 for crate::v1::SetProviderResponse {
+    type Output = super::SetProviderResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            http,
-            sse: _,
-            #[cfg(feature = "unstable_mcp_over_acp")]
-            acp,
-            meta,
-        } = self;
-        Ok(super::McpCapabilities {
-            stdio: Some(super::McpStdioCapabilities::new()),
-            http: http.then(super::McpHttpCapabilities::new),
-            #[cfg(feature = "unstable_mcp_over_acp")]
-            acp: acp.then(super::McpAcpCapabilities::new),
+        let Self { meta } = self;
+        Ok(super::SetProviderResponse {
             meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::ClientRequest {
-    type Output = crate::v1::ClientRequest;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV1 for super::DisableProviderRequest {
+    type Output = crate::v1::DisableProviderRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::InitializeRequest(value) => {
-                crate::v1::ClientRequest::InitializeRequest(value.into_v1()?)
-            }
-            Self::LoginAuthRequest(value) => {
-                crate::v1::ClientRequest::AuthenticateRequest(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_llm_providers")]
-            Self::ListProvidersRequest(value) => {
-                crate::v1::ClientRequest::ListProvidersRequest(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_llm_providers")]
-            Self::SetProviderRequest(value) => {
-                crate::v1::ClientRequest::SetProviderRequest(value.into_v1()?)
-            }
+        let Self { provider_id, meta } = self;
+        Ok(crate::v1::DisableProviderRequest {
+            provider_id: provider_id.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV2 for crate::v1::DisableProviderRequest {
+    type Output = super::DisableProviderRequest;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { provider_id, meta } = self;
+        Ok(super::DisableProviderRequest {
+            provider_id: provider_id.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV1 for super::DisableProviderResponse {
+    type Output = crate::v1::DisableProviderResponse;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(crate::v1::DisableProviderResponse {
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV2 for crate::v1::DisableProviderResponse {
+    type Output = super::DisableProviderResponse;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(super::DisableProviderResponse {
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+impl IntoV1 for super::AgentCapabilities {
+    type Output = crate::v1::AgentCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            session,
+            auth,
+            #[cfg(feature = "unstable_concurrent_prompts")]
+            concurrent_prompts,
             #[cfg(feature = "unstable_llm_providers")]
-            Self::DisableProviderRequest(value) => {
-                crate::v1::ClientRequest::DisableProviderRequest(value.into_v1()?)
-            }
-            Self::LogoutAuthRequest(value) => {
-                crate::v1::ClientRequest::LogoutRequest(value.into_v1()?)
-            }
-            Self::NewSessionRequest(value) => {
-                crate::v1::ClientRequest::NewSessionRequest(value.into_v1()?)
-            }
-            Self::LoadSessionRequest(value) => {
-                crate::v1::ClientRequest::LoadSessionRequest(value.into_v1()?)
-            }
-            Self::ListSessionsRequest(value) => {
-                crate::v1::ClientRequest::ListSessionsRequest(value.into_v1()?)
-            }
-            Self::DeleteSessionRequest(value) => {
-                crate::v1::ClientRequest::DeleteSessionRequest(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_session_fork")]
-            Self::ForkSessionRequest(value) => {
-                crate::v1::ClientRequest::ForkSessionRequest(value.into_v1()?)
-            }
-            Self::ResumeSessionRequest(value) => {
-                crate::v1::ClientRequest::ResumeSessionRequest(value.into_v1()?)
-            }
-            Self::CloseSessionRequest(value) => {
-                crate::v1::ClientRequest::CloseSessionRequest(value.into_v1()?)
-            }
-            Self::SetSessionConfigOptionRequest(value) => {
-                crate::v1::ClientRequest::SetSessionConfigOptionRequest(value.into_v1()?)
-            }
-            Self::PromptRequest(value) => crate::v1::ClientRequest::PromptRequest(value.into_v1()?),
+            providers,
             #[cfg(feature = "unstable_nes")]
-            Self::StartNesRequest(value) => {
-                crate::v1::ClientRequest::StartNesRequest(value.into_v1()?)
-            }
+            nes,
             #[cfg(feature = "unstable_nes")]
-            Self::SuggestNesRequest(value) => {
-                crate::v1::ClientRequest::SuggestNesRequest(value.into_v1()?)
-            }
+            position_encoding,
+            #[cfg(feature = "unstable_accepted_mime_types")]
+            accepted_mime_types,
+            meta,
+        } = self;
+        let Some(session) = session else {
+            return Err(ProtocolConversionError::new(
+                "v2 AgentCapabilities without `session` cannot be represented in v1",
+            ));
+        };
+        let V1SessionCapabilityParts {
+            session_capabilities,
+            prompt_capabilities,
+            load_session,
+            mcp_capabilities,
+        } = session.into_v1()?;
+
+        Ok(crate::v1::AgentCapabilities {
+            load_session: load_session.into_v1()?,
+            #[cfg(feature = "unstable_concurrent_prompts")]
+            concurrent_prompts: concurrent_prompts.is_some(),
+            prompt_capabilities,
+            mcp_capabilities,
+            session_capabilities,
+            auth: auth.map(IntoV1::into_v1).transpose()?.unwrap_or_default(),
+            #[cfg(feature = "unstable_llm_providers")]
+            providers: into_v1_default_on_error(providers),
             #[cfg(feature = "unstable_nes")]
-            Self::CloseNesRequest(value) => {
-                crate::v1::ClientRequest::CloseNesRequest(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_mcp_over_acp")]
-            Self::MessageMcpRequest(value) => {
-                crate::v1::ClientRequest::MessageMcpRequest(value.into_v1()?)
-            }
-            Self::ExtMethodRequest(value) => {
-                crate::v1::ClientRequest::ExtMethodRequest(value.into_v1()?)
-            }
+            nes: into_v1_default_on_error(nes),
+            #[cfg(feature = "unstable_nes")]
+            position_encoding: into_v1_default_on_error(position_encoding),
+            #[cfg(feature = "unstable_accepted_mime_types")]
+            accepted_mime_types: accepted_mime_types.into_v1()?,
+            meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::ClientRequest {
-    type Output = super::ClientRequest;
+impl IntoV2 for crate::v1::AgentCapabilities {
+    type Output = super::AgentCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::InitializeRequest(value) => {
-                super::ClientRequest::InitializeRequest(Box::new(value.into_v2()?))
-            }
-            Self::AuthenticateRequest(value) => {
-                super::ClientRequest::LoginAuthRequest(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_llm_providers")]
-            Self::ListProvidersRequest(value) => {
-                super::ClientRequest::ListProvidersRequest(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_llm_providers")]
-            Self::SetProviderRequest(value) => {
-                super::ClientRequest::SetProviderRequest(Box::new(value.into_v2()?))
-            }
+        let Self {
+            load_session,
+            #[cfg(feature = "unstable_concurrent_prompts")]
+            concurrent_prompts,
+            prompt_capabilities,
+            mcp_capabilities,
+            session_capabilities,
+            auth,
             #[cfg(feature = "unstable_llm_providers")]
-            Self::DisableProviderRequest(value) => {
-                super::ClientRequest::DisableProviderRequest(Box::new(value.into_v2()?))
-            }
-            Self::LogoutRequest(value) => {
-                super::ClientRequest::LogoutAuthRequest(Box::new(value.into_v2()?))
-            }
-            Self::NewSessionRequest(value) => {
-                super::ClientRequest::NewSessionRequest(Box::new(value.into_v2()?))
-            }
-            Self::LoadSessionRequest(value) => {
-                super::ClientRequest::LoadSessionRequest(Box::new(value.into_v2()?))
-            }
-            Self::ListSessionsRequest(value) => {
-                super::ClientRequest::ListSessionsRequest(Box::new(value.into_v2()?))
-            }
-            Self::DeleteSessionRequest(value) => {
-                super::ClientRequest::DeleteSessionRequest(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_session_fork")]
-            Self::ForkSessionRequest(value) => {
-                super::ClientRequest::ForkSessionRequest(Box::new(value.into_v2()?))
-            }
-            Self::ResumeSessionRequest(value) => {
-                super::ClientRequest::ResumeSessionRequest(Box::new(value.into_v2()?))
-            }
-            Self::CloseSessionRequest(value) => {
-                super::ClientRequest::CloseSessionRequest(Box::new(value.into_v2()?))
-            }
-            Self::SetSessionModeRequest(_) => {
-                return Err(removed_v1_enum_variant("ClientRequest", "session/set_mode"));
-            }
-            Self::SetSessionConfigOptionRequest(value) => {
-                super::ClientRequest::SetSessionConfigOptionRequest(Box::new(value.into_v2()?))
-            }
-            Self::PromptRequest(value) => {
-                super::ClientRequest::PromptRequest(Box::new(value.into_v2()?))
-            }
+            providers,
             #[cfg(feature = "unstable_nes")]
-            Self::StartNesRequest(value) => {
-                super::ClientRequest::StartNesRequest(Box::new(value.into_v2()?))
-            }
+            nes,
             #[cfg(feature = "unstable_nes")]
-            Self::SuggestNesRequest(value) => {
-                super::ClientRequest::SuggestNesRequest(Box::new(value.into_v2()?))
-            }
+            position_encoding,
+            #[cfg(feature = "unstable_accepted_mime_types")]
+            accepted_mime_types,
+            meta,
+        } = self;
+        let session = super::SessionCapabilities::from_v1(
+            session_capabilities,
+            prompt_capabilities,
+            load_session,
+            mcp_capabilities,
+        )?;
+
+        Ok(super::AgentCapabilities {
+            session: Some(session),
+            auth: Some(auth.into_v2()?),
+            #[cfg(feature = "unstable_concurrent_prompts")]
+            concurrent_prompts: concurrent_prompts.then(super::ConcurrentPromptsCapabilities::new),
+            #[cfg(feature = "unstable_llm_providers")]
+            providers: into_v2_default_on_error(providers),
             #[cfg(feature = "unstable_nes")]
-            Self::CloseNesRequest(value) => {
-                super::ClientRequest::CloseNesRequest(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_mcp_over_acp")]
-            Self::MessageMcpRequest(value) => {
-                super::ClientRequest::MessageMcpRequest(Box::new(value.into_v2()?))
-            }
-            Self::ExtMethodRequest(value) => {
-                super::ClientRequest::ExtMethodRequest(Box::new(value.into_v2()?))
-            }
+            nes: into_v2_default_on_error(nes),
+            #[cfg(feature = "unstable_nes")]
+            position_encoding: into_v2_default_on_error(position_encoding),
+            #[cfg(feature = "unstable_accepted_mime_types")]
+            accepted_mime_types: accepted_mime_types.into_v2()?,
+            meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::AgentResponse {
-    type Output = crate::v1::AgentResponse;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV1 for super::ProvidersCapabilities {
+    type Output = crate::v1::ProvidersCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::InitializeResponse(value) => {
-                crate::v1::AgentResponse::InitializeResponse(value.into_v1()?)
-            }
-            Self::LoginAuthResponse(value) => {
-                crate::v1::AgentResponse::AuthenticateResponse(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_llm_providers")]
-            Self::ListProvidersResponse(value) => {
-                crate::v1::AgentResponse::ListProvidersResponse(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_llm_providers")]
-            Self::SetProviderResponse(value) => {
-                crate::v1::AgentResponse::SetProviderResponse(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_llm_providers")]
-            Self::DisableProviderResponse(value) => {
-                crate::v1::AgentResponse::DisableProviderResponse(value.into_v1()?)
-            }
-            Self::LogoutAuthResponse(value) => {
-                crate::v1::AgentResponse::LogoutResponse(value.into_v1()?)
-            }
-            Self::NewSessionResponse(value) => {
-                crate::v1::AgentResponse::NewSessionResponse(value.into_v1()?)
-            }
-            Self::LoadSessionResponse(value) => {
-                crate::v1::AgentResponse::LoadSessionResponse(value.into_v1()?)
-            }
-            Self::ListSessionsResponse(value) => {
-                crate::v1::AgentResponse::ListSessionsResponse(value.into_v1()?)
-            }
-            Self::DeleteSessionResponse(value) => {
-                crate::v1::AgentResponse::DeleteSessionResponse(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_session_fork")]
-            Self::ForkSessionResponse(value) => {
-                crate::v1::AgentResponse::ForkSessionResponse(value.into_v1()?)
-            }
-            Self::ResumeSessionResponse(value) => {
-                crate::v1::AgentResponse::ResumeSessionResponse(value.into_v1()?)
-            }
-            Self::CloseSessionResponse(value) => {
-                crate::v1::AgentResponse::CloseSessionResponse(value.into_v1()?)
-            }
-            Self::SetSessionConfigOptionResponse(value) => {
-                crate::v1::AgentResponse::SetSessionConfigOptionResponse(value.into_v1()?)
-            }
-            Self::PromptResponse(value) => {
-                crate::v1::AgentResponse::PromptResponse(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::StartNesResponse(value) => {
-                crate::v1::AgentResponse::StartNesResponse(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::SuggestNesResponse(value) => {
-                crate::v1::AgentResponse::SuggestNesResponse(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::CloseNesResponse(value) => {
-                crate::v1::AgentResponse::CloseNesResponse(value.into_v1()?)
-            }
-            Self::ExtMethodResponse(value) => {
-                crate::v1::AgentResponse::ExtMethodResponse(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_mcp_over_acp")]
-            Self::MessageMcpResponse(value) => {
-                crate::v1::AgentResponse::MessageMcpResponse(value.into_v1()?)
-            }
+        let Self { meta } = self;
+        Ok(crate::v1::ProvidersCapabilities {
+            meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::AgentResponse {
-    type Output = super::AgentResponse;
+#[cfg(feature = "unstable_llm_providers")]
+impl IntoV2 for crate::v1::ProvidersCapabilities {
+    type Output = super::ProvidersCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::InitializeResponse(value) => {
-                super::AgentResponse::InitializeResponse(Box::new(value.into_v2()?))
-            }
-            Self::AuthenticateResponse(value) => {
-                super::AgentResponse::LoginAuthResponse(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_llm_providers")]
-            Self::ListProvidersResponse(value) => {
-                super::AgentResponse::ListProvidersResponse(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_llm_providers")]
-            Self::SetProviderResponse(value) => {
-                super::AgentResponse::SetProviderResponse(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_llm_providers")]
-            Self::DisableProviderResponse(value) => {
-                super::AgentResponse::DisableProviderResponse(Box::new(value.into_v2()?))
-            }
-            Self::LogoutResponse(value) => {
-                super::AgentResponse::LogoutAuthResponse(Box::new(value.into_v2()?))
-            }
-            Self::NewSessionResponse(value) => {
-                super::AgentResponse::NewSessionResponse(Box::new(value.into_v2()?))
-            }
-            Self::LoadSessionResponse(value) => {
-                super::AgentResponse::LoadSessionResponse(Box::new(value.into_v2()?))
-            }
-            Self::ListSessionsResponse(value) => {
-                super::AgentResponse::ListSessionsResponse(Box::new(value.into_v2()?))
-            }
-            Self::DeleteSessionResponse(value) => {
-                super::AgentResponse::DeleteSessionResponse(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_session_fork")]
-            Self::ForkSessionResponse(value) => {
-                super::AgentResponse::ForkSessionResponse(Box::new(value.into_v2()?))
-            }
-            Self::ResumeSessionResponse(value) => {
-                super::AgentResponse::ResumeSessionResponse(Box::new(value.into_v2()?))
-            }
-            Self::CloseSessionResponse(value) => {
-                super::AgentResponse::CloseSessionResponse(Box::new(value.into_v2()?))
-            }
-            Self::SetSessionModeResponse(_) => {
-                return Err(removed_v1_enum_variant("AgentResponse", "session/set_mode"));
-            }
-            Self::SetSessionConfigOptionResponse(value) => {
-                super::AgentResponse::SetSessionConfigOptionResponse(Box::new(value.into_v2()?))
-            }
-            Self::PromptResponse(value) => {
-                super::AgentResponse::PromptResponse(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::StartNesResponse(value) => {
-                super::AgentResponse::StartNesResponse(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::SuggestNesResponse(value) => {
-                super::AgentResponse::SuggestNesResponse(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::CloseNesResponse(value) => {
-                super::AgentResponse::CloseNesResponse(Box::new(value.into_v2()?))
-            }
-            Self::ExtMethodResponse(value) => {
-                super::AgentResponse::ExtMethodResponse(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_mcp_over_acp")]
-            Self::MessageMcpResponse(value) => {
-                super::AgentResponse::MessageMcpResponse(Box::new(value.into_v2()?))
-            }
+        let Self { meta } = self;
+        Ok(super::ProvidersCapabilities {
+            meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::ClientNotification {
+/// The v1 capability fields represented by v2 `SessionCapabilities`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct V1SessionCapabilityParts {
+    /// Session-specific v1 capabilities.
+    pub session_capabilities: crate::v1::SessionCapabilities,
+    /// Prompt capabilities for v1 `session/prompt` requests.
+    pub prompt_capabilities: crate::v1::PromptCapabilities,
+    /// Whether v1 `session/load` is supported.
+    pub load_session: bool,
+    /// MCP capabilities for v1 session lifecycle requests.
+    pub mcp_capabilities: crate::v1::McpCapabilities,
+}
+
+impl super::SessionCapabilities {
+    /// Converts these v2 draft session capabilities into the v1 capability
+    /// fields they represent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProtocolConversionError`] when any contained capability field
+    /// cannot be represented in v1.
+    pub fn into_v1(self) -> Result<V1SessionCapabilityParts> {
+        let Self {
+            prompt,
+            mcp,
+            load,
+            list,
+            delete,
+            additional_directories,
+            #[cfg(feature = "unstable_session_fork")]
+            fork,
+            resume,
+            close,
+            #[cfg(feature = "unstable_session_compact")]
+            compact,
+            #[cfg(feature = "unstable_session_set_title")]
+            set_title,
+            #[cfg(feature = "unstable_cancel_tool_call")]
+            cancel_tool_call,
+            #[cfg(feature = "unstable_session_load_pagination")]
+            load_pagination,
+            meta,
+        } = self;
+
+        Ok(V1SessionCapabilityParts {
+            session_capabilities: crate::v1::SessionCapabilities {
+                list: into_v1_default_on_error(list),
+                delete: into_v1_default_on_error(delete),
+                additional_directories: into_v1_default_on_error(additional_directories),
+                #[cfg(feature = "unstable_session_fork")]
+                fork: into_v1_default_on_error(fork),
+                resume: into_v1_default_on_error(resume),
+                close: into_v1_default_on_error(close),
+                #[cfg(feature = "unstable_session_compact")]
+                compact: into_v1_default_on_error(compact),
+                #[cfg(feature = "unstable_session_set_title")]
+                set_title: into_v1_default_on_error(set_title),
+                #[cfg(feature = "unstable_cancel_tool_call")]
+                cancel_tool_call: into_v1_default_on_error(cancel_tool_call),
+                #[cfg(feature = "unstable_session_load_pagination")]
+                load_pagination: into_v1_default_on_error(load_pagination),
+                meta: meta.into_v1()?,
+            },
+            prompt_capabilities: prompt.unwrap_or_default().into_v1()?,
+            load_session: load.is_some(),
+            mcp_capabilities: mcp.unwrap_or_default().into_v1()?,
+        })
+    }
+
+    /// Builds v2 draft session capabilities from the v1 agent capability fields
+    /// that now live under `session` in v2.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProtocolConversionError`] when any of the supplied v1
+    /// capability fields cannot be represented in v2.
+    pub fn from_v1(
+        session_capabilities: crate::v1::SessionCapabilities,
+        prompt_capabilities: crate::v1::PromptCapabilities,
+        load_session: bool,
+        mcp_capabilities: crate::v1::McpCapabilities,
+    ) -> Result<Self> {
+        let crate::v1::SessionCapabilities {
+            list,
+            delete,
+            additional_directories,
+            #[cfg(feature = "unstable_session_fork")]
+            fork,
+            resume,
+            close,
+            #[cfg(feature = "unstable_session_compact")]
+            compact,
+            #[cfg(feature = "unstable_session_set_title")]
+            set_title,
+            #[cfg(feature = "unstable_cancel_tool_call")]
+            cancel_tool_call,
+            #[cfg(feature = "unstable_session_load_pagination")]
+            load_pagination,
+            meta,
+        } = session_capabilities;
+
+        Ok(super::SessionCapabilities {
+            prompt: Some(prompt_capabilities.into_v2()?),
+            mcp: Some(mcp_capabilities.into_v2()?),
+            load: load_session.then(super::SessionLoadCapabilities::new),
+            list: into_v2_default_on_error(list),
+            delete: into_v2_default_on_error(delete),
+            additional_directories: into_v2_default_on_error(additional_directories),
+            #[cfg(feature = "unstable_session_fork")]
+            fork: into_v2_default_on_error(fork),
+            resume: into_v2_default_on_error(resume),
+            close: into_v2_default_on_error(close),
+            #[cfg(feature = "unstable_session_compact")]
+            compact: into_v2_default_on_error(compact),
+            #[cfg(feature = "unstable_session_set_title")]
+            set_title: into_v2_default_on_error(set_title),
+            #[cfg(feature = "unstable_cancel_tool_call")]
+            cancel_tool_call: into_v2_default_on_error(cancel_tool_call),
+            #[cfg(feature = "unstable_session_load_pagination")]
+            load_pagination: into_v2_default_on_error(load_pagination),
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+impl IntoV1 for super::SessionListCapabilities {
+    type Output = crate::v1::SessionListCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(crate::v1::SessionListCapabilities {
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+impl IntoV2 for crate::v1::SessionListCapabilities {
+    type Output = super::SessionListCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(super::SessionListCapabilities {
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+impl IntoV1 for super::SessionDeleteCapabilities {
+    type Output = crate::v1::SessionDeleteCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(crate::v1::SessionDeleteCapabilities {
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+impl IntoV2 for crate::v1::SessionDeleteCapabilities {
+    type Output = super::SessionDeleteCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(super::SessionDeleteCapabilities {
+            meta: meta.into_v2()?,
+        })
+    }
+}
+impl IntoV1 for super::SessionAdditionalDirectoriesCapabilities {
+    type Output = crate::v1::SessionAdditionalDirectoriesCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(crate::v1::SessionAdditionalDirectoriesCapabilities {
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+impl IntoV2 for crate::v1::SessionAdditionalDirectoriesCapabilities {
+    type Output = super::SessionAdditionalDirectoriesCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(super::SessionAdditionalDirectoriesCapabilities {
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_session_fork")]
+impl IntoV1 for super::SessionForkCapabilities {
+    type Output = crate::v1::SessionForkCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(crate::v1::SessionForkCapabilities {
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_session_fork")]
+impl IntoV2 for crate::v1::SessionForkCapabilities {
+    type Output = super::SessionForkCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(super::SessionForkCapabilities {
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+impl IntoV1 for super::SessionResumeCapabilities {
+    type Output = crate::v1::SessionResumeCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(crate::v1::SessionResumeCapabilities {
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+impl IntoV2 for crate::v1::SessionResumeCapabilities {
+    type Output = super::SessionResumeCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(super::SessionResumeCapabilities {
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+impl IntoV1 for super::SessionCloseCapabilities {
+    type Output = crate::v1::SessionCloseCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(crate::v1::SessionCloseCapabilities {
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+impl IntoV2 for crate::v1::SessionCloseCapabilities {
+    type Output = super::SessionCloseCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(super::SessionCloseCapabilities {
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+impl IntoV1 for super::PromptCapabilities {
+    type Output = crate::v1::PromptCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            image,
+            audio,
+            embedded_context,
+            #[cfg(feature = "unstable_tool_result_ref")]
+            tool_result_ref,
+            #[cfg(feature = "unstable_mention")]
+            mention,
+            #[cfg(feature = "unstable_reasoning_effort")]
+            reasoning_effort,
+            #[cfg(feature = "unstable_sampling_overrides")]
+            sampling_overrides,
+            meta,
+        } = self;
+        Ok(crate::v1::PromptCapabilities {
+            image: image.is_some(),
+            audio: audio.is_some(),
+            embedded_context: embedded_context.is_some(),
+            #[cfg(feature = "unstable_tool_result_ref")]
+            tool_result_ref: tool_result_ref.is_some(),
+            #[cfg(feature = "unstable_mention")]
+            mention: mention.is_some(),
+            #[cfg(feature = "unstable_reasoning_effort")]
+            reasoning_effort: reasoning_effort.is_some(),
+            #[cfg(feature = "unstable_sampling_overrides")]
+            sampling_overrides: sampling_overrides.is_some(),
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+impl IntoV2 for crate::v1::PromptCapabilities {
+    type Output = super::PromptCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            image,
+            audio,
+            embedded_context,
+            #[cfg(feature = "unstable_tool_result_ref")]
+            tool_result_ref,
+            #[cfg(feature = "unstable_mention")]
+            mention,
+            #[cfg(feature = "unstable_reasoning_effort")]
+            reasoning_effort,
+            #[cfg(feature = "unstable_sampling_overrides")]
+            sampling_overrides,
+            meta,
+        } = self;
+        Ok(super::PromptCapabilities {
+            image: image.then(super::PromptImageCapabilities::new),
+            audio: audio.then(super::PromptAudioCapabilities::new),
+            embedded_context: embedded_context.then(super::PromptEmbeddedContextCapabilities::new),
+            #[cfg(feature = "unstable_tool_result_ref")]
+            tool_result_ref: tool_result_ref.then(super::PromptToolResultRefCapabilities::new),
+            #[cfg(feature = "unstable_mention")]
+            mention: mention.then(super::PromptMentionCapabilities::new),
+            #[cfg(feature = "unstable_reasoning_effort")]
+            reasoning_effort: reasoning_effort.then(super::PromptReasoningEffortCapabilities::new),
+            #[cfg(feature = "unstable_sampling_overrides")]
+            sampling_overrides: sampling_overrides
+                .then(super::PromptSamplingOverridesCapabilities::new),
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+impl IntoV1 for super::McpCapabilities {
+    type Output = crate::v1::McpCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            stdio: _,
+            http,
+            #[cfg(feature = "unstable_mcp_over_acp")]
+            acp,
+            meta,
+        } = self;
+        Ok(crate::v1::McpCapabilities {
+            http: http.is_some(),
+            sse: false,
+            #[cfg(feature = "unstable_mcp_over_acp")]
+            acp: acp.is_some(),
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+impl IntoV2 for crate::v1::McpCapabilities {
+    type Output = super::McpCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            http,
+            sse: _,
+            #[cfg(feature = "unstable_mcp_over_acp")]
+            acp,
+            meta,
+        } = self;
+        Ok(super::McpCapabilities {
+            stdio: Some(super::McpStdioCapabilities::new()),
+            http: http.then(super::McpHttpCapabilities::new),
+            #[cfg(feature = "unstable_mcp_over_acp")]
+            acp: acp.then(super::McpAcpCapabilities::new),
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+impl IntoV1 for super::ClientRequest {
+    type Output = crate::v1::ClientRequest;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        Ok(match self {
+            Self::InitializeRequest(value) => {
+                crate::v1::ClientRequest::InitializeRequest(value.into_v1()?)
+            }
+            Self::LoginAuthRequest(value) => {
+                crate::v1::ClientRequest::AuthenticateRequest(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_llm_providers")]
+            Self::ListProvidersRequest(value) => {
+                crate::v1::ClientRequest::ListProvidersRequest(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_llm_providers")]
+            Self::SetProviderRequest(value) => {
+                crate::v1::ClientRequest::SetProviderRequest(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_llm_providers")]
+            Self::DisableProviderRequest(value) => {
+                crate::v1::ClientRequest::DisableProviderRequest(value.into_v1()?)
+            }
+            Self::LogoutAuthRequest(value) => {
+                crate::v1::ClientRequest::LogoutRequest(value.into_v1()?)
+            }
+            Self::NewSessionRequest(value) => {
+                crate::v1::ClientRequest::NewSessionRequest(value.into_v1()?)
+            }
+            Self::LoadSessionRequest(value) => {
+                crate::v1::ClientRequest::LoadSessionRequest(value.into_v1()?)
+            }
+            Self::ListSessionsRequest(value) => {
+                crate::v1::ClientRequest::ListSessionsRequest(value.into_v1()?)
+            }
+            Self::DeleteSessionRequest(value) => {
+                crate::v1::ClientRequest::DeleteSessionRequest(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_session_fork")]
+            Self::ForkSessionRequest(value) => {
+                crate::v1::ClientRequest::ForkSessionRequest(value.into_v1()?)
+            }
+            Self::ResumeSessionRequest(value) => {
+                crate::v1::ClientRequest::ResumeSessionRequest(value.into_v1()?)
+            }
+            Self::CloseSessionRequest(value) => {
+                crate::v1::ClientRequest::CloseSessionRequest(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_session_compact")]
+            Self::CompactSessionRequest(value) => {
+                crate::v1::ClientRequest::CompactSessionRequest(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_session_set_title")]
+            Self::SetSessionTitleRequest(value) => {
+                crate::v1::ClientRequest::SetSessionTitleRequest(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_cancel_tool_call")]
+            Self::CancelToolCallRequest(value) => {
+                crate::v1::ClientRequest::CancelToolCallRequest(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_command_invocation")]
+            Self::InvokeCommandRequest(value) => {
+                crate::v1::ClientRequest::InvokeCommandRequest(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_input_request")]
+            Self::ProvideInputRequest(value) => {
+                crate::v1::ClientRequest::ProvideInputRequest(value.into_v1()?)
+            }
+            Self::SetSessionConfigOptionRequest(value) => {
+                crate::v1::ClientRequest::SetSessionConfigOptionRequest(value.into_v1()?)
+            }
+            Self::PromptRequest(value) => crate::v1::ClientRequest::PromptRequest(value.into_v1()?),
+            #[cfg(feature = "unstable_nes")]
+            Self::StartNesRequest(value) => {
+                crate::v1::ClientRequest::StartNesRequest(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::SuggestNesRequest(value) => {
+                crate::v1::ClientRequest::SuggestNesRequest(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::CloseNesRequest(value) => {
+                crate::v1::ClientRequest::CloseNesRequest(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_mcp_over_acp")]
+            Self::MessageMcpRequest(value) => {
+                crate::v1::ClientRequest::MessageMcpRequest(value.into_v1()?)
+            }
+            Self::ExtMethodRequest(value) => {
+                crate::v1::ClientRequest::ExtMethodRequest(value.into_v1()?)
+            }
+        })
+    }
+}
+
+impl IntoV2 for crate::v1::ClientRequest {
+    type Output = super::ClientRequest;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        Ok(match self {
+            Self::InitializeRequest(value) => {
+                super::ClientRequest::InitializeRequest(Box::new(value.into_v2()?))
+            }
+            Self::AuthenticateRequest(value) => {
+                super::ClientRequest::LoginAuthRequest(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_llm_providers")]
+            Self::ListProvidersRequest(value) => {
+                super::ClientRequest::ListProvidersRequest(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_llm_providers")]
+            Self::SetProviderRequest(value) => {
+                super::ClientRequest::SetProviderRequest(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_llm_providers")]
+            Self::DisableProviderRequest(value) => {
+                super::ClientRequest::DisableProviderRequest(Box::new(value.into_v2()?))
+            }
+            Self::LogoutRequest(value) => {
+                super::ClientRequest::LogoutAuthRequest(Box::new(value.into_v2()?))
+            }
+            Self::NewSessionRequest(value) => {
+                super::ClientRequest::NewSessionRequest(Box::new(value.into_v2()?))
+            }
+            Self::LoadSessionRequest(value) => {
+                super::ClientRequest::LoadSessionRequest(Box::new(value.into_v2()?))
+            }
+            Self::ListSessionsRequest(value) => {
+                super::ClientRequest::ListSessionsRequest(Box::new(value.into_v2()?))
+            }
+            Self::DeleteSessionRequest(value) => {
+                super::ClientRequest::DeleteSessionRequest(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_session_fork")]
+            Self::ForkSessionRequest(value) => {
+                super::ClientRequest::ForkSessionRequest(Box::new(value.into_v2()?))
+            }
+            Self::ResumeSessionRequest(value) => {
+                super::ClientRequest::ResumeSessionRequest(Box::new(value.into_v2()?))
+            }
+            Self::CloseSessionRequest(value) => {
+                super::ClientRequest::CloseSessionRequest(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_session_compact")]
+            Self::CompactSessionRequest(value) => {
+                super::ClientRequest::CompactSessionRequest(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_session_set_title")]
+            Self::SetSessionTitleRequest(value) => {
+                super::ClientRequest::SetSessionTitleRequest(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_cancel_tool_call")]
+            Self::CancelToolCallRequest(value) => {
+                super::ClientRequest::CancelToolCallRequest(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_command_invocation")]
+            Self::InvokeCommandRequest(value) => {
+                super::ClientRequest::InvokeCommandRequest(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_input_request")]
+            Self::ProvideInputRequest(value) => {
+                super::ClientRequest::ProvideInputRequest(Box::new(value.into_v2()?))
+            }
+            Self::SetSessionModeRequest(_) => {
+                return Err(removed_v1_enum_variant("ClientRequest", "session/set_mode"));
+            }
+            Self::SetSessionConfigOptionRequest(value) => {
+                super::ClientRequest::SetSessionConfigOptionRequest(Box::new(value.into_v2()?))
+            }
+            Self::PromptRequest(value) => {
+                super::ClientRequest::PromptRequest(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::StartNesRequest(value) => {
+                super::ClientRequest::StartNesRequest(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::SuggestNesRequest(value) => {
+                super::ClientRequest::SuggestNesRequest(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::CloseNesRequest(value) => {
+                super::ClientRequest::CloseNesRequest(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_mcp_over_acp")]
+            Self::MessageMcpRequest(value) => {
+                super::ClientRequest::MessageMcpRequest(Box::new(value.into_v2()?))
+            }
+            Self::ExtMethodRequest(value) => {
+                super::ClientRequest::ExtMethodRequest(Box::new(value.into_v2()?))
+            }
+        })
+    }
+}
+
+impl IntoV1 for super::AgentResponse {
+    type Output = crate::v1::AgentResponse;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        Ok(match self {
+            Self::InitializeResponse(value) => {
+                crate::v1::AgentResponse::InitializeResponse(value.into_v1()?)
+            }
+            Self::LoginAuthResponse(value) => {
+                crate::v1::AgentResponse::AuthenticateResponse(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_llm_providers")]
+            Self::ListProvidersResponse(value) => {
+                crate::v1::AgentResponse::ListProvidersResponse(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_llm_providers")]
+            Self::SetProviderResponse(value) => {
+                crate::v1::AgentResponse::SetProviderResponse(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_llm_providers")]
+            Self::DisableProviderResponse(value) => {
+                crate::v1::AgentResponse::DisableProviderResponse(value.into_v1()?)
+            }
+            Self::LogoutAuthResponse(value) => {
+                crate::v1::AgentResponse::LogoutResponse(value.into_v1()?)
+            }
+            Self::NewSessionResponse(value) => {
+                crate::v1::AgentResponse::NewSessionResponse(value.into_v1()?)
+            }
+            Self::LoadSessionResponse(value) => {
+                crate::v1::AgentResponse::LoadSessionResponse(value.into_v1()?)
+            }
+            Self::ListSessionsResponse(value) => {
+                crate::v1::AgentResponse::ListSessionsResponse(value.into_v1()?)
+            }
+            Self::DeleteSessionResponse(value) => {
+                crate::v1::AgentResponse::DeleteSessionResponse(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_session_fork")]
+            Self::ForkSessionResponse(value) => {
+                crate::v1::AgentResponse::ForkSessionResponse(value.into_v1()?)
+            }
+            Self::ResumeSessionResponse(value) => {
+                crate::v1::AgentResponse::ResumeSessionResponse(value.into_v1()?)
+            }
+            Self::CloseSessionResponse(value) => {
+                crate::v1::AgentResponse::CloseSessionResponse(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_session_compact")]
+            Self::CompactSessionResponse(value) => {
+                crate::v1::AgentResponse::CompactSessionResponse(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_session_set_title")]
+            Self::SetSessionTitleResponse(value) => {
+                crate::v1::AgentResponse::SetSessionTitleResponse(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_cancel_tool_call")]
+            Self::CancelToolCallResponse(value) => {
+                crate::v1::AgentResponse::CancelToolCallResponse(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_command_invocation")]
+            Self::InvokeCommandResponse(value) => {
+                crate::v1::AgentResponse::InvokeCommandResponse(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_input_request")]
+            Self::ProvideInputResponse(value) => {
+                crate::v1::AgentResponse::ProvideInputResponse(value.into_v1()?)
+            }
+            Self::SetSessionConfigOptionResponse(value) => {
+                crate::v1::AgentResponse::SetSessionConfigOptionResponse(value.into_v1()?)
+            }
+            Self::PromptResponse(value) => {
+                crate::v1::AgentResponse::PromptResponse(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::StartNesResponse(value) => {
+                crate::v1::AgentResponse::StartNesResponse(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::SuggestNesResponse(value) => {
+                crate::v1::AgentResponse::SuggestNesResponse(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::CloseNesResponse(value) => {
+                crate::v1::AgentResponse::CloseNesResponse(value.into_v1()?)
+            }
+            Self::ExtMethodResponse(value) => {
+                crate::v1::AgentResponse::ExtMethodResponse(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_mcp_over_acp")]
+            Self::MessageMcpResponse(value) => {
+                crate::v1::AgentResponse::MessageMcpResponse(value.into_v1()?)
+            }
+        })
+    }
+}
+
+impl IntoV2 for crate::v1::AgentResponse {
+    type Output = super::AgentResponse;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        Ok(match self {
+            Self::InitializeResponse(value) => {
+                super::AgentResponse::InitializeResponse(Box::new(value.into_v2()?))
+            }
+            Self::AuthenticateResponse(value) => {
+                super::AgentResponse::LoginAuthResponse(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_llm_providers")]
+            Self::ListProvidersResponse(value) => {
+                super::AgentResponse::ListProvidersResponse(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_llm_providers")]
+            Self::SetProviderResponse(value) => {
+                super::AgentResponse::SetProviderResponse(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_llm_providers")]
+            Self::DisableProviderResponse(value) => {
+                super::AgentResponse::DisableProviderResponse(Box::new(value.into_v2()?))
+            }
+            Self::LogoutResponse(value) => {
+                super::AgentResponse::LogoutAuthResponse(Box::new(value.into_v2()?))
+            }
+            Self::NewSessionResponse(value) => {
+                super::AgentResponse::NewSessionResponse(Box::new(value.into_v2()?))
+            }
+            Self::LoadSessionResponse(value) => {
+                super::AgentResponse::LoadSessionResponse(Box::new(value.into_v2()?))
+            }
+            Self::ListSessionsResponse(value) => {
+                super::AgentResponse::ListSessionsResponse(Box::new(value.into_v2()?))
+            }
+            Self::DeleteSessionResponse(value) => {
+                super::AgentResponse::DeleteSessionResponse(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_session_fork")]
+            Self::ForkSessionResponse(value) => {
+                super::AgentResponse::ForkSessionResponse(Box::new(value.into_v2()?))
+            }
+            Self::ResumeSessionResponse(value) => {
+                super::AgentResponse::ResumeSessionResponse(Box::new(value.into_v2()?))
+            }
+            Self::CloseSessionResponse(value) => {
+                super::AgentResponse::CloseSessionResponse(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_session_compact")]
+            Self::CompactSessionResponse(value) => {
+                super::AgentResponse::CompactSessionResponse(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_session_set_title")]
+            Self::SetSessionTitleResponse(value) => {
+                super::AgentResponse::SetSessionTitleResponse(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_cancel_tool_call")]
+            Self::CancelToolCallResponse(value) => {
+                super::AgentResponse::CancelToolCallResponse(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_command_invocation")]
+            Self::InvokeCommandResponse(value) => {
+                super::AgentResponse::InvokeCommandResponse(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_input_request")]
+            Self::ProvideInputResponse(value) => {
+                super::AgentResponse::ProvideInputResponse(Box::new(value.into_v2()?))
+            }
+            Self::SetSessionModeResponse(_) => {
+                return Err(removed_v1_enum_variant("AgentResponse", "session/set_mode"));
+            }
+            Self::SetSessionConfigOptionResponse(value) => {
+                super::AgentResponse::SetSessionConfigOptionResponse(Box::new(value.into_v2()?))
+            }
+            Self::PromptResponse(value) => {
+                super::AgentResponse::PromptResponse(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::StartNesResponse(value) => {
+                super::AgentResponse::StartNesResponse(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::SuggestNesResponse(value) => {
+                super::AgentResponse::SuggestNesResponse(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::CloseNesResponse(value) => {
+                super::AgentResponse::CloseNesResponse(Box::new(value.into_v2()?))
+            }
+            Self::ExtMethodResponse(value) => {
+                super::AgentResponse::ExtMethodResponse(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_mcp_over_acp")]
+            Self::MessageMcpResponse(value) => {
+                super::AgentResponse::MessageMcpResponse(Box::new(value.into_v2()?))
+            }
+        })
+    }
+}
+
+impl IntoV1 for super::ClientNotification {
     type Output = crate::v1::ClientNotification;
 
     fn into_v1(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::CancelSessionNotification(value) => {
-                crate::v1::ClientNotification::CancelNotification(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::DidOpenDocumentNotification(value) => {
-                crate::v1::ClientNotification::DidOpenDocumentNotification(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::DidChangeDocumentNotification(value) => {
-                crate::v1::ClientNotification::DidChangeDocumentNotification(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::DidCloseDocumentNotification(value) => {
-                crate::v1::ClientNotification::DidCloseDocumentNotification(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::DidSaveDocumentNotification(value) => {
-                crate::v1::ClientNotification::DidSaveDocumentNotification(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::DidFocusDocumentNotification(value) => {
-                crate::v1::ClientNotification::DidFocusDocumentNotification(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::AcceptNesNotification(value) => {
-                crate::v1::ClientNotification::AcceptNesNotification(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::RejectNesNotification(value) => {
-                crate::v1::ClientNotification::RejectNesNotification(value.into_v1()?)
-            }
-            #[cfg(feature = "unstable_mcp_over_acp")]
-            Self::MessageMcpNotification(value) => {
-                crate::v1::ClientNotification::MessageMcpNotification(value.into_v1()?)
-            }
-            Self::ExtNotification(value) => {
-                crate::v1::ClientNotification::ExtNotification(value.into_v1()?)
-            }
+            Self::CancelSessionNotification(value) => {
+                crate::v1::ClientNotification::CancelNotification(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::DidOpenDocumentNotification(value) => {
+                crate::v1::ClientNotification::DidOpenDocumentNotification(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::DidChangeDocumentNotification(value) => {
+                crate::v1::ClientNotification::DidChangeDocumentNotification(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::DidCloseDocumentNotification(value) => {
+                crate::v1::ClientNotification::DidCloseDocumentNotification(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::DidSaveDocumentNotification(value) => {
+                crate::v1::ClientNotification::DidSaveDocumentNotification(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::DidFocusDocumentNotification(value) => {
+                crate::v1::ClientNotification::DidFocusDocumentNotification(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::AcceptNesNotification(value) => {
+                crate::v1::ClientNotification::AcceptNesNotification(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::RejectNesNotification(value) => {
+                crate::v1::ClientNotification::RejectNesNotification(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_mcp_over_acp")]
+            Self::MessageMcpNotification(value) => {
+                crate::v1::ClientNotification::MessageMcpNotification(value.into_v1()?)
+            }
+            #[cfg(feature = "unstable_edit_conflict")]
+            Self::ReportEditConflictNotification(value) => {
+                crate::v1::ClientNotification::ReportEditConflictNotification(value.into_v1()?)
+            }
+            Self::ExtNotification(value) => {
+                crate::v1::ClientNotification::ExtNotification(value.into_v1()?)
+            }
+        })
+    }
+}
+
+impl IntoV2 for crate::v1::ClientNotification {
+    type Output = super::ClientNotification;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        Ok(match self {
+            Self::CancelNotification(value) => {
+                super::ClientNotification::CancelSessionNotification(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::DidOpenDocumentNotification(value) => {
+                super::ClientNotification::DidOpenDocumentNotification(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::DidChangeDocumentNotification(value) => {
+                super::ClientNotification::DidChangeDocumentNotification(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::DidCloseDocumentNotification(value) => {
+                super::ClientNotification::DidCloseDocumentNotification(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::DidSaveDocumentNotification(value) => {
+                super::ClientNotification::DidSaveDocumentNotification(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::DidFocusDocumentNotification(value) => {
+                super::ClientNotification::DidFocusDocumentNotification(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::AcceptNesNotification(value) => {
+                super::ClientNotification::AcceptNesNotification(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_nes")]
+            Self::RejectNesNotification(value) => {
+                super::ClientNotification::RejectNesNotification(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_mcp_over_acp")]
+            Self::MessageMcpNotification(value) => {
+                super::ClientNotification::MessageMcpNotification(Box::new(value.into_v2()?))
+            }
+            #[cfg(feature = "unstable_edit_conflict")]
+            Self::ReportEditConflictNotification(value) => {
+                super::ClientNotification::ReportEditConflictNotification(Box::new(
+                    value.into_v2()?,
+                ))
+            }
+            #[cfg(feature = "unstable_fs_watch")]
+            Self::FileChangedNotification(_) => {
+                return Err(removed_v1_enum_variant(
+                    "ClientNotification",
+                    "fs/file_changed",
+                ));
+            }
+            Self::ExtNotification(value) => {
+                super::ClientNotification::ExtNotification(Box::new(value.into_v2()?))
+            }
+        })
+    }
+}
+
+impl IntoV1 for super::CancelSessionNotification {
+    type Output = crate::v1::CancelNotification;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            session_id,
+            #[cfg(feature = "unstable_cancel_reason")]
+            reason,
+            meta,
+        } = self;
+        Ok(crate::v1::CancelNotification {
+            session_id: session_id.into_v1()?,
+            #[cfg(feature = "unstable_cancel_reason")]
+            reason: into_v1_default_on_error(reason),
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+impl IntoV2 for crate::v1::CancelNotification {
+    type Output = super::CancelSessionNotification;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            session_id,
+            #[cfg(feature = "unstable_cancel_reason")]
+            reason,
+            meta,
+        } = self;
+        Ok(super::CancelSessionNotification {
+            session_id: session_id.into_v2()?,
+            #[cfg(feature = "unstable_cancel_reason")]
+            reason: into_v2_default_on_error(reason),
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_edit_conflict")]
+impl IntoV1 for super::ReportEditConflictNotification {
+    type Output = crate::v1::ReportEditConflictNotification;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            session_id,
+            tool_call_id,
+            report,
+            meta,
+        } = self;
+        Ok(crate::v1::ReportEditConflictNotification {
+            session_id: session_id.into_v1()?,
+            tool_call_id: tool_call_id.into_v1()?,
+            report: report.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_edit_conflict")]
+impl IntoV2 for crate::v1::ReportEditConflictNotification {
+    type Output = super::ReportEditConflictNotification;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            session_id,
+            tool_call_id,
+            report,
+            meta,
+        } = self;
+        Ok(super::ReportEditConflictNotification {
+            session_id: session_id.into_v2()?,
+            tool_call_id: tool_call_id.into_v2()?,
+            report: report.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV1 for super::PositionEncodingKind {
+    type Output = crate::v1::PositionEncodingKind;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        Ok(match self {
+            Self::Utf16 => crate::v1::PositionEncodingKind::Utf16,
+            Self::Utf32 => crate::v1::PositionEncodingKind::Utf32,
+            Self::Utf8 => crate::v1::PositionEncodingKind::Utf8,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV2 for crate::v1::PositionEncodingKind {
+    type Output = super::PositionEncodingKind;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        Ok(match self {
+            Self::Utf16 => super::PositionEncodingKind::Utf16,
+            Self::Utf32 => super::PositionEncodingKind::Utf32,
+            Self::Utf8 => super::PositionEncodingKind::Utf8,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV1 for super::Position {
+    type Output = crate::v1::Position;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            line,
+            character,
+            meta,
+        } = self;
+        Ok(crate::v1::Position {
+            line: line.into_v1()?,
+            character: character.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV2 for crate::v1::Position {
+    type Output = super::Position;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            line,
+            character,
+            meta,
+        } = self;
+        Ok(super::Position {
+            line: line.into_v2()?,
+            character: character.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV1 for super::Range {
+    type Output = crate::v1::Range;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { start, end, meta } = self;
+        Ok(crate::v1::Range {
+            start: start.into_v1()?,
+            end: end.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV2 for crate::v1::Range {
+    type Output = super::Range;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { start, end, meta } = self;
+        Ok(super::Range {
+            start: start.into_v2()?,
+            end: end.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV1 for super::NesCapabilities {
+    type Output = crate::v1::NesCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            events,
+            context,
+            meta,
+        } = self;
+        Ok(crate::v1::NesCapabilities {
+            events: into_v1_default_on_error(events),
+            context: into_v1_default_on_error(context),
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV2 for crate::v1::NesCapabilities {
+    type Output = super::NesCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            events,
+            context,
+            meta,
+        } = self;
+        Ok(super::NesCapabilities {
+            events: into_v2_default_on_error(events),
+            context: into_v2_default_on_error(context),
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV1 for super::NesEventCapabilities {
+    type Output = crate::v1::NesEventCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { document, meta } = self;
+        Ok(crate::v1::NesEventCapabilities {
+            document: into_v1_default_on_error(document),
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV2 for crate::v1::NesEventCapabilities {
+    type Output = super::NesEventCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { document, meta } = self;
+        Ok(super::NesEventCapabilities {
+            document: into_v2_default_on_error(document),
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV1 for super::NesDocumentEventCapabilities {
+    type Output = crate::v1::NesDocumentEventCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            did_open,
+            did_change,
+            did_close,
+            did_save,
+            did_focus,
+            meta,
+        } = self;
+        Ok(crate::v1::NesDocumentEventCapabilities {
+            did_open: into_v1_default_on_error(did_open),
+            did_change: into_v1_default_on_error(did_change),
+            did_close: into_v1_default_on_error(did_close),
+            did_save: into_v1_default_on_error(did_save),
+            did_focus: into_v1_default_on_error(did_focus),
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV2 for crate::v1::NesDocumentEventCapabilities {
+    type Output = super::NesDocumentEventCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            did_open,
+            did_change,
+            did_close,
+            did_save,
+            did_focus,
+            meta,
+        } = self;
+        Ok(super::NesDocumentEventCapabilities {
+            did_open: into_v2_default_on_error(did_open),
+            did_change: into_v2_default_on_error(did_change),
+            did_close: into_v2_default_on_error(did_close),
+            did_save: into_v2_default_on_error(did_save),
+            did_focus: into_v2_default_on_error(did_focus),
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV1 for super::NesDocumentDidOpenCapabilities {
+    type Output = crate::v1::NesDocumentDidOpenCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(crate::v1::NesDocumentDidOpenCapabilities {
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV2 for crate::v1::NesDocumentDidOpenCapabilities {
+    type Output = super::NesDocumentDidOpenCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(super::NesDocumentDidOpenCapabilities {
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV1 for super::NesDocumentDidChangeCapabilities {
+    type Output = crate::v1::NesDocumentDidChangeCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { sync_kind, meta } = self;
+        Ok(crate::v1::NesDocumentDidChangeCapabilities {
+            sync_kind: sync_kind.into_v1()?,
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV2 for crate::v1::NesDocumentDidChangeCapabilities {
+    type Output = super::NesDocumentDidChangeCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { sync_kind, meta } = self;
+        Ok(super::NesDocumentDidChangeCapabilities {
+            sync_kind: sync_kind.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV1 for super::TextDocumentSyncKind {
+    type Output = crate::v1::TextDocumentSyncKind;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        Ok(match self {
+            Self::Full => crate::v1::TextDocumentSyncKind::Full,
+            Self::Incremental => crate::v1::TextDocumentSyncKind::Incremental,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV2 for crate::v1::TextDocumentSyncKind {
+    type Output = super::TextDocumentSyncKind;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        Ok(match self {
+            Self::Full => super::TextDocumentSyncKind::Full,
+            Self::Incremental => super::TextDocumentSyncKind::Incremental,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV1 for super::NesDocumentDidCloseCapabilities {
+    type Output = crate::v1::NesDocumentDidCloseCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(crate::v1::NesDocumentDidCloseCapabilities {
+            meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::ClientNotification {
-    type Output = super::ClientNotification;
+#[cfg(feature = "unstable_nes")]
+impl IntoV2 for crate::v1::NesDocumentDidCloseCapabilities {
+    type Output = super::NesDocumentDidCloseCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::CancelNotification(value) => {
-                super::ClientNotification::CancelSessionNotification(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::DidOpenDocumentNotification(value) => {
-                super::ClientNotification::DidOpenDocumentNotification(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::DidChangeDocumentNotification(value) => {
-                super::ClientNotification::DidChangeDocumentNotification(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::DidCloseDocumentNotification(value) => {
-                super::ClientNotification::DidCloseDocumentNotification(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::DidSaveDocumentNotification(value) => {
-                super::ClientNotification::DidSaveDocumentNotification(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::DidFocusDocumentNotification(value) => {
-                super::ClientNotification::DidFocusDocumentNotification(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::AcceptNesNotification(value) => {
-                super::ClientNotification::AcceptNesNotification(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_nes")]
-            Self::RejectNesNotification(value) => {
-                super::ClientNotification::RejectNesNotification(Box::new(value.into_v2()?))
-            }
-            #[cfg(feature = "unstable_mcp_over_acp")]
-            Self::MessageMcpNotification(value) => {
-                super::ClientNotification::MessageMcpNotification(Box::new(value.into_v2()?))
-            }
-            Self::ExtNotification(value) => {
-                super::ClientNotification::ExtNotification(Box::new(value.into_v2()?))
-            }
+        let Self { meta } = self;
+        Ok(super::NesDocumentDidCloseCapabilities {
+            meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::CancelSessionNotification {
-    type Output = crate::v1::CancelNotification;
+#[cfg(feature = "unstable_nes")]
+impl IntoV1 for super::NesDocumentDidSaveCapabilities {
+    type Output = crate::v1::NesDocumentDidSaveCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { session_id, meta } = self;
-        Ok(crate::v1::CancelNotification {
-            session_id: session_id.into_v1()?,
+        let Self { meta } = self;
+        Ok(crate::v1::NesDocumentDidSaveCapabilities {
             meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::CancelNotification {
-    type Output = super::CancelSessionNotification;
+#[cfg(feature = "unstable_nes")]
+impl IntoV2 for crate::v1::NesDocumentDidSaveCapabilities {
+    type Output = super::NesDocumentDidSaveCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { session_id, meta } = self;
-        Ok(super::CancelSessionNotification {
-            session_id: session_id.into_v2()?,
+        let Self { meta } = self;
+        Ok(super::NesDocumentDidSaveCapabilities {
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::PositionEncodingKind {
-    type Output = crate::v1::PositionEncodingKind;
+impl IntoV1 for super::NesDocumentDidFocusCapabilities {
+    type Output = crate::v1::NesDocumentDidFocusCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Utf16 => crate::v1::PositionEncodingKind::Utf16,
-            Self::Utf32 => crate::v1::PositionEncodingKind::Utf32,
-            Self::Utf8 => crate::v1::PositionEncodingKind::Utf8,
+        let Self { meta } = self;
+        Ok(crate::v1::NesDocumentDidFocusCapabilities {
+            meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::PositionEncodingKind {
-    type Output = super::PositionEncodingKind;
+impl IntoV2 for crate::v1::NesDocumentDidFocusCapabilities {
+    type Output = super::NesDocumentDidFocusCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Utf16 => super::PositionEncodingKind::Utf16,
-            Self::Utf32 => super::PositionEncodingKind::Utf32,
-            Self::Utf8 => super::PositionEncodingKind::Utf8,
+        let Self { meta } = self;
+        Ok(super::NesDocumentDidFocusCapabilities {
+            meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::Position {
-    type Output = crate::v1::Position;
+impl IntoV1 for super::NesContextCapabilities {
+    type Output = crate::v1::NesContextCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            line,
-            character,
+            recent_files,
+            related_snippets,
+            edit_history,
+            user_actions,
+            open_files,
+            diagnostics,
             meta,
         } = self;
-        Ok(crate::v1::Position {
-            line: line.into_v1()?,
-            character: character.into_v1()?,
+        Ok(crate::v1::NesContextCapabilities {
+            recent_files: into_v1_default_on_error(recent_files),
+            related_snippets: into_v1_default_on_error(related_snippets),
+            edit_history: into_v1_default_on_error(edit_history),
+            user_actions: into_v1_default_on_error(user_actions),
+            open_files: into_v1_default_on_error(open_files),
+            diagnostics: into_v1_default_on_error(diagnostics),
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::Position {
-    type Output = super::Position;
+impl IntoV2 for crate::v1::NesContextCapabilities {
+    type Output = super::NesContextCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            line,
-            character,
+            recent_files,
+            related_snippets,
+            edit_history,
+            user_actions,
+            open_files,
+            diagnostics,
             meta,
         } = self;
-        Ok(super::Position {
-            line: line.into_v2()?,
-            character: character.into_v2()?,
+        Ok(super::NesContextCapabilities {
+            recent_files: into_v2_default_on_error(recent_files),
+            related_snippets: into_v2_default_on_error(related_snippets),
+            edit_history: into_v2_default_on_error(edit_history),
+            user_actions: into_v2_default_on_error(user_actions),
+            open_files: into_v2_default_on_error(open_files),
+            diagnostics: into_v2_default_on_error(diagnostics),
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::Range {
-    type Output = crate::v1::Range;
+impl IntoV1 for super::NesRecentFilesCapabilities {
+    type Output = crate::v1::NesRecentFilesCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { start, end, meta } = self;
-        Ok(crate::v1::Range {
-            start: start.into_v1()?,
-            end: end.into_v1()?,
+        let Self { max_count, meta } = self;
+        Ok(crate::v1::NesRecentFilesCapabilities {
+            max_count: max_count.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::Range {
-    type Output = super::Range;
+impl IntoV2 for crate::v1::NesRecentFilesCapabilities {
+    type Output = super::NesRecentFilesCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { start, end, meta } = self;
-        Ok(super::Range {
-            start: start.into_v2()?,
-            end: end.into_v2()?,
+        let Self { max_count, meta } = self;
+        Ok(super::NesRecentFilesCapabilities {
+            max_count: max_count.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesCapabilities {
-    type Output = crate::v1::NesCapabilities;
+impl IntoV1 for super::NesRelatedSnippetsCapabilities {
+    type Output = crate::v1::NesRelatedSnippetsCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            events,
-            context,
-            meta,
-        } = self;
-        Ok(crate::v1::NesCapabilities {
-            events: into_v1_default_on_error(events),
-            context: into_v1_default_on_error(context),
+        let Self { meta } = self;
+        Ok(crate::v1::NesRelatedSnippetsCapabilities {
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesCapabilities {
-    type Output = super::NesCapabilities;
+impl IntoV2 for crate::v1::NesRelatedSnippetsCapabilities {
+    type Output = super::NesRelatedSnippetsCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            events,
-            context,
-            meta,
-        } = self;
-        Ok(super::NesCapabilities {
-            events: into_v2_default_on_error(events),
-            context: into_v2_default_on_error(context),
+        let Self { meta } = self;
+        Ok(super::NesRelatedSnippetsCapabilities {
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesEventCapabilities {
-    type Output = crate::v1::NesEventCapabilities;
+impl IntoV1 for super::NesEditHistoryCapabilities {
+    type Output = crate::v1::NesEditHistoryCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { document, meta } = self;
-        Ok(crate::v1::NesEventCapabilities {
-            document: into_v1_default_on_error(document),
+        let Self { max_count, meta } = self;
+        Ok(crate::v1::NesEditHistoryCapabilities {
+            max_count: max_count.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesEventCapabilities {
-    type Output = super::NesEventCapabilities;
+impl IntoV2 for crate::v1::NesEditHistoryCapabilities {
+    type Output = super::NesEditHistoryCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { document, meta } = self;
-        Ok(super::NesEventCapabilities {
-            document: into_v2_default_on_error(document),
+        let Self { max_count, meta } = self;
+        Ok(super::NesEditHistoryCapabilities {
+            max_count: max_count.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesDocumentEventCapabilities {
-    type Output = crate::v1::NesDocumentEventCapabilities;
+impl IntoV1 for super::NesUserActionsCapabilities {
+    type Output = crate::v1::NesUserActionsCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            did_open,
-            did_change,
-            did_close,
-            did_save,
-            did_focus,
-            meta,
-        } = self;
-        Ok(crate::v1::NesDocumentEventCapabilities {
-            did_open: into_v1_default_on_error(did_open),
-            did_change: into_v1_default_on_error(did_change),
-            did_close: into_v1_default_on_error(did_close),
-            did_save: into_v1_default_on_error(did_save),
-            did_focus: into_v1_default_on_error(did_focus),
+        let Self { max_count, meta } = self;
+        Ok(crate::v1::NesUserActionsCapabilities {
+            max_count: max_count.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesDocumentEventCapabilities {
-    type Output = super::NesDocumentEventCapabilities;
+impl IntoV2 for crate::v1::NesUserActionsCapabilities {
+    type Output = super::NesUserActionsCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            did_open,
-            did_change,
-            did_close,
-            did_save,
-            did_focus,
-            meta,
-        } = self;
-        Ok(super::NesDocumentEventCapabilities {
-            did_open: into_v2_default_on_error(did_open),
-            did_change: into_v2_default_on_error(did_change),
-            did_close: into_v2_default_on_error(did_close),
-            did_save: into_v2_default_on_error(did_save),
-            did_focus: into_v2_default_on_error(did_focus),
+        let Self { max_count, meta } = self;
+        Ok(super::NesUserActionsCapabilities {
+            max_count: max_count.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV1 for super::NesOpenFilesCapabilities {
+    type Output = crate::v1::NesOpenFilesCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(crate::v1::NesOpenFilesCapabilities {
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_nes")]
+impl IntoV2 for crate::v1::NesOpenFilesCapabilities {
+    type Output = super::NesOpenFilesCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(super::NesOpenFilesCapabilities {
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesDocumentDidOpenCapabilities {
-    type Output = crate::v1::NesDocumentDidOpenCapabilities;
+impl IntoV1 for super::NesDiagnosticsCapabilities {
+    type Output = crate::v1::NesDiagnosticsCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self { meta } = self;
-        Ok(crate::v1::NesDocumentDidOpenCapabilities {
+        Ok(crate::v1::NesDiagnosticsCapabilities {
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesDocumentDidOpenCapabilities {
-    type Output = super::NesDocumentDidOpenCapabilities;
+impl IntoV2 for crate::v1::NesDiagnosticsCapabilities {
+    type Output = super::NesDiagnosticsCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self { meta } = self;
-        Ok(super::NesDocumentDidOpenCapabilities {
+        Ok(super::NesDiagnosticsCapabilities {
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesDocumentDidChangeCapabilities {
-    type Output = crate::v1::NesDocumentDidChangeCapabilities;
+impl IntoV1 for super::ClientNesCapabilities {
+    type Output = crate::v1::ClientNesCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { sync_kind, meta } = self;
-        Ok(crate::v1::NesDocumentDidChangeCapabilities {
-            sync_kind: sync_kind.into_v1()?,
+        let Self {
+            jump,
+            rename,
+            search_and_replace,
+            meta,
+        } = self;
+        Ok(crate::v1::ClientNesCapabilities {
+            jump: into_v1_default_on_error(jump),
+            rename: into_v1_default_on_error(rename),
+            search_and_replace: into_v1_default_on_error(search_and_replace),
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesDocumentDidChangeCapabilities {
-    type Output = super::NesDocumentDidChangeCapabilities;
+impl IntoV2 for crate::v1::ClientNesCapabilities {
+    type Output = super::ClientNesCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { sync_kind, meta } = self;
-        Ok(super::NesDocumentDidChangeCapabilities {
-            sync_kind: sync_kind.into_v2()?,
+        let Self {
+            jump,
+            rename,
+            search_and_replace,
+            meta,
+        } = self;
+        Ok(super::ClientNesCapabilities {
+            jump: into_v2_default_on_error(jump),
+            rename: into_v2_default_on_error(rename),
+            search_and_replace: into_v2_default_on_error(search_and_replace),
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::TextDocumentSyncKind {
-    type Output = crate::v1::TextDocumentSyncKind;
+impl IntoV1 for super::NesJumpCapabilities {
+    type Output = crate::v1::NesJumpCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Full => crate::v1::TextDocumentSyncKind::Full,
-            Self::Incremental => crate::v1::TextDocumentSyncKind::Incremental,
+        let Self { meta } = self;
+        Ok(crate::v1::NesJumpCapabilities {
+            meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::TextDocumentSyncKind {
-    type Output = super::TextDocumentSyncKind;
+impl IntoV2 for crate::v1::NesJumpCapabilities {
+    type Output = super::NesJumpCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Full => super::TextDocumentSyncKind::Full,
-            Self::Incremental => super::TextDocumentSyncKind::Incremental,
+        let Self { meta } = self;
+        Ok(super::NesJumpCapabilities {
+            meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesDocumentDidCloseCapabilities {
-    type Output = crate::v1::NesDocumentDidCloseCapabilities;
+impl IntoV1 for super::NesRenameCapabilities {
+    type Output = crate::v1::NesRenameCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self { meta } = self;
-        Ok(crate::v1::NesDocumentDidCloseCapabilities {
+        Ok(crate::v1::NesRenameCapabilities {
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesDocumentDidCloseCapabilities {
-    type Output = super::NesDocumentDidCloseCapabilities;
+impl IntoV2 for crate::v1::NesRenameCapabilities {
+    type Output = super::NesRenameCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self { meta } = self;
-        Ok(super::NesDocumentDidCloseCapabilities {
+        Ok(super::NesRenameCapabilities {
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesDocumentDidSaveCapabilities {
-    type Output = crate::v1::NesDocumentDidSaveCapabilities;
+impl IntoV1 for super::NesSearchAndReplaceCapabilities {
+    type Output = crate::v1::NesSearchAndReplaceCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self { meta } = self;
-        Ok(crate::v1::NesDocumentDidSaveCapabilities {
+        Ok(crate::v1::NesSearchAndReplaceCapabilities {
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesDocumentDidSaveCapabilities {
-    type Output = super::NesDocumentDidSaveCapabilities;
+impl IntoV2 for crate::v1::NesSearchAndReplaceCapabilities {
+    type Output = super::NesSearchAndReplaceCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self { meta } = self;
-        Ok(super::NesDocumentDidSaveCapabilities {
+        Ok(super::NesSearchAndReplaceCapabilities {
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesDocumentDidFocusCapabilities {
-    type Output = crate::v1::NesDocumentDidFocusCapabilities;
+impl IntoV1 for super::DidOpenDocumentNotification {
+    type Output = crate::v1::DidOpenDocumentNotification;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::NesDocumentDidFocusCapabilities {
+        let Self {
+            session_id,
+            uri,
+            language_id,
+            version,
+            text,
+            meta,
+        } = self;
+        Ok(crate::v1::DidOpenDocumentNotification {
+            session_id: session_id.into_v1()?,
+            uri: uri.into_v1()?,
+            language_id: language_id.into_v1()?,
+            version: version.into_v1()?,
+            text: text.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesDocumentDidFocusCapabilities {
-    type Output = super::NesDocumentDidFocusCapabilities;
+impl IntoV2 for crate::v1::DidOpenDocumentNotification {
+    type Output = super::DidOpenDocumentNotification;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::NesDocumentDidFocusCapabilities {
+        let Self {
+            session_id,
+            uri,
+            language_id,
+            version,
+            text,
+            meta,
+        } = self;
+        Ok(super::DidOpenDocumentNotification {
+            session_id: session_id.into_v2()?,
+            uri: uri.into_v2()?,
+            language_id: language_id.into_v2()?,
+            version: version.into_v2()?,
+            text: text.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesContextCapabilities {
-    type Output = crate::v1::NesContextCapabilities;
+impl IntoV1 for super::DidChangeDocumentNotification {
+    type Output = crate::v1::DidChangeDocumentNotification;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            recent_files,
-            related_snippets,
-            edit_history,
-            user_actions,
-            open_files,
-            diagnostics,
+            session_id,
+            uri,
+            version,
+            content_changes,
             meta,
         } = self;
-        Ok(crate::v1::NesContextCapabilities {
-            recent_files: into_v1_default_on_error(recent_files),
-            related_snippets: into_v1_default_on_error(related_snippets),
-            edit_history: into_v1_default_on_error(edit_history),
-            user_actions: into_v1_default_on_error(user_actions),
-            open_files: into_v1_default_on_error(open_files),
-            diagnostics: into_v1_default_on_error(diagnostics),
+        Ok(crate::v1::DidChangeDocumentNotification {
+            session_id: session_id.into_v1()?,
+            uri: uri.into_v1()?,
+            version: version.into_v1()?,
+            content_changes: content_changes.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesContextCapabilities {
-    type Output = super::NesContextCapabilities;
+impl IntoV2 for crate::v1::DidChangeDocumentNotification {
+    type Output = super::DidChangeDocumentNotification;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            recent_files,
-            related_snippets,
-            edit_history,
-            user_actions,
-            open_files,
-            diagnostics,
+            session_id,
+            uri,
+            version,
+            content_changes,
             meta,
         } = self;
-        Ok(super::NesContextCapabilities {
-            recent_files: into_v2_default_on_error(recent_files),
-            related_snippets: into_v2_default_on_error(related_snippets),
-            edit_history: into_v2_default_on_error(edit_history),
-            user_actions: into_v2_default_on_error(user_actions),
-            open_files: into_v2_default_on_error(open_files),
-            diagnostics: into_v2_default_on_error(diagnostics),
+        Ok(super::DidChangeDocumentNotification {
+            session_id: session_id.into_v2()?,
+            uri: uri.into_v2()?,
+            version: version.into_v2()?,
+            content_changes: content_changes.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesRecentFilesCapabilities {
-    type Output = crate::v1::NesRecentFilesCapabilities;
+impl IntoV1 for super::TextDocumentContentChangeEvent {
+    type Output = crate::v1::TextDocumentContentChangeEvent;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { max_count, meta } = self;
-        Ok(crate::v1::NesRecentFilesCapabilities {
-            max_count: max_count.into_v1()?,
+        let Self { range, text, meta } = self;
+        Ok(crate::v1::TextDocumentContentChangeEvent {
+            range: range.into_v1()?,
+            text: text.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesRecentFilesCapabilities {
-    type Output = super::NesRecentFilesCapabilities;
+impl IntoV2 for crate::v1::TextDocumentContentChangeEvent {
+    type Output = super::TextDocumentContentChangeEvent;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { max_count, meta } = self;
-        Ok(super::NesRecentFilesCapabilities {
-            max_count: max_count.into_v2()?,
+        let Self { range, text, meta } = self;
+        Ok(super::TextDocumentContentChangeEvent {
+            range: range.into_v2()?,
+            text: text.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesRelatedSnippetsCapabilities {
-    type Output = crate::v1::NesRelatedSnippetsCapabilities;
+impl IntoV1 for super::DidCloseDocumentNotification {
+    type Output = crate::v1::DidCloseDocumentNotification;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::NesRelatedSnippetsCapabilities {
+        let Self {
+            session_id,
+            uri,
+            meta,
+        } = self;
+        Ok(crate::v1::DidCloseDocumentNotification {
+            session_id: session_id.into_v1()?,
+            uri: uri.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesRelatedSnippetsCapabilities {
-    type Output = super::NesRelatedSnippetsCapabilities;
+impl IntoV2 for crate::v1::DidCloseDocumentNotification {
+    type Output = super::DidCloseDocumentNotification;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::NesRelatedSnippetsCapabilities {
+        let Self {
+            session_id,
+            uri,
+            meta,
+        } = self;
+        Ok(super::DidCloseDocumentNotification {
+            session_id: session_id.into_v2()?,
+            uri: uri.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesEditHistoryCapabilities {
-    type Output = crate::v1::NesEditHistoryCapabilities;
+impl IntoV1 for super::DidSaveDocumentNotification {
+    type Output = crate::v1::DidSaveDocumentNotification;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { max_count, meta } = self;
-        Ok(crate::v1::NesEditHistoryCapabilities {
-            max_count: max_count.into_v1()?,
+        let Self {
+            session_id,
+            uri,
+            meta,
+        } = self;
+        Ok(crate::v1::DidSaveDocumentNotification {
+            session_id: session_id.into_v1()?,
+            uri: uri.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesEditHistoryCapabilities {
-    type Output = super::NesEditHistoryCapabilities;
+impl IntoV2 for crate::v1::DidSaveDocumentNotification {
+    type Output = super::DidSaveDocumentNotification;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { max_count, meta } = self;
-        Ok(super::NesEditHistoryCapabilities {
-            max_count: max_count.into_v2()?,
+        let Self {
+            session_id,
+            uri,
+            meta,
+        } = self;
+        Ok(super::DidSaveDocumentNotification {
+            session_id: session_id.into_v2()?,
+            uri: uri.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesUserActionsCapabilities {
-    type Output = crate::v1::NesUserActionsCapabilities;
+impl IntoV1 for super::DidFocusDocumentNotification {
+    type Output = crate::v1::DidFocusDocumentNotification;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { max_count, meta } = self;
-        Ok(crate::v1::NesUserActionsCapabilities {
-            max_count: max_count.into_v1()?,
+        let Self {
+            session_id,
+            uri,
+            version,
+            position,
+            visible_range,
+            meta,
+        } = self;
+        Ok(crate::v1::DidFocusDocumentNotification {
+            session_id: session_id.into_v1()?,
+            uri: uri.into_v1()?,
+            version: version.into_v1()?,
+            position: position.into_v1()?,
+            visible_range: visible_range.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesUserActionsCapabilities {
-    type Output = super::NesUserActionsCapabilities;
+impl IntoV2 for crate::v1::DidFocusDocumentNotification {
+    type Output = super::DidFocusDocumentNotification;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { max_count, meta } = self;
-        Ok(super::NesUserActionsCapabilities {
-            max_count: max_count.into_v2()?,
+        let Self {
+            session_id,
+            uri,
+            version,
+            position,
+            visible_range,
+            meta,
+        } = self;
+        Ok(super::DidFocusDocumentNotification {
+            session_id: session_id.into_v2()?,
+            uri: uri.into_v2()?,
+            version: version.into_v2()?,
+            position: position.into_v2()?,
+            visible_range: visible_range.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesOpenFilesCapabilities {
-    type Output = crate::v1::NesOpenFilesCapabilities;
+impl IntoV1 for super::StartNesRequest {
+    type Output = crate::v1::StartNesRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::NesOpenFilesCapabilities {
+        let Self {
+            workspace_uri,
+            workspace_folders,
+            repository,
+            meta,
+        } = self;
+        Ok(crate::v1::StartNesRequest {
+            workspace_uri: workspace_uri.into_v1()?,
+            workspace_folders: option_vec_into_v1_skip_errors(workspace_folders),
+            repository: into_v1_default_on_error(repository),
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesOpenFilesCapabilities {
-    type Output = super::NesOpenFilesCapabilities;
+impl IntoV2 for crate::v1::StartNesRequest {
+    type Output = super::StartNesRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::NesOpenFilesCapabilities {
+        let Self {
+            workspace_uri,
+            workspace_folders,
+            repository,
+            meta,
+        } = self;
+        Ok(super::StartNesRequest {
+            workspace_uri: workspace_uri.into_v2()?,
+            workspace_folders: option_vec_into_v2_skip_errors(workspace_folders),
+            repository: into_v2_default_on_error(repository),
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesDiagnosticsCapabilities {
-    type Output = crate::v1::NesDiagnosticsCapabilities;
+impl IntoV1 for super::WorkspaceFolder {
+    type Output = crate::v1::WorkspaceFolder;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::NesDiagnosticsCapabilities {
+        let Self { uri, name, meta } = self;
+        Ok(crate::v1::WorkspaceFolder {
+            uri: uri.into_v1()?,
+            name: name.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesDiagnosticsCapabilities {
-    type Output = super::NesDiagnosticsCapabilities;
+impl IntoV2 for crate::v1::WorkspaceFolder {
+    type Output = super::WorkspaceFolder;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::NesDiagnosticsCapabilities {
+        let Self { uri, name, meta } = self;
+        Ok(super::WorkspaceFolder {
+            uri: uri.into_v2()?,
+            name: name.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::ClientNesCapabilities {
-    type Output = crate::v1::ClientNesCapabilities;
+impl IntoV1 for super::NesRepository {
+    type Output = crate::v1::NesRepository;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            jump,
-            rename,
-            search_and_replace,
+            name,
+            owner,
+            remote_url,
             meta,
         } = self;
-        Ok(crate::v1::ClientNesCapabilities {
-            jump: into_v1_default_on_error(jump),
-            rename: into_v1_default_on_error(rename),
-            search_and_replace: into_v1_default_on_error(search_and_replace),
+        Ok(crate::v1::NesRepository {
+            name: name.into_v1()?,
+            owner: owner.into_v1()?,
+            remote_url: remote_url.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::ClientNesCapabilities {
-    type Output = super::ClientNesCapabilities;
+impl IntoV2 for crate::v1::NesRepository {
+    type Output = super::NesRepository;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            jump,
-            rename,
-            search_and_replace,
+            name,
+            owner,
+            remote_url,
             meta,
         } = self;
-        Ok(super::ClientNesCapabilities {
-            jump: into_v2_default_on_error(jump),
-            rename: into_v2_default_on_error(rename),
-            search_and_replace: into_v2_default_on_error(search_and_replace),
+        Ok(super::NesRepository {
+            name: name.into_v2()?,
+            owner: owner.into_v2()?,
+            remote_url: remote_url.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesJumpCapabilities {
-    type Output = crate::v1::NesJumpCapabilities;
+impl IntoV1 for super::StartNesResponse {
+    type Output = crate::v1::StartNesResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::NesJumpCapabilities {
+        let Self { session_id, meta } = self;
+        Ok(crate::v1::StartNesResponse {
+            session_id: session_id.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesJumpCapabilities {
-    type Output = super::NesJumpCapabilities;
+impl IntoV2 for crate::v1::StartNesResponse {
+    type Output = super::StartNesResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::NesJumpCapabilities {
+        let Self { session_id, meta } = self;
+        Ok(super::StartNesResponse {
+            session_id: session_id.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesRenameCapabilities {
-    type Output = crate::v1::NesRenameCapabilities;
+impl IntoV1 for super::CloseNesRequest {
+    type Output = crate::v1::CloseNesRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::NesRenameCapabilities {
+        let Self { session_id, meta } = self;
+        Ok(crate::v1::CloseNesRequest {
+            session_id: session_id.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesRenameCapabilities {
-    type Output = super::NesRenameCapabilities;
+impl IntoV2 for crate::v1::CloseNesRequest {
+    type Output = super::CloseNesRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::NesRenameCapabilities {
+        let Self { session_id, meta } = self;
+        Ok(super::CloseNesRequest {
+            session_id: session_id.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesSearchAndReplaceCapabilities {
-    type Output = crate::v1::NesSearchAndReplaceCapabilities;
+impl IntoV1 for super::CloseNesResponse {
+    type Output = crate::v1::CloseNesResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self { meta } = self;
-        Ok(crate::v1::NesSearchAndReplaceCapabilities {
+        Ok(crate::v1::CloseNesResponse {
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesSearchAndReplaceCapabilities {
-    type Output = super::NesSearchAndReplaceCapabilities;
+impl IntoV2 for crate::v1::CloseNesResponse {
+    type Output = super::CloseNesResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self { meta } = self;
-        Ok(super::NesSearchAndReplaceCapabilities {
+        Ok(super::CloseNesResponse {
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::DidOpenDocumentNotification {
-    type Output = crate::v1::DidOpenDocumentNotification;
+impl IntoV1 for super::NesTriggerKind {
+    type Output = crate::v1::NesTriggerKind;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            session_id,
-            uri,
-            language_id,
-            version,
-            text,
-            meta,
-        } = self;
-        Ok(crate::v1::DidOpenDocumentNotification {
-            session_id: session_id.into_v1()?,
-            uri: uri.into_v1()?,
-            language_id: language_id.into_v1()?,
-            version: version.into_v1()?,
-            text: text.into_v1()?,
-            meta: meta.into_v1()?,
+        Ok(match self {
+            Self::Automatic => crate::v1::NesTriggerKind::Automatic,
+            Self::Diagnostic => crate::v1::NesTriggerKind::Diagnostic,
+            Self::Manual => crate::v1::NesTriggerKind::Manual,
+            Self::Other(value) => return Err(unknown_v2_enum_variant("NesTriggerKind", &value)),
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::DidOpenDocumentNotification {
-    type Output = super::DidOpenDocumentNotification;
+impl IntoV2 for crate::v1::NesTriggerKind {
+    type Output = super::NesTriggerKind;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            session_id,
-            uri,
-            language_id,
-            version,
-            text,
-            meta,
-        } = self;
-        Ok(super::DidOpenDocumentNotification {
-            session_id: session_id.into_v2()?,
-            uri: uri.into_v2()?,
-            language_id: language_id.into_v2()?,
-            version: version.into_v2()?,
-            text: text.into_v2()?,
-            meta: meta.into_v2()?,
+        Ok(match self {
+            Self::Automatic => super::NesTriggerKind::Automatic,
+            Self::Diagnostic => super::NesTriggerKind::Diagnostic,
+            Self::Manual => super::NesTriggerKind::Manual,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::DidChangeDocumentNotification {
-    type Output = crate::v1::DidChangeDocumentNotification;
+impl IntoV1 for super::SuggestNesRequest {
+    type Output = crate::v1::SuggestNesRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
             session_id,
             uri,
             version,
-            content_changes,
+            position,
+            selection,
+            trigger_kind,
+            context,
             meta,
         } = self;
-        Ok(crate::v1::DidChangeDocumentNotification {
+        Ok(crate::v1::SuggestNesRequest {
             session_id: session_id.into_v1()?,
             uri: uri.into_v1()?,
             version: version.into_v1()?,
-            content_changes: content_changes.into_v1()?,
+            position: position.into_v1()?,
+            selection: into_v1_default_on_error(selection),
+            trigger_kind: trigger_kind.into_v1()?,
+            context: into_v1_default_on_error(context),
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::DidChangeDocumentNotification {
-    type Output = super::DidChangeDocumentNotification;
+impl IntoV2 for crate::v1::SuggestNesRequest {
+    type Output = super::SuggestNesRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
             session_id,
             uri,
             version,
-            content_changes,
+            position,
+            selection,
+            trigger_kind,
+            context,
             meta,
         } = self;
-        Ok(super::DidChangeDocumentNotification {
+        Ok(super::SuggestNesRequest {
             session_id: session_id.into_v2()?,
             uri: uri.into_v2()?,
             version: version.into_v2()?,
-            content_changes: content_changes.into_v2()?,
-            meta: meta.into_v2()?,
-        })
-    }
-}
-
-#[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::TextDocumentContentChangeEvent {
-    type Output = crate::v1::TextDocumentContentChangeEvent;
-
-    fn into_v1(self) -> Result<Self::Output> {
-        let Self { range, text, meta } = self;
-        Ok(crate::v1::TextDocumentContentChangeEvent {
-            range: range.into_v1()?,
-            text: text.into_v1()?,
-            meta: meta.into_v1()?,
-        })
-    }
-}
-
-#[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::TextDocumentContentChangeEvent {
-    type Output = super::TextDocumentContentChangeEvent;
-
-    fn into_v2(self) -> Result<Self::Output> {
-        let Self { range, text, meta } = self;
-        Ok(super::TextDocumentContentChangeEvent {
-            range: range.into_v2()?,
-            text: text.into_v2()?,
+            position: position.into_v2()?,
+            selection: into_v2_default_on_error(selection),
+            trigger_kind: trigger_kind.into_v2()?,
+            context: into_v2_default_on_error(context),
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::DidCloseDocumentNotification {
-    type Output = crate::v1::DidCloseDocumentNotification;
+impl IntoV1 for super::NesSuggestContext {
+    type Output = crate::v1::NesSuggestContext;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            session_id,
-            uri,
+            recent_files,
+            related_snippets,
+            edit_history,
+            user_actions,
+            open_files,
+            diagnostics,
             meta,
         } = self;
-        Ok(crate::v1::DidCloseDocumentNotification {
-            session_id: session_id.into_v1()?,
-            uri: uri.into_v1()?,
+        Ok(crate::v1::NesSuggestContext {
+            recent_files: option_vec_into_v1_skip_errors(recent_files),
+            related_snippets: option_vec_into_v1_skip_errors(related_snippets),
+            edit_history: option_vec_into_v1_skip_errors(edit_history),
+            user_actions: option_vec_into_v1_skip_errors(user_actions),
+            open_files: option_vec_into_v1_skip_errors(open_files),
+            diagnostics: option_vec_into_v1_skip_errors(diagnostics),
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::DidCloseDocumentNotification {
-    type Output = super::DidCloseDocumentNotification;
+impl IntoV2 for crate::v1::NesSuggestContext {
+    type Output = super::NesSuggestContext;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            session_id,
-            uri,
+            recent_files,
+            related_snippets,
+            edit_history,
+            user_actions,
+            open_files,
+            diagnostics,
             meta,
         } = self;
-        Ok(super::DidCloseDocumentNotification {
-            session_id: session_id.into_v2()?,
-            uri: uri.into_v2()?,
+        Ok(super::NesSuggestContext {
+            recent_files: option_vec_into_v2_skip_errors(recent_files),
+            related_snippets: option_vec_into_v2_skip_errors(related_snippets),
+            edit_history: option_vec_into_v2_skip_errors(edit_history),
+            user_actions: option_vec_into_v2_skip_errors(user_actions),
+            open_files: option_vec_into_v2_skip_errors(open_files),
+            diagnostics: option_vec_into_v2_skip_errors(diagnostics),
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::DidSaveDocumentNotification {
-    type Output = crate::v1::DidSaveDocumentNotification;
+impl IntoV1 for super::NesRecentFile {
+    type Output = crate::v1::NesRecentFile;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            session_id,
             uri,
+            language_id,
+            text,
             meta,
         } = self;
-        Ok(crate::v1::DidSaveDocumentNotification {
-            session_id: session_id.into_v1()?,
+        Ok(crate::v1::NesRecentFile {
             uri: uri.into_v1()?,
+            language_id: language_id.into_v1()?,
+            text: text.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::DidSaveDocumentNotification {
-    type Output = super::DidSaveDocumentNotification;
+impl IntoV2 for crate::v1::NesRecentFile {
+    type Output = super::NesRecentFile;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            session_id,
             uri,
+            language_id,
+            text,
             meta,
         } = self;
-        Ok(super::DidSaveDocumentNotification {
-            session_id: session_id.into_v2()?,
+        Ok(super::NesRecentFile {
             uri: uri.into_v2()?,
+            language_id: language_id.into_v2()?,
+            text: text.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::DidFocusDocumentNotification {
-    type Output = crate::v1::DidFocusDocumentNotification;
+impl IntoV1 for super::NesRelatedSnippet {
+    type Output = crate::v1::NesRelatedSnippet;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            session_id,
             uri,
-            version,
-            position,
-            visible_range,
+            excerpts,
             meta,
         } = self;
-        Ok(crate::v1::DidFocusDocumentNotification {
-            session_id: session_id.into_v1()?,
+        Ok(crate::v1::NesRelatedSnippet {
             uri: uri.into_v1()?,
-            version: version.into_v1()?,
-            position: position.into_v1()?,
-            visible_range: visible_range.into_v1()?,
+            excerpts: excerpts.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::DidFocusDocumentNotification {
-    type Output = super::DidFocusDocumentNotification;
+impl IntoV2 for crate::v1::NesRelatedSnippet {
+    type Output = super::NesRelatedSnippet;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            session_id,
             uri,
-            version,
-            position,
-            visible_range,
+            excerpts,
             meta,
         } = self;
-        Ok(super::DidFocusDocumentNotification {
-            session_id: session_id.into_v2()?,
+        Ok(super::NesRelatedSnippet {
             uri: uri.into_v2()?,
-            version: version.into_v2()?,
-            position: position.into_v2()?,
-            visible_range: visible_range.into_v2()?,
+            excerpts: excerpts.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::StartNesRequest {
-    type Output = crate::v1::StartNesRequest;
+impl IntoV1 for super::NesExcerpt {
+    type Output = crate::v1::NesExcerpt;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            workspace_uri,
-            workspace_folders,
-            repository,
+            start_line,
+            end_line,
+            text,
             meta,
         } = self;
-        Ok(crate::v1::StartNesRequest {
-            workspace_uri: workspace_uri.into_v1()?,
-            workspace_folders: option_vec_into_v1_skip_errors(workspace_folders),
-            repository: into_v1_default_on_error(repository),
+        Ok(crate::v1::NesExcerpt {
+            start_line: start_line.into_v1()?,
+            end_line: end_line.into_v1()?,
+            text: text.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::StartNesRequest {
-    type Output = super::StartNesRequest;
+impl IntoV2 for crate::v1::NesExcerpt {
+    type Output = super::NesExcerpt;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            workspace_uri,
-            workspace_folders,
-            repository,
+            start_line,
+            end_line,
+            text,
             meta,
         } = self;
-        Ok(super::StartNesRequest {
-            workspace_uri: workspace_uri.into_v2()?,
-            workspace_folders: option_vec_into_v2_skip_errors(workspace_folders),
-            repository: into_v2_default_on_error(repository),
+        Ok(super::NesExcerpt {
+            start_line: start_line.into_v2()?,
+            end_line: end_line.into_v2()?,
+            text: text.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::WorkspaceFolder {
-    type Output = crate::v1::WorkspaceFolder;
+impl IntoV1 for super::NesEditHistoryEntry {
+    type Output = crate::v1::NesEditHistoryEntry;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { uri, name, meta } = self;
-        Ok(crate::v1::WorkspaceFolder {
+        let Self { uri, diff, meta } = self;
+        Ok(crate::v1::NesEditHistoryEntry {
             uri: uri.into_v1()?,
-            name: name.into_v1()?,
+            diff: diff.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::WorkspaceFolder {
-    type Output = super::WorkspaceFolder;
+impl IntoV2 for crate::v1::NesEditHistoryEntry {
+    type Output = super::NesEditHistoryEntry;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { uri, name, meta } = self;
-        Ok(super::WorkspaceFolder {
+        let Self { uri, diff, meta } = self;
+        Ok(super::NesEditHistoryEntry {
             uri: uri.into_v2()?,
-            name: name.into_v2()?,
+            diff: diff.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesRepository {
-    type Output = crate::v1::NesRepository;
+impl IntoV1 for super::NesUserAction {
+    type Output = crate::v1::NesUserAction;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            name,
-            owner,
-            remote_url,
+            action,
+            uri,
+            position,
+            timestamp_ms,
             meta,
         } = self;
-        Ok(crate::v1::NesRepository {
-            name: name.into_v1()?,
-            owner: owner.into_v1()?,
-            remote_url: remote_url.into_v1()?,
+        Ok(crate::v1::NesUserAction {
+            action: action.into_v1()?,
+            uri: uri.into_v1()?,
+            position: position.into_v1()?,
+            timestamp_ms: timestamp_ms.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesRepository {
-    type Output = super::NesRepository;
+impl IntoV2 for crate::v1::NesUserAction {
+    type Output = super::NesUserAction;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            name,
-            owner,
-            remote_url,
+            action,
+            uri,
+            position,
+            timestamp_ms,
             meta,
         } = self;
-        Ok(super::NesRepository {
-            name: name.into_v2()?,
-            owner: owner.into_v2()?,
-            remote_url: remote_url.into_v2()?,
+        Ok(super::NesUserAction {
+            action: action.into_v2()?,
+            uri: uri.into_v2()?,
+            position: position.into_v2()?,
+            timestamp_ms: timestamp_ms.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::StartNesResponse {
-    type Output = crate::v1::StartNesResponse;
+impl IntoV1 for super::NesOpenFile {
+    type Output = crate::v1::NesOpenFile;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { session_id, meta } = self;
-        Ok(crate::v1::StartNesResponse {
-            session_id: session_id.into_v1()?,
+        let Self {
+            uri,
+            language_id,
+            visible_range,
+            last_focused_ms,
+            meta,
+        } = self;
+        Ok(crate::v1::NesOpenFile {
+            uri: uri.into_v1()?,
+            language_id: language_id.into_v1()?,
+            visible_range: into_v1_default_on_error(visible_range),
+            last_focused_ms: into_v1_default_on_error(last_focused_ms),
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::StartNesResponse {
-    type Output = super::StartNesResponse;
+impl IntoV2 for crate::v1::NesOpenFile {
+    type Output = super::NesOpenFile;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { session_id, meta } = self;
-        Ok(super::StartNesResponse {
-            session_id: session_id.into_v2()?,
+        let Self {
+            uri,
+            language_id,
+            visible_range,
+            last_focused_ms,
+            meta,
+        } = self;
+        Ok(super::NesOpenFile {
+            uri: uri.into_v2()?,
+            language_id: language_id.into_v2()?,
+            visible_range: into_v2_default_on_error(visible_range),
+            last_focused_ms: into_v2_default_on_error(last_focused_ms),
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::CloseNesRequest {
-    type Output = crate::v1::CloseNesRequest;
+impl IntoV1 for super::NesDiagnostic {
+    type Output = crate::v1::NesDiagnostic;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { session_id, meta } = self;
-        Ok(crate::v1::CloseNesRequest {
-            session_id: session_id.into_v1()?,
+        let Self {
+            uri,
+            range,
+            severity,
+            message,
+            meta,
+        } = self;
+        Ok(crate::v1::NesDiagnostic {
+            uri: uri.into_v1()?,
+            range: range.into_v1()?,
+            severity: severity.into_v1()?,
+            message: message.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::CloseNesRequest {
-    type Output = super::CloseNesRequest;
+impl IntoV2 for crate::v1::NesDiagnostic {
+    type Output = super::NesDiagnostic;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { session_id, meta } = self;
-        Ok(super::CloseNesRequest {
-            session_id: session_id.into_v2()?,
+        let Self {
+            uri,
+            range,
+            severity,
+            message,
+            meta,
+        } = self;
+        Ok(super::NesDiagnostic {
+            uri: uri.into_v2()?,
+            range: range.into_v2()?,
+            severity: severity.into_v2()?,
+            message: message.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::CloseNesResponse {
-    type Output = crate::v1::CloseNesResponse;
+impl IntoV1 for super::NesDiagnosticSeverity {
+    type Output = crate::v1::NesDiagnosticSeverity;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::CloseNesResponse {
-            meta: meta.into_v1()?,
+        Ok(match self {
+            Self::Error => crate::v1::NesDiagnosticSeverity::Error,
+            Self::Warning => crate::v1::NesDiagnosticSeverity::Warning,
+            Self::Information => crate::v1::NesDiagnosticSeverity::Information,
+            Self::Hint => crate::v1::NesDiagnosticSeverity::Hint,
+            Self::Other(value) => {
+                return Err(unknown_v2_enum_variant("NesDiagnosticSeverity", &value));
+            }
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::CloseNesResponse {
-    type Output = super::CloseNesResponse;
+impl IntoV2 for crate::v1::NesDiagnosticSeverity {
+    type Output = super::NesDiagnosticSeverity;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::CloseNesResponse {
-            meta: meta.into_v2()?,
+        Ok(match self {
+            Self::Error => super::NesDiagnosticSeverity::Error,
+            Self::Warning => super::NesDiagnosticSeverity::Warning,
+            Self::Information => super::NesDiagnosticSeverity::Information,
+            Self::Hint => super::NesDiagnosticSeverity::Hint,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesTriggerKind {
-    type Output = crate::v1::NesTriggerKind;
+impl IntoV1 for super::SuggestNesResponse {
+    type Output = crate::v1::SuggestNesResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Automatic => crate::v1::NesTriggerKind::Automatic,
-            Self::Diagnostic => crate::v1::NesTriggerKind::Diagnostic,
-            Self::Manual => crate::v1::NesTriggerKind::Manual,
-            Self::Other(value) => return Err(unknown_v2_enum_variant("NesTriggerKind", &value)),
+        let Self { suggestions, meta } = self;
+        Ok(crate::v1::SuggestNesResponse {
+            suggestions: into_v1_vec_skip_errors(suggestions),
+            meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesTriggerKind {
-    type Output = super::NesTriggerKind;
+impl IntoV2 for crate::v1::SuggestNesResponse {
+    type Output = super::SuggestNesResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Automatic => super::NesTriggerKind::Automatic,
-            Self::Diagnostic => super::NesTriggerKind::Diagnostic,
-            Self::Manual => super::NesTriggerKind::Manual,
+        let Self { suggestions, meta } = self;
+        Ok(super::SuggestNesResponse {
+            suggestions: into_v2_vec_skip_errors(suggestions),
+            meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::SuggestNesRequest {
-    type Output = crate::v1::SuggestNesRequest;
+impl IntoV1 for super::NesSuggestion {
+    type Output = crate::v1::NesSuggestion;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            session_id,
-            uri,
-            version,
-            position,
-            selection,
-            trigger_kind,
-            context,
-            meta,
-        } = self;
-        Ok(crate::v1::SuggestNesRequest {
-            session_id: session_id.into_v1()?,
-            uri: uri.into_v1()?,
-            version: version.into_v1()?,
-            position: position.into_v1()?,
-            selection: into_v1_default_on_error(selection),
-            trigger_kind: trigger_kind.into_v1()?,
-            context: into_v1_default_on_error(context),
-            meta: meta.into_v1()?,
+        Ok(match self {
+            Self::Edit(value) => crate::v1::NesSuggestion::Edit(value.into_v1()?),
+            Self::Jump(value) => crate::v1::NesSuggestion::Jump(value.into_v1()?),
+            Self::Rename(value) => crate::v1::NesSuggestion::Rename(value.into_v1()?),
+            Self::SearchAndReplace(value) => {
+                crate::v1::NesSuggestion::SearchAndReplace(value.into_v1()?)
+            }
+            Self::Other(value) => {
+                return Err(unknown_v2_enum_variant("NesSuggestion", &value.kind));
+            }
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::SuggestNesRequest {
-    type Output = super::SuggestNesRequest;
+impl IntoV2 for crate::v1::NesSuggestion {
+    type Output = super::NesSuggestion;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            session_id,
-            uri,
-            version,
-            position,
-            selection,
-            trigger_kind,
-            context,
-            meta,
-        } = self;
-        Ok(super::SuggestNesRequest {
-            session_id: session_id.into_v2()?,
-            uri: uri.into_v2()?,
-            version: version.into_v2()?,
-            position: position.into_v2()?,
-            selection: into_v2_default_on_error(selection),
-            trigger_kind: trigger_kind.into_v2()?,
-            context: into_v2_default_on_error(context),
-            meta: meta.into_v2()?,
+        Ok(match self {
+            Self::Edit(value) => super::NesSuggestion::Edit(value.into_v2()?),
+            Self::Jump(value) => super::NesSuggestion::Jump(value.into_v2()?),
+            Self::Rename(value) => super::NesSuggestion::Rename(value.into_v2()?),
+            Self::SearchAndReplace(value) => {
+                super::NesSuggestion::SearchAndReplace(value.into_v2()?)
+            }
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesSuggestContext {
-    type Output = crate::v1::NesSuggestContext;
+impl IntoV1 for super::NesSuggestionId {
+    type Output = crate::v1::NesSuggestionId;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            recent_files,
-            related_snippets,
-            edit_history,
-            user_actions,
-            open_files,
-            diagnostics,
-            meta,
-        } = self;
-        Ok(crate::v1::NesSuggestContext {
-            recent_files: option_vec_into_v1_skip_errors(recent_files),
-            related_snippets: option_vec_into_v1_skip_errors(related_snippets),
-            edit_history: option_vec_into_v1_skip_errors(edit_history),
-            user_actions: option_vec_into_v1_skip_errors(user_actions),
-            open_files: option_vec_into_v1_skip_errors(open_files),
-            diagnostics: option_vec_into_v1_skip_errors(diagnostics),
-            meta: meta.into_v1()?,
-        })
+        Ok(crate::v1::NesSuggestionId(self.0.into_v1()?))
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesSuggestContext {
-    type Output = super::NesSuggestContext;
+impl IntoV2 for crate::v1::NesSuggestionId {
+    type Output = super::NesSuggestionId;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            recent_files,
-            related_snippets,
-            edit_history,
-            user_actions,
-            open_files,
-            diagnostics,
-            meta,
-        } = self;
-        Ok(super::NesSuggestContext {
-            recent_files: option_vec_into_v2_skip_errors(recent_files),
-            related_snippets: option_vec_into_v2_skip_errors(related_snippets),
-            edit_history: option_vec_into_v2_skip_errors(edit_history),
-            user_actions: option_vec_into_v2_skip_errors(user_actions),
-            open_files: option_vec_into_v2_skip_errors(open_files),
-            diagnostics: option_vec_into_v2_skip_errors(diagnostics),
-            meta: meta.into_v2()?,
-        })
+        Ok(super::NesSuggestionId(self.0.into_v2()?))
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesRecentFile {
-    type Output = crate::v1::NesRecentFile;
+impl IntoV1 for super::NesEditSuggestion {
+    type Output = crate::v1::NesEditSuggestion;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
+            suggestion_id,
             uri,
-            language_id,
-            text,
+            edits,
+            cursor_position,
             meta,
         } = self;
-        Ok(crate::v1::NesRecentFile {
+        Ok(crate::v1::NesEditSuggestion {
+            id: suggestion_id.into_v1()?,
             uri: uri.into_v1()?,
-            language_id: language_id.into_v1()?,
-            text: text.into_v1()?,
+            edits: edits.into_v1()?,
+            cursor_position: into_v1_default_on_error(cursor_position),
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesRecentFile {
-    type Output = super::NesRecentFile;
+impl IntoV2 for crate::v1::NesEditSuggestion {
+    type Output = super::NesEditSuggestion;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
+            id,
             uri,
-            language_id,
-            text,
+            edits,
+            cursor_position,
             meta,
         } = self;
-        Ok(super::NesRecentFile {
+        Ok(super::NesEditSuggestion {
+            suggestion_id: id.into_v2()?,
             uri: uri.into_v2()?,
-            language_id: language_id.into_v2()?,
-            text: text.into_v2()?,
+            edits: edits.into_v2()?,
+            cursor_position: into_v2_default_on_error(cursor_position),
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesRelatedSnippet {
-    type Output = crate::v1::NesRelatedSnippet;
+impl IntoV1 for super::NesTextEdit {
+    type Output = crate::v1::NesTextEdit;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            uri,
-            excerpts,
+            range,
+            new_text,
             meta,
         } = self;
-        Ok(crate::v1::NesRelatedSnippet {
-            uri: uri.into_v1()?,
-            excerpts: excerpts.into_v1()?,
+        Ok(crate::v1::NesTextEdit {
+            range: range.into_v1()?,
+            new_text: new_text.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesRelatedSnippet {
-    type Output = super::NesRelatedSnippet;
+impl IntoV2 for crate::v1::NesTextEdit {
+    type Output = super::NesTextEdit;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            uri,
-            excerpts,
+            range,
+            new_text,
             meta,
         } = self;
-        Ok(super::NesRelatedSnippet {
-            uri: uri.into_v2()?,
-            excerpts: excerpts.into_v2()?,
+        Ok(super::NesTextEdit {
+            range: range.into_v2()?,
+            new_text: new_text.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesExcerpt {
-    type Output = crate::v1::NesExcerpt;
+impl IntoV1 for super::NesJumpSuggestion {
+    type Output = crate::v1::NesJumpSuggestion;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            start_line,
-            end_line,
-            text,
+            suggestion_id,
+            uri,
+            position,
             meta,
         } = self;
-        Ok(crate::v1::NesExcerpt {
-            start_line: start_line.into_v1()?,
-            end_line: end_line.into_v1()?,
-            text: text.into_v1()?,
+        Ok(crate::v1::NesJumpSuggestion {
+            id: suggestion_id.into_v1()?,
+            uri: uri.into_v1()?,
+            position: position.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesExcerpt {
-    type Output = super::NesExcerpt;
+impl IntoV2 for crate::v1::NesJumpSuggestion {
+    type Output = super::NesJumpSuggestion;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            start_line,
-            end_line,
-            text,
+            id,
+            uri,
+            position,
             meta,
         } = self;
-        Ok(super::NesExcerpt {
-            start_line: start_line.into_v2()?,
-            end_line: end_line.into_v2()?,
-            text: text.into_v2()?,
+        Ok(super::NesJumpSuggestion {
+            suggestion_id: id.into_v2()?,
+            uri: uri.into_v2()?,
+            position: position.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesEditHistoryEntry {
-    type Output = crate::v1::NesEditHistoryEntry;
+impl IntoV1 for super::NesRenameSuggestion {
+    type Output = crate::v1::NesRenameSuggestion;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { uri, diff, meta } = self;
-        Ok(crate::v1::NesEditHistoryEntry {
+        let Self {
+            suggestion_id,
+            uri,
+            position,
+            new_name,
+            meta,
+        } = self;
+        Ok(crate::v1::NesRenameSuggestion {
+            id: suggestion_id.into_v1()?,
             uri: uri.into_v1()?,
-            diff: diff.into_v1()?,
+            position: position.into_v1()?,
+            new_name: new_name.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesEditHistoryEntry {
-    type Output = super::NesEditHistoryEntry;
+impl IntoV2 for crate::v1::NesRenameSuggestion {
+    type Output = super::NesRenameSuggestion;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { uri, diff, meta } = self;
-        Ok(super::NesEditHistoryEntry {
+        let Self {
+            id,
+            uri,
+            position,
+            new_name,
+            meta,
+        } = self;
+        Ok(super::NesRenameSuggestion {
+            suggestion_id: id.into_v2()?,
             uri: uri.into_v2()?,
-            diff: diff.into_v2()?,
+            position: position.into_v2()?,
+            new_name: new_name.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesUserAction {
-    type Output = crate::v1::NesUserAction;
+impl IntoV1 for super::NesSearchAndReplaceSuggestion {
+    type Output = crate::v1::NesSearchAndReplaceSuggestion;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            action,
+            suggestion_id,
             uri,
-            position,
-            timestamp_ms,
+            search,
+            replace,
+            is_regex,
             meta,
         } = self;
-        Ok(crate::v1::NesUserAction {
-            action: action.into_v1()?,
+        Ok(crate::v1::NesSearchAndReplaceSuggestion {
+            id: suggestion_id.into_v1()?,
             uri: uri.into_v1()?,
-            position: position.into_v1()?,
-            timestamp_ms: timestamp_ms.into_v1()?,
+            search: search.into_v1()?,
+            replace: replace.into_v1()?,
+            is_regex: is_regex.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesUserAction {
-    type Output = super::NesUserAction;
+impl IntoV2 for crate::v1::NesSearchAndReplaceSuggestion {
+    type Output = super::NesSearchAndReplaceSuggestion;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            action,
+            id,
             uri,
-            position,
-            timestamp_ms,
+            search,
+            replace,
+            is_regex,
             meta,
         } = self;
-        Ok(super::NesUserAction {
-            action: action.into_v2()?,
+        Ok(super::NesSearchAndReplaceSuggestion {
+            suggestion_id: id.into_v2()?,
             uri: uri.into_v2()?,
-            position: position.into_v2()?,
-            timestamp_ms: timestamp_ms.into_v2()?,
+            search: search.into_v2()?,
+            replace: replace.into_v2()?,
+            is_regex: is_regex.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesOpenFile {
-    type Output = crate::v1::NesOpenFile;
+impl IntoV1 for super::AcceptNesNotification {
+    type Output = crate::v1::AcceptNesNotification;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            uri,
-            language_id,
-            visible_range,
-            last_focused_ms,
+            session_id,
+            suggestion_id,
             meta,
         } = self;
-        Ok(crate::v1::NesOpenFile {
-            uri: uri.into_v1()?,
-            language_id: language_id.into_v1()?,
-            visible_range: into_v1_default_on_error(visible_range),
-            last_focused_ms: into_v1_default_on_error(last_focused_ms),
+        Ok(crate::v1::AcceptNesNotification {
+            session_id: session_id.into_v1()?,
+            id: suggestion_id.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesOpenFile {
-    type Output = super::NesOpenFile;
+impl IntoV2 for crate::v1::AcceptNesNotification {
+    type Output = super::AcceptNesNotification;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            uri,
-            language_id,
-            visible_range,
-            last_focused_ms,
+            session_id,
+            id,
             meta,
         } = self;
-        Ok(super::NesOpenFile {
-            uri: uri.into_v2()?,
-            language_id: language_id.into_v2()?,
-            visible_range: into_v2_default_on_error(visible_range),
-            last_focused_ms: into_v2_default_on_error(last_focused_ms),
+        Ok(super::AcceptNesNotification {
+            session_id: session_id.into_v2()?,
+            suggestion_id: id.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesDiagnostic {
-    type Output = crate::v1::NesDiagnostic;
+impl IntoV1 for super::RejectNesNotification {
+    type Output = crate::v1::RejectNesNotification;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            uri,
-            range,
-            severity,
-            message,
+            session_id,
+            suggestion_id,
+            reason,
             meta,
         } = self;
-        Ok(crate::v1::NesDiagnostic {
-            uri: uri.into_v1()?,
-            range: range.into_v1()?,
-            severity: severity.into_v1()?,
-            message: message.into_v1()?,
+        Ok(crate::v1::RejectNesNotification {
+            session_id: session_id.into_v1()?,
+            id: suggestion_id.into_v1()?,
+            reason: into_v1_default_on_error(reason),
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesDiagnostic {
-    type Output = super::NesDiagnostic;
+impl IntoV2 for crate::v1::RejectNesNotification {
+    type Output = super::RejectNesNotification;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            uri,
-            range,
-            severity,
-            message,
+            session_id,
+            id,
+            reason,
             meta,
         } = self;
-        Ok(super::NesDiagnostic {
-            uri: uri.into_v2()?,
-            range: range.into_v2()?,
-            severity: severity.into_v2()?,
-            message: message.into_v2()?,
+        Ok(super::RejectNesNotification {
+            session_id: session_id.into_v2()?,
+            suggestion_id: id.into_v2()?,
+            reason: into_v2_default_on_error(reason),
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesDiagnosticSeverity {
-    type Output = crate::v1::NesDiagnosticSeverity;
+impl IntoV1 for super::NesRejectReason {
+    type Output = crate::v1::NesRejectReason;
 
     fn into_v1(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::Error => crate::v1::NesDiagnosticSeverity::Error,
-            Self::Warning => crate::v1::NesDiagnosticSeverity::Warning,
-            Self::Information => crate::v1::NesDiagnosticSeverity::Information,
-            Self::Hint => crate::v1::NesDiagnosticSeverity::Hint,
-            Self::Other(value) => {
-                return Err(unknown_v2_enum_variant("NesDiagnosticSeverity", &value));
-            }
+            Self::Rejected => crate::v1::NesRejectReason::Rejected,
+            Self::Ignored => crate::v1::NesRejectReason::Ignored,
+            Self::Replaced => crate::v1::NesRejectReason::Replaced,
+            Self::Cancelled => crate::v1::NesRejectReason::Cancelled,
+            Self::Other(value) => return Err(unknown_v2_enum_variant("NesRejectReason", &value)),
         })
     }
 }
 
 #[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesDiagnosticSeverity {
-    type Output = super::NesDiagnosticSeverity;
+impl IntoV2 for crate::v1::NesRejectReason {
+    type Output = super::NesRejectReason;
 
     fn into_v2(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::Error => super::NesDiagnosticSeverity::Error,
-            Self::Warning => super::NesDiagnosticSeverity::Warning,
-            Self::Information => super::NesDiagnosticSeverity::Information,
-            Self::Hint => super::NesDiagnosticSeverity::Hint,
+            Self::Rejected => super::NesRejectReason::Rejected,
+            Self::Ignored => super::NesRejectReason::Ignored,
+            Self::Replaced => super::NesRejectReason::Replaced,
+            Self::Cancelled => super::NesRejectReason::Cancelled,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::SuggestNesResponse {
-    type Output = crate::v1::SuggestNesResponse;
-
-    fn into_v1(self) -> Result<Self::Output> {
-        let Self { suggestions, meta } = self;
-        Ok(crate::v1::SuggestNesResponse {
-            suggestions: into_v1_vec_skip_errors(suggestions),
-            meta: meta.into_v1()?,
-        })
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV1 for super::ElicitationId {
+    type Output = crate::v1::ElicitationId;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        Ok(crate::v1::ElicitationId(self.0.into_v1()?))
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::SuggestNesResponse {
-    type Output = super::SuggestNesResponse;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV2 for crate::v1::ElicitationId {
+    type Output = super::ElicitationId;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { suggestions, meta } = self;
-        Ok(super::SuggestNesResponse {
-            suggestions: into_v2_vec_skip_errors(suggestions),
-            meta: meta.into_v2()?,
-        })
+        Ok(super::ElicitationId(self.0.into_v2()?))
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesSuggestion {
-    type Output = crate::v1::NesSuggestion;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV1 for super::StringFormat {
+    type Output = crate::v1::StringFormat;
 
     fn into_v1(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::Edit(value) => crate::v1::NesSuggestion::Edit(value.into_v1()?),
-            Self::Jump(value) => crate::v1::NesSuggestion::Jump(value.into_v1()?),
-            Self::Rename(value) => crate::v1::NesSuggestion::Rename(value.into_v1()?),
-            Self::SearchAndReplace(value) => {
-                crate::v1::NesSuggestion::SearchAndReplace(value.into_v1()?)
-            }
-            Self::Other(value) => {
-                return Err(unknown_v2_enum_variant("NesSuggestion", &value.kind));
-            }
+            Self::Email => crate::v1::StringFormat::Email,
+            Self::Uri => crate::v1::StringFormat::Uri,
+            Self::Date => crate::v1::StringFormat::Date,
+            Self::DateTime => crate::v1::StringFormat::DateTime,
+            Self::Other(value) => return Err(unknown_v2_enum_variant("StringFormat", &value)),
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesSuggestion {
-    type Output = super::NesSuggestion;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV2 for crate::v1::StringFormat {
+    type Output = super::StringFormat;
 
     fn into_v2(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::Edit(value) => super::NesSuggestion::Edit(value.into_v2()?),
-            Self::Jump(value) => super::NesSuggestion::Jump(value.into_v2()?),
-            Self::Rename(value) => super::NesSuggestion::Rename(value.into_v2()?),
-            Self::SearchAndReplace(value) => {
-                super::NesSuggestion::SearchAndReplace(value.into_v2()?)
-            }
+            Self::Email => super::StringFormat::Email,
+            Self::Uri => super::StringFormat::Uri,
+            Self::Date => super::StringFormat::Date,
+            Self::DateTime => super::StringFormat::DateTime,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesSuggestionId {
-    type Output = crate::v1::NesSuggestionId;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV1 for super::ElicitationSchemaType {
+    type Output = crate::v1::ElicitationSchemaType;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(crate::v1::NesSuggestionId(self.0.into_v1()?))
+        Ok(match self {
+            Self::Object => crate::v1::ElicitationSchemaType::Object,
+        })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesSuggestionId {
-    type Output = super::NesSuggestionId;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV2 for crate::v1::ElicitationSchemaType {
+    type Output = super::ElicitationSchemaType;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(super::NesSuggestionId(self.0.into_v2()?))
+        Ok(match self {
+            Self::Object => super::ElicitationSchemaType::Object,
+        })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesEditSuggestion {
-    type Output = crate::v1::NesEditSuggestion;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV1 for super::EnumOption {
+    type Output = crate::v1::EnumOption;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            suggestion_id,
-            uri,
-            edits,
-            cursor_position,
-            meta,
-        } = self;
-        Ok(crate::v1::NesEditSuggestion {
-            id: suggestion_id.into_v1()?,
-            uri: uri.into_v1()?,
-            edits: edits.into_v1()?,
-            cursor_position: into_v1_default_on_error(cursor_position),
+        let Self { value, title, meta } = self;
+        Ok(crate::v1::EnumOption {
+            value: value.into_v1()?,
+            title: title.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesEditSuggestion {
-    type Output = super::NesEditSuggestion;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV2 for crate::v1::EnumOption {
+    type Output = super::EnumOption;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            id,
-            uri,
-            edits,
-            cursor_position,
-            meta,
-        } = self;
-        Ok(super::NesEditSuggestion {
-            suggestion_id: id.into_v2()?,
-            uri: uri.into_v2()?,
-            edits: edits.into_v2()?,
-            cursor_position: into_v2_default_on_error(cursor_position),
+        let Self { value, title, meta } = self;
+        Ok(super::EnumOption {
+            value: value.into_v2()?,
+            title: title.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesTextEdit {
-    type Output = crate::v1::NesTextEdit;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV1 for super::StringPropertySchema {
+    type Output = crate::v1::StringPropertySchema;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            range,
-            new_text,
+            title,
+            description,
+            min_length,
+            max_length,
+            pattern,
+            format,
+            default,
+            enum_values,
+            one_of,
             meta,
         } = self;
-        Ok(crate::v1::NesTextEdit {
-            range: range.into_v1()?,
-            new_text: new_text.into_v1()?,
+        Ok(crate::v1::StringPropertySchema {
+            title: title.into_v1()?,
+            description: description.into_v1()?,
+            min_length: min_length.into_v1()?,
+            max_length: max_length.into_v1()?,
+            pattern: pattern.into_v1()?,
+            format: format.into_v1()?,
+            default: default.into_v1()?,
+            enum_values: enum_values.into_v1()?,
+            one_of: one_of.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesTextEdit {
-    type Output = super::NesTextEdit;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV2 for crate::v1::StringPropertySchema {
+    type Output = super::StringPropertySchema;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            range,
-            new_text,
+            title,
+            description,
+            min_length,
+            max_length,
+            pattern,
+            format,
+            default,
+            enum_values,
+            one_of,
             meta,
         } = self;
-        Ok(super::NesTextEdit {
-            range: range.into_v2()?,
-            new_text: new_text.into_v2()?,
+        Ok(super::StringPropertySchema {
+            title: title.into_v2()?,
+            description: description.into_v2()?,
+            min_length: min_length.into_v2()?,
+            max_length: max_length.into_v2()?,
+            pattern: pattern.into_v2()?,
+            format: format.into_v2()?,
+            default: default.into_v2()?,
+            enum_values: enum_values.into_v2()?,
+            one_of: one_of.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesJumpSuggestion {
-    type Output = crate::v1::NesJumpSuggestion;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV1 for super::NumberPropertySchema {
+    type Output = crate::v1::NumberPropertySchema;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            suggestion_id,
-            uri,
-            position,
+            title,
+            description,
+            minimum,
+            maximum,
+            default,
             meta,
         } = self;
-        Ok(crate::v1::NesJumpSuggestion {
-            id: suggestion_id.into_v1()?,
-            uri: uri.into_v1()?,
-            position: position.into_v1()?,
+        Ok(crate::v1::NumberPropertySchema {
+            title: title.into_v1()?,
+            description: description.into_v1()?,
+            minimum: minimum.into_v1()?,
+            maximum: maximum.into_v1()?,
+            default: default.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesJumpSuggestion {
-    type Output = super::NesJumpSuggestion;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV2 for crate::v1::NumberPropertySchema {
+    type Output = super::NumberPropertySchema;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            id,
-            uri,
-            position,
+            title,
+            description,
+            minimum,
+            maximum,
+            default,
             meta,
         } = self;
-        Ok(super::NesJumpSuggestion {
-            suggestion_id: id.into_v2()?,
-            uri: uri.into_v2()?,
-            position: position.into_v2()?,
+        Ok(super::NumberPropertySchema {
+            title: title.into_v2()?,
+            description: description.into_v2()?,
+            minimum: minimum.into_v2()?,
+            maximum: maximum.into_v2()?,
+            default: default.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesRenameSuggestion {
-    type Output = crate::v1::NesRenameSuggestion;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV1 for super::IntegerPropertySchema {
+    type Output = crate::v1::IntegerPropertySchema;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            suggestion_id,
-            uri,
-            position,
-            new_name,
+            title,
+            description,
+            minimum,
+            maximum,
+            default,
             meta,
         } = self;
-        Ok(crate::v1::NesRenameSuggestion {
-            id: suggestion_id.into_v1()?,
-            uri: uri.into_v1()?,
-            position: position.into_v1()?,
-            new_name: new_name.into_v1()?,
+        Ok(crate::v1::IntegerPropertySchema {
+            title: title.into_v1()?,
+            description: description.into_v1()?,
+            minimum: minimum.into_v1()?,
+            maximum: maximum.into_v1()?,
+            default: default.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesRenameSuggestion {
-    type Output = super::NesRenameSuggestion;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV2 for crate::v1::IntegerPropertySchema {
+    type Output = super::IntegerPropertySchema;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            id,
-            uri,
-            position,
-            new_name,
+            title,
+            description,
+            minimum,
+            maximum,
+            default,
             meta,
         } = self;
-        Ok(super::NesRenameSuggestion {
-            suggestion_id: id.into_v2()?,
-            uri: uri.into_v2()?,
-            position: position.into_v2()?,
-            new_name: new_name.into_v2()?,
+        Ok(super::IntegerPropertySchema {
+            title: title.into_v2()?,
+            description: description.into_v2()?,
+            minimum: minimum.into_v2()?,
+            maximum: maximum.into_v2()?,
+            default: default.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesSearchAndReplaceSuggestion {
-    type Output = crate::v1::NesSearchAndReplaceSuggestion;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV1 for super::BooleanPropertySchema {
+    type Output = crate::v1::BooleanPropertySchema;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            suggestion_id,
-            uri,
-            search,
-            replace,
-            is_regex,
+            title,
+            description,
+            default,
             meta,
         } = self;
-        Ok(crate::v1::NesSearchAndReplaceSuggestion {
-            id: suggestion_id.into_v1()?,
-            uri: uri.into_v1()?,
-            search: search.into_v1()?,
-            replace: replace.into_v1()?,
-            is_regex: is_regex.into_v1()?,
+        Ok(crate::v1::BooleanPropertySchema {
+            title: title.into_v1()?,
+            description: description.into_v1()?,
+            default: default.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesSearchAndReplaceSuggestion {
-    type Output = super::NesSearchAndReplaceSuggestion;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV2 for crate::v1::BooleanPropertySchema {
+    type Output = super::BooleanPropertySchema;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            id,
-            uri,
-            search,
-            replace,
-            is_regex,
+            title,
+            description,
+            default,
             meta,
         } = self;
-        Ok(super::NesSearchAndReplaceSuggestion {
-            suggestion_id: id.into_v2()?,
-            uri: uri.into_v2()?,
-            search: search.into_v2()?,
-            replace: replace.into_v2()?,
-            is_regex: is_regex.into_v2()?,
+        Ok(super::BooleanPropertySchema {
+            title: title.into_v2()?,
+            description: description.into_v2()?,
+            default: default.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::AcceptNesNotification {
-    type Output = crate::v1::AcceptNesNotification;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV1 for super::StringMultiSelectItems {
+    type Output = crate::v1::StringMultiSelectItems;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            session_id,
-            suggestion_id,
-            meta,
-        } = self;
-        Ok(crate::v1::AcceptNesNotification {
-            session_id: session_id.into_v1()?,
-            id: suggestion_id.into_v1()?,
+        let Self { values, meta } = self;
+        Ok(crate::v1::StringMultiSelectItems {
+            values: values.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::AcceptNesNotification {
-    type Output = super::AcceptNesNotification;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV2 for crate::v1::StringMultiSelectItems {
+    type Output = super::StringMultiSelectItems;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            session_id,
-            id,
-            meta,
-        } = self;
-        Ok(super::AcceptNesNotification {
-            session_id: session_id.into_v2()?,
-            suggestion_id: id.into_v2()?,
+        let Self { values, meta } = self;
+        Ok(super::StringMultiSelectItems {
+            values: values.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::RejectNesNotification {
-    type Output = crate::v1::RejectNesNotification;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV1 for super::OtherMultiSelectItems {
+    type Output = crate::v1::OtherMultiSelectItems;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            session_id,
-            suggestion_id,
-            reason,
-            meta,
-        } = self;
-        Ok(crate::v1::RejectNesNotification {
-            session_id: session_id.into_v1()?,
-            id: suggestion_id.into_v1()?,
-            reason: into_v1_default_on_error(reason),
-            meta: meta.into_v1()?,
+        let Self { type_, fields } = self;
+        Ok(crate::v1::OtherMultiSelectItems {
+            type_: type_.into_v1()?,
+            fields: fields.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::RejectNesNotification {
-    type Output = super::RejectNesNotification;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV2 for crate::v1::OtherMultiSelectItems {
+    type Output = super::OtherMultiSelectItems;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            session_id,
-            id,
-            reason,
-            meta,
-        } = self;
-        Ok(super::RejectNesNotification {
-            session_id: session_id.into_v2()?,
-            suggestion_id: id.into_v2()?,
-            reason: into_v2_default_on_error(reason),
-            meta: meta.into_v2()?,
+        let Self { type_, fields } = self;
+        Ok(super::OtherMultiSelectItems {
+            type_: type_.into_v2()?,
+            fields: fields.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV1 for super::NesRejectReason {
-    type Output = crate::v1::NesRejectReason;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV1 for super::TitledMultiSelectItems {
+    type Output = crate::v1::TitledMultiSelectItems;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Rejected => crate::v1::NesRejectReason::Rejected,
-            Self::Ignored => crate::v1::NesRejectReason::Ignored,
-            Self::Replaced => crate::v1::NesRejectReason::Replaced,
-            Self::Cancelled => crate::v1::NesRejectReason::Cancelled,
-            Self::Other(value) => return Err(unknown_v2_enum_variant("NesRejectReason", &value)),
+        let Self { options, meta } = self;
+        Ok(crate::v1::TitledMultiSelectItems {
+            options: options.into_v1()?,
+            meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_nes")]
-impl IntoV2 for crate::v1::NesRejectReason {
-    type Output = super::NesRejectReason;
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV2 for crate::v1::TitledMultiSelectItems {
+    type Output = super::TitledMultiSelectItems;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Rejected => super::NesRejectReason::Rejected,
-            Self::Ignored => super::NesRejectReason::Ignored,
-            Self::Replaced => super::NesRejectReason::Replaced,
-            Self::Cancelled => super::NesRejectReason::Cancelled,
+        let Self { options, meta } = self;
+        Ok(super::TitledMultiSelectItems {
+            options: options.into_v2()?,
+            meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationId {
-    type Output = crate::v1::ElicitationId;
+impl IntoV1 for super::MultiSelectItems {
+    type Output = crate::v1::MultiSelectItems;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(crate::v1::ElicitationId(self.0.into_v1()?))
+        Ok(match self {
+            Self::String(value) => crate::v1::MultiSelectItems::String(value.into_v1()?),
+            Self::Other(value) => crate::v1::MultiSelectItems::Other(value.into_v1()?),
+            Self::Titled(value) => crate::v1::MultiSelectItems::Titled(value.into_v1()?),
+        })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationId {
-    type Output = super::ElicitationId;
+impl IntoV2 for crate::v1::MultiSelectItems {
+    type Output = super::MultiSelectItems;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(super::ElicitationId(self.0.into_v2()?))
+        Ok(match self {
+            Self::String(value) => super::MultiSelectItems::String(value.into_v2()?),
+            Self::Other(value) => super::MultiSelectItems::Other(value.into_v2()?),
+            Self::Titled(value) => super::MultiSelectItems::Titled(value.into_v2()?),
+        })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::StringFormat {
-    type Output = crate::v1::StringFormat;
+impl IntoV1 for super::MultiSelectPropertySchema {
+    type Output = crate::v1::MultiSelectPropertySchema;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Email => crate::v1::StringFormat::Email,
-            Self::Uri => crate::v1::StringFormat::Uri,
-            Self::Date => crate::v1::StringFormat::Date,
-            Self::DateTime => crate::v1::StringFormat::DateTime,
-            Self::Other(value) => return Err(unknown_v2_enum_variant("StringFormat", &value)),
+        let Self {
+            title,
+            description,
+            min_items,
+            max_items,
+            items,
+            default,
+            meta,
+        } = self;
+        Ok(crate::v1::MultiSelectPropertySchema {
+            title: title.into_v1()?,
+            description: description.into_v1()?,
+            min_items: min_items.into_v1()?,
+            max_items: max_items.into_v1()?,
+            items: items.into_v1()?,
+            default: default.into_v1()?,
+            meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::StringFormat {
-    type Output = super::StringFormat;
+impl IntoV2 for crate::v1::MultiSelectPropertySchema {
+    type Output = super::MultiSelectPropertySchema;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Email => super::StringFormat::Email,
-            Self::Uri => super::StringFormat::Uri,
-            Self::Date => super::StringFormat::Date,
-            Self::DateTime => super::StringFormat::DateTime,
+        let Self {
+            title,
+            description,
+            min_items,
+            max_items,
+            items,
+            default,
+            meta,
+        } = self;
+        Ok(super::MultiSelectPropertySchema {
+            title: title.into_v2()?,
+            description: description.into_v2()?,
+            min_items: min_items.into_v2()?,
+            max_items: max_items.into_v2()?,
+            items: items.into_v2()?,
+            default: default.into_v2()?,
+            meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationSchemaType {
-    type Output = crate::v1::ElicitationSchemaType;
+impl IntoV1 for super::ElicitationPropertySchema {
+    type Output = crate::v1::ElicitationPropertySchema;
 
     fn into_v1(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::Object => crate::v1::ElicitationSchemaType::Object,
+            Self::String(value) => crate::v1::ElicitationPropertySchema::String(value.into_v1()?),
+            Self::Number(value) => crate::v1::ElicitationPropertySchema::Number(value.into_v1()?),
+            Self::Integer(value) => crate::v1::ElicitationPropertySchema::Integer(value.into_v1()?),
+            Self::Boolean(value) => crate::v1::ElicitationPropertySchema::Boolean(value.into_v1()?),
+            Self::Array(value) => crate::v1::ElicitationPropertySchema::Array(value.into_v1()?),
+            Self::Other(value) => crate::v1::ElicitationPropertySchema::Other(value.into_v1()?),
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationSchemaType {
-    type Output = super::ElicitationSchemaType;
+impl IntoV2 for crate::v1::ElicitationPropertySchema {
+    type Output = super::ElicitationPropertySchema;
 
     fn into_v2(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::Object => super::ElicitationSchemaType::Object,
+            Self::String(value) => super::ElicitationPropertySchema::String(value.into_v2()?),
+            Self::Number(value) => super::ElicitationPropertySchema::Number(value.into_v2()?),
+            Self::Integer(value) => super::ElicitationPropertySchema::Integer(value.into_v2()?),
+            Self::Boolean(value) => super::ElicitationPropertySchema::Boolean(value.into_v2()?),
+            Self::Array(value) => super::ElicitationPropertySchema::Array(value.into_v2()?),
+            Self::Other(value) => super::ElicitationPropertySchema::Other(value.into_v2()?),
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::EnumOption {
-    type Output = crate::v1::EnumOption;
+impl IntoV1 for super::OtherElicitationPropertySchema {
+    type Output = crate::v1::OtherElicitationPropertySchema;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { value, title, meta } = self;
-        Ok(crate::v1::EnumOption {
-            value: value.into_v1()?,
-            title: title.into_v1()?,
-            meta: meta.into_v1()?,
+        let Self { type_, fields } = self;
+        Ok(crate::v1::OtherElicitationPropertySchema {
+            type_: type_.into_v1()?,
+            fields: fields.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::EnumOption {
-    type Output = super::EnumOption;
+impl IntoV2 for crate::v1::OtherElicitationPropertySchema {
+    type Output = super::OtherElicitationPropertySchema;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { value, title, meta } = self;
-        Ok(super::EnumOption {
-            value: value.into_v2()?,
-            title: title.into_v2()?,
-            meta: meta.into_v2()?,
+        let Self { type_, fields } = self;
+        Ok(super::OtherElicitationPropertySchema {
+            type_: type_.into_v2()?,
+            fields: fields.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::StringPropertySchema {
-    type Output = crate::v1::StringPropertySchema;
+impl IntoV1 for super::ElicitationSchema {
+    type Output = crate::v1::ElicitationSchema;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
+            type_,
             title,
+            properties,
+            required,
             description,
-            min_length,
-            max_length,
-            pattern,
-            format,
-            default,
-            enum_values,
-            one_of,
             meta,
         } = self;
-        Ok(crate::v1::StringPropertySchema {
+        Ok(crate::v1::ElicitationSchema {
+            type_: type_.into_v1()?,
             title: title.into_v1()?,
+            properties: properties.into_v1()?,
+            required: required.into_v1()?,
             description: description.into_v1()?,
-            min_length: min_length.into_v1()?,
-            max_length: max_length.into_v1()?,
-            pattern: pattern.into_v1()?,
-            format: format.into_v1()?,
-            default: default.into_v1()?,
-            enum_values: enum_values.into_v1()?,
-            one_of: one_of.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::StringPropertySchema {
-    type Output = super::StringPropertySchema;
+impl IntoV2 for crate::v1::ElicitationSchema {
+    type Output = super::ElicitationSchema;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
+            type_,
             title,
+            properties,
+            required,
             description,
-            min_length,
-            max_length,
-            pattern,
-            format,
-            default,
-            enum_values,
-            one_of,
             meta,
         } = self;
-        Ok(super::StringPropertySchema {
+        Ok(super::ElicitationSchema {
+            type_: type_.into_v2()?,
             title: title.into_v2()?,
+            properties: properties.into_v2()?,
+            required: required.into_v2()?,
             description: description.into_v2()?,
-            min_length: min_length.into_v2()?,
-            max_length: max_length.into_v2()?,
-            pattern: pattern.into_v2()?,
-            format: format.into_v2()?,
-            default: default.into_v2()?,
-            enum_values: enum_values.into_v2()?,
-            one_of: one_of.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::NumberPropertySchema {
-    type Output = crate::v1::NumberPropertySchema;
+impl IntoV1 for super::ElicitationCapabilities {
+    type Output = crate::v1::ElicitationCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            title,
-            description,
-            minimum,
-            maximum,
-            default,
-            meta,
-        } = self;
-        Ok(crate::v1::NumberPropertySchema {
-            title: title.into_v1()?,
-            description: description.into_v1()?,
-            minimum: minimum.into_v1()?,
-            maximum: maximum.into_v1()?,
-            default: default.into_v1()?,
+        let Self { form, url, meta } = self;
+        Ok(crate::v1::ElicitationCapabilities {
+            form: into_v1_default_on_error(form),
+            url: into_v1_default_on_error(url),
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::NumberPropertySchema {
-    type Output = super::NumberPropertySchema;
+impl IntoV2 for crate::v1::ElicitationCapabilities {
+    type Output = super::ElicitationCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            title,
-            description,
-            minimum,
-            maximum,
-            default,
-            meta,
-        } = self;
-        Ok(super::NumberPropertySchema {
-            title: title.into_v2()?,
-            description: description.into_v2()?,
-            minimum: minimum.into_v2()?,
-            maximum: maximum.into_v2()?,
-            default: default.into_v2()?,
+        let Self { form, url, meta } = self;
+        Ok(super::ElicitationCapabilities {
+            form: into_v2_default_on_error(form),
+            url: into_v2_default_on_error(url),
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::IntegerPropertySchema {
-    type Output = crate::v1::IntegerPropertySchema;
+impl IntoV1 for super::ElicitationFormCapabilities {
+    type Output = crate::v1::ElicitationFormCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            title,
-            description,
-            minimum,
-            maximum,
-            default,
-            meta,
-        } = self;
-        Ok(crate::v1::IntegerPropertySchema {
-            title: title.into_v1()?,
-            description: description.into_v1()?,
-            minimum: minimum.into_v1()?,
-            maximum: maximum.into_v1()?,
-            default: default.into_v1()?,
+        let Self { meta } = self;
+        Ok(crate::v1::ElicitationFormCapabilities {
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::IntegerPropertySchema {
-    type Output = super::IntegerPropertySchema;
+impl IntoV2 for crate::v1::ElicitationFormCapabilities {
+    type Output = super::ElicitationFormCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            title,
-            description,
-            minimum,
-            maximum,
-            default,
-            meta,
-        } = self;
-        Ok(super::IntegerPropertySchema {
-            title: title.into_v2()?,
-            description: description.into_v2()?,
-            minimum: minimum.into_v2()?,
-            maximum: maximum.into_v2()?,
-            default: default.into_v2()?,
+        let Self { meta } = self;
+        Ok(super::ElicitationFormCapabilities {
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::BooleanPropertySchema {
-    type Output = crate::v1::BooleanPropertySchema;
+impl IntoV1 for super::ElicitationUrlCapabilities {
+    type Output = crate::v1::ElicitationUrlCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            title,
-            description,
-            default,
-            meta,
-        } = self;
-        Ok(crate::v1::BooleanPropertySchema {
-            title: title.into_v1()?,
-            description: description.into_v1()?,
-            default: default.into_v1()?,
+        let Self { meta } = self;
+        Ok(crate::v1::ElicitationUrlCapabilities {
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::BooleanPropertySchema {
-    type Output = super::BooleanPropertySchema;
+impl IntoV2 for crate::v1::ElicitationUrlCapabilities {
+    type Output = super::ElicitationUrlCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            title,
-            description,
-            default,
-            meta,
-        } = self;
-        Ok(super::BooleanPropertySchema {
-            title: title.into_v2()?,
-            description: description.into_v2()?,
-            default: default.into_v2()?,
+        let Self { meta } = self;
+        Ok(super::ElicitationUrlCapabilities {
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::StringMultiSelectItems {
-    type Output = crate::v1::StringMultiSelectItems;
+impl IntoV1 for super::ElicitationScope {
+    type Output = crate::v1::ElicitationScope;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { values, meta } = self;
-        Ok(crate::v1::StringMultiSelectItems {
-            values: values.into_v1()?,
-            meta: meta.into_v1()?,
+        Ok(match self {
+            Self::Session(value) => crate::v1::ElicitationScope::Session(value.into_v1()?),
+            Self::Request(value) => crate::v1::ElicitationScope::Request(value.into_v1()?),
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::StringMultiSelectItems {
-    type Output = super::StringMultiSelectItems;
+impl IntoV2 for crate::v1::ElicitationScope {
+    type Output = super::ElicitationScope;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { values, meta } = self;
-        Ok(super::StringMultiSelectItems {
-            values: values.into_v2()?,
-            meta: meta.into_v2()?,
+        Ok(match self {
+            Self::Session(value) => super::ElicitationScope::Session(value.into_v2()?),
+            Self::Request(value) => super::ElicitationScope::Request(value.into_v2()?),
+        })
+    }
+}
+
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV1 for super::ElicitationSessionScope {
+    type Output = crate::v1::ElicitationSessionScope;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            session_id,
+            tool_call_id,
+        } = self;
+        Ok(crate::v1::ElicitationSessionScope {
+            session_id: session_id.into_v1()?,
+            tool_call_id: tool_call_id.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV2 for crate::v1::ElicitationSessionScope {
+    type Output = super::ElicitationSessionScope;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            session_id,
+            tool_call_id,
+        } = self;
+        Ok(super::ElicitationSessionScope {
+            session_id: session_id.into_v2()?,
+            tool_call_id: tool_call_id.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::OtherMultiSelectItems {
-    type Output = crate::v1::OtherMultiSelectItems;
+impl IntoV1 for super::ElicitationRequestScope {
+    type Output = crate::v1::ElicitationRequestScope;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { type_, fields } = self;
-        Ok(crate::v1::OtherMultiSelectItems {
-            type_: type_.into_v1()?,
-            fields: fields.into_v1()?,
+        let Self { request_id } = self;
+        Ok(crate::v1::ElicitationRequestScope {
+            request_id: request_id.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::OtherMultiSelectItems {
-    type Output = super::OtherMultiSelectItems;
+impl IntoV2 for crate::v1::ElicitationRequestScope {
+    type Output = super::ElicitationRequestScope;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { type_, fields } = self;
-        Ok(super::OtherMultiSelectItems {
-            type_: type_.into_v2()?,
-            fields: fields.into_v2()?,
+        let Self { request_id } = self;
+        Ok(super::ElicitationRequestScope {
+            request_id: request_id.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::TitledMultiSelectItems {
-    type Output = crate::v1::TitledMultiSelectItems;
+impl IntoV1 for super::CreateElicitationRequest {
+    type Output = crate::v1::CreateElicitationRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { options, meta } = self;
-        Ok(crate::v1::TitledMultiSelectItems {
-            options: options.into_v1()?,
+        let Self {
+            mode,
+            message,
+            meta,
+        } = self;
+        Ok(crate::v1::CreateElicitationRequest {
+            mode: mode.into_v1()?,
+            message: message.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::TitledMultiSelectItems {
-    type Output = super::TitledMultiSelectItems;
+impl IntoV2 for crate::v1::CreateElicitationRequest {
+    type Output = super::CreateElicitationRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { options, meta } = self;
-        Ok(super::TitledMultiSelectItems {
-            options: options.into_v2()?,
+        let Self {
+            mode,
+            message,
+            meta,
+        } = self;
+        Ok(super::CreateElicitationRequest {
+            mode: mode.into_v2()?,
+            message: message.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::MultiSelectItems {
-    type Output = crate::v1::MultiSelectItems;
+impl IntoV1 for super::ElicitationMode {
+    type Output = crate::v1::ElicitationMode;
 
     fn into_v1(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::String(value) => crate::v1::MultiSelectItems::String(value.into_v1()?),
-            Self::Other(value) => crate::v1::MultiSelectItems::Other(value.into_v1()?),
-            Self::Titled(value) => crate::v1::MultiSelectItems::Titled(value.into_v1()?),
+            Self::Form(value) => crate::v1::ElicitationMode::Form(value.into_v1()?),
+            Self::Url(value) => crate::v1::ElicitationMode::Url(value.into_v1()?),
+            Self::Other(value) => crate::v1::ElicitationMode::Other(value.into_v1()?),
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::MultiSelectItems {
-    type Output = super::MultiSelectItems;
+impl IntoV2 for crate::v1::ElicitationMode {
+    type Output = super::ElicitationMode;
 
     fn into_v2(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::String(value) => super::MultiSelectItems::String(value.into_v2()?),
-            Self::Other(value) => super::MultiSelectItems::Other(value.into_v2()?),
-            Self::Titled(value) => super::MultiSelectItems::Titled(value.into_v2()?),
+            Self::Form(value) => super::ElicitationMode::Form(value.into_v2()?),
+            Self::Url(value) => super::ElicitationMode::Url(value.into_v2()?),
+            Self::Other(value) => super::ElicitationMode::Other(value.into_v2()?),
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::MultiSelectPropertySchema {
-    type Output = crate::v1::MultiSelectPropertySchema;
+impl IntoV1 for super::OtherElicitationMode {
+    type Output = crate::v1::OtherElicitationMode;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            title,
-            description,
-            min_items,
-            max_items,
-            items,
-            default,
-            meta,
+            mode,
+            scope,
+            fields,
         } = self;
-        Ok(crate::v1::MultiSelectPropertySchema {
-            title: title.into_v1()?,
-            description: description.into_v1()?,
-            min_items: min_items.into_v1()?,
-            max_items: max_items.into_v1()?,
-            items: items.into_v1()?,
-            default: default.into_v1()?,
-            meta: meta.into_v1()?,
+        Ok(crate::v1::OtherElicitationMode {
+            mode: mode.into_v1()?,
+            scope: scope.into_v1()?,
+            fields: fields.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::MultiSelectPropertySchema {
-    type Output = super::MultiSelectPropertySchema;
+impl IntoV2 for crate::v1::OtherElicitationMode {
+    type Output = super::OtherElicitationMode;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            title,
-            description,
-            min_items,
-            max_items,
-            items,
-            default,
-            meta,
+            mode,
+            scope,
+            fields,
         } = self;
-        Ok(super::MultiSelectPropertySchema {
-            title: title.into_v2()?,
-            description: description.into_v2()?,
-            min_items: min_items.into_v2()?,
-            max_items: max_items.into_v2()?,
-            items: items.into_v2()?,
-            default: default.into_v2()?,
-            meta: meta.into_v2()?,
+        Ok(super::OtherElicitationMode {
+            mode: mode.into_v2()?,
+            scope: scope.into_v2()?,
+            fields: fields.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationPropertySchema {
-    type Output = crate::v1::ElicitationPropertySchema;
+impl IntoV1 for super::ElicitationFormMode {
+    type Output = crate::v1::ElicitationFormMode;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::String(value) => crate::v1::ElicitationPropertySchema::String(value.into_v1()?),
-            Self::Number(value) => crate::v1::ElicitationPropertySchema::Number(value.into_v1()?),
-            Self::Integer(value) => crate::v1::ElicitationPropertySchema::Integer(value.into_v1()?),
-            Self::Boolean(value) => crate::v1::ElicitationPropertySchema::Boolean(value.into_v1()?),
-            Self::Array(value) => crate::v1::ElicitationPropertySchema::Array(value.into_v1()?),
-            Self::Other(value) => crate::v1::ElicitationPropertySchema::Other(value.into_v1()?),
+        let Self {
+            scope,
+            requested_schema,
+        } = self;
+        Ok(crate::v1::ElicitationFormMode {
+            scope: scope.into_v1()?,
+            requested_schema: requested_schema.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationPropertySchema {
-    type Output = super::ElicitationPropertySchema;
+impl IntoV2 for crate::v1::ElicitationFormMode {
+    type Output = super::ElicitationFormMode;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::String(value) => super::ElicitationPropertySchema::String(value.into_v2()?),
-            Self::Number(value) => super::ElicitationPropertySchema::Number(value.into_v2()?),
-            Self::Integer(value) => super::ElicitationPropertySchema::Integer(value.into_v2()?),
-            Self::Boolean(value) => super::ElicitationPropertySchema::Boolean(value.into_v2()?),
-            Self::Array(value) => super::ElicitationPropertySchema::Array(value.into_v2()?),
-            Self::Other(value) => super::ElicitationPropertySchema::Other(value.into_v2()?),
+        let Self {
+            scope,
+            requested_schema,
+        } = self;
+        Ok(super::ElicitationFormMode {
+            scope: scope.into_v2()?,
+            requested_schema: requested_schema.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::OtherElicitationPropertySchema {
-    type Output = crate::v1::OtherElicitationPropertySchema;
+impl IntoV1 for super::ElicitationUrlMode {
+    type Output = crate::v1::ElicitationUrlMode;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { type_, fields } = self;
-        Ok(crate::v1::OtherElicitationPropertySchema {
-            type_: type_.into_v1()?,
-            fields: fields.into_v1()?,
+        let Self {
+            scope,
+            elicitation_id,
+            url,
+        } = self;
+        Ok(crate::v1::ElicitationUrlMode {
+            scope: scope.into_v1()?,
+            elicitation_id: elicitation_id.into_v1()?,
+            url: url.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::OtherElicitationPropertySchema {
-    type Output = super::OtherElicitationPropertySchema;
+impl IntoV2 for crate::v1::ElicitationUrlMode {
+    type Output = super::ElicitationUrlMode;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { type_, fields } = self;
-        Ok(super::OtherElicitationPropertySchema {
-            type_: type_.into_v2()?,
-            fields: fields.into_v2()?,
+        let Self {
+            scope,
+            elicitation_id,
+            url,
+        } = self;
+        Ok(super::ElicitationUrlMode {
+            scope: scope.into_v2()?,
+            elicitation_id: elicitation_id.into_v2()?,
+            url: url.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationSchema {
-    type Output = crate::v1::ElicitationSchema;
+impl IntoV1 for super::CreateElicitationResponse {
+    type Output = crate::v1::CreateElicitationResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            type_,
-            title,
-            properties,
-            required,
-            description,
-            meta,
-        } = self;
-        Ok(crate::v1::ElicitationSchema {
-            type_: type_.into_v1()?,
-            title: title.into_v1()?,
-            properties: properties.into_v1()?,
-            required: required.into_v1()?,
-            description: description.into_v1()?,
+        let Self { action, meta } = self;
+        Ok(crate::v1::CreateElicitationResponse {
+            action: action.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationSchema {
-    type Output = super::ElicitationSchema;
+impl IntoV2 for crate::v1::CreateElicitationResponse {
+    type Output = super::CreateElicitationResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            type_,
-            title,
-            properties,
-            required,
-            description,
-            meta,
-        } = self;
-        Ok(super::ElicitationSchema {
-            type_: type_.into_v2()?,
-            title: title.into_v2()?,
-            properties: properties.into_v2()?,
-            required: required.into_v2()?,
-            description: description.into_v2()?,
+        let Self { action, meta } = self;
+        Ok(super::CreateElicitationResponse {
+            action: action.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationCapabilities {
-    type Output = crate::v1::ElicitationCapabilities;
+impl IntoV1 for super::ElicitationAction {
+    type Output = crate::v1::ElicitationAction;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        Ok(match self {
+            Self::Accept(value) => crate::v1::ElicitationAction::Accept(value.into_v1()?),
+            Self::Decline => crate::v1::ElicitationAction::Decline,
+            Self::Cancel => crate::v1::ElicitationAction::Cancel,
+            Self::Other(value) => crate::v1::ElicitationAction::Other(value.into_v1()?),
+        })
+    }
+}
+
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV2 for crate::v1::ElicitationAction {
+    type Output = super::ElicitationAction;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        Ok(match self {
+            Self::Accept(value) => super::ElicitationAction::Accept(value.into_v2()?),
+            Self::Decline => super::ElicitationAction::Decline,
+            Self::Cancel => super::ElicitationAction::Cancel,
+            Self::Other(value) => super::ElicitationAction::Other(value.into_v2()?),
+        })
+    }
+}
+
+#[cfg(feature = "unstable_elicitation")]
+impl IntoV1 for super::OtherElicitationAction {
+    type Output = crate::v1::OtherElicitationAction;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { form, url, meta } = self;
-        Ok(crate::v1::ElicitationCapabilities {
-            form: into_v1_default_on_error(form),
-            url: into_v1_default_on_error(url),
-            meta: meta.into_v1()?,
+        let Self { action, fields } = self;
+        Ok(crate::v1::OtherElicitationAction {
+            action: action.into_v1()?,
+            fields: fields.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationCapabilities {
-    type Output = super::ElicitationCapabilities;
+impl IntoV2 for crate::v1::OtherElicitationAction {
+    type Output = super::OtherElicitationAction;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { form, url, meta } = self;
-        Ok(super::ElicitationCapabilities {
-            form: into_v2_default_on_error(form),
-            url: into_v2_default_on_error(url),
-            meta: meta.into_v2()?,
+        let Self { action, fields } = self;
+        Ok(super::OtherElicitationAction {
+            action: action.into_v2()?,
+            fields: fields.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationFormCapabilities {
-    type Output = crate::v1::ElicitationFormCapabilities;
+impl IntoV1 for super::ElicitationAcceptAction {
+    type Output = crate::v1::ElicitationAcceptAction;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::ElicitationFormCapabilities {
-            meta: meta.into_v1()?,
+        let Self { content } = self;
+        Ok(crate::v1::ElicitationAcceptAction {
+            content: content.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationFormCapabilities {
-    type Output = super::ElicitationFormCapabilities;
+impl IntoV2 for crate::v1::ElicitationAcceptAction {
+    type Output = super::ElicitationAcceptAction;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::ElicitationFormCapabilities {
-            meta: meta.into_v2()?,
+        let Self { content } = self;
+        Ok(super::ElicitationAcceptAction {
+            content: content.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationUrlCapabilities {
-    type Output = crate::v1::ElicitationUrlCapabilities;
+impl IntoV1 for super::ElicitationContentValue {
+    type Output = crate::v1::ElicitationContentValue;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(crate::v1::ElicitationUrlCapabilities {
-            meta: meta.into_v1()?,
+        Ok(match self {
+            Self::String(value) => crate::v1::ElicitationContentValue::String(value.into_v1()?),
+            Self::Integer(value) => crate::v1::ElicitationContentValue::Integer(value.into_v1()?),
+            Self::Number(value) => crate::v1::ElicitationContentValue::Number(value.into_v1()?),
+            Self::Boolean(value) => crate::v1::ElicitationContentValue::Boolean(value.into_v1()?),
+            Self::StringArray(value) => {
+                crate::v1::ElicitationContentValue::StringArray(value.into_v1()?)
+            }
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationUrlCapabilities {
-    type Output = super::ElicitationUrlCapabilities;
+impl IntoV2 for crate::v1::ElicitationContentValue {
+    type Output = super::ElicitationContentValue;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { meta } = self;
-        Ok(super::ElicitationUrlCapabilities {
-            meta: meta.into_v2()?,
+        Ok(match self {
+            Self::String(value) => super::ElicitationContentValue::String(value.into_v2()?),
+            Self::Integer(value) => super::ElicitationContentValue::Integer(value.into_v2()?),
+            Self::Number(value) => super::ElicitationContentValue::Number(value.into_v2()?),
+            Self::Boolean(value) => super::ElicitationContentValue::Boolean(value.into_v2()?),
+            Self::StringArray(value) => {
+                super::ElicitationContentValue::StringArray(value.into_v2()?)
+            }
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationScope {
-    type Output = crate::v1::ElicitationScope;
+#[cfg(feature = "unstable_agent_ready")]
+impl IntoV1 for super::AgentReadyNotification {
+    type Output = crate::v1::AgentReadyNotification;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Session(value) => crate::v1::ElicitationScope::Session(value.into_v1()?),
-            Self::Request(value) => crate::v1::ElicitationScope::Request(value.into_v1()?),
+        let Self { meta } = self;
+        Ok(crate::v1::AgentReadyNotification {
+            meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationScope {
-    type Output = super::ElicitationScope;
+#[cfg(feature = "unstable_agent_ready")]
+impl IntoV2 for crate::v1::AgentReadyNotification {
+    type Output = super::AgentReadyNotification;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Session(value) => super::ElicitationScope::Session(value.into_v2()?),
-            Self::Request(value) => super::ElicitationScope::Request(value.into_v2()?),
+        let Self { meta } = self;
+        Ok(super::AgentReadyNotification {
+            meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationSessionScope {
-    type Output = crate::v1::ElicitationSessionScope;
+impl IntoV1 for super::CompleteElicitationNotification {
+    type Output = crate::v1::CompleteElicitationNotification;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            session_id,
-            tool_call_id,
+            elicitation_id,
+            meta,
         } = self;
-        Ok(crate::v1::ElicitationSessionScope {
-            session_id: session_id.into_v1()?,
-            tool_call_id: tool_call_id.into_v1()?,
+        Ok(crate::v1::CompleteElicitationNotification {
+            elicitation_id: elicitation_id.into_v1()?,
+            meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationSessionScope {
-    type Output = super::ElicitationSessionScope;
+impl IntoV2 for crate::v1::CompleteElicitationNotification {
+    type Output = super::CompleteElicitationNotification;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            session_id,
-            tool_call_id,
+            elicitation_id,
+            meta,
         } = self;
-        Ok(super::ElicitationSessionScope {
-            session_id: session_id.into_v2()?,
-            tool_call_id: tool_call_id.into_v2()?,
+        Ok(super::CompleteElicitationNotification {
+            elicitation_id: elicitation_id.into_v2()?,
+            meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationRequestScope {
-    type Output = crate::v1::ElicitationRequestScope;
+impl IntoV1 for super::UrlElicitationRequiredData {
+    type Output = crate::v1::UrlElicitationRequiredData;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { request_id } = self;
-        Ok(crate::v1::ElicitationRequestScope {
-            request_id: request_id.into_v1()?,
+        let Self { elicitations } = self;
+        Ok(crate::v1::UrlElicitationRequiredData {
+            elicitations: elicitations.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationRequestScope {
-    type Output = super::ElicitationRequestScope;
+impl IntoV2 for crate::v1::UrlElicitationRequiredData {
+    type Output = super::UrlElicitationRequiredData;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { request_id } = self;
-        Ok(super::ElicitationRequestScope {
-            request_id: request_id.into_v2()?,
+        let Self { elicitations } = self;
+        Ok(super::UrlElicitationRequiredData {
+            elicitations: elicitations.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::CreateElicitationRequest {
-    type Output = crate::v1::CreateElicitationRequest;
+impl IntoV1 for super::UrlElicitationRequiredItem {
+    type Output = crate::v1::UrlElicitationRequiredItem;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
             mode,
+            elicitation_id,
+            url,
             message,
-            meta,
         } = self;
-        Ok(crate::v1::CreateElicitationRequest {
+        Ok(crate::v1::UrlElicitationRequiredItem {
             mode: mode.into_v1()?,
+            elicitation_id: elicitation_id.into_v1()?,
+            url: url.into_v1()?,
             message: message.into_v1()?,
-            meta: meta.into_v1()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::CreateElicitationRequest {
-    type Output = super::CreateElicitationRequest;
+impl IntoV2 for crate::v1::UrlElicitationRequiredItem {
+    type Output = super::UrlElicitationRequiredItem;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
             mode,
+            elicitation_id,
+            url,
             message,
-            meta,
         } = self;
-        Ok(super::CreateElicitationRequest {
+        Ok(super::UrlElicitationRequiredItem {
             mode: mode.into_v2()?,
+            elicitation_id: elicitation_id.into_v2()?,
+            url: url.into_v2()?,
             message: message.into_v2()?,
-            meta: meta.into_v2()?,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationMode {
-    type Output = crate::v1::ElicitationMode;
+impl IntoV1 for super::ElicitationUrlOnlyMode {
+    type Output = crate::v1::ElicitationUrlOnlyMode;
 
     fn into_v1(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::Form(value) => crate::v1::ElicitationMode::Form(value.into_v1()?),
-            Self::Url(value) => crate::v1::ElicitationMode::Url(value.into_v1()?),
-            Self::Other(value) => crate::v1::ElicitationMode::Other(value.into_v1()?),
+            Self::Url => crate::v1::ElicitationUrlOnlyMode::Url,
         })
     }
 }
 
 #[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationMode {
-    type Output = super::ElicitationMode;
+impl IntoV2 for crate::v1::ElicitationUrlOnlyMode {
+    type Output = super::ElicitationUrlOnlyMode;
 
     fn into_v2(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::Form(value) => super::ElicitationMode::Form(value.into_v2()?),
-            Self::Url(value) => super::ElicitationMode::Url(value.into_v2()?),
-            Self::Other(value) => super::ElicitationMode::Other(value.into_v2()?),
+            Self::Url => super::ElicitationUrlOnlyMode::Url,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::OtherElicitationMode {
-    type Output = crate::v1::OtherElicitationMode;
+#[cfg(feature = "unstable_sampling")]
+impl IntoV1 for super::SamplingCapabilities {
+    type Output = crate::v1::SamplingCapabilities;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(crate::v1::SamplingCapabilities {
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_sampling")]
+impl IntoV2 for crate::v1::SamplingCapabilities {
+    type Output = super::SamplingCapabilities;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self { meta } = self;
+        Ok(super::SamplingCapabilities {
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_sampling")]
+impl IntoV1 for super::SamplingMessage {
+    type Output = crate::v1::SamplingMessage;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            mode,
-            scope,
-            fields,
+            role,
+            content,
+            meta,
         } = self;
-        Ok(crate::v1::OtherElicitationMode {
-            mode: mode.into_v1()?,
-            scope: scope.into_v1()?,
-            fields: fields.into_v1()?,
+        Ok(crate::v1::SamplingMessage {
+            role: role.into_v1()?,
+            content: content.into_v1()?,
+            meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::OtherElicitationMode {
-    type Output = super::OtherElicitationMode;
+#[cfg(feature = "unstable_sampling")]
+impl IntoV2 for crate::v1::SamplingMessage {
+    type Output = super::SamplingMessage;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            mode,
-            scope,
-            fields,
+            role,
+            content,
+            meta,
         } = self;
-        Ok(super::OtherElicitationMode {
-            mode: mode.into_v2()?,
-            scope: scope.into_v2()?,
-            fields: fields.into_v2()?,
+        Ok(super::SamplingMessage {
+            role: role.into_v2()?,
+            content: content.into_v2()?,
+            meta: meta.into_v2()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_sampling")]
+impl IntoV1 for super::ModelPreferences {
+    type Output = crate::v1::ModelPreferences;
+
+    fn into_v1(self) -> Result<Self::Output> {
+        let Self {
+            hints,
+            cost_priority,
+            speed_priority,
+            intelligence_priority,
+            meta,
+        } = self;
+        Ok(crate::v1::ModelPreferences {
+            hints: into_v1_vec_skip_errors(hints),
+            cost_priority: into_v1_default_on_error(cost_priority),
+            speed_priority: into_v1_default_on_error(speed_priority),
+            intelligence_priority: into_v1_default_on_error(intelligence_priority),
+            meta: meta.into_v1()?,
+        })
+    }
+}
+
+#[cfg(feature = "unstable_sampling")]
+impl IntoV2 for crate::v1::ModelPreferences {
+    type Output = super::ModelPreferences;
+
+    fn into_v2(self) -> Result<Self::Output> {
+        let Self {
+            hints,
+            cost_priority,
+            speed_priority,
+            intelligence_priority,
+            meta,
+        } = self;
+        Ok(super::ModelPreferences {
+            hints: into_v2_vec_skip_errors(hints),
+            cost_priority: into_v2_default_on_error(cost_priority),
+            speed_priority: into_v2_default_on_error(speed_priority),
+            intelligence_priority: into_v2_default_on_error(intelligence_priority),
+            meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationFormMode {
-    type Output = crate::v1::ElicitationFormMode;
+#[cfg(feature = "unstable_sampling")]
+impl IntoV1 for super::ModelHint {
+    type Output = crate::v1::ModelHint;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self {
-            scope,
-            requested_schema,
-        } = self;
-        Ok(crate::v1::ElicitationFormMode {
-            scope: scope.into_v1()?,
-            requested_schema: requested_schema.into_v1()?,
+        let Self { name, meta } = self;
+        Ok(crate::v1::ModelHint {
+            name: into_v1_default_on_error(name),
+            meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationFormMode {
-    type Output = super::ElicitationFormMode;
+#[cfg(feature = "unstable_sampling")]
+impl IntoV2 for crate::v1::ModelHint {
+    type Output = super::ModelHint;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self {
-            scope,
-            requested_schema,
-        } = self;
-        Ok(super::ElicitationFormMode {
-            scope: scope.into_v2()?,
-            requested_schema: requested_schema.into_v2()?,
+        let Self { name, meta } = self;
+        Ok(super::ModelHint {
+            name: into_v2_default_on_error(name),
+            meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationUrlMode {
-    type Output = crate::v1::ElicitationUrlMode;
+#[cfg(feature = "unstable_sampling")]
+impl IntoV1 for super::CreateMessageRequest {
+    type Output = crate::v1::CreateMessageRequest;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            scope,
-            elicitation_id,
-            url,
+            session_id,
+            messages,
+            max_tokens,
+            model_preferences,
+            meta,
         } = self;
-        Ok(crate::v1::ElicitationUrlMode {
-            scope: scope.into_v1()?,
-            elicitation_id: elicitation_id.into_v1()?,
-            url: url.into_v1()?,
+        Ok(crate::v1::CreateMessageRequest {
+            session_id: session_id.into_v1()?,
+            messages: messages.into_v1()?,
+            max_tokens: max_tokens.into_v1()?,
+            model_preferences: into_v1_default_on_error(model_preferences),
+            meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationUrlMode {
-    type Output = super::ElicitationUrlMode;
+#[cfg(feature = "unstable_sampling")]
+impl IntoV2 for crate::v1::CreateMessageRequest {
+    type Output = super::CreateMessageRequest;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            scope,
-            elicitation_id,
-            url,
+            session_id,
+            messages,
+            max_tokens,
+            model_preferences,
+            meta,
         } = self;
-        Ok(super::ElicitationUrlMode {
-            scope: scope.into_v2()?,
-            elicitation_id: elicitation_id.into_v2()?,
-            url: url.into_v2()?,
+        Ok(super::CreateMessageRequest {
+            session_id: session_id.into_v2()?,
+            messages: messages.into_v2()?,
+            max_tokens: max_tokens.into_v2()?,
+            model_preferences: into_v2_default_on_error(model_preferences),
+            meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::CreateElicitationResponse {
-    type Output = crate::v1::CreateElicitationResponse;
+#[cfg(feature = "unstable_sampling")]
+impl IntoV1 for super::CreateMessageResponse {
+    type Output = crate::v1::CreateMessageResponse;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { action, meta } = self;
-        Ok(crate::v1::CreateElicitationResponse {
-            action: action.into_v1()?,
+        let Self {
+            content,
+            stop_reason,
+            model,
+            meta,
+        } = self;
+        Ok(crate::v1::CreateMessageResponse {
+            content: content.into_v1()?,
+            stop_reason: stop_reason.into_v1()?,
+            model: into_v1_default_on_error(model),
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::CreateElicitationResponse {
-    type Output = super::CreateElicitationResponse;
+#[cfg(feature = "unstable_sampling")]
+impl IntoV2 for crate::v1::CreateMessageResponse {
+    type Output = super::CreateMessageResponse;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { action, meta } = self;
-        Ok(super::CreateElicitationResponse {
-            action: action.into_v2()?,
+        let Self {
+            content,
+            stop_reason,
+            model,
+            meta,
+        } = self;
+        Ok(super::CreateMessageResponse {
+            content: content.into_v2()?,
+            stop_reason: stop_reason.into_v2()?,
+            model: into_v2_default_on_error(model),
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationAction {
-    type Output = crate::v1::ElicitationAction;
+impl IntoV1 for super::ContentBlock {
+    type Output = crate::v1::ContentBlock;
 
     fn into_v1(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::Accept(value) => crate::v1::ElicitationAction::Accept(value.into_v1()?),
-            Self::Decline => crate::v1::ElicitationAction::Decline,
-            Self::Cancel => crate::v1::ElicitationAction::Cancel,
-            Self::Other(value) => crate::v1::ElicitationAction::Other(value.into_v1()?),
+            Self::Text(value) => crate::v1::ContentBlock::Text(value.into_v1()?),
+            Self::Image(value) => crate::v1::ContentBlock::Image(value.into_v1()?),
+            Self::Audio(value) => crate::v1::ContentBlock::Audio(value.into_v1()?),
+            Self::ResourceLink(value) => crate::v1::ContentBlock::ResourceLink(value.into_v1()?),
+            Self::Resource(value) => crate::v1::ContentBlock::Resource(value.into_v1()?),
+            #[cfg(feature = "unstable_tool_result_ref")]
+            Self::ToolResultRef(value) => crate::v1::ContentBlock::ToolResultRef(value.into_v1()?),
+            #[cfg(feature = "unstable_mention")]
+            Self::Mention(value) => crate::v1::ContentBlock::Mention(value.into_v1()?),
+            #[cfg(feature = "unstable_table")]
+            Self::Table(value) => crate::v1::ContentBlock::Table(value.into_v1()?),
+            #[cfg(feature = "unstable_json_content")]
+            Self::Json(value) => crate::v1::ContentBlock::Json(value.into_v1()?),
+            Self::Other(value) => {
+                return Err(unknown_v2_enum_variant("ContentBlock", &value.type_));
+            }
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationAction {
-    type Output = super::ElicitationAction;
+impl IntoV2 for crate::v1::ContentBlock {
+    type Output = super::ContentBlock;
 
     fn into_v2(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::Accept(value) => super::ElicitationAction::Accept(value.into_v2()?),
-            Self::Decline => super::ElicitationAction::Decline,
-            Self::Cancel => super::ElicitationAction::Cancel,
-            Self::Other(value) => super::ElicitationAction::Other(value.into_v2()?),
+            Self::Text(value) => super::ContentBlock::Text(value.into_v2()?),
+            Self::Image(value) => super::ContentBlock::Image(value.into_v2()?),
+            Self::Audio(value) => super::ContentBlock::Audio(value.into_v2()?),
+            Self::ResourceLink(value) => super::ContentBlock::ResourceLink(value.into_v2()?),
+            Self::Resource(value) => super::ContentBlock::Resource(value.into_v2()?),
+            #[cfg(feature = "unstable_tool_result_ref")]
+            Self::ToolResultRef(value) => super::ContentBlock::ToolResultRef(value.into_v2()?),
+            #[cfg(feature = "unstable_mention")]
+            Self::Mention(value) => super::ContentBlock::Mention(value.into_v2()?),
+            #[cfg(feature = "unstable_table")]
+            Self::Table(value) => super::ContentBlock::Table(value.into_v2()?),
+            #[cfg(feature = "unstable_json_content")]
+            Self::Json(value) => super::ContentBlock::Json(value.into_v2()?),
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::OtherElicitationAction {
-    type Output = crate::v1::OtherElicitationAction;
+#[cfg(feature = "unstable_tool_result_ref")]
+impl IntoV1 for super::ToolResultRef {
+    type Output = crate::v1::ToolResultRef;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { action, fields } = self;
-        Ok(crate::v1::OtherElicitationAction {
-            action: action.into_v1()?,
-            fields: fields.into_v1()?,
+        let Self { tool_call_id, meta } = self;
+        Ok(crate::v1::ToolResultRef {
+            tool_call_id: tool_call_id.into_v1()?,
+            meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::OtherElicitationAction {
-    type Output = super::OtherElicitationAction;
+#[cfg(feature = "unstable_tool_result_ref")]
+impl IntoV2 for crate::v1::ToolResultRef {
+    type Output = super::ToolResultRef;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { action, fields } = self;
-        Ok(super::OtherElicitationAction {
-            action: action.into_v2()?,
-            fields: fields.into_v2()?,
+        let Self { tool_call_id, meta } = self;
+        Ok(super::ToolResultRef {
+            tool_call_id: tool_call_id.into_v2()?,
+            meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationAcceptAction {
-    type Output = crate::v1::ElicitationAcceptAction;
+#[cfg(feature = "unstable_mention")]
+impl IntoV1 for super::Mention {
+    type Output = crate::v1::Mention;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { content } = self;
-        Ok(crate::v1::ElicitationAcceptAction {
-            content: content.into_v1()?,
+        let Self {
+            kind,
+            label,
+            uri,
+            meta,
+        } = self;
+        Ok(crate::v1::Mention {
+            kind: kind.into_v1()?,
+            label: label.into_v1()?,
+            uri: uri.into_v1()?,
+            meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationAcceptAction {
-    type Output = super::ElicitationAcceptAction;
+#[cfg(feature = "unstable_mention")]
+impl IntoV2 for crate::v1::Mention {
+    type Output = super::Mention;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { content } = self;
-        Ok(super::ElicitationAcceptAction {
-            content: content.into_v2()?,
+        let Self {
+            kind,
+            label,
+            uri,
+            meta,
+        } = self;
+        Ok(super::Mention {
+            kind: kind.into_v2()?,
+            label: label.into_v2()?,
+            uri: uri.into_v2()?,
+            meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationContentValue {
-    type Output = crate::v1::ElicitationContentValue;
+#[cfg(feature = "unstable_mention")]
+impl IntoV1 for super::MentionKind {
+    type Output = crate::v1::MentionKind;
 
     fn into_v1(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::String(value) => crate::v1::ElicitationContentValue::String(value.into_v1()?),
-            Self::Integer(value) => crate::v1::ElicitationContentValue::Integer(value.into_v1()?),
-            Self::Number(value) => crate::v1::ElicitationContentValue::Number(value.into_v1()?),
-            Self::Boolean(value) => crate::v1::ElicitationContentValue::Boolean(value.into_v1()?),
-            Self::StringArray(value) => {
-                crate::v1::ElicitationContentValue::StringArray(value.into_v1()?)
+            Self::File => crate::v1::MentionKind::File,
+            Self::Symbol => crate::v1::MentionKind::Symbol,
+            Self::Url => crate::v1::MentionKind::Url,
+            Self::Other(value) => {
+                return Err(unknown_v2_enum_variant("MentionKind", &value));
             }
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationContentValue {
-    type Output = super::ElicitationContentValue;
+#[cfg(feature = "unstable_mention")]
+impl IntoV2 for crate::v1::MentionKind {
+    type Output = super::MentionKind;
 
     fn into_v2(self) -> Result<Self::Output> {
         Ok(match self {
-            Self::String(value) => super::ElicitationContentValue::String(value.into_v2()?),
-            Self::Integer(value) => super::ElicitationContentValue::Integer(value.into_v2()?),
-            Self::Number(value) => super::ElicitationContentValue::Number(value.into_v2()?),
-            Self::Boolean(value) => super::ElicitationContentValue::Boolean(value.into_v2()?),
-            Self::StringArray(value) => {
-                super::ElicitationContentValue::StringArray(value.into_v2()?)
-            }
+            Self::File => super::MentionKind::File,
+            Self::Symbol => super::MentionKind::Symbol,
+            Self::Url => super::MentionKind::Url,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::CompleteElicitationNotification {
-    type Output = crate::v1::CompleteElicitationNotification;
+#[cfg(feature = "unstable_table")]
+impl IntoV1 for super::Table {
+    type Output = crate::v1::Table;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            elicitation_id,
+            columns,
+            rows,
             meta,
         } = self;
-        Ok(crate::v1::CompleteElicitationNotification {
-            elicitation_id: elicitation_id.into_v1()?,
+        Ok(crate::v1::Table {
+            columns: columns.into_v1()?,
+            rows: rows.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::CompleteElicitationNotification {
-    type Output = super::CompleteElicitationNotification;
+#[cfg(feature = "unstable_table")]
+impl IntoV2 for crate::v1::Table {
+    type Output = super::Table;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            elicitation_id,
+            columns,
+            rows,
             meta,
         } = self;
-        Ok(super::CompleteElicitationNotification {
-            elicitation_id: elicitation_id.into_v2()?,
+        Ok(super::Table {
+            columns: columns.into_v2()?,
+            rows: rows.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::UrlElicitationRequiredData {
-    type Output = crate::v1::UrlElicitationRequiredData;
+#[cfg(feature = "unstable_table")]
+impl IntoV1 for super::TableCapabilities {
+    type Output = crate::v1::TableCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        let Self { elicitations } = self;
-        Ok(crate::v1::UrlElicitationRequiredData {
-            elicitations: elicitations.into_v1()?,
+        let Self { meta } = self;
+        Ok(crate::v1::TableCapabilities {
+            meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::UrlElicitationRequiredData {
-    type Output = super::UrlElicitationRequiredData;
+#[cfg(feature = "unstable_table")]
+impl IntoV2 for crate::v1::TableCapabilities {
+    type Output = super::TableCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        let Self { elicitations } = self;
-        Ok(super::UrlElicitationRequiredData {
-            elicitations: elicitations.into_v2()?,
+        let Self { meta } = self;
+        Ok(super::TableCapabilities {
+            meta: meta.into_v2()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::UrlElicitationRequiredItem {
-    type Output = crate::v1::UrlElicitationRequiredItem;
+#[cfg(feature = "unstable_json_content")]
+impl IntoV1 for super::JsonContent {
+    type Output = crate::v1::JsonContent;
 
     fn into_v1(self) -> Result<Self::Output> {
         let Self {
-            mode,
-            elicitation_id,
-            url,
-            message,
+            value,
+            schema,
+            meta,
         } = self;
-        Ok(crate::v1::UrlElicitationRequiredItem {
-            mode: mode.into_v1()?,
-            elicitation_id: elicitation_id.into_v1()?,
-            url: url.into_v1()?,
-            message: message.into_v1()?,
+        Ok(crate::v1::JsonContent {
+            value: value.into_v1()?,
+            schema: into_v1_default_on_error(schema),
+            meta: meta.into_v1()?,
         })
     }
 }
 
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::UrlElicitationRequiredItem {
-    type Output = super::UrlElicitationRequiredItem;
+#[cfg(feature = "unstable_json_content")]
+impl IntoV2 for crate::v1::JsonContent {
+    type Output = super::JsonContent;
 
     fn into_v2(self) -> Result<Self::Output> {
         let Self {
-            mode,
-            elicitation_id,
-            url,
-            message,
-        } = self;
-        Ok(super::UrlElicitationRequiredItem {
-            mode: mode.into_v2()?,
-            elicitation_id: elicitation_id.into_v2()?,
-            url: url.into_v2()?,
-            message: message.into_v2()?,
-        })
-    }
-}
-
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV1 for super::ElicitationUrlOnlyMode {
-    type Output = crate::v1::ElicitationUrlOnlyMode;
-
-    fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Url => crate::v1::ElicitationUrlOnlyMode::Url,
-        })
-    }
-}
-
-#[cfg(feature = "unstable_elicitation")]
-impl IntoV2 for crate::v1::ElicitationUrlOnlyMode {
-    type Output = super::ElicitationUrlOnlyMode;
-
-    fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Url => super::ElicitationUrlOnlyMode::Url,
+            value,
+            schema,
+            meta,
+        } = self;
+        Ok(super::JsonContent {
+            value: value.into_v2()?,
+            schema: into_v2_default_on_error(schema),
+            meta: meta.into_v2()?,
         })
     }
 }
 
-impl IntoV1 for super::ContentBlock {
-    type Output = crate::v1::ContentBlock;
+#[cfg(feature = "unstable_json_content")]
+impl IntoV1 for super::JsonContentCapabilities {
+    type Output = crate::v1::JsonContentCapabilities;
 
     fn into_v1(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Text(value) => crate::v1::ContentBlock::Text(value.into_v1()?),
-            Self::Image(value) => crate::v1::ContentBlock::Image(value.into_v1()?),
-            Self::Audio(value) => crate::v1::ContentBlock::Audio(value.into_v1()?),
-            Self::ResourceLink(value) => crate::v1::ContentBlock::ResourceLink(value.into_v1()?),
-            Self::Resource(value) => crate::v1::ContentBlock::Resource(value.into_v1()?),
-            Self::Other(value) => {
-                return Err(unknown_v2_enum_variant("ContentBlock", &value.type_));
-            }
+        let Self { meta } = self;
+        Ok(crate::v1::JsonContentCapabilities {
+            meta: meta.into_v1()?,
         })
     }
 }
 
-impl IntoV2 for crate::v1::ContentBlock {
-    type Output = super::ContentBlock;
+#[cfg(feature = "unstable_json_content")]
+impl IntoV2 for crate::v1::JsonContentCapabilities {
+    type Output = super::JsonContentCapabilities;
 
     fn into_v2(self) -> Result<Self::Output> {
-        Ok(match self {
-            Self::Text(value) => super::ContentBlock::Text(value.into_v2()?),
-            Self::Image(value) => super::ContentBlock::Image(value.into_v2()?),
-            Self::Audio(value) => super::ContentBlock::Audio(value.into_v2()?),
-            Self::ResourceLink(value) => super::ContentBlock::ResourceLink(value.into_v2()?),
-            Self::Resource(value) => super::ContentBlock::Resource(value.into_v2()?),
+        let Self { meta } = self;
+        Ok(super::JsonContentCapabilities {
+            meta: meta.into_v2()?,
         })
     }
 }
@@ -9040,12 +11270,16 @@ impl IntoV1 for super::Annotations {
             audience,
             last_modified,
             priority,
+            #[cfg(feature = "unstable_content_truncation")]
+            truncated,
             meta,
         } = self;
         Ok(crate::v1::Annotations {
             audience: option_vec_into_v1_skip_errors(audience),
             last_modified: last_modified.into_v1()?,
             priority: priority.into_v1()?,
+            #[cfg(feature = "unstable_content_truncation")]
+            truncated: truncated.into_v1()?,
             meta: meta.into_v1()?,
         })
     }
@@ -9059,12 +11293,16 @@ impl IntoV2 for crate::v1::Annotations {
             audience,
             last_modified,
             priority,
+            #[cfg(feature = "unstable_content_truncation")]
+            truncated,
             meta,
         } = self;
         Ok(super::Annotations {
             audience: option_vec_into_v2_skip_errors(audience),
             last_modified: last_modified.into_v2()?,
             priority: priority.into_v2()?,
+            #[cfg(feature = "unstable_content_truncation")]
+            truncated: truncated.into_v2()?,
             meta: meta.into_v2()?,
         })
     }
@@ -9309,6 +11547,23 @@ mod tests {
         assert!(converted_json.get("info").is_some());
     }
 
+    #[cfg(feature = "unstable_locale")]
+    #[test]
+    fn round_trips_locale() {
+        let request = v1::InitializeRequest::new(ProtocolVersion::V1)
+            .client_info(v1::Implementation::new("test-client", "1.0.0"))
+            .locale("ja-JP");
+        let converted: v2::InitializeRequest = v1_to_v2(request).unwrap();
+        assert_eq!(converted.locale.as_deref(), Some("ja-JP"));
+        let back: v1::InitializeRequest = v2_to_v1(converted).unwrap();
+        assert_eq!(back.locale.as_deref(), Some("ja-JP"));
+
+        #[cfg(feature = "unstable_session_context")]
+        assert_v1_round_trip::<v1::SessionContext, v2::SessionContext>(
+            v1::SessionContext::new("linux").locale("fr-CA"),
+        );
+    }
+
     #[test]
     fn round_trips_initialize_response() {
         let response = v1::InitializeResponse::new(ProtocolVersion::V1)
@@ -9329,6 +11584,34 @@ mod tests {
         assert_eq!(converted_json.pointer("/capabilities/loadSession"), None);
     }
 
+    #[cfg(feature = "unstable_agent_ready")]
+    #[test]
+    fn round_trips_agent_ready() {
+        let response = v1::InitializeResponse::new(ProtocolVersion::V1)
+            .agent_capabilities(
+                v1::AgentCapabilities::new()
+                    .auth(v1::AgentAuthCapabilities::new().logout(v1::LogoutCapabilities::new())),
+            )
+            .agent_info(v1::Implementation::new("test-agent", "2.0.0"))
+            .ready(false);
+        assert_v1_round_trip::<v1::InitializeResponse, v2::InitializeResponse>(response.clone());
+        let converted: v2::InitializeResponse =
+            v1_to_v2(response).expect("v1 -> v2 conversion failed");
+        assert!(!converted.is_ready());
+        assert_eq!(
+            serde_json::to_value(&converted).expect("v2 serialize")["ready"],
+            false
+        );
+
+        let notification =
+            v1::AgentNotification::AgentReadyNotification(v1::AgentReadyNotification::new());
+        let as_v2: v2::AgentNotification = v1_to_v2(notification).expect("v1 -> v2 conversion");
+        assert_eq!(as_v2.method(), "agent/ready");
+        let back = v2_to_v1_many(as_v2).expect("v2 -> v1 conversion");
+        assert_eq!(back.len(), 1);
+        assert_eq!(back[0].method(), "agent/ready");
+    }
+
     #[test]
     fn agent_load_session_capability_moves_between_v1_and_v2() {
         let v1_capabilities = v1::AgentCapabilities::new().load_session(true);
@@ -9632,6 +11915,72 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "unstable_fs_watch")]
+    #[test]
+    fn v1_fs_watch_methods_do_not_convert_to_v2() {
+        assert_v1_to_v2_error(
+            v1::AgentRequest::WatchPathsRequest(v1::WatchPathsRequest::new(
+                "sess",
+                vec!["/workspace".into()],
+            )),
+            "v1 AgentRequest variant `fs/watch` cannot be represented in v2",
+        );
+        assert_v1_to_v2_error(
+            v1::ClientResponse::WatchPathsResponse(v1::WatchPathsResponse::new()),
+            "v1 ClientResponse variant `fs/watch` cannot be represented in v2",
+        );
+        assert_v1_to_v2_error(
+            v1::ClientNotification::FileChangedNotification(v1::FileChangedNotification::new(
+                "sess",
+                "/workspace/file.txt",
+                v1::ChangeKind::Deleted,
+            )),
+            "v1 ClientNotification variant `fs/file_changed` cannot be represented in v2",
+        );
+    }
+
+    #[cfg(feature = "unstable_clipboard")]
+    #[test]
+    fn v1_clipboard_methods_do_not_convert_to_v2() {
+        assert_v1_to_v2_error(
+            v1::AgentRequest::ReadClipboardRequest(v1::ReadClipboardRequest::new("sess")),
+            "v1 AgentRequest variant `clipboard/read` cannot be represented in v2",
+        );
+        assert_v1_to_v2_error(
+            v1::AgentRequest::WriteClipboardRequest(v1::WriteClipboardRequest::new(
+                "sess",
+                "copied".into(),
+            )),
+            "v1 AgentRequest variant `clipboard/write` cannot be represented in v2",
+        );
+        assert_v1_to_v2_error(
+            v1::ClientResponse::ReadClipboardResponse(Box::new(v1::ReadClipboardResponse::new(
+                "copied".into(),
+            ))),
+            "v1 ClientResponse variant `clipboard/read` cannot be represented in v2",
+        );
+        assert_v1_to_v2_error(
+            v1::ClientResponse::WriteClipboardResponse(v1::WriteClipboardResponse::new()),
+            "v1 ClientResponse variant `clipboard/write` cannot be represented in v2",
+        );
+    }
+
+    #[cfg(feature = "unstable_env")]
+    #[test]
+    fn v1_env_methods_do_not_convert_to_v2() {
+        assert_v1_to_v2_error(
+            v1::AgentRequest::GetEnvRequest(v1::GetEnvRequest::new(
+                "sess",
+                vec!["EDITOR".to_string()],
+            )),
+            "v1 AgentRequest variant `env/get` cannot be represented in v2",
+        );
+        assert_v1_to_v2_error(
+            v1::ClientResponse::GetEnvResponse(v1::GetEnvResponse::default()),
+            "v1 ClientResponse variant `env/get` cannot be represented in v2",
+        );
+    }
+
     #[test]
     fn v1_terminal_tool_call_content_does_not_convert_to_v2() {
         assert_v1_to_v2_error(
@@ -9698,6 +12047,251 @@ mod tests {
         assert_json_eq_after_v1_to_v2::<v1::PromptRequest, v2::PromptRequest>(request);
     }
 
+    #[cfg(feature = "unstable_reasoning_effort")]
+    #[test]
+    fn round_trips_prompt_request_reasoning_effort() {
+        let request =
+            v1::PromptRequest::new("sess_1", vec![]).reasoning_effort(v1::ReasoningEffort::High);
+        assert_v1_round_trip::<v1::PromptRequest, v2::PromptRequest>(request.clone());
+        assert_json_eq_after_v1_to_v2::<v1::PromptRequest, v2::PromptRequest>(request);
+
+        // The hint is advisory, so a level v1 cannot represent is dropped.
+        let request = v2::PromptRequest::new("sess_1", vec![])
+            .reasoning_effort(v2::ReasoningEffort::Other("_max".to_string()));
+        assert_eq!(request.into_v1().unwrap().reasoning_effort, None);
+    }
+
+    #[cfg(feature = "unstable_sampling_overrides")]
+    #[test]
+    fn round_trips_prompt_request_sampling_overrides() {
+        let request = v1::PromptRequest::new("sess_1", vec![]).overrides(
+            v1::SamplingOverrides::new()
+                .temperature(0.5)
+                .top_p(0.75)
+                .max_tokens(1024),
+        );
+        assert_v1_round_trip::<v1::PromptRequest, v2::PromptRequest>(request.clone());
+        assert_json_eq_after_v1_to_v2::<v1::PromptRequest, v2::PromptRequest>(request);
+
+        let capabilities = v1::PromptCapabilities::new().sampling_overrides(true);
+        assert_v1_round_trip::<v1::PromptCapabilities, v2::PromptCapabilities>(capabilities);
+    }
+
+    #[cfg(feature = "unstable_prompt_deadline")]
+    #[test]
+    fn round_trips_prompt_deadline() {
+        let request = v1::PromptRequest::new("sess_1", vec![]).deadline_ms(30_000);
+        assert_v1_round_trip::<v1::PromptRequest, v2::PromptRequest>(request.clone());
+        assert_json_eq_after_v1_to_v2::<v1::PromptRequest, v2::PromptRequest>(request);
+
+        assert_eq!(
+            v1::StopReason::Deadline.into_v2().unwrap(),
+            v2::StopReason::Deadline
+        );
+        assert_eq!(
+            v2::StopReason::Deadline.into_v1().unwrap(),
+            v1::StopReason::Deadline
+        );
+    }
+
+    #[cfg(feature = "unstable_concurrent_prompts")]
+    #[test]
+    fn converts_concurrent_prompts_capability() {
+        let capabilities = v1::AgentCapabilities::new().concurrent_prompts(true);
+        let v2_capabilities = capabilities.into_v2().unwrap();
+        assert_eq!(
+            v2_capabilities.concurrent_prompts,
+            Some(v2::ConcurrentPromptsCapabilities::new())
+        );
+        assert!(v2_capabilities.into_v1().unwrap().concurrent_prompts);
+
+        assert_eq!(
+            v1::ErrorCode::SessionBusy.into_v2().unwrap(),
+            v2::ErrorCode::SessionBusy
+        );
+    }
+
+    #[cfg(feature = "unstable_session_not_found")]
+    #[test]
+    fn converts_session_not_found_error() {
+        assert_eq!(
+            v1::ErrorCode::SessionNotFound.into_v2().unwrap(),
+            v2::ErrorCode::SessionNotFound
+        );
+        assert_eq!(
+            v2::ErrorCode::SessionNotFound.into_v1().unwrap(),
+            v1::ErrorCode::SessionNotFound
+        );
+
+        let error = v1::Error::session_not_found("sess_abc123");
+        assert_eq!(
+            error.clone().into_v2().unwrap(),
+            v2::Error::session_not_found("sess_abc123")
+        );
+        assert_eq!(
+            v2::Error::session_not_found("sess_abc123")
+                .into_v1()
+                .unwrap(),
+            error
+        );
+    }
+
+    #[cfg(feature = "unstable_accepted_mime_types")]
+    #[test]
+    fn converts_accepted_mime_types() {
+        let accepted_mime_types = vec!["image/png".to_string(), "audio/*".to_string()];
+        let capabilities =
+            v1::AgentCapabilities::new().accepted_mime_types(accepted_mime_types.clone());
+        let v2_capabilities = capabilities.into_v2().unwrap();
+        assert_eq!(v2_capabilities.accepted_mime_types, accepted_mime_types);
+        assert_eq!(
+            v2_capabilities.into_v1().unwrap().accepted_mime_types,
+            accepted_mime_types
+        );
+    }
+
+    #[cfg(feature = "unstable_turn_id")]
+    #[test]
+    fn converts_turn_id_on_session_notifications() {
+        let notification = v1::SessionNotification::new(
+            "sess",
+            v1::SessionUpdate::AgentMessageChunk(
+                v1::ContentChunk::new("hi".into()).message_id("msg_agent"),
+            ),
+        )
+        .turn_id("turn_1");
+        let as_v2: v2::UpdateSessionNotification =
+            v1_to_v2(notification.clone()).expect("v1 -> v2 conversion");
+        assert_eq!(as_v2.turn_id, Some(v2::TurnId::new("turn_1")));
+        assert_eq!(
+            v2_to_v1_many(as_v2).expect("v2 -> v1 conversion"),
+            vec![notification]
+        );
+
+        // Every v1 notification fanned out from one v2 update keeps its turn.
+        let notification = v2::UpdateSessionNotification::new(
+            "sess",
+            v2::SessionUpdate::AgentMessage(v2::AgentMessage::new("msg_agent").content(vec![
+                v2::ContentBlock::Text(v2::TextContent::new("hello")),
+                v2::ContentBlock::Text(v2::TextContent::new("world")),
+            ])),
+        )
+        .turn_id("turn_2");
+        let notifications = v2_to_v1_many(notification).expect("v2 -> v1 conversion");
+        assert_eq!(notifications.len(), 2);
+        assert!(
+            notifications
+                .iter()
+                .all(|notification| notification.turn_id == Some(v1::TurnId::new("turn_2")))
+        );
+
+        assert_v1_round_trip(v1::PromptRequest::new("sess", vec!["hi".into()]).turn_id("turn_3"));
+    }
+
+    #[cfg(feature = "unstable_edit_conflict")]
+    #[test]
+    fn round_trips_report_edit_conflict_notification() {
+        let notification = v1::ReportEditConflictNotification::new(
+            "sess_1",
+            "call_1",
+            v1::ConflictReport::new("/src/main.rs", "fn main() {}\n", "fn main() { run() }\n"),
+        );
+        assert_v1_round_trip::<
+            v1::ReportEditConflictNotification,
+            v2::ReportEditConflictNotification,
+        >(notification.clone());
+        assert_json_eq_after_v1_to_v2::<
+            v1::ReportEditConflictNotification,
+            v2::ReportEditConflictNotification,
+        >(notification.clone());
+
+        let converted = v1_to_v2(v1::ClientNotification::ReportEditConflictNotification(
+            notification,
+        ))
+        .unwrap();
+        assert!(matches!(
+            converted,
+            v2::ClientNotification::ReportEditConflictNotification(_)
+        ));
+    }
+
+    #[cfg(feature = "unstable_session_compact")]
+    #[test]
+    fn round_trips_compact_session() {
+        let request = v1::CompactSessionRequest::new("sess_1").target_tokens(20_000);
+        assert_v1_round_trip::<v1::CompactSessionRequest, v2::CompactSessionRequest>(
+            request.clone(),
+        );
+        let converted = v1_to_v2(v1::ClientRequest::CompactSessionRequest(request)).unwrap();
+        assert!(matches!(
+            converted,
+            v2::ClientRequest::CompactSessionRequest(_)
+        ));
+
+        let response = v1::CompactSessionResponse::new(12).summary("Set up the workspace.");
+        assert_v1_round_trip::<v1::CompactSessionResponse, v2::CompactSessionResponse>(
+            response.clone(),
+        );
+        assert_json_eq_after_v1_to_v2::<v1::CompactSessionResponse, v2::CompactSessionResponse>(
+            response,
+        );
+    }
+
+    #[cfg(feature = "unstable_session_set_title")]
+    #[test]
+    fn round_trips_set_session_title() {
+        let request = v1::SetSessionTitleRequest::new("sess_1", "Fix flaky parser test");
+        assert_v1_round_trip::<v1::SetSessionTitleRequest, v2::SetSessionTitleRequest>(
+            request.clone(),
+        );
+        let converted = v1_to_v2(v1::ClientRequest::SetSessionTitleRequest(request)).unwrap();
+        assert!(matches!(
+            converted,
+            v2::ClientRequest::SetSessionTitleRequest(_)
+        ));
+
+        assert_json_eq_after_v1_to_v2::<v1::SetSessionTitleResponse, v2::SetSessionTitleResponse>(
+            v1::SetSessionTitleResponse::new(),
+        );
+    }
+
+    #[cfg(feature = "unstable_cancel_tool_call")]
+    #[test]
+    fn round_trips_cancel_tool_call() {
+        let request = v1::CancelToolCallRequest::new("sess_1", "call_grep");
+        assert_v1_round_trip::<v1::CancelToolCallRequest, v2::CancelToolCallRequest>(
+            request.clone(),
+        );
+        let converted = v1_to_v2(v1::ClientRequest::CancelToolCallRequest(request)).unwrap();
+        assert!(matches!(
+            converted,
+            v2::ClientRequest::CancelToolCallRequest(_)
+        ));
+
+        assert_json_eq_after_v1_to_v2::<v1::CancelToolCallResponse, v2::CancelToolCallResponse>(
+            v1::CancelToolCallResponse::new(),
+        );
+    }
+
+    #[cfg(feature = "unstable_json_content")]
+    #[test]
+    fn round_trips_json_content_block() {
+        let block = v1::ContentBlock::Json(
+            v1::JsonContent::new(serde_json::json!({ "rows": [{ "id": 1 }, null], "done": true }))
+                .schema(serde_json::json!({ "type": "object" })),
+        );
+        assert_v1_round_trip::<v1::ContentBlock, v2::ContentBlock>(block.clone());
+        assert_json_eq_after_v1_to_v2::<v1::ContentBlock, v2::ContentBlock>(block);
+
+        let capabilities =
+            v1::ClientCapabilities::new().json_content(v1::JsonContentCapabilities::new());
+        let converted = v1_to_v2(capabilities).unwrap();
+        assert_eq!(
+            converted.json_content,
+            Some(v2::JsonContentCapabilities::new())
+        );
+    }
+
     #[cfg(feature = "unstable_elicitation")]
     #[test]
     fn round_trips_elicitation_property_schema_unknown_type() {
@@ -9917,6 +12511,18 @@ mod tests {
         assert_json_eq_after_v1_to_v2::<v1::ToolCallUpdate, v2::ToolCallUpdate>(update);
     }
 
+    #[cfg(feature = "unstable_tool_call_parent")]
+    #[test]
+    fn round_trips_tool_call_parent_id() {
+        let update = v1::ToolCallUpdate::new(
+            "call_grep",
+            v1::ToolCallUpdateFields::new().parent_id("call_agent"),
+        );
+
+        assert_v1_round_trip::<v1::ToolCallUpdate, v2::ToolCallUpdate>(update.clone());
+        assert_json_eq_after_v1_to_v2::<v1::ToolCallUpdate, v2::ToolCallUpdate>(update);
+    }
+
     #[test]
     fn round_trips_session_notification_for_unchanged_update_kinds() {
         fn content_chunk(text: &str, message_id: &str) -> v1::ContentChunk {
@@ -9932,6 +12538,33 @@ mod tests {
             v1::SessionUpdate::UsageUpdate(
                 v1::UsageUpdate::new(53_000, 200_000).cost(v1::Cost::new(0.045, "USD")),
             ),
+            #[cfg(feature = "unstable_session_notice")]
+            v1::SessionUpdate::Notice(v1::Notice::new(
+                "Reading 42 files",
+                v1::NoticeLevel::Warning,
+            )),
+            #[cfg(feature = "unstable_tool_calls_cancelled")]
+            v1::SessionUpdate::ToolCallsCancelled(v1::ToolCallsCancelled::new(vec![
+                v1::ToolCallId::new("call_001"),
+            ])),
+            #[cfg(feature = "unstable_attachment")]
+            v1::SessionUpdate::Attachment(v1::Attachment::new(
+                "chart.png",
+                v1::EmbeddedResource::new(v1::EmbeddedResourceResource::BlobResourceContents(
+                    v1::BlobResourceContents::new("iVBORw0KGgo=", "attachment:chart.png")
+                        .mime_type("image/png"),
+                )),
+            )),
+            #[cfg(feature = "unstable_input_request")]
+            v1::SessionUpdate::InputRequest(v1::InputRequest::new(
+                "input_1",
+                "Which branch should I deploy?",
+            )),
+            #[cfg(feature = "unstable_suggested_prompts")]
+            v1::SessionUpdate::SuggestedPrompts(v1::SuggestedPrompts::new(vec![
+                "Add tests for the parser".to_string(),
+                "Explain the change".to_string(),
+            ])),
         ];
         for update in cases {
             let notification = v1::SessionNotification::new("sess", update);
@@ -10096,6 +12729,43 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "unstable_session_update_sequence")]
+    #[test]
+    fn v2_sequenced_fan_out_is_rejected_instead_of_stalling_the_reorderer() {
+        let message = |sequence: Option<u64>, texts: &[&str]| {
+            v2::UpdateSessionNotification::new(
+                "sess",
+                v2::SessionUpdate::AgentMessage(
+                    v2::AgentMessage::new("msg_agent").content(
+                        texts
+                            .iter()
+                            .map(|text| v2::ContentBlock::Text(v2::TextContent::new(*text)))
+                            .collect::<Vec<_>>(),
+                    ),
+                ),
+            )
+            .sequence(sequence)
+        };
+        let mut reorderer = v1::SessionNotificationReorderer::new();
+
+        let first = v2_to_v1_many(message(Some(0), &["a"])).expect("v2 -> v1 conversion");
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].sequence, Some(0));
+        assert_eq!(reorderer.push(first[0].clone()), first);
+
+        assert!(v2_to_v1_many(message(Some(1), &["b", "c"])).is_err());
+
+        let unsequenced = v2_to_v1_many(message(None, &["b", "c"])).expect("v2 -> v1 conversion");
+        assert_eq!(unsequenced.len(), 2);
+        for notification in unsequenced {
+            assert_eq!(reorderer.push(notification.clone()), vec![notification]);
+        }
+
+        let next = v2_to_v1_many(message(Some(1), &["d"])).expect("v2 -> v1 conversion");
+        assert_eq!(reorderer.push(next[0].clone()), next);
+        assert_eq!(reorderer.missing(), None);
+    }
+
     #[test]
     fn v2_json_rpc_agent_notification_fans_out_to_v1_chunk_notifications() {
         let message = v2::JsonRpcMessage::wrap(v2::Notification {
@@ -10242,6 +12912,84 @@ mod tests {
         ));
     }
 
+    #[cfg(feature = "unstable_plan_entry_update")]
+    #[test]
+    fn v1_plan_entry_update_targets_legacy_v2_plan() {
+        let update = v1::SessionUpdate::PlanEntryUpdate(
+            v1::PlanEntryUpdate::new(1).status(v1::PlanEntryStatus::Completed),
+        );
+
+        let as_v2: v2::SessionUpdate = v1_to_v2(update.clone()).unwrap();
+        assert_eq!(
+            serde_json::to_value(&as_v2).unwrap(),
+            serde_json::json!({
+                "sessionUpdate": "plan_entry_update",
+                "planId": LEGACY_V1_PLAN_ID,
+                "index": 1,
+                "status": "completed"
+            })
+        );
+
+        let back = v2_to_v1_many(as_v2).unwrap();
+        #[cfg(not(feature = "unstable_plan_operations"))]
+        assert_eq!(back, vec![update]);
+        #[cfg(feature = "unstable_plan_operations")]
+        assert_eq!(
+            back,
+            vec![v1::SessionUpdate::PlanEntryUpdate(
+                v1::PlanEntryUpdate::new(1)
+                    .plan_id(v1::PlanId::new(LEGACY_V1_PLAN_ID))
+                    .status(v1::PlanEntryStatus::Completed),
+            )]
+        );
+    }
+
+    #[cfg(feature = "unstable_plan_entry_update")]
+    #[test]
+    fn v2_plan_entry_update_keeps_non_legacy_plan_id() {
+        let update = v2::SessionUpdate::PlanEntryUpdate(
+            v2::PlanEntryUpdate::new("plan-2", 0).status(v2::PlanEntryStatus::Completed),
+        );
+
+        #[cfg(not(feature = "unstable_plan_operations"))]
+        assert_v2_to_v1_many_error(
+            update,
+            "v2 PlanEntryUpdate for plan `plan-2` cannot be represented in v1 without plan operations",
+        );
+
+        #[cfg(feature = "unstable_plan_operations")]
+        {
+            let back = v2_to_v1_many(update.clone()).unwrap();
+            assert_eq!(
+                back,
+                vec![v1::SessionUpdate::PlanEntryUpdate(
+                    v1::PlanEntryUpdate::new(0)
+                        .plan_id(v1::PlanId::new("plan-2"))
+                        .status(v1::PlanEntryStatus::Completed),
+                )]
+            );
+            let [v1::SessionUpdate::PlanEntryUpdate(entry_update)] = back.as_slice() else {
+                unreachable!();
+            };
+
+            let pending = || {
+                vec![v1::PlanEntry::new(
+                    "step",
+                    v1::PlanEntryPriority::High,
+                    v1::PlanEntryStatus::Pending,
+                )]
+            };
+            let mut main = v1::PlanItems::new(LEGACY_V1_PLAN_ID, pending());
+            let mut other = v1::PlanItems::new("plan-2", pending());
+            main.apply(entry_update.clone());
+            other.apply(entry_update.clone());
+            assert_eq!(main.entries[0].status, v1::PlanEntryStatus::Pending);
+            assert_eq!(other.entries[0].status, v1::PlanEntryStatus::Completed);
+
+            assert_eq!(v1_to_v2(back[0].clone()).unwrap(), update);
+        }
+    }
+
     #[test]
     fn unknown_v2_session_update_does_not_convert_to_v1() {
         let update = v2::SessionUpdate::Other(v2::OtherSessionUpdate::new(
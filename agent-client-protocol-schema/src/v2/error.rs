@@ -18,6 +18,12 @@ use serde_with::{DefaultOnError, serde_as, skip_serializing_none};
 
 use crate::IntoOption;
 
+#[cfg(any(
+    feature = "unstable_concurrent_prompts",
+    feature = "unstable_session_not_found"
+))]
+use super::SessionId;
+
 /// Convenience result type using this protocol version's error type.
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
@@ -125,6 +131,43 @@ impl Error {
         ErrorCode::UrlElicitationRequired.into()
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The requested command is not available in this session.
+    #[cfg(feature = "unstable_command_invocation")]
+    #[must_use]
+    pub fn command_not_found(command: impl Into<String>) -> Self {
+        Error::from(ErrorCode::CommandNotFound)
+            .data(serde_json::json!({ "command": command.into() }))
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The session is already processing a prompt and the agent does not accept
+    /// concurrent prompts.
+    #[cfg(feature = "unstable_concurrent_prompts")]
+    #[must_use]
+    pub fn session_busy(session_id: impl Into<SessionId>) -> Self {
+        Error::from(ErrorCode::SessionBusy)
+            .data(serde_json::json!({ "sessionId": session_id.into() }))
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The session does not exist, for example because it was deleted.
+    #[cfg(feature = "unstable_session_not_found")]
+    #[must_use]
+    pub fn session_not_found(session_id: impl Into<SessionId>) -> Self {
+        Error::from(ErrorCode::SessionNotFound)
+            .data(serde_json::json!({ "sessionId": session_id.into() }))
+    }
+
     /// A given resource, such as a file, was not found.
     #[must_use]
     pub fn resource_not_found(uri: Option<String>) -> Self {
@@ -193,6 +236,34 @@ pub enum ErrorCode {
     #[schemars(transform = error_code_transform)]
     #[strum(to_string = "Resource not found")]
     ResourceNotFound, // -32002
+    #[cfg(feature = "unstable_command_invocation")]
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The requested command is not available in this session.
+    #[schemars(transform = error_code_transform)]
+    #[strum(to_string = "Command not found")]
+    CommandNotFound, // -32003
+    #[cfg(feature = "unstable_concurrent_prompts")]
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The session is already processing a prompt and the agent does not accept
+    /// concurrent prompts.
+    #[schemars(transform = error_code_transform)]
+    #[strum(to_string = "Session busy")]
+    SessionBusy, // -32004
+    #[cfg(feature = "unstable_session_not_found")]
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The session does not exist, for example because it was deleted.
+    #[schemars(transform = error_code_transform)]
+    #[strum(to_string = "Session not found")]
+    SessionNotFound, // -32005
     #[cfg(feature = "unstable_elicitation")]
     /// **UNSTABLE**
     ///
@@ -220,6 +291,12 @@ impl From<i32> for ErrorCode {
             -32800 => ErrorCode::RequestCancelled,
             -32000 => ErrorCode::AuthRequired,
             -32002 => ErrorCode::ResourceNotFound,
+            #[cfg(feature = "unstable_command_invocation")]
+            -32003 => ErrorCode::CommandNotFound,
+            #[cfg(feature = "unstable_concurrent_prompts")]
+            -32004 => ErrorCode::SessionBusy,
+            #[cfg(feature = "unstable_session_not_found")]
+            -32005 => ErrorCode::SessionNotFound,
             #[cfg(feature = "unstable_elicitation")]
             -32042 => ErrorCode::UrlElicitationRequired,
             _ => ErrorCode::Other(value),
@@ -238,6 +315,12 @@ impl From<ErrorCode> for i32 {
             ErrorCode::RequestCancelled => -32800,
             ErrorCode::AuthRequired => -32000,
             ErrorCode::ResourceNotFound => -32002,
+            #[cfg(feature = "unstable_command_invocation")]
+            ErrorCode::CommandNotFound => -32003,
+            #[cfg(feature = "unstable_concurrent_prompts")]
+            ErrorCode::SessionBusy => -32004,
+            #[cfg(feature = "unstable_session_not_found")]
+            ErrorCode::SessionNotFound => -32005,
             #[cfg(feature = "unstable_elicitation")]
             ErrorCode::UrlElicitationRequired => -32042,
             ErrorCode::Other(value) => value,
@@ -266,6 +349,12 @@ fn error_code_transform(schema: &mut Schema) {
         "RequestCancelled" => ErrorCode::RequestCancelled,
         "AuthRequired" => ErrorCode::AuthRequired,
         "ResourceNotFound" => ErrorCode::ResourceNotFound,
+        #[cfg(feature = "unstable_command_invocation")]
+        "CommandNotFound" => ErrorCode::CommandNotFound,
+        #[cfg(feature = "unstable_concurrent_prompts")]
+        "SessionBusy" => ErrorCode::SessionBusy,
+        #[cfg(feature = "unstable_session_not_found")]
+        "SessionNotFound" => ErrorCode::SessionNotFound,
         #[cfg(feature = "unstable_elicitation")]
         "UrlElicitationRequired" => ErrorCode::UrlElicitationRequired,
         _ => panic!("Unexpected error code name {name}"),
@@ -362,4 +451,22 @@ mod tests {
             );
         }
     }
+
+    #[cfg(feature = "unstable_session_not_found")]
+    #[test]
+    fn session_not_found_error() {
+        assert_eq!(ErrorCode::from(-32005), ErrorCode::SessionNotFound);
+        assert_eq!(i32::from(ErrorCode::SessionNotFound), -32005);
+
+        let error = Error::session_not_found("sess_abc123");
+        assert_eq!(error.code, ErrorCode::SessionNotFound);
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            serde_json::json!({
+                "code": -32005,
+                "message": "Session not found",
+                "data": { "sessionId": "sess_abc123" }
+            })
+        );
+    }
 }
@@ -1,10 +1,12 @@
 //! Extension types and constants for protocol extensibility.
 use derive_more::From;
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use serde_json::value::RawValue;
 use std::sync::Arc;
 
+use super::Result;
+
 /// Value attached to a given ACP type on the `_meta` field.
 ///
 /// The _meta property is reserved by ACP to allow clients and agents to attach
@@ -14,6 +16,54 @@ use std::sync::Arc;
 /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
 pub type Meta = serde_json::Map<String, serde_json::Value>;
 
+/// Typed accessors for values stored in a [`Meta`] map.
+///
+/// Extensions are encouraged to namespace their keys with a reverse-DNS prefix
+/// (for example `com.example.editor/selection`) so unrelated extensions do not
+/// collide. Implementations MUST NOT make assumptions about values at these
+/// keys: a peer may omit a key, or store a value of an unexpected shape under
+/// it, so callers should treat both a missing key and a decoding error as
+/// recoverable.
+///
+/// These helpers only touch the requested key. Any other keys, including ones
+/// this side does not understand, are preserved as-is so they round-trip
+/// untouched.
+///
+/// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+pub trait MetaExt {
+    /// Decodes the value stored under `key` as `T`.
+    ///
+    /// Returns `Ok(None)` when the key is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`invalid_params`](super::Error::invalid_params) error when
+    /// the stored value cannot be deserialized as `T`.
+    fn get_as<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>>;
+
+    /// Serializes `value` and stores it under `key`, replacing any previous value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`invalid_params`](super::Error::invalid_params) error when
+    /// `value` cannot be serialized to JSON.
+    fn set<T: Serialize>(&mut self, key: impl Into<String>, value: T) -> Result<()>;
+}
+
+impl MetaExt for Meta {
+    fn get_as<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        self.get(key)
+            .map(T::deserialize)
+            .transpose()
+            .map_err(Into::into)
+    }
+
+    fn set<T: Serialize>(&mut self, key: impl Into<String>, value: T) -> Result<()> {
+        self.insert(key.into(), serde_json::to_value(value)?);
+        Ok(())
+    }
+}
+
 /// Allows for sending an arbitrary request that is not part of the ACP spec.
 /// Extension methods provide a way to add custom functionality while maintaining
 /// protocol compatibility.
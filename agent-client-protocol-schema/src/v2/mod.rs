@@ -24,6 +24,8 @@ mod mcp;
 mod nes;
 mod plan;
 mod protocol_level;
+#[cfg(feature = "unstable_sampling")]
+mod sampling;
 pub(crate) mod schema_util;
 mod tool_call;
 
@@ -42,12 +44,96 @@ pub use mcp::*;
 pub use nes::*;
 pub use plan::*;
 pub use protocol_level::*;
+#[cfg(feature = "unstable_sampling")]
+pub use sampling::*;
 pub use serde_json::value::RawValue;
 pub use tool_call::*;
 
 /// JSON-RPC response envelope using this protocol version's error type.
 pub type Response<Result> = crate::rpc::Response<Result, Error>;
 
+/// All messages that an agent can send to a client.
+pub type AgentOutgoingMessage =
+    crate::rpc::Message<AgentRequest, AgentResponse, AgentNotification, Error>;
+
+/// All messages that a client can send to an agent.
+pub type ClientOutgoingMessage =
+    crate::rpc::Message<ClientRequest, ClientResponse, ClientNotification, Error>;
+
+/// A message from either side with its params and result left as JSON.
+///
+/// Useful for recording, replaying, or proxying frames without decoding them.
+/// Unlike the routing enums, which pick a params type by shape, this lets the
+/// caller decode params according to `method`.
+pub type RawMessage =
+    crate::rpc::Message<serde_json::Value, serde_json::Value, serde_json::Value, Error>;
+
+/// Returns the JSON Schemas for one method's params and result, or `None` if
+/// this protocol version has no method with that name.
+///
+/// ```
+/// use agent_client_protocol_schema::v2::{CLIENT_METHOD_NAMES, method_schema};
+///
+/// let schema = method_schema(CLIENT_METHOD_NAMES.session_request_permission).unwrap();
+/// assert!(schema.params.as_value()["properties"].get("toolCall").is_some());
+/// assert!(schema.result.unwrap().as_value()["properties"].get("outcome").is_some());
+/// ```
+#[must_use]
+pub fn method_schema(method: &str) -> Option<crate::MethodSchema> {
+    use schemars::SchemaGenerator;
+
+    crate::method_schema::method_schema(
+        method,
+        &[
+            SchemaGenerator::subschema_for::<AgentRequest>,
+            SchemaGenerator::subschema_for::<ClientRequest>,
+            SchemaGenerator::subschema_for::<AgentNotification>,
+            SchemaGenerator::subschema_for::<ClientNotification>,
+            SchemaGenerator::subschema_for::<ProtocolLevelNotification>,
+        ],
+        &[
+            SchemaGenerator::subschema_for::<AgentResponse>,
+            SchemaGenerator::subschema_for::<ClientResponse>,
+        ],
+    )
+}
+
+/// Messages that an agent can include in a JSON-RPC batch call to a client.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+#[schemars(inline)]
+#[allow(
+    clippy::exhaustive_enums,
+    reason = "This comes from the JSON-RPC specification itself"
+)]
+#[allow(clippy::large_enum_variant)]
+pub enum AgentBatchCallMessage {
+    /// A request from the agent, answered by the client.
+    Request(Request<AgentRequest>),
+    /// A notification from the agent, which expects no response.
+    Notification(Notification<AgentNotification>),
+    /// A protocol-level notification, such as `$/cancel_request`.
+    ProtocolLevelNotification(Notification<ProtocolLevelNotification>),
+}
+
+/// Messages that a client can include in a JSON-RPC batch call to an agent.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+#[schemars(inline)]
+#[allow(
+    clippy::exhaustive_enums,
+    reason = "This comes from the JSON-RPC specification itself"
+)]
+#[allow(clippy::large_enum_variant)]
+pub enum ClientBatchCallMessage {
+    /// A request from the client, answered by the agent.
+    Request(Request<ClientRequest>),
+    /// A notification from the client, which expects no response.
+    Notification(Notification<ClientNotification>),
+    /// A protocol-level notification, such as `$/cancel_request`.
+    ProtocolLevelNotification(Notification<ProtocolLevelNotification>),
+}
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
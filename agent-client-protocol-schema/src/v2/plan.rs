@@ -253,6 +253,113 @@ impl PlanItems {
         }
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Applies a [`PlanEntryUpdate`] to the matching entry, leaving the others intact.
+    ///
+    /// Updates for another plan or for an index past the end of the plan are ignored.
+    #[cfg(feature = "unstable_plan_entry_update")]
+    pub fn apply(&mut self, update: PlanEntryUpdate) {
+        if update.plan_id != self.plan_id {
+            return;
+        }
+        let Some(entry) = usize::try_from(update.index)
+            .ok()
+            .and_then(|index| self.entries.get_mut(index))
+        else {
+            return;
+        };
+        if let Some(status) = update.status {
+            entry.status = status;
+        }
+        if let Some(content) = update.content {
+            entry.content = content;
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// Patches a single entry of [`PlanItems`] with the same `plan_id` instead of resending every entry.
+///
+/// Entry indices are stable within a turn: agents append new entries and send a
+/// full plan before reordering or removing any.
+#[cfg(feature = "unstable_plan_entry_update")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PlanEntryUpdate {
+    /// The plan containing the entry.
+    pub plan_id: PlanId,
+    /// Zero-based index of the entry to update.
+    pub index: u32,
+    /// The entry's new status, if it changed.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub status: Option<PlanEntryStatus>,
+    /// The entry's new description, if it changed.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub content: Option<String>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_plan_entry_update")]
+impl PlanEntryUpdate {
+    /// Builds [`PlanEntryUpdate`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(plan_id: impl Into<PlanId>, index: u32) -> Self {
+        Self {
+            plan_id: plan_id.into(),
+            index,
+            status: None,
+            content: None,
+            meta: None,
+        }
+    }
+
+    /// The entry's new status, if it changed.
+    #[must_use]
+    pub fn status(mut self, status: impl IntoOption<PlanEntryStatus>) -> Self {
+        self.status = status.into_option();
+        self
+    }
+
+    /// The entry's new description, if it changed.
+    #[must_use]
+    pub fn content(mut self, content: impl IntoOption<String>) -> Self {
+        self.content = content.into_option();
+        self
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -591,6 +698,75 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "unstable_plan_entry_update")]
+    #[test]
+    fn plan_items_apply_patches_one_entry() {
+        let mut plan = PlanItems::new(
+            "plan-1",
+            vec![
+                PlanEntry::new(
+                    "Read the code",
+                    PlanEntryPriority::High,
+                    PlanEntryStatus::Completed,
+                ),
+                PlanEntry::new(
+                    "Write the fix",
+                    PlanEntryPriority::High,
+                    PlanEntryStatus::Pending,
+                ),
+                PlanEntry::new(
+                    "Run the tests",
+                    PlanEntryPriority::Medium,
+                    PlanEntryStatus::Pending,
+                ),
+            ],
+        );
+
+        plan.apply(
+            PlanEntryUpdate::new("plan-1", 1)
+                .status(PlanEntryStatus::InProgress)
+                .content("Write the fix and a regression test".to_string()),
+        );
+        assert_eq!(
+            plan.entries,
+            vec![
+                PlanEntry::new(
+                    "Read the code",
+                    PlanEntryPriority::High,
+                    PlanEntryStatus::Completed
+                ),
+                PlanEntry::new(
+                    "Write the fix and a regression test",
+                    PlanEntryPriority::High,
+                    PlanEntryStatus::InProgress
+                ),
+                PlanEntry::new(
+                    "Run the tests",
+                    PlanEntryPriority::Medium,
+                    PlanEntryStatus::Pending
+                ),
+            ]
+        );
+    }
+
+    #[cfg(feature = "unstable_plan_entry_update")]
+    #[test]
+    fn plan_items_apply_ignores_other_plans_and_missing_entries() {
+        let mut plan = PlanItems::new(
+            "plan-1",
+            vec![PlanEntry::new(
+                "Read the code",
+                PlanEntryPriority::High,
+                PlanEntryStatus::Pending,
+            )],
+        );
+        let before = plan.clone();
+
+        plan.apply(PlanEntryUpdate::new("plan-2", 0).status(PlanEntryStatus::Completed));
+        plan.apply(PlanEntryUpdate::new("plan-1", 1).status(PlanEntryStatus::Completed));
+        assert_eq!(plan, before);
+    }
+
     #[test]
     fn plan_update_content_requires_id_for_unknown_variant() {
         assert!(
@@ -4,6 +4,13 @@
 //! running code, or fetching data—it generates tool calls that the agent executes on its behalf.
 //!
 /// See protocol docs: [Tool Calls](https://agentclientprotocol.com/protocol/tool-calls)
+#[cfg(any(
+    feature = "unstable_tool_call_group",
+    feature = "unstable_tool_call_parent"
+))]
+use std::collections::HashMap;
+#[cfg(feature = "unstable_tool_call_group")]
+use std::collections::hash_map::Entry;
 use std::{collections::BTreeMap, path::PathBuf, sync::Arc};
 
 use derive_more::{Display, From};
@@ -11,7 +18,9 @@ use schemars::{JsonSchema, Schema};
 use serde::{Deserialize, Serialize};
 use serde_with::{DefaultOnError, VecSkipError, serde_as, skip_serializing_none};
 
-use super::{ContentBlock, Meta};
+#[cfg(feature = "unstable_permission_inline_tool_call")]
+use super::ClientCapabilities;
+use super::{ContentBlock, Error, Meta, Result};
 use crate::{IntoMaybeUndefined, IntoOption, MaybeUndefined, SkipListener};
 
 /// Represents an upsert for a tool call that the language model has requested.
@@ -46,6 +55,46 @@ pub struct ToolCallUpdate {
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub kind: MaybeUndefined<ToolKind>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A free-form label refining [`ToolKind`], such as `"database query"`.
+    ///
+    /// Clients MAY use it for display or icon selection and SHOULD fall back to
+    /// `kind` when they don't recognize it.
+    #[cfg(feature = "unstable_tool_kind_label")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub custom_kind: MaybeUndefined<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Groups related tool calls, such as retries of the same command.
+    ///
+    /// Clients MAY collapse tool calls that share a `groupId` into a single
+    /// entry. Ids are chosen by the Agent and only meaningful within a session.
+    #[cfg(feature = "unstable_tool_call_group")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub group_id: MaybeUndefined<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The tool call that started this one, such as the sub-agent call that
+    /// ran it.
+    ///
+    /// Clients MAY render tool calls as a tree under their parent. A tool call
+    /// whose parent is unknown is shown at the top level.
+    #[cfg(feature = "unstable_tool_call_parent")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub parent_id: MaybeUndefined<ToolCallId>,
     /// Current execution status of the tool call.
     #[serde_as(deserialize_as = "DefaultOnError")]
     #[schemars(extend("x-deserialize-default-on-error" = true))]
@@ -72,6 +121,79 @@ pub struct ToolCallUpdate {
     #[schemars(extend("x-deserialize-default-on-error" = true))]
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub raw_output: MaybeUndefined<serde_json::Value>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A patch to merge into the raw output instead of replacing it.
+    ///
+    /// Applied with JSON Merge Patch (RFC 7386) semantics, after `rawOutput` when
+    /// both are present. Keys in the patch are added or overwritten, keys set to
+    /// `null` are removed, and nested objects are merged recursively. Any other
+    /// value, including an array, replaces the target outright, so arrays are
+    /// resent in full rather than appended to. A `null` patch clears the raw
+    /// output.
+    #[cfg(feature = "unstable_raw_output_patch")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub raw_output_patch: MaybeUndefined<serde_json::Value>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// JSON Schema describing `rawInput`.
+    ///
+    /// Lets clients validate the tool's arguments and render them as a form.
+    #[cfg(feature = "unstable_tool_call_schema")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub input_schema: MaybeUndefined<serde_json::Value>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// JSON Schema describing `rawOutput`.
+    #[cfg(feature = "unstable_tool_call_schema")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub output_schema: MaybeUndefined<serde_json::Value>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// ISO 8601 timestamp of when the tool call was created.
+    #[cfg(feature = "unstable_tool_call_timestamps")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub created_at: MaybeUndefined<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// ISO 8601 timestamp of when the tool call reached `completed` or `failed`.
+    ///
+    /// Agents SHOULD send it in the same update that moves `status` to a terminal state.
+    #[cfg(feature = "unstable_tool_call_timestamps")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub completed_at: MaybeUndefined<String>,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Details about why the tool call failed.
+    ///
+    /// Agents SHOULD set this alongside a `failed` status.
+    #[cfg(feature = "unstable_tool_call_error")]
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub error: MaybeUndefined<ToolCallError>,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -92,11 +214,29 @@ impl ToolCallUpdate {
             tool_call_id: tool_call_id.into(),
             title: MaybeUndefined::Undefined,
             kind: MaybeUndefined::Undefined,
+            #[cfg(feature = "unstable_tool_kind_label")]
+            custom_kind: MaybeUndefined::Undefined,
+            #[cfg(feature = "unstable_tool_call_group")]
+            group_id: MaybeUndefined::Undefined,
+            #[cfg(feature = "unstable_tool_call_parent")]
+            parent_id: MaybeUndefined::Undefined,
             status: MaybeUndefined::Undefined,
             content: MaybeUndefined::Undefined,
             locations: MaybeUndefined::Undefined,
             raw_input: MaybeUndefined::Undefined,
             raw_output: MaybeUndefined::Undefined,
+            #[cfg(feature = "unstable_raw_output_patch")]
+            raw_output_patch: MaybeUndefined::Undefined,
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            created_at: MaybeUndefined::Undefined,
+            #[cfg(feature = "unstable_tool_call_timestamps")]
+            completed_at: MaybeUndefined::Undefined,
+            #[cfg(feature = "unstable_tool_call_error")]
+            error: MaybeUndefined::Undefined,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            input_schema: MaybeUndefined::Undefined,
+            #[cfg(feature = "unstable_tool_call_schema")]
+            output_schema: MaybeUndefined::Undefined,
             meta: None,
         }
     }
@@ -116,6 +256,43 @@ impl ToolCallUpdate {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A free-form label refining [`ToolKind`], such as `"database query"`.
+    #[cfg(feature = "unstable_tool_kind_label")]
+    #[must_use]
+    pub fn custom_kind(mut self, custom_kind: impl IntoMaybeUndefined<String>) -> Self {
+        self.custom_kind = custom_kind.into_maybe_undefined();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Groups related tool calls, such as retries of the same command.
+    #[cfg(feature = "unstable_tool_call_group")]
+    #[must_use]
+    pub fn group_id(mut self, group_id: impl IntoMaybeUndefined<String>) -> Self {
+        self.group_id = group_id.into_maybe_undefined();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The tool call that started this one, such as the sub-agent call that
+    /// ran it.
+    #[cfg(feature = "unstable_tool_call_parent")]
+    #[must_use]
+    pub fn parent_id(mut self, parent_id: impl IntoMaybeUndefined<ToolCallId>) -> Self {
+        self.parent_id = parent_id.into_maybe_undefined();
+        self
+    }
+
     /// Current execution status of the tool call.
     #[must_use]
     pub fn status(mut self, status: impl IntoMaybeUndefined<ToolCallStatus>) -> Self {
@@ -152,6 +329,107 @@ impl ToolCallUpdate {
         self
     }
 
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// A JSON Merge Patch to merge into the raw output instead of replacing it.
+    #[cfg(feature = "unstable_raw_output_patch")]
+    #[must_use]
+    pub fn raw_output_patch(
+        mut self,
+        raw_output_patch: impl IntoMaybeUndefined<serde_json::Value>,
+    ) -> Self {
+        self.raw_output_patch = raw_output_patch.into_maybe_undefined();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// JSON Schema describing `rawInput`.
+    #[cfg(feature = "unstable_tool_call_schema")]
+    #[must_use]
+    pub fn input_schema(
+        mut self,
+        input_schema: impl IntoMaybeUndefined<serde_json::Value>,
+    ) -> Self {
+        self.input_schema = input_schema.into_maybe_undefined();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// JSON Schema describing `rawOutput`.
+    #[cfg(feature = "unstable_tool_call_schema")]
+    #[must_use]
+    pub fn output_schema(
+        mut self,
+        output_schema: impl IntoMaybeUndefined<serde_json::Value>,
+    ) -> Self {
+        self.output_schema = output_schema.into_maybe_undefined();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// ISO 8601 timestamp of when the tool call was created.
+    #[cfg(feature = "unstable_tool_call_timestamps")]
+    #[must_use]
+    pub fn created_at(mut self, created_at: impl IntoMaybeUndefined<String>) -> Self {
+        self.created_at = created_at.into_maybe_undefined();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// ISO 8601 timestamp of when the tool call reached `completed` or `failed`.
+    #[cfg(feature = "unstable_tool_call_timestamps")]
+    #[must_use]
+    pub fn completed_at(mut self, completed_at: impl IntoMaybeUndefined<String>) -> Self {
+        self.completed_at = completed_at.into_maybe_undefined();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Details about why the tool call failed.
+    #[cfg(feature = "unstable_tool_call_error")]
+    #[must_use]
+    pub fn error(mut self, error: impl IntoMaybeUndefined<ToolCallError>) -> Self {
+        self.error = error.into_maybe_undefined();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Prepares this update to be sent in a `session/request_permission` request
+    /// to a client with `capabilities`.
+    ///
+    /// Returns the update unchanged if the client can render inline tool calls.
+    /// Otherwise keeps only the `toolCallId`, so the agent must already have
+    /// reported the tool call with a `tool_call_update`.
+    #[cfg(feature = "unstable_permission_inline_tool_call")]
+    #[must_use]
+    pub fn for_permission_request(self, capabilities: &ClientCapabilities) -> Self {
+        if capabilities.supports_inline_tool_call() {
+            self
+        } else {
+            Self::new(self.tool_call_id).meta(self.meta)
+        }
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -175,6 +453,18 @@ impl ToolCallUpdate {
         if !update.kind.is_undefined() {
             self.kind = update.kind;
         }
+        #[cfg(feature = "unstable_tool_kind_label")]
+        if !update.custom_kind.is_undefined() {
+            self.custom_kind = update.custom_kind;
+        }
+        #[cfg(feature = "unstable_tool_call_group")]
+        if !update.group_id.is_undefined() {
+            self.group_id = update.group_id;
+        }
+        #[cfg(feature = "unstable_tool_call_parent")]
+        if !update.parent_id.is_undefined() {
+            self.parent_id = update.parent_id;
+        }
         if !update.status.is_undefined() {
             self.status = update.status;
         }
@@ -190,6 +480,262 @@ impl ToolCallUpdate {
         if !update.raw_output.is_undefined() {
             self.raw_output = update.raw_output;
         }
+        #[cfg(feature = "unstable_raw_output_patch")]
+        match update.raw_output_patch {
+            MaybeUndefined::Undefined => {}
+            MaybeUndefined::Null => self.raw_output = MaybeUndefined::Null,
+            MaybeUndefined::Value(patch) => {
+                let mut raw_output = std::mem::take(&mut self.raw_output)
+                    .take()
+                    .unwrap_or_default();
+                crate::merge_patch::merge_patch(&mut raw_output, &patch);
+                self.raw_output = MaybeUndefined::Value(raw_output);
+            }
+        }
+        #[cfg(feature = "unstable_tool_call_schema")]
+        if !update.input_schema.is_undefined() {
+            self.input_schema = update.input_schema;
+        }
+        #[cfg(feature = "unstable_tool_call_schema")]
+        if !update.output_schema.is_undefined() {
+            self.output_schema = update.output_schema;
+        }
+        #[cfg(feature = "unstable_tool_call_timestamps")]
+        if !update.created_at.is_undefined() {
+            self.created_at = update.created_at;
+        }
+        #[cfg(feature = "unstable_tool_call_timestamps")]
+        if !update.completed_at.is_undefined() {
+            self.completed_at = update.completed_at;
+        }
+        #[cfg(feature = "unstable_tool_call_error")]
+        if !update.error.is_undefined() {
+            self.error = update.error;
+        }
+    }
+
+    /// The places this tool call edits, in order, for clients that offer a
+    /// jump list.
+    ///
+    /// Each [`Diff`] in `content` contributes its path and the first line it
+    /// changes, followed by the explicit `locations`. Repeated path and line
+    /// pairs are listed once, at their first position.
+    #[must_use]
+    pub fn edit_locations(&self) -> Vec<ToolCallLocation> {
+        let diff_locations = self
+            .content
+            .value()
+            .into_iter()
+            .flatten()
+            .filter_map(|content| match content {
+                ToolCallContent::Diff(diff) => {
+                    Some(ToolCallLocation::new(diff.path.clone()).line(diff.first_changed_line()))
+                }
+                _ => None,
+            });
+        let explicit_locations = self.locations.value().into_iter().flatten().cloned();
+        let mut locations: Vec<ToolCallLocation> = Vec::new();
+        for location in diff_locations.chain(explicit_locations) {
+            if !locations
+                .iter()
+                .any(|seen| seen.path == location.path && seen.line == location.line)
+            {
+                locations.push(location);
+            }
+        }
+        locations
+    }
+
+    /// This tool call's content as a flat, ordered list of items to render.
+    ///
+    /// Content blocks are unwrapped from [`ToolCallContent::Content`]. Each
+    /// diff carries the entries of `locations` in the same file, so clients
+    /// can show them alongside it.
+    #[must_use]
+    pub fn render_items(&self) -> Vec<RenderItem<'_>> {
+        let locations = self.locations.value().map_or(&[][..], Vec::as_slice);
+        self.content
+            .value()
+            .into_iter()
+            .flatten()
+            .map(|content| match content {
+                ToolCallContent::Content(content) => RenderItem::Content(&content.content),
+                ToolCallContent::Diff(diff) => RenderItem::Diff {
+                    diff,
+                    locations: locations
+                        .iter()
+                        .filter(|location| location.path == diff.path)
+                        .collect(),
+                },
+                ToolCallContent::Other(other) => RenderItem::Other(other),
+            })
+            .collect()
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Splits `tool_calls` into groups for display, keeping their order.
+    ///
+    /// Tool calls that share a `group_id` are gathered at the position of the
+    /// first of them. A tool call without a `group_id` is a group of its own.
+    #[cfg(feature = "unstable_tool_call_group")]
+    #[must_use]
+    pub fn group_by(tool_calls: &[Self]) -> Vec<Vec<&Self>> {
+        let mut groups: Vec<Vec<&Self>> = Vec::new();
+        let mut group_indices: HashMap<&str, usize> = HashMap::new();
+        for tool_call in tool_calls {
+            let Some(group_id) = tool_call.group_id.value().map(String::as_str) else {
+                groups.push(vec![tool_call]);
+                continue;
+            };
+            match group_indices.entry(group_id) {
+                Entry::Occupied(entry) => groups[*entry.get()].push(tool_call),
+                Entry::Vacant(entry) => {
+                    entry.insert(groups.len());
+                    groups.push(vec![tool_call]);
+                }
+            }
+        }
+        groups
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Arranges `tool_calls` into a tree for display, keeping their order.
+    ///
+    /// Each tool call becomes a child of the one its `parent_id` names. Tool
+    /// calls without a `parent_id`, and orphans whose parent is not in
+    /// `tool_calls`, are returned as roots. Every tool call appears exactly
+    /// once, so a chain of parents that loops back on itself is broken at its
+    /// first member.
+    #[cfg(feature = "unstable_tool_call_parent")]
+    #[must_use]
+    pub fn build_tree(tool_calls: &[Self]) -> Vec<ToolCallNode<'_>> {
+        let mut indices: HashMap<&ToolCallId, usize> = HashMap::new();
+        for (index, tool_call) in tool_calls.iter().enumerate() {
+            indices.entry(&tool_call.tool_call_id).or_insert(index);
+        }
+        let parents: Vec<Option<usize>> = tool_calls
+            .iter()
+            .enumerate()
+            .map(|(index, tool_call)| {
+                tool_call
+                    .parent_id
+                    .value()
+                    .and_then(|parent_id| indices.get(parent_id).copied())
+                    .filter(|&parent| parent != index)
+            })
+            .collect();
+        let mut children = vec![Vec::new(); tool_calls.len()];
+        for (index, parent) in parents.iter().enumerate() {
+            if let Some(parent) = parent {
+                children[*parent].push(index);
+            }
+        }
+
+        let mut visited = vec![false; tool_calls.len()];
+        let mut nodes: Vec<Option<ToolCallNode<'_>>> = vec![None; tool_calls.len()];
+        let mut roots = Vec::new();
+        // Tool calls whose parents form a cycle are not reachable from a root,
+        // so they start trees of their own once every root is placed.
+        let starts = (0..tool_calls.len())
+            .filter(|&index| parents[index].is_none())
+            .chain(0..tool_calls.len());
+        for start in starts {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            // Walk the tree depth first so every tool call comes after its parent.
+            let mut order = Vec::new();
+            let mut stack = vec![start];
+            while let Some(index) = stack.pop() {
+                order.push(index);
+                for &child in children[index].iter().rev() {
+                    if !visited[child] {
+                        visited[child] = true;
+                        stack.push(child);
+                    }
+                }
+            }
+            // Build the nodes bottom-up, so children are complete before their parent.
+            for &index in order.iter().rev() {
+                let children = children[index]
+                    .iter()
+                    .filter_map(|&child| nodes[child].take())
+                    .collect();
+                nodes[index] = Some(ToolCallNode {
+                    tool_call: &tool_calls[index],
+                    children,
+                });
+            }
+            roots.extend(nodes[start].take());
+        }
+        roots
+    }
+}
+
+/// One renderable piece of a tool call's content.
+///
+/// See [`ToolCallUpdate::render_items`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum RenderItem<'a> {
+    /// A content block, such as text or an image.
+    Content(&'a ContentBlock),
+    /// A file modification.
+    Diff {
+        /// The diff to show.
+        diff: &'a Diff,
+        /// The tool call's locations in the diff's file.
+        locations: Vec<&'a ToolCallLocation>,
+    },
+    /// Custom or future tool call content, passed through as received.
+    Other(&'a OtherToolCallContent),
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A tool call and the tool calls it started.
+///
+/// See [`ToolCallUpdate::build_tree`].
+#[cfg(feature = "unstable_tool_call_parent")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolCallNode<'a> {
+    tool_call: &'a ToolCallUpdate,
+    children: Vec<ToolCallNode<'a>>,
+}
+
+#[cfg(feature = "unstable_tool_call_parent")]
+impl<'a> ToolCallNode<'a> {
+    /// The tool call at this node.
+    #[must_use]
+    pub fn tool_call(&self) -> &'a ToolCallUpdate {
+        self.tool_call
+    }
+
+    /// The tool calls whose parent is this one, in order.
+    #[must_use]
+    pub fn children(&self) -> &[ToolCallNode<'a>] {
+        &self.children
+    }
+}
+
+#[cfg(feature = "unstable_tool_call_parent")]
+impl Drop for ToolCallNode<'_> {
+    fn drop(&mut self) {
+        // Detach descendants one level at a time, so dropping a deep tree
+        // doesn't recurse once per level.
+        let mut descendants = std::mem::take(&mut self.children);
+        while let Some(mut node) = descendants.pop() {
+            descendants.append(&mut node.children);
+        }
     }
 }
 
@@ -245,6 +791,61 @@ impl ToolCallContentChunk {
     }
 }
 
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A fragment of a tool call's raw input, streamed as the model generates it.
+///
+/// Concatenating the fragments for a tool call, in order, yields its raw input as
+/// JSON text. Agents SHOULD still send the complete `rawInput` once it is known.
+#[cfg(feature = "unstable_tool_call_input_delta")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ToolCallInputDelta {
+    /// The ID of the tool call this input belongs to.
+    pub tool_call_id: ToolCallId,
+    /// The next piece of the raw input JSON text.
+    pub json_fragment: String,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_tool_call_input_delta")]
+impl ToolCallInputDelta {
+    /// Builds [`ToolCallInputDelta`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(tool_call_id: impl Into<ToolCallId>, json_fragment: impl Into<String>) -> Self {
+        Self {
+            tool_call_id: tool_call_id.into(),
+            json_fragment: json_fragment.into(),
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
 /// Unique identifier for a tool call within a session.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, Display, From)]
 #[serde(transparent)]
@@ -325,6 +926,26 @@ pub enum ToolCallStatus {
     Completed,
     /// The tool call failed with an error.
     Failed,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The tool call was aborted before it finished, typically because the
+    /// prompt turn was cancelled.
+    #[cfg(feature = "unstable_tool_call_cancelled")]
+    Cancelled,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The tool call is blocked on a permission request and waits for the user
+    /// to approve it.
+    ///
+    /// Unlike `pending`, the input is complete. The tool call moves to
+    /// `in_progress` once approved, or to `cancelled` if the user declines or
+    /// the prompt turn is cancelled.
+    #[cfg(feature = "unstable_tool_call_awaiting_confirmation")]
+    AwaitingConfirmation,
     /// Custom or future tool call status.
     ///
     /// Values beginning with `_` are reserved for implementation-specific
@@ -334,31 +955,117 @@ pub enum ToolCallStatus {
     Other(String),
 }
 
-/// Content produced by a tool call.
+impl ToolCallStatus {
+    /// Whether the tool call has finished, successfully, with an error, or by
+    /// being cancelled.
+    ///
+    /// Terminal tool calls receive no further status changes. Custom or future
+    /// statuses are not treated as terminal.
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        match self {
+            ToolCallStatus::Completed | ToolCallStatus::Failed => true,
+            #[cfg(feature = "unstable_tool_call_cancelled")]
+            ToolCallStatus::Cancelled => true,
+            ToolCallStatus::Pending | ToolCallStatus::InProgress | ToolCallStatus::Other(_) => {
+                false
+            }
+            #[cfg(feature = "unstable_tool_call_awaiting_confirmation")]
+            ToolCallStatus::AwaitingConfirmation => false,
+        }
+    }
+}
+
+/// **UNSTABLE**
 ///
-/// Tool calls can produce different types of content including
-/// standard content blocks (text, images) or file diffs.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// See protocol docs: [Content](https://agentclientprotocol.com/protocol/tool-calls#content)
+/// Details about why a tool call failed.
+///
+/// Sent alongside a [`ToolCallStatus::Failed`] status so clients can explain the
+/// failure instead of only marking the tool call as failed.
+#[cfg(feature = "unstable_tool_call_error")]
+#[serde_as]
+#[skip_serializing_none]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
-#[serde(tag = "type", rename_all = "snake_case")]
-#[schemars(extend("discriminator" = {"propertyName": "type"}))]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub enum ToolCallContent {
-    /// Standard content block (text, images, resources).
-    Content(Box<Content>),
-    /// File modification shown as a diff.
-    Diff(Diff),
-    /// Custom or future tool call content.
-    ///
-    /// Values beginning with `_` are reserved for implementation-specific
-    /// extensions. Unknown values that do not begin with `_` are reserved for
-    /// future ACP variants.
+pub struct ToolCallError {
+    /// Human-readable description of the failure.
+    pub message: String,
+    /// Additional structured information about the failure.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub detail: Option<serde_json::Value>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
     ///
-    /// Receivers that do not understand this content type should preserve the
-    /// raw payload when storing, replaying, proxying, or forwarding tool call
-    /// output, and otherwise ignore it or display it generically.
-    #[serde(untagged)]
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_tool_call_error")]
+impl ToolCallError {
+    /// Builds [`ToolCallError`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            detail: None,
+            meta: None,
+        }
+    }
+
+    /// Additional structured information about the failure.
+    #[must_use]
+    pub fn detail(mut self, detail: impl IntoOption<serde_json::Value>) -> Self {
+        self.detail = detail.into_option();
+        self
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Content produced by a tool call.
+///
+/// Tool calls can produce different types of content including
+/// standard content blocks (text, images) or file diffs.
+///
+/// See protocol docs: [Content](https://agentclientprotocol.com/protocol/tool-calls#content)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[schemars(extend("discriminator" = {"propertyName": "type"}))]
+#[non_exhaustive]
+pub enum ToolCallContent {
+    /// Standard content block (text, images, resources).
+    Content(Box<Content>),
+    /// File modification shown as a diff.
+    Diff(Diff),
+    /// Custom or future tool call content.
+    ///
+    /// Values beginning with `_` are reserved for implementation-specific
+    /// extensions. Unknown values that do not begin with `_` are reserved for
+    /// future ACP variants.
+    ///
+    /// Receivers that do not understand this content type should preserve the
+    /// raw payload when storing, replaying, proxying, or forwarding tool call
+    /// output, and otherwise ignore it or display it generically.
+    #[serde(untagged)]
     Other(OtherToolCallContent),
 }
 
@@ -387,64 +1094,567 @@ impl OtherToolCallContent {
     pub fn new(type_: impl Into<String>, mut fields: BTreeMap<String, serde_json::Value>) -> Self {
         fields.remove("type");
         Self {
-            type_: type_.into(),
-            fields,
+            type_: type_.into(),
+            fields,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OtherToolCallContent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut fields = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
+        let type_ = fields
+            .remove("type")
+            .ok_or_else(|| serde::de::Error::missing_field("type"))?;
+        let serde_json::Value::String(type_) = type_ else {
+            return Err(serde::de::Error::custom("`type` must be a string"));
+        };
+
+        if is_known_tool_call_content_type(&type_) {
+            return Err(serde::de::Error::custom(format!(
+                "known tool call content `{type_}` did not match its schema"
+            )));
+        }
+
+        Ok(Self { type_, fields })
+    }
+}
+
+fn is_known_tool_call_content_type(type_: &str) -> bool {
+    matches!(type_, "content" | "diff")
+}
+
+fn other_tool_call_content_schema(schema: &mut Schema) {
+    super::schema_util::reject_known_string_discriminators(schema, "type", &["content", "diff"]);
+}
+
+impl<T: Into<ContentBlock>> From<T> for ToolCallContent {
+    fn from(content: T) -> Self {
+        ToolCallContent::Content(Box::new(Content::new(content)))
+    }
+}
+
+impl From<Diff> for ToolCallContent {
+    fn from(diff: Diff) -> Self {
+        ToolCallContent::Diff(diff)
+    }
+}
+
+/// Standard content block (text, images, resources).
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Content {
+    /// The actual content block.
+    pub content: ContentBlock,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl Content {
+    /// Builds [`Content`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(content: impl Into<ContentBlock>) -> Self {
+        Self {
+            content: content.into(),
+            meta: None,
+        }
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// A diff representing file modifications.
+///
+/// Shows changes to files in a format suitable for display in the client UI.
+///
+/// See protocol docs: [Content](https://agentclientprotocol.com/protocol/tool-calls#content)
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Diff {
+    /// The absolute file path being modified.
+    pub path: PathBuf,
+    /// The original content (None for new files).
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub old_text: Option<String>,
+    /// The new content after modification.
+    pub new_text: String,
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The changed regions of the file, for clients that let users accept or
+    /// reject each one.
+    ///
+    /// Agents that send hunks MUST honor per-hunk decisions in the permission
+    /// response. Omitted or empty means the diff can only be reviewed as a whole.
+    #[cfg(feature = "unstable_diff_hunks")]
+    #[serde_as(deserialize_as = "DefaultOnError<VecSkipError<_, SkipListener>>")]
+    #[schemars(extend("x-deserialize-default-on-error" = true, "x-deserialize-skip-invalid-items" = true))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hunks: Vec<DiffHunk>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl Diff {
+    /// Builds [`Diff`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>, new_text: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            old_text: None,
+            new_text: new_text.into(),
+            #[cfg(feature = "unstable_diff_hunks")]
+            hunks: Vec::new(),
+            meta: None,
+        }
+    }
+
+    /// The original content (None for new files).
+    #[must_use]
+    pub fn old_text(mut self, old_text: impl IntoOption<String>) -> Self {
+        self.old_text = old_text.into_option();
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The changed regions of the file, for clients that let users accept or
+    /// reject each one.
+    #[cfg(feature = "unstable_diff_hunks")]
+    #[must_use]
+    pub fn hunks(mut self, hunks: Vec<DiffHunk>) -> Self {
+        self.hunks = hunks;
+        self
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Splits this diff into hunks of consecutive changed lines.
+    ///
+    /// Each hunk's id is its index in the returned list, and no hunk has a
+    /// decision yet. A missing `old_text` is treated as an empty file. Very
+    /// large rewrites may come back as a single hunk.
+    #[cfg(feature = "unstable_diff_hunks")]
+    #[must_use]
+    pub fn compute_hunks(&self) -> Vec<DiffHunk> {
+        crate::line_diff::line_hunks(self.old_text.as_deref().unwrap_or_default(), &self.new_text)
+            .into_iter()
+            .enumerate()
+            .map(|(index, hunk)| {
+                DiffHunk::new(
+                    index.to_string(),
+                    DiffLineRange::from_offset(hunk.old_start, hunk.old_count),
+                    DiffLineRange::from_offset(hunk.new_start, hunk.new_count),
+                )
+            })
+            .collect()
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// Records the decisions in `decisions` that target this diff's hunks.
+    ///
+    /// Decisions for other files or unknown hunk ids are ignored.
+    #[cfg(feature = "unstable_diff_hunks")]
+    pub fn apply_hunk_decisions(&mut self, decisions: &[DiffHunkDecision]) {
+        for decision in decisions
+            .iter()
+            .filter(|decision| decision.path == self.path)
+        {
+            if let Some(hunk) = self
+                .hunks
+                .iter_mut()
+                .find(|hunk| hunk.id == decision.hunk_id)
+            {
+                hunk.accepted = Some(decision.accepted);
+            }
+        }
+    }
+
+    /// **UNSTABLE**
+    ///
+    /// This capability is not part of the spec yet, and may be removed or changed at any point.
+    ///
+    /// The file contents after this diff with only the hunks the user did not
+    /// reject: each hunk whose `accepted` is `false` keeps its lines from
+    /// `old_text`, while accepted and undecided hunks take theirs from
+    /// `new_text`.
+    ///
+    /// Returns `None` when a hunk is rejected and the hunks don't line up with
+    /// the text, for example because their ranges overlap, run past the end,
+    /// or miss a changed line.
+    #[cfg(feature = "unstable_diff_hunks")]
+    #[must_use]
+    pub fn accepted_text(&self) -> Option<String> {
+        if self.hunks.iter().all(|hunk| hunk.accepted != Some(false)) {
+            return Some(self.new_text.clone());
+        }
+
+        let old_lines: Vec<&str> = self
+            .old_text
+            .as_deref()
+            .unwrap_or_default()
+            .split_inclusive('\n')
+            .collect();
+        let new_lines: Vec<&str> = self.new_text.split_inclusive('\n').collect();
+        let range = |range: &DiffLineRange| {
+            let start = usize::try_from(range.start).ok()?.checked_sub(1)?;
+            Some(start..start.checked_add(usize::try_from(range.count).ok()?)?)
+        };
+
+        let mut hunks: Vec<&DiffHunk> = self.hunks.iter().collect();
+        hunks.sort_by_key(|hunk| hunk.old_range.start);
+        let mut text = String::new();
+        let (mut old_end, mut new_end) = (0, 0);
+        for hunk in hunks {
+            let old_range = range(&hunk.old_range)?;
+            let new_range = range(&hunk.new_range)?;
+            // Lines between hunks are unchanged, so both sides must agree on them.
+            let unchanged = old_lines.get(old_end..old_range.start)?;
+            if new_lines.get(new_end..new_range.start)? != unchanged {
+                return None;
+            }
+            text.extend(unchanged.iter().copied());
+            let old = old_lines.get(old_range.clone())?;
+            let new = new_lines.get(new_range.clone())?;
+            let kept = if hunk.accepted == Some(false) {
+                old
+            } else {
+                new
+            };
+            text.extend(kept.iter().copied());
+            (old_end, new_end) = (old_range.end, new_range.end);
+        }
+        let unchanged = old_lines.get(old_end..)?;
+        if new_lines.get(new_end..)? != unchanged {
+            return None;
+        }
+        text.extend(unchanged.iter().copied());
+        Some(text)
+    }
+
+    /// Reconstructs the file contents after this diff, given the file as it is now.
+    ///
+    /// `current` is the file's content, or `None` if it does not exist. When
+    /// [`Diff::old_text`] is present, `current` must match it exactly; otherwise the
+    /// diff was computed against a different version of the file and applying it would
+    /// silently discard changes. When `old_text` is absent (a new file), the file must
+    /// not exist yet. This is the same rule `ConflictReport::check` uses.
+    ///
+    /// Hunks the user rejected are left as they are in `old_text`, see
+    /// [`Diff::accepted_text`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DiffConflictError`] naming the `path` when `current` does not
+    /// match `old_text`, so clients can warn about a stale edit, or when rejected
+    /// hunks do not line up with the diff's text. It converts into an
+    /// [`Error::invalid_params`] error for replying to a request.
+    pub fn apply(&self, current: Option<&str>) -> std::result::Result<String, DiffConflictError> {
+        if let Some(reason) = self.conflict_reason(current) {
+            return Err(DiffConflictError {
+                path: self.path.clone(),
+                reason,
+            });
+        }
+        #[cfg(feature = "unstable_diff_hunks")]
+        let new_text = self.accepted_text().ok_or_else(|| DiffConflictError {
+            path: self.path.clone(),
+            reason: "the diff's hunks do not match its text",
+        })?;
+        #[cfg(not(feature = "unstable_diff_hunks"))]
+        let new_text = self.new_text.clone();
+        Ok(new_text)
+    }
+
+    /// Why this diff cannot be applied to a file holding `current`, `None` if
+    /// the file does not exist. Returns `None` when it can be applied.
+    fn conflict_reason(&self, current: Option<&str>) -> Option<&'static str> {
+        match (self.old_text.as_deref(), current) {
+            (Some(_), None) => Some("the file does not exist"),
+            (None, Some(_)) => Some("the file already exists"),
+            (old_text, current) => (old_text != current)
+                .then_some("file contents do not match the diff's original text"),
+        }
+    }
+
+    /// The 1-based line of `newText` where this diff's first change starts.
+    ///
+    /// A missing `oldText` is treated as an empty file. Returns `None` when
+    /// the diff changes nothing.
+    fn first_changed_line(&self) -> Option<u32> {
+        let old_text = self.old_text.as_deref().unwrap_or_default();
+        if old_text == self.new_text {
+            return None;
+        }
+        let unchanged = old_text
+            .split_inclusive('\n')
+            .zip(self.new_text.split_inclusive('\n'))
+            .take_while(|(old_line, new_line)| old_line == new_line)
+            .count();
+        u32::try_from(unchanged + 1).ok()
+    }
+
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
+    }
+}
+
+/// Error returned by [`Diff::apply`] when the file no longer holds the
+/// diff's `oldText`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DiffConflictError {
+    path: PathBuf,
+    reason: &'static str,
+}
+
+impl DiffConflictError {
+    /// Returns the absolute path of the file the diff targets.
+    #[must_use]
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+
+    /// Returns the human-readable reason the diff could not be applied.
+    #[must_use]
+    pub fn reason(&self) -> &str {
+        self.reason
+    }
+}
+
+impl std::fmt::Display for DiffConflictError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{}: {}", self.path.display(), self.reason)
+    }
+}
+
+impl std::error::Error for DiffConflictError {}
+
+impl From<DiffConflictError> for Error {
+    fn from(error: DiffConflictError) -> Self {
+        Error::invalid_params().data(serde_json::json!({
+            "reason": error.reason,
+            "path": error.path,
+        }))
+    }
+}
+
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A [`Diff`] that could not be applied because the file no longer matches it.
+#[cfg(feature = "unstable_edit_conflict")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ConflictReport {
+    /// The absolute path of the file the diff targets.
+    pub path: PathBuf,
+    /// The content the diff expected to replace, its `oldText`. Omitted when
+    /// the diff creates the file.
+    #[serde(default)]
+    pub expected: Option<String>,
+    /// The file's content when the client tried to apply the diff. Omitted when
+    /// the file does not exist.
+    #[serde(default)]
+    pub actual: Option<String>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+#[cfg(feature = "unstable_edit_conflict")]
+impl ConflictReport {
+    /// Builds [`ConflictReport`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(
+        path: impl Into<PathBuf>,
+        expected: impl IntoOption<String>,
+        actual: impl IntoOption<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            expected: expected.into_option(),
+            actual: actual.into_option(),
+            meta: None,
         }
     }
-}
-
-impl<'de> Deserialize<'de> for OtherToolCallContent {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        let mut fields = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
-        let type_ = fields
-            .remove("type")
-            .ok_or_else(|| serde::de::Error::missing_field("type"))?;
-        let serde_json::Value::String(type_) = type_ else {
-            return Err(serde::de::Error::custom("`type` must be a string"));
-        };
 
-        if is_known_tool_call_content_type(&type_) {
-            return Err(serde::de::Error::custom(format!(
-                "known tool call content `{type_}` did not match its schema"
-            )));
-        }
+    /// Compares `diff` with the file's `actual` content, `None` if the file
+    /// does not exist, returning a report if the file no longer holds the
+    /// diff's `oldText`.
+    ///
+    /// Diffs without `oldText` create a file, so they conflict when the file
+    /// already exists.
+    #[must_use]
+    pub fn check(diff: &Diff, actual: Option<&str>) -> Option<Self> {
+        diff.conflict_reason(actual)?;
+        Some(Self::new(
+            diff.path.clone(),
+            diff.old_text.clone(),
+            actual.map(str::to_owned),
+        ))
+    }
 
-        Ok(Self { type_, fields })
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
     }
 }
 
-fn is_known_tool_call_content_type(type_: &str) -> bool {
-    matches!(type_, "content" | "diff")
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A region of a [`Diff`] that the user can accept or reject on its own.
+#[cfg(feature = "unstable_diff_hunks")]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DiffHunk {
+    /// Identifies the hunk within its diff. Stays the same for the lifetime
+    /// of the tool call, so decisions can refer to it.
+    pub id: String,
+    /// The lines this hunk replaces in `oldText`.
+    pub old_range: DiffLineRange,
+    /// The lines this hunk introduces in `newText`.
+    pub new_range: DiffLineRange,
+    /// Whether the user accepted the hunk. Omitted while undecided.
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    pub accepted: Option<bool>,
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[serde_as(deserialize_as = "DefaultOnError")]
+    #[schemars(extend("x-deserialize-default-on-error" = true))]
+    #[serde(default)]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-fn other_tool_call_content_schema(schema: &mut Schema) {
-    super::schema_util::reject_known_string_discriminators(schema, "type", &["content", "diff"]);
-}
+#[cfg(feature = "unstable_diff_hunks")]
+impl DiffHunk {
+    /// Builds [`DiffHunk`] with the required fields set; optional fields start unset or empty.
+    #[must_use]
+    pub fn new(id: impl Into<String>, old_range: DiffLineRange, new_range: DiffLineRange) -> Self {
+        Self {
+            id: id.into(),
+            old_range,
+            new_range,
+            accepted: None,
+            meta: None,
+        }
+    }
 
-impl<T: Into<ContentBlock>> From<T> for ToolCallContent {
-    fn from(content: T) -> Self {
-        ToolCallContent::Content(Box::new(Content::new(content)))
+    /// Whether the user accepted the hunk. Omitted while undecided.
+    #[must_use]
+    pub fn accepted(mut self, accepted: impl IntoOption<bool>) -> Self {
+        self.accepted = accepted.into_option();
+        self
     }
-}
 
-impl From<Diff> for ToolCallContent {
-    fn from(diff: Diff) -> Self {
-        ToolCallContent::Diff(diff)
+    /// The _meta property is reserved by ACP to allow clients and agents to attach additional
+    /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
+    /// these keys.
+    ///
+    /// See protocol docs: [Extensibility](https://agentclientprotocol.com/protocol/extensibility)
+    #[must_use]
+    pub fn meta(mut self, meta: impl IntoOption<Meta>) -> Self {
+        self.meta = meta.into_option();
+        self
     }
 }
 
-/// Standard content block (text, images, resources).
+/// **UNSTABLE**
+///
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
+///
+/// A run of lines on one side of a [`DiffHunk`].
+#[cfg(feature = "unstable_diff_hunks")]
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct Content {
-    /// The actual content block.
-    pub content: ContentBlock,
+pub struct DiffLineRange {
+    /// The 1-based number of the first line in the range. For an empty range,
+    /// the number of the line it sits before.
+    pub start: u32,
+    /// How many lines the range covers. Zero for a pure insertion or deletion.
+    pub count: u32,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -457,16 +1667,23 @@ pub struct Content {
     pub meta: Option<Meta>,
 }
 
-impl Content {
-    /// Builds [`Content`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_diff_hunks")]
+impl DiffLineRange {
+    /// Builds [`DiffLineRange`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(content: impl Into<ContentBlock>) -> Self {
+    pub fn new(start: u32, count: u32) -> Self {
         Self {
-            content: content.into(),
+            start,
+            count,
             meta: None,
         }
     }
 
+    fn from_offset(offset: usize, count: usize) -> Self {
+        let to_u32 = |value: usize| u32::try_from(value).unwrap_or(u32::MAX);
+        Self::new(to_u32(offset.saturating_add(1)), to_u32(count))
+    }
+
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -479,26 +1696,24 @@ impl Content {
     }
 }
 
-/// A diff representing file modifications.
+/// **UNSTABLE**
 ///
-/// Shows changes to files in a format suitable for display in the client UI.
+/// This capability is not part of the spec yet, and may be removed or changed at any point.
 ///
-/// See protocol docs: [Content](https://agentclientprotocol.com/protocol/tool-calls#content)
+/// The user's decision on one [`DiffHunk`], sent in a permission response.
+#[cfg(feature = "unstable_diff_hunks")]
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
-pub struct Diff {
-    /// The absolute file path being modified.
+pub struct DiffHunkDecision {
+    /// The file the hunk belongs to, matching [`Diff::path`].
     pub path: PathBuf,
-    /// The original content (None for new files).
-    #[serde_as(deserialize_as = "DefaultOnError")]
-    #[schemars(extend("x-deserialize-default-on-error" = true))]
-    #[serde(default)]
-    pub old_text: Option<String>,
-    /// The new content after modification.
-    pub new_text: String,
+    /// The [`DiffHunk::id`] this decision applies to.
+    pub hunk_id: String,
+    /// Whether the user accepted the hunk.
+    pub accepted: bool,
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -511,25 +1726,19 @@ pub struct Diff {
     pub meta: Option<Meta>,
 }
 
-impl Diff {
-    /// Builds [`Diff`] with the required fields set; optional fields start unset or empty.
+#[cfg(feature = "unstable_diff_hunks")]
+impl DiffHunkDecision {
+    /// Builds [`DiffHunkDecision`] with the required fields set; optional fields start unset or empty.
     #[must_use]
-    pub fn new(path: impl Into<PathBuf>, new_text: impl Into<String>) -> Self {
+    pub fn new(path: impl Into<PathBuf>, hunk_id: impl Into<String>, accepted: bool) -> Self {
         Self {
             path: path.into(),
-            old_text: None,
-            new_text: new_text.into(),
+            hunk_id: hunk_id.into(),
+            accepted,
             meta: None,
         }
     }
 
-    /// The original content (None for new files).
-    #[must_use]
-    pub fn old_text(mut self, old_text: impl IntoOption<String>) -> Self {
-        self.old_text = old_text.into_option();
-        self
-    }
-
     /// The _meta property is reserved by ACP to allow clients and agents to attach additional
     /// metadata to their interactions. Implementations MUST NOT make assumptions about values at
     /// these keys.
@@ -608,6 +1817,277 @@ mod tests {
     use super::*;
     use crate::MaybeUndefined;
 
+    #[test]
+    fn test_tool_call_status_is_terminal() {
+        for (status, terminal) in [
+            (ToolCallStatus::Pending, false),
+            (ToolCallStatus::InProgress, false),
+            (ToolCallStatus::Completed, true),
+            (ToolCallStatus::Failed, true),
+            #[cfg(feature = "unstable_tool_call_cancelled")]
+            (ToolCallStatus::Cancelled, true),
+            #[cfg(feature = "unstable_tool_call_awaiting_confirmation")]
+            (ToolCallStatus::AwaitingConfirmation, false),
+            (ToolCallStatus::Other("_paused".into()), false),
+        ] {
+            assert_eq!(status.is_terminal(), terminal, "{status:?}");
+        }
+    }
+
+    #[cfg(feature = "unstable_tool_call_cancelled")]
+    #[test]
+    fn test_tool_call_status_cancelled_roundtrip() {
+        assert_eq!(
+            serde_json::to_value(ToolCallStatus::Cancelled).unwrap(),
+            "cancelled"
+        );
+        assert_eq!(
+            serde_json::from_value::<ToolCallStatus>("cancelled".into()).unwrap(),
+            ToolCallStatus::Cancelled
+        );
+
+        let update = ToolCallUpdate::new("call_001").status(ToolCallStatus::Cancelled);
+        let json = serde_json::to_value(&update).unwrap();
+        assert_eq!(json["status"], "cancelled");
+        assert_eq!(
+            serde_json::from_value::<ToolCallUpdate>(json).unwrap(),
+            update
+        );
+    }
+
+    #[cfg(feature = "unstable_tool_call_awaiting_confirmation")]
+    #[test]
+    fn test_tool_call_status_awaiting_confirmation_roundtrip() {
+        assert_eq!(
+            serde_json::to_value(ToolCallStatus::AwaitingConfirmation).unwrap(),
+            "awaiting_confirmation"
+        );
+        assert_eq!(
+            serde_json::from_value::<ToolCallStatus>("awaiting_confirmation".into()).unwrap(),
+            ToolCallStatus::AwaitingConfirmation
+        );
+        assert!(!ToolCallStatus::AwaitingConfirmation.is_terminal());
+
+        let tool_call =
+            ToolCallUpdate::new("call_001").status(ToolCallStatus::AwaitingConfirmation);
+        let json = serde_json::to_value(&tool_call).unwrap();
+        assert_eq!(json["status"], "awaiting_confirmation");
+        assert_eq!(
+            serde_json::from_value::<ToolCallUpdate>(json).unwrap(),
+            tool_call
+        );
+    }
+
+    #[test]
+    fn test_edit_locations() {
+        let tool_call = ToolCallUpdate::new("call_001")
+            .content(vec![
+                ToolCallContent::from("Renamed `port` to `listen_port`."),
+                Diff::new(
+                    "/project/src/config.rs",
+                    "use std::net;\n\nlet listen_port = 80;\n",
+                )
+                .old_text("use std::net;\n\nlet port = 80;\n")
+                .into(),
+                Diff::new("/project/CHANGELOG.md", "- Renamed `port`.\n").into(),
+            ])
+            .locations(vec![
+                ToolCallLocation::new("/project/src/config.rs").line(3),
+                ToolCallLocation::new("/project/src/main.rs").line(10),
+                ToolCallLocation::new("/project/CHANGELOG.md").line(1),
+            ]);
+
+        assert_eq!(
+            tool_call.edit_locations(),
+            vec![
+                ToolCallLocation::new("/project/src/config.rs").line(3),
+                ToolCallLocation::new("/project/CHANGELOG.md").line(1),
+                ToolCallLocation::new("/project/src/main.rs").line(10),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_edit_locations_omits_line_for_unchanged_diff() {
+        let tool_call = ToolCallUpdate::new("call_001").content(vec![
+            Diff::new("/project/README.md", "same\n")
+                .old_text("same\n")
+                .into(),
+        ]);
+
+        assert_eq!(
+            tool_call.edit_locations(),
+            vec![ToolCallLocation::new("/project/README.md")]
+        );
+    }
+
+    #[test]
+    fn test_render_items() {
+        let config_diff = Diff::new("/project/src/config.rs", "let listen_port = 80;\n")
+            .old_text("let port = 80;\n");
+        let changelog_diff = Diff::new("/project/CHANGELOG.md", "- Renamed `port`.\n");
+        let tool_call = ToolCallUpdate::new("call_001")
+            .content(vec![
+                ToolCallContent::from("Renamed `port` to `listen_port`."),
+                config_diff.clone().into(),
+                ToolCallContent::Other(OtherToolCallContent::new("_chart", BTreeMap::new())),
+                changelog_diff.clone().into(),
+            ])
+            .locations(vec![
+                ToolCallLocation::new("/project/src/config.rs").line(1),
+                ToolCallLocation::new("/project/src/main.rs").line(10),
+                ToolCallLocation::new("/project/src/config.rs").line(20),
+            ]);
+
+        assert_eq!(
+            tool_call.render_items(),
+            vec![
+                RenderItem::Content(&ContentBlock::from("Renamed `port` to `listen_port`.")),
+                RenderItem::Diff {
+                    diff: &config_diff,
+                    locations: vec![
+                        &ToolCallLocation::new("/project/src/config.rs").line(1),
+                        &ToolCallLocation::new("/project/src/config.rs").line(20),
+                    ],
+                },
+                RenderItem::Other(&OtherToolCallContent::new("_chart", BTreeMap::new())),
+                RenderItem::Diff {
+                    diff: &changelog_diff,
+                    locations: vec![],
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "unstable_tool_call_group")]
+    #[test]
+    fn test_tool_call_group_roundtrip() {
+        let first = ToolCallUpdate::new("call_1")
+            .title("Run tests")
+            .group_id("tests");
+        let retry = ToolCallUpdate::new("call_2")
+            .title("Run tests")
+            .group_id("tests");
+        for tool_call in [&first, &retry] {
+            let json = serde_json::to_value(tool_call).unwrap();
+            assert_eq!(json["groupId"], "tests");
+            assert_eq!(
+                &serde_json::from_value::<ToolCallUpdate>(json).unwrap(),
+                tool_call
+            );
+        }
+        assert_eq!(
+            serde_json::to_value(&first).unwrap(),
+            serde_json::json!({"toolCallId": "call_1", "title": "Run tests", "groupId": "tests"})
+        );
+
+        let mut updated = ToolCallUpdate::new("call_1").title("Run tests");
+        updated.apply_update(ToolCallUpdate::new("call_1").group_id("tests"));
+        assert_eq!(updated, first);
+    }
+
+    #[cfg(feature = "unstable_tool_call_group")]
+    #[test]
+    fn test_tool_call_group_by() {
+        let tool_calls = [
+            ToolCallUpdate::new("call_1")
+                .title("Run tests")
+                .group_id("tests"),
+            ToolCallUpdate::new("call_2").title("Read file"),
+            ToolCallUpdate::new("call_3")
+                .title("Run tests")
+                .group_id("tests"),
+            ToolCallUpdate::new("call_4").title("Search"),
+        ];
+
+        let groups = ToolCallUpdate::group_by(&tool_calls);
+        assert_eq!(
+            groups,
+            [
+                vec![&tool_calls[0], &tool_calls[2]],
+                vec![&tool_calls[1]],
+                vec![&tool_calls[3]],
+            ]
+        );
+    }
+
+    #[cfg(feature = "unstable_tool_call_parent")]
+    #[test]
+    fn test_tool_call_build_tree() {
+        let tool_calls = [
+            ToolCallUpdate::new("call_agent").title("Run sub-agent"),
+            ToolCallUpdate::new("call_grep")
+                .title("Search for TODOs")
+                .parent_id(ToolCallId::new("call_agent")),
+            ToolCallUpdate::new("call_lint")
+                .title("Run linter")
+                .parent_id(ToolCallId::new("call_missing")),
+            ToolCallUpdate::new("call_read")
+                .title("Read file")
+                .parent_id(ToolCallId::new("call_agent")),
+        ];
+        let json = serde_json::to_value(&tool_calls[1]).unwrap();
+        assert_eq!(json["parentId"], "call_agent");
+        assert_eq!(
+            serde_json::from_value::<ToolCallUpdate>(json).unwrap(),
+            tool_calls[1]
+        );
+
+        let tree = ToolCallUpdate::build_tree(&tool_calls);
+        let roots: Vec<_> = tree.iter().map(ToolCallNode::tool_call).collect();
+        assert_eq!(roots, [&tool_calls[0], &tool_calls[2]]);
+        let children: Vec<_> = tree[0]
+            .children()
+            .iter()
+            .map(ToolCallNode::tool_call)
+            .collect();
+        assert_eq!(children, [&tool_calls[1], &tool_calls[3]]);
+        assert!(tree[1].children().is_empty());
+    }
+
+    #[cfg(feature = "unstable_raw_output_patch")]
+    #[test]
+    fn test_raw_output_patch_adds_overwrites_and_deletes_keys() {
+        let mut tool_call = ToolCallUpdate::new("call_1")
+            .title("Run tests")
+            .raw_output(serde_json::json!({"status": "running", "log": ["collecting"], "pid": 42}));
+        let patch = ToolCallUpdate::new("call_1").raw_output_patch(serde_json::json!({
+            "status": "passed",
+            "log": ["collecting", "1 passed"],
+            "summary": {"passed": 1},
+            "pid": null
+        }));
+        assert_eq!(
+            serde_json::to_value(&patch).unwrap()["rawOutputPatch"]["pid"],
+            serde_json::json!(null)
+        );
+        tool_call.apply_update(patch);
+        assert_eq!(
+            tool_call.raw_output,
+            MaybeUndefined::Value(
+                serde_json::json!({"status": "passed", "log": ["collecting", "1 passed"], "summary": {"passed": 1}})
+            )
+        );
+    }
+
+    #[cfg(feature = "unstable_raw_output_patch")]
+    #[test]
+    fn test_raw_output_patch_without_existing_output() {
+        let mut tool_call = ToolCallUpdate::new("call_1");
+        tool_call.apply_update(
+            ToolCallUpdate::new("call_1")
+                .raw_output_patch(serde_json::json!({"status": "running", "pid": null})),
+        );
+        assert_eq!(
+            tool_call.raw_output,
+            MaybeUndefined::Value(serde_json::json!({"status": "running"}))
+        );
+
+        tool_call
+            .apply_update(ToolCallUpdate::new("call_1").raw_output_patch(MaybeUndefined::Null));
+        assert_eq!(tool_call.raw_output, MaybeUndefined::Null);
+    }
+
     #[test]
     fn tool_call_serializes_as_upsert() {
         let tool_call = ToolCallUpdate::new("tc_1")
@@ -767,4 +2247,141 @@ mod tests {
             .is_err()
         );
     }
+
+    #[cfg(feature = "unstable_tool_call_error")]
+    #[test]
+    fn tool_call_error_is_carried_through_apply_update() {
+        let mut tool_call = ToolCallUpdate::new("tc_1").title("Query");
+        tool_call.apply_update(
+            ToolCallUpdate::new("tc_1")
+                .status(ToolCallStatus::Failed)
+                .error(
+                    ToolCallError::new("connection refused")
+                        .detail(serde_json::json!({"port": 5432})),
+                ),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&tool_call).unwrap(),
+            serde_json::json!({
+                "toolCallId": "tc_1",
+                "title": "Query",
+                "status": "failed",
+                "error": {
+                    "message": "connection refused",
+                    "detail": {"port": 5432}
+                }
+            })
+        );
+
+        tool_call.apply_update(ToolCallUpdate::new("tc_1").error(None::<ToolCallError>));
+        assert_eq!(tool_call.error, MaybeUndefined::Null);
+    }
+
+    #[test]
+    fn test_diff_apply() {
+        let diff = Diff::new("/src/main.rs", "fn main() {}\n").old_text("fn main() {\n}\n");
+        assert_eq!(
+            diff.apply(Some("fn main() {\n}\n")).unwrap(),
+            "fn main() {}\n"
+        );
+        assert_eq!(
+            diff.apply(None).unwrap_err().reason(),
+            "the file does not exist"
+        );
+
+        let new_file = Diff::new("/src/lib.rs", "pub mod cli;\n");
+        assert_eq!(new_file.apply(None).unwrap(), "pub mod cli;\n");
+        // Creating a file that appeared in the meantime would overwrite it.
+        for current in ["pub mod old;\n", ""] {
+            assert_eq!(
+                new_file.apply(Some(current)).unwrap_err().reason(),
+                "the file already exists"
+            );
+        }
+
+        let err = diff.apply(Some("fn main() { todo!() }\n")).unwrap_err();
+        assert_eq!(err.path(), std::path::Path::new("/src/main.rs"));
+        assert_eq!(
+            err.to_string(),
+            "/src/main.rs: file contents do not match the diff's original text"
+        );
+
+        // Replying to a request still sends `invalid_params` naming the path.
+        let err = Error::from(err);
+        assert_eq!(err.code, crate::v2::ErrorCode::InvalidParams);
+        assert_eq!(
+            err.data.unwrap(),
+            serde_json::json!({
+                "reason": "file contents do not match the diff's original text",
+                "path": "/src/main.rs"
+            })
+        );
+    }
+
+    #[cfg(feature = "unstable_diff_hunks")]
+    #[test]
+    fn test_diff_hunks() {
+        let diff = Diff::new("/src/main.rs", "a\nB\nc\nd\nE\nf\n").old_text("a\nb\nc\nd\ne\n");
+        let hunks = diff.compute_hunks();
+        assert_eq!(
+            hunks,
+            vec![
+                DiffHunk::new("0", DiffLineRange::new(2, 1), DiffLineRange::new(2, 1)),
+                DiffHunk::new("1", DiffLineRange::new(5, 1), DiffLineRange::new(5, 2)),
+            ]
+        );
+
+        let mut diff = diff.hunks(hunks);
+        diff.apply_hunk_decisions(&[
+            DiffHunkDecision::new("/src/main.rs", "1", false),
+            DiffHunkDecision::new("/src/other.rs", "0", true),
+        ]);
+        assert_eq!(diff.hunks[0].accepted, None);
+        assert_eq!(diff.hunks[1].accepted, Some(false));
+        assert_eq!(
+            diff.apply(Some("a\nb\nc\nd\ne\n")).unwrap(),
+            "a\nB\nc\nd\ne\n"
+        );
+        assert_eq!(
+            diff.clone()
+                .hunks(vec![diff.hunks[1].clone()])
+                .accepted_text(),
+            None
+        );
+
+        let new_file = Diff::new("/src/lib.rs", "pub mod cli;\n");
+        assert_eq!(
+            new_file.compute_hunks(),
+            vec![DiffHunk::new(
+                "0",
+                DiffLineRange::new(1, 0),
+                DiffLineRange::new(1, 1)
+            )]
+        );
+    }
+
+    #[cfg(feature = "unstable_tool_call_schema")]
+    #[test]
+    fn tool_call_schemas_are_carried_through_apply_update() {
+        let mut tool_call = ToolCallUpdate::new("tc_1")
+            .title("Read")
+            .input_schema(serde_json::json!({"type": "object"}));
+        tool_call.apply_update(
+            ToolCallUpdate::new("tc_1").output_schema(serde_json::json!({"type": "string"})),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&tool_call).unwrap(),
+            serde_json::json!({
+                "toolCallId": "tc_1",
+                "title": "Read",
+                "inputSchema": {"type": "object"},
+                "outputSchema": {"type": "string"}
+            })
+        );
+
+        tool_call.apply_update(ToolCallUpdate::new("tc_1").input_schema(None::<serde_json::Value>));
+        assert_eq!(tool_call.input_schema, MaybeUndefined::Null);
+    }
 }
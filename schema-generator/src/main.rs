@@ -3,22 +3,21 @@
 use agent_client_protocol_schema::ProtocolVersion;
 #[cfg(not(feature = "unstable_protocol_v2"))]
 use agent_client_protocol_schema::v1::{
-    AGENT_METHOD_NAMES, AgentNotification, AgentRequest, AgentResponse, CLIENT_METHOD_NAMES,
-    ClientNotification, ClientRequest, ClientResponse, JsonRpcMessage, Notification,
-    PROTOCOL_LEVEL_METHOD_NAMES, ProtocolLevelNotification, Request, Response,
+    AGENT_METHOD_NAMES, AgentOutgoingMessage, CLIENT_METHOD_NAMES, ClientOutgoingMessage,
+    JsonRpcMessage, Notification, PROTOCOL_LEVEL_METHOD_NAMES, ProtocolLevelNotification,
 };
 #[cfg(feature = "unstable_protocol_v2")]
 use agent_client_protocol_schema::v2::{
-    AGENT_METHOD_NAMES, AgentNotification, AgentRequest, AgentResponse, CLIENT_METHOD_NAMES,
-    ClientNotification, ClientRequest, ClientResponse, JsonRpcBatch, JsonRpcMessage, Notification,
-    PROTOCOL_LEVEL_METHOD_NAMES, ProtocolLevelNotification, Request, Response,
+    AGENT_METHOD_NAMES, AgentBatchCallMessage, AgentOutgoingMessage, AgentResponse,
+    CLIENT_METHOD_NAMES, ClientBatchCallMessage, ClientOutgoingMessage, ClientResponse,
+    JsonRpcBatch, JsonRpcMessage, Notification, PROTOCOL_LEVEL_METHOD_NAMES,
+    ProtocolLevelNotification, Response,
 };
 use schemars::{
     JsonSchema,
     generate::SchemaSettings,
     transform::{RemoveRefSiblings, ReplaceBoolSchemas},
 };
-use serde::{Deserialize, Serialize};
 use std::{
     env, fs,
     path::{Path, PathBuf},
@@ -48,52 +47,6 @@ const VERSIONED_PROTOCOL_DOC_PATHS: &[&str] = &[
     "transports",
 ];
 
-/// All messages that an agent can send to a client.
-#[derive(Serialize, Deserialize, JsonSchema)]
-#[serde(untagged)]
-#[schemars(inline)]
-#[allow(clippy::large_enum_variant)]
-enum AgentOutgoingMessage {
-    Request(Request<AgentRequest>),
-    Response(Response<AgentResponse>),
-    Notification(Notification<AgentNotification>),
-}
-
-/// All messages that a client can send to an agent.
-#[derive(Serialize, Deserialize, JsonSchema)]
-#[serde(untagged)]
-#[schemars(inline)]
-#[allow(clippy::large_enum_variant)]
-enum ClientOutgoingMessage {
-    Request(Request<ClientRequest>),
-    Response(Response<ClientResponse>),
-    Notification(Notification<ClientNotification>),
-}
-
-/// Messages that an agent can include in a JSON-RPC batch call to a client.
-#[cfg(feature = "unstable_protocol_v2")]
-#[derive(Serialize, Deserialize, JsonSchema)]
-#[serde(untagged)]
-#[schemars(inline)]
-#[allow(clippy::large_enum_variant)]
-enum AgentBatchCallMessage {
-    Request(Request<AgentRequest>),
-    Notification(Notification<AgentNotification>),
-    ProtocolLevelNotification(Notification<ProtocolLevelNotification>),
-}
-
-/// Messages that a client can include in a JSON-RPC batch call to an agent.
-#[cfg(feature = "unstable_protocol_v2")]
-#[derive(Serialize, Deserialize, JsonSchema)]
-#[serde(untagged)]
-#[schemars(inline)]
-#[allow(clippy::large_enum_variant)]
-enum ClientBatchCallMessage {
-    Request(Request<ClientRequest>),
-    Notification(Notification<ClientNotification>),
-    ProtocolLevelNotification(Notification<ProtocolLevelNotification>),
-}
-
 #[expect(dead_code)]
 #[derive(JsonSchema)]
 #[serde(untagged)]
@@ -327,6 +280,35 @@ mod schema_annotation_tests {
     const DEFAULT_ON_ERROR_EXTENSION: &str = "x-deserialize-default-on-error";
     const SKIP_INVALID_ITEMS_EXTENSION: &str = "x-deserialize-skip-invalid-items";
 
+    /// Fields that fail deserialization on invalid input on purpose, as
+    /// `(struct, field)` pairs. Dropping their values would change what the
+    /// message asks for instead of losing optional detail.
+    const STRICT_FIELDS: &[(&str, &str)] = &[
+        ("CreateMessageRequest", "messages"),
+        // Skipping an invalid header would shift every later column.
+        ("Table", "columns"),
+        // Skipping an invalid id would widen the update to every tool call.
+        ("ToolCallsCancelled", "tool_call_ids"),
+        // Dropping an invalid plan id would patch the session's default plan.
+        ("PlanEntryUpdate", "plan_id"),
+        // Dropping an invalid cursor would replay the session from the start.
+        ("LoadSessionRequest", "cursor"),
+        // Dropping an invalid turn id would leave the turn's updates unattributed.
+        ("PromptRequest", "turn_id"),
+        // Dropping an invalid value would report a missing file, or a diff that
+        // creates one.
+        ("ConflictReport", "expected"),
+        ("ConflictReport", "actual"),
+        // Dropping an invalid rejection would apply a hunk the user rejected.
+        ("SelectedPermissionOutcome", "hunk_decisions"),
+        // Dropping an invalid flag would turn a dry run into a real write.
+        ("WriteTextFileRequest", "dry_run"),
+        // Out-of-range sampling values reject the whole override set.
+        ("SamplingOverrides", "temperature"),
+        ("SamplingOverrides", "top_p"),
+        ("SamplingOverrides", "max_tokens"),
+    ];
+
     #[test]
     fn generated_schema_includes_tolerant_deserialization_extensions() {
         let schema = root_schema_value();
@@ -515,6 +497,9 @@ mod schema_annotation_tests {
                     let Some(field_type) = public_struct_field_type(line) else {
                         continue;
                     };
+                    if is_strict_field(&lines, line_index) {
+                        continue;
+                    }
 
                     let annotations = field_annotations(&lines, line_index);
                     let has_serde_default = serde_annotations_contain_default(&annotations);
@@ -689,6 +674,24 @@ mod schema_annotation_tests {
         Some(field_type.trim_end_matches(',').trim())
     }
 
+    fn is_strict_field(lines: &[&str], line_index: usize) -> bool {
+        let Some((field, _)) = lines[line_index]
+            .trim()
+            .strip_prefix("pub ")
+            .and_then(|line| line.split_once(':'))
+        else {
+            return false;
+        };
+        let Some(struct_name) = lines[..line_index].iter().rev().find_map(|line| {
+            let name = line.trim_start().strip_prefix("pub struct ")?;
+            name.split(|c: char| !c.is_alphanumeric() && c != '_')
+                .next()
+        }) else {
+            return false;
+        };
+        STRICT_FIELDS.contains(&(struct_name, field))
+    }
+
     fn field_annotations(lines: &[&str], line_index: usize) -> String {
         let mut start = line_index;
         let mut cursor = line_index;
@@ -1871,6 +1874,11 @@ starting with '$/' it is free to ignore the notification."
                     .or_else(|| self.agent.get("CancelNotification"))
                     .unwrap(),
                 "session/close" => self.agent.get("CloseSessionRequest").unwrap(),
+                "session/compact" => self.agent.get("CompactSessionRequest").unwrap(),
+                "session/set_title" => self.agent.get("SetSessionTitleRequest").unwrap(),
+                "session/cancel_tool_call" => self.agent.get("CancelToolCallRequest").unwrap(),
+                "command/invoke" => self.agent.get("InvokeCommandRequest").unwrap(),
+                "session/provide_input" => self.agent.get("ProvideInputRequest").unwrap(),
                 "logout" => self.agent.get("LogoutRequest").unwrap(),
                 "auth/logout" => self.agent.get("LogoutAuthRequest").unwrap(),
                 "nes/start" => self.agent.get("StartNesRequest").unwrap(),
@@ -1884,6 +1892,10 @@ starting with '$/' it is free to ignore the notification."
                 "document/didSave" => self.agent.get("DidSaveDocumentNotification").unwrap(),
                 "document/didFocus" => self.agent.get("DidFocusDocumentNotification").unwrap(),
                 "mcp/message" => self.agent.get("MessageMcpRequest").unwrap(),
+                "fs/file_changed" => self.agent.get("FileChangedNotification").unwrap(),
+                "session/report_edit_conflict" => {
+                    self.agent.get("ReportEditConflictNotification").unwrap()
+                }
                 _ => panic!("Introduced a method? Add it here :)"),
             }
         }
@@ -1912,6 +1924,12 @@ starting with '$/' it is free to ignore the notification."
                 "mcp/connect" => self.client.get("ConnectMcpRequest").unwrap(),
                 "mcp/message" => self.client.get("MessageMcpRequest").unwrap(),
                 "mcp/disconnect" => self.client.get("DisconnectMcpRequest").unwrap(),
+                "sampling/create_message" => self.client.get("CreateMessageRequest").unwrap(),
+                "fs/watch" => self.client.get("WatchPathsRequest").unwrap(),
+                "clipboard/read" => self.client.get("ReadClipboardRequest").unwrap(),
+                "clipboard/write" => self.client.get("WriteClipboardRequest").unwrap(),
+                "env/get" => self.client.get("GetEnvRequest").unwrap(),
+                "agent/ready" => self.client.get("AgentReadyNotification").unwrap(),
                 _ => panic!("Introduced a method? Add it here :)"),
             }
         }